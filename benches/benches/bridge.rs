@@ -0,0 +1,74 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use cxx_qt_benches::ffi;
+use cxx_qt_lib::QString;
+use std::hint::black_box;
+
+fn baseline(c: &mut Criterion) {
+    let object = ffi::bench_object_new();
+    c.bench_function("baseline call into C++", |b| {
+        b.iter(|| ffi::bench_object_baseline(black_box(&object)))
+    });
+}
+
+fn property(c: &mut Criterion) {
+    let mut object = ffi::bench_object_new();
+    let mut group = c.benchmark_group("property");
+    group.bench_function("get i32", |b| {
+        b.iter(|| ffi::bench_object_get_number(black_box(&object)))
+    });
+    let mut value = 0;
+    group.bench_function("set i32", |b| {
+        b.iter(|| {
+            // Change the value each time, otherwise the setter returns early
+            value += 1;
+            ffi::bench_object_set_number(object.pin_mut(), black_box(value))
+        })
+    });
+
+    let text = QString::from("A string which is long enough to not be inline");
+    ffi::bench_object_set_text(object.pin_mut(), &text);
+    group.bench_function("get QString", |b| {
+        b.iter(|| ffi::bench_object_get_text(black_box(&object)))
+    });
+    let texts = [QString::from("first"), QString::from("second")];
+    let mut index = 0;
+    group.bench_function("set QString", |b| {
+        b.iter(|| {
+            index = (index + 1) % texts.len();
+            ffi::bench_object_set_text(object.pin_mut(), black_box(&texts[index]))
+        })
+    });
+    group.finish();
+}
+
+fn invokable(c: &mut Criterion) {
+    let object = ffi::bench_object_new();
+    c.bench_function("invokable i32", |b| {
+        b.iter(|| ffi::bench_object_add(black_box(&object), black_box(1), black_box(2)))
+    });
+}
+
+fn signal(c: &mut Criterion) {
+    let mut object = ffi::bench_object_new();
+    let mut group = c.benchmark_group("signal");
+    group.bench_function("emit without connections", |b| {
+        b.iter(|| object.pin_mut().value_changed(black_box(1)))
+    });
+
+    // The handler is disconnected when the connection is dropped at the end of the function
+    let _connection = object.pin_mut().on_value_changed(|_, value| {
+        black_box(value);
+    });
+    group.bench_function("emit to a Rust handler", |b| {
+        b.iter(|| object.pin_mut().value_changed(black_box(1)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, baseline, property, invokable, signal);
+criterion_main!(benches);
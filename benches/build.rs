@@ -0,0 +1,19 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_build::CxxQtBuilder;
+
+fn main() {
+    CxxQtBuilder::new()
+        .file("src/lib.rs")
+        .cc_builder(|cc| {
+            cc.include("include");
+            cc.file("src/benchobject.cpp");
+        })
+        .build();
+
+    println!("cargo:rerun-if-changed=include/cxx-qt-benches/benchobject.h");
+    println!("cargo:rerun-if-changed=src/benchobject.cpp");
+}
@@ -0,0 +1,37 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QString>
+
+namespace cxx_qt::benches {
+
+// The QObject is only complete in the generated header,
+// which includes this header, so it is declared here
+class BenchObject;
+
+// Each helper calls the generated C++ method, so that calling the helper from
+// Rust measures a round trip from Rust to C++ and back into the Rust struct
+::std::unique_ptr<BenchObject>
+benchobjectNew();
+::std::int32_t
+benchobjectAdd(const BenchObject& object, ::std::int32_t a, ::std::int32_t b);
+::std::int32_t
+benchobjectBaseline(const BenchObject& object);
+::std::int32_t
+benchobjectGetNumber(const BenchObject& object);
+QString
+benchobjectGetText(const BenchObject& object);
+void
+benchobjectSetNumber(BenchObject& object, ::std::int32_t value);
+void
+benchobjectSetText(BenchObject& object, const QString& value);
+
+}
@@ -0,0 +1,56 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-benches/benchobject.h"
+
+#include "cxx-qt-gen/benchobject.cxxqt.h"
+
+namespace cxx_qt::benches {
+
+::std::unique_ptr<BenchObject>
+benchobjectNew()
+{
+  return ::std::make_unique<BenchObject>();
+}
+
+::std::int32_t
+benchobjectAdd(const BenchObject& object, ::std::int32_t a, ::std::int32_t b)
+{
+  return object.add(a, b);
+}
+
+::std::int32_t
+benchobjectBaseline(const BenchObject&)
+{
+  return 0;
+}
+
+::std::int32_t
+benchobjectGetNumber(const BenchObject& object)
+{
+  return object.getNumber();
+}
+
+QString
+benchobjectGetText(const BenchObject& object)
+{
+  return object.getText();
+}
+
+void
+benchobjectSetNumber(BenchObject& object, ::std::int32_t value)
+{
+  object.setNumber(value);
+}
+
+void
+benchobjectSetText(BenchObject& object, const QString& value)
+{
+  object.setText(value);
+}
+
+}
@@ -0,0 +1,82 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! A QObject which is used to measure the overhead of calls across a cxx-qt bridge
+//!
+//! Run the benchmarks with `cargo bench -p cxx-qt-benches`.
+
+/// The bridge with the QObject and helpers to call its generated C++ methods
+#[cxx_qt::bridge(cxx_file_stem = "benchobject", namespace = "cxx_qt::benches")]
+pub mod ffi {
+    #[namespace = ""]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        /// QString from cxx_qt_lib
+        type QString = cxx_qt_lib::QString;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-benches/benchobject.h");
+
+        /// Construct a new QObject without a parent
+        #[rust_name = "bench_object_new"]
+        fn benchobjectNew() -> UniquePtr<BenchObject>;
+
+        /// Call the invokable from C++
+        #[rust_name = "bench_object_add"]
+        fn benchobjectAdd(object: &BenchObject, a: i32, b: i32) -> i32;
+
+        /// A C++ function which does nothing, to measure the cost of calling into C++ alone
+        #[rust_name = "bench_object_baseline"]
+        fn benchobjectBaseline(object: &BenchObject) -> i32;
+
+        /// Call the getter of the property from C++
+        #[rust_name = "bench_object_get_number"]
+        fn benchobjectGetNumber(object: &BenchObject) -> i32;
+
+        /// Call the getter of the property from C++
+        #[rust_name = "bench_object_get_text"]
+        fn benchobjectGetText(object: &BenchObject) -> QString;
+
+        /// Call the setter of the property from C++
+        #[rust_name = "bench_object_set_number"]
+        fn benchobjectSetNumber(object: Pin<&mut BenchObject>, value: i32);
+
+        /// Call the setter of the property from C++
+        #[rust_name = "bench_object_set_text"]
+        fn benchobjectSetText(object: Pin<&mut BenchObject>, value: &QString);
+    }
+
+    unsafe extern "RustQt" {
+        #[qobject]
+        #[qproperty(i32, number)]
+        #[qproperty(QString, text)]
+        type BenchObject = super::BenchObjectRust;
+
+        /// A signal with a trivial argument
+        #[qsignal]
+        fn value_changed(self: Pin<&mut BenchObject>, value: i32);
+
+        /// An invokable with trivial arguments
+        #[qinvokable]
+        fn add(self: &BenchObject, a: i32, b: i32) -> i32;
+    }
+}
+
+use cxx_qt_lib::QString;
+
+/// The Rust struct of the QObject
+#[derive(Default)]
+pub struct BenchObjectRust {
+    number: i32,
+    text: QString,
+}
+
+impl ffi::BenchObject {
+    /// Add the two values
+    pub fn add(&self, a: i32, b: i32) -> i32 {
+        a.wrapping_add(b)
+    }
+}
@@ -0,0 +1,38 @@
+# SPDX-FileCopyrightText: 2026 agent <agent@local>
+# SPDX-FileContributor: agent <agent@local>
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+#[=======================================================================[.rst:
+CxxQtLibHeaders
+---------------
+
+Create an INTERFACE library with the cxx-qt-lib headers, so that C++ code can
+include them as `cxx-qt-lib/qstring.h` or `cxx-qt-lib/cxx-qt-lib.h` without
+running a Rust build first.
+
+  cxx_qt_lib_headers(<target> [FEATURES <feature>...])
+
+The FEATURES are the optional Qt modules of cxx-qt-lib, such as `gui` or `qml`,
+which define the matching `CXX_QT_<FEATURE>_FEATURE` for the headers.
+
+The headers include `rust/cxx.h`, so its directory must also be an include
+directory of the consumer and the consumer must link to Qt.
+#]=======================================================================]
+
+set(CXX_QT_LIB_HEADERS_DIR "${CMAKE_CURRENT_LIST_DIR}/../crates/cxx-qt-lib-headers/include")
+
+function(cxx_qt_lib_headers TARGET)
+  cmake_parse_arguments(PARSE_ARGV 1 ARG "" "" "FEATURES")
+
+  # Use the same cxx-qt-lib prefix as the headers which are written by Cargo builds
+  set(HEADER_DIR "${CMAKE_CURRENT_BINARY_DIR}/${TARGET}/include")
+  file(GLOB_RECURSE HEADERS "${CXX_QT_LIB_HEADERS_DIR}/*.h")
+  file(COPY ${HEADERS} DESTINATION "${HEADER_DIR}/cxx-qt-lib")
+
+  add_library(${TARGET} INTERFACE)
+  target_include_directories(${TARGET} INTERFACE "${HEADER_DIR}")
+  foreach(FEATURE ${ARG_FEATURES})
+    string(TOUPPER "${FEATURE}" FEATURE)
+    target_compile_definitions(${TARGET} INTERFACE "CXX_QT_${FEATURE}_FEATURE")
+  endforeach()
+endfunction()
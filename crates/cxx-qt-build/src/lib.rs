@@ -21,16 +21,17 @@ pub use qml_modules::QmlModule;
 use convert_case::{Case, Casing};
 use quote::ToTokens;
 use std::{
-    collections::HashSet,
+    collections::BTreeSet,
     env,
     fs::File,
     io::Write,
     path::{Path, PathBuf},
 };
 
+pub use cxx_qt_gen::CppFormatStyle;
 use cxx_qt_gen::{
-    parse_qt_file, write_cpp, write_rust, CppFragment, CxxQtItem, GeneratedCppBlocks,
-    GeneratedRustBlocks, Parser,
+    generate_warnings, parse_qt_file, write_cpp_with_style, write_rust, CppFragment, CxxQtItem,
+    GeneratedCppBlocks, GeneratedRustBlocks, GeneratedWarning, Parser, SourceIdents,
 };
 
 // TODO: we need to eventually support having multiple modules defined in a single file. This
@@ -51,11 +52,18 @@ struct GeneratedCpp {
     cxx_qt: Option<CppFragment>,
     cxx: cxx_gen::GeneratedCode,
     file_ident: String,
+    /// The cargo warnings for the bridge, these are printed once all of the bridges are generated
+    warnings: Vec<String>,
 }
 
 impl GeneratedCpp {
     /// Generate QObject and cxx header/source C++ file contents
-    pub fn new(rust_file_path: impl AsRef<Path>) -> Result<Self, Diagnostic> {
+    pub fn new(
+        rust_file_path: impl AsRef<Path>,
+        crate_sources: &SourceIdents,
+        include_prefix: &str,
+        cpp_format_style: &CppFormatStyle,
+    ) -> Result<Self, Diagnostic> {
         let to_diagnostic = |err| Diagnostic::new(rust_file_path.as_ref().to_owned(), err);
 
         let rust_file_path = rust_file_path.as_ref();
@@ -72,6 +80,7 @@ impl GeneratedCpp {
         // for now this uses the module ident
         let mut file_ident: String = "".to_owned();
         let mut tokens = proc_macro2::TokenStream::new();
+        let mut warnings = vec![];
 
         // Add any attributes in the file into the tokenstream
         for attr in &file.attrs {
@@ -79,7 +88,7 @@ impl GeneratedCpp {
         }
 
         // Loop through the items looking for any CXX or CXX-Qt blocks
-        for item in &file.items {
+        for item in file.items {
             match item {
                 CxxQtItem::Cxx(m) => {
                     // TODO: later we will allow for multiple CXX or CXX-Qt blocks in one file
@@ -102,15 +111,23 @@ impl GeneratedCpp {
                             rust_file_path.display());
                     }
 
-                    let parser = Parser::from(m.clone())
+                    let mut parser = Parser::from(m)
                         .map_err(GeneratedError::from)
                         .map_err(to_diagnostic)?;
+                    parser.include_prefix = include_prefix.to_owned();
+                    // Items may have been merged into the bridge from other files
+                    for path in &parser.extension_files {
+                        println!("cargo:rerun-if-changed={}", path.display());
+                    }
+                    warnings =
+                        format_warnings(rust_file_path, generate_warnings(&parser, crate_sources));
+
                     let generated_cpp = GeneratedCppBlocks::from(&parser)
                         .map_err(GeneratedError::from)
                         .map_err(to_diagnostic)?;
                     // TODO: we'll have to extend the C++ data here rather than overwriting
                     // assuming we share the same file
-                    cxx_qt = Some(write_cpp(&generated_cpp));
+                    cxx_qt = Some(write_cpp_with_style(&generated_cpp, cpp_format_style));
 
                     let generated_rust = GeneratedRustBlocks::from(&parser)
                         .map_err(GeneratedError::from)
@@ -137,6 +154,7 @@ impl GeneratedCpp {
             cxx_qt,
             cxx,
             file_ident,
+            warnings,
         })
     }
 
@@ -164,15 +182,12 @@ impl GeneratedCpp {
                 header_directory.display(),
                 self.file_ident
             ));
-            let mut header =
-                File::create(&header_path).expect("Could not create cxx-qt header file");
             let header_generated = match cxx_qt_generated {
                 CppFragment::Pair { header, source: _ } => header,
                 CppFragment::Header(header) => header,
                 CppFragment::Source(_) => panic!("Unexpected call for source fragment."),
             };
-            header
-                .write_all(header_generated.as_bytes())
+            write_if_changed(&header_path, header_generated.as_bytes())
                 .expect("Could not write cxx-qt header file");
             cpp_file_paths.qobject_header = Some(header_path);
 
@@ -181,13 +196,12 @@ impl GeneratedCpp {
                 cpp_directory.display(),
                 self.file_ident
             ));
-            let mut cpp = File::create(&cpp_path).expect("Could not create cxx-qt source file");
             let source_generated = match cxx_qt_generated {
                 CppFragment::Pair { header: _, source } => source,
                 CppFragment::Header(_) => panic!("Unexpected call for header fragment."),
                 CppFragment::Source(source) => source,
             };
-            cpp.write_all(source_generated.as_bytes())
+            write_if_changed(&cpp_path, source_generated.as_bytes())
                 .expect("Could not write cxx-qt source file");
             cpp_file_paths.qobject = Some(cpp_path);
         }
@@ -197,18 +211,14 @@ impl GeneratedCpp {
             header_directory.display(),
             self.file_ident
         ));
-        let mut header = File::create(header_path).expect("Could not create cxx header file");
-        header
-            .write_all(&self.cxx.header)
-            .expect("Could not write cxx header file");
+        write_if_changed(&header_path, &self.cxx.header).expect("Could not write cxx header file");
 
         let cpp_path = PathBuf::from(format!(
             "{}/{}.cxx.cpp",
             cpp_directory.display(),
             self.file_ident
         ));
-        let mut cpp = File::create(&cpp_path).expect("Could not create cxx source file");
-        cpp.write_all(&self.cxx.implementation)
+        write_if_changed(&cpp_path, &self.cxx.implementation)
             .expect("Could not write cxx source file");
         cpp_file_paths.plain_cpp = cpp_path;
 
@@ -216,30 +226,161 @@ impl GeneratedCpp {
     }
 }
 
+/// Write the contents to the file unless it already has the same contents
+///
+/// This keeps the modification time of unchanged outputs, so that tools which compare
+/// timestamps do not consider them as changed in incremental builds.
+fn write_if_changed(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    if std::fs::read(path).is_ok_and(|existing| existing == contents) {
+        return Ok(());
+    }
+
+    std::fs::write(path, contents)
+}
+
+/// Stop the build script with an error for a Qt installation which could not be used
+///
+/// This is shown by cargo as the error of the build script, rather than a panic with a backtrace.
+fn exit_with_qt_error(err: qt_build_utils::QtBuildError) -> ! {
+    eprintln!("error: Could not find a usable Qt installation: {err}");
+    std::process::exit(1)
+}
+
+/// Format any warnings for a bridge as cargo warnings
+///
+/// The spans of the warnings cannot be sent between threads, so they are formatted in the thread
+/// which generated the bridge.
+fn format_warnings(rust_file_path: &Path, warnings: Vec<GeneratedWarning>) -> Vec<String> {
+    warnings
+        .into_iter()
+        .map(|warning| {
+            let start = warning.span.start();
+            format!(
+                "cargo:warning={}:{}:{}: {}",
+                rust_file_path.display(),
+                start.line,
+                start.column + 1,
+                warning.message
+            )
+        })
+        .collect()
+}
+
+/// The directories of a crate which contain the sources of its targets,
+/// as a signal of a bridge in the library may only be emitted by its tests, benches, or examples
+const CRATE_TARGET_DIRECTORIES: [&str; 4] = ["src", "tests", "benches", "examples"];
+
+/// Read all of the Rust sources of the targets of the crate, these are used to find unused parts of bridges
+fn read_crate_target_sources(manifest_dir: &Path) -> Vec<proc_macro2::TokenStream> {
+    let mut sources = vec![];
+    for directory in CRATE_TARGET_DIRECTORIES {
+        read_crate_sources(&manifest_dir.join(directory), &mut sources);
+    }
+    sources
+}
+
+/// Read all of the Rust sources in a directory of the crate
+fn read_crate_sources(directory: &Path, sources: &mut Vec<proc_macro2::TokenStream>) {
+    let entries = match std::fs::read_dir(directory) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+
+    // Sort the entries so that the sources are always visited in the same order
+    let mut paths: Vec<_> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .collect();
+    paths.sort();
+
+    for path in paths {
+        if path.is_dir() {
+            read_crate_sources(&path, sources);
+        } else if path.extension().and_then(|extension| extension.to_str()) == Some("rs") {
+            // Files which fail to tokenize are reported by the compiler instead
+            if let Some(tokens) = std::fs::read_to_string(&path)
+                .ok()
+                .and_then(|contents| contents.parse().ok())
+            {
+                sources.push(tokens);
+            }
+        }
+    }
+}
+
 /// Generate C++ files from a given list of Rust files, returning the generated paths
 fn generate_cxxqt_cpp_files(
     rs_source: &[impl AsRef<Path>],
     header_dir: impl AsRef<Path>,
+    cpp_dir: impl AsRef<Path>,
+    include_prefix: &str,
+    cpp_format_style: &CppFormatStyle,
 ) -> Vec<GeneratedCppFilePaths> {
     let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
 
-    let mut generated_file_paths: Vec<GeneratedCppFilePaths> = Vec::with_capacity(rs_source.len());
-    for rs_path in rs_source {
-        let cpp_directory = format!("{}/cxx-qt-gen/src", env::var("OUT_DIR").unwrap());
-        let path = format!("{manifest_dir}/{}", rs_path.as_ref().display());
+    // The sources are only walked once rather than for each bridge in the crate
+    let crate_sources =
+        SourceIdents::from_sources(read_crate_target_sources(Path::new(&manifest_dir)));
+
+    let paths = rs_source
+        .iter()
+        .map(|rs_path| format!("{manifest_dir}/{}", rs_path.as_ref().display()))
+        .collect::<Vec<_>>();
+    for path in &paths {
         println!("cargo:rerun-if-changed={path}");
+    }
 
-        let generated_code = match GeneratedCpp::new(&path) {
-            Ok(v) => v,
+    // Each bridge is parsed and generated independently of the others,
+    // so crates with many bridges are split across threads
+    let threads = std::thread::available_parallelism().map_or(1, |threads| threads.get());
+    let chunk_size = paths.len().div_ceil(threads).max(1);
+    let (header_dir, cpp_dir) = (header_dir.as_ref(), cpp_dir.as_ref());
+    let crate_sources = &crate_sources;
+    let results = std::thread::scope(|scope| {
+        let handles = paths
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|path| {
+                            GeneratedCpp::new(path, crate_sources, include_prefix, cpp_format_style)
+                                .map(|mut generated_code| {
+                                    let warnings = std::mem::take(&mut generated_code.warnings);
+                                    (
+                                        warnings,
+                                        generated_code.write_to_directories(cpp_dir, header_dir),
+                                    )
+                                })
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("Could not generate cxx-qt files"))
+            .collect::<Vec<_>>()
+    });
+
+    // Warnings and errors are reported in the order of the files once all of the threads have
+    // finished, so that the output does not depend on the scheduling of the threads and the
+    // first failing bridge is reported
+    results
+        .into_iter()
+        .map(|result| match result {
+            Ok((warnings, generated_paths)) => {
+                for warning in warnings {
+                    println!("{warning}");
+                }
+                generated_paths
+            }
             Err(diagnostic) => {
                 diagnostic.report();
                 std::process::exit(1);
             }
-        };
-        generated_file_paths.push(generated_code.write_to_directories(cpp_directory, &header_dir));
-    }
-
-    generated_file_paths
+        })
+        .collect()
 }
 
 fn panic_duplicate_file_and_qml_module(
@@ -288,20 +429,45 @@ pub struct CxxQtBuilder {
     rust_sources: Vec<PathBuf>,
     qobject_headers: Vec<PathBuf>,
     qrc_files: Vec<PathBuf>,
-    qt_modules: HashSet<String>,
+    qt_modules: BTreeSet<String>,
     qml_modules: Vec<OwningQmlModule>,
     cc_builder: cc::Build,
+    qt_location: Option<QtLocation>,
+    output_dir: Option<PathBuf>,
+    include_prefix: Option<String>,
+    cpp_format_style: CppFormatStyle,
+}
+
+/// An explicit location of the Qt installation, rather than searching for qmake
+enum QtLocation {
+    InstallPrefix(PathBuf),
+    QMake(PathBuf),
+    QueryFile(PathBuf),
 }
 
 impl CxxQtBuilder {
     /// Create a new builder
     pub fn new() -> Self {
-        let mut qt_modules = HashSet::new();
+        let mut qt_modules = BTreeSet::new();
         qt_modules.insert("Core".to_owned());
+        #[cfg(feature = "qt_bluetooth")]
+        qt_modules.insert("Bluetooth".to_owned());
         #[cfg(feature = "qt_gui")]
         qt_modules.insert("Gui".to_owned());
+        #[cfg(feature = "qt_network")]
+        qt_modules.insert("Network".to_owned());
         #[cfg(feature = "qt_qml")]
         qt_modules.insert("Qml".to_owned());
+        #[cfg(feature = "qt_quick3d")]
+        qt_modules.insert("Quick3D".to_owned());
+        #[cfg(feature = "qt_sensors")]
+        qt_modules.insert("Sensors".to_owned());
+        #[cfg(feature = "qt_serialport")]
+        qt_modules.insert("SerialPort".to_owned());
+        #[cfg(feature = "qt_sql")]
+        qt_modules.insert("Sql".to_owned());
+        #[cfg(feature = "qt_websockets")]
+        qt_modules.insert("WebSockets".to_owned());
         Self {
             rust_sources: vec![],
             qobject_headers: vec![],
@@ -309,6 +475,10 @@ impl CxxQtBuilder {
             qt_modules,
             qml_modules: vec![],
             cc_builder: cc::Build::new(),
+            qt_location: None,
+            output_dir: None,
+            include_prefix: None,
+            cpp_format_style: CppFormatStyle::default(),
         }
     }
 
@@ -366,6 +536,66 @@ impl CxxQtBuilder {
         self
     }
 
+    /// Use the Qt installation at the given prefix, the directory which contains the `bin`
+    /// directory with qmake, rather than searching for qmake in the `PATH` or using the
+    /// `QMAKE` environment variable.
+    pub fn qt_install_prefix(mut self, path: impl AsRef<Path>) -> Self {
+        self.qt_location = Some(QtLocation::InstallPrefix(path.as_ref().to_owned()));
+        self
+    }
+
+    /// Use the qmake executable at the given path, rather than searching for qmake in the
+    /// `PATH` or using the `QMAKE` environment variable.
+    pub fn qmake_path(mut self, path: impl AsRef<Path>) -> Self {
+        self.qt_location = Some(QtLocation::QMake(path.as_ref().to_owned()));
+        self
+    }
+
+    /// Use pre-queried metadata about the Qt installation from a JSON file, rather than
+    /// running qmake. This allows hermetic build systems to drive the build without qmake.
+    ///
+    /// The file is a JSON object of the variables returned by `qmake -query`,
+    /// see [QtBuild::with_query_file](qt_build_utils::QtBuild::with_query_file) for the format.
+    pub fn qt_query_file(mut self, path: impl AsRef<Path>) -> Self {
+        self.qt_location = Some(QtLocation::QueryFile(path.as_ref().to_owned()));
+        self
+    }
+
+    /// Write the generated C++ headers and sources into the given directory, rather than into
+    /// the `CXXQT_EXPORT_DIR` environment variable or [cargo's OUT_DIR](https://doc.rust-lang.org/cargo/reference/environment-variables.html).
+    ///
+    /// The generated headers are written into the [include prefix](CxxQtBuilder::include_prefix)
+    /// subdirectory and the sources into a `src` directory within that, so that build systems
+    /// which require artifacts to be in known locations can find them.
+    pub fn output_dir(mut self, path: impl AsRef<Path>) -> Self {
+        self.output_dir = Some(path.as_ref().to_owned());
+        self
+    }
+
+    /// The directory prefix that the generated headers are included with, by default this is
+    /// `cxx-qt-gen` so that headers are included as `#include "cxx-qt-gen/my_object.cxxqt.h"`.
+    ///
+    /// The `cxx_qt::bridge` macro uses the same prefix for the include paths it generates.
+    pub fn include_prefix(mut self, prefix: &str) -> Self {
+        self.include_prefix = Some(prefix.to_owned());
+        self
+    }
+
+    /// The clang-format style that the generated C++ code is formatted with, so that it conforms to the style checks of the project.
+    ///
+    /// By default the `.clang-format` file in the crate directory or its parents is used.
+    /// A relative [CppFormatStyle::FilePath] is treated as relative to the path of your crate's Cargo.toml file.
+    pub fn cpp_format_style(mut self, mut style: CppFormatStyle) -> Self {
+        if let CppFormatStyle::FilePath(path) = &mut style {
+            if path.is_relative() {
+                *path = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join(&path);
+            }
+            println!("cargo:rerun-if-changed={}", path.display());
+        }
+        self.cpp_format_style = style;
+        self
+    }
+
     /// Register a QML module at build time. The `rust_files` of the [QmlModule] struct
     /// should contain `#[cxx_qt::bridge]` modules with QObject types annotated with `#[qml_element]`.
     ///
@@ -377,6 +607,14 @@ impl CxxQtBuilder {
     /// When using Qt 6, this will [run qmlcachegen](https://doc.qt.io/qt-6/qtqml-qtquick-compiler-tech.html)
     /// to compile the specified `.qml` files ahead-of-time.
     ///
+    /// The module is registered automatically at startup via static initializers. As some linkers
+    /// or LTO can discard these, an `extern "C"` function named after the URI with dots replaced by
+    /// underscores and a `_register_all_types` suffix is also generated, for example
+    /// `com_kdab_cxx_qt_demo_register_all_types`. Calling this function before loading any QML
+    /// explicitly registers the QML types and resources of the module; further calls, including from
+    /// other threads, have no effect. From Rust the function can be called with
+    /// `cxx_qt::register_qml_module!(com_kdab_cxx_qt_demo)`.
+    ///
     /// ```no_run
     /// use cxx_qt_build::{CxxQtBuilder, QmlModule};
     ///
@@ -445,14 +683,54 @@ impl CxxQtBuilder {
 
         // The include directory needs to be namespaced by crate name when exporting for a C++ build system,
         // but for using cargo build without a C++ build system, OUT_DIR is already namespaced by crate name.
-        let header_root = match env::var("CXXQT_EXPORT_DIR") {
-            Ok(export_dir) => format!("{export_dir}/{}", env::var("CARGO_PKG_NAME").unwrap()),
-            Err(_) => env::var("OUT_DIR").unwrap(),
+        let header_root = match (&self.output_dir, env::var("CXXQT_EXPORT_DIR")) {
+            (Some(output_dir), _) => output_dir.display().to_string(),
+            (None, Ok(export_dir)) => {
+                format!("{export_dir}/{}", env::var("CARGO_PKG_NAME").unwrap())
+            }
+            (None, Err(_)) => env::var("OUT_DIR").unwrap(),
+        };
+        let source_root = match &self.output_dir {
+            Some(output_dir) => output_dir.display().to_string(),
+            None => env::var("OUT_DIR").unwrap(),
         };
-        let generated_header_dir = format!("{header_root}/cxx-qt-gen");
 
-        let mut qtbuild = qt_build_utils::QtBuild::new(self.qt_modules.into_iter().collect())
-            .expect("Could not find Qt installation");
+        // The macro is told the include prefix so that its include paths match the generated headers
+        println!("cargo:rerun-if-env-changed=CXXQT_INCLUDE_PREFIX");
+        let include_prefix = self
+            .include_prefix
+            .clone()
+            .or_else(|| env::var("CXXQT_INCLUDE_PREFIX").ok())
+            .filter(|prefix| !prefix.is_empty())
+            .unwrap_or_else(|| "cxx-qt-gen".to_owned());
+        println!("cargo:rustc-env=CXXQT_INCLUDE_PREFIX={include_prefix}");
+        let generated_header_dir = format!("{header_root}/{include_prefix}");
+        let generated_source_dir = format!("{source_root}/{include_prefix}/src");
+
+        // Show the message of the error, which includes how to resolve it, rather than its Debug output
+        let find_qt = |qt_modules: Vec<String>| {
+            match &self.qt_location {
+                Some(QtLocation::InstallPrefix(path)) => {
+                    qt_build_utils::QtBuild::with_install_prefix(path, qt_modules)
+                }
+                Some(QtLocation::QMake(path)) => {
+                    qt_build_utils::QtBuild::with_qmake(path, qt_modules)
+                }
+                Some(QtLocation::QueryFile(path)) => {
+                    qt_build_utils::QtBuild::with_query_file(path, qt_modules)
+                }
+                None => qt_build_utils::QtBuild::new(qt_modules),
+            }
+            .unwrap_or_else(|err| exit_with_qt_error(err))
+        };
+        #[allow(unused_mut)]
+        let mut qtbuild = find_qt(self.qt_modules.iter().cloned().collect());
+        // QStateMachine moved from Qt Core to its own module in Qt 6,
+        // which is only known once the version of the Qt installation has been found
+        #[cfg(feature = "qt_statemachine")]
+        if qtbuild.version().major >= 6 && self.qt_modules.insert("StateMachine".to_owned()) {
+            qtbuild = find_qt(self.qt_modules.iter().cloned().collect());
+        }
         qtbuild.cargo_link_libraries(&mut self.cc_builder);
 
         // Write cxx-qt-gen, cxx-qt-lib and cxx headers
@@ -510,12 +788,42 @@ impl CxxQtBuilder {
             builder.flag_if_supported("-std=c++17");
             // MinGW requires big-obj otherwise debug builds fail
             builder.flag_if_supported("-Wa,-mbig-obj");
+            // Enable Qt Bluetooth in C++ if the feature is enabled
+            #[cfg(feature = "qt_bluetooth")]
+            builder.define("CXX_QT_BLUETOOTH_FEATURE", None);
             // Enable Qt Gui in C++ if the feature is enabled
             #[cfg(feature = "qt_gui")]
             builder.define("CXX_QT_GUI_FEATURE", None);
             // Enable Qt Gui in C++ if the feature is enabled
             #[cfg(feature = "qt_qml")]
             builder.define("CXX_QT_QML_FEATURE", None);
+            // Enable QtKeychain in C++ if the feature is enabled
+            #[cfg(feature = "qt_keychain")]
+            builder.define("CXX_QT_KEYCHAIN_FEATURE", None);
+            // Enable Qt Network in C++ if the feature is enabled
+            #[cfg(feature = "qt_network")]
+            builder.define("CXX_QT_NETWORK_FEATURE", None);
+            // Enable Qt Quick3D in C++ if the feature is enabled
+            #[cfg(feature = "qt_quick3d")]
+            builder.define("CXX_QT_QUICK3D_FEATURE", None);
+            // Enable Qt Sensors in C++ if the feature is enabled
+            #[cfg(feature = "qt_sensors")]
+            builder.define("CXX_QT_SENSORS_FEATURE", None);
+            // Enable Qt SerialPort in C++ if the feature is enabled
+            #[cfg(feature = "qt_serialport")]
+            builder.define("CXX_QT_SERIALPORT_FEATURE", None);
+            // Enable Qt SQL in C++ if the feature is enabled
+            #[cfg(feature = "qt_sql")]
+            builder.define("CXX_QT_SQL_FEATURE", None);
+            // Enable the TLS types of Qt Network in C++ if the feature is enabled
+            #[cfg(feature = "qt_ssl")]
+            builder.define("CXX_QT_SSL_FEATURE", None);
+            // Enable Qt StateMachine in C++ if the feature is enabled
+            #[cfg(feature = "qt_statemachine")]
+            builder.define("CXX_QT_STATEMACHINE_FEATURE", None);
+            // Enable Qt WebSockets in C++ if the feature is enabled
+            #[cfg(feature = "qt_websockets")]
+            builder.define("CXX_QT_WEBSOCKETS_FEATURE", None);
             for include_dir in qtbuild.include_paths() {
                 builder.include(&include_dir);
             }
@@ -524,7 +832,13 @@ impl CxxQtBuilder {
         }
 
         // Generate files
-        for files in generate_cxxqt_cpp_files(&self.rust_sources, &generated_header_dir) {
+        for files in generate_cxxqt_cpp_files(
+            &self.rust_sources,
+            &generated_header_dir,
+            &generated_source_dir,
+            &include_prefix,
+            &self.cpp_format_style,
+        ) {
             self.cc_builder.file(files.plain_cpp);
             if let (Some(qobject), Some(qobject_header)) = (files.qobject, files.qobject_header) {
                 self.cc_builder.file(&qobject);
@@ -547,7 +861,13 @@ impl CxxQtBuilder {
         for qml_module in self.qml_modules {
             let mut qml_metatypes_json = Vec::new();
 
-            for files in generate_cxxqt_cpp_files(&qml_module.rust_files, &generated_header_dir) {
+            for files in generate_cxxqt_cpp_files(
+                &qml_module.rust_files,
+                &generated_header_dir,
+                &generated_source_dir,
+                &include_prefix,
+                &self.cpp_format_style,
+            ) {
                 self.cc_builder.file(files.plain_cpp);
                 if let (Some(qobject), Some(qobject_header)) = (files.qobject, files.qobject_header)
                 {
@@ -635,3 +955,188 @@ impl CxxQtBuilder {
         self.cc_builder.compile(lib_name);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_if_changed() {
+        let directory = env::temp_dir().join(format!(
+            "cxx-qt-build-test-write-if-changed-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("generated.cxxqt.h");
+
+        // A new file is written
+        write_if_changed(&path, b"#pragma once").unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), b"#pragma once");
+
+        // Move the modification time into the past, so that any write would change it
+        let modified = std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(60);
+        let set_modified = |modified| {
+            File::options()
+                .write(true)
+                .open(&path)
+                .unwrap()
+                .set_modified(modified)
+                .unwrap()
+        };
+        let read_modified = || std::fs::metadata(&path).unwrap().modified().unwrap();
+        set_modified(modified);
+
+        // The same contents keep the modification time
+        write_if_changed(&path, b"#pragma once").unwrap();
+        let unchanged_modified = read_modified();
+
+        // Different contents are written
+        write_if_changed(&path, b"#pragma once\n").unwrap();
+        let changed_contents = std::fs::read(&path).unwrap();
+        let changed_modified = read_modified();
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(unchanged_modified, modified);
+        assert_eq!(changed_contents, b"#pragma once\n");
+        assert_ne!(changed_modified, modified);
+    }
+
+    #[test]
+    fn test_generated_files_are_reproducible() {
+        let bridge = r#"
+            #[cxx_qt::bridge(namespace = "cxx_qt::reproducible")]
+            mod ffi {
+                unsafe extern "C++" {
+                    include!("cxx-qt-lib/qstring.h");
+                    type QString = cxx_qt_lib::QString;
+                }
+
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(QString, name)]
+                    type SecondObject = super::SecondObjectRust;
+
+                    #[qobject]
+                    #[qproperty(i32, number)]
+                    type FirstObject = super::FirstObjectRust;
+
+                    #[qsignal]
+                    fn ready(self: Pin<&mut FirstObject>, value: i32);
+                }
+            }
+            "#;
+        let sources = [
+            (
+                "tests/emit.rs",
+                "fn emit(o: Pin<&mut FirstObject>) { o.ready(1); }",
+            ),
+            ("tests/name.rs", "fn name(o: &SecondObject) { o.name(); }"),
+            ("src/other.rs", "fn number(o: &FirstObject) { o.number(); }"),
+        ];
+
+        // Generate the same crate twice, with the sources written in a different order
+        let generate = |name: &str, reverse: bool| {
+            let manifest_dir = env::temp_dir().join(format!(
+                "cxx-qt-build-test-reproducible-{name}-{}",
+                std::process::id()
+            ));
+            std::fs::create_dir_all(manifest_dir.join("src")).unwrap();
+            std::fs::create_dir_all(manifest_dir.join("tests")).unwrap();
+            std::fs::write(manifest_dir.join("src/lib.rs"), bridge).unwrap();
+            let mut ordered: Vec<_> = sources.iter().collect();
+            if reverse {
+                ordered.reverse();
+            }
+            for (path, contents) in ordered {
+                std::fs::write(manifest_dir.join(path), contents).unwrap();
+            }
+
+            let crate_sources =
+                SourceIdents::from_sources(read_crate_target_sources(&manifest_dir));
+            let Ok(generated) = GeneratedCpp::new(
+                manifest_dir.join("src/lib.rs"),
+                &crate_sources,
+                "cxx-qt-gen",
+                &CppFormatStyle::None,
+            ) else {
+                panic!("Could not generate the bridge");
+            };
+            let warnings = generated.warnings.len();
+            let paths = generated
+                .write_to_directories(manifest_dir.join("cpp"), manifest_dir.join("include"));
+            let contents: Vec<_> = [
+                Some(paths.plain_cpp),
+                paths.qobject,
+                paths.qobject_header,
+                Some(manifest_dir.join("include/ffi.cxx.h")),
+            ]
+            .into_iter()
+            .flatten()
+            .map(|path| {
+                (
+                    path.strip_prefix(&manifest_dir).unwrap().to_owned(),
+                    std::fs::read(&path).unwrap(),
+                )
+            })
+            .collect();
+            std::fs::remove_dir_all(&manifest_dir).unwrap();
+            (warnings, contents)
+        };
+
+        let (first_warnings, first) = generate("first", false);
+        let (second_warnings, second) = generate("second", true);
+        assert_eq!(first.len(), 4);
+        assert_eq!(first_warnings, second_warnings);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_signal_emitted_outside_src() {
+        let manifest_dir = env::temp_dir().join(format!(
+            "cxx-qt-build-test-crate-sources-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(manifest_dir.join("src")).unwrap();
+        std::fs::create_dir_all(manifest_dir.join("benches")).unwrap();
+
+        // The bridge of the library declares a signal which is only emitted by a bench
+        let bridge_path = manifest_dir.join("src/lib.rs");
+        std::fs::write(
+            &bridge_path,
+            r#"
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    type BenchObject = super::BenchObjectRust;
+
+                    #[qsignal]
+                    fn value_changed(self: Pin<&mut BenchObject>, value: i32);
+                }
+            }
+            "#,
+        )
+        .unwrap();
+        std::fs::write(
+            manifest_dir.join("benches/bridge.rs"),
+            "fn emit(object: Pin<&mut BenchObject>) { object.value_changed(1); }",
+        )
+        .unwrap();
+
+        let crate_sources = SourceIdents::from_sources(read_crate_target_sources(&manifest_dir));
+        let module = parse_qt_file(&bridge_path)
+            .unwrap()
+            .items
+            .into_iter()
+            .find_map(|item| match item {
+                CxxQtItem::CxxQt(module) => Some(module),
+                _others => None,
+            })
+            .unwrap();
+        let parser = Parser::from(module).unwrap();
+        let warnings = generate_warnings(&parser, &crate_sources);
+        std::fs::remove_dir_all(&manifest_dir).unwrap();
+
+        assert!(warnings.is_empty());
+    }
+}
@@ -7,9 +7,13 @@
 
 #pragma once
 
+#include <atomic>
+#include <cstddef>
 #include <memory>
 #include <mutex>
 
+#include <QtCore/QtGlobal>
+
 namespace rust::cxxqtlib1 {
 
 class CxxQtLocking
@@ -20,17 +24,29 @@ public:
   {
   }
 
-  virtual ~CxxQtLocking() = default;
+  virtual ~CxxQtLocking() { cxxQtAssertNotExecuting(); }
 
 protected:
+  // Deleting the object while Rust code is executing on it would leave the
+  // Rust code with a dangling reference, so fail with a clear error instead
+  void cxxQtAssertNotExecuting() const
+  {
+    if (m_rustCallDepth.load() > 0) {
+      qFatal("A CXX-Qt QObject was destroyed while one of its invokables, "
+             "property setters, or queued closures was executing");
+    }
+  }
+
   [[nodiscard]] ::std::lock_guard<::std::recursive_mutex> unsafeRustLock() const
   {
     return ::std::lock_guard<::std::recursive_mutex>(*m_rustObjMutex);
   }
 
   ::std::shared_ptr<::std::recursive_mutex> m_rustObjMutex;
+  // The number of calls into Rust which are currently executing
+  mutable ::std::atomic<::std::size_t> m_rustCallDepth = 0;
 
-  // Friend MaybeLockGuard so that it can use unsafeRustLock()
+  // Friend MaybeLockGuard so that it can use unsafeRustLock() and m_rustCallDepth
   template<typename T, typename D>
   friend class MaybeLockGuard;
 };
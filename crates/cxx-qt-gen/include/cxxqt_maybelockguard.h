@@ -30,11 +30,16 @@ struct MaybeLockGuard<T,
 {
   MaybeLockGuard(const CxxQtLocking& locking)
     : m_lock(locking.unsafeRustLock())
+    , m_locking(locking)
   {
+    m_locking.m_rustCallDepth++;
   }
 
+  ~MaybeLockGuard() { m_locking.m_rustCallDepth--; }
+
 private:
   ::std::lock_guard<::std::recursive_mutex> m_lock;
+  const CxxQtLocking& m_locking;
 };
 
 }
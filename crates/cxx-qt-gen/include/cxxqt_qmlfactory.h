@@ -0,0 +1,28 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+
+#include <QtQml/QQmlEngine>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+template<typename T>
+T*
+qmlFactoryNew(::std::int32_t ownership)
+{
+  // The object has no parent, so the ownership decides who destroys it
+  auto* obj = new T();
+  ::QQmlEngine::setObjectOwnership(
+    obj, static_cast<::QQmlEngine::ObjectOwnership>(ownership));
+  return obj;
+}
+
+} // namespace cxxqtlib1
+} // namespace rust
@@ -16,6 +16,8 @@
 
 #include "rust/cxx.h"
 
+#include <cxx-qt-common/cxxqt_maybelockguard.h>
+
 namespace rust {
 namespace cxxqtlib1 {
 
@@ -61,17 +63,15 @@ public:
 
     // Construct the lambda
     auto obj = m_obj;
-    auto rustObjMutex = m_rustObjMutex;
     auto lambda = [obj = ::std::move(obj),
-                   rustObjMutex = ::std::move(rustObjMutex),
                    func = ::std::move(func),
                    arg = ::std::move(arg)]() mutable {
       // Ensure that we can read the pointer and it's not being written to
       const auto guard = ::std::shared_lock(obj->mutex);
       if (obj->ptr) {
-        // Ensure that the rustObj is locked
-        const ::std::lock_guard<::std::recursive_mutex> guardRustObj(
-          *rustObjMutex);
+        // Ensure that the rustObj is locked and that the object is not
+        // destroyed while the function is executing
+        const MaybeLockGuard<T> guardRustObj(*obj->ptr);
         func(*obj->ptr, ::std::move(arg));
       } else {
         qWarning()
@@ -87,8 +87,17 @@ public:
     }
   }
 
+  bool isDestroyed() const
+  {
+    // Ensure that we can read the pointer and it's not being written to
+    const auto guard = ::std::shared_lock(m_obj->mutex);
+    return !m_obj->ptr;
+  }
+
 private:
   ::std::shared_ptr<CxxQtGuardedPointer<T>> m_obj;
+  // The rustObj is locked through the object itself, but the mutex is kept
+  // so that the layout stays two std::shared_ptr as the Rust side expects
   ::std::shared_ptr<::std::recursive_mutex> m_rustObjMutex;
 };
 
@@ -106,6 +115,13 @@ cxxQtThreadDrop(CxxQtThread<T>& cxxQtThread)
   cxxQtThread.~CxxQtThread<T>();
 }
 
+template<typename T>
+bool
+cxxQtThreadIsDestroyed(const CxxQtThread<T>& cxxQtThread)
+{
+  return cxxQtThread.isDestroyed();
+}
+
 template<typename A, typename T>
 void
 cxxQtThreadQueue(const CxxQtThread<T>& cxxQtThread,
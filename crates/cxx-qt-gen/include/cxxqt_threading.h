@@ -26,6 +26,10 @@ public:
 
   virtual ~CxxQtThreading()
   {
+    // A queued closure holds the shared lock while it is executing
+    // so check before taking the unique lock, which would deadlock
+    this->cxxQtAssertNotExecuting();
+
     const auto guard = ::std::unique_lock(m_cxxQtThreadObj->mutex);
     m_cxxQtThreadObj->ptr = nullptr;
   }
@@ -9,6 +9,9 @@
 
 #include <memory>
 #include <mutex>
+#include <type_traits>
+
+#include <QtCore/QObject>
 
 #include "rust/cxx.h"
 
@@ -32,4 +35,29 @@ protected:
   ::rust::Box<T> m_rustObj;
 };
 
+// Some base classes take a more derived parent than QObject in their
+// constructor, such as QQuickItem, so these are constructed without a parent
+// and the parent is set once the base class has been constructed
+template<typename Base>
+auto
+constructorParent(QObject* parent)
+{
+  if constexpr (::std::is_constructible_v<Base, QObject*>) {
+    return parent;
+  } else {
+    return nullptr;
+  }
+}
+
+template<typename Base>
+void
+setConstructorParent(Base& object, QObject* parent)
+{
+  if constexpr (!::std::is_constructible_v<Base, QObject*>) {
+    if (parent != nullptr) {
+      object.QObject::setParent(parent);
+    }
+  }
+}
+
 }
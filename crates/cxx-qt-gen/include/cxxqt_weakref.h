@@ -0,0 +1,60 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <type_traits>
+
+#include <QtCore/QPointer>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+template<typename T>
+QPointer<T>
+qobjectWeakRefNew(const T& obj)
+{
+  // The QPointer does not modify the object, it only tracks its destruction
+  return QPointer<T>(const_cast<T*>(&obj));
+}
+
+template<typename T>
+QPointer<T>
+qobjectWeakRefClone(const QPointer<T>& weakRef)
+{
+  return QPointer<T>(weakRef);
+}
+
+template<typename T>
+void
+qobjectWeakRefDrop(QPointer<T>& weakRef)
+{
+  weakRef.~QPointer<T>();
+}
+
+template<typename T>
+T*
+qobjectWeakRefGet(const QPointer<T>& weakRef)
+{
+  // This is nullptr once the object has been destroyed
+  return weakRef.data();
+}
+
+} // namespace cxxqtlib1
+} // namespace rust
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<typename T>
+struct IsRelocatable<QPointer<T>> : ::std::true_type
+{
+};
+
+} // namespace rust
@@ -0,0 +1,155 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{
+        cpp::{fragment::CppFragment, qobject::GeneratedCppQObjectBlocks},
+        naming::{property::QPropertyName, qobject::QObjectName},
+    },
+    parser::qobject::ParsedQObject,
+};
+use indoc::formatdoc;
+use syn::Result;
+
+pub fn generate(
+    qobject: &ParsedQObject,
+    qobject_idents: &QObjectName,
+) -> Result<GeneratedCppQObjectBlocks> {
+    let mut result = GeneratedCppQObjectBlocks::default();
+
+    let qobject_ident = qobject_idents.cpp_class.cpp.to_string();
+    for property in qobject
+        .properties
+        .iter()
+        .filter(|property| property.is_bindable())
+    {
+        let idents = QPropertyName::from(property);
+        let bind_ident = format!("{}BindTo", idents.name.cpp);
+        let source = formatdoc! {
+            r#"
+            ::QMetaObject::Connection
+            {qobject_ident}::{bind_ident}(::QObject& target, ::rust::Str property) const
+            {{
+                const auto targetProperty = ::rust::cxxqtlib1::qobjectFindWritableProperty(target, property);
+                const auto update = [this, &target, targetProperty]() {{
+                    targetProperty.write(&target, ::QVariant::fromValue({ident_getter}()));
+                }};
+                update();
+                return ::QObject::connect(this, &{qobject_ident}::{ident_notify}, &target, update);
+            }}
+            "#,
+            ident_getter = idents.getter.cpp,
+            ident_notify = idents.notify.cpp,
+        };
+        result.methods.push(CppFragment::Pair {
+            header: format!(
+                "::QMetaObject::Connection {bind_ident}(::QObject& target, ::rust::Str property) const;"
+            ),
+            // Binding a deprecated property is not a use by the consumer, so don't warn
+            source: if property.deprecated.is_some() {
+                format!("QT_WARNING_PUSH\nQT_WARNING_DISABLE_DEPRECATED\n{source}QT_WARNING_POP\n")
+            } else {
+                source
+            },
+        });
+    }
+
+    result
+        .includes
+        .insert("#include <cxx-qt-lib/qobjectconnect.h>".to_owned());
+    result
+        .includes
+        .insert("#include <QtCore/QVariant>".to_owned());
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::parser::Parser;
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_generate_cpp_connect() {
+        let parser = Parser::from(parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(i32, number)]
+                    #[qproperty(i32, version, constant)]
+                    #[qproperty(QString, name, qdeprecated("use number"))]
+                    type MyObject = super::MyObjectRust;
+                }
+
+                impl cxx_qt_lib::Connect for MyObject {}
+            }
+        })
+        .unwrap();
+        let qobject = parser.cxx_qt_data.qobjects.values().next().unwrap();
+
+        let generated = generate(qobject, &QObjectName::from(qobject)).unwrap();
+
+        // The constant property cannot be bound
+        assert_eq!(generated.methods.len(), 2);
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[0] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(
+            header,
+            "::QMetaObject::Connection numberBindTo(::QObject& target, ::rust::Str property) const;"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            ::QMetaObject::Connection
+            MyObject::numberBindTo(::QObject& target, ::rust::Str property) const
+            {
+                const auto targetProperty = ::rust::cxxqtlib1::qobjectFindWritableProperty(target, property);
+                const auto update = [this, &target, targetProperty]() {
+                    targetProperty.write(&target, ::QVariant::fromValue(getNumber()));
+                };
+                update();
+                return ::QObject::connect(this, &MyObject::numberChanged, &target, update);
+            }
+            "#}
+        );
+
+        let source = if let CppFragment::Pair { source, .. } = &generated.methods[1] {
+            source
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            QT_WARNING_PUSH
+            QT_WARNING_DISABLE_DEPRECATED
+            ::QMetaObject::Connection
+            MyObject::nameBindTo(::QObject& target, ::rust::Str property) const
+            {
+                const auto targetProperty = ::rust::cxxqtlib1::qobjectFindWritableProperty(target, property);
+                const auto update = [this, &target, targetProperty]() {
+                    targetProperty.write(&target, ::QVariant::fromValue(getName()));
+                };
+                update();
+                return ::QObject::connect(this, &MyObject::nameChanged, &target, update);
+            }
+            QT_WARNING_POP
+            "#}
+        );
+
+        assert_eq!(generated.includes.len(), 2);
+        assert!(generated
+            .includes
+            .contains("#include <cxx-qt-lib/qobjectconnect.h>"));
+    }
+}
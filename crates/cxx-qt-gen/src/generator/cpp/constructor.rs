@@ -11,30 +11,55 @@ use crate::{
 };
 
 use indoc::formatdoc;
-use syn::{Result, Type};
+use syn::{Error, Result, Type};
 
 fn default_constructor(
     qobject: &GeneratedCppQObject,
     base_class: String,
     initializers: String,
+    statements: String,
 ) -> GeneratedCppQObjectBlocks {
+    // A custom base class may not take a QObject as the parent, such as QQuickItem
+    let source = if base_class == "QObject" {
+        let body = if statements.is_empty() {
+            "{ }".to_owned()
+        } else {
+            format!("{{{statements}\n}}")
+        };
+        formatdoc!(
+            r#"
+            {class_name}::{class_name}(QObject* parent)
+              : {base_class}(parent)
+              , ::rust::cxxqtlib1::CxxQtType<{rust_obj}>(::{namespace_internals}::createRs()){initializers}
+            {body}
+            "#,
+            class_name = qobject.ident,
+            namespace_internals = qobject.namespace_internals,
+            rust_obj = qobject.rust_ident,
+        )
+    } else {
+        formatdoc!(
+            r#"
+            {class_name}::{class_name}(QObject* parent)
+              : {base_class}(::rust::cxxqtlib1::constructorParent<{base_class}>(parent))
+              , ::rust::cxxqtlib1::CxxQtType<{rust_obj}>(::{namespace_internals}::createRs()){initializers}
+            {{
+              ::rust::cxxqtlib1::setConstructorParent<{base_class}>(*this, parent);{statements}
+            }}
+            "#,
+            class_name = qobject.ident,
+            namespace_internals = qobject.namespace_internals,
+            rust_obj = qobject.rust_ident,
+        )
+    };
+
     GeneratedCppQObjectBlocks {
         methods: vec![CppFragment::Pair {
             header: format!(
                 "explicit {class_name}(QObject* parent = nullptr);",
                 class_name = qobject.ident
             ),
-            source: formatdoc!(
-                r#"
-            {class_name}::{class_name}(QObject* parent)
-              : {base_class}(parent)
-              , ::rust::cxxqtlib1::CxxQtType<{rust_obj}>(::{namespace_internals}::createRs()){initializers}
-            {{ }}
-            "#,
-                class_name = qobject.ident,
-                namespace_internals = qobject.namespace_internals,
-                rust_obj = qobject.rust_ident,
-            ),
+            source,
         }],
         ..Default::default()
     }
@@ -62,6 +87,7 @@ pub fn generate(
     constructors: &[Constructor],
     base_class: String,
     class_initializers: &[String],
+    constructor_statements: &[String],
     cxx_mappings: &ParsedCxxMappings,
 ) -> Result<GeneratedCppQObjectBlocks> {
     let initializers = class_initializers
@@ -69,9 +95,20 @@ pub fn generate(
         .map(|initializer| format!("\n  , {initializer}"))
         .collect::<Vec<_>>()
         .join("");
+    // Statements which are run at the end of the body of the constructor, after the initialize function
+    let statements = constructor_statements
+        .iter()
+        .map(|statement| format!("\n  {statement}"))
+        .collect::<Vec<_>>()
+        .join("");
 
     if constructors.is_empty() {
-        return Ok(default_constructor(qobject, base_class, initializers));
+        return Ok(default_constructor(
+            qobject,
+            base_class,
+            initializers,
+            statements,
+        ));
     }
 
     let mut generated = GeneratedCppQObjectBlocks::default();
@@ -79,8 +116,22 @@ pub fn generate(
     let class_name = qobject.ident.as_str();
     let rust_obj = qobject.rust_ident.as_str();
     let namespace_internals = &qobject.namespace_internals;
+    let mut argument_lists = vec![];
     for (index, constructor) in constructors.iter().enumerate() {
         let argument_list = expand_arguments(&constructor.arguments, cxx_mappings)?;
+        // Each constructor is an overload in C++, so they must have different argument types
+        let argument_types = constructor
+            .arguments
+            .iter()
+            .map(|ty| syn_type_to_cpp_type(ty, cxx_mappings))
+            .collect::<Result<Vec<_>>>()?;
+        if argument_lists.contains(&argument_types) {
+            return Err(Error::new_spanned(
+                &constructor.imp.self_ty,
+                "A cxx_qt::Constructor with the same C++ argument types has already been declared for this QObject",
+            ));
+        }
+        argument_lists.push(argument_types);
         let constructor_argument_names = argument_names(&constructor.arguments);
 
         generated.methods.push(CppFragment::Pair {
@@ -121,7 +172,7 @@ pub fn generate(
                   : {base_class}({base_args})
                   , ::rust::cxxqtlib1::CxxQtType<{rust_obj}>(::{namespace_internals}::newRs{index}(::std::move(args.new_))){initializers}
                 {{
-                  ::{namespace_internals}::initialize{index}(*this, ::std::move(args.initialize));
+                  ::{namespace_internals}::initialize{index}(*this, ::std::move(args.initialize));{statements}
                 }}
                 "#,
             },
@@ -171,6 +222,7 @@ mod tests {
             &[],
             "BaseClass".to_owned(),
             &["member1(1)".to_string(), "member2{ 2 }".to_string()],
+            &[],
             &ParsedCxxMappings::default(),
         )
         .unwrap();
@@ -184,11 +236,13 @@ mod tests {
                 source: formatdoc!(
                     "
                     MyObject::MyObject(QObject* parent)
-                      : BaseClass(parent)
+                      : BaseClass(::rust::cxxqtlib1::constructorParent<BaseClass>(parent))
                       , ::rust::cxxqtlib1::CxxQtType<MyObjectRust>(::rust::createRs())
                       , member1(1)
                       , member2{{ 2 }}
-                    {{ }}
+                    {{
+                      ::rust::cxxqtlib1::setConstructorParent<BaseClass>(*this, parent);
+                    }}
                     "
                 ),
             }]
@@ -201,6 +255,40 @@ mod tests {
             &[],
             "BaseClass".to_owned(),
             &[],
+            &[],
+            &ParsedCxxMappings::default(),
+        )
+        .unwrap();
+
+        assert_empty_blocks(&blocks);
+        assert!(blocks.private_methods.is_empty());
+        assert_eq!(
+            blocks.methods,
+            vec![CppFragment::Pair {
+                header: "explicit MyObject(QObject* parent = nullptr);".to_string(),
+                source: formatdoc!(
+                    "
+                    MyObject::MyObject(QObject* parent)
+                      : BaseClass(::rust::cxxqtlib1::constructorParent<BaseClass>(parent))
+                      , ::rust::cxxqtlib1::CxxQtType<MyObjectRust>(::rust::createRs())
+                    {{
+                      ::rust::cxxqtlib1::setConstructorParent<BaseClass>(*this, parent);
+                    }}
+                    "
+                ),
+            }]
+        );
+    }
+
+    #[test]
+    fn default_constructor_qobject_base() {
+        // A QObject base class takes the parent directly
+        let blocks = generate(
+            &qobject_for_testing(),
+            &[],
+            "QObject".to_owned(),
+            &["member(1)".to_string()],
+            &[],
             &ParsedCxxMappings::default(),
         )
         .unwrap();
@@ -214,8 +302,9 @@ mod tests {
                 source: formatdoc!(
                     "
                     MyObject::MyObject(QObject* parent)
-                      : BaseClass(parent)
+                      : QObject(parent)
                       , ::rust::cxxqtlib1::CxxQtType<MyObjectRust>(::rust::createRs())
+                      , member(1)
                     {{ }}
                     "
                 ),
@@ -223,6 +312,131 @@ mod tests {
         );
     }
 
+    #[test]
+    fn default_constructor_quick_item_base() {
+        // The constructor of QQuickItem takes a QQuickItem parent, so the parent is set afterwards
+        let blocks = generate(
+            &qobject_for_testing(),
+            &[],
+            "QQuickItem".to_owned(),
+            &[],
+            &[],
+            &ParsedCxxMappings::default(),
+        )
+        .unwrap();
+
+        assert_empty_blocks(&blocks);
+        assert!(blocks.private_methods.is_empty());
+        assert_eq!(
+            blocks.methods,
+            vec![CppFragment::Pair {
+                header: "explicit MyObject(QObject* parent = nullptr);".to_string(),
+                source: formatdoc!(
+                    "
+                    MyObject::MyObject(QObject* parent)
+                      : QQuickItem(::rust::cxxqtlib1::constructorParent<QQuickItem>(parent))
+                      , ::rust::cxxqtlib1::CxxQtType<MyObjectRust>(::rust::createRs())
+                    {{
+                      ::rust::cxxqtlib1::setConstructorParent<QQuickItem>(*this, parent);
+                    }}
+                    "
+                ),
+            }]
+        );
+    }
+
+    #[test]
+    fn default_constructor_with_statements() {
+        let blocks = generate(
+            &qobject_for_testing(),
+            &[],
+            "QObject".to_owned(),
+            &[],
+            &["statement1();".to_string(), "statement2();".to_string()],
+            &ParsedCxxMappings::default(),
+        )
+        .unwrap();
+
+        assert_empty_blocks(&blocks);
+        assert!(blocks.private_methods.is_empty());
+        assert_eq!(
+            blocks.methods,
+            vec![CppFragment::Pair {
+                header: "explicit MyObject(QObject* parent = nullptr);".to_string(),
+                source: formatdoc!(
+                    "
+                    MyObject::MyObject(QObject* parent)
+                      : QObject(parent)
+                      , ::rust::cxxqtlib1::CxxQtType<MyObjectRust>(::rust::createRs())
+                    {{
+                      statement1();
+                      statement2();
+                    }}
+                    "
+                ),
+            }]
+        );
+
+        let blocks = generate(
+            &qobject_for_testing(),
+            &[],
+            "BaseClass".to_owned(),
+            &[],
+            &["statement();".to_string()],
+            &ParsedCxxMappings::default(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            blocks.methods,
+            vec![CppFragment::Pair {
+                header: "explicit MyObject(QObject* parent = nullptr);".to_string(),
+                source: formatdoc!(
+                    "
+                    MyObject::MyObject(QObject* parent)
+                      : BaseClass(::rust::cxxqtlib1::constructorParent<BaseClass>(parent))
+                      , ::rust::cxxqtlib1::CxxQtType<MyObjectRust>(::rust::createRs())
+                    {{
+                      ::rust::cxxqtlib1::setConstructorParent<BaseClass>(*this, parent);
+                      statement();
+                    }}
+                    "
+                ),
+            }]
+        );
+    }
+
+    #[test]
+    fn constructor_with_statements() {
+        let blocks = generate(
+            &qobject_for_testing(),
+            &[mock_constructor()],
+            "BaseClass".to_owned(),
+            &[],
+            &["statement();".to_string()],
+            &ParsedCxxMappings::default(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            blocks.private_methods,
+            vec![CppFragment::Pair {
+                header: "explicit MyObject(::rust::CxxQtConstructorArguments0&& args);".to_string(),
+                source: formatdoc!(
+                    "
+                    MyObject::MyObject(::rust::CxxQtConstructorArguments0&& args)
+                      : BaseClass()
+                      , ::rust::cxxqtlib1::CxxQtType<MyObjectRust>(::rust::newRs0(::std::move(args.new_)))
+                    {{
+                      ::rust::initialize0(*this, ::std::move(args.initialize));
+                      statement();
+                    }}
+                    "
+                ),
+            }]
+        );
+    }
+
     #[test]
     fn constructor_without_base_arguments() {
         let blocks = generate(
@@ -233,6 +447,7 @@ mod tests {
             }],
             "BaseClass".to_owned(),
             &[],
+            &[],
             &ParsedCxxMappings::default(),
         )
         .unwrap();
@@ -283,6 +498,7 @@ mod tests {
             }],
             "BaseClass".to_owned(),
             &["initializer".to_string()],
+            &[],
             &ParsedCxxMappings::default(),
         )
         .unwrap();
@@ -320,6 +536,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn duplicate_constructors() {
+        let result = generate(
+            &qobject_for_testing(),
+            &[
+                Constructor {
+                    arguments: vec![parse_quote! { &'a QString }],
+                    ..mock_constructor()
+                },
+                Constructor {
+                    arguments: vec![parse_quote! { i32 }],
+                    ..mock_constructor()
+                },
+                Constructor {
+                    arguments: vec![parse_quote! { &'b QString }],
+                    ..mock_constructor()
+                },
+            ],
+            "BaseClass".to_owned(),
+            &[],
+            &[],
+            &ParsedCxxMappings::default(),
+        );
+        assert!(result.is_err());
+    }
+
     #[test]
     fn multiple_constructors() {
         let blocks = generate(
@@ -337,6 +579,7 @@ mod tests {
             ],
             "BaseClass".to_owned(),
             &["initializer".to_string()],
+            &[],
             &ParsedCxxMappings::default(),
         )
         .unwrap();
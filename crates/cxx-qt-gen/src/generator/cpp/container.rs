@@ -0,0 +1,197 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use std::collections::BTreeSet;
+
+use syn::{ForeignItem, Item, LitStr, Result, Type};
+
+use crate::{
+    generator::utils::cpp::syn_type_to_cpp_type,
+    parser::{
+        container::{find_declaration, ContainerTypeDeclaration, ParsedContainer},
+        mappings::ParsedCxxMappings,
+    },
+    syntax::path::path_compare_str,
+    writer::cpp::namespaced,
+};
+
+/// For an `include!` in an extern "C++" block return the C++ include
+fn foreign_item_to_include(item: &ForeignItem) -> Option<String> {
+    if let ForeignItem::Macro(foreign_macro) = item {
+        if path_compare_str(&foreign_macro.mac.path, &["include"]) {
+            return Some(
+                if let Ok(path) = syn::parse2::<LitStr>(foreign_macro.mac.tokens.clone()) {
+                    format!("#include \"{}\"", path.value())
+                } else {
+                    // An include!(<path>) has spaces between each of the tokens
+                    format!(
+                        "#include {}",
+                        foreign_macro.mac.tokens.to_string().replace(' ', "")
+                    )
+                },
+            );
+        }
+    }
+
+    None
+}
+
+/// Generate the type alias of a container in the bridge namespace
+///
+/// This is written before the CXX header is included, so any shared structs that are stored in
+/// the container are forward declared and the headers of any extern "C++" types are included.
+pub fn generate(
+    container: &ParsedContainer,
+    items: &[Item],
+    namespace: &str,
+    cxx_mappings: &ParsedCxxMappings,
+    includes: &mut BTreeSet<String>,
+) -> Result<String> {
+    let template = match container {
+        ParsedContainer::QList { .. } => "QList",
+        ParsedContainer::QHash { .. } => "QHash",
+    };
+    includes.insert(format!(
+        "#include \"cxx-qt-lib/{}.h\"",
+        template.to_lowercase()
+    ));
+
+    let mut declarations = vec![];
+    let mut cpp_types = vec![];
+    for ident in container.types() {
+        match find_declaration(items, ident)? {
+            ContainerTypeDeclaration::BuiltIn => {}
+            ContainerTypeDeclaration::SharedStruct => {
+                let ident = ident.to_string();
+                let cxx_name = cxx_mappings.cxx_names.get(&ident).unwrap_or(&ident);
+                let struct_namespace = cxx_mappings
+                    .namespaces
+                    .get(&ident)
+                    .map(String::as_str)
+                    .unwrap_or_default();
+                declarations.push(namespaced(struct_namespace, &format!("struct {cxx_name};")));
+            }
+            ContainerTypeDeclaration::ExternCxx(foreign_mod) => {
+                includes.extend(foreign_mod.items.iter().filter_map(foreign_item_to_include));
+            }
+        }
+
+        let ty: Type = syn::parse_quote! { #ident };
+        cpp_types.push(syn_type_to_cpp_type(&ty, cxx_mappings)?);
+    }
+
+    declarations.push(namespaced(
+        namespace,
+        &format!(
+            "using {alias} = ::{template}<{types}>;",
+            alias = container.alias_ident(),
+            types = cpp_types.join(", ")
+        ),
+    ));
+    Ok(declarations.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::parser::Parser;
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
+    use syn::{parse_quote, ItemMod};
+
+    fn generate_containers(module: ItemMod) -> (Vec<String>, BTreeSet<String>) {
+        let parser = Parser::from(module).unwrap();
+        let items = &parser.passthrough_module.content.as_ref().unwrap().1;
+        let mut includes = BTreeSet::new();
+        let declarations = parser
+            .cxx_qt_data
+            .containers
+            .iter()
+            .map(|container| {
+                generate(
+                    container,
+                    items,
+                    &parser.cxx_qt_data.namespace,
+                    &parser.cxx_qt_data.cxx_mappings,
+                    &mut includes,
+                )
+                .unwrap()
+            })
+            .collect();
+        (declarations, includes)
+    }
+
+    #[test]
+    fn test_generate_qlist() {
+        let (declarations, includes) = generate_containers(parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt::my_object")]
+            mod ffi {
+                #[namespace = "gadgets"]
+                #[cxx_name = "Gadget"]
+                struct MyGadget {
+                    number: i32,
+                }
+
+                impl_qlist_element!(MyGadget);
+            }
+        });
+
+        assert_eq!(declarations.len(), 1);
+        assert_str_eq!(
+            declarations[0],
+            indoc! {r#"
+                namespace gadgets {
+                struct Gadget;
+                } // namespace gadgets
+
+                namespace cxx_qt::my_object {
+                using QList_MyGadget = ::QList<::gadgets::Gadget>;
+                } // namespace cxx_qt::my_object
+            "#}
+        );
+        assert_eq!(includes.len(), 1);
+        assert!(includes.contains("#include \"cxx-qt-lib/qlist.h\""));
+    }
+
+    #[test]
+    fn test_generate_qhash() {
+        let (declarations, includes) = generate_containers(parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                struct MyGadget {
+                    number: i32,
+                }
+
+                unsafe extern "C++" {
+                    include!("cxx-qt-lib/qstring.h");
+                    include!(<QtCore/QPoint>);
+                    type QString = cxx_qt_lib::QString;
+                }
+
+                impl_qhash_pair!(QString, MyGadget);
+                impl_qhash_pair!(i32, MyGadget);
+            }
+        });
+
+        assert_eq!(declarations.len(), 2);
+        assert_str_eq!(
+            declarations[0],
+            indoc! {r#"
+                struct MyGadget;
+                using QHash_QString_MyGadget = ::QHash<QString, MyGadget>;"#}
+        );
+        assert_str_eq!(
+            declarations[1],
+            indoc! {r#"
+                struct MyGadget;
+                using QHash_i32_MyGadget = ::QHash<::std::int32_t, MyGadget>;"#}
+        );
+        assert_eq!(includes.len(), 3);
+        assert!(includes.contains("#include \"cxx-qt-lib/qhash.h\""));
+        assert!(includes.contains("#include \"cxx-qt-lib/qstring.h\""));
+        assert!(includes.contains("#include <QtCore/QPoint>"));
+    }
+}
@@ -12,6 +12,7 @@ use crate::{
     parser::{inherit::ParsedInheritedMethod, mappings::ParsedCxxMappings},
 };
 
+use std::collections::{BTreeMap, BTreeSet};
 use syn::Result;
 
 pub fn generate(
@@ -20,24 +21,45 @@ pub fn generate(
     cxx_mappings: &ParsedCxxMappings,
 ) -> Result<GeneratedCppQObjectBlocks> {
     let mut result = GeneratedCppQObjectBlocks::default();
+    let base_class = base_class.as_deref().unwrap_or("QObject");
 
+    // Overloads of the same C++ method, distinguished by their rust_name, share a
+    // single variadic template wrapper for each constness.
+    let mut wrappers: BTreeMap<(String, bool), (&ParsedInheritedMethod, BTreeSet<String>)> =
+        BTreeMap::new();
     for method in inherited_methods {
-        let return_type = syn_type_to_cpp_return_type(&method.method.sig.output, cxx_mappings)?;
-        let base_class = base_class.as_deref().unwrap_or("QObject");
-
-        result.methods.push(CppFragment::Header(formatdoc! {
-        r#"
-              template <class... Args>
-              {return_type} {wrapper_ident}(Args ...args){mutability}
-              {{
-                  return {base_class}::{func_ident}(args...);
-              }}"#,
-        mutability = if method.mutable { "" } else { " const" },
-        func_ident = method.ident.cpp,
-        wrapper_ident = method.wrapper_ident(),
-        return_type = return_type.unwrap_or_else(|| "void".to_string()),
-        base_class = base_class
-        }));
+        let return_type = syn_type_to_cpp_return_type(&method.method.sig.output, cxx_mappings)?
+            .unwrap_or_else(|| "void".to_string());
+        wrappers
+            .entry((method.wrapper_ident().to_string(), method.mutable))
+            .or_insert_with(|| (method, BTreeSet::new()))
+            .1
+            .insert(return_type);
+    }
+
+    for method in inherited_methods {
+        if let Some((first, return_types)) =
+            wrappers.remove(&(method.wrapper_ident().to_string(), method.mutable))
+        {
+            // If the overloads differ in their return type let the compiler deduce it
+            let return_type = if return_types.len() == 1 {
+                return_types.into_iter().next().unwrap()
+            } else {
+                "decltype(auto)".to_string()
+            };
+
+            result.methods.push(CppFragment::Header(formatdoc! {
+            r#"
+                  template <class... Args>
+                  {return_type} {wrapper_ident}(Args ...args){mutability}
+                  {{
+                      return {base_class}::{func_ident}(args...);
+                  }}"#,
+            mutability = if first.mutable { "" } else { " const" },
+            func_ident = first.ident.cpp,
+            wrapper_ident = first.wrapper_ident(),
+            }));
+        }
     }
 
     Ok(result)
@@ -139,4 +161,69 @@ mod tests {
             &generated,
         );
     }
+
+    #[test]
+    fn test_overloads() {
+        let inherited_methods = vec![
+            ParsedInheritedMethod::parse(
+                parse_quote! {
+                    #[rust_name = "data_int"]
+                    fn data(self: &T, index: i32) -> i32;
+                },
+                Safety::Safe,
+            )
+            .unwrap(),
+            ParsedInheritedMethod::parse(
+                parse_quote! {
+                    #[rust_name = "data_string"]
+                    fn data(self: &T, index: &QString) -> QString;
+                },
+                Safety::Safe,
+            )
+            .unwrap(),
+            ParsedInheritedMethod::parse(
+                parse_quote! {
+                    #[rust_name = "data_mut"]
+                    fn data(self: Pin<&mut T>, index: i32) -> i32;
+                },
+                Safety::Safe,
+            )
+            .unwrap(),
+        ];
+        let generated = generate(
+            &inherited_methods,
+            &Some("TestBaseClass".to_owned()),
+            &ParsedCxxMappings::default(),
+        )
+        .unwrap();
+
+        assert_eq!(generated.methods.len(), 2);
+        let header = |index: usize| {
+            if let CppFragment::Header(header) = &generated.methods[index] {
+                header.clone()
+            } else {
+                panic!("Expected header fragment");
+            }
+        };
+        assert_str_eq!(
+            header(0),
+            indoc::indoc! {"
+                template <class... Args>
+                decltype(auto) dataCxxQtInherit(Args ...args) const
+                {
+                    return TestBaseClass::data(args...);
+                }"
+            }
+        );
+        assert_str_eq!(
+            header(1),
+            indoc::indoc! {"
+                template <class... Args>
+                ::std::int32_t dataCxxQtInherit(Args ...args)
+                {
+                    return TestBaseClass::data(args...);
+                }"
+            }
+        );
+    }
 }
@@ -9,14 +9,16 @@ use crate::{
             fragment::{CppFragment, CppNamedType},
             qobject::GeneratedCppQObjectBlocks,
         },
-        naming::{method::QMethodName, qobject::QObjectName},
+        naming::{method::QMethodName, namespace::NamespaceName, qobject::QObjectName},
+        utils::cpp::Indent,
         utils::cpp::{
             syn_return_type_to_cpp_except, syn_type_to_cpp_return_type, syn_type_to_cpp_type,
         },
     },
     parser::{
         mappings::ParsedCxxMappings,
-        method::{ParsedMethod, ParsedQInvokableSpecifiers},
+        method::{ParsedMethod, ParsedMethodAccess, ParsedQInvokableSpecifiers},
+        panic::ParsedQPanic,
     },
 };
 use indoc::formatdoc;
@@ -25,11 +27,14 @@ use syn::{spanned::Spanned, Error, FnArg, Pat, PatIdent, PatType, Result};
 pub fn generate_cpp_methods(
     invokables: &Vec<ParsedMethod>,
     qobject_idents: &QObjectName,
+    namespace_idents: &NamespaceName,
     cxx_mappings: &ParsedCxxMappings,
+    panic: ParsedQPanic,
 ) -> Result<GeneratedCppQObjectBlocks> {
     let mut generated = GeneratedCppQObjectBlocks::default();
     let qobject_ident = qobject_idents.cpp_class.cpp.to_string();
     for invokable in invokables {
+        let catch_panic = panic.catches_method(invokable)?;
         let idents = QMethodName::from(invokable);
         let return_cxx_ty =
             syn_type_to_cpp_return_type(&invokable.method.sig.output, cxx_mappings)?;
@@ -64,9 +69,19 @@ pub fn generate_cpp_methods(
             .filter_map(|result| result.map_or_else(|e| Some(Err(e)), |v| v.map(Ok)))
             .collect::<Result<Vec<CppNamedType>>>()?;
 
+        // A static invokable calls the free function which CXX generates in the internal namespace
+        let wrapper_ident = if invokable.is_static {
+            format!(
+                "::{namespace_internals}::{ident}",
+                namespace_internals = namespace_idents.internal,
+                ident = idents.wrapper.cpp
+            )
+        } else {
+            idents.wrapper.cpp.to_string()
+        };
         let body = format!(
             "{ident}({parameter_names})",
-            ident = idents.wrapper.cpp,
+            ident = wrapper_ident,
             parameter_names = parameters
                 .iter()
                 .map(|parameter| parameter.ident.as_str())
@@ -78,11 +93,48 @@ pub fn generate_cpp_methods(
             .map(|parameter| format!("{ty} {ident}", ident = parameter.ident, ty = parameter.ty))
             .collect::<Vec<String>>()
             .join(", ");
-        let is_const = if !invokable.mutable { " const" } else { "" };
+        // A static member has no instance so cannot be const
+        let is_const = if !invokable.mutable && !invokable.is_static {
+            " const"
+        } else {
+            ""
+        };
+
+        // Set the QML ownership of the returned QObject before it is handed to QML
+        let call = if let Some(ownership) = invokable.ownership {
+            generated
+                .includes
+                .insert("#include <QtQml/QQmlEngine>".to_owned());
+
+            formatdoc! {
+                r#"
+                auto* result = {body};
+                ::QQmlEngine::setObjectOwnership(result, {ownership});
+                return result;"#,
+                ownership = ownership.to_cpp_ownership(),
+            }
+        } else if return_cxx_ty.is_some() {
+            format!("return {body};")
+        } else {
+            format!("{body};")
+        };
+
+        // Describe the deprecation to QML tooling, as only invokables are in the meta object
+        if let (true, Some(deprecated)) = (invokable.is_qinvokable, &invokable.deprecated) {
+            generated
+                .metaobjects
+                .push(deprecated.to_cpp_class_info(&idents.name.cpp.to_string()));
+        }
 
-        generated.methods.push(CppFragment::Pair {
+        // Place the method in the section of the class for its access specifier
+        let methods = match invokable.access {
+            ParsedMethodAccess::Public => &mut generated.methods,
+            ParsedMethodAccess::Protected => &mut generated.protected_methods,
+            ParsedMethodAccess::Private => &mut generated.private_methods,
+        };
+        methods.push(CppFragment::Pair {
             header: format!(
-                "{is_qinvokable}{is_virtual}{return_cxx_ty} {ident}({parameter_types}){is_const}{is_final}{is_override};",
+                "{is_qinvokable}{revision}{deprecated}{is_static}{is_virtual}{return_cxx_ty} {ident}({parameter_types}){is_const}{is_final}{is_override};",
                 return_cxx_ty = if let Some(return_cxx_ty) = &return_cxx_ty {
                     return_cxx_ty
                 } else {
@@ -95,6 +147,16 @@ pub fn generate_cpp_methods(
                 } else {
                     ""
                 },
+                revision = if let Some(revision) = invokable.revision {
+                    format!("Q_REVISION({args}) ", args = revision.to_cpp_args())
+                } else {
+                    "".to_owned()
+                },
+                deprecated = invokable
+                    .deprecated
+                    .as_ref()
+                    .map(|deprecated| format!("{} ", deprecated.to_cpp_attribute()))
+                    .unwrap_or_default(),
                 is_final = if invokable.specifiers.contains(&ParsedQInvokableSpecifiers::Final) {
                     " final"
                 } else {
@@ -105,6 +167,7 @@ pub fn generate_cpp_methods(
                 } else {
                     ""
                 },
+                is_static = if invokable.is_static { "static " } else { "" },
                 is_virtual = if invokable.specifiers.contains(&ParsedQInvokableSpecifiers::Virtual) {
                     "virtual "
                 } else {
@@ -116,8 +179,7 @@ pub fn generate_cpp_methods(
                     {return_cxx_ty}
                     {qobject_ident}::{ident}({parameter_types}){is_const}
                     {{
-                        const ::rust::cxxqtlib1::MaybeLockGuard<{qobject_ident}> guard(*this);
-                        {body};
+                    {guard}{body}
                     }}
                     "#,
                 return_cxx_ty = if let Some(return_cxx_ty) = &return_cxx_ty {
@@ -126,21 +188,43 @@ pub fn generate_cpp_methods(
                     "void"
                 },
                 ident = idents.name.cpp,
-                body = if return_cxx_ty.is_some() {
-                    format!("return {body}", body = body)
+                // There is no instance to lock for a static invokable
+                guard = if invokable.is_static {
+                    "".to_owned()
                 } else {
-                    body
+                    format!("    const ::rust::cxxqtlib1::MaybeLockGuard<{qobject_ident}> guard(*this);\n")
                 },
+                body = if catch_panic { panic } else { ParsedQPanic::Abort }
+                    .to_cpp_call(
+                        &call,
+                        &qobject_ident,
+                        &format!("{qobject_ident}::{ident}", ident = idents.name.cpp),
+                        return_cxx_ty.is_some(),
+                    )
+                    .indented(4),
             },
         });
 
+        // The free function of a static invokable is declared by CXX in its own header
+        if invokable.is_static {
+            continue;
+        }
+
         // Note that we are generating a header to match the extern "Rust" method
         // in Rust for our invokable.
         //
         // CXX generates the source and we just need the matching header.
-        let has_noexcept = syn_return_type_to_cpp_except(&invokable.method.sig.output);
+        //
+        // The wrapper throws when it returns a Result or its panics are caught, otherwise it is noexcept
+        let has_noexcept = if catch_panic
+            || syn_return_type_to_cpp_except(&invokable.method.sig.output).is_empty()
+        {
+            ""
+        } else {
+            " noexcept"
+        };
         generated.private_methods.push(CppFragment::Header(format!(
-            "{return_cxx_ty} {ident}({parameter_types}){is_const} {has_noexcept};",
+            "{return_cxx_ty} {ident}({parameter_types}){is_const}{has_noexcept};",
             return_cxx_ty = if let Some(return_cxx_ty) = &return_cxx_ty {
                 return_cxx_ty
             } else {
@@ -158,7 +242,10 @@ mod tests {
     use super::*;
 
     use crate::generator::naming::qobject::tests::create_qobjectname;
-    use crate::parser::parameter::ParsedFunctionParameter;
+    use crate::parser::{
+        deprecated::ParsedQDeprecated, ownership::ParsedQmlOwnership,
+        parameter::ParsedFunctionParameter, revision::ParsedQRevision,
+    };
     use indoc::indoc;
     use pretty_assertions::assert_str_eq;
     use quote::format_ident;
@@ -176,6 +263,14 @@ mod tests {
                 parameters: vec![],
                 specifiers: HashSet::new(),
                 is_qinvokable: true,
+                revision: None,
+                deprecated: Some(ParsedQDeprecated {
+                    reason: Some("use trivialInvokable".to_owned()),
+                }),
+                ownership: None,
+                access: Default::default(),
+                is_static: false,
+                conversions: Default::default(),
             },
             ParsedMethod {
                 method: parse_quote! { fn trivial_invokable(self: &MyObject, param: i32) -> i32; },
@@ -188,6 +283,12 @@ mod tests {
                 }],
                 specifiers: HashSet::new(),
                 is_qinvokable: true,
+                revision: None,
+                deprecated: None,
+                ownership: None,
+                access: Default::default(),
+                is_static: false,
+                conversions: Default::default(),
             },
             ParsedMethod {
                 method: parse_quote! { fn opaque_invokable(self: Pin<&mut MyObject>, param: &QColor) -> UniquePtr<QColor>; },
@@ -200,6 +301,12 @@ mod tests {
                 }],
                 specifiers: HashSet::new(),
                 is_qinvokable: true,
+                revision: None,
+                deprecated: None,
+                ownership: None,
+                access: Default::default(),
+                is_static: false,
+                conversions: Default::default(),
             },
             ParsedMethod {
                 method: parse_quote! { fn specifiers_invokable(self: &MyObject, param: i32) -> i32; },
@@ -218,6 +325,12 @@ mod tests {
                     specifiers
                 },
                 is_qinvokable: true,
+                revision: Some(ParsedQRevision { major: 2, minor: 1 }),
+                deprecated: None,
+                ownership: None,
+                access: Default::default(),
+                is_static: false,
+                conversions: Default::default(),
             },
             ParsedMethod {
                 method: parse_quote! { fn cpp_method(self: &MyObject); },
@@ -227,13 +340,33 @@ mod tests {
                 parameters: vec![],
                 specifiers: HashSet::new(),
                 is_qinvokable: false,
+                revision: None,
+                deprecated: None,
+                ownership: None,
+                access: Default::default(),
+                is_static: false,
+                conversions: Default::default(),
             },
         ];
         let qobject_idents = create_qobjectname();
+        let namespace_idents =
+            NamespaceName::from_pair_str("cxx_qt::my_object", &format_ident!("MyObject"));
+
+        let generated = generate_cpp_methods(
+            &invokables,
+            &qobject_idents,
+            &namespace_idents,
+            &ParsedCxxMappings::default(),
+            ParsedQPanic::Abort,
+        )
+        .unwrap();
 
-        let generated =
-            generate_cpp_methods(&invokables, &qobject_idents, &ParsedCxxMappings::default())
-                .unwrap();
+        // metaobjects
+        assert_eq!(generated.metaobjects.len(), 1);
+        assert_str_eq!(
+            generated.metaobjects[0],
+            "Q_CLASSINFO(\"cxx-qt.deprecated.voidInvokable\", \"use trivialInvokable\")"
+        );
 
         // methods
         assert_eq!(generated.methods.len(), 5);
@@ -243,7 +376,10 @@ mod tests {
         } else {
             panic!("Expected pair")
         };
-        assert_str_eq!(header, "Q_INVOKABLE void voidInvokable() const;");
+        assert_str_eq!(
+            header,
+            "Q_INVOKABLE [[deprecated(\"use trivialInvokable\")]] void voidInvokable() const;"
+        );
         assert_str_eq!(
             source,
             indoc! {r#"
@@ -305,7 +441,7 @@ mod tests {
         };
         assert_str_eq!(
             header,
-            "Q_INVOKABLE virtual ::std::int32_t specifiersInvokable(::std::int32_t param) const final override;"
+            "Q_INVOKABLE Q_REVISION(2, 1) virtual ::std::int32_t specifiersInvokable(::std::int32_t param) const final override;"
         );
         assert_str_eq!(
             source,
@@ -398,8 +534,16 @@ mod tests {
             }],
             specifiers: HashSet::new(),
             is_qinvokable: true,
+            revision: None,
+            deprecated: None,
+            ownership: None,
+            access: Default::default(),
+            is_static: false,
+            conversions: Default::default(),
         }];
         let qobject_idents = create_qobjectname();
+        let namespace_idents =
+            NamespaceName::from_pair_str("cxx_qt::my_object", &format_ident!("MyObject"));
 
         let mut cxx_mappings = ParsedCxxMappings::default();
         cxx_mappings
@@ -409,7 +553,14 @@ mod tests {
             .cxx_names
             .insert("B".to_owned(), "B2".to_owned());
 
-        let generated = generate_cpp_methods(&invokables, &qobject_idents, &cxx_mappings).unwrap();
+        let generated = generate_cpp_methods(
+            &invokables,
+            &qobject_idents,
+            &namespace_idents,
+            &cxx_mappings,
+            ParsedQPanic::Abort,
+        )
+        .unwrap();
 
         // methods
         assert_eq!(generated.methods.len(), 1);
@@ -445,4 +596,425 @@ mod tests {
             "B2 trivialInvokableWrapper(A1 param) const noexcept;"
         );
     }
+
+    #[test]
+    fn test_generate_cpp_invokables_event_override() {
+        let invokables = vec![ParsedMethod {
+            method: parse_quote! { unsafe fn event(self: Pin<&mut MyObject>, event: *mut QEvent) -> bool; },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: true,
+            safe: false,
+            parameters: vec![ParsedFunctionParameter {
+                ident: format_ident!("event"),
+                ty: parse_quote! { *mut QEvent },
+            }],
+            specifiers: [ParsedQInvokableSpecifiers::Override].into_iter().collect(),
+            is_qinvokable: false,
+            revision: None,
+            deprecated: None,
+            ownership: None,
+            access: Default::default(),
+            is_static: false,
+            conversions: Default::default(),
+        }];
+        let qobject_idents = create_qobjectname();
+        let namespace_idents =
+            NamespaceName::from_pair_str("cxx_qt::my_object", &format_ident!("MyObject"));
+
+        let generated = generate_cpp_methods(
+            &invokables,
+            &qobject_idents,
+            &namespace_idents,
+            &ParsedCxxMappings::default(),
+            ParsedQPanic::Abort,
+        )
+        .unwrap();
+
+        // methods
+        assert_eq!(generated.methods.len(), 1);
+
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[0] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        // The override is not in the meta object, as it is only called from C++
+        assert_str_eq!(header, "bool event(QEvent* event) override;");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            bool
+            MyObject::event(QEvent* event)
+            {
+                const ::rust::cxxqtlib1::MaybeLockGuard<MyObject> guard(*this);
+                return eventWrapper(event);
+            }
+            "#}
+        );
+
+        // private methods
+        assert_eq!(generated.private_methods.len(), 1);
+
+        let header = if let CppFragment::Header(header) = &generated.private_methods[0] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(header, "bool eventWrapper(QEvent* event) noexcept;");
+    }
+
+    #[test]
+    fn test_generate_cpp_invokables_access() {
+        let invokables = vec![
+            ParsedMethod {
+                method: parse_quote! { fn protected_invokable(self: &MyObject); },
+                qobject_ident: format_ident!("MyObject"),
+                mutable: false,
+                safe: true,
+                parameters: vec![],
+                specifiers: HashSet::new(),
+                is_qinvokable: true,
+                revision: None,
+                deprecated: None,
+                ownership: None,
+                access: ParsedMethodAccess::Protected,
+                is_static: false,
+                conversions: Default::default(),
+            },
+            ParsedMethod {
+                method: parse_quote! { fn private_invokable(self: &MyObject); },
+                qobject_ident: format_ident!("MyObject"),
+                mutable: false,
+                safe: true,
+                parameters: vec![],
+                specifiers: HashSet::new(),
+                is_qinvokable: true,
+                revision: None,
+                deprecated: None,
+                ownership: None,
+                access: ParsedMethodAccess::Private,
+                is_static: false,
+                conversions: Default::default(),
+            },
+        ];
+        let qobject_idents = create_qobjectname();
+        let namespace_idents =
+            NamespaceName::from_pair_str("cxx_qt::my_object", &format_ident!("MyObject"));
+
+        let generated = generate_cpp_methods(
+            &invokables,
+            &qobject_idents,
+            &namespace_idents,
+            &ParsedCxxMappings::default(),
+            ParsedQPanic::Abort,
+        )
+        .unwrap();
+
+        // methods
+        assert!(generated.methods.is_empty());
+
+        // protected methods
+        assert_eq!(generated.protected_methods.len(), 1);
+        let header = if let CppFragment::Pair { header, .. } = &generated.protected_methods[0] {
+            header
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(header, "Q_INVOKABLE void protectedInvokable() const;");
+
+        // private methods, which include the wrappers of both invokables
+        assert_eq!(generated.private_methods.len(), 3);
+        let header = if let CppFragment::Header(header) = &generated.private_methods[0] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(header, "void protectedInvokableWrapper() const noexcept;");
+        let header = if let CppFragment::Pair { header, .. } = &generated.private_methods[1] {
+            header
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(header, "Q_INVOKABLE void privateInvokable() const;");
+        let header = if let CppFragment::Header(header) = &generated.private_methods[2] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(header, "void privateInvokableWrapper() const noexcept;");
+    }
+
+    #[test]
+    fn test_generate_cpp_invokables_static() {
+        let invokables = vec![ParsedMethod {
+            method: parse_quote! { fn helper(value: i32) -> i32; },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: false,
+            safe: true,
+            parameters: vec![ParsedFunctionParameter {
+                ident: format_ident!("value"),
+                ty: parse_quote! { i32 },
+            }],
+            specifiers: HashSet::new(),
+            is_qinvokable: true,
+            revision: None,
+            deprecated: None,
+            ownership: None,
+            access: Default::default(),
+            is_static: true,
+            conversions: Default::default(),
+        }];
+        let qobject_idents = create_qobjectname();
+        let namespace_idents =
+            NamespaceName::from_pair_str("cxx_qt::my_object", &format_ident!("MyObject"));
+
+        // Panics are not caught as there is no instance to report them to
+        let generated = generate_cpp_methods(
+            &invokables,
+            &qobject_idents,
+            &namespace_idents,
+            &ParsedCxxMappings::default(),
+            ParsedQPanic::Signal,
+        )
+        .unwrap();
+
+        // methods
+        assert_eq!(generated.methods.len(), 1);
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[0] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(
+            header,
+            "Q_INVOKABLE static ::std::int32_t helper(::std::int32_t value);"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            ::std::int32_t
+            MyObject::helper(::std::int32_t value)
+            {
+                return ::cxx_qt::my_object::cxx_qt_my_object::helperWrapper(value);
+            }
+            "#}
+        );
+
+        // The wrapper is a free function which is declared by CXX
+        assert!(generated.private_methods.is_empty());
+    }
+
+    #[test]
+    fn test_generate_cpp_invokables_array() {
+        let invokables = vec![ParsedMethod {
+            method: parse_quote! { fn scale_color(self: &MyObject, color: [f32; 4], factor: f32) -> [f32; 4]; },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: false,
+            safe: true,
+            parameters: vec![
+                ParsedFunctionParameter {
+                    ident: format_ident!("color"),
+                    ty: parse_quote! { [f32; 4] },
+                },
+                ParsedFunctionParameter {
+                    ident: format_ident!("factor"),
+                    ty: parse_quote! { f32 },
+                },
+            ],
+            specifiers: HashSet::new(),
+            is_qinvokable: true,
+            revision: None,
+            deprecated: None,
+            ownership: None,
+            access: Default::default(),
+            is_static: false,
+            conversions: Default::default(),
+        }];
+        let qobject_idents = create_qobjectname();
+        let namespace_idents =
+            NamespaceName::from_pair_str("cxx_qt::my_object", &format_ident!("MyObject"));
+
+        let generated = generate_cpp_methods(
+            &invokables,
+            &qobject_idents,
+            &namespace_idents,
+            &ParsedCxxMappings::default(),
+            ParsedQPanic::Abort,
+        )
+        .unwrap();
+
+        // Arrays are passed by value as a std::array
+        let header = if let CppFragment::Pair { header, .. } = &generated.methods[0] {
+            header
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(
+            header,
+            "Q_INVOKABLE ::std::array<float, 4> scaleColor(::std::array<float, 4> color, float factor) const;"
+        );
+        let header = if let CppFragment::Header(header) = &generated.private_methods[0] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(
+            header,
+            "::std::array<float, 4> scaleColorWrapper(::std::array<float, 4> color, float factor) const noexcept;"
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_invokables_panic_signal() {
+        let invokables = vec![ParsedMethod {
+            method: parse_quote! { fn trivial_invokable(self: &MyObject, param: i32) -> i32; },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: false,
+            safe: true,
+            parameters: vec![ParsedFunctionParameter {
+                ident: format_ident!("param"),
+                ty: parse_quote! { i32 },
+            }],
+            specifiers: HashSet::new(),
+            is_qinvokable: true,
+            revision: None,
+            deprecated: None,
+            ownership: None,
+            access: Default::default(),
+            is_static: false,
+            conversions: Default::default(),
+        }];
+        let qobject_idents = create_qobjectname();
+        let namespace_idents =
+            NamespaceName::from_pair_str("cxx_qt::my_object", &format_ident!("MyObject"));
+
+        let generated = generate_cpp_methods(
+            &invokables,
+            &qobject_idents,
+            &namespace_idents,
+            &ParsedCxxMappings::default(),
+            ParsedQPanic::Signal,
+        )
+        .unwrap();
+
+        assert_eq!(generated.methods.len(), 1);
+        let source = if let CppFragment::Pair { source, .. } = &generated.methods[0] {
+            source
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            ::std::int32_t
+            MyObject::trivialInvokable(::std::int32_t param) const
+            {
+                const ::rust::cxxqtlib1::MaybeLockGuard<MyObject> guard(*this);
+                try {
+                  return trivialInvokableWrapper(param);
+                } catch (const ::rust::Error& error) {
+                  qWarning("Panic in MyObject::trivialInvokable: %s", error.what());
+                  Q_EMIT const_cast<MyObject*>(this)->panicked(QString::fromUtf8(error.what()));
+                  return {};
+                }
+            }
+            "#}
+        );
+
+        // The wrapper throws a rust::Error when the invokable has panicked
+        assert_eq!(generated.private_methods.len(), 1);
+        let header = if let CppFragment::Header(header) = &generated.private_methods[0] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(
+            header,
+            "::std::int32_t trivialInvokableWrapper(::std::int32_t param) const;"
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_invokables_panic_reference() {
+        let invokables = vec![ParsedMethod {
+            method: parse_quote! { fn reference_invokable(self: &MyObject) -> &QColor; },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: false,
+            safe: true,
+            parameters: vec![],
+            specifiers: HashSet::new(),
+            is_qinvokable: true,
+            revision: None,
+            deprecated: None,
+            ownership: None,
+            access: Default::default(),
+            is_static: false,
+            conversions: Default::default(),
+        }];
+        let qobject_idents = create_qobjectname();
+        let namespace_idents =
+            NamespaceName::from_pair_str("cxx_qt::my_object", &format_ident!("MyObject"));
+
+        assert!(generate_cpp_methods(
+            &invokables,
+            &qobject_idents,
+            &namespace_idents,
+            &ParsedCxxMappings::default(),
+            ParsedQPanic::Exception,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_generate_cpp_invokables_ownership() {
+        let invokables = vec![ParsedMethod {
+            method: parse_quote! { fn child_invokable(self: &MyObject) -> *mut Other; },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: false,
+            safe: true,
+            parameters: vec![],
+            specifiers: HashSet::new(),
+            is_qinvokable: true,
+            revision: None,
+            deprecated: None,
+            ownership: Some(ParsedQmlOwnership::JavaScript),
+            access: Default::default(),
+            is_static: false,
+            conversions: Default::default(),
+        }];
+        let qobject_idents = create_qobjectname();
+        let namespace_idents =
+            NamespaceName::from_pair_str("cxx_qt::my_object", &format_ident!("MyObject"));
+
+        let generated = generate_cpp_methods(
+            &invokables,
+            &qobject_idents,
+            &namespace_idents,
+            &ParsedCxxMappings::default(),
+            ParsedQPanic::Abort,
+        )
+        .unwrap();
+
+        assert!(generated.includes.contains("#include <QtQml/QQmlEngine>"));
+
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[0] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(header, "Q_INVOKABLE Other* childInvokable() const;");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            Other*
+            MyObject::childInvokable() const
+            {
+                const ::rust::cxxqtlib1::MaybeLockGuard<MyObject> guard(*this);
+                auto* result = childInvokableWrapper();
+                ::QQmlEngine::setObjectOwnership(result, ::QQmlEngine::JavaScriptOwnership);
+                return result;
+            }
+            "#}
+        );
+    }
 }
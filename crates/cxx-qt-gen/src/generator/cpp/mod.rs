@@ -3,7 +3,9 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+pub mod connect;
 mod constructor;
+pub mod container;
 pub mod cxxqttype;
 pub mod externcxxqt;
 pub mod fragment;
@@ -12,15 +14,21 @@ pub mod locking;
 pub mod method;
 pub mod property;
 pub mod qenum;
+pub mod qmetatype;
+pub mod qmlattached;
+pub mod qmlfactory;
+pub mod qmlparserstatus;
 pub mod qnamespace;
 pub mod qobject;
 pub mod signal;
 pub mod threading;
+pub mod weakref;
 
 use std::collections::BTreeSet;
 
 use crate::parser::Parser;
 use externcxxqt::GeneratedCppExternCxxQtBlocks;
+use fragment::CppFragment;
 use qobject::GeneratedCppQObject;
 use syn::Result;
 
@@ -32,10 +40,14 @@ pub struct GeneratedCppBlocks {
     pub includes: BTreeSet<String>,
     /// Stem of the CXX header to include
     pub cxx_file_stem: String,
+    /// Directory prefix that the generated headers are included with
+    pub include_prefix: String,
     /// Generated QObjects
     pub qobjects: Vec<GeneratedCppQObject>,
     /// Generated extern C++Qt blocks
     pub extern_cxx_qt: Vec<GeneratedCppExternCxxQtBlocks>,
+    /// Declarations and registrations of third-party types as a QMetaType
+    pub qmetatypes: Vec<CppFragment>,
 }
 
 impl GeneratedCppBlocks {
@@ -55,10 +67,33 @@ impl GeneratedCppBlocks {
                 .iter()
                 .map(|parsed_qenum| qenum::generate_declaration(parsed_qenum, &mut includes)),
         );
+        for container in &parser.cxx_qt_data.containers {
+            forward_declares.push(container::generate(
+                container,
+                parser
+                    .passthrough_module
+                    .content
+                    .as_ref()
+                    .map(|(_, items)| items.as_slice())
+                    .unwrap_or_default(),
+                &parser.cxx_qt_data.namespace,
+                &parser.cxx_qt_data.cxx_mappings,
+                &mut includes,
+            )?);
+        }
+        let qmetatypes = parser
+            .cxx_qt_data
+            .qmetatypes
+            .iter()
+            .map(|ident| {
+                qmetatype::generate(ident, &parser.cxx_qt_data.cxx_mappings, &mut includes)
+            })
+            .collect();
         Ok(GeneratedCppBlocks {
             forward_declares,
             includes,
             cxx_file_stem: parser.cxx_file_stem.clone(),
+            include_prefix: parser.include_prefix.clone(),
             qobjects: parser
                 .cxx_qt_data
                 .qobjects
@@ -69,6 +104,7 @@ impl GeneratedCppBlocks {
                 &parser.cxx_qt_data.extern_cxxqt_blocks,
                 &parser.cxx_qt_data.cxx_mappings,
             )?,
+            qmetatypes,
         })
     }
 }
@@ -134,4 +170,30 @@ mod tests {
         let cpp = GeneratedCppBlocks::from(&parser).unwrap();
         assert_eq!(cpp.qobjects[0].namespace, "cxx_qt");
     }
+
+    #[test]
+    fn test_generated_cpp_blocks_qmetatypes() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                unsafe extern "C++" {
+                    include!("company/money.h");
+
+                    #[qmetatype]
+                    #[namespace = "company"]
+                    type Money = crate::Money;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        let cpp = GeneratedCppBlocks::from(&parser).unwrap();
+        assert_eq!(cpp.qmetatypes.len(), 1);
+        if let CppFragment::Pair { header, .. } = &cpp.qmetatypes[0] {
+            assert_eq!(header, "Q_DECLARE_METATYPE(::company::Money)");
+        } else {
+            panic!("Expected pair")
+        }
+        assert!(cpp.includes.contains("#include <QtCore/QMetaType>"));
+    }
 }
@@ -3,13 +3,24 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::generator::{cpp::fragment::CppFragment, naming::property::QPropertyName};
+use crate::{
+    generator::{cpp::fragment::CppFragment, naming::property::QPropertyName, utils::cpp::Indent},
+    parser::{deprecated::ParsedQDeprecated, panic::ParsedQPanic},
+};
 use indoc::formatdoc;
 
-pub fn generate(idents: &QPropertyName, qobject_ident: &str, return_cxx_ty: &str) -> CppFragment {
+pub fn generate(
+    idents: &QPropertyName,
+    qobject_ident: &str,
+    return_cxx_ty: &str,
+    deprecated: Option<&ParsedQDeprecated>,
+) -> CppFragment {
     CppFragment::Pair {
         header: format!(
-            "{return_cxx_ty} const& {ident_getter}() const;",
+            "{deprecated}{return_cxx_ty} const& {ident_getter}() const;",
+            deprecated = deprecated
+                .map(|deprecated| format!("{} ", deprecated.to_cpp_attribute()))
+                .unwrap_or_default(),
             ident_getter = idents.getter.cpp
         ),
         source: formatdoc!(
@@ -33,3 +44,59 @@ pub fn generate_wrapper(idents: &QPropertyName, cxx_ty: &str) -> CppFragment {
         ident_getter_wrapper = idents.getter_wrapper.cpp
     ))
 }
+
+pub fn generate_computed(
+    idents: &QPropertyName,
+    qobject_ident: &str,
+    return_cxx_ty: &str,
+    deprecated: Option<&ParsedQDeprecated>,
+    panic: ParsedQPanic,
+) -> CppFragment {
+    CppFragment::Pair {
+        header: format!(
+            "{deprecated}{return_cxx_ty} {ident_getter}() const;",
+            deprecated = deprecated
+                .map(|deprecated| format!("{} ", deprecated.to_cpp_attribute()))
+                .unwrap_or_default(),
+            ident_getter = idents.getter.cpp
+        ),
+        source: formatdoc!(
+            r#"
+            {return_cxx_ty}
+            {qobject_ident}::{ident_getter}() const
+            {{
+                const ::rust::cxxqtlib1::MaybeLockGuard<{qobject_ident}> guard(*this);
+            {body}
+            }}
+            "#,
+            ident_getter = idents.getter.cpp,
+            body = panic
+                .to_cpp_call(
+                    &format!(
+                        "return {ident_getter_wrapper}();",
+                        ident_getter_wrapper = idents.getter_wrapper.cpp
+                    ),
+                    qobject_ident,
+                    &format!(
+                        "{qobject_ident}::{ident_getter}",
+                        ident_getter = idents.getter.cpp
+                    ),
+                    true,
+                )
+                .indented(4),
+        ),
+    }
+}
+
+pub fn generate_computed_wrapper(
+    idents: &QPropertyName,
+    cxx_ty: &str,
+    panic: ParsedQPanic,
+) -> CppFragment {
+    CppFragment::Header(format!(
+        "{cxx_ty} {ident_getter_wrapper}() const{has_noexcept};",
+        ident_getter_wrapper = idents.getter_wrapper.cpp,
+        // A wrapper which catches panics throws them as a rust::Error
+        has_noexcept = if panic.catches() { "" } else { " noexcept" },
+    ))
+}
@@ -3,16 +3,52 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::generator::naming::property::QPropertyName;
+use crate::{
+    generator::naming::property::QPropertyName,
+    parser::{property::QPropertyFlags, revision::ParsedQRevision},
+};
 
 /// Generate the metaobject line for a given property
-pub fn generate(idents: &QPropertyName, cxx_ty: &str) -> String {
+pub fn generate(
+    idents: &QPropertyName,
+    cxx_ty: &str,
+    revision: Option<ParsedQRevision>,
+    flags: &QPropertyFlags,
+) -> String {
+    let reset = idents
+        .reset
+        .as_ref()
+        .map(|reset| format!("RESET {ident_reset} ", ident_reset = reset.cpp))
+        .unwrap_or_default();
+
+    // A constant property has neither a setter nor a notify signal
+    let write_notify = if flags.constant {
+        "CONSTANT".to_owned()
+    // A read only or computed property can only be changed from Rust, but still notifies QML
+    } else if flags.read_only || flags.computed {
+        format!(
+            "{reset}NOTIFY {ident_notify}",
+            ident_notify = idents.notify.cpp
+        )
+    } else {
+        format!(
+            "WRITE {ident_setter} {reset}NOTIFY {ident_notify}",
+            ident_setter = idents.setter.cpp,
+            ident_notify = idents.notify.cpp,
+        )
+    };
+
     format!(
-        "Q_PROPERTY({ty} {ident} READ {ident_getter} WRITE {ident_setter} NOTIFY {ident_notify})",
+        "Q_PROPERTY({ty} {ident} READ {ident_getter} {write_notify}{revision}{is_final}{required})",
         ty = cxx_ty,
         ident = idents.name.cpp,
         ident_getter = idents.getter.cpp,
-        ident_setter = idents.setter.cpp,
-        ident_notify = idents.notify.cpp,
+        revision = if let Some(revision) = revision {
+            format!(" REVISION({args})", args = revision.to_cpp_args())
+        } else {
+            "".to_owned()
+        },
+        is_final = if flags.is_final { " FINAL" } else { "" },
+        required = if flags.required { " REQUIRED" } else { "" },
     )
 }
@@ -8,11 +8,12 @@ use crate::generator::{
     naming::{property::QPropertyName, qobject::QObjectName},
     utils::cpp::syn_type_to_cpp_type,
 };
-use crate::parser::{mappings::ParsedCxxMappings, property::ParsedQProperty};
+use crate::parser::{mappings::ParsedCxxMappings, panic::ParsedQPanic, property::ParsedQProperty};
 use syn::Result;
 
 mod getter;
 mod meta;
+mod reset;
 mod setter;
 mod signal;
 
@@ -20,6 +21,7 @@ pub fn generate_cpp_properties(
     properties: &Vec<ParsedQProperty>,
     qobject_idents: &QObjectName,
     cxx_mappings: &ParsedCxxMappings,
+    panic: ParsedQPanic,
 ) -> Result<GeneratedCppQObjectBlocks> {
     let mut generated = GeneratedCppQObjectBlocks::default();
     let mut signals = vec![];
@@ -30,20 +32,74 @@ pub fn generate_cpp_properties(
         let idents = QPropertyName::from(property);
         let cxx_ty = syn_type_to_cpp_type(&property.ty, cxx_mappings)?;
 
-        generated.metaobjects.push(meta::generate(&idents, &cxx_ty));
-        generated
-            .methods
-            .push(getter::generate(&idents, &qobject_ident, &cxx_ty));
-        generated
-            .private_methods
-            .push(getter::generate_wrapper(&idents, &cxx_ty));
-        generated
-            .methods
-            .push(setter::generate(&idents, &qobject_ident, &cxx_ty));
+        generated.metaobjects.push(meta::generate(
+            &idents,
+            &cxx_ty,
+            property.revision,
+            &property.flags,
+        ));
+        if let Some(deprecated) = &property.deprecated {
+            generated
+                .metaobjects
+                .push(deprecated.to_cpp_class_info(&idents.name.cpp.to_string()));
+        }
+        // A computed property returns the value from the user defined getter by value
+        if property.flags.computed {
+            generated.methods.push(getter::generate_computed(
+                &idents,
+                &qobject_ident,
+                &cxx_ty,
+                property.deprecated.as_ref(),
+                panic,
+            ));
+            generated
+                .private_methods
+                .push(getter::generate_computed_wrapper(&idents, &cxx_ty, panic));
+        } else {
+            generated.methods.push(getter::generate(
+                &idents,
+                &qobject_ident,
+                &cxx_ty,
+                property.deprecated.as_ref(),
+            ));
+            generated
+                .private_methods
+                .push(getter::generate_wrapper(&idents, &cxx_ty));
+        }
+
+        if let (Some(reset), Some(reset_wrapper)) = (&idents.reset, &idents.reset_wrapper) {
+            generated.methods.push(reset::generate(
+                reset,
+                reset_wrapper,
+                &qobject_ident,
+                property.deprecated.as_ref(),
+                panic,
+            ));
+            generated
+                .private_methods
+                .push(reset::generate_wrapper(reset_wrapper, panic));
+        }
+
+        if property.flags.constant {
+            continue;
+        }
+
+        signals.push(signal::generate(&idents, qobject_idents, property.revision));
+
+        if property.flags.read_only || property.flags.computed {
+            continue;
+        }
+
+        generated.methods.push(setter::generate(
+            &idents,
+            &qobject_ident,
+            &cxx_ty,
+            property.deprecated.as_ref(),
+            panic,
+        ));
         generated
             .private_methods
-            .push(setter::generate_wrapper(&idents, &cxx_ty));
-        signals.push(signal::generate(&idents, qobject_idents));
+            .push(setter::generate_wrapper(&idents, &cxx_ty, panic));
     }
 
     generated.append(&mut generate_cpp_signals(
@@ -60,6 +116,9 @@ mod tests {
     use super::*;
 
     use crate::generator::naming::qobject::tests::create_qobjectname;
+    use crate::parser::{
+        deprecated::ParsedQDeprecated, property::QPropertyFlags, revision::ParsedQRevision,
+    };
     use crate::CppFragment;
     use indoc::indoc;
     use pretty_assertions::assert_str_eq;
@@ -72,17 +131,27 @@ mod tests {
             ParsedQProperty {
                 ident: format_ident!("trivial_property"),
                 ty: parse_quote! { i32 },
+                revision: None,
+                deprecated: None,
+                flags: QPropertyFlags::default(),
             },
             ParsedQProperty {
                 ident: format_ident!("opaque_property"),
                 ty: parse_quote! { UniquePtr<QColor> },
+                revision: None,
+                deprecated: None,
+                flags: QPropertyFlags::default(),
             },
         ];
         let qobject_idents = create_qobjectname();
 
-        let generated =
-            generate_cpp_properties(&properties, &qobject_idents, &ParsedCxxMappings::default())
-                .unwrap();
+        let generated = generate_cpp_properties(
+            &properties,
+            &qobject_idents,
+            &ParsedCxxMappings::default(),
+            ParsedQPanic::Abort,
+        )
+        .unwrap();
 
         // metaobjects
         assert_eq!(generated.metaobjects.len(), 2);
@@ -288,6 +357,9 @@ mod tests {
         let properties = vec![ParsedQProperty {
             ident: format_ident!("mapped_property"),
             ty: parse_quote! { A1 },
+            revision: None,
+            deprecated: None,
+            flags: QPropertyFlags::default(),
         }];
         let qobject_idents = create_qobjectname();
 
@@ -296,8 +368,13 @@ mod tests {
             .cxx_names
             .insert("A".to_owned(), "A1".to_owned());
 
-        let generated =
-            generate_cpp_properties(&properties, &qobject_idents, &cxx_mapping).unwrap();
+        let generated = generate_cpp_properties(
+            &properties,
+            &qobject_idents,
+            &cxx_mapping,
+            ParsedQPanic::Abort,
+        )
+        .unwrap();
 
         // metaobjects
         assert_eq!(generated.metaobjects.len(), 1);
@@ -393,4 +470,378 @@ mod tests {
         };
         assert_str_eq!(header, "void setMappedPropertyWrapper(A1 value) noexcept;");
     }
+
+    #[test]
+    fn test_generate_cpp_properties_revision() {
+        let properties = vec![ParsedQProperty {
+            ident: format_ident!("revision_property"),
+            ty: parse_quote! { i32 },
+            revision: Some(ParsedQRevision { major: 2, minor: 1 }),
+            deprecated: None,
+            flags: QPropertyFlags::default(),
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let generated = generate_cpp_properties(
+            &properties,
+            &qobject_idents,
+            &ParsedCxxMappings::default(),
+            ParsedQPanic::Abort,
+        )
+        .unwrap();
+
+        assert_eq!(generated.metaobjects.len(), 1);
+        assert_str_eq!(generated.metaobjects[0], "Q_PROPERTY(::std::int32_t revisionProperty READ getRevisionProperty WRITE setRevisionProperty NOTIFY revisionPropertyChanged REVISION(2, 1))");
+
+        let header = if let CppFragment::Header(header) = &generated.methods[2] {
+            header
+        } else {
+            panic!("Expected header!")
+        };
+        assert_str_eq!(
+            header,
+            "Q_SIGNAL Q_REVISION(2, 1) void revisionPropertyChanged();"
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_properties_required_final() {
+        let properties = vec![ParsedQProperty {
+            ident: format_ident!("required_property"),
+            ty: parse_quote! { i32 },
+            revision: Some(ParsedQRevision { major: 2, minor: 1 }),
+            deprecated: None,
+            flags: QPropertyFlags {
+                required: true,
+                is_final: true,
+                ..Default::default()
+            },
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let generated = generate_cpp_properties(
+            &properties,
+            &qobject_idents,
+            &ParsedCxxMappings::default(),
+            ParsedQPanic::Abort,
+        )
+        .unwrap();
+
+        assert_eq!(generated.metaobjects.len(), 1);
+        assert_str_eq!(generated.metaobjects[0], "Q_PROPERTY(::std::int32_t requiredProperty READ getRequiredProperty WRITE setRequiredProperty NOTIFY requiredPropertyChanged REVISION(2, 1) FINAL REQUIRED)");
+    }
+
+    #[test]
+    fn test_generate_cpp_properties_constant() {
+        let properties = vec![ParsedQProperty {
+            ident: format_ident!("constant_property"),
+            ty: parse_quote! { i32 },
+            revision: None,
+            deprecated: None,
+            flags: QPropertyFlags {
+                constant: true,
+                ..Default::default()
+            },
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let generated = generate_cpp_properties(
+            &properties,
+            &qobject_idents,
+            &ParsedCxxMappings::default(),
+            ParsedQPanic::Abort,
+        )
+        .unwrap();
+
+        assert_eq!(generated.metaobjects.len(), 1);
+        assert_str_eq!(
+            generated.metaobjects[0],
+            "Q_PROPERTY(::std::int32_t constantProperty READ getConstantProperty CONSTANT)"
+        );
+
+        // Only the getter is generated, without a setter or notify signal
+        assert_eq!(generated.methods.len(), 1);
+        assert_eq!(generated.private_methods.len(), 1);
+        let header = if let CppFragment::Header(header) = &generated.private_methods[0] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(
+            header,
+            "::std::int32_t const& getConstantPropertyWrapper() const noexcept;"
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_properties_read_only() {
+        let properties = vec![ParsedQProperty {
+            ident: format_ident!("read_only_property"),
+            ty: parse_quote! { i32 },
+            revision: None,
+            deprecated: None,
+            flags: QPropertyFlags {
+                read_only: true,
+                ..Default::default()
+            },
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let generated = generate_cpp_properties(
+            &properties,
+            &qobject_idents,
+            &ParsedCxxMappings::default(),
+            ParsedQPanic::Abort,
+        )
+        .unwrap();
+
+        assert_eq!(generated.metaobjects.len(), 1);
+        assert_str_eq!(
+            generated.metaobjects[0],
+            "Q_PROPERTY(::std::int32_t readOnlyProperty READ getReadOnlyProperty NOTIFY readOnlyPropertyChanged)"
+        );
+
+        // The getter and the notify signal are generated, but not the setter
+        assert_eq!(generated.methods.len(), 3);
+        let header = if let CppFragment::Header(header) = &generated.methods[1] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(header, "Q_SIGNAL void readOnlyPropertyChanged();");
+        assert_eq!(generated.private_methods.len(), 1);
+    }
+
+    #[test]
+    fn test_generate_cpp_properties_computed() {
+        let properties = vec![ParsedQProperty {
+            ident: format_ident!("computed_property"),
+            ty: parse_quote! { i32 },
+            revision: None,
+            deprecated: None,
+            flags: QPropertyFlags {
+                computed: true,
+                ..Default::default()
+            },
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let generated = generate_cpp_properties(
+            &properties,
+            &qobject_idents,
+            &ParsedCxxMappings::default(),
+            ParsedQPanic::Abort,
+        )
+        .unwrap();
+
+        assert_eq!(generated.metaobjects.len(), 1);
+        assert_str_eq!(
+            generated.metaobjects[0],
+            "Q_PROPERTY(::std::int32_t computedProperty READ getComputedProperty NOTIFY computedPropertyChanged)"
+        );
+
+        // The getter and the notify signal are generated, but not the setter
+        assert_eq!(generated.methods.len(), 3);
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[0] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(header, "::std::int32_t getComputedProperty() const;");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            ::std::int32_t
+            MyObject::getComputedProperty() const
+            {
+                const ::rust::cxxqtlib1::MaybeLockGuard<MyObject> guard(*this);
+                return getComputedPropertyWrapper();
+            }
+            "#}
+        );
+
+        // The value is returned by value from the Rust getter
+        assert_eq!(generated.private_methods.len(), 1);
+        let header = if let CppFragment::Header(header) = &generated.private_methods[0] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(
+            header,
+            "::std::int32_t getComputedPropertyWrapper() const noexcept;"
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_properties_reset() {
+        let properties = vec![ParsedQProperty {
+            ident: format_ident!("resettable_property"),
+            ty: parse_quote! { i32 },
+            revision: None,
+            deprecated: None,
+            flags: QPropertyFlags {
+                reset: Some(format_ident!("reset_resettable_property")),
+                ..Default::default()
+            },
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let generated = generate_cpp_properties(
+            &properties,
+            &qobject_idents,
+            &ParsedCxxMappings::default(),
+            ParsedQPanic::Signal,
+        )
+        .unwrap();
+
+        assert_eq!(generated.metaobjects.len(), 1);
+        assert_str_eq!(
+            generated.metaobjects[0],
+            "Q_PROPERTY(::std::int32_t resettableProperty READ getResettableProperty WRITE setResettableProperty RESET resetResettableProperty NOTIFY resettablePropertyChanged)"
+        );
+
+        // The reset slot is generated after the getter
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[1] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(header, "Q_SLOT void resetResettableProperty();");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            void
+            MyObject::resetResettableProperty()
+            {
+                const ::rust::cxxqtlib1::MaybeLockGuard<MyObject> guard(*this);
+                try {
+                  resetResettablePropertyWrapper();
+                } catch (const ::rust::Error& error) {
+                  qWarning("Panic in MyObject::resetResettableProperty: %s", error.what());
+                  Q_EMIT const_cast<MyObject*>(this)->panicked(QString::fromUtf8(error.what()));
+                  return;
+                }
+            }
+            "#}
+        );
+
+        let header = if let CppFragment::Header(header) = &generated.private_methods[1] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(header, "void resetResettablePropertyWrapper();");
+    }
+
+    #[test]
+    fn test_generate_cpp_properties_deprecated() {
+        let properties = vec![ParsedQProperty {
+            ident: format_ident!("old_property"),
+            ty: parse_quote! { i32 },
+            revision: None,
+            deprecated: Some(ParsedQDeprecated {
+                reason: Some("use newProperty".to_owned()),
+            }),
+            flags: QPropertyFlags::default(),
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let generated = generate_cpp_properties(
+            &properties,
+            &qobject_idents,
+            &ParsedCxxMappings::default(),
+            ParsedQPanic::Abort,
+        )
+        .unwrap();
+
+        assert_eq!(generated.metaobjects.len(), 2);
+        assert_str_eq!(
+            generated.metaobjects[1],
+            "Q_CLASSINFO(\"cxx-qt.deprecated.oldProperty\", \"use newProperty\")"
+        );
+
+        let header = if let CppFragment::Pair { header, .. } = &generated.methods[0] {
+            header
+        } else {
+            panic!("Expected pair!")
+        };
+        assert_str_eq!(
+            header,
+            "[[deprecated(\"use newProperty\")]] ::std::int32_t const& getOldProperty() const;"
+        );
+        let header = if let CppFragment::Pair { header, .. } = &generated.methods[1] {
+            header
+        } else {
+            panic!("Expected pair!")
+        };
+        assert_str_eq!(
+            header,
+            "Q_SLOT [[deprecated(\"use newProperty\")]] void setOldProperty(::std::int32_t const& value);"
+        );
+        // The notify signal is emitted by the setter, so is not deprecated
+        let header = if let CppFragment::Header(header) = &generated.methods[2] {
+            header
+        } else {
+            panic!("Expected header!")
+        };
+        assert_str_eq!(header, "Q_SIGNAL void oldPropertyChanged();");
+    }
+
+    #[test]
+    fn test_generate_cpp_properties_panic() {
+        let properties = vec![ParsedQProperty {
+            ident: format_ident!("trivial_property"),
+            ty: parse_quote! { i32 },
+            revision: None,
+            deprecated: None,
+            flags: QPropertyFlags::default(),
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let generated = generate_cpp_properties(
+            &properties,
+            &qobject_idents,
+            &ParsedCxxMappings::default(),
+            ParsedQPanic::Exception,
+        )
+        .unwrap();
+
+        let source = if let CppFragment::Pair { source, .. } = &generated.methods[1] {
+            source
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            void
+            MyObject::setTrivialProperty(::std::int32_t const& value)
+            {
+                const ::rust::cxxqtlib1::MaybeLockGuard<MyObject> guard(*this);
+                try {
+                  setTrivialPropertyWrapper(value);
+                } catch (const ::rust::Error& error) {
+                  if (auto* engine = ::qmlEngine(this)) {
+                    engine->throwError(QString::fromUtf8(error.what()));
+                  } else {
+                    qWarning("Panic in MyObject::setTrivialProperty: %s", error.what());
+                  }
+                  return;
+                }
+            }
+            "#}
+        );
+
+        // The setter wrapper throws a rust::Error when the setter has panicked
+        let header = if let CppFragment::Header(header) = &generated.private_methods[1] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(
+            header,
+            "void setTrivialPropertyWrapper(::std::int32_t value);"
+        );
+    }
 }
@@ -0,0 +1,56 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{cpp::fragment::CppFragment, naming::CombinedIdent, utils::cpp::Indent},
+    parser::{deprecated::ParsedQDeprecated, panic::ParsedQPanic},
+};
+use indoc::formatdoc;
+
+pub fn generate(
+    reset: &CombinedIdent,
+    reset_wrapper: &CombinedIdent,
+    qobject_ident: &str,
+    deprecated: Option<&ParsedQDeprecated>,
+    panic: ParsedQPanic,
+) -> CppFragment {
+    CppFragment::Pair {
+        header: format!(
+            "Q_SLOT {deprecated}void {ident_reset}();",
+            deprecated = deprecated
+                .map(|deprecated| format!("{} ", deprecated.to_cpp_attribute()))
+                .unwrap_or_default(),
+            ident_reset = reset.cpp,
+        ),
+        source: formatdoc! {
+            r#"
+            void
+            {qobject_ident}::{ident_reset}()
+            {{
+                const ::rust::cxxqtlib1::MaybeLockGuard<{qobject_ident}> guard(*this);
+            {body}
+            }}
+            "#,
+            ident_reset = reset.cpp,
+            body = panic
+                .to_cpp_call(
+                    &format!("{ident_reset_wrapper}();", ident_reset_wrapper = reset_wrapper.cpp),
+                    qobject_ident,
+                    &format!("{qobject_ident}::{ident_reset}", ident_reset = reset.cpp),
+                    false,
+                )
+                .indented(4),
+        },
+    }
+}
+
+pub fn generate_wrapper(reset_wrapper: &CombinedIdent, panic: ParsedQPanic) -> CppFragment {
+    CppFragment::Header(format!(
+        "void {ident_reset_wrapper}(){has_noexcept};",
+        ident_reset_wrapper = reset_wrapper.cpp,
+        // A wrapper which catches panics throws them as a rust::Error
+        has_noexcept = if panic.catches() { "" } else { " noexcept" },
+    ))
+}
@@ -3,13 +3,25 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::generator::{cpp::fragment::CppFragment, naming::property::QPropertyName};
+use crate::{
+    generator::{cpp::fragment::CppFragment, naming::property::QPropertyName, utils::cpp::Indent},
+    parser::{deprecated::ParsedQDeprecated, panic::ParsedQPanic},
+};
 use indoc::formatdoc;
 
-pub fn generate(idents: &QPropertyName, qobject_ident: &str, cxx_ty: &str) -> CppFragment {
+pub fn generate(
+    idents: &QPropertyName,
+    qobject_ident: &str,
+    cxx_ty: &str,
+    deprecated: Option<&ParsedQDeprecated>,
+    panic: ParsedQPanic,
+) -> CppFragment {
     CppFragment::Pair {
         header: format!(
-            "Q_SLOT void {ident_setter}({cxx_ty} const& value);",
+            "Q_SLOT {deprecated}void {ident_setter}({cxx_ty} const& value);",
+            deprecated = deprecated
+                .map(|deprecated| format!("{} ", deprecated.to_cpp_attribute()))
+                .unwrap_or_default(),
             ident_setter = idents.setter.cpp,
         ),
         source: formatdoc! {
@@ -18,20 +30,29 @@ pub fn generate(idents: &QPropertyName, qobject_ident: &str, cxx_ty: &str) -> Cp
             {qobject_ident}::{ident_setter}({cxx_ty} const& value)
             {{
                 const ::rust::cxxqtlib1::MaybeLockGuard<{qobject_ident}> guard(*this);
-                {ident_setter_wrapper}(value);
+            {body}
             }}
             "#,
             ident_setter = idents.setter.cpp,
-            ident_setter_wrapper = idents.setter_wrapper.cpp.to_string(),
+            body = panic
+                .to_cpp_call(
+                    &format!("{ident_setter_wrapper}(value);", ident_setter_wrapper = idents.setter_wrapper.cpp),
+                    qobject_ident,
+                    &format!("{qobject_ident}::{ident_setter}", ident_setter = idents.setter.cpp),
+                    false,
+                )
+                .indented(4),
         },
     }
 }
 
-pub fn generate_wrapper(idents: &QPropertyName, cxx_ty: &str) -> CppFragment {
+pub fn generate_wrapper(idents: &QPropertyName, cxx_ty: &str, panic: ParsedQPanic) -> CppFragment {
     CppFragment::Header(format!(
         // Note that we pass T not const T& to Rust so that it is by-value
         // https://github.com/KDAB/cxx-qt/issues/463
-        "void {ident_setter_wrapper}({cxx_ty} value) noexcept;",
-        ident_setter_wrapper = idents.setter_wrapper.cpp
+        "void {ident_setter_wrapper}({cxx_ty} value){has_noexcept};",
+        ident_setter_wrapper = idents.setter_wrapper.cpp,
+        // A wrapper which catches panics throws them as a rust::Error
+        has_noexcept = if panic.catches() { "" } else { " noexcept" },
     ))
 }
@@ -7,10 +7,14 @@ use syn::ForeignItemFn;
 
 use crate::{
     generator::naming::{property::QPropertyName, qobject::QObjectName},
-    parser::signals::ParsedSignal,
+    parser::{revision::ParsedQRevision, signals::ParsedSignal},
 };
 
-pub fn generate(idents: &QPropertyName, qobject_idents: &QObjectName) -> ParsedSignal {
+pub fn generate(
+    idents: &QPropertyName,
+    qobject_idents: &QObjectName,
+    revision: Option<ParsedQRevision>,
+) -> ParsedSignal {
     // We build our signal in the generation phase as we need to use the naming
     // structs to build the signal name
     let cpp_class_rust = &qobject_idents.cpp_class.rust;
@@ -21,9 +25,12 @@ pub fn generate(idents: &QPropertyName, qobject_idents: &QObjectName) -> ParsedS
         #[rust_name = #notify_rust_str]
         fn #notify_cpp(self: Pin<&mut #cpp_class_rust>);
     };
-    ParsedSignal::from_property_method(
+    let mut signal = ParsedSignal::from_property_method(
         method,
         idents.notify.clone(),
         qobject_idents.cpp_class.rust.clone(),
-    )
+    );
+    // The notify signal is added in the same revision as the property
+    signal.revision = revision;
+    signal
 }
@@ -22,7 +22,15 @@ fn generate_definition(qenum: &ParsedQEnum) -> String {
     let enum_values = qenum
         .variants
         .iter()
-        .map(ToString::to_string)
+        .enumerate()
+        .map(|(index, variant)| {
+            // Each variant of flags is a single bit, matching the discriminants on the Rust side
+            if qenum.flags.is_some() {
+                format!("{variant} = {value}", value = 1 << index)
+            } else {
+                variant.to_string()
+            }
+        })
         .collect::<Vec<_>>()
         .join(",\n");
 
@@ -38,6 +46,16 @@ pub fn generate_declaration(qenum: &ParsedQEnum, includes: &mut BTreeSet<String>
 
     let enum_definition = generate_definition(qenum).indented(2);
     let enum_name = &qenum.ident.to_string();
+    let registration = if let Some(flags) = &qenum.flags {
+        includes.insert("#include <QtCore/QFlags>".to_string());
+
+        formatdoc! {r#"
+            Q_DECLARE_FLAGS({flags}, {enum_name})
+            Q_FLAG_NS({flags})
+            Q_DECLARE_OPERATORS_FOR_FLAGS({flags})"# }
+    } else {
+        format!("Q_ENUM_NS({enum_name})")
+    };
     namespaced(
         &qenum.namespace,
         // The declaration must still include Q_NAMESPACE, as otherwise moc will complain.
@@ -46,10 +64,22 @@ pub fn generate_declaration(qenum: &ParsedQEnum, includes: &mut BTreeSet<String>
         &formatdoc! {r#"
             Q_NAMESPACE
             {enum_definition}
-            Q_ENUM_NS({enum_name}) "# },
+            {registration} "# },
     )
 }
 
+/// The name of the type in C++ including its namespace
+fn qualify_name(name: &str, cxx_mappings: &ParsedCxxMappings) -> String {
+    let mut qualified_name = cxx_mappings.cxx(name);
+    // TODO: this is a workaround for cxx_mappings.cxx not always returning a fully-qualified
+    // identifier.
+    // Once https://github.com/KDAB/cxx-qt/issues/619 is fixed, this can be removed.
+    if !qualified_name.starts_with("::") {
+        qualified_name.insert_str(0, "::");
+    }
+    qualified_name
+}
+
 pub fn generate(
     qenums: &[ParsedQEnum],
     cxx_mappings: &ParsedCxxMappings,
@@ -58,27 +88,45 @@ pub fn generate(
 
     for qenum in qenums {
         let enum_name = &qenum.ident.to_string();
-
-        let mut qualified_name = cxx_mappings.cxx(enum_name);
-        // TODO: this is a workaround for cxx_mappings.cxx not always returning a fully-qualified
-        // identifier.
-        // Once https://github.com/KDAB/cxx-qt/issues/619 is fixed, this can be removed.
-        if !qualified_name.starts_with("::") {
-            qualified_name.insert_str(0, "::");
-        }
+        let qualified_name = qualify_name(enum_name, cxx_mappings);
 
         generated.includes.insert("#include <cstdint>".to_string());
         let enum_definition = generate_definition(qenum);
         generated.forward_declares.push(enum_definition.clone());
-        generated.metaobjects.push(formatdoc! {r#"
-            #ifdef Q_MOC_RUN
-            {enum_definition}
-              Q_ENUM({enum_name})
-            #else
-              using {enum_name} = {qualified_name};
-              Q_ENUM({enum_name})
-            #endif
-        "#, enum_definition = enum_definition.indented(2) });
+
+        if let Some(flags) = &qenum.flags {
+            let qualified_flags = qualify_name(&flags.to_string(), cxx_mappings);
+
+            // The QFlags type is declared next to the enum so that it can be used by CXX
+            generated
+                .includes
+                .insert("#include <QtCore/QFlags>".to_string());
+            generated.forward_declares.push(formatdoc! {r#"
+                using {flags} = ::QFlags<{enum_name}>;
+                Q_DECLARE_OPERATORS_FOR_FLAGS({flags})
+            "#});
+            generated.metaobjects.push(formatdoc! {r#"
+                #ifdef Q_MOC_RUN
+                {enum_definition}
+                  Q_DECLARE_FLAGS({flags}, {enum_name})
+                  Q_FLAG({flags})
+                #else
+                  using {enum_name} = {qualified_name};
+                  using {flags} = {qualified_flags};
+                  Q_FLAG({flags})
+                #endif
+            "#, enum_definition = enum_definition.indented(2) });
+        } else {
+            generated.metaobjects.push(formatdoc! {r#"
+                #ifdef Q_MOC_RUN
+                {enum_definition}
+                  Q_ENUM({enum_name})
+                #else
+                  using {enum_name} = {qualified_name};
+                  Q_ENUM({enum_name})
+                #endif
+            "#, enum_definition = enum_definition.indented(2) });
+        }
     }
 
     Ok(generated)
@@ -134,4 +182,68 @@ mod tests {
             generated.forward_declares[0],
         );
     }
+
+    #[test]
+    fn generates_flags() {
+        let qenums = [ParsedQEnum::parse(parse_quote! {
+            #[qflags(MyFlags)]
+            enum MyFlag {
+                A, B, C
+            }
+        })
+        .unwrap()];
+
+        let generated = generate(&qenums, &ParsedCxxMappings::default()).unwrap();
+        assert_eq!(generated.includes.len(), 2);
+        assert!(generated.includes.contains("#include <cstdint>"));
+        assert!(generated.includes.contains("#include <QtCore/QFlags>"));
+        assert_eq!(generated.metaobjects.len(), 1);
+        assert_str_eq!(
+            indoc! {r#"
+                #ifdef Q_MOC_RUN
+                  enum class MyFlag : ::std::int32_t {
+                    A = 1,
+                    B = 2,
+                    C = 4
+                  };
+                  Q_DECLARE_FLAGS(MyFlags, MyFlag)
+                  Q_FLAG(MyFlags)
+                #else
+                  using MyFlag = ::MyFlag;
+                  using MyFlags = ::MyFlags;
+                  Q_FLAG(MyFlags)
+                #endif
+            "#},
+            generated.metaobjects[0],
+        );
+        assert_eq!(generated.forward_declares.len(), 2);
+        assert_str_eq!(
+            indoc! { r#"
+                using MyFlags = ::QFlags<MyFlag>;
+                Q_DECLARE_OPERATORS_FOR_FLAGS(MyFlags)
+            "# },
+            generated.forward_declares[1],
+        );
+    }
+
+    #[test]
+    fn generates_namespaced_flags() {
+        let qenum = ParsedQEnum::parse(parse_quote! {
+            #[qflags(MyFlags)]
+            #[namespace = "my_namespace"]
+            enum MyFlag {
+                A, B
+            }
+        })
+        .unwrap();
+
+        let mut includes = BTreeSet::new();
+        let declaration = generate_declaration(&qenum, &mut includes);
+        assert!(includes.contains("#include <QtCore/QFlags>"));
+        assert!(declaration.contains("A = 1,\n    B = 2\n"));
+        assert!(declaration.contains("Q_DECLARE_FLAGS(MyFlags, MyFlag)\n"));
+        assert!(declaration.contains("Q_FLAG_NS(MyFlags)\n"));
+        assert!(declaration.contains("Q_DECLARE_OPERATORS_FOR_FLAGS(MyFlags)"));
+        assert!(!declaration.contains("Q_ENUM_NS"));
+    }
 }
@@ -0,0 +1,67 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use std::collections::BTreeSet;
+
+use indoc::formatdoc;
+use syn::Ident;
+
+use crate::{generator::cpp::fragment::CppFragment, parser::mappings::ParsedCxxMappings};
+
+/// Generate the declaration and registration of a third-party C++ type as a QMetaType
+///
+/// The type is declared with Q_DECLARE_METATYPE so that it can be stored in a QVariant and used in properties,
+/// for Qt 5 it is also registered by name so that it can be used in queued connections.
+pub fn generate(
+    ident: &Ident,
+    cxx_mappings: &ParsedCxxMappings,
+    includes: &mut BTreeSet<String>,
+) -> CppFragment {
+    includes.insert("#include <QtCore/QMetaType>".to_owned());
+
+    let cpp_ty = cxx_mappings.cxx(&ident.to_string());
+    CppFragment::Pair {
+        header: format!("Q_DECLARE_METATYPE({cpp_ty})"),
+        source: formatdoc! { r#"
+            #if (QT_VERSION < QT_VERSION_CHECK(6, 0, 0))
+            static const int register_{ident} = qRegisterMetaType<{cpp_ty}>("{cpp_ty}");
+            #endif"#
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
+    use quote::format_ident;
+
+    #[test]
+    fn test_generate() {
+        let mut cxx_mappings = ParsedCxxMappings::default();
+        cxx_mappings
+            .namespaces
+            .insert("Money".to_owned(), "company".to_owned());
+        let mut includes = BTreeSet::new();
+
+        let fragment = generate(&format_ident!("Money"), &cxx_mappings, &mut includes);
+        let (header, source) = if let CppFragment::Pair { header, source } = fragment {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(header, "Q_DECLARE_METATYPE(::company::Money)");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            #if (QT_VERSION < QT_VERSION_CHECK(6, 0, 0))
+            static const int register_Money = qRegisterMetaType<::company::Money>("::company::Money");
+            #endif"#}
+        );
+        assert!(includes.contains("#include <QtCore/QMetaType>"));
+    }
+}
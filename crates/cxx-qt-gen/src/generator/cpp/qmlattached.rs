@@ -0,0 +1,107 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{
+        cpp::{fragment::CppFragment, qobject::GeneratedCppQObjectBlocks},
+        naming::qobject::QObjectName,
+    },
+    parser::mappings::ParsedCxxMappings,
+};
+use indoc::formatdoc;
+use syn::{Ident, Result};
+
+pub fn generate(
+    qobject_idents: &QObjectName,
+    attached: &Ident,
+    cxx_mappings: &ParsedCxxMappings,
+) -> Result<GeneratedCppQObjectBlocks> {
+    let mut result = GeneratedCppQObjectBlocks::default();
+
+    let cpp_class = &qobject_idents.cpp_class.cpp;
+    let attached_ty = cxx_mappings.cxx(&attached.to_string());
+
+    // QML_ATTACHED stringifies the type for qmltyperegistrar, so it is not prefixed with ::
+    result.metaobjects.push(format!(
+        "QML_ATTACHED({attached})",
+        attached = attached_ty.trim_start_matches("::")
+    ));
+
+    // The attached object is created with the object it is attached to as its parent,
+    // so that it is destroyed with it
+    result.methods.push(CppFragment::Pair {
+        header: format!("static {attached_ty}* qmlAttachedProperties(QObject* object);"),
+        source: formatdoc! {
+            r#"
+            {attached_ty}*
+            {cpp_class}::qmlAttachedProperties(QObject* object)
+            {{
+              return new {attached_ty}(object);
+            }}
+            "#
+        },
+    });
+
+    result
+        .includes
+        .insert("#include <QtQml/QQmlEngine>".to_owned());
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::naming::qobject::tests::create_qobjectname;
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
+    use quote::format_ident;
+
+    #[test]
+    fn test_generate_cpp_qml_attached() {
+        let qobject_idents = create_qobjectname();
+        let mut cxx_mappings = ParsedCxxMappings::default();
+        cxx_mappings
+            .namespaces
+            .insert("MyAttached".to_owned(), "my_namespace".to_owned());
+
+        let generated =
+            generate(&qobject_idents, &format_ident!("MyAttached"), &cxx_mappings).unwrap();
+
+        // metaobjects
+        assert_eq!(generated.metaobjects.len(), 1);
+        assert_str_eq!(
+            generated.metaobjects[0],
+            "QML_ATTACHED(my_namespace::MyAttached)"
+        );
+
+        // methods
+        assert_eq!(generated.methods.len(), 1);
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[0] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(
+            header,
+            "static ::my_namespace::MyAttached* qmlAttachedProperties(QObject* object);"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            ::my_namespace::MyAttached*
+            MyObject::qmlAttachedProperties(QObject* object)
+            {
+              return new ::my_namespace::MyAttached(object);
+            }
+            "#}
+        );
+
+        // includes
+        assert_eq!(generated.includes.len(), 1);
+        assert!(generated.includes.contains("#include <QtQml/QQmlEngine>"));
+    }
+}
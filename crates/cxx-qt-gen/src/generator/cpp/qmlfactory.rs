@@ -0,0 +1,85 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::generator::{
+    cpp::{fragment::CppFragment, qobject::GeneratedCppQObjectBlocks},
+    naming::qobject::QObjectName,
+};
+use indoc::formatdoc;
+use syn::Result;
+
+pub fn generate(qobject_idents: &QObjectName) -> Result<GeneratedCppQObjectBlocks> {
+    let mut result = GeneratedCppQObjectBlocks::default();
+
+    let cpp_class = &qobject_idents.cpp_class.cpp;
+    let factory_ident = &qobject_idents.qml_factory_new;
+
+    // The factory is declared before the CXX header, so that CXX can bind to it
+    result.forward_declares.push(format!(
+        "{cpp_class}* {factory_ident}(::std::int32_t ownership);"
+    ));
+    result.methods.push(CppFragment::Source(formatdoc! {
+        r#"
+        {cpp_class}*
+        {factory_ident}(::std::int32_t ownership)
+        {{
+          return ::rust::cxxqtlib1::qmlFactoryNew<{cpp_class}>(ownership);
+        }}
+        "#
+    }));
+
+    result
+        .includes
+        .insert("#include <cxx-qt-common/cxxqt_qmlfactory.h>".to_owned());
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::naming::qobject::tests::create_qobjectname;
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
+
+    #[test]
+    fn test_generate_cpp_qml_factory() {
+        let qobject_idents = create_qobjectname();
+
+        let generated = generate(&qobject_idents).unwrap();
+
+        // forward declares
+        assert_eq!(generated.forward_declares.len(), 1);
+        assert_str_eq!(
+            generated.forward_declares[0],
+            "MyObject* cxxQtNewMyObjectWithOwnership(::std::int32_t ownership);"
+        );
+
+        // methods
+        assert_eq!(generated.methods.len(), 1);
+        let source = if let CppFragment::Source(source) = &generated.methods[0] {
+            source
+        } else {
+            panic!("Expected source")
+        };
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            MyObject*
+            cxxQtNewMyObjectWithOwnership(::std::int32_t ownership)
+            {
+              return ::rust::cxxqtlib1::qmlFactoryNew<MyObject>(ownership);
+            }
+            "#}
+        );
+
+        // includes
+        assert_eq!(generated.includes.len(), 1);
+        assert!(generated
+            .includes
+            .contains("#include <cxx-qt-common/cxxqt_qmlfactory.h>"));
+    }
+}
@@ -0,0 +1,116 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::generator::{
+    cpp::{fragment::CppFragment, qobject::GeneratedCppQObjectBlocks},
+    naming::qobject::QObjectName,
+};
+use indoc::formatdoc;
+use syn::Result;
+
+pub fn generate(
+    qobject_idents: &QObjectName,
+    namespace_internals: &str,
+) -> Result<GeneratedCppQObjectBlocks> {
+    let mut result = GeneratedCppQObjectBlocks::default();
+
+    let cpp_class = &qobject_idents.cpp_class.cpp;
+
+    result.base_classes.push("QQmlParserStatus".to_owned());
+    result
+        .metaobjects
+        .push("Q_INTERFACES(QQmlParserStatus)".to_owned());
+
+    // The Rust implementations are exposed with the same name as the virtual method they override
+    for method in ["classBegin", "componentComplete"] {
+        result.methods.push(CppFragment::Pair {
+            header: format!("void {method}() override;"),
+            source: formatdoc! {
+                r#"
+                void
+                {cpp_class}::{method}()
+                {{
+                  const ::rust::cxxqtlib1::MaybeLockGuard<{cpp_class}> guard(*this);
+                  ::{namespace_internals}::{method}(*this);
+                }}
+                "#
+            },
+        });
+    }
+
+    result
+        .includes
+        .insert("#include <QtQml/QQmlParserStatus>".to_owned());
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::naming::qobject::tests::create_qobjectname;
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
+
+    #[test]
+    fn test_generate_cpp_qml_parser_status() {
+        let qobject_idents = create_qobjectname();
+        let generated = generate(&qobject_idents, "cxx_qt_my_object").unwrap();
+
+        // base classes
+        assert_eq!(generated.base_classes, vec!["QQmlParserStatus"]);
+
+        // metaobjects
+        assert_eq!(
+            generated.metaobjects,
+            vec!["Q_INTERFACES(QQmlParserStatus)"]
+        );
+
+        // methods
+        assert_eq!(generated.methods.len(), 2);
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[0] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(header, "void classBegin() override;");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            void
+            MyObject::classBegin()
+            {
+              const ::rust::cxxqtlib1::MaybeLockGuard<MyObject> guard(*this);
+              ::cxx_qt_my_object::classBegin(*this);
+            }
+            "#}
+        );
+
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[1] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(header, "void componentComplete() override;");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            void
+            MyObject::componentComplete()
+            {
+              const ::rust::cxxqtlib1::MaybeLockGuard<MyObject> guard(*this);
+              ::cxx_qt_my_object::componentComplete(*this);
+            }
+            "#}
+        );
+
+        // includes
+        assert_eq!(generated.includes.len(), 1);
+        assert!(generated
+            .includes
+            .contains("#include <QtQml/QQmlParserStatus>"));
+    }
+}
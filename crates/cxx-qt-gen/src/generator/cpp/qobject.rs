@@ -5,13 +5,13 @@
 
 use crate::generator::{
     cpp::{
-        constructor, cxxqttype, fragment::CppFragment, inherit, locking,
-        method::generate_cpp_methods, property::generate_cpp_properties, qenum,
-        signal::generate_cpp_signals, threading,
+        connect, constructor, cxxqttype, fragment::CppFragment, inherit, locking,
+        method::generate_cpp_methods, property::generate_cpp_properties, qenum, qmlattached,
+        qmlfactory, qmlparserstatus, signal::generate_cpp_signals, threading, weakref,
     },
-    naming::{namespace::NamespaceName, qobject::QObjectName},
+    naming::{namespace::NamespaceName, property::QPropertyName, qobject::QObjectName},
 };
-use crate::parser::{mappings::ParsedCxxMappings, qobject::ParsedQObject};
+use crate::parser::{mappings::ParsedCxxMappings, panic::ParsedQPanic, qobject::ParsedQObject};
 use std::collections::BTreeSet;
 use syn::Result;
 
@@ -23,6 +23,8 @@ pub struct GeneratedCppQObjectBlocks {
     pub metaobjects: Vec<String>,
     /// List of public methods for the QObject
     pub methods: Vec<CppFragment>,
+    /// List of protected methods for the QObject
+    pub protected_methods: Vec<CppFragment>,
     /// List of private methods for the QObject
     pub private_methods: Vec<CppFragment>,
     /// List of includes
@@ -36,6 +38,7 @@ impl GeneratedCppQObjectBlocks {
         self.forward_declares.append(&mut other.forward_declares);
         self.metaobjects.append(&mut other.metaobjects);
         self.methods.append(&mut other.methods);
+        self.protected_methods.append(&mut other.protected_methods);
         self.private_methods.append(&mut other.private_methods);
         self.includes.append(&mut other.includes);
         self.base_classes.append(&mut other.base_classes);
@@ -47,19 +50,56 @@ impl GeneratedCppQObjectBlocks {
             // Somehow moc doesn't include the info in metatypes.json that qmltyperegistrar needs
             // when using the QML_ELEMENT/QML_NAMED_ELEMENT macros, but moc works when using what
             // those macros expand to.
+            //
+            // An anonymous element is what QML_ANONYMOUS expands to.
             qml_specifiers.push(format!(
                 "Q_CLASSINFO(\"QML.Element\", \"{}\")",
-                qml_metadata.name
+                if qml_metadata.anonymous {
+                    "anonymous"
+                } else {
+                    &qml_metadata.name
+                }
             ));
 
             if qml_metadata.uncreatable {
                 qml_specifiers.push("Q_CLASSINFO(\"QML.Creatable\", \"false\")".to_owned());
+
+                // This is what QML_UNCREATABLE(reason) expands to
+                if let Some(reason) = &qml_metadata.uncreatable_reason {
+                    qml_specifiers.push(format!(
+                        "Q_CLASSINFO(\"QML.UncreatableReason\", \"{}\")",
+                        reason.replace('\\', "\\\\").replace('"', "\\\"")
+                    ));
+                }
             }
 
             if qml_metadata.singleton {
                 qml_specifiers.push("QML_SINGLETON".to_owned());
             }
+
+            if let Some(added_in) = &qml_metadata.added_in {
+                qml_specifiers.push(format!(
+                    "QML_ADDED_IN_VERSION({args})",
+                    args = added_in.to_cpp_args()
+                ));
+            }
         }
+
+        // QML assigns nested objects to the default property when no property is named
+        if let Some(default_property) = &qobject.default_property {
+            qml_specifiers.push(format!(
+                "Q_CLASSINFO(\"DefaultProperty\", \"{name}\")",
+                name = QPropertyName::from(default_property).name.cpp
+            ));
+        }
+
+        qml_specifiers.extend(
+            qobject
+                .class_infos
+                .iter()
+                .map(|class_info| class_info.to_cpp()),
+        );
+
         GeneratedCppQObjectBlocks {
             metaobjects: qml_specifiers,
             ..Default::default()
@@ -95,7 +135,7 @@ impl GeneratedCppQObject {
             ident: cpp_class.clone(),
             rust_ident: qobject_idents.rust_struct.cpp.to_string(),
             namespace: qobject.namespace.clone(),
-            namespace_internals: namespace_idents.internal,
+            namespace_internals: namespace_idents.internal.clone(),
             blocks: GeneratedCppQObjectBlocks::from(qobject),
         };
 
@@ -122,17 +162,43 @@ impl GeneratedCppQObject {
             &qobject.properties,
             &qobject_idents,
             cxx_mappings,
+            qobject.panic,
         )?);
         generated.blocks.append(&mut generate_cpp_methods(
             &qobject.methods,
             &qobject_idents,
+            &namespace_idents,
             cxx_mappings,
+            qobject.panic,
         )?);
         generated.blocks.append(&mut generate_cpp_signals(
             &qobject.signals,
             &qobject_idents,
             cxx_mappings,
         )?);
+        if let Some(signal) = qobject.panic.signal(&qobject_idents.cpp_class.rust)? {
+            generated.blocks.append(&mut generate_cpp_signals(
+                &vec![signal],
+                &qobject_idents,
+                cxx_mappings,
+            )?);
+        }
+        for qenum in &qobject.qenums {
+            if let Some(signal) = qenum.error_signal(&qobject_idents.cpp_class.rust)? {
+                generated.blocks.append(&mut generate_cpp_signals(
+                    &vec![signal],
+                    &qobject_idents,
+                    cxx_mappings,
+                )?);
+            }
+        }
+        // The QML engine of the QObject is needed to throw a panic as an exception
+        if qobject.panic == ParsedQPanic::Exception {
+            generated
+                .blocks
+                .includes
+                .insert("#include <QtQml/QQmlEngine>".to_owned());
+        }
         generated.blocks.append(&mut inherit::generate(
             &qobject.inherited_methods,
             &qobject.base_class,
@@ -143,6 +209,7 @@ impl GeneratedCppQObject {
             .append(&mut qenum::generate(&qobject.qenums, cxx_mappings)?);
 
         let mut class_initializers = vec![];
+        let mut constructor_statements = vec![];
 
         // If this type has threading enabled then add generation
         //
@@ -161,11 +228,70 @@ impl GeneratedCppQObject {
             class_initializers.push(initializer);
         }
 
+        // If this type has a context in its Rust struct then attach it once the QObject is constructed
+        if qobject.context {
+            constructor_statements.push(format!(
+                "::{namespace_internals}::attachContext(*this);",
+                namespace_internals = generated.namespace_internals
+            ));
+        }
+
+        // If this type has the initialized hook then call it at the end of the constructor
+        if qobject.initialized {
+            constructor_statements.push(format!(
+                "::{namespace_internals}::initialized(*this);",
+                namespace_internals = generated.namespace_internals
+            ));
+        }
+
+        // If this type has weak references enabled then add generation
+        if qobject.weak_ref {
+            generated
+                .blocks
+                .append(&mut weakref::generate(&qobject_idents)?);
+        }
+
+        // If this type has typed connection handles enabled then add generation
+        if qobject.connect {
+            generated
+                .blocks
+                .append(&mut connect::generate(qobject, &qobject_idents)?);
+        }
+
+        // If this type has the QML factory enabled then add generation
+        if qobject.qml_factory {
+            generated
+                .blocks
+                .append(&mut qmlfactory::generate(&qobject_idents)?);
+        }
+
+        // If this type has an attached object then add generation
+        if let Some(attached) = qobject
+            .qml_metadata
+            .as_ref()
+            .and_then(|qml_metadata| qml_metadata.attached.as_ref())
+        {
+            generated.blocks.append(&mut qmlattached::generate(
+                &qobject_idents,
+                attached,
+                cxx_mappings,
+            )?);
+        }
+
+        // If this type implements QQmlParserStatus then add generation
+        if qobject.qml_parser_status {
+            generated.blocks.append(&mut qmlparserstatus::generate(
+                &qobject_idents,
+                &generated.namespace_internals,
+            )?);
+        }
+
         generated.blocks.append(&mut constructor::generate(
             &generated,
             &qobject.constructors,
             base_class,
             &class_initializers,
+            &constructor_statements,
             cxx_mappings,
         )?);
 
@@ -335,4 +461,168 @@ mod tests {
             "Q_CLASSINFO(\"QML.Creatable\", \"false\")"
         );
     }
+
+    #[test]
+    fn test_generated_cpp_qobject_uncreatable_reason() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_element]
+                    #[qml_uncreatable("Created by \"backend\" only")]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        let cpp = GeneratedCppQObject::from(
+            parser.cxx_qt_data.qobjects.values().next().unwrap(),
+            &ParsedCxxMappings::default(),
+        )
+        .unwrap();
+        assert_eq!(cpp.blocks.metaobjects.len(), 3);
+        assert_eq!(
+            cpp.blocks.metaobjects[1],
+            "Q_CLASSINFO(\"QML.Creatable\", \"false\")"
+        );
+        assert_eq!(
+            cpp.blocks.metaobjects[2],
+            "Q_CLASSINFO(\"QML.UncreatableReason\", \"Created by \\\"backend\\\" only\")"
+        );
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_anonymous() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_anonymous]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        let cpp = GeneratedCppQObject::from(
+            parser.cxx_qt_data.qobjects.values().next().unwrap(),
+            &ParsedCxxMappings::default(),
+        )
+        .unwrap();
+        assert_eq!(cpp.blocks.metaobjects.len(), 1);
+        assert_eq!(
+            cpp.blocks.metaobjects[0],
+            "Q_CLASSINFO(\"QML.Element\", \"anonymous\")"
+        );
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_added_in() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_element]
+                    #[qml_added_in(2, 1)]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        let cpp = GeneratedCppQObject::from(
+            parser.cxx_qt_data.qobjects.values().next().unwrap(),
+            &ParsedCxxMappings::default(),
+        )
+        .unwrap();
+        assert_eq!(cpp.blocks.metaobjects.len(), 2);
+        assert_eq!(cpp.blocks.metaobjects[1], "QML_ADDED_IN_VERSION(2, 1)");
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_attached() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_element]
+                    #[qml_attached(MyAttached)]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        let cpp = GeneratedCppQObject::from(
+            parser.cxx_qt_data.qobjects.values().next().unwrap(),
+            &ParsedCxxMappings::default(),
+        )
+        .unwrap();
+        assert_eq!(cpp.blocks.metaobjects.len(), 2);
+        assert_eq!(cpp.blocks.metaobjects[1], "QML_ATTACHED(MyAttached)");
+        assert!(cpp.blocks.includes.contains("#include <QtQml/QQmlEngine>"));
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_default_property() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_element]
+                    #[qml_default_property(child_items)]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        let cpp = GeneratedCppQObject::from(
+            parser.cxx_qt_data.qobjects.values().next().unwrap(),
+            &ParsedCxxMappings::default(),
+        )
+        .unwrap();
+        assert_eq!(cpp.blocks.metaobjects.len(), 2);
+        assert_eq!(
+            cpp.blocks.metaobjects[1],
+            "Q_CLASSINFO(\"DefaultProperty\", \"childItems\")"
+        );
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_class_infos() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qclassinfo(key = "D-Bus Interface", value = "com.kdab.Example")]
+                    #[qclassinfo(key = "Version", value = "1.0")]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        let cpp = GeneratedCppQObject::from(
+            parser.cxx_qt_data.qobjects.values().next().unwrap(),
+            &ParsedCxxMappings::default(),
+        )
+        .unwrap();
+        assert_eq!(cpp.blocks.metaobjects.len(), 2);
+        assert_eq!(
+            cpp.blocks.metaobjects[0],
+            "Q_CLASSINFO(\"D-Bus Interface\", \"com.kdab.Example\")"
+        );
+        assert_eq!(
+            cpp.blocks.metaobjects[1],
+            "Q_CLASSINFO(\"Version\", \"1.0\")"
+        );
+    }
 }
@@ -143,30 +143,49 @@ pub fn generate_cpp_signals(
 
         // Generate the Q_SIGNAL if this is not an existing signal
         if !signal.inherit {
+            let revision = if let Some(revision) = signal.revision {
+                format!("Q_REVISION({args}) ", args = revision.to_cpp_args())
+            } else {
+                "".to_owned()
+            };
+            let deprecated = if let Some(deprecated) = &signal.deprecated {
+                generated
+                    .metaobjects
+                    .push(deprecated.to_cpp_class_info(&signal_ident));
+                format!("{} ", deprecated.to_cpp_attribute())
+            } else {
+                "".to_owned()
+            };
             generated.methods.push(CppFragment::Header(format!(
-                "Q_SIGNAL void {signal_ident}({parameters_types_signal});"
+                "Q_SIGNAL {revision}{deprecated}void {signal_ident}({parameters_types_signal});"
             )));
         }
 
+        let source = formatdoc! {
+            r#"
+            ::QMetaObject::Connection
+            {qobject_ident}::{connect_ident}(::rust::Fn<void({parameters_types_closure})> func, ::Qt::ConnectionType type)
+            {{
+                return ::QObject::connect(this,
+                    &{qobject_ident}::{signal_ident},
+                    this,
+                    [&, func = ::std::move(func)]({parameters_types_signal}) {{
+                        const ::rust::cxxqtlib1::MaybeLockGuard<{qobject_ident}> guard(*this);
+                        func({parameters_values_closure});
+                    }},
+                    type);
+            }}
+            "#,
+        };
         generated.methods.push(CppFragment::Pair {
             header: format!(
                 "::QMetaObject::Connection {connect_ident}(::rust::Fn<void({parameters_types_closure})> func, ::Qt::ConnectionType type);",
             ),
-            source: formatdoc! {
-                r#"
-                ::QMetaObject::Connection
-                {qobject_ident}::{connect_ident}(::rust::Fn<void({parameters_types_closure})> func, ::Qt::ConnectionType type)
-                {{
-                    return ::QObject::connect(this,
-                        &{qobject_ident}::{signal_ident},
-                        this,
-                        [&, func = ::std::move(func)]({parameters_types_signal}) {{
-                            const ::rust::cxxqtlib1::MaybeLockGuard<{qobject_ident}> guard(*this);
-                            func({parameters_values_closure});
-                        }},
-                        type);
-                }}
-                "#,
+            // Connecting to a deprecated signal is not a use by the consumer, so don't warn
+            source: if signal.deprecated.is_some() {
+                format!("QT_WARNING_PUSH\nQT_WARNING_DISABLE_DEPRECATED\n{source}QT_WARNING_POP\n")
+            } else {
+                source
             },
         });
     }
@@ -179,7 +198,7 @@ mod tests {
     use super::*;
 
     use crate::generator::naming::{qobject::tests::create_qobjectname, CombinedIdent};
-    use crate::parser::parameter::ParsedFunctionParameter;
+    use crate::parser::{deprecated::ParsedQDeprecated, parameter::ParsedFunctionParameter};
     use indoc::indoc;
     use pretty_assertions::assert_str_eq;
     use quote::format_ident;
@@ -210,6 +229,8 @@ mod tests {
             safe: true,
             inherit: false,
             private: false,
+            revision: None,
+            deprecated: None,
         }];
         let qobject_idents = create_qobjectname();
 
@@ -274,6 +295,8 @@ mod tests {
             safe: true,
             inherit: false,
             private: false,
+            revision: None,
+            deprecated: None,
         }];
         let qobject_idents = create_qobjectname();
 
@@ -320,6 +343,73 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_generate_cpp_signals_emit_without_wrapper() {
+        let signals = vec![ParsedSignal {
+            method: parse_quote! {
+                fn moved(self: Pin<&mut MyObject>, index: i32, point: QPointF, name: &QString);
+            },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: true,
+            parameters: vec![
+                ParsedFunctionParameter {
+                    ident: format_ident!("index"),
+                    ty: parse_quote! { i32 },
+                },
+                ParsedFunctionParameter {
+                    ident: format_ident!("point"),
+                    ty: parse_quote! { QPointF },
+                },
+                ParsedFunctionParameter {
+                    ident: format_ident!("name"),
+                    ty: parse_quote! { &QString },
+                },
+            ],
+            ident: CombinedIdent {
+                cpp: format_ident!("moved"),
+                rust: format_ident!("moved"),
+            },
+            safe: true,
+            inherit: false,
+            private: false,
+            revision: None,
+            deprecated: None,
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let generated =
+            generate_cpp_signals(&signals, &qobject_idents, &ParsedCxxMappings::default()).unwrap();
+
+        // The signal is only declared, so emitting it from Rust calls the Q_SIGNAL directly
+        // without any generated C++ in between which could copy or convert the arguments
+        assert_eq!(generated.methods.len(), 2);
+        let header = if let CppFragment::Header(header) = &generated.methods[0] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(
+            header,
+            "Q_SIGNAL void moved(::std::int32_t index, QPointF point, QString const& name);"
+        );
+
+        // The only source is the connection, which passes the arguments to the handler as they are
+        let source = if let CppFragment::Pair { source, .. } = &generated.methods[1] {
+            source
+        } else {
+            panic!("Expected Pair")
+        };
+        assert!(source.starts_with("::QMetaObject::Connection\nMyObject::movedConnect("));
+        assert!(source.contains(
+            "[&, func = ::std::move(func)](::std::int32_t index, QPointF point, QString const& name) {"
+        ));
+        assert!(source
+            .contains("func(*this, ::std::move(index), ::std::move(point), ::std::move(name));"));
+        for allocation in ["new ", "make_shared", "make_unique", "fromUtf8", "QString("] {
+            assert!(!source.contains(allocation));
+        }
+    }
+
     #[test]
     fn test_generate_cpp_signals_existing_cxx_name() {
         let signals = vec![ParsedSignal {
@@ -337,6 +427,8 @@ mod tests {
             safe: true,
             inherit: true,
             private: false,
+            revision: None,
+            deprecated: None,
         }];
         let qobject_idents = create_qobjectname();
 
@@ -386,6 +478,8 @@ mod tests {
             safe: true,
             inherit: false,
             private: false,
+            revision: None,
+            deprecated: None,
         };
 
         let generated = generate_cpp_free_signal(&signal, &ParsedCxxMappings::default()).unwrap();
@@ -441,6 +535,8 @@ mod tests {
             safe: true,
             inherit: false,
             private: false,
+            revision: None,
+            deprecated: None,
         };
 
         let mut cxx_mappings = ParsedCxxMappings::default();
@@ -486,4 +582,69 @@ mod tests {
             "#}
         );
     }
+
+    #[test]
+    fn test_generate_cpp_signals_deprecated() {
+        let signals = vec![ParsedSignal {
+            method: parse_quote! {
+                fn ready(self: Pin<&mut MyObject>);
+            },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: true,
+            parameters: vec![],
+            ident: CombinedIdent {
+                cpp: format_ident!("ready"),
+                rust: format_ident!("ready"),
+            },
+            safe: true,
+            inherit: false,
+            private: false,
+            revision: None,
+            deprecated: Some(ParsedQDeprecated { reason: None }),
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let generated =
+            generate_cpp_signals(&signals, &qobject_idents, &ParsedCxxMappings::default()).unwrap();
+
+        assert_eq!(generated.metaobjects.len(), 1);
+        assert_str_eq!(
+            generated.metaobjects[0],
+            "Q_CLASSINFO(\"cxx-qt.deprecated.ready\", \"\")"
+        );
+
+        assert_eq!(generated.methods.len(), 2);
+        let header = if let CppFragment::Header(header) = &generated.methods[0] {
+            header
+        } else {
+            panic!("Expected header")
+        };
+        assert_str_eq!(header, "Q_SIGNAL [[deprecated]] void ready();");
+
+        let source = if let CppFragment::Pair { source, .. } = &generated.methods[1] {
+            source
+        } else {
+            panic!("Expected Pair")
+        };
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            QT_WARNING_PUSH
+            QT_WARNING_DISABLE_DEPRECATED
+            ::QMetaObject::Connection
+            MyObject::readyConnect(::rust::Fn<void(MyObject&)> func, ::Qt::ConnectionType type)
+            {
+                return ::QObject::connect(this,
+                    &MyObject::ready,
+                    this,
+                    [&, func = ::std::move(func)]() {
+                        const ::rust::cxxqtlib1::MaybeLockGuard<MyObject> guard(*this);
+                        func(*this);
+                    },
+                    type);
+            }
+            QT_WARNING_POP
+            "#}
+        );
+    }
 }
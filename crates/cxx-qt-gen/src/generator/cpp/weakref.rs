@@ -0,0 +1,80 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::generator::{
+    cpp::{fragment::CppFragment, qobject::GeneratedCppQObjectBlocks},
+    naming::qobject::QObjectName,
+};
+use indoc::formatdoc;
+use syn::Result;
+
+pub fn generate(qobject_idents: &QObjectName) -> Result<GeneratedCppQObjectBlocks> {
+    let mut result = GeneratedCppQObjectBlocks::default();
+
+    let cpp_class = &qobject_idents.cpp_class.cpp;
+    let weak_ref_ident = &qobject_idents.weak_ref_class;
+
+    result
+        .forward_declares
+        .push(format!("using {weak_ref_ident} = ::QPointer<{cpp_class}>;"));
+    // Ensure that the QObjectWeakRef<T> is of the correct size and alignment
+    // which should be a QPointer<T> which is two pointers
+    result.methods.push(CppFragment::Source(formatdoc! {
+        r#"
+        static_assert(alignof({weak_ref_ident}) <= alignof(::std::size_t), "unexpected aligment");
+        static_assert(sizeof({weak_ref_ident}) == sizeof(::std::size_t[2]), "unexpected size");
+        "#
+    }));
+
+    result
+        .includes
+        .insert("#include <cxx-qt-common/cxxqt_weakref.h>".to_owned());
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::naming::qobject::tests::create_qobjectname;
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
+
+    #[test]
+    fn test_generate_cpp_weak_ref() {
+        let qobject_idents = create_qobjectname();
+
+        let generated = generate(&qobject_idents).unwrap();
+
+        // forward declares
+        assert_eq!(generated.forward_declares.len(), 1);
+        assert_str_eq!(
+            generated.forward_declares[0],
+            "using MyObjectQObjectWeakRef = ::QPointer<MyObject>;"
+        );
+
+        // methods
+        assert_eq!(generated.methods.len(), 1);
+        let source = if let CppFragment::Source(source) = &generated.methods[0] {
+            source
+        } else {
+            panic!("Expected source")
+        };
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            static_assert(alignof(MyObjectQObjectWeakRef) <= alignof(::std::size_t), "unexpected aligment");
+            static_assert(sizeof(MyObjectQObjectWeakRef) == sizeof(::std::size_t[2]), "unexpected size");
+            "#}
+        );
+
+        // includes
+        assert_eq!(generated.includes.len(), 1);
+        assert!(generated
+            .includes
+            .contains("#include <cxx-qt-common/cxxqt_weakref.h>"));
+    }
+}
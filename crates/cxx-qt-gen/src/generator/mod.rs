@@ -7,3 +7,4 @@ pub mod cpp;
 pub mod naming;
 pub mod rust;
 pub mod utils;
+pub mod warnings;
@@ -60,6 +60,12 @@ mod tests {
             parameters: vec![],
             specifiers: HashSet::new(),
             is_qinvokable: true,
+            revision: None,
+            deprecated: None,
+            ownership: None,
+            access: Default::default(),
+            is_static: false,
+            conversions: Default::default(),
         };
 
         let invokable = QMethodName::from(&parsed);
@@ -16,6 +16,8 @@ pub struct QPropertyName {
     pub setter: CombinedIdent,
     pub setter_wrapper: CombinedIdent,
     pub notify: CombinedIdent,
+    pub reset: Option<CombinedIdent>,
+    pub reset_wrapper: Option<CombinedIdent>,
 }
 
 impl From<&Ident> for QPropertyName {
@@ -29,13 +31,35 @@ impl From<&Ident> for QPropertyName {
             setter_wrapper: CombinedIdent::wrapper_from_combined_property(&setter),
             setter,
             notify: CombinedIdent::notify_from_property(ident),
+            reset: None,
+            reset_wrapper: None,
         }
     }
 }
 
 impl From<&ParsedQProperty> for QPropertyName {
     fn from(property: &ParsedQProperty) -> Self {
-        Self::from(&property.ident)
+        let mut names = Self::from(&property.ident);
+
+        // Custom names are used for both Rust and C++, with the C++ name in camelCase
+        if let Some(read) = &property.flags.read {
+            names.getter = CombinedIdent::from_rust_function(read.clone());
+            names.getter_wrapper = CombinedIdent::wrapper_from_combined_property(&names.getter);
+        }
+        if let Some(write) = &property.flags.write {
+            names.setter = CombinedIdent::from_rust_function(write.clone());
+            names.setter_wrapper = CombinedIdent::wrapper_from_combined_property(&names.setter);
+        }
+        if let Some(notify) = &property.flags.notify {
+            names.notify = CombinedIdent::from_rust_function(notify.clone());
+        }
+        if let Some(reset) = &property.flags.reset {
+            let reset = CombinedIdent::from_rust_function(reset.clone());
+            names.reset_wrapper = Some(CombinedIdent::wrapper_from_combined_property(&reset));
+            names.reset = Some(reset);
+        }
+
+        names
     }
 }
 
@@ -88,12 +112,16 @@ pub mod tests {
     use syn::parse_quote;
 
     use super::*;
+    use crate::parser::property::QPropertyFlags;
 
     pub fn create_i32_qpropertyname() -> QPropertyName {
         let ty: syn::Type = parse_quote! { i32 };
         let property = ParsedQProperty {
             ident: format_ident!("my_property"),
             ty,
+            revision: None,
+            deprecated: None,
+            flags: QPropertyFlags::default(),
         };
         QPropertyName::from(&property)
     }
@@ -110,4 +138,44 @@ pub mod tests {
         assert_eq!(names.notify.cpp, format_ident!("myPropertyChanged"));
         assert_eq!(names.notify.rust, format_ident!("my_property_changed"));
     }
+
+    #[test]
+    fn test_parsed_property_custom_names() {
+        let property = ParsedQProperty {
+            ident: format_ident!("name"),
+            ty: parse_quote! { QString },
+            revision: None,
+            deprecated: None,
+            flags: QPropertyFlags {
+                read: Some(format_ident!("display_name")),
+                write: Some(format_ident!("set_display_name")),
+                notify: Some(format_ident!("name_edited")),
+                reset: Some(format_ident!("reset_name")),
+                ..Default::default()
+            },
+        };
+        let names = QPropertyName::from(&property);
+        assert_eq!(names.name.cpp, format_ident!("name"));
+        assert_eq!(names.getter.cpp, format_ident!("displayName"));
+        assert_eq!(names.getter.rust, format_ident!("display_name"));
+        assert_eq!(
+            names.getter_wrapper.cpp,
+            format_ident!("displayNameWrapper")
+        );
+        assert_eq!(names.setter.cpp, format_ident!("setDisplayName"));
+        assert_eq!(names.setter.rust, format_ident!("set_display_name"));
+        assert_eq!(
+            names.setter_wrapper.cpp,
+            format_ident!("setDisplayNameWrapper")
+        );
+        assert_eq!(names.notify.cpp, format_ident!("nameEdited"));
+        assert_eq!(names.notify.rust, format_ident!("name_edited"));
+        let reset = names.reset.unwrap();
+        assert_eq!(reset.cpp, format_ident!("resetName"));
+        assert_eq!(reset.rust, format_ident!("reset_name"));
+        assert_eq!(
+            names.reset_wrapper.unwrap().cpp,
+            format_ident!("resetNameWrapper")
+        );
+    }
 }
@@ -20,6 +20,10 @@ pub struct QObjectName {
     pub cxx_qt_thread_class: Ident,
     /// The name of the Rust closure wrapper to be passed in to CxxQtThread
     pub cxx_qt_thread_queued_fn_struct: Ident,
+    /// The name of the QObjectWeakRef
+    pub weak_ref_class: Ident,
+    /// The name of the C++ factory which creates the QObject with a QML ownership
+    pub qml_factory_new: Ident,
 }
 
 impl From<&ParsedQObject> for QObjectName {
@@ -38,6 +42,8 @@ impl QObjectName {
             rust_struct: CombinedIdent::from_ident(ident_right),
             cxx_qt_thread_class: cxx_qt_thread_class_from_ident(&ident_left),
             cxx_qt_thread_queued_fn_struct: cxx_qt_thread_queued_fn_struct_from_ident(&ident_left),
+            weak_ref_class: weak_ref_class_from_ident(&ident_left),
+            qml_factory_new: qml_factory_new_from_ident(&ident_left),
             ident: ident_left,
         }
     }
@@ -61,6 +67,16 @@ fn cxx_qt_thread_queued_fn_struct_from_ident(ident: &Ident) -> Ident {
     format_ident!("{ident}CxxQtThreadQueuedFn")
 }
 
+/// For a given ident generate the QObjectWeakRef ident
+fn weak_ref_class_from_ident(ident: &Ident) -> Ident {
+    format_ident!("{ident}QObjectWeakRef")
+}
+
+/// For a given ident generate the QML factory ident
+fn qml_factory_new_from_ident(ident: &Ident) -> Ident {
+    format_ident!("cxxQtNew{ident}WithOwnership")
+}
+
 impl CombinedIdent {
     /// For a given ident generate the Rust and C++ names
     fn from_ident(ident: Ident) -> Self {
@@ -96,6 +112,14 @@ pub mod tests {
             names.cxx_qt_thread_queued_fn_struct,
             format_ident!("MyObjectCxxQtThreadQueuedFn")
         );
+        assert_eq!(
+            names.weak_ref_class,
+            format_ident!("MyObjectQObjectWeakRef")
+        );
+        assert_eq!(
+            names.qml_factory_new,
+            format_ident!("cxxQtNewMyObjectWithOwnership")
+        );
 
         assert_eq!(
             names.cxx_qt_thread_method("threading_clone"),
@@ -62,6 +62,8 @@ mod tests {
             safe: true,
             inherit: false,
             private: false,
+            revision: None,
+            deprecated: None,
         };
 
         let names = QSignalName::from(&qsignal);
@@ -92,6 +94,8 @@ mod tests {
             safe: true,
             inherit: false,
             private: false,
+            revision: None,
+            deprecated: None,
         };
 
         let names = QSignalName::from(&qsignal);
@@ -0,0 +1,334 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use std::collections::BTreeMap;
+
+use crate::{
+    generator::{
+        naming::{
+            method::QMethodName, property::QPropertyName, qobject::QObjectName,
+            signals::QSignalName,
+        },
+        rust::{fragment::RustFragmentPair, qobject::GeneratedRustQObject},
+        utils::rust::{syn_ident_cxx_bridge_to_qualified_impl, syn_type_cxx_bridge_to_qualified},
+    },
+    parser::{
+        method::ParsedMethodAccess, parameter::ParsedFunctionParameter, qobject::ParsedQObject,
+    },
+};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Ident, Path, Result};
+
+/// The `fn(A, B)` type which describes the parameters of a signal or slot
+fn parameters_fn_type(
+    parameters: &[ParsedFunctionParameter],
+    qualified_mappings: &BTreeMap<Ident, Path>,
+) -> TokenStream {
+    let types = parameters
+        .iter()
+        .map(|parameter| syn_type_cxx_bridge_to_qualified(&parameter.ty, qualified_mappings));
+    quote! { fn(#(#types),*) }
+}
+
+pub fn generate(
+    qobject: &ParsedQObject,
+    qobject_idents: &QObjectName,
+    qualified_mappings: &BTreeMap<Ident, Path>,
+    module_ident: &Ident,
+) -> Result<GeneratedRustQObject> {
+    let mut blocks = GeneratedRustQObject::default();
+
+    let cpp_struct_ident = &qobject_idents.cpp_class.rust;
+    let upcast = qobject_idents.cxx_qt_thread_method("upcast");
+    let upcast_mut = qobject_idents.cxx_qt_thread_method("upcast_mut");
+    let qualified_impl =
+        syn_ident_cxx_bridge_to_qualified_impl(cpp_struct_ident, qualified_mappings);
+
+    let mut fragment = RustFragmentPair {
+        cxx_bridge: vec![quote! {
+            unsafe extern "C++" {
+                include!("cxx-qt-lib/qobjectconnect.h");
+
+                #[doc(hidden)]
+                #[namespace = "rust::cxxqtlib1"]
+                #[cxx_name = "qobjectUpcast"]
+                fn #upcast(object: &#cpp_struct_ident) -> &CxxQtQObject;
+
+                #[doc(hidden)]
+                #[namespace = "rust::cxxqtlib1"]
+                #[cxx_name = "qobjectUpcastMut"]
+                fn #upcast_mut(object: Pin<&mut #cpp_struct_ident>) -> Pin<&mut CxxQtQObject>;
+            }
+        }],
+        implementation: vec![quote! {
+            impl cxx_qt_lib::Connect for #qualified_impl {
+                fn as_qobject(&self) -> &cxx_qt_lib::QObject
+                {
+                    #module_ident::#upcast(self)
+                }
+
+                fn as_qobject_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut cxx_qt_lib::QObject>
+                {
+                    #module_ident::#upcast_mut(self)
+                }
+            }
+        }],
+    };
+
+    // The signals, including the notify signals of the properties
+    let mut signals = qobject
+        .signals
+        .iter()
+        .filter(|signal| !signal.private)
+        .map(|signal| {
+            let idents = QSignalName::from(signal);
+            (
+                idents.name,
+                parameters_fn_type(&signal.parameters, qualified_mappings),
+                signal.parameters.len(),
+            )
+        })
+        .collect::<Vec<_>>();
+    signals.extend(
+        qobject
+            .properties
+            .iter()
+            .filter(|property| !property.flags.constant)
+            .map(|property| (QPropertyName::from(property).notify, quote! { fn() }, 0)),
+    );
+    for (name, args, parameter_count) in signals {
+        let signal_ident = format_ident!("{}_signal", name.rust);
+        let signal_name_cpp_str = name.cpp.to_string();
+        fragment.implementation.push(quote! {
+            impl #qualified_impl {
+                #[doc = "Returns the signal "]
+                #[doc = #signal_name_cpp_str]
+                #[doc = ", which can be connected to a signal or invokable of another QObject with [cxx_qt_lib::connect_signal_to]."]
+                pub fn #signal_ident() -> cxx_qt_lib::QSignal<Self, #args>
+                {
+                    unsafe { cxx_qt_lib::QSignal::new_unchecked(#signal_name_cpp_str, #parameter_count) }
+                }
+            }
+        });
+    }
+
+    // Only public invokables can be invoked by a connection
+    for method in qobject.methods.iter().filter(|method| {
+        method.is_qinvokable && !method.is_static && method.access == ParsedMethodAccess::Public
+    }) {
+        let name = QMethodName::from(method).name;
+        let slot_ident = format_ident!("{}_slot", name.rust);
+        let slot_name_cpp_str = name.cpp.to_string();
+        let args = parameters_fn_type(&method.parameters, qualified_mappings);
+        fragment.implementation.push(quote! {
+            impl #qualified_impl {
+                #[doc = "Returns the invokable "]
+                #[doc = #slot_name_cpp_str]
+                #[doc = ", which can be connected to a signal of another QObject with [cxx_qt_lib::connect_signal_to]."]
+                pub fn #slot_ident() -> cxx_qt_lib::QSlot<Self, #args>
+                {
+                    unsafe { cxx_qt_lib::QSlot::new_unchecked(#slot_name_cpp_str) }
+                }
+            }
+        });
+    }
+
+    for property in qobject
+        .properties
+        .iter()
+        .filter(|property| property.is_bindable())
+    {
+        let idents = QPropertyName::from(property);
+        let property_ident = format_ident!("{}_property", idents.name.rust);
+        let property_name_cpp_str = idents.name.cpp.to_string();
+        let bind_ident_cpp_str = format!("{}BindTo", idents.name.cpp);
+        let bind_ident_rust = format_ident!("cxx_qt_ffi_{}_bind_to", idents.name.rust);
+        let ty = syn_type_cxx_bridge_to_qualified(&property.ty, qualified_mappings);
+        fragment.cxx_bridge.push(quote! {
+            unsafe extern "C++" {
+                #[doc(hidden)]
+                #[cxx_name = #bind_ident_cpp_str]
+                fn #bind_ident_rust(self: &#cpp_struct_ident, target: Pin<&mut CxxQtQObject>, property: &str) -> Result<CxxQtQMetaObjectConnection>;
+            }
+        });
+        fragment.implementation.push(quote! {
+            impl #qualified_impl {
+                #[doc = "Returns the property "]
+                #[doc = #property_name_cpp_str]
+                #[doc = ", which can be bound to a property of another QObject with [cxx_qt_lib::bind_property]."]
+                pub fn #property_ident() -> cxx_qt_lib::QProperty<Self, #ty>
+                {
+                    unsafe { cxx_qt_lib::QProperty::new_unchecked(#property_name_cpp_str, Self::#bind_ident_rust) }
+                }
+            }
+        });
+    }
+
+    blocks
+        .cxx_mod_contents
+        .append(&mut fragment.cxx_bridge_as_items()?);
+    blocks
+        .cxx_qt_mod_contents
+        .append(&mut fragment.implementation_as_items()?);
+
+    Ok(blocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::parser::Parser;
+    use crate::tests::assert_tokens_eq;
+    use syn::parse_quote;
+
+    fn generate_connect(module: syn::ItemMod) -> GeneratedRustQObject {
+        let parser = Parser::from(module).unwrap();
+        let qobject = parser.cxx_qt_data.qobjects.values().next().unwrap();
+        assert!(qobject.connect);
+        generate(
+            qobject,
+            &QObjectName::from(qobject),
+            &parser.cxx_qt_data.cxx_mappings.qualified,
+            &format_ident!("ffi"),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_generate_connect() {
+        let generated = generate_connect(parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(i32, number)]
+                    #[qproperty(i32, version, constant)]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qsignal]
+                    fn data_changed(self: Pin<&mut MyObject>, first: i32, last: i32);
+
+                    #[qinvokable]
+                    fn refresh(self: Pin<&mut MyObject>, first: i32, last: i32);
+
+                    #[qinvokable(access = "protected")]
+                    fn hidden(self: &MyObject);
+
+                    fn not_invokable(self: &MyObject);
+                }
+
+                impl cxx_qt_lib::Connect for MyObject {}
+            }
+        });
+
+        assert_eq!(generated.cxx_mod_contents.len(), 2);
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                unsafe extern "C++" {
+                    include!("cxx-qt-lib/qobjectconnect.h");
+
+                    #[doc(hidden)]
+                    #[namespace = "rust::cxxqtlib1"]
+                    #[cxx_name = "qobjectUpcast"]
+                    fn cxx_qt_ffi_my_object_upcast(object: &MyObject) -> &CxxQtQObject;
+
+                    #[doc(hidden)]
+                    #[namespace = "rust::cxxqtlib1"]
+                    #[cxx_name = "qobjectUpcastMut"]
+                    fn cxx_qt_ffi_my_object_upcast_mut(object: Pin<&mut MyObject>) -> Pin<&mut CxxQtQObject>;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[1],
+            quote! {
+                unsafe extern "C++" {
+                    #[doc(hidden)]
+                    #[cxx_name = "numberBindTo"]
+                    fn cxx_qt_ffi_number_bind_to(self: &MyObject, target: Pin<&mut CxxQtQObject>, property: &str) -> Result<CxxQtQMetaObjectConnection>;
+                }
+            },
+        );
+
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 5);
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl cxx_qt_lib::Connect for ffi::MyObject {
+                    fn as_qobject(&self) -> &cxx_qt_lib::QObject
+                    {
+                        ffi::cxx_qt_ffi_my_object_upcast(self)
+                    }
+
+                    fn as_qobject_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut cxx_qt_lib::QObject>
+                    {
+                        ffi::cxx_qt_ffi_my_object_upcast_mut(self)
+                    }
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[1],
+            quote! {
+                impl ffi::MyObject {
+                    #[doc = "Returns the signal "]
+                    #[doc = "dataChanged"]
+                    #[doc = ", which can be connected to a signal or invokable of another QObject with [cxx_qt_lib::connect_signal_to]."]
+                    pub fn data_changed_signal() -> cxx_qt_lib::QSignal<Self, fn(i32, i32)>
+                    {
+                        unsafe { cxx_qt_lib::QSignal::new_unchecked("dataChanged", 2usize) }
+                    }
+                }
+            },
+        );
+        // The constant property has no notify signal
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[2],
+            quote! {
+                impl ffi::MyObject {
+                    #[doc = "Returns the signal "]
+                    #[doc = "numberChanged"]
+                    #[doc = ", which can be connected to a signal or invokable of another QObject with [cxx_qt_lib::connect_signal_to]."]
+                    pub fn number_changed_signal() -> cxx_qt_lib::QSignal<Self, fn()>
+                    {
+                        unsafe { cxx_qt_lib::QSignal::new_unchecked("numberChanged", 0usize) }
+                    }
+                }
+            },
+        );
+        // Protected invokables and methods which are not invokable cannot be connected
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[3],
+            quote! {
+                impl ffi::MyObject {
+                    #[doc = "Returns the invokable "]
+                    #[doc = "refresh"]
+                    #[doc = ", which can be connected to a signal of another QObject with [cxx_qt_lib::connect_signal_to]."]
+                    pub fn refresh_slot() -> cxx_qt_lib::QSlot<Self, fn(i32, i32)>
+                    {
+                        unsafe { cxx_qt_lib::QSlot::new_unchecked("refresh") }
+                    }
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[4],
+            quote! {
+                impl ffi::MyObject {
+                    #[doc = "Returns the property "]
+                    #[doc = "number"]
+                    #[doc = ", which can be bound to a property of another QObject with [cxx_qt_lib::bind_property]."]
+                    pub fn number_property() -> cxx_qt_lib::QProperty<Self, i32>
+                    {
+                        unsafe { cxx_qt_lib::QProperty::new_unchecked("number", Self::cxx_qt_ffi_number_bind_to) }
+                    }
+                }
+            },
+        );
+    }
+}
@@ -0,0 +1,640 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use std::collections::BTreeMap;
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Ident, Path};
+
+use crate::{
+    generator::{
+        rust::fragment::RustFragmentPair, utils::rust::syn_ident_cxx_bridge_to_qualified_impl,
+    },
+    parser::container::ParsedContainer,
+};
+
+/// The Rust name of a C++ function of the container in the CXX bridge
+///
+/// CXX uses the Rust name for the symbol, so the name includes the type id of the container
+/// to avoid clashes between containers of types with the same name in different namespaces.
+struct ContainerFunctions {
+    prefix: String,
+}
+
+impl ContainerFunctions {
+    fn new(type_id: &str) -> Self {
+        Self {
+            prefix: format!("cxx_qt_{}", type_id.replace("::", "_")),
+        }
+    }
+
+    /// The ident of the function that is used to call it outside of the bridge
+    fn ident(&self, name: &str) -> Ident {
+        format_ident!("{}_{name}", self.prefix)
+    }
+
+    /// Declare the C++ function in the bridge with the Rust name of the function
+    fn declare(&self, name: &str, function: TokenStream) -> TokenStream {
+        let rust_name = self.ident(name).to_string();
+        quote! {
+            #[doc(hidden)]
+            #[rust_name = #rust_name]
+            #function
+        }
+    }
+}
+
+/// Generate the CXX bridge and the `QListElement` or `QHashPair` implementation of a container
+pub fn generate(
+    container: &ParsedContainer,
+    namespace: &str,
+    qualified_mappings: &BTreeMap<Ident, Path>,
+    module_ident: &Ident,
+) -> RustFragmentPair {
+    let alias = container.alias_ident();
+    let type_id = if namespace.is_empty() {
+        alias.to_string()
+    } else {
+        format!("{namespace}::{alias}")
+    };
+    let functions = ContainerFunctions::new(&type_id);
+
+    match container {
+        ParsedContainer::QList { element } => generate_qlist(
+            element,
+            &alias,
+            &type_id,
+            &functions,
+            &syn_ident_cxx_bridge_to_qualified_impl(element, qualified_mappings),
+            module_ident,
+        ),
+        ParsedContainer::QHash { key, value } => generate_qhash(
+            (key, value),
+            &alias,
+            &type_id,
+            &functions,
+            (
+                &syn_ident_cxx_bridge_to_qualified_impl(key, qualified_mappings),
+                &syn_ident_cxx_bridge_to_qualified_impl(value, qualified_mappings),
+            ),
+            module_ident,
+        ),
+    }
+}
+
+fn generate_qlist(
+    element: &Ident,
+    alias: &Ident,
+    type_id: &str,
+    functions: &ContainerFunctions,
+    qualified_element: &Path,
+    module_ident: &Ident,
+) -> RustFragmentPair {
+    let [construct_clone, construct_default, drop] = [
+        ("clone", quote! { fn construct(_: &#alias) -> #alias; }),
+        ("default", quote! { fn construct() -> #alias; }),
+        ("drop", quote! { fn drop(_: &mut #alias); }),
+    ]
+    .map(|(name, function)| functions.declare(name, function));
+    let qlist_functions = [
+        (
+            "append",
+            quote! { fn qlistAppend(_: &mut #alias, _: &#element); },
+        ),
+        ("clear", quote! { fn qlistClear(_: &mut #alias); }),
+        (
+            "contains",
+            quote! { fn qlistContains(_: &#alias, _: &#element) -> bool; },
+        ),
+        (
+            "get_unchecked",
+            quote! {
+                #[allow(clippy::needless_lifetimes)]
+                unsafe fn qlistGetUnchecked<'a>(_: &'a #alias, pos: isize) -> &'a #element;
+            },
+        ),
+        (
+            "index_of",
+            quote! { fn qlistIndexOf(_: &#alias, _: &#element) -> isize; },
+        ),
+        (
+            "insert",
+            quote! { fn qlistInsert(_: &mut #alias, _: isize, _: &#element); },
+        ),
+        ("len", quote! { fn qlistLen(_: &#alias) -> isize; }),
+        (
+            "remove",
+            quote! { fn qlistRemove(_: &mut #alias, _: isize); },
+        ),
+        (
+            "reserve",
+            quote! { fn qlistReserve(_: &mut #alias, size: isize); },
+        ),
+    ]
+    .map(|(name, function)| functions.declare(name, function));
+
+    let append = functions.ident("append");
+    let clear = functions.ident("clear");
+    let clone = functions.ident("clone");
+    let contains = functions.ident("contains");
+    let default = functions.ident("default");
+    let drop_ident = functions.ident("drop");
+    let get_unchecked = functions.ident("get_unchecked");
+    let index_of = functions.ident("index_of");
+    let insert = functions.ident("insert");
+    let len = functions.ident("len");
+    let remove = functions.ident("remove");
+    let reserve = functions.ident("reserve");
+
+    RustFragmentPair {
+        cxx_bridge: vec![
+            quote! {
+                unsafe extern "C++" {
+                    include!("cxx-qt-lib/qlist.h");
+                    type #alias = cxx_qt_lib::QList<#element>;
+                }
+            },
+            quote! {
+                #[namespace = "rust::cxxqtlib1"]
+                unsafe extern "C++" {
+                    include!("cxx-qt-lib/common.h");
+
+                    #construct_clone
+                    #construct_default
+                    #drop
+                }
+            },
+            quote! {
+                #[namespace = "rust::cxxqtlib1::qlist"]
+                unsafe extern "C++" {
+                    #(#qlist_functions)*
+                }
+            },
+        ],
+        implementation: vec![quote! {
+            impl cxx_qt_lib::QListElement for #qualified_element {
+                type TypeId = cxx::type_id!(#type_id);
+
+                fn append(list: &mut cxx_qt_lib::QList<Self>, value: Self) {
+                    #module_ident::#append(list, &value);
+                }
+
+                fn append_clone(list: &mut cxx_qt_lib::QList<Self>, value: &Self) {
+                    #module_ident::#append(list, value);
+                }
+
+                fn clear(list: &mut cxx_qt_lib::QList<Self>) {
+                    #module_ident::#clear(list);
+                }
+
+                fn clone(list: &cxx_qt_lib::QList<Self>) -> cxx_qt_lib::QList<Self> {
+                    #module_ident::#clone(list)
+                }
+
+                fn contains(list: &cxx_qt_lib::QList<Self>, value: &Self) -> bool {
+                    #module_ident::#contains(list, value)
+                }
+
+                fn default() -> cxx_qt_lib::QList<Self> {
+                    #module_ident::#default()
+                }
+
+                fn drop(list: &mut cxx_qt_lib::QList<Self>) {
+                    #module_ident::#drop_ident(list);
+                }
+
+                unsafe fn get_unchecked(list: &cxx_qt_lib::QList<Self>, pos: isize) -> &Self {
+                    #module_ident::#get_unchecked(list, pos)
+                }
+
+                fn index_of(list: &cxx_qt_lib::QList<Self>, value: &Self) -> isize {
+                    #module_ident::#index_of(list, value)
+                }
+
+                fn insert(list: &mut cxx_qt_lib::QList<Self>, pos: isize, value: Self) {
+                    #module_ident::#insert(list, pos, &value);
+                }
+
+                fn insert_clone(list: &mut cxx_qt_lib::QList<Self>, pos: isize, value: &Self) {
+                    #module_ident::#insert(list, pos, value);
+                }
+
+                fn len(list: &cxx_qt_lib::QList<Self>) -> isize {
+                    #module_ident::#len(list)
+                }
+
+                fn remove(list: &mut cxx_qt_lib::QList<Self>, pos: isize) {
+                    #module_ident::#remove(list, pos);
+                }
+
+                fn reserve(list: &mut cxx_qt_lib::QList<Self>, size: isize) {
+                    #module_ident::#reserve(list, size);
+                }
+            }
+        }],
+    }
+}
+
+fn generate_qhash(
+    (key, value): (&Ident, &Ident),
+    alias: &Ident,
+    type_id: &str,
+    functions: &ContainerFunctions,
+    (qualified_key, qualified_value): (&Path, &Path),
+    module_ident: &Ident,
+) -> RustFragmentPair {
+    let pair = format_ident!("QHashPair_{key}_{value}");
+    let doc =
+        format!(" The pair of a [`QHash`](cxx_qt_lib::QHash) with {key} keys and {value} values.");
+    let [construct_clone, construct_default, drop] = [
+        ("clone", quote! { fn construct(_: &#alias) -> #alias; }),
+        ("default", quote! { fn construct() -> #alias; }),
+        ("drop", quote! { fn drop(_: &mut #alias); }),
+    ]
+    .map(|(name, function)| functions.declare(name, function));
+    let qhash_functions = [
+        ("clear", quote! { fn qhashClear(_: &mut #alias); }),
+        (
+            "contains",
+            quote! { fn qhashContains(_: &#alias, key: &#key) -> bool; },
+        ),
+        (
+            "get_or_default",
+            quote! { fn qhashGetOrDefault(_: &#alias, key: &#key) -> #value; },
+        ),
+        (
+            "get_unchecked_key",
+            quote! { unsafe fn qhashGetUncheckedKey(_: &#alias, pos: isize) -> &#key; },
+        ),
+        (
+            "get_unchecked_value",
+            quote! { unsafe fn qhashGetUncheckedValue(_: &#alias, pos: isize) -> &#value; },
+        ),
+        (
+            "insert",
+            quote! { fn qhashInsert(_: &mut #alias, key: &#key, value: &#value); },
+        ),
+        ("len", quote! { fn qhashLen(_: &#alias) -> isize; }),
+        (
+            "remove",
+            quote! { fn qhashRemove(_: &mut #alias, key: &#key) -> bool; },
+        ),
+    ]
+    .map(|(name, function)| functions.declare(name, function));
+
+    let clear = functions.ident("clear");
+    let clone = functions.ident("clone");
+    let contains = functions.ident("contains");
+    let default = functions.ident("default");
+    let drop_ident = functions.ident("drop");
+    let get_or_default = functions.ident("get_or_default");
+    let get_unchecked_key = functions.ident("get_unchecked_key");
+    let get_unchecked_value = functions.ident("get_unchecked_value");
+    let insert = functions.ident("insert");
+    let len = functions.ident("len");
+    let remove = functions.ident("remove");
+
+    RustFragmentPair {
+        cxx_bridge: vec![
+            quote! {
+                unsafe extern "C++" {
+                    include!("cxx-qt-lib/qhash.h");
+                    type #alias = cxx_qt_lib::QHash<super::#pair>;
+                }
+            },
+            quote! {
+                #[namespace = "rust::cxxqtlib1"]
+                unsafe extern "C++" {
+                    include!("cxx-qt-lib/common.h");
+
+                    #construct_clone
+                    #construct_default
+                    #drop
+                }
+            },
+            quote! {
+                #[namespace = "rust::cxxqtlib1::qhash"]
+                unsafe extern "C++" {
+                    #(#qhash_functions)*
+                }
+            },
+        ],
+        implementation: vec![
+            quote! {
+                #[doc = #doc]
+                #[allow(non_camel_case_types)]
+                pub struct #pair;
+            },
+            quote! {
+                impl cxx_qt_lib::QHashPair for #pair {
+                    type Key = #qualified_key;
+                    type Value = #qualified_value;
+                    type TypeId = cxx::type_id!(#type_id);
+
+                    fn clear(hash: &mut cxx_qt_lib::QHash<Self>) {
+                        #module_ident::#clear(hash);
+                    }
+
+                    fn clone(hash: &cxx_qt_lib::QHash<Self>) -> cxx_qt_lib::QHash<Self> {
+                        #module_ident::#clone(hash)
+                    }
+
+                    fn contains(hash: &cxx_qt_lib::QHash<Self>, key: &Self::Key) -> bool {
+                        #module_ident::#contains(hash, key)
+                    }
+
+                    fn default() -> cxx_qt_lib::QHash<Self> {
+                        #module_ident::#default()
+                    }
+
+                    fn drop(hash: &mut cxx_qt_lib::QHash<Self>) {
+                        #module_ident::#drop_ident(hash);
+                    }
+
+                    fn get_or_default(hash: &cxx_qt_lib::QHash<Self>, key: &Self::Key) -> Self::Value {
+                        #module_ident::#get_or_default(hash, key)
+                    }
+
+                    unsafe fn get_unchecked_key(hash: &cxx_qt_lib::QHash<Self>, pos: isize) -> &Self::Key {
+                        #module_ident::#get_unchecked_key(hash, pos)
+                    }
+
+                    unsafe fn get_unchecked_value(hash: &cxx_qt_lib::QHash<Self>, pos: isize) -> &Self::Value {
+                        #module_ident::#get_unchecked_value(hash, pos)
+                    }
+
+                    fn insert(hash: &mut cxx_qt_lib::QHash<Self>, key: Self::Key, value: Self::Value) {
+                        #module_ident::#insert(hash, &key, &value);
+                    }
+
+                    fn insert_clone(hash: &mut cxx_qt_lib::QHash<Self>, key: &Self::Key, value: &Self::Value) {
+                        #module_ident::#insert(hash, key, value);
+                    }
+
+                    fn len(hash: &cxx_qt_lib::QHash<Self>) -> isize {
+                        #module_ident::#len(hash)
+                    }
+
+                    fn remove(hash: &mut cxx_qt_lib::QHash<Self>, key: &Self::Key) -> bool {
+                        #module_ident::#remove(hash, key)
+                    }
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::assert_tokens_eq;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_generate_qlist() {
+        let mut qualified_mappings = BTreeMap::new();
+        qualified_mappings.insert(format_ident!("MyGadget"), parse_quote! { ffi::MyGadget });
+
+        let generated = generate(
+            &ParsedContainer::QList {
+                element: format_ident!("MyGadget"),
+            },
+            "cxx_qt::my_object",
+            &qualified_mappings,
+            &format_ident!("ffi"),
+        );
+
+        assert_eq!(generated.cxx_bridge.len(), 3);
+        assert_eq!(generated.implementation.len(), 1);
+
+        assert_tokens_eq(
+            &generated.cxx_bridge[0],
+            quote! {
+                unsafe extern "C++" {
+                    include!("cxx-qt-lib/qlist.h");
+                    type QList_MyGadget = cxx_qt_lib::QList<MyGadget>;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_bridge[1],
+            quote! {
+                #[namespace = "rust::cxxqtlib1"]
+                unsafe extern "C++" {
+                    include!("cxx-qt-lib/common.h");
+
+                    #[doc(hidden)]
+                    #[rust_name = "cxx_qt_cxx_qt_my_object_QList_MyGadget_clone"]
+                    fn construct(_: &QList_MyGadget) -> QList_MyGadget;
+                    #[doc(hidden)]
+                    #[rust_name = "cxx_qt_cxx_qt_my_object_QList_MyGadget_default"]
+                    fn construct() -> QList_MyGadget;
+                    #[doc(hidden)]
+                    #[rust_name = "cxx_qt_cxx_qt_my_object_QList_MyGadget_drop"]
+                    fn drop(_: &mut QList_MyGadget);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_bridge[2],
+            quote! {
+                #[namespace = "rust::cxxqtlib1::qlist"]
+                unsafe extern "C++" {
+                    #[doc(hidden)]
+                    #[rust_name = "cxx_qt_cxx_qt_my_object_QList_MyGadget_append"]
+                    fn qlistAppend(_: &mut QList_MyGadget, _: &MyGadget);
+                    #[doc(hidden)]
+                    #[rust_name = "cxx_qt_cxx_qt_my_object_QList_MyGadget_clear"]
+                    fn qlistClear(_: &mut QList_MyGadget);
+                    #[doc(hidden)]
+                    #[rust_name = "cxx_qt_cxx_qt_my_object_QList_MyGadget_contains"]
+                    fn qlistContains(_: &QList_MyGadget, _: &MyGadget) -> bool;
+                    #[doc(hidden)]
+                    #[rust_name = "cxx_qt_cxx_qt_my_object_QList_MyGadget_get_unchecked"]
+                    #[allow(clippy::needless_lifetimes)]
+                    unsafe fn qlistGetUnchecked<'a>(_: &'a QList_MyGadget, pos: isize) -> &'a MyGadget;
+                    #[doc(hidden)]
+                    #[rust_name = "cxx_qt_cxx_qt_my_object_QList_MyGadget_index_of"]
+                    fn qlistIndexOf(_: &QList_MyGadget, _: &MyGadget) -> isize;
+                    #[doc(hidden)]
+                    #[rust_name = "cxx_qt_cxx_qt_my_object_QList_MyGadget_insert"]
+                    fn qlistInsert(_: &mut QList_MyGadget, _: isize, _: &MyGadget);
+                    #[doc(hidden)]
+                    #[rust_name = "cxx_qt_cxx_qt_my_object_QList_MyGadget_len"]
+                    fn qlistLen(_: &QList_MyGadget) -> isize;
+                    #[doc(hidden)]
+                    #[rust_name = "cxx_qt_cxx_qt_my_object_QList_MyGadget_remove"]
+                    fn qlistRemove(_: &mut QList_MyGadget, _: isize);
+                    #[doc(hidden)]
+                    #[rust_name = "cxx_qt_cxx_qt_my_object_QList_MyGadget_reserve"]
+                    fn qlistReserve(_: &mut QList_MyGadget, size: isize);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.implementation[0],
+            quote! {
+                impl cxx_qt_lib::QListElement for ffi::MyGadget {
+                    type TypeId = cxx::type_id!("cxx_qt::my_object::QList_MyGadget");
+
+                    fn append(list: &mut cxx_qt_lib::QList<Self>, value: Self) {
+                        ffi::cxx_qt_cxx_qt_my_object_QList_MyGadget_append(list, &value);
+                    }
+
+                    fn append_clone(list: &mut cxx_qt_lib::QList<Self>, value: &Self) {
+                        ffi::cxx_qt_cxx_qt_my_object_QList_MyGadget_append(list, value);
+                    }
+
+                    fn clear(list: &mut cxx_qt_lib::QList<Self>) {
+                        ffi::cxx_qt_cxx_qt_my_object_QList_MyGadget_clear(list);
+                    }
+
+                    fn clone(list: &cxx_qt_lib::QList<Self>) -> cxx_qt_lib::QList<Self> {
+                        ffi::cxx_qt_cxx_qt_my_object_QList_MyGadget_clone(list)
+                    }
+
+                    fn contains(list: &cxx_qt_lib::QList<Self>, value: &Self) -> bool {
+                        ffi::cxx_qt_cxx_qt_my_object_QList_MyGadget_contains(list, value)
+                    }
+
+                    fn default() -> cxx_qt_lib::QList<Self> {
+                        ffi::cxx_qt_cxx_qt_my_object_QList_MyGadget_default()
+                    }
+
+                    fn drop(list: &mut cxx_qt_lib::QList<Self>) {
+                        ffi::cxx_qt_cxx_qt_my_object_QList_MyGadget_drop(list);
+                    }
+
+                    unsafe fn get_unchecked(list: &cxx_qt_lib::QList<Self>, pos: isize) -> &Self {
+                        ffi::cxx_qt_cxx_qt_my_object_QList_MyGadget_get_unchecked(list, pos)
+                    }
+
+                    fn index_of(list: &cxx_qt_lib::QList<Self>, value: &Self) -> isize {
+                        ffi::cxx_qt_cxx_qt_my_object_QList_MyGadget_index_of(list, value)
+                    }
+
+                    fn insert(list: &mut cxx_qt_lib::QList<Self>, pos: isize, value: Self) {
+                        ffi::cxx_qt_cxx_qt_my_object_QList_MyGadget_insert(list, pos, &value);
+                    }
+
+                    fn insert_clone(list: &mut cxx_qt_lib::QList<Self>, pos: isize, value: &Self) {
+                        ffi::cxx_qt_cxx_qt_my_object_QList_MyGadget_insert(list, pos, value);
+                    }
+
+                    fn len(list: &cxx_qt_lib::QList<Self>) -> isize {
+                        ffi::cxx_qt_cxx_qt_my_object_QList_MyGadget_len(list)
+                    }
+
+                    fn remove(list: &mut cxx_qt_lib::QList<Self>, pos: isize) {
+                        ffi::cxx_qt_cxx_qt_my_object_QList_MyGadget_remove(list, pos);
+                    }
+
+                    fn reserve(list: &mut cxx_qt_lib::QList<Self>, size: isize) {
+                        ffi::cxx_qt_cxx_qt_my_object_QList_MyGadget_reserve(list, size);
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_qhash() {
+        let mut qualified_mappings = BTreeMap::new();
+        qualified_mappings.insert(format_ident!("QString"), parse_quote! { ffi::QString });
+        qualified_mappings.insert(format_ident!("MyGadget"), parse_quote! { ffi::MyGadget });
+
+        let generated = generate(
+            &ParsedContainer::QHash {
+                key: format_ident!("QString"),
+                value: format_ident!("MyGadget"),
+            },
+            "",
+            &qualified_mappings,
+            &format_ident!("ffi"),
+        );
+
+        assert_eq!(generated.cxx_bridge.len(), 3);
+        assert_eq!(generated.implementation.len(), 2);
+
+        assert_tokens_eq(
+            &generated.cxx_bridge[0],
+            quote! {
+                unsafe extern "C++" {
+                    include!("cxx-qt-lib/qhash.h");
+                    type QHash_QString_MyGadget = cxx_qt_lib::QHash<super::QHashPair_QString_MyGadget>;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_bridge[2],
+            quote! {
+                #[namespace = "rust::cxxqtlib1::qhash"]
+                unsafe extern "C++" {
+                    #[doc(hidden)]
+                    #[rust_name = "cxx_qt_QHash_QString_MyGadget_clear"]
+                    fn qhashClear(_: &mut QHash_QString_MyGadget);
+                    #[doc(hidden)]
+                    #[rust_name = "cxx_qt_QHash_QString_MyGadget_contains"]
+                    fn qhashContains(_: &QHash_QString_MyGadget, key: &QString) -> bool;
+                    #[doc(hidden)]
+                    #[rust_name = "cxx_qt_QHash_QString_MyGadget_get_or_default"]
+                    fn qhashGetOrDefault(_: &QHash_QString_MyGadget, key: &QString) -> MyGadget;
+                    #[doc(hidden)]
+                    #[rust_name = "cxx_qt_QHash_QString_MyGadget_get_unchecked_key"]
+                    unsafe fn qhashGetUncheckedKey(_: &QHash_QString_MyGadget, pos: isize) -> &QString;
+                    #[doc(hidden)]
+                    #[rust_name = "cxx_qt_QHash_QString_MyGadget_get_unchecked_value"]
+                    unsafe fn qhashGetUncheckedValue(_: &QHash_QString_MyGadget, pos: isize) -> &MyGadget;
+                    #[doc(hidden)]
+                    #[rust_name = "cxx_qt_QHash_QString_MyGadget_insert"]
+                    fn qhashInsert(_: &mut QHash_QString_MyGadget, key: &QString, value: &MyGadget);
+                    #[doc(hidden)]
+                    #[rust_name = "cxx_qt_QHash_QString_MyGadget_len"]
+                    fn qhashLen(_: &QHash_QString_MyGadget) -> isize;
+                    #[doc(hidden)]
+                    #[rust_name = "cxx_qt_QHash_QString_MyGadget_remove"]
+                    fn qhashRemove(_: &mut QHash_QString_MyGadget, key: &QString) -> bool;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.implementation[0],
+            quote! {
+                #[doc = " The pair of a [`QHash`](cxx_qt_lib::QHash) with QString keys and MyGadget values."]
+                #[allow(non_camel_case_types)]
+                pub struct QHashPair_QString_MyGadget;
+            },
+        );
+
+        let implementation = generated.implementation[1].to_string();
+        assert!(
+            implementation.contains("impl cxx_qt_lib :: QHashPair for QHashPair_QString_MyGadget")
+        );
+        assert!(implementation.contains("type Key = ffi :: QString ;"));
+        assert!(implementation.contains("type Value = ffi :: MyGadget ;"));
+        assert!(implementation.contains("cxx :: type_id ! (\"QHash_QString_MyGadget\")"));
+    }
+
+    #[test]
+    fn test_generate_qhash_built_in() {
+        let generated = generate(
+            &ParsedContainer::QHash {
+                key: format_ident!("i32"),
+                value: format_ident!("f64"),
+            },
+            "cxx_qt",
+            &BTreeMap::new(),
+            &format_ident!("ffi"),
+        );
+
+        // Built in types are not qualified with the module
+        let implementation = generated.implementation[1].to_string();
+        assert!(implementation.contains("type Key = i32 ;"));
+        assert!(implementation.contains("type Value = f64 ;"));
+        assert!(implementation.contains("cxx :: type_id ! (\"cxx_qt::QHash_i32_f64\")"));
+    }
+}
@@ -0,0 +1,175 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::parser::Parser;
+use quote::quote;
+use syn::{ForeignItem, Ident, Item, Result};
+
+/// Generate a module which re-exports the public API of the bridge, if a facade was requested
+///
+/// This allows downstream crates to use a stable path for the QObjects and enums
+/// rather than reaching into the CXX bridge module, which also contains hidden items.
+pub fn generate(parser: &Parser) -> Result<Option<Item>> {
+    let facade_ident = if let Some(facade_ident) = &parser.facade {
+        facade_ident
+    } else {
+        return Ok(None);
+    };
+
+    let module_ident = &parser.passthrough_module.ident;
+    let vis = &parser.passthrough_module.vis;
+    let cxx_qt_data = &parser.cxx_qt_data;
+
+    // Collect every type which is public API, the QObjects are sorted by name and are followed
+    // by the free QEnums and extern C++Qt types in the order they were declared
+    let mut idents: Vec<&Ident> = vec![];
+    let mut has_signals = false;
+    let mut has_constructors = false;
+    for qobject in cxx_qt_data.qobjects.values() {
+        idents.push(&qobject.qobject_ty.ident_left);
+        idents.extend(qobject.qenums.iter().map(|qenum| &qenum.ident));
+        has_signals |= !qobject.signals.is_empty();
+        has_constructors |= !qobject.constructors.is_empty();
+    }
+    idents.extend(cxx_qt_data.qenums.iter().map(|qenum| &qenum.ident));
+    for extern_cxx_qt in &cxx_qt_data.extern_cxxqt_blocks {
+        idents.extend(
+            extern_cxx_qt
+                .passthrough_items
+                .iter()
+                .filter_map(|item| match item {
+                    ForeignItem::Type(foreign_type) => Some(&foreign_type.ident),
+                    _ => None,
+                }),
+        );
+        has_signals |= !extern_cxx_qt.signals.is_empty();
+    }
+
+    // Connecting to a signal takes a connection type and returns a connection, so ensure that
+    // these types are available
+    let connection = if has_signals {
+        Some(quote! {
+            pub use cxx_qt_lib::{ConnectionType, QMetaObjectConnection};
+        })
+    } else {
+        None
+    };
+
+    // The constructors of the QObjects are implementations of this trait
+    let constructor = if has_constructors {
+        Some(quote! {
+            pub use cxx_qt::Constructor;
+        })
+    } else {
+        None
+    };
+
+    let doc = format!(
+        "The public API of the [`{module_ident}`] bridge, re-exported under a stable path."
+    );
+    syn::parse2(quote! {
+        #[doc = #doc]
+        #vis mod #facade_ident {
+            #(pub use super::#module_ident::#idents;)*
+            #connection
+            #constructor
+        }
+    })
+    .map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::assert_tokens_eq;
+    use syn::{parse_quote, ItemMod};
+
+    #[test]
+    fn test_generate_no_facade() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        assert!(generate(&parser).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_generate_facade() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt", facade = "api")]
+            pub mod ffi {
+                #[qenum]
+                enum MyEnum {
+                    A,
+                }
+
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qsignal]
+                    fn ready(self: Pin<&mut MyObject>);
+                }
+
+                unsafe extern "C++Qt" {
+                    type QPushButton;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        assert_tokens_eq(
+            &generate(&parser).unwrap().unwrap(),
+            quote! {
+                #[doc = "The public API of the [`ffi`] bridge, re-exported under a stable path."]
+                pub mod api {
+                    pub use super::ffi::MyObject;
+                    pub use super::ffi::MyEnum;
+                    pub use super::ffi::QPushButton;
+                    pub use cxx_qt_lib::{ConnectionType, QMetaObjectConnection};
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_facade_constructor() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(facade = "api")]
+            mod ffi {
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qobject]
+                    type AnotherObject = super::AnotherObjectRust;
+                }
+
+                impl cxx_qt::Constructor<(i32,)> for MyObject {}
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        assert_tokens_eq(
+            &generate(&parser).unwrap().unwrap(),
+            quote! {
+                #[doc = "The public API of the [`ffi`] bridge, re-exported under a stable path."]
+                mod api {
+                    pub use super::ffi::AnotherObject;
+                    pub use super::ffi::MyObject;
+                    pub use cxx_qt::Constructor;
+                }
+            },
+        );
+    }
+}
@@ -30,7 +30,7 @@ pub fn generate(
                     quote! { #ident: #ty }
                 })
                 .collect::<Vec<TokenStream>>();
-            let ident = &method.method.sig.ident;
+            let ident = &method.ident.rust;
             let cxx_name_string = &method.wrapper_ident().to_string();
             let self_param = if method.mutable {
                 quote! { self: Pin<&mut #qobject_name> }
@@ -148,4 +148,29 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn test_rust_name() {
+        let generated = generate_from_foreign(
+            parse_quote! {
+                #[rust_name = "test_const"]
+                fn test(self: &MyObject, a: B);
+            },
+            Safety::Safe,
+        )
+        .unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 1);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 0);
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                unsafe extern "C++" {
+                    #[cxx_name = "testCxxQtInherit"]
+                    fn test_const(self: &MyObject, a: B);
+                }
+            },
+        );
+    }
 }
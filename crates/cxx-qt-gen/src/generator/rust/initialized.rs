@@ -0,0 +1,104 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use std::collections::BTreeMap;
+
+use crate::generator::{
+    naming::{namespace::NamespaceName, qobject::QObjectName},
+    rust::{fragment::RustFragmentPair, qobject::GeneratedRustQObject},
+    utils::rust::syn_ident_cxx_bridge_to_qualified_impl,
+};
+use quote::quote;
+use syn::{Ident, Path, Result};
+
+pub fn generate(
+    qobject_ident: &QObjectName,
+    namespace_ident: &NamespaceName,
+    qualified_mappings: &BTreeMap<Ident, Path>,
+) -> Result<GeneratedRustQObject> {
+    let mut blocks = GeneratedRustQObject::default();
+
+    let cpp_struct_ident = &qobject_ident.cpp_class.rust;
+    let initialized_rust = qobject_ident.cxx_qt_thread_method("initialized");
+    let namespace_internals = &namespace_ident.internal;
+    let qualified_impl =
+        syn_ident_cxx_bridge_to_qualified_impl(cpp_struct_ident, qualified_mappings);
+
+    let fragment = RustFragmentPair {
+        cxx_bridge: vec![quote! {
+            extern "Rust" {
+                #[doc(hidden)]
+                #[namespace = #namespace_internals]
+                #[cxx_name = "initialized"]
+                fn #initialized_rust(qobject: Pin<&mut #cpp_struct_ident>);
+            }
+        }],
+        implementation: vec![quote! {
+            #[doc(hidden)]
+            pub fn #initialized_rust(qobject: core::pin::Pin<&mut #qualified_impl>) {
+                <#qualified_impl as cxx_qt::Initialized>::initialized(qobject);
+            }
+        }],
+    };
+
+    blocks
+        .cxx_mod_contents
+        .append(&mut fragment.cxx_bridge_as_items()?);
+    blocks
+        .cxx_qt_mod_contents
+        .append(&mut fragment.implementation_as_items()?);
+
+    Ok(blocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::assert_tokens_eq;
+
+    use crate::parser::qobject::tests::create_parsed_qobject;
+
+    #[test]
+    fn test_generate_rust_initialized() {
+        let qobject = create_parsed_qobject();
+        let qobject_idents = QObjectName::from(&qobject);
+        let namespace_ident = NamespaceName::from(&qobject);
+
+        let generated = generate(
+            &qobject_idents,
+            &namespace_ident,
+            &BTreeMap::<Ident, Path>::default(),
+        )
+        .unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 1);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 1);
+
+        // CXX bridges
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                extern "Rust" {
+                    #[doc(hidden)]
+                    #[namespace = "cxx_qt_my_object"]
+                    #[cxx_name = "initialized"]
+                    fn cxx_qt_ffi_my_object_initialized(qobject: Pin<&mut MyObject>);
+                }
+            },
+        );
+
+        // CXX-Qt generated contents
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                #[doc(hidden)]
+                pub fn cxx_qt_ffi_my_object_initialized(qobject: core::pin::Pin<&mut MyObject>) {
+                    <MyObject as cxx_qt::Initialized>::initialized(qobject);
+                }
+            },
+        );
+    }
+}
@@ -3,20 +3,28 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+use std::collections::BTreeMap;
+
 use crate::{
     generator::{
-        naming::{method::QMethodName, qobject::QObjectName},
-        rust::{fragment::RustFragmentPair, qobject::GeneratedRustQObject},
+        naming::{method::QMethodName, namespace::NamespaceName, qobject::QObjectName},
+        rust::{fragment::RustFragmentPair, qobject::GeneratedRustQObject, trace},
+        utils::rust::{syn_ident_cxx_bridge_to_qualified_impl, syn_type_cxx_bridge_to_qualified},
     },
-    parser::method::ParsedMethod,
+    parser::{method::ParsedMethod, panic::ParsedQPanic},
 };
+use convert_case::{Case, Casing};
 use proc_macro2::TokenStream;
-use quote::quote;
-use syn::Result;
+use quote::{format_ident, quote};
+use syn::{Ident, Path, Result, ReturnType, Type};
 
 pub fn generate_rust_methods(
     invokables: &Vec<ParsedMethod>,
     qobject_idents: &QObjectName,
+    namespace_idents: &NamespaceName,
+    qualified_mappings: &BTreeMap<Ident, Path>,
+    trace: bool,
+    panic: ParsedQPanic,
 ) -> Result<GeneratedRustQObject> {
     let mut generated = GeneratedRustQObject::default();
     let cpp_class_name_rust = &qobject_idents.cpp_class.rust;
@@ -33,18 +41,21 @@ pub fn generate_rust_methods(
         } else {
             quote! { &#cpp_class_name_rust }
         };
-        let parameter_signatures = if invokable.parameters.is_empty() {
+        let parameters = invokable
+            .parameters
+            .iter()
+            .map(|parameter| {
+                let ident = &parameter.ident;
+                let ty = &parameter.ty;
+                quote! { #ident: #ty }
+            })
+            .collect::<Vec<TokenStream>>();
+        // A static invokable is exposed to C++ as a free function, as it has no self receiver
+        let parameter_signatures = if invokable.is_static {
+            quote! { #(#parameters),* }
+        } else if parameters.is_empty() {
             quote! { self: #cpp_struct }
         } else {
-            let parameters = invokable
-                .parameters
-                .iter()
-                .map(|parameter| {
-                    let ident = &parameter.ident;
-                    let ty = &parameter.ty;
-                    quote! { #ident: #ty }
-                })
-                .collect::<Vec<TokenStream>>();
             quote! { self: #cpp_struct, #(#parameters),* }
         };
 
@@ -56,19 +67,150 @@ pub fn generate_rust_methods(
             std::mem::swap(&mut unsafe_call, &mut unsafe_block);
         }
 
-        let fragment = RustFragmentPair {
-            cxx_bridge: vec![quote! {
-                // Note: extern "Rust" block does not need to be unsafe
-                extern "Rust" {
-                    // Note that we are exposing a Rust method on the C++ type to C++
-                    //
-                    // CXX ends up generating the source, then we generate the matching header.
-                    #[doc(hidden)]
-                    #[cxx_name = #wrapper_ident_cpp]
-                    #unsafe_call fn #invokable_ident_rust(#parameter_signatures) #return_type;
+        let catch_panic = panic.catches_method(invokable)?;
+        let fragment = if invokable.is_static
+            || trace
+            || catch_panic
+            || !invokable.conversions.is_empty()
+        {
+            // Expose a wrapper to C++ instead, which enters a span around the call,
+            // converts between the Qt and Rust types, and catches any panic so that it can be handled by C++
+            //
+            // The wrapper of a static invokable is a free function so it is prefixed with the QObject name
+            let wrapper_ident_rust = if invokable.is_static {
+                format_ident!(
+                    "{}_{}",
+                    cpp_class_name_rust.to_string().to_case(Case::Snake),
+                    idents.wrapper.rust
+                )
+            } else {
+                idents.wrapper.rust.clone()
+            };
+            let span = trace.then(|| {
+                trace::generate_span(&format!("{cpp_class_name_rust}::{invokable_ident_rust}"))
+            });
+            let qualified_impl =
+                syn_ident_cxx_bridge_to_qualified_impl(cpp_class_name_rust, qualified_mappings);
+            let self_ty = if invokable.mutable {
+                quote! { core::pin::Pin<&mut Self> }
+            } else {
+                quote! { &Self }
+            };
+            let qualified_parameters = invokable
+                .parameters
+                .iter()
+                .map(|parameter| {
+                    let ident = &parameter.ident;
+                    let ty = syn_type_cxx_bridge_to_qualified(&parameter.ty, qualified_mappings);
+                    quote! { #ident: #ty }
+                })
+                .collect::<Vec<TokenStream>>();
+            let parameter_names = invokable
+                .parameters
+                .iter()
+                .map(|parameter| {
+                    let ident = &parameter.ident;
+                    // A reference to the Qt type is passed to the conversion
+                    match (invokable.conversions.parameters.get(ident), &parameter.ty) {
+                        (Some(rust_ty), Type::Reference(_)) => {
+                            quote! { <#rust_ty as cxx_qt_lib::FromQt<_>>::from_qt(&*#ident) }
+                        }
+                        (Some(rust_ty), _) => {
+                            quote! { <#rust_ty as cxx_qt_lib::FromQt<_>>::from_qt(&#ident) }
+                        }
+                        (None, _) => quote! { #ident },
+                    }
+                })
+                .collect::<Vec<TokenStream>>();
+            let qualified_return_type = match return_type {
+                ReturnType::Default => quote! {},
+                ReturnType::Type(_, ty) => {
+                    let ty = syn_type_cxx_bridge_to_qualified(ty, qualified_mappings);
+                    quote! { -> #ty }
                 }
-            }],
-            implementation: vec![],
+            };
+            let call = if invokable.is_static {
+                quote! { #unsafe_call { <#qualified_impl>::#invokable_ident_rust(#(#parameter_names),*) } }
+            } else {
+                quote! { #unsafe_call { self.#invokable_ident_rust(#(#parameter_names),*) } }
+            };
+            let call = if let Some(rust_ty) = &invokable.conversions.return_ty {
+                quote! { <#rust_ty as cxx_qt_lib::IntoQt<_>>::into_qt(#call) }
+            } else {
+                call
+            };
+            let (return_type, qualified_return_type, call) = if catch_panic {
+                // CXX throws the error of the Result as a rust::Error exception in C++
+                let (ty, qualified_ty) = match return_type {
+                    ReturnType::Default => (quote! { () }, quote! { () }),
+                    ReturnType::Type(_, ty) => {
+                        let qualified_ty = syn_type_cxx_bridge_to_qualified(ty, qualified_mappings);
+                        (quote! { #ty }, quote! { #qualified_ty })
+                    }
+                };
+                (
+                    quote! { -> Result<#ty> },
+                    quote! { -> core::result::Result<#qualified_ty, String> },
+                    quote! { cxx_qt::catch_panic(move || #call) },
+                )
+            } else {
+                (quote! { #return_type }, qualified_return_type, call)
+            };
+
+            if invokable.is_static {
+                let namespace_internals = &namespace_idents.internal;
+                RustFragmentPair {
+                    cxx_bridge: vec![quote! {
+                        extern "Rust" {
+                            #[doc(hidden)]
+                            #[cxx_name = #wrapper_ident_cpp]
+                            #[namespace = #namespace_internals]
+                            #unsafe_call fn #wrapper_ident_rust(#parameter_signatures) #return_type;
+                        }
+                    }],
+                    implementation: vec![quote! {
+                        #[doc(hidden)]
+                        pub #unsafe_call fn #wrapper_ident_rust(#(#qualified_parameters),*) #qualified_return_type {
+                            #span
+                            #call
+                        }
+                    }],
+                }
+            } else {
+                RustFragmentPair {
+                    cxx_bridge: vec![quote! {
+                        extern "Rust" {
+                            #[doc(hidden)]
+                            #[cxx_name = #wrapper_ident_cpp]
+                            #unsafe_call fn #wrapper_ident_rust(#parameter_signatures) #return_type;
+                        }
+                    }],
+                    implementation: vec![quote! {
+                        impl #qualified_impl {
+                            #[doc(hidden)]
+                            #unsafe_call fn #wrapper_ident_rust(self: #self_ty, #(#qualified_parameters),*) #qualified_return_type {
+                                #span
+                                #call
+                            }
+                        }
+                    }],
+                }
+            }
+        } else {
+            RustFragmentPair {
+                cxx_bridge: vec![quote! {
+                    // Note: extern "Rust" block does not need to be unsafe
+                    extern "Rust" {
+                        // Note that we are exposing a Rust method on the C++ type to C++
+                        //
+                        // CXX ends up generating the source, then we generate the matching header.
+                        #[doc(hidden)]
+                        #[cxx_name = #wrapper_ident_cpp]
+                        #unsafe_call fn #invokable_ident_rust(#parameter_signatures) #return_type;
+                    }
+                }],
+                implementation: vec![],
+            }
         };
 
         generated
@@ -87,7 +229,7 @@ mod tests {
     use super::*;
 
     use crate::generator::naming::qobject::tests::create_qobjectname;
-    use crate::parser::parameter::ParsedFunctionParameter;
+    use crate::parser::{conversion::ParsedQtConversions, parameter::ParsedFunctionParameter};
     use crate::tests::assert_tokens_eq;
     use quote::format_ident;
     use std::collections::HashSet;
@@ -104,6 +246,12 @@ mod tests {
                 parameters: vec![],
                 specifiers: HashSet::new(),
                 is_qinvokable: true,
+                revision: None,
+                deprecated: None,
+                ownership: None,
+                access: Default::default(),
+                is_static: false,
+                conversions: Default::default(),
             },
             ParsedMethod {
                 method: parse_quote! { fn trivial_invokable(self: &MyObject, param: i32) -> i32; },
@@ -116,6 +264,12 @@ mod tests {
                 }],
                 specifiers: HashSet::new(),
                 is_qinvokable: true,
+                revision: None,
+                deprecated: None,
+                ownership: None,
+                access: Default::default(),
+                is_static: false,
+                conversions: Default::default(),
             },
             ParsedMethod {
                 method: parse_quote! { fn opaque_invokable(self: Pin<&mut MyObject>, param: &QColor) -> UniquePtr<QColor>; },
@@ -128,6 +282,12 @@ mod tests {
                 }],
                 specifiers: HashSet::new(),
                 is_qinvokable: true,
+                revision: None,
+                deprecated: None,
+                ownership: None,
+                access: Default::default(),
+                is_static: false,
+                conversions: Default::default(),
             },
             ParsedMethod {
                 method: parse_quote! { unsafe fn unsafe_invokable(self: &MyObject, param: *mut T) -> *mut T; },
@@ -140,11 +300,27 @@ mod tests {
                 }],
                 specifiers: HashSet::new(),
                 is_qinvokable: true,
+                revision: None,
+                deprecated: None,
+                ownership: None,
+                access: Default::default(),
+                is_static: false,
+                conversions: Default::default(),
             },
         ];
         let qobject_idents = create_qobjectname();
+        let namespace_idents =
+            NamespaceName::from_pair_str("cxx_qt::my_object", &format_ident!("MyObject"));
 
-        let generated = generate_rust_methods(&invokables, &qobject_idents).unwrap();
+        let generated = generate_rust_methods(
+            &invokables,
+            &qobject_idents,
+            &namespace_idents,
+            &BTreeMap::<Ident, Path>::default(),
+            false,
+            ParsedQPanic::Abort,
+        )
+        .unwrap();
 
         assert_eq!(generated.cxx_mod_contents.len(), 4);
         assert_eq!(generated.cxx_qt_mod_contents.len(), 0);
@@ -197,4 +373,341 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn test_generate_rust_invokables_trace() {
+        let invokables = vec![
+            ParsedMethod {
+                method: parse_quote! { fn opaque_invokable(self: Pin<&mut MyObject>, param: &QColor) -> UniquePtr<QColor>; },
+                qobject_ident: format_ident!("MyObject"),
+                mutable: true,
+                safe: true,
+                parameters: vec![ParsedFunctionParameter {
+                    ident: format_ident!("param"),
+                    ty: parse_quote! { &QColor },
+                }],
+                specifiers: HashSet::new(),
+                is_qinvokable: true,
+                revision: None,
+                deprecated: None,
+                ownership: None,
+                access: Default::default(),
+                is_static: false,
+                conversions: Default::default(),
+            },
+            ParsedMethod {
+                method: parse_quote! { unsafe fn unsafe_invokable(self: &MyObject, param: *mut T) -> *mut T; },
+                qobject_ident: format_ident!("MyObject"),
+                mutable: false,
+                safe: false,
+                parameters: vec![ParsedFunctionParameter {
+                    ident: format_ident!("param"),
+                    ty: parse_quote! { *mut T },
+                }],
+                specifiers: HashSet::new(),
+                is_qinvokable: true,
+                revision: None,
+                deprecated: None,
+                ownership: None,
+                access: Default::default(),
+                is_static: false,
+                conversions: Default::default(),
+            },
+        ];
+        let qobject_idents = create_qobjectname();
+        let namespace_idents =
+            NamespaceName::from_pair_str("cxx_qt::my_object", &format_ident!("MyObject"));
+
+        let generated = generate_rust_methods(
+            &invokables,
+            &qobject_idents,
+            &namespace_idents,
+            &BTreeMap::<Ident, Path>::default(),
+            true,
+            ParsedQPanic::Abort,
+        )
+        .unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 2);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 2);
+
+        // opaque_invokable
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                extern "Rust" {
+                    #[doc(hidden)]
+                    #[cxx_name = "opaqueInvokableWrapper"]
+                    fn opaque_invokable_wrapper(self: Pin<&mut MyObject>, param: &QColor) -> UniquePtr<QColor>;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl MyObject {
+                    #[doc(hidden)]
+                    fn opaque_invokable_wrapper(self: core::pin::Pin<&mut Self>, param: &QColor) -> cxx::UniquePtr<QColor> {
+                        let _cxx_qt_span = cxx_qt::tracing::trace_span!("MyObject::opaque_invokable").entered();
+                        { self.opaque_invokable(param) }
+                    }
+                }
+            },
+        );
+
+        // unsafe_invokable
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[1],
+            quote! {
+                extern "Rust" {
+                    #[doc(hidden)]
+                    #[cxx_name = "unsafeInvokableWrapper"]
+                    unsafe fn unsafe_invokable_wrapper(self: &MyObject, param: *mut T) -> *mut T;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[1],
+            quote! {
+                impl MyObject {
+                    #[doc(hidden)]
+                    unsafe fn unsafe_invokable_wrapper(self: &Self, param: *mut T) -> *mut T {
+                        let _cxx_qt_span = cxx_qt::tracing::trace_span!("MyObject::unsafe_invokable").entered();
+                        unsafe { self.unsafe_invokable(param) }
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_invokables_panic() {
+        let invokables = vec![
+            ParsedMethod {
+                method: parse_quote! { fn void_invokable(self: &MyObject); },
+                qobject_ident: format_ident!("MyObject"),
+                mutable: false,
+                safe: true,
+                parameters: vec![],
+                specifiers: HashSet::new(),
+                is_qinvokable: true,
+                revision: None,
+                deprecated: None,
+                ownership: None,
+                access: Default::default(),
+                is_static: false,
+                conversions: Default::default(),
+            },
+            ParsedMethod {
+                method: parse_quote! { fn opaque_invokable(self: Pin<&mut MyObject>, param: &QColor) -> UniquePtr<QColor>; },
+                qobject_ident: format_ident!("MyObject"),
+                mutable: true,
+                safe: true,
+                parameters: vec![ParsedFunctionParameter {
+                    ident: format_ident!("param"),
+                    ty: parse_quote! { &QColor },
+                }],
+                specifiers: HashSet::new(),
+                is_qinvokable: true,
+                revision: None,
+                deprecated: None,
+                ownership: None,
+                access: Default::default(),
+                is_static: false,
+                conversions: Default::default(),
+            },
+        ];
+        let qobject_idents = create_qobjectname();
+        let namespace_idents =
+            NamespaceName::from_pair_str("cxx_qt::my_object", &format_ident!("MyObject"));
+
+        let generated = generate_rust_methods(
+            &invokables,
+            &qobject_idents,
+            &namespace_idents,
+            &BTreeMap::<Ident, Path>::default(),
+            false,
+            ParsedQPanic::Signal,
+        )
+        .unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 2);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 2);
+
+        // void_invokable
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                extern "Rust" {
+                    #[doc(hidden)]
+                    #[cxx_name = "voidInvokableWrapper"]
+                    fn void_invokable_wrapper(self: &MyObject) -> Result<()>;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl MyObject {
+                    #[doc(hidden)]
+                    fn void_invokable_wrapper(self: &Self, ) -> core::result::Result<(), String> {
+                        cxx_qt::catch_panic(move | | { self.void_invokable() })
+                    }
+                }
+            },
+        );
+
+        // opaque_invokable
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[1],
+            quote! {
+                extern "Rust" {
+                    #[doc(hidden)]
+                    #[cxx_name = "opaqueInvokableWrapper"]
+                    fn opaque_invokable_wrapper(self: Pin<&mut MyObject>, param: &QColor) -> Result<UniquePtr<QColor> >;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[1],
+            quote! {
+                impl MyObject {
+                    #[doc(hidden)]
+                    fn opaque_invokable_wrapper(self: core::pin::Pin<&mut Self>, param: &QColor) -> core::result::Result<cxx::UniquePtr<QColor>, String> {
+                        cxx_qt::catch_panic(move | | { self.opaque_invokable(param) })
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_invokables_conversions() {
+        let invokables = vec![ParsedMethod {
+            method: parse_quote! { fn greet(self: &MyObject, name: &QString, count: i32) -> QString; },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: false,
+            safe: true,
+            parameters: vec![
+                ParsedFunctionParameter {
+                    ident: format_ident!("name"),
+                    ty: parse_quote! { &QString },
+                },
+                ParsedFunctionParameter {
+                    ident: format_ident!("count"),
+                    ty: parse_quote! { i32 },
+                },
+            ],
+            specifiers: HashSet::new(),
+            is_qinvokable: true,
+            revision: None,
+            deprecated: None,
+            ownership: None,
+            access: Default::default(),
+            is_static: false,
+            conversions: ParsedQtConversions {
+                parameters: BTreeMap::from([(format_ident!("name"), parse_quote! { String })]),
+                return_ty: Some(parse_quote! { String }),
+            },
+        }];
+        let qobject_idents = create_qobjectname();
+        let namespace_idents =
+            NamespaceName::from_pair_str("cxx_qt::my_object", &format_ident!("MyObject"));
+
+        let generated = generate_rust_methods(
+            &invokables,
+            &qobject_idents,
+            &namespace_idents,
+            &BTreeMap::<Ident, Path>::default(),
+            false,
+            ParsedQPanic::Abort,
+        )
+        .unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 1);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 1);
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                extern "Rust" {
+                    #[doc(hidden)]
+                    #[cxx_name = "greetWrapper"]
+                    fn greet_wrapper(self: &MyObject, name: &QString, count: i32) -> QString;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl MyObject {
+                    #[doc(hidden)]
+                    fn greet_wrapper(self: &Self, name: &QString, count: i32) -> QString {
+                        <String as cxx_qt_lib::IntoQt<_> >::into_qt({
+                            self.greet(<String as cxx_qt_lib::FromQt<_> >::from_qt(&*name), count)
+                        })
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_invokables_static() {
+        let invokables = vec![ParsedMethod {
+            method: parse_quote! { fn helper(value: i32) -> i32; },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: false,
+            safe: true,
+            parameters: vec![ParsedFunctionParameter {
+                ident: format_ident!("value"),
+                ty: parse_quote! { i32 },
+            }],
+            specifiers: HashSet::new(),
+            is_qinvokable: true,
+            revision: None,
+            deprecated: None,
+            ownership: None,
+            access: Default::default(),
+            is_static: true,
+            conversions: Default::default(),
+        }];
+        let qobject_idents = create_qobjectname();
+        let namespace_idents =
+            NamespaceName::from_pair_str("cxx_qt::my_object", &format_ident!("MyObject"));
+
+        let generated = generate_rust_methods(
+            &invokables,
+            &qobject_idents,
+            &namespace_idents,
+            &BTreeMap::<Ident, Path>::default(),
+            false,
+            ParsedQPanic::Abort,
+        )
+        .unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 1);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 1);
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                extern "Rust" {
+                    #[doc(hidden)]
+                    #[cxx_name = "helperWrapper"]
+                    #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+                    fn my_object_helper_wrapper(value: i32) -> i32;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                #[doc(hidden)]
+                pub fn my_object_helper_wrapper(value: i32) -> i32 {
+                    { <MyObject>::helper(value) }
+                }
+            },
+        );
+    }
 }
@@ -3,17 +3,26 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+pub mod connect;
 pub mod constructor;
+pub mod container;
+pub mod context;
 pub mod cxxqttype;
 pub mod externcxxqt;
+pub mod facade;
 pub mod fragment;
 pub mod inherit;
+pub mod initialized;
 pub mod method;
 pub mod property;
 pub mod qenum;
+pub mod qmlfactory;
+pub mod qmlparserstatus;
 pub mod qobject;
 pub mod signals;
 pub mod threading;
+pub mod trace;
+pub mod weakref;
 
 use crate::generator::rust::{externcxxqt::GeneratedExternCxxQt, qobject::GeneratedRustQObject};
 use crate::parser::Parser;
@@ -41,10 +50,41 @@ impl GeneratedRustBlocks {
         let mut cxx_mod_contents = qenum::generate_cxx_mod_contents(&parser.cxx_qt_data.qenums);
         cxx_mod_contents.push(generate_include(parser)?);
 
+        let mut container_mod_contents = vec![];
+        for container in &parser.cxx_qt_data.containers {
+            let fragment = container::generate(
+                container,
+                &parser.cxx_qt_data.namespace,
+                &parser.cxx_qt_data.cxx_mappings.qualified,
+                &parser.passthrough_module.ident,
+            );
+            cxx_mod_contents.extend(fragment.cxx_bridge_as_items()?);
+            container_mod_contents.extend(fragment.implementation_as_items()?);
+        }
+
+        // The typed connection handles of each QObject upcast to the same QObject alias
+        if parser
+            .cxx_qt_data
+            .qobjects
+            .values()
+            .any(|qobject| qobject.connect)
+        {
+            cxx_mod_contents.push(generate_qobject_alias()?);
+        }
+
         Ok(GeneratedRustBlocks {
             cxx_mod: parser.passthrough_module.clone(),
             cxx_mod_contents,
-            cxx_qt_mod_contents: vec![],
+            cxx_qt_mod_contents: facade::generate(parser)?
+                .into_iter()
+                .chain(qenum::generate_cxx_qt_mod_contents(
+                    &parser.cxx_qt_data.qenums,
+                    &parser.cxx_qt_data.namespace,
+                    &parser.passthrough_module.ident,
+                ))
+                .chain(container_mod_contents)
+                .chain(generate_extension_dependencies(parser)?)
+                .collect(),
             namespace: parser.cxx_qt_data.namespace.clone(),
             qobjects: parser
                 .cxx_qt_data
@@ -55,6 +95,7 @@ impl GeneratedRustBlocks {
                         qobject,
                         &parser.cxx_qt_data.cxx_mappings.qualified,
                         &parser.passthrough_module.ident,
+                        parser.trace,
                     )
                 })
                 .collect::<Result<Vec<GeneratedRustQObject>>>()?,
@@ -76,7 +117,7 @@ impl GeneratedRustBlocks {
 
 /// Generate the include line for this parsed block
 fn generate_include(parser: &Parser) -> Result<Item> {
-    let import_path = format!("cxx-qt-gen/{}.cxxqt.h", parser.cxx_file_stem);
+    let import_path = format!("{}/{}.cxxqt.h", parser.include_prefix, parser.cxx_file_stem);
 
     syn::parse2(quote! {
         unsafe extern "C++" {
@@ -85,6 +126,35 @@ fn generate_include(parser: &Parser) -> Result<Item> {
     })
 }
 
+/// Generate the alias of QObject which the typed connection handles upcast to
+fn generate_qobject_alias() -> Result<Item> {
+    syn::parse2(quote! {
+        unsafe extern "C++" {
+            include!("cxx-qt-lib/qobjectconnect.h");
+            #[doc(hidden)]
+            #[namespace = ""]
+            // Rename to CxxQtQObject so the developer can define QObject
+            // in their bridges without an invisible conflict
+            #[rust_name = "CxxQtQObject"]
+            type QObject = cxx_qt_lib::QObject;
+        }
+    })
+}
+
+/// Generate an include of any extension files, so that the bridge is rebuilt when they change
+fn generate_extension_dependencies(parser: &Parser) -> Result<Vec<Item>> {
+    parser
+        .extension_files
+        .iter()
+        .map(|path| {
+            let path = path.to_string_lossy();
+            syn::parse2(quote! {
+                const _: &[u8] = include_bytes!(#path);
+            })
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use syn::parse_quote;
@@ -143,6 +213,43 @@ mod tests {
         assert_eq!(rust.qobjects.len(), 1);
     }
 
+    #[test]
+    fn test_generated_rust_blocks_connect() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    type First = super::FirstRust;
+
+                    #[qobject]
+                    type Second = super::SecondRust;
+                }
+
+                impl cxx_qt_lib::Connect for First {}
+                impl cxx_qt_lib::Connect for Second {}
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        // The QObject alias is only declared once for all of the QObjects
+        let rust = GeneratedRustBlocks::from(&parser).unwrap();
+        assert_eq!(rust.cxx_mod_contents.len(), 2);
+        assert_tokens_eq(
+            &rust.cxx_mod_contents[1],
+            quote! {
+                unsafe extern "C++" {
+                    include!("cxx-qt-lib/qobjectconnect.h");
+                    #[doc(hidden)]
+                    #[namespace = ""]
+                    #[rust_name = "CxxQtQObject"]
+                    type QObject = cxx_qt_lib::QObject;
+                }
+            },
+        );
+        assert_eq!(rust.qobjects.len(), 2);
+    }
+
     #[test]
     fn test_generated_rust_blocks_cxx_file_stem() {
         let module: ItemMod = parse_quote! {
@@ -171,4 +278,53 @@ mod tests {
         assert_eq!(rust.namespace, "");
         assert_eq!(rust.qobjects.len(), 1);
     }
+
+    #[test]
+    fn test_generated_rust_blocks_include_prefix() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let mut parser = Parser::from(module).unwrap();
+        parser.include_prefix = "my_crate/generated".to_owned();
+
+        let rust = GeneratedRustBlocks::from(&parser).unwrap();
+        assert_tokens_eq(
+            &rust.cxx_mod_contents[0],
+            quote! {
+                unsafe extern "C++" {
+                    include!("my_crate/generated/ffi.cxxqt.h");
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generated_rust_blocks_extend() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(extend = "test_inputs/extend/invokables.rs")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        let rust = GeneratedRustBlocks::from(&parser).unwrap();
+        assert_eq!(rust.cxx_qt_mod_contents.len(), 1);
+        let path = parser.extension_files[0].to_string_lossy();
+        assert_tokens_eq(
+            &rust.cxx_qt_mod_contents[0],
+            quote! {
+                const _: &[u8] = include_bytes!(#path);
+            },
+        );
+    }
 }
@@ -6,7 +6,10 @@
 use crate::generator::{
     naming::{property::QPropertyName, qobject::QObjectName},
     rust::fragment::RustFragmentPair,
-    utils::rust::{syn_ident_cxx_bridge_to_qualified_impl, syn_type_cxx_bridge_to_qualified},
+    utils::rust::{
+        syn_ident_cxx_bridge_to_qualified_impl, syn_type_cxx_bridge_to_qualified,
+        syn_type_is_cxx_bridge_unsafe,
+    },
 };
 use quote::quote;
 use std::collections::BTreeMap;
@@ -45,3 +48,57 @@ pub fn generate(
         }],
     }
 }
+
+pub fn generate_computed(
+    idents: &QPropertyName,
+    qobject_idents: &QObjectName,
+    cxx_ty: &Type,
+    qualified_mappings: &BTreeMap<Ident, Path>,
+    catch_panic: bool,
+) -> RustFragmentPair {
+    let cpp_class_name_rust = &qobject_idents.cpp_class.rust;
+    let getter_wrapper_cpp = idents.getter_wrapper.cpp.to_string();
+    let getter_rust = &idents.getter.rust;
+
+    // Determine if unsafe is required due to an unsafe type
+    let has_unsafe = if syn_type_is_cxx_bridge_unsafe(cxx_ty) {
+        quote! { unsafe }
+    } else {
+        quote! {}
+    };
+
+    // The getter is implemented by the developer, so it is bound directly
+    if !catch_panic {
+        return RustFragmentPair {
+            cxx_bridge: vec![quote! {
+                extern "Rust" {
+                    #[cxx_name = #getter_wrapper_cpp]
+                    #has_unsafe fn #getter_rust(self: &#cpp_class_name_rust) -> #cxx_ty;
+                }
+            }],
+            implementation: vec![],
+        };
+    }
+
+    // Expose a wrapper to C++ instead, which catches any panic so that it can be handled by C++
+    let getter_wrapper_rust = &idents.getter_wrapper.rust;
+    let qualified_ty = syn_type_cxx_bridge_to_qualified(cxx_ty, qualified_mappings);
+    let qualified_impl =
+        syn_ident_cxx_bridge_to_qualified_impl(cpp_class_name_rust, qualified_mappings);
+    RustFragmentPair {
+        cxx_bridge: vec![quote! {
+            extern "Rust" {
+                #[cxx_name = #getter_wrapper_cpp]
+                #has_unsafe fn #getter_wrapper_rust(self: &#cpp_class_name_rust) -> Result<#cxx_ty>;
+            }
+        }],
+        implementation: vec![quote! {
+            impl #qualified_impl {
+                #[doc(hidden)]
+                fn #getter_wrapper_rust(&self) -> core::result::Result<#qualified_ty, String> {
+                    cxx_qt::catch_panic(move || self.#getter_rust())
+                }
+            }
+        }],
+    }
+}
@@ -4,6 +4,7 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 pub mod getter;
+pub mod reset;
 pub mod setter;
 pub mod signal;
 
@@ -12,7 +13,7 @@ use crate::{
         naming::{property::QPropertyName, qobject::QObjectName},
         rust::qobject::GeneratedRustQObject,
     },
-    parser::property::ParsedQProperty,
+    parser::{panic::ParsedQPanic, property::ParsedQProperty},
 };
 use std::collections::BTreeMap;
 use syn::{Ident, Path, Result};
@@ -23,6 +24,8 @@ pub fn generate_rust_properties(
     properties: &Vec<ParsedQProperty>,
     qobject_idents: &QObjectName,
     qualified_mappings: &BTreeMap<Ident, Path>,
+    trace: bool,
+    panic: ParsedQPanic,
 ) -> Result<GeneratedRustQObject> {
     let mut generated = GeneratedRustQObject::default();
     let mut signals = vec![];
@@ -30,8 +33,18 @@ pub fn generate_rust_properties(
     for property in properties {
         let idents = QPropertyName::from(property);
 
-        // Getters
-        let getter = getter::generate(&idents, qobject_idents, &property.ty, qualified_mappings);
+        // Getters, a computed property uses the getter which is implemented by the developer
+        let getter = if property.flags.computed {
+            getter::generate_computed(
+                &idents,
+                qobject_idents,
+                &property.ty,
+                qualified_mappings,
+                panic.catches(),
+            )
+        } else {
+            getter::generate(&idents, qobject_idents, &property.ty, qualified_mappings)
+        };
         generated
             .cxx_mod_contents
             .append(&mut getter.cxx_bridge_as_items()?);
@@ -39,17 +52,53 @@ pub fn generate_rust_properties(
             .cxx_qt_mod_contents
             .append(&mut getter.implementation_as_items()?);
 
+        // The reset function is implemented by the developer
+        if let (Some(reset), Some(reset_wrapper)) = (&idents.reset, &idents.reset_wrapper) {
+            let reset = reset::generate(
+                reset,
+                reset_wrapper,
+                qobject_idents,
+                qualified_mappings,
+                trace,
+                panic.catches(),
+            );
+            generated
+                .cxx_mod_contents
+                .append(&mut reset.cxx_bridge_as_items()?);
+            generated
+                .cxx_qt_mod_contents
+                .append(&mut reset.implementation_as_items()?);
+        }
+
+        // A constant property has neither a setter nor a notify signal
+        if property.flags.constant {
+            continue;
+        }
+
+        // Signals
+        signals.push(signal::generate(&idents, qobject_idents));
+
+        // A read only property has no setter, so is changed from Rust with rust_mut and the notify signal
+        // and a computed property has no setter, its notify signal is emitted when the computed value changes
+        if property.flags.read_only || property.flags.computed {
+            continue;
+        }
+
         // Setters
-        let setter = setter::generate(&idents, qobject_idents, &property.ty, qualified_mappings);
+        let setter = setter::generate(
+            &idents,
+            qobject_idents,
+            &property.ty,
+            qualified_mappings,
+            trace,
+            panic.catches(),
+        );
         generated
             .cxx_mod_contents
             .append(&mut setter.cxx_bridge_as_items()?);
         generated
             .cxx_qt_mod_contents
             .append(&mut setter.implementation_as_items()?);
-
-        // Signals
-        signals.push(signal::generate(&idents, qobject_idents));
     }
 
     generated.append(&mut generate_rust_signals(
@@ -65,8 +114,11 @@ pub fn generate_rust_properties(
 mod tests {
     use super::*;
 
-    use crate::{generator::naming::qobject::tests::create_qobjectname, tests::assert_tokens_eq};
-    use quote::format_ident;
+    use crate::{
+        generator::naming::qobject::tests::create_qobjectname, parser::property::QPropertyFlags,
+        tests::assert_tokens_eq,
+    };
+    use quote::{format_ident, ToTokens};
     use syn::parse_quote;
 
     #[test]
@@ -75,14 +127,23 @@ mod tests {
             ParsedQProperty {
                 ident: format_ident!("trivial_property"),
                 ty: parse_quote! { i32 },
+                revision: None,
+                deprecated: None,
+                flags: QPropertyFlags::default(),
             },
             ParsedQProperty {
                 ident: format_ident!("opaque_property"),
                 ty: parse_quote! { UniquePtr<QColor> },
+                revision: None,
+                deprecated: None,
+                flags: QPropertyFlags::default(),
             },
             ParsedQProperty {
                 ident: format_ident!("unsafe_property"),
                 ty: parse_quote! { *mut T },
+                revision: None,
+                deprecated: None,
+                flags: QPropertyFlags::default(),
             },
         ];
         let qobject_idents = create_qobjectname();
@@ -91,6 +152,8 @@ mod tests {
             &properties,
             &qobject_idents,
             &BTreeMap::<Ident, Path>::default(),
+            false,
+            ParsedQPanic::Abort,
         )
         .unwrap();
 
@@ -382,4 +445,283 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn test_generate_rust_properties_constant() {
+        let properties = vec![ParsedQProperty {
+            ident: format_ident!("constant_property"),
+            ty: parse_quote! { i32 },
+            revision: None,
+            deprecated: None,
+            flags: QPropertyFlags {
+                constant: true,
+                ..Default::default()
+            },
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let generated = generate_rust_properties(
+            &properties,
+            &qobject_idents,
+            &BTreeMap::<Ident, Path>::default(),
+            false,
+            ParsedQPanic::Abort,
+        )
+        .unwrap();
+
+        // Only the getter is generated, without a setter or notify signal
+        assert_eq!(generated.cxx_mod_contents.len(), 1);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 1);
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            parse_quote! {
+                extern "Rust" {
+                    #[cxx_name = "getConstantPropertyWrapper"]
+                    unsafe fn constant_property<'a>(self: &'a MyObject) -> &'a i32;
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_properties_read_only() {
+        let properties = vec![ParsedQProperty {
+            ident: format_ident!("read_only_property"),
+            ty: parse_quote! { i32 },
+            revision: None,
+            deprecated: None,
+            flags: QPropertyFlags {
+                read_only: true,
+                ..Default::default()
+            },
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let generated = generate_rust_properties(
+            &properties,
+            &qobject_idents,
+            &BTreeMap::<Ident, Path>::default(),
+            false,
+            ParsedQPanic::Abort,
+        )
+        .unwrap();
+
+        // The getter and the notify signal are generated, but not the setter
+        assert!(!generated.cxx_mod_contents.iter().any(|item| item
+            .to_token_stream()
+            .to_string()
+            .contains("set_read_only_property")));
+        assert!(generated.cxx_mod_contents.iter().any(|item| item
+            .to_token_stream()
+            .to_string()
+            .contains("read_only_property_changed")));
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            parse_quote! {
+                extern "Rust" {
+                    #[cxx_name = "getReadOnlyPropertyWrapper"]
+                    unsafe fn read_only_property<'a>(self: &'a MyObject) -> &'a i32;
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_properties_computed() {
+        let properties = vec![ParsedQProperty {
+            ident: format_ident!("computed_property"),
+            ty: parse_quote! { i32 },
+            revision: None,
+            deprecated: None,
+            flags: QPropertyFlags {
+                computed: true,
+                read: Some(format_ident!("compute")),
+                ..Default::default()
+            },
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let generated = generate_rust_properties(
+            &properties,
+            &qobject_idents,
+            &BTreeMap::<Ident, Path>::default(),
+            false,
+            ParsedQPanic::Abort,
+        )
+        .unwrap();
+
+        // The user defined getter is bound directly and there is no setter
+        assert!(!generated.cxx_mod_contents.iter().any(|item| item
+            .to_token_stream()
+            .to_string()
+            .contains("set_computed_property")));
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            parse_quote! {
+                extern "Rust" {
+                    #[cxx_name = "computeWrapper"]
+                    fn compute(self: &MyObject) -> i32;
+                }
+            },
+        );
+        assert!(!generated
+            .cxx_qt_mod_contents
+            .iter()
+            .any(|item| item.to_token_stream().to_string().contains("fn compute")));
+
+        let generated = generate_rust_properties(
+            &properties,
+            &qobject_idents,
+            &BTreeMap::<Ident, Path>::default(),
+            false,
+            ParsedQPanic::Signal,
+        )
+        .unwrap();
+
+        // The user defined getter is exposed to C++ through a wrapper which catches panics
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            parse_quote! {
+                extern "Rust" {
+                    #[cxx_name = "computeWrapper"]
+                    fn compute_wrapper(self: &MyObject) -> Result<i32>;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            parse_quote! {
+                impl MyObject {
+                    #[doc(hidden)]
+                    fn compute_wrapper(&self) -> core::result::Result<i32, String> {
+                        cxx_qt::catch_panic(move | | self.compute())
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_properties_reset() {
+        let properties = vec![ParsedQProperty {
+            ident: format_ident!("trivial_property"),
+            ty: parse_quote! { i32 },
+            revision: None,
+            deprecated: None,
+            flags: QPropertyFlags {
+                reset: Some(format_ident!("reset_trivial_property")),
+                ..Default::default()
+            },
+        }];
+        let qobject_idents = create_qobjectname();
+        let find_reset = |items: &Vec<syn::Item>| {
+            items
+                .iter()
+                .find(|item| {
+                    item.to_token_stream()
+                        .to_string()
+                        .contains("reset_trivial_property")
+                })
+                .cloned()
+                .unwrap()
+        };
+
+        let generated = generate_rust_properties(
+            &properties,
+            &qobject_idents,
+            &BTreeMap::<Ident, Path>::default(),
+            false,
+            ParsedQPanic::Abort,
+        )
+        .unwrap();
+
+        // The user defined reset function is bound directly
+        assert_tokens_eq(
+            &find_reset(&generated.cxx_mod_contents),
+            parse_quote! {
+                extern "Rust" {
+                    #[cxx_name = "resetTrivialPropertyWrapper"]
+                    fn reset_trivial_property(self: Pin<&mut MyObject>);
+                }
+            },
+        );
+        assert!(!generated.cxx_qt_mod_contents.iter().any(|item| item
+            .to_token_stream()
+            .to_string()
+            .contains("reset_trivial_property")));
+
+        let generated = generate_rust_properties(
+            &properties,
+            &qobject_idents,
+            &BTreeMap::<Ident, Path>::default(),
+            false,
+            ParsedQPanic::Signal,
+        )
+        .unwrap();
+
+        // The user defined reset function is exposed to C++ through a wrapper which catches panics
+        assert_tokens_eq(
+            &find_reset(&generated.cxx_mod_contents),
+            parse_quote! {
+                extern "Rust" {
+                    #[cxx_name = "resetTrivialPropertyWrapper"]
+                    fn reset_trivial_property_wrapper(self: Pin<&mut MyObject>) -> Result<()>;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &find_reset(&generated.cxx_qt_mod_contents),
+            parse_quote! {
+                impl MyObject {
+                    #[doc(hidden)]
+                    fn reset_trivial_property_wrapper(self: core::pin::Pin<&mut Self>) -> core::result::Result<(), String> {
+                        cxx_qt::catch_panic(move | | self.reset_trivial_property())
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_properties_panic() {
+        let properties = vec![ParsedQProperty {
+            ident: format_ident!("trivial_property"),
+            ty: parse_quote! { i32 },
+            revision: None,
+            deprecated: None,
+            flags: QPropertyFlags::default(),
+        }];
+        let qobject_idents = create_qobjectname();
+
+        let generated = generate_rust_properties(
+            &properties,
+            &qobject_idents,
+            &BTreeMap::<Ident, Path>::default(),
+            false,
+            ParsedQPanic::Signal,
+        )
+        .unwrap();
+
+        // The setter is exposed to C++ through a wrapper which catches panics
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[1],
+            parse_quote! {
+                extern "Rust" {
+                    #[cxx_name = "setTrivialPropertyWrapper"]
+                    fn set_trivial_property_wrapper(self: Pin<&mut MyObject>, value: i32) -> Result<()>;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[2],
+            parse_quote! {
+                impl MyObject {
+                    #[doc(hidden)]
+                    fn set_trivial_property_wrapper(self: core::pin::Pin<&mut Self>, value: i32) -> core::result::Result<(), String> {
+                        cxx_qt::catch_panic(move | | self.set_trivial_property(value))
+                    }
+                }
+            },
+        );
+    }
 }
@@ -0,0 +1,72 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::generator::{
+    naming::{qobject::QObjectName, CombinedIdent},
+    rust::{fragment::RustFragmentPair, trace},
+    utils::rust::syn_ident_cxx_bridge_to_qualified_impl,
+};
+use quote::quote;
+use std::collections::BTreeMap;
+use syn::{Ident, Path};
+
+pub fn generate(
+    reset: &CombinedIdent,
+    reset_wrapper: &CombinedIdent,
+    qobject_idents: &QObjectName,
+    qualified_mappings: &BTreeMap<Ident, Path>,
+    trace: bool,
+    catch_panic: bool,
+) -> RustFragmentPair {
+    let cpp_class_name_rust = &qobject_idents.cpp_class.rust;
+    let reset_wrapper_cpp = reset_wrapper.cpp.to_string();
+    let reset_rust = &reset.rust;
+
+    // The reset function is implemented by the developer, so it is bound directly
+    if !trace && !catch_panic {
+        return RustFragmentPair {
+            cxx_bridge: vec![quote! {
+                extern "Rust" {
+                    #[cxx_name = #reset_wrapper_cpp]
+                    fn #reset_rust(self: Pin<&mut #cpp_class_name_rust>);
+                }
+            }],
+            implementation: vec![],
+        };
+    }
+
+    // Expose a wrapper to C++ instead, which traces the call or catches any panic so that it can be handled by C++
+    let reset_wrapper_rust = &reset_wrapper.rust;
+    let qualified_impl =
+        syn_ident_cxx_bridge_to_qualified_impl(cpp_class_name_rust, qualified_mappings);
+    let span = trace.then(|| trace::generate_span(&format!("{cpp_class_name_rust}::{reset_rust}")));
+    let (return_cxx_ty, return_ty, call) = if catch_panic {
+        (
+            quote! { -> Result<()> },
+            quote! { -> core::result::Result<(), String> },
+            quote! { cxx_qt::catch_panic(move || self.#reset_rust()) },
+        )
+    } else {
+        (quote! {}, quote! {}, quote! { self.#reset_rust() })
+    };
+
+    RustFragmentPair {
+        cxx_bridge: vec![quote! {
+            extern "Rust" {
+                #[cxx_name = #reset_wrapper_cpp]
+                fn #reset_wrapper_rust(self: Pin<&mut #cpp_class_name_rust>) #return_cxx_ty;
+            }
+        }],
+        implementation: vec![quote! {
+            impl #qualified_impl {
+                #[doc(hidden)]
+                fn #reset_wrapper_rust(self: core::pin::Pin<&mut Self>) #return_ty {
+                    #span
+                    #call
+                }
+            }
+        }],
+    }
+}
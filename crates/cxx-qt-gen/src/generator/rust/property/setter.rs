@@ -5,7 +5,7 @@
 
 use crate::generator::{
     naming::{property::QPropertyName, qobject::QObjectName},
-    rust::fragment::RustFragmentPair,
+    rust::{fragment::RustFragmentPair, trace},
     utils::rust::{
         syn_ident_cxx_bridge_to_qualified_impl, syn_type_cxx_bridge_to_qualified,
         syn_type_is_cxx_bridge_unsafe,
@@ -20,6 +20,8 @@ pub fn generate(
     qobject_idents: &QObjectName,
     cxx_ty: &Type,
     qualified_mappings: &BTreeMap<Ident, Path>,
+    trace: bool,
+    catch_panic: bool,
 ) -> RustFragmentPair {
     let cpp_class_name_rust = &qobject_idents.cpp_class.rust;
     let setter_wrapper_cpp = idents.setter_wrapper.cpp.to_string();
@@ -31,6 +33,9 @@ pub fn generate(
     let qualified_impl =
         syn_ident_cxx_bridge_to_qualified_impl(cpp_class_name_rust, qualified_mappings);
 
+    let span =
+        trace.then(|| trace::generate_span(&format!("{cpp_class_name_rust}::{setter_rust}")));
+
     // Determine if unsafe is required due to an unsafe type
     let has_unsafe = if syn_type_is_cxx_bridge_unsafe(cxx_ty) {
         quote! { unsafe }
@@ -38,7 +43,7 @@ pub fn generate(
         quote! {}
     };
 
-    RustFragmentPair {
+    let mut fragment = RustFragmentPair {
         cxx_bridge: vec![quote! {
             extern "Rust" {
                 #[cxx_name = #setter_wrapper_cpp]
@@ -51,6 +56,7 @@ pub fn generate(
                 #[doc = #ident_str]
                 pub fn #setter_rust(mut self: core::pin::Pin<&mut Self>, value: #qualified_ty) {
                     use cxx_qt::CxxQtType;
+                    #span
                     if self.#ident == value {
                         // don't want to set the value again and reemit the signal,
                         // as this can cause binding loops
@@ -61,5 +67,26 @@ pub fn generate(
                 }
             }
         }],
+    };
+
+    // Expose a wrapper to C++ instead, which catches any panic so that it can be handled by C++
+    if catch_panic {
+        let setter_wrapper_rust = &idents.setter_wrapper.rust;
+        fragment.cxx_bridge = vec![quote! {
+            extern "Rust" {
+                #[cxx_name = #setter_wrapper_cpp]
+                #has_unsafe fn #setter_wrapper_rust(self: Pin<&mut #cpp_class_name_rust>, value: #cxx_ty) -> Result<()>;
+            }
+        }];
+        fragment.implementation.push(quote! {
+            impl #qualified_impl {
+                #[doc(hidden)]
+                fn #setter_wrapper_rust(self: core::pin::Pin<&mut Self>, value: #qualified_ty) -> core::result::Result<(), String> {
+                    cxx_qt::catch_panic(move || self.#setter_rust(value))
+                }
+            }
+        });
     }
+
+    fragment
 }
@@ -3,9 +3,17 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::{generator::rust::qobject::GeneratedRustQObject, parser::qenum::ParsedQEnum};
+use std::collections::BTreeMap;
+
+use crate::{
+    generator::{
+        naming::qobject::QObjectName, rust::qobject::GeneratedRustQObject,
+        utils::rust::syn_ident_cxx_bridge_to_qualified_impl,
+    },
+    parser::qenum::ParsedQEnum,
+};
 use quote::quote;
-use syn::{parse_quote, Item};
+use syn::{parse_quote, Ident, Item, Path};
 
 pub fn generate_cxx_mod_contents(qenums: &[ParsedQEnum]) -> Vec<Item> {
     qenums
@@ -19,7 +27,7 @@ pub fn generate_cxx_mod_contents(qenums: &[ParsedQEnum]) -> Vec<Item> {
             } else {
                 quote! { #[namespace = #namespace ] }
             };
-            vec![
+            let mut items: Vec<Item> = vec![
                 parse_quote! {
                     #[repr(i32)]
                     #qenum_item
@@ -30,23 +38,226 @@ pub fn generate_cxx_mod_contents(qenums: &[ParsedQEnum]) -> Vec<Item> {
                         type #qenum_ident;
                     }
                 },
-            ]
-            .into_iter()
+            ];
+            // The QFlags type is defined outside of the bridge and is trivial like the enum
+            if let Some(flags) = &qenum.flags {
+                items.push(parse_quote! {
+                    extern "C++" {
+                        #namespace
+                        type #flags = super::#flags;
+                    }
+                });
+            }
+            items.into_iter()
         })
         .collect()
 }
 
-pub fn generate(qenums: &[ParsedQEnum]) -> GeneratedRustQObject {
+/// Generate the Rust type of QFlags, which stores an OR-combination of the variants of the QEnum
+///
+/// The namespace is used when the QEnum does not have its own namespace.
+fn generate_flags(
+    qenum: &ParsedQEnum,
+    flags: &Ident,
+    namespace: &str,
+    module_ident: &Ident,
+) -> Vec<Item> {
+    let qenum_ident = &qenum.ident;
+    let namespace = if qenum.namespace.is_empty() {
+        namespace
+    } else {
+        &qenum.namespace
+    };
+    let type_id = if namespace.is_empty() {
+        flags.to_string()
+    } else {
+        format!("{namespace}::{flags}")
+    };
+    let doc = format!(
+        " The {flags} type stores an OR-combination of [{qenum_ident}]({module_ident}::{qenum_ident}) values."
+    );
+    let debug_format = format!("{flags}({{:#x}})");
+
+    vec![
+        parse_quote! {
+            #[doc = #doc]
+            #[repr(transparent)]
+            #[derive(Clone, Copy, Default, PartialEq, Eq)]
+            pub struct #flags {
+                i: i32,
+            }
+        },
+        parse_quote! {
+            impl #flags {
+                /// Sets the flag if on is true or unsets it if on is false.
+                pub fn set_flag(&mut self, flag: #module_ident::#qenum_ident, on: bool) {
+                    if on {
+                        self.i |= flag.repr;
+                    } else {
+                        self.i &= !flag.repr;
+                    }
+                }
+
+                /// Returns true if the flag is set, otherwise false.
+                pub fn test_flag(&self, flag: #module_ident::#qenum_ident) -> bool {
+                    (self.i & flag.repr) == flag.repr
+                }
+
+                /// Returns the value stored in the flags as an integer.
+                pub fn to_int(&self) -> i32 {
+                    self.i
+                }
+            }
+        },
+        parse_quote! {
+            impl From<#module_ident::#qenum_ident> for #flags {
+                fn from(flag: #module_ident::#qenum_ident) -> Self {
+                    Self { i: flag.repr }
+                }
+            }
+        },
+        parse_quote! {
+            impl core::ops::BitOr<#module_ident::#qenum_ident> for #flags {
+                type Output = Self;
+
+                fn bitor(self, flag: #module_ident::#qenum_ident) -> Self {
+                    Self {
+                        i: self.i | flag.repr,
+                    }
+                }
+            }
+        },
+        parse_quote! {
+            impl core::ops::BitOr for #module_ident::#qenum_ident {
+                type Output = #flags;
+
+                fn bitor(self, other: Self) -> #flags {
+                    #flags::from(self) | other
+                }
+            }
+        },
+        parse_quote! {
+            impl core::ops::BitOrAssign<#module_ident::#qenum_ident> for #flags {
+                fn bitor_assign(&mut self, flag: #module_ident::#qenum_ident) {
+                    self.i |= flag.repr;
+                }
+            }
+        },
+        parse_quote! {
+            impl core::fmt::Debug for #flags {
+                fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    write!(f, #debug_format, self.i)
+                }
+            }
+        },
+        parse_quote! {
+            // Safety:
+            //
+            // The QFlags of a QEnum with an i32 representation has a single i32 member.
+            unsafe impl cxx::ExternType for #flags {
+                type Id = cxx::type_id!(#type_id);
+                type Kind = cxx::kind::Trivial;
+            }
+        },
+    ]
+}
+
+/// Generate the variants and their names as constants, so that Rust code such as
+/// the role names of a model can be built from the same list as the QML values
+///
+/// For QEnums registered as flags the QFlags type is also generated, the namespace is used
+/// for its C++ type when the QEnum does not have its own namespace.
+pub fn generate_cxx_qt_mod_contents(
+    qenums: &[ParsedQEnum],
+    namespace: &str,
+    module_ident: &Ident,
+) -> Vec<Item> {
+    qenums
+        .iter()
+        .flat_map(|qenum| {
+            let qenum_ident = &qenum.ident;
+            let len = proc_macro2::Literal::usize_unsuffixed(qenum.variants.len());
+            let variants = &qenum.variants;
+            let names = qenum.variants.iter().map(Ident::to_string);
+            let mut items: Vec<Item> = vec![parse_quote! {
+                impl #module_ident::#qenum_ident {
+                    /// The variants of the QEnum in the order they are declared
+                    pub const VARIANTS: [Self; #len] = [#(Self::#variants),*];
+
+                    /// The names of the variants of the QEnum as seen from QML, in the same order as VARIANTS
+                    pub const NAMES: [&str; #len] = [#(#names),*];
+                }
+            }];
+            if let Some(flags) = &qenum.flags {
+                items.extend(generate_flags(qenum, flags, namespace, module_ident));
+            }
+            items.into_iter()
+        })
+        .collect()
+}
+
+/// Generate the `cxx_qt::QmlError` implementation of a `#[qml_error]` QEnum and the `emit_error` method of its QObject
+pub fn generate_qml_error(
+    qenum: &ParsedQEnum,
+    qobject_idents: &QObjectName,
+    qualified_mappings: &BTreeMap<Ident, Path>,
+    module_ident: &Ident,
+) -> GeneratedRustQObject {
+    let qenum_ident = &qenum.ident;
+    let variants = &qenum.variants;
+    let messages = qenum.error_messages();
+    let qualified_impl =
+        syn_ident_cxx_bridge_to_qualified_impl(&qobject_idents.cpp_class.rust, qualified_mappings);
+
+    GeneratedRustQObject {
+        cxx_mod_contents: vec![],
+        cxx_qt_mod_contents: vec![
+            parse_quote! {
+                impl cxx_qt::QmlError for #module_ident::#qenum_ident {
+                    type Code = Self;
+
+                    fn code(&self) -> Self {
+                        *self
+                    }
+
+                    fn message(&self) -> String {
+                        match *self {
+                            #(Self::#variants => #messages.to_owned(),)*
+                            _ => String::new(),
+                        }
+                    }
+                }
+            },
+            parse_quote! {
+                impl #qualified_impl {
+                    /// Emit the error_occurred signal with the code and the message of the error
+                    pub fn emit_error(
+                        self: core::pin::Pin<&mut Self>,
+                        error: &impl cxx_qt::QmlError<Code = #module_ident::#qenum_ident>,
+                    ) {
+                        self.error_occurred(error.code(), core::convert::From::from(error.message()));
+                    }
+                }
+            },
+        ],
+    }
+}
+
+pub fn generate(
+    qenums: &[ParsedQEnum],
+    namespace: &str,
+    module_ident: &Ident,
+) -> GeneratedRustQObject {
     GeneratedRustQObject {
         cxx_mod_contents: generate_cxx_mod_contents(qenums),
-        ..Default::default()
+        cxx_qt_mod_contents: generate_cxx_qt_mod_contents(qenums, namespace, module_ident),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::tests::assert_tokens_eq;
-    use quote::quote;
+    use quote::{format_ident, quote};
     use syn::parse_quote;
 
     use super::*;
@@ -64,7 +275,7 @@ mod tests {
         })
         .unwrap()];
 
-        let generated = generate(&qenums);
+        let generated = generate(&qenums, "", &format_ident!("qobject"));
         assert_eq!(generated.cxx_mod_contents.len(), 2);
         assert_tokens_eq(
             &generated.cxx_mod_contents[0],
@@ -86,6 +297,77 @@ mod tests {
                     type MyEnum;
                 }
             },
-        )
+        );
+
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 1);
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl qobject::MyEnum {
+                    #[doc = r" The variants of the QEnum in the order they are declared"]
+                    pub const VARIANTS: [Self; 2] = [Self::Variant1, Self::Variant2];
+
+                    #[doc = r" The names of the variants of the QEnum as seen from QML, in the same order as VARIANTS"]
+                    pub const NAMES: [&str; 2] = ["Variant1", "Variant2"];
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn generates_qml_error() {
+        let qenum = ParsedQEnum::parse(parse_quote! {
+            #[qml_error]
+            enum MyError {
+                /// The request timed out
+                Timeout,
+                NotFound,
+            }
+        })
+        .unwrap();
+        let qobject_idents = crate::generator::naming::qobject::tests::create_qobjectname();
+
+        let generated = generate_qml_error(
+            &qenum,
+            &qobject_idents,
+            &BTreeMap::<Ident, Path>::default(),
+            &format_ident!("qobject"),
+        );
+        assert!(generated.cxx_mod_contents.is_empty());
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 2);
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl cxx_qt::QmlError for qobject::MyError {
+                    type Code = Self;
+
+                    fn code(&self) -> Self {
+                        *self
+                    }
+
+                    fn message(&self) -> String {
+                        match *self {
+                            Self::Timeout => "The request timed out".to_owned(),
+                            Self::NotFound => "NotFound".to_owned(),
+                            _ => String::new(),
+                        }
+                    }
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[1],
+            quote! {
+                impl MyObject {
+                    #[doc = r" Emit the error_occurred signal with the code and the message of the error"]
+                    pub fn emit_error(
+                        self: core::pin::Pin<&mut Self>,
+                        error: &impl cxx_qt::QmlError<Code = qobject::MyError>,
+                    ) {
+                        self.error_occurred(error.code(), core::convert::From::from(error.message()));
+                    }
+                }
+            },
+        );
     }
 }
@@ -0,0 +1,117 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use std::collections::BTreeMap;
+
+use crate::generator::{
+    naming::{namespace::NamespaceName, qobject::QObjectName},
+    rust::{fragment::RustFragmentPair, qobject::GeneratedRustQObject},
+    utils::rust::syn_ident_cxx_bridge_to_qualified_impl,
+};
+use quote::quote;
+use syn::{Ident, Path, Result};
+
+pub fn generate(
+    qobject_ident: &QObjectName,
+    namespace_ident: &NamespaceName,
+    qualified_mappings: &BTreeMap<Ident, Path>,
+    module_ident: &Ident,
+) -> Result<GeneratedRustQObject> {
+    let mut blocks = GeneratedRustQObject::default();
+
+    let cpp_struct_ident = &qobject_ident.cpp_class.rust;
+    let factory_cpp = qobject_ident.qml_factory_new.to_string();
+    let factory_rust = qobject_ident.cxx_qt_thread_method("new_with_ownership");
+    let namespace = &namespace_ident.namespace;
+    let qualified_impl =
+        syn_ident_cxx_bridge_to_qualified_impl(cpp_struct_ident, qualified_mappings);
+
+    let fragment = RustFragmentPair {
+        cxx_bridge: vec![quote! {
+            unsafe extern "C++" {
+                include!("cxx-qt-common/cxxqt_qmlfactory.h");
+
+                #[doc(hidden)]
+                #[namespace = #namespace]
+                #[cxx_name = #factory_cpp]
+                fn #factory_rust(ownership: i32) -> *mut #cpp_struct_ident;
+            }
+        }],
+        implementation: vec![quote! {
+            impl cxx_qt::QmlFactory for #qualified_impl {
+                fn new_with_ownership(ownership: cxx_qt::QmlOwnership) -> *mut #qualified_impl
+                {
+                    #module_ident::#factory_rust(ownership as i32)
+                }
+            }
+        }],
+    };
+
+    blocks
+        .cxx_mod_contents
+        .append(&mut fragment.cxx_bridge_as_items()?);
+    blocks
+        .cxx_qt_mod_contents
+        .append(&mut fragment.implementation_as_items()?);
+
+    Ok(blocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::assert_tokens_eq;
+
+    use crate::parser::qobject::tests::create_parsed_qobject;
+
+    use quote::format_ident;
+
+    #[test]
+    fn test_generate_rust_qml_factory() {
+        let qobject = create_parsed_qobject();
+        let qobject_idents = QObjectName::from(&qobject);
+        let namespace_ident = NamespaceName::from(&qobject);
+
+        let generated = generate(
+            &qobject_idents,
+            &namespace_ident,
+            &BTreeMap::<Ident, Path>::default(),
+            &format_ident!("ffi"),
+        )
+        .unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 1);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 1);
+
+        // CXX bridges
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                unsafe extern "C++" {
+                    include!("cxx-qt-common/cxxqt_qmlfactory.h");
+
+                    #[doc(hidden)]
+                    #[namespace = ""]
+                    #[cxx_name = "cxxQtNewMyObjectWithOwnership"]
+                    fn cxx_qt_ffi_my_object_new_with_ownership(ownership: i32) -> *mut MyObject;
+                }
+            },
+        );
+
+        // CXX-Qt generated contents
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl cxx_qt::QmlFactory for MyObject {
+                    fn new_with_ownership(ownership: cxx_qt::QmlOwnership) -> *mut MyObject
+                    {
+                        ffi::cxx_qt_ffi_my_object_new_with_ownership(ownership as i32)
+                    }
+                }
+            },
+        );
+    }
+}
@@ -9,9 +9,9 @@ use crate::{
     generator::{
         naming::{namespace::NamespaceName, qobject::QObjectName},
         rust::{
-            constructor, cxxqttype, fragment::RustFragmentPair, inherit,
-            method::generate_rust_methods, property::generate_rust_properties,
-            signals::generate_rust_signals, threading,
+            connect, constructor, context, cxxqttype, fragment::RustFragmentPair, inherit,
+            initialized, method::generate_rust_methods, property::generate_rust_properties,
+            qmlfactory, qmlparserstatus, signals::generate_rust_signals, threading, weakref,
         },
         utils::rust::syn_ident_cxx_bridge_to_qualified_impl,
     },
@@ -41,6 +41,7 @@ impl GeneratedRustQObject {
         qobject: &ParsedQObject,
         qualified_mappings: &BTreeMap<Ident, Path>,
         module_ident: &Ident,
+        trace: bool,
     ) -> Result<GeneratedRustQObject> {
         // Create the base object
         let qobject_idents = QObjectName::from(qobject);
@@ -52,15 +53,30 @@ impl GeneratedRustQObject {
             &namespace_idents.namespace,
         )?);
 
+        // A generic Rust struct is monomorphized by an alias which CXX can use as an extern "Rust" type
+        if let Some(ty_right_generic) = &qobject.qobject_ty.ty_right_generic {
+            let rust_struct = &qobject_idents.rust_struct.rust;
+            generated.cxx_qt_mod_contents.push(syn::parse_quote! {
+                #[doc(hidden)]
+                pub type #rust_struct = #ty_right_generic;
+            });
+        }
+
         // Generate methods for the properties, invokables, signals
         generated.append(&mut generate_rust_properties(
             &qobject.properties,
             &qobject_idents,
             qualified_mappings,
+            trace,
+            qobject.panic,
         )?);
         generated.append(&mut generate_rust_methods(
             &qobject.methods,
             &qobject_idents,
+            &namespace_idents,
+            qualified_mappings,
+            trace,
+            qobject.panic,
         )?);
         generated.append(&mut inherit::generate(
             &qobject_idents,
@@ -71,7 +87,33 @@ impl GeneratedRustQObject {
             &qobject_idents,
             qualified_mappings,
         )?);
-        generated.append(&mut qenum::generate(&qobject.qenums));
+        if let Some(signal) = qobject.panic.signal(&qobject_idents.cpp_class.rust)? {
+            generated.append(&mut generate_rust_signals(
+                &vec![signal],
+                &qobject_idents,
+                qualified_mappings,
+            )?);
+        }
+        for qenum in &qobject.qenums {
+            if let Some(signal) = qenum.error_signal(&qobject_idents.cpp_class.rust)? {
+                generated.append(&mut generate_rust_signals(
+                    &vec![signal],
+                    &qobject_idents,
+                    qualified_mappings,
+                )?);
+                generated.append(&mut qenum::generate_qml_error(
+                    qenum,
+                    &qobject_idents,
+                    qualified_mappings,
+                    module_ident,
+                ));
+            }
+        }
+        generated.append(&mut qenum::generate(
+            &qobject.qenums,
+            &qobject.namespace,
+            module_ident,
+        ));
 
         // If this type is a singleton then we need to add an include
         if let Some(qml_metadata) = &qobject.qml_metadata {
@@ -97,6 +139,64 @@ impl GeneratedRustQObject {
                 &namespace_idents,
                 qualified_mappings,
                 module_ident,
+                trace,
+            )?);
+        }
+
+        // If this type has a context in its Rust struct then add generation
+        if qobject.context {
+            generated.append(&mut context::generate(
+                &qobject_idents,
+                &namespace_idents,
+                qualified_mappings,
+            )?);
+        }
+
+        // If this type has the initialized hook then add generation
+        if qobject.initialized {
+            generated.append(&mut initialized::generate(
+                &qobject_idents,
+                &namespace_idents,
+                qualified_mappings,
+            )?);
+        }
+
+        // If this type has weak references enabled then add generation
+        if qobject.weak_ref {
+            generated.append(&mut weakref::generate(
+                &qobject_idents,
+                &namespace_idents,
+                qualified_mappings,
+                module_ident,
+            )?);
+        }
+
+        // If this type has typed connection handles enabled then add generation
+        if qobject.connect {
+            generated.append(&mut connect::generate(
+                qobject,
+                &qobject_idents,
+                qualified_mappings,
+                module_ident,
+            )?);
+        }
+
+        // If this type has the QML factory enabled then add generation
+        if qobject.qml_factory {
+            generated.append(&mut qmlfactory::generate(
+                &qobject_idents,
+                &namespace_idents,
+                qualified_mappings,
+                module_ident,
+            )?);
+        }
+
+        // If this type implements QQmlParserStatus then add generation
+        if qobject.qml_parser_status {
+            generated.append(&mut qmlparserstatus::generate(
+                &qobject_idents,
+                &namespace_idents,
+                qualified_mappings,
             )?);
         }
 
@@ -204,6 +304,7 @@ mod tests {
             parser.cxx_qt_data.qobjects.values().next().unwrap(),
             &BTreeMap::<Ident, Path>::default(),
             &format_ident!("ffi"),
+            false,
         )
         .unwrap();
         assert_eq!(rust.cxx_mod_contents.len(), 6);
@@ -269,4 +370,41 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn test_generated_rust_qobject_blocks_generic() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    type IntList = super::ListOf<i32>;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        let rust = GeneratedRustQObject::from(
+            parser.cxx_qt_data.qobjects.values().next().unwrap(),
+            &BTreeMap::<Ident, Path>::default(),
+            &format_ident!("ffi"),
+            false,
+        )
+        .unwrap();
+        assert_tokens_eq(
+            &rust.cxx_mod_contents[1],
+            quote! {
+                extern "Rust" {
+                    type IntListRust;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &rust.cxx_qt_mod_contents[0],
+            quote! {
+                #[doc(hidden)]
+                pub type IntListRust = ListOf<i32>;
+            },
+        );
+    }
 }
@@ -207,26 +207,33 @@ pub fn generate_rust_signals(
 
         let attrs = &signal.method.attrs;
 
+        let mut cxx_bridge = vec![];
+
+        // A private signal of the base class, such as one with a QPrivateSignal parameter,
+        // cannot be emitted, so only the connection is generated
+        if !(signal.inherit && signal.private) {
+            cxx_bridge.push(quote! {
+                #unsafe_block extern "C++" {
+                    #(#attrs)*
+                    #[rust_name = #signal_name_rust_str]
+                    #unsafe_call fn #signal_name_cpp(self: #self_type_cxx, #(#parameters_cxx),*);
+                }
+            });
+        }
+
+        cxx_bridge.push(quote! {
+            unsafe extern "C++" {
+                #[doc = "Connect the given function pointer to the signal "]
+                #[doc = #signal_name_cpp_str]
+                #[doc = ", so that when the signal is emitted the function pointer is executed."]
+                #[must_use]
+                #[rust_name = #connect_ident_rust_str]
+                fn #connect_ident_cpp(self: #self_type_cxx, func: #unsafe_call fn(#self_type_cxx, #(#parameters_cxx),*), conn_type: CxxQtConnectionType) -> CxxQtQMetaObjectConnection;
+            }
+        });
+
         let fragment = RustFragmentPair {
-            cxx_bridge: vec![
-                quote! {
-                    #unsafe_block extern "C++" {
-                        #(#attrs)*
-                        #[rust_name = #signal_name_rust_str]
-                        #unsafe_call fn #signal_name_cpp(self: #self_type_cxx, #(#parameters_cxx),*);
-                    }
-                },
-                quote! {
-                    unsafe extern "C++" {
-                        #[doc = "Connect the given function pointer to the signal "]
-                        #[doc = #signal_name_cpp_str]
-                        #[doc = ", so that when the signal is emitted the function pointer is executed."]
-                        #[must_use]
-                        #[rust_name = #connect_ident_rust_str]
-                        fn #connect_ident_cpp(self: #self_type_cxx, func: #unsafe_call fn(#self_type_cxx, #(#parameters_cxx),*), conn_type: CxxQtConnectionType) -> CxxQtQMetaObjectConnection;
-                    }
-                },
-            ],
+            cxx_bridge,
             implementation: vec![quote! {
                 impl #qualified_impl {
                     #[doc = "Connect the given function pointer to the signal "]
@@ -261,7 +268,7 @@ mod tests {
     use crate::generator::naming::{qobject::tests::create_qobjectname, CombinedIdent};
     use crate::parser::parameter::ParsedFunctionParameter;
     use crate::tests::assert_tokens_eq;
-    use quote::{format_ident, quote};
+    use quote::{format_ident, quote, ToTokens};
     use syn::parse_quote;
 
     #[test]
@@ -280,6 +287,8 @@ mod tests {
             safe: true,
             inherit: false,
             private: false,
+            revision: None,
+            deprecated: None,
         };
         let qobject_idents = create_qobjectname();
 
@@ -360,6 +369,8 @@ mod tests {
             safe: true,
             inherit: false,
             private: false,
+            revision: None,
+            deprecated: None,
         };
         let qobject_idents = create_qobjectname();
 
@@ -415,6 +426,68 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_generate_rust_signal_emit_without_wrapper() {
+        let qsignal = ParsedSignal {
+            method: parse_quote! {
+                fn moved(self: Pin<&mut MyObject>, index: i32, point: QPointF, name: &QString);
+            },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: true,
+            parameters: vec![
+                ParsedFunctionParameter {
+                    ident: format_ident!("index"),
+                    ty: parse_quote! { i32 },
+                },
+                ParsedFunctionParameter {
+                    ident: format_ident!("point"),
+                    ty: parse_quote! { QPointF },
+                },
+                ParsedFunctionParameter {
+                    ident: format_ident!("name"),
+                    ty: parse_quote! { &QString },
+                },
+            ],
+            ident: CombinedIdent {
+                cpp: format_ident!("moved"),
+                rust: format_ident!("moved"),
+            },
+            safe: true,
+            inherit: false,
+            private: false,
+            revision: None,
+            deprecated: None,
+        };
+        let qobject_idents = create_qobjectname();
+
+        let generated = generate_rust_signals(
+            &vec![qsignal],
+            &qobject_idents,
+            &BTreeMap::<Ident, Path>::default(),
+        )
+        .unwrap();
+
+        // The emit method is the CXX binding of the Q_SIGNAL itself with the declared parameters,
+        // so there is no generated Rust function in between which could convert the arguments
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                unsafe extern "C++" {
+                    #[rust_name = "moved"]
+                    fn moved(self: Pin<&mut MyObject>, index: i32, point: QPointF, name: &QString);
+                }
+            },
+        );
+
+        // The only implementation is the connection helper
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 1);
+        let implementation = generated.cxx_qt_mod_contents[0]
+            .to_token_stream()
+            .to_string();
+        assert!(implementation.contains("pub fn on_moved"));
+        assert!(!implementation.contains("pub fn moved"));
+    }
+
     #[test]
     fn test_generate_rust_signal_unsafe() {
         let qsignal = ParsedSignal {
@@ -434,6 +507,8 @@ mod tests {
             safe: false,
             inherit: false,
             private: false,
+            revision: None,
+            deprecated: None,
         };
         let qobject_idents = create_qobjectname();
 
@@ -505,6 +580,8 @@ mod tests {
             safe: true,
             inherit: true,
             private: false,
+            revision: None,
+            deprecated: None,
         };
         let qobject_idents = create_qobjectname();
 
@@ -560,6 +637,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_generate_rust_signal_existing_private() {
+        let qsignal = ParsedSignal {
+            method: parse_quote! {
+                pub(self) fn rows_inserted(self: Pin<&mut MyObject>);
+            },
+            qobject_ident: format_ident!("MyObject"),
+            mutable: true,
+            parameters: vec![],
+            ident: CombinedIdent {
+                cpp: format_ident!("rowsInserted"),
+                rust: format_ident!("rows_inserted"),
+            },
+            safe: true,
+            inherit: true,
+            private: true,
+            revision: None,
+            deprecated: None,
+        };
+        let qobject_idents = create_qobjectname();
+
+        let generated = generate_rust_signals(
+            &vec![qsignal],
+            &qobject_idents,
+            &BTreeMap::<Ident, Path>::default(),
+        )
+        .unwrap();
+
+        // Only the connection is generated, as the signal cannot be emitted
+        assert_eq!(generated.cxx_mod_contents.len(), 1);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 1);
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                unsafe extern "C++" {
+                    #[doc = "Connect the given function pointer to the signal "]
+                    #[doc = "rowsInserted"]
+                    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+                    #[must_use]
+                    #[rust_name = "connect_rows_inserted"]
+                    fn rowsInsertedConnect(self: Pin<&mut MyObject>, func: fn(Pin<&mut MyObject>, ), conn_type : CxxQtConnectionType) -> CxxQtQMetaObjectConnection;
+                }
+            },
+        );
+    }
+
     #[test]
     fn test_generate_rust_signal_free() {
         let qsignal = ParsedSignal {
@@ -576,6 +700,8 @@ mod tests {
             safe: true,
             inherit: false,
             private: false,
+            revision: None,
+            deprecated: None,
         };
 
         let generated = generate_rust_free_signal(
@@ -658,6 +784,8 @@ mod tests {
             safe: true,
             inherit: false,
             private: true,
+            revision: None,
+            deprecated: None,
         };
 
         let generated = generate_rust_free_signal(
@@ -10,7 +10,7 @@ use crate::generator::{
         namespace::{namespace_combine_ident, NamespaceName},
         qobject::QObjectName,
     },
-    rust::qobject::GeneratedRustQObject,
+    rust::{qobject::GeneratedRustQObject, trace},
     utils::rust::syn_ident_cxx_bridge_to_qualified_impl,
 };
 use quote::quote;
@@ -23,6 +23,7 @@ pub fn generate(
     namespace_ident: &NamespaceName,
     qualified_mappings: &BTreeMap<Ident, Path>,
     module_ident: &Ident,
+    trace: bool,
 ) -> Result<GeneratedRustQObject> {
     let mut blocks = GeneratedRustQObject::default();
 
@@ -32,12 +33,15 @@ pub fn generate(
     let cxx_qt_thread_queue_fn = qobject_ident.cxx_qt_thread_method("queue_boxed_fn");
     let cxx_qt_thread_clone = qobject_ident.cxx_qt_thread_method("threading_clone");
     let cxx_qt_thread_drop = qobject_ident.cxx_qt_thread_method("threading_drop");
+    let cxx_qt_thread_is_destroyed = qobject_ident.cxx_qt_thread_method("is_destroyed");
     let namespace_internals = &namespace_ident.internal;
     let cxx_qt_thread_ident_type_id_str =
         namespace_combine_ident(&namespace_ident.namespace, cxx_qt_thread_ident);
     let qualified_impl =
         syn_ident_cxx_bridge_to_qualified_impl(cpp_struct_ident, qualified_mappings);
 
+    let span = trace.then(|| trace::generate_span(&format!("{cpp_struct_ident}::queued closure")));
+
     let fragment = RustFragmentPair {
         cxx_bridge: vec![
             quote! {
@@ -77,6 +81,11 @@ pub fn generate(
                     #[namespace = "rust::cxxqtlib1"]
                     #[cxx_name = "cxxQtThreadDrop"]
                     fn #cxx_qt_thread_drop(cxx_qt_thread: &mut #cxx_qt_thread_ident);
+
+                    #[doc(hidden)]
+                    #[namespace = "rust::cxxqtlib1"]
+                    #[cxx_name = "cxxQtThreadIsDestroyed"]
+                    fn #cxx_qt_thread_is_destroyed(cxx_qt_thread: &#cxx_qt_thread_ident) -> bool;
                 }
             },
             quote! {
@@ -112,6 +121,7 @@ pub fn generate(
                             obj: core::pin::Pin<&mut #qualified_impl>,
                             arg: std::boxed::Box<#cxx_qt_thread_queued_fn_ident>,
                         ) {
+                            #span
                             (arg.inner)(obj)
                         }
                         let arg = #cxx_qt_thread_queued_fn_ident { inner: std::boxed::Box::new(f) };
@@ -129,6 +139,12 @@ pub fn generate(
                     {
                         #module_ident::#cxx_qt_thread_drop(cxx_qt_thread);
                     }
+
+                    #[doc(hidden)]
+                    fn is_destroyed(cxx_qt_thread: &#module_ident::#cxx_qt_thread_ident) -> bool
+                    {
+                        #module_ident::#cxx_qt_thread_is_destroyed(cxx_qt_thread)
+                    }
                 }
             },
             quote! {
@@ -173,6 +189,7 @@ mod tests {
             &namespace_ident,
             &BTreeMap::<Ident, Path>::default(),
             &format_ident!("ffi"),
+            false,
         )
         .unwrap();
 
@@ -214,6 +231,11 @@ mod tests {
                     #[namespace = "rust::cxxqtlib1"]
                     #[cxx_name = "cxxQtThreadDrop"]
                     fn cxx_qt_ffi_my_object_threading_drop(cxx_qt_thread: &mut MyObjectCxxQtThread);
+
+                    #[doc(hidden)]
+                    #[namespace = "rust::cxxqtlib1"]
+                    #[cxx_name = "cxxQtThreadIsDestroyed"]
+                    fn cxx_qt_ffi_my_object_is_destroyed(cxx_qt_thread: &MyObjectCxxQtThread) -> bool;
                 }
             },
         );
@@ -272,6 +294,12 @@ mod tests {
                     {
                         ffi::cxx_qt_ffi_my_object_threading_drop(cxx_qt_thread);
                     }
+
+                    #[doc(hidden)]
+                    fn is_destroyed(cxx_qt_thread: &ffi::MyObjectCxxQtThread) -> bool
+                    {
+                        ffi::cxx_qt_ffi_my_object_is_destroyed(cxx_qt_thread)
+                    }
                 }
             },
         );
@@ -0,0 +1,33 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generate a statement which enters a tracing span with the given name until the end of the scope
+///
+/// This uses the `tracing` crate which is re-exported by `cxx-qt` when its `tracing` feature is enabled.
+pub fn generate_span(name: &str) -> TokenStream {
+    quote! {
+        let _cxx_qt_span = cxx_qt::tracing::trace_span!(#name).entered();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::assert_tokens_eq;
+
+    #[test]
+    fn test_generate_span() {
+        assert_tokens_eq(
+            &generate_span("MyObject::invokable"),
+            quote! {
+                let _cxx_qt_span = cxx_qt::tracing::trace_span!("MyObject::invokable").entered();
+            },
+        );
+    }
+}
@@ -0,0 +1,204 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use std::collections::BTreeMap;
+
+use crate::generator::{
+    naming::{
+        namespace::{namespace_combine_ident, NamespaceName},
+        qobject::QObjectName,
+    },
+    rust::{fragment::RustFragmentPair, qobject::GeneratedRustQObject},
+    utils::rust::syn_ident_cxx_bridge_to_qualified_impl,
+};
+use quote::quote;
+use syn::{Ident, Path, Result};
+
+pub fn generate(
+    qobject_ident: &QObjectName,
+    namespace_ident: &NamespaceName,
+    qualified_mappings: &BTreeMap<Ident, Path>,
+    module_ident: &Ident,
+) -> Result<GeneratedRustQObject> {
+    let mut blocks = GeneratedRustQObject::default();
+
+    let cpp_struct_ident = &qobject_ident.cpp_class.rust;
+    let weak_ref_ident = &qobject_ident.weak_ref_class;
+    let weak_ref_new = qobject_ident.cxx_qt_thread_method("weak_ref_new");
+    let weak_ref_clone = qobject_ident.cxx_qt_thread_method("weak_ref_clone");
+    let weak_ref_drop = qobject_ident.cxx_qt_thread_method("weak_ref_drop");
+    let weak_ref_get = qobject_ident.cxx_qt_thread_method("weak_ref_get");
+    let weak_ref_ident_type_id_str =
+        namespace_combine_ident(&namespace_ident.namespace, weak_ref_ident);
+    let qualified_impl =
+        syn_ident_cxx_bridge_to_qualified_impl(cpp_struct_ident, qualified_mappings);
+
+    let fragment = RustFragmentPair {
+        cxx_bridge: vec![quote! {
+            unsafe extern "C++" {
+                // Specialised version of QObjectWeakRef, which is a QPointer<T> in C++
+                //
+                // CXX doesn't support having generic types in the function yet
+                // so we cannot have QObjectWeakRef<T> in cxx-qt-lib and then use that here
+                // For now we use a type alias in C++ then use it like a normal type here
+                // <https://github.com/dtolnay/cxx/issues/683>
+                #[doc(hidden)]
+                type #weak_ref_ident = cxx_qt::QObjectWeakRef<#cpp_struct_ident>;
+                include!("cxx-qt-common/cxxqt_weakref.h");
+
+                #[doc(hidden)]
+                #[namespace = "rust::cxxqtlib1"]
+                #[cxx_name = "qobjectWeakRefNew"]
+                fn #weak_ref_new(obj: &#cpp_struct_ident) -> #weak_ref_ident;
+
+                #[doc(hidden)]
+                #[namespace = "rust::cxxqtlib1"]
+                #[cxx_name = "qobjectWeakRefClone"]
+                fn #weak_ref_clone(weak_ref: &#weak_ref_ident) -> #weak_ref_ident;
+
+                #[doc(hidden)]
+                #[namespace = "rust::cxxqtlib1"]
+                #[cxx_name = "qobjectWeakRefDrop"]
+                fn #weak_ref_drop(weak_ref: &mut #weak_ref_ident);
+
+                #[doc(hidden)]
+                #[namespace = "rust::cxxqtlib1"]
+                #[cxx_name = "qobjectWeakRefGet"]
+                fn #weak_ref_get(weak_ref: &#weak_ref_ident) -> *mut #cpp_struct_ident;
+            }
+        }],
+        implementation: vec![quote! {
+            impl cxx_qt::WeakRef for #qualified_impl {
+                type WeakRefTypeId = cxx::type_id!(#weak_ref_ident_type_id_str);
+
+                fn weak_ref(&self) -> #module_ident::#weak_ref_ident
+                {
+                    #module_ident::#weak_ref_new(self)
+                }
+
+                #[doc(hidden)]
+                fn weak_ref_clone(weak_ref: &#module_ident::#weak_ref_ident) -> #module_ident::#weak_ref_ident
+                {
+                    #module_ident::#weak_ref_clone(weak_ref)
+                }
+
+                #[doc(hidden)]
+                fn weak_ref_drop(weak_ref: &mut #module_ident::#weak_ref_ident)
+                {
+                    #module_ident::#weak_ref_drop(weak_ref);
+                }
+
+                #[doc(hidden)]
+                fn weak_ref_get(weak_ref: &#module_ident::#weak_ref_ident) -> *mut #qualified_impl
+                {
+                    #module_ident::#weak_ref_get(weak_ref)
+                }
+            }
+        }],
+    };
+
+    blocks
+        .cxx_mod_contents
+        .append(&mut fragment.cxx_bridge_as_items()?);
+    blocks
+        .cxx_qt_mod_contents
+        .append(&mut fragment.implementation_as_items()?);
+
+    Ok(blocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::assert_tokens_eq;
+
+    use crate::parser::qobject::tests::create_parsed_qobject;
+
+    use quote::format_ident;
+
+    #[test]
+    fn test_generate_rust_weak_ref() {
+        let qobject = create_parsed_qobject();
+        let qobject_idents = QObjectName::from(&qobject);
+        let namespace_ident = NamespaceName::from(&qobject);
+
+        let generated = generate(
+            &qobject_idents,
+            &namespace_ident,
+            &BTreeMap::<Ident, Path>::default(),
+            &format_ident!("ffi"),
+        )
+        .unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 1);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 1);
+
+        // CXX bridges
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                unsafe extern "C++" {
+                    #[doc(hidden)]
+                    type MyObjectQObjectWeakRef = cxx_qt::QObjectWeakRef<MyObject>;
+                    include!("cxx-qt-common/cxxqt_weakref.h");
+
+                    #[doc(hidden)]
+                    #[namespace = "rust::cxxqtlib1"]
+                    #[cxx_name = "qobjectWeakRefNew"]
+                    fn cxx_qt_ffi_my_object_weak_ref_new(obj: &MyObject) -> MyObjectQObjectWeakRef;
+
+                    #[doc(hidden)]
+                    #[namespace = "rust::cxxqtlib1"]
+                    #[cxx_name = "qobjectWeakRefClone"]
+                    fn cxx_qt_ffi_my_object_weak_ref_clone(weak_ref: &MyObjectQObjectWeakRef) -> MyObjectQObjectWeakRef;
+
+                    #[doc(hidden)]
+                    #[namespace = "rust::cxxqtlib1"]
+                    #[cxx_name = "qobjectWeakRefDrop"]
+                    fn cxx_qt_ffi_my_object_weak_ref_drop(weak_ref: &mut MyObjectQObjectWeakRef);
+
+                    #[doc(hidden)]
+                    #[namespace = "rust::cxxqtlib1"]
+                    #[cxx_name = "qobjectWeakRefGet"]
+                    fn cxx_qt_ffi_my_object_weak_ref_get(weak_ref: &MyObjectQObjectWeakRef) -> *mut MyObject;
+                }
+            },
+        );
+
+        // CXX-Qt generated contents
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl cxx_qt::WeakRef for MyObject {
+                    type WeakRefTypeId = cxx::type_id!("MyObjectQObjectWeakRef");
+
+                    fn weak_ref(&self) -> ffi::MyObjectQObjectWeakRef
+                    {
+                        ffi::cxx_qt_ffi_my_object_weak_ref_new(self)
+                    }
+
+                    #[doc(hidden)]
+                    fn weak_ref_clone(weak_ref: &ffi::MyObjectQObjectWeakRef) -> ffi::MyObjectQObjectWeakRef
+                    {
+                        ffi::cxx_qt_ffi_my_object_weak_ref_clone(weak_ref)
+                    }
+
+                    #[doc(hidden)]
+                    fn weak_ref_drop(weak_ref: &mut ffi::MyObjectQObjectWeakRef)
+                    {
+                        ffi::cxx_qt_ffi_my_object_weak_ref_drop(weak_ref);
+                    }
+
+                    #[doc(hidden)]
+                    fn weak_ref_get(weak_ref: &ffi::MyObjectQObjectWeakRef) -> *mut MyObject
+                    {
+                        ffi::cxx_qt_ffi_my_object_weak_ref_get(weak_ref)
+                    }
+                }
+            },
+        );
+    }
+}
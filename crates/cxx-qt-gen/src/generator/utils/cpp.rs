@@ -4,6 +4,7 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use crate::parser::mappings::ParsedCxxMappings;
+use quote::ToTokens;
 use syn::{
     spanned::Spanned, Error, Expr, GenericArgument, Lit, PathArguments, PathSegment, Result,
     ReturnType, Type, TypeArray, TypeBareFn, TypePtr, TypeReference, TypeSlice,
@@ -166,7 +167,7 @@ pub(crate) fn syn_type_to_cpp_type(ty: &Type, cxx_mapping: &ParsedCxxMappings) -
         Type::Tuple(tuple) if tuple.elems.is_empty() => Ok("void".to_string()),
         _others => Err(Error::new(
             ty.span(),
-            format!("Unsupported type: {_others:?}"),
+            format!("Unsupported type: {}", _others.to_token_stream()),
         )),
     }
 }
@@ -586,6 +587,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_syn_type_to_cpp_type_slice_u8() {
+        let ty = parse_quote! { &[u8] };
+        assert_eq!(
+            syn_type_to_cpp_type(&ty, &ParsedCxxMappings::default()).unwrap(),
+            "::rust::Slice<::std::uint8_t const>"
+        );
+    }
+
     #[test]
     fn test_syn_type_to_cpp_type_slice_mut() {
         let ty = parse_quote! { &mut [i32] };
@@ -622,6 +632,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_syn_type_to_cpp_type_array_nested() {
+        let ty = parse_quote! { [[f32; 4]; 4] };
+        assert_eq!(
+            syn_type_to_cpp_type(&ty, &ParsedCxxMappings::default()).unwrap(),
+            "::std::array<::std::array<float, 4>, 4>"
+        );
+    }
+
+    #[test]
+    fn test_syn_type_to_cpp_type_array_mapped() {
+        let ty = parse_quote! { [Rgba; 2] };
+        let mut cxx_mappings = ParsedCxxMappings::default();
+        cxx_mappings
+            .namespaces
+            .insert("Rgba".to_owned(), "colors".to_owned());
+        assert_eq!(
+            syn_type_to_cpp_type(&ty, &cxx_mappings).unwrap(),
+            "::std::array<::colors::Rgba, 2>"
+        );
+    }
+
     #[test]
     fn test_syn_type_to_cpp_type_array_length_zero() {
         let ty = parse_quote! { [i32; 0] };
@@ -0,0 +1,355 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Warnings for common mistakes in a bridge which are valid code but likely unintended.
+
+use crate::{
+    generator::naming::{method::QMethodName, property::QPropertyName, CombinedIdent},
+    parser::{
+        method::{ParsedMethod, ParsedQInvokableSpecifiers},
+        qobject::ParsedQObject,
+        Parser,
+    },
+    syntax::{attribute::attribute_find_path, expr::expr_to_string},
+};
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::format_ident;
+use std::collections::BTreeMap;
+use syn::spanned::Spanned;
+
+/// A warning about a likely mistake in the bridge
+#[derive(Debug)]
+pub struct GeneratedWarning {
+    /// The message of the warning
+    pub message: String,
+    /// Where the warning originates from in the bridge
+    pub span: Span,
+}
+
+/// The idents in the sources of a crate and how often each occurs
+///
+/// This is built once for a crate and shared by all of its bridges,
+/// as walking the tokens of every source file is costly for crates with many bridges.
+#[derive(Debug, Default)]
+pub struct SourceIdents {
+    counts: BTreeMap<String, usize>,
+}
+
+impl SourceIdents {
+    /// Count the idents in the given sources
+    pub fn from_sources(sources: impl IntoIterator<Item = TokenStream>) -> Self {
+        let mut counts = BTreeMap::new();
+        for source in sources {
+            count_idents(source, &mut counts);
+        }
+        Self { counts }
+    }
+}
+
+/// Count the occurrences of each ident in the given tokens
+fn count_idents(tokens: TokenStream, counts: &mut BTreeMap<String, usize>) {
+    for token in tokens {
+        match token {
+            TokenTree::Ident(ident) => *counts.entry(ident.to_string()).or_default() += 1,
+            TokenTree::Group(group) => count_idents(group.stream(), counts),
+            _others => {}
+        }
+    }
+}
+
+/// The Rust and C++ names of a method, taking into account any cxx_name attribute
+fn method_name(method: &ParsedMethod) -> CombinedIdent {
+    let mut method_name = QMethodName::from(method).name;
+    // The C++ name of a method can be changed with a cxx_name attribute
+    if let Some(index) = attribute_find_path(&method.method.attrs, &["cxx_name"]) {
+        let cxx_name = method.method.attrs[index]
+            .meta
+            .require_name_value()
+            .ok()
+            .and_then(|name_value| expr_to_string(&name_value.value).ok());
+        if let Some(cxx_name) = cxx_name {
+            method_name.cpp = format_ident!("{cxx_name}");
+        }
+    }
+    method_name
+}
+
+/// Warn about invokables which have the same name as a property getter or setter
+fn invokable_collisions(qobject: &ParsedQObject) -> Vec<GeneratedWarning> {
+    let properties = qobject
+        .properties
+        .iter()
+        .map(|property| (property, QPropertyName::from(property)))
+        .collect::<Vec<_>>();
+
+    qobject
+        .methods
+        .iter()
+        .filter(|method| method.is_qinvokable)
+        .filter_map(|method| {
+            let method_name = method_name(method);
+            properties
+                .iter()
+                .find(|(property, property_name)| {
+                    // Constant, read only, and computed properties do not have a setter to collide with
+                    let has_setter = !property.flags.constant
+                        && !property.flags.read_only
+                        && !property.flags.computed;
+                    [
+                        Some(&property_name.name),
+                        Some(&property_name.getter),
+                        has_setter.then_some(&property_name.setter),
+                    ]
+                    .iter()
+                    .flatten()
+                    .any(|name| name.rust == method_name.rust || name.cpp == method_name.cpp)
+                })
+                .map(|(property, _)| GeneratedWarning {
+                    message: format!(
+                        "invokable `{}` of `{}` collides with the getter or setter of property `{}`",
+                        method_name.rust, qobject.qobject_ty.ident_left, property.ident
+                    ),
+                    span: method.method.sig.ident.span(),
+                })
+        })
+        .collect()
+}
+
+/// Warn about signals which are declared but never referred to in the given sources
+fn unemitted_signals(
+    qobject: &ParsedQObject,
+    ident_counts: &BTreeMap<String, usize>,
+) -> Vec<GeneratedWarning> {
+    qobject
+        .signals
+        .iter()
+        // Inherited signals are emitted by the base class
+        .filter(|signal| !signal.inherit)
+        .filter(|signal| {
+            let rust_ident = signal.ident.rust.to_string();
+            let mut count = ident_counts.get(&rust_ident).copied().unwrap_or_default();
+            // The declaration of the signal is also in the sources
+            if signal.method.sig.ident == rust_ident {
+                count = count.saturating_sub(1);
+            }
+            count == 0
+        })
+        .map(|signal| GeneratedWarning {
+            message: format!(
+                "signal `{}` of `{}` is declared but never emitted",
+                signal.ident.rust, qobject.qobject_ty.ident_left
+            ),
+            span: signal.method.span(),
+        })
+        .collect()
+}
+
+/// The pure virtual methods of abstract Qt base classes which a subclass must override to be instantiable
+const ABSTRACT_BASE_CLASSES: &[(&str, &[&str])] = &[
+    (
+        "QAbstractItemModel",
+        &["columnCount", "data", "index", "parent", "rowCount"],
+    ),
+    ("QAbstractListModel", &["data", "rowCount"]),
+    ("QAbstractTableModel", &["columnCount", "data", "rowCount"]),
+    ("QQuickFramebufferObject", &["createRenderer"]),
+    ("QQuickPaintedItem", &["paint"]),
+    ("QValidator", &["validate"]),
+];
+
+/// Warn about pure virtual methods of a known abstract base class which are not overridden
+///
+/// Without an override of every pure virtual the generated C++ class is abstract,
+/// which is only reported by the C++ compiler when the class is instantiated, eg from QML.
+fn missing_pure_virtuals(qobject: &ParsedQObject) -> Vec<GeneratedWarning> {
+    let base_class = qobject.base_class.as_deref().unwrap_or_default();
+    let pure_virtuals = ABSTRACT_BASE_CLASSES
+        .iter()
+        .find(|(class, _)| *class == base_class)
+        .map(|(_, pure_virtuals)| *pure_virtuals)
+        .unwrap_or_default();
+
+    let overrides = qobject
+        .methods
+        .iter()
+        .filter(|method| {
+            method
+                .specifiers
+                .contains(&ParsedQInvokableSpecifiers::Override)
+                || method
+                    .specifiers
+                    .contains(&ParsedQInvokableSpecifiers::Final)
+        })
+        .map(|method| method_name(method).cpp.to_string())
+        .collect::<Vec<_>>();
+
+    pure_virtuals
+        .iter()
+        .filter(|pure_virtual| !overrides.iter().any(|name| name == *pure_virtual))
+        .map(|pure_virtual| GeneratedWarning {
+            message: format!(
+                "`{}` does not override the pure virtual `{pure_virtual}` of its base class `{base_class}`, use #[cxx_override] to implement it",
+                qobject.qobject_ty.ident_left
+            ),
+            span: qobject.qobject_ty.ident_left.span(),
+        })
+        .collect()
+}
+
+/// Generate warnings for likely mistakes in the bridge
+///
+/// The sources are the idents of the crate, including the file containing the bridge,
+/// which are used to find signals that are never emitted.
+pub fn generate_warnings(parser: &Parser, sources: &SourceIdents) -> Vec<GeneratedWarning> {
+    parser
+        .cxx_qt_data
+        .qobjects
+        .values()
+        .flat_map(|qobject| {
+            let mut warnings = invokable_collisions(qobject);
+            warnings.extend(unemitted_signals(qobject, &sources.counts));
+            warnings.extend(missing_pure_virtuals(qobject));
+            warnings
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use quote::quote;
+    use syn::{parse_quote, ItemMod};
+
+    fn create_module() -> ItemMod {
+        parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(i32, number)]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qinvokable]
+                    fn number(self: &MyObject) -> i32;
+
+                    #[qinvokable]
+                    #[cxx_name = "setNumber"]
+                    fn update_number(self: Pin<&mut MyObject>);
+
+                    #[qinvokable]
+                    fn reset(self: Pin<&mut MyObject>);
+
+                    #[qsignal]
+                    fn ready(self: Pin<&mut MyObject>);
+
+                    #[qsignal]
+                    fn finished(self: Pin<&mut MyObject>);
+
+                    #[qsignal]
+                    #[inherit]
+                    fn destroyed(self: Pin<&mut MyObject>);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_generate_warnings() {
+        let module = create_module();
+        let source = quote! {
+            #module
+
+            impl ffi::MyObject {
+                fn reset(self: Pin<&mut Self>) {
+                    self.ready();
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        let warnings = generate_warnings(&parser, &SourceIdents::from_sources([source]));
+        let messages = warnings
+            .iter()
+            .map(|warning| warning.message.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            [
+                "invokable `number` of `MyObject` collides with the getter or setter of property `number`",
+                "invokable `update_number` of `MyObject` collides with the getter or setter of property `number`",
+                "signal `finished` of `MyObject` is declared but never emitted",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_generate_warnings_read_only() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(i32, number, read_only)]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qinvokable]
+                    fn set_number(self: Pin<&mut MyObject>, number: i32);
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        // A read only property has no setter to collide with
+        let warnings = generate_warnings(&parser, &SourceIdents::default());
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_generate_warnings_pure_virtuals() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    #[base = "QAbstractTableModel"]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qinvokable]
+                    #[cxx_override]
+                    fn data(self: &MyObject, index: &QModelIndex, role: i32) -> QVariant;
+
+                    #[cxx_final]
+                    #[cxx_name = "rowCount"]
+                    fn rows(self: &MyObject, parent: &QModelIndex) -> i32;
+
+                    // Not an override so does not implement the pure virtual
+                    #[qinvokable]
+                    fn column_count(self: &MyObject, parent: &QModelIndex) -> i32;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        let warnings = generate_warnings(&parser, &SourceIdents::default());
+        let messages = warnings
+            .iter()
+            .map(|warning| warning.message.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            ["`MyObject` does not override the pure virtual `columnCount` of its base class `QAbstractTableModel`, use #[cxx_override] to implement it"]
+        );
+    }
+
+    #[test]
+    fn test_generate_warnings_no_sources() {
+        let parser = Parser::from(create_module()).unwrap();
+
+        // Without any sources every signal appears to be never emitted
+        let warnings = generate_warnings(&parser, &SourceIdents::default());
+        assert_eq!(warnings.len(), 4);
+    }
+}
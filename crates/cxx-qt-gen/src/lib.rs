@@ -11,10 +11,14 @@ mod writer;
 pub use generator::{
     cpp::{fragment::CppFragment, GeneratedCppBlocks},
     rust::GeneratedRustBlocks,
+    warnings::{generate_warnings, GeneratedWarning, SourceIdents},
 };
 pub use parser::{qobject::QmlElementMetadata, Parser};
 pub use syntax::{parse_qt_file, CxxQtItem};
-pub use writer::{cpp::write_cpp, rust::write_rust};
+pub use writer::{
+    cpp::{format::CppFormatStyle, write_cpp, write_cpp_with_style},
+    rust::{write_rust, write_rust_stub},
+};
 
 use std::{fs::File, io::Write, path::Path};
 pub use syn::{Error, Result};
@@ -32,12 +36,20 @@ pub fn write_headers(directory: impl AsRef<Path>) {
             include_str!("../include/cxxqt_maybelockguard.h"),
             "cxxqt_maybelockguard.h",
         ),
+        (
+            include_str!("../include/cxxqt_qmlfactory.h"),
+            "cxxqt_qmlfactory.h",
+        ),
         (include_str!("../include/cxxqt_thread.h"), "cxxqt_thread.h"),
         (
             include_str!("../include/cxxqt_threading.h"),
             "cxxqt_threading.h",
         ),
         (include_str!("../include/cxxqt_type.h"), "cxxqt_type.h"),
+        (
+            include_str!("../include/cxxqt_weakref.h"),
+            "cxxqt_weakref.h",
+        ),
     ] {
         // Note that we do not need rerun-if-changed for these files
         // as include_str causes a rerun when the header changes
@@ -64,7 +76,10 @@ mod tests {
         io::Write,
         path::{Path, PathBuf},
     };
-    use writer::{cpp::write_cpp, rust::write_rust};
+    use writer::{
+        cpp::write_cpp,
+        rust::{write_rust, write_rust_stub},
+    };
 
     /// Helper to ensure that a given syn item is the same as the given TokenStream
     pub fn assert_tokens_eq<T: ToTokens>(item: &T, tokens: TokenStream) {
@@ -166,6 +181,10 @@ mod tests {
         let generated_rust = GeneratedRustBlocks::from(&parser).unwrap();
         let rust = sanitize_code(format_rs_source(&write_rust(&generated_rust).to_string()));
 
+        // The stubs for IDE tooling should be valid Rust for every input
+        let stub = write_rust_stub(&generated_rust).unwrap();
+        syn::parse2::<syn::File>(stub).unwrap();
+
         if !update_expected(test_name, &rust, &header, &source) {
             assert_str_eq!(sanitize_code(expected_cpp_header.to_owned()), header);
             assert_str_eq!(sanitize_code(expected_cpp_source.to_owned()), source);
@@ -0,0 +1,122 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::syntax::{attribute::attribute_take_path, expr::expr_to_string};
+use syn::{punctuated::Punctuated, spanned::Spanned, Attribute, Error, Meta, Result, Token};
+
+/// Describes a class info entry of the meta-object of a QObject
+///
+/// This is declared as `#[qclassinfo(key = "...", value = "...")]` and is generated as `Q_CLASSINFO(key, value)`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParsedQClassInfo {
+    /// The name of the class info
+    pub key: String,
+    /// The value of the class info
+    pub value: String,
+}
+
+impl ParsedQClassInfo {
+    /// Parse the `key = "...", value = "..."` arguments of a class info
+    fn parse(attr: &Attribute) -> Result<Self> {
+        let mut key = None;
+        let mut value = None;
+
+        let nested = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+        for meta in nested {
+            match meta {
+                Meta::NameValue(name_value) if name_value.path.is_ident("key") && key.is_none() => {
+                    key = Some(expr_to_string(&name_value.value)?);
+                }
+                Meta::NameValue(name_value)
+                    if name_value.path.is_ident("value") && value.is_none() =>
+                {
+                    value = Some(expr_to_string(&name_value.value)?);
+                }
+                meta => {
+                    return Err(Error::new(
+                        meta.span(),
+                        "Expected a key = \"...\" or value = \"...\" argument of #[qclassinfo]",
+                    ))
+                }
+            }
+        }
+
+        match (key, value) {
+            (Some(key), Some(value)) => Ok(Self { key, value }),
+            _others => Err(Error::new(
+                attr.span(),
+                "Expected #[qclassinfo(key = \"...\", value = \"...\")]",
+            )),
+        }
+    }
+
+    /// Take all of the `#[qclassinfo(key = "...", value = "...")]` attributes from the given attributes and parse them
+    pub fn take_all(attrs: &mut Vec<Attribute>) -> Result<Vec<Self>> {
+        let mut class_infos = vec![];
+        while let Some(attr) = attribute_take_path(attrs, &["qclassinfo"]) {
+            class_infos.push(Self::parse(&attr)?);
+        }
+        Ok(class_infos)
+    }
+
+    /// The Q_CLASSINFO entry of the meta-object
+    pub fn to_cpp(&self) -> String {
+        let escape = |s: &str| s.replace('\\', "\\\\").replace('"', "\\\"");
+        format!(
+            "Q_CLASSINFO(\"{key}\", \"{value}\")",
+            key = escape(&self.key),
+            value = escape(&self.value)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::syntax::foreignmod::ForeignTypeIdentAlias;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_take_all() {
+        let mut item: ForeignTypeIdentAlias = parse_quote! {
+            #[qclassinfo(key = "D-Bus Interface", value = "com.kdab.\"Example\"")]
+            #[qobject]
+            #[qclassinfo(value = "1.0", key = "Version")]
+            type MyObject = super::MyObjectRust;
+        };
+        let class_infos = ParsedQClassInfo::take_all(&mut item.attrs).unwrap();
+        assert_eq!(class_infos.len(), 2);
+        assert_eq!(class_infos[0].key, "D-Bus Interface");
+        assert_eq!(class_infos[0].value, "com.kdab.\"Example\"");
+        assert_eq!(
+            class_infos[0].to_cpp(),
+            "Q_CLASSINFO(\"D-Bus Interface\", \"com.kdab.\\\"Example\\\"\")"
+        );
+        assert_eq!(class_infos[1].to_cpp(), "Q_CLASSINFO(\"Version\", \"1.0\")");
+        assert_eq!(item.attrs.len(), 1);
+    }
+
+    #[test]
+    fn test_take_all_invalid() {
+        let mut item: ForeignTypeIdentAlias = parse_quote! {
+            #[qclassinfo(key = "Version")]
+            type MyObject = super::MyObjectRust;
+        };
+        assert!(ParsedQClassInfo::take_all(&mut item.attrs).is_err());
+
+        let mut item: ForeignTypeIdentAlias = parse_quote! {
+            #[qclassinfo(key = "Version", value = "1.0", other = "value")]
+            type MyObject = super::MyObjectRust;
+        };
+        assert!(ParsedQClassInfo::take_all(&mut item.attrs).is_err());
+
+        let mut item: ForeignTypeIdentAlias = parse_quote! {
+            #[qclassinfo(key = Version, value = "1.0")]
+            type MyObject = super::MyObjectRust;
+        };
+        assert!(ParsedQClassInfo::take_all(&mut item.attrs).is_err());
+    }
+}
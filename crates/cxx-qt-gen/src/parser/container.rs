@@ -0,0 +1,294 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    parser::Parser,
+    syntax::{foreignmod::foreign_mod_to_foreign_item_types, path::path_compare_str},
+};
+use quote::format_ident;
+use syn::{
+    punctuated::Punctuated, spanned::Spanned, Error, ForeignItem, Ident, Item, ItemForeignMod,
+    ItemMacro, Result, Token,
+};
+
+/// The Rust primitives which can be stored in a container without being declared in the bridge
+const BUILT_IN_TYPES: [&str; 13] = [
+    "bool", "f32", "f64", "i8", "i16", "i32", "i64", "isize", "u8", "u16", "u32", "u64", "usize",
+];
+
+/// A Qt container of custom types, declared with `impl_qlist_element!` or `impl_qhash_pair!`
+#[derive(Debug, PartialEq)]
+pub enum ParsedContainer {
+    /// A `QList<T>` from `impl_qlist_element!(T)`
+    QList { element: Ident },
+    /// A `QHash<K, V>` from `impl_qhash_pair!(K, V)`
+    QHash { key: Ident, value: Ident },
+}
+
+impl ParsedContainer {
+    /// Parse an `impl_qlist_element!` or `impl_qhash_pair!` macro, other macros return None
+    pub fn parse(mac: &ItemMacro) -> Result<Option<Self>> {
+        let (name, count) = if path_compare_str(&mac.mac.path, &["impl_qlist_element"]) {
+            ("impl_qlist_element", 1)
+        } else if path_compare_str(&mac.mac.path, &["impl_qhash_pair"]) {
+            ("impl_qhash_pair", 2)
+        } else {
+            return Ok(None);
+        };
+
+        if let Some(attr) = mac.attrs.first() {
+            return Err(Error::new_spanned(
+                attr,
+                format!("{name}! macro must not have any attributes!"),
+            ));
+        }
+
+        if let Some(ident) = &mac.ident {
+            return Err(Error::new_spanned(
+                ident,
+                format!("{name}! macro must not have an additional identifier"),
+            ));
+        }
+
+        let mut idents = mac
+            .mac
+            .parse_body_with(Punctuated::<Ident, Token![,]>::parse_terminated)?
+            .into_iter();
+        if idents.len() != count {
+            return Err(Error::new(
+                mac.span(),
+                format!("{name}! expects {count} type name(s) declared in the bridge"),
+            ));
+        }
+
+        let mut next = || idents.next().expect("the number of idents was checked");
+        Ok(Some(if count == 1 {
+            Self::QList { element: next() }
+        } else {
+            Self::QHash {
+                key: next(),
+                value: next(),
+            }
+        }))
+    }
+
+    /// The name of the type alias of the container in the bridge, eg `QList_T` or `QHash_K_V`
+    pub fn alias_ident(&self) -> Ident {
+        match self {
+            Self::QList { element } => format_ident!("QList_{element}"),
+            Self::QHash { key, value } => format_ident!("QHash_{key}_{value}"),
+        }
+    }
+
+    /// The types which are stored in the container
+    pub fn types(&self) -> Vec<&Ident> {
+        match self {
+            Self::QList { element } => vec![element],
+            Self::QHash { key, value } => vec![key, value],
+        }
+    }
+}
+
+/// How a type which is stored in a container has been declared
+pub enum ContainerTypeDeclaration<'a> {
+    /// A Rust primitive, such as `i32`
+    BuiltIn,
+    /// A shared struct of the bridge
+    SharedStruct,
+    /// A type alias in an `extern "C++"` block, such as `type QString = cxx_qt_lib::QString;`
+    ExternCxx(&'a ItemForeignMod),
+}
+
+/// Find how a type stored in a container is declared in the passthrough items of the bridge
+///
+/// The type must be trivial, as the container methods take and return it by value,
+/// so opaque `extern "C++"` types are not supported.
+pub fn find_declaration<'a>(
+    items: &'a [Item],
+    ident: &Ident,
+) -> Result<ContainerTypeDeclaration<'a>> {
+    if BUILT_IN_TYPES.contains(&ident.to_string().as_str()) {
+        return Ok(ContainerTypeDeclaration::BuiltIn);
+    }
+
+    for item in items {
+        match item {
+            Item::Struct(item_struct) if &item_struct.ident == ident => {
+                return Ok(ContainerTypeDeclaration::SharedStruct);
+            }
+            Item::ForeignMod(foreign_mod)
+                if foreign_mod.abi.name.as_ref().map(|name| name.value())
+                    == Some("C++".to_owned()) =>
+            {
+                if foreign_mod.items.iter().any(
+                    |item| matches!(item, ForeignItem::Type(foreign_type) if &foreign_type.ident == ident),
+                ) {
+                    return Err(Error::new_spanned(
+                        ident,
+                        format!("{ident} is an opaque type, only trivial types such as `type {ident} = crate::{ident};` can be stored in a Qt container"),
+                    ));
+                }
+
+                if foreign_mod_to_foreign_item_types(foreign_mod)?
+                    .iter()
+                    .any(|foreign_type| &foreign_type.ident == ident)
+                {
+                    return Ok(ContainerTypeDeclaration::ExternCxx(foreign_mod));
+                }
+            }
+            _others => {}
+        }
+    }
+
+    Err(Error::new_spanned(
+        ident,
+        format!(
+            "Could not find a shared struct or extern \"C++\" type named {ident} in the bridge"
+        ),
+    ))
+}
+
+/// Check that each type stored in a container is declared in the bridge
+pub fn validate(parser: &Parser) -> Result<()> {
+    let items = parser
+        .passthrough_module
+        .content
+        .as_ref()
+        .map(|(_, items)| items.as_slice())
+        .unwrap_or_default();
+
+    for container in &parser.cxx_qt_data.containers {
+        for ident in container.types() {
+            find_declaration(items, ident)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use syn::{parse_quote, ItemMod};
+
+    #[test]
+    fn test_parse_qlist() {
+        let container = ParsedContainer::parse(&parse_quote! {
+            impl_qlist_element!(MyGadget);
+        })
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            container,
+            ParsedContainer::QList {
+                element: format_ident!("MyGadget")
+            }
+        );
+        assert_eq!(container.alias_ident(), "QList_MyGadget");
+        assert_eq!(container.types(), vec!["MyGadget"]);
+    }
+
+    #[test]
+    fn test_parse_qhash() {
+        let container = ParsedContainer::parse(&parse_quote! {
+            impl_qhash_pair!(QString, MyGadget);
+        })
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            container,
+            ParsedContainer::QHash {
+                key: format_ident!("QString"),
+                value: format_ident!("MyGadget")
+            }
+        );
+        assert_eq!(container.alias_ident(), "QHash_QString_MyGadget");
+        assert_eq!(container.types(), vec!["QString", "MyGadget"]);
+    }
+
+    #[test]
+    fn test_parse_other_macro() {
+        assert!(ParsedContainer::parse(&parse_quote! {
+            qnamespace!("my_namespace");
+        })
+        .unwrap()
+        .is_none());
+    }
+
+    macro_rules! assert_parse_error {
+        { $($input:tt)* } => {
+            assert!(ParsedContainer::parse(&parse_quote! { $($input)* }).is_err())
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_parse_error! {
+            impl_qlist_element!();
+        }
+        assert_parse_error! {
+            impl_qlist_element!(A, B);
+        }
+        assert_parse_error! {
+            impl_qhash_pair!(QString);
+        }
+        assert_parse_error! {
+            impl_qlist_element!(crate::MyGadget);
+        }
+        assert_parse_error! {
+            impl_qlist_element!("MyGadget");
+        }
+        assert_parse_error! {
+            #[namespace = "other"]
+            impl_qlist_element!(MyGadget);
+        }
+        assert_parse_error! {
+            impl_qlist_element! named (MyGadget);
+        }
+    }
+
+    #[test]
+    fn test_find_declaration() {
+        let module: ItemMod = parse_quote! {
+            mod ffi {
+                struct MyGadget {
+                    number: i32,
+                }
+
+                unsafe extern "C++" {
+                    include!("cxx-qt-lib/qstring.h");
+                    type QString = cxx_qt_lib::QString;
+
+                    include!("company/money.h");
+                    type Money;
+                }
+
+                enum Colour {
+                    Red,
+                }
+            }
+        };
+        let items = module.content.unwrap().1;
+
+        assert!(matches!(
+            find_declaration(&items, &format_ident!("i32")),
+            Ok(ContainerTypeDeclaration::BuiltIn)
+        ));
+        assert!(matches!(
+            find_declaration(&items, &format_ident!("MyGadget")),
+            Ok(ContainerTypeDeclaration::SharedStruct)
+        ));
+        assert!(matches!(
+            find_declaration(&items, &format_ident!("QString")),
+            Ok(ContainerTypeDeclaration::ExternCxx(_))
+        ));
+        // Opaque types cannot be passed by value
+        assert!(find_declaration(&items, &format_ident!("Money")).is_err());
+        // Shared enums and unknown types are not supported
+        assert!(find_declaration(&items, &format_ident!("Colour")).is_err());
+        assert!(find_declaration(&items, &format_ident!("Unknown")).is_err());
+    }
+}
@@ -0,0 +1,118 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::syntax::attribute::attribute_take_path;
+use std::collections::BTreeMap;
+use syn::{
+    spanned::Spanned, Error, FnArg, ForeignItemFn, Ident, Pat, PatIdent, Result, ReturnType, Type,
+};
+
+/// Describes the Rust types that the parameters and return type of a method are converted between
+///
+/// A parameter is declared as `#[from_qt(T)] name: QtType` and is converted with `cxx_qt_lib::FromQt`,
+/// the return type is declared as `#[into_qt(T)]` on the method and is converted with `cxx_qt_lib::IntoQt`.
+#[derive(Default)]
+pub struct ParsedQtConversions {
+    /// The Rust type that each converted parameter is passed to the implementation as
+    pub parameters: BTreeMap<Ident, Type>,
+    /// The Rust type that the implementation returns, if it is converted
+    pub return_ty: Option<Type>,
+}
+
+impl ParsedQtConversions {
+    /// Take any `#[from_qt(T)]` and `#[into_qt(T)]` attributes from the method and its parameters and parse them
+    pub fn take_from(method: &mut ForeignItemFn) -> Result<Self> {
+        let mut conversions = Self::default();
+
+        for input in method.sig.inputs.iter_mut() {
+            let (attrs, ident) = match input {
+                FnArg::Receiver(receiver) => (&mut receiver.attrs, None),
+                FnArg::Typed(pat_type) => match &*pat_type.pat {
+                    Pat::Ident(PatIdent { ident, .. }) if ident != "self" => {
+                        (&mut pat_type.attrs, Some(ident.clone()))
+                    }
+                    _others => (&mut pat_type.attrs, None),
+                },
+            };
+
+            if let Some(attr) = attribute_take_path(attrs, &["from_qt"]) {
+                if let Some(ident) = ident {
+                    conversions.parameters.insert(ident, attr.parse_args()?);
+                } else {
+                    return Err(Error::new(
+                        attr.span(),
+                        "#[from_qt] can only be used on named parameters",
+                    ));
+                }
+            }
+        }
+
+        if let Some(attr) = attribute_take_path(&mut method.attrs, &["into_qt"]) {
+            if matches!(method.sig.output, ReturnType::Default) {
+                return Err(Error::new(
+                    attr.span(),
+                    "#[into_qt] can only be used on methods which return a value",
+                ));
+            }
+            conversions.return_ty = Some(attr.parse_args()?);
+        }
+
+        Ok(conversions)
+    }
+
+    /// Whether any of the parameters or the return type are converted
+    pub fn is_empty(&self) -> bool {
+        self.parameters.is_empty() && self.return_ty.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use quote::format_ident;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_take_from() {
+        let mut method: ForeignItemFn = parse_quote! {
+            #[into_qt(String)]
+            fn greet(self: &MyObject, #[from_qt(String)] name: QString, count: i32) -> QString;
+        };
+        let conversions = ParsedQtConversions::take_from(&mut method).unwrap();
+        assert!(!conversions.is_empty());
+        assert_eq!(conversions.parameters.len(), 1);
+        assert_eq!(
+            conversions.parameters[&format_ident!("name")],
+            parse_quote! { String }
+        );
+        assert_eq!(conversions.return_ty, Some(parse_quote! { String }));
+
+        // The attributes are removed from the method
+        let expected: ForeignItemFn = parse_quote! {
+            fn greet(self: &MyObject, name: QString, count: i32) -> QString;
+        };
+        assert_eq!(method, expected);
+
+        let mut method: ForeignItemFn = parse_quote! { fn invokable(self: &MyObject); };
+        assert!(ParsedQtConversions::take_from(&mut method)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_take_from_invalid() {
+        let mut method: ForeignItemFn = parse_quote! {
+            #[into_qt(String)]
+            fn invokable(self: &MyObject);
+        };
+        assert!(ParsedQtConversions::take_from(&mut method).is_err());
+
+        let mut method: ForeignItemFn = parse_quote! {
+            fn invokable(#[from_qt(String)] self: &MyObject);
+        };
+        assert!(ParsedQtConversions::take_from(&mut method).is_err());
+    }
+}
@@ -4,14 +4,16 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use crate::syntax::attribute::{attribute_find_path, attribute_take_path};
-use crate::syntax::foreignmod::{foreign_mod_to_foreign_item_types, ForeignTypeIdentAlias};
+use crate::syntax::foreignmod::{
+    foreign_item_type_take_attribute, foreign_mod_to_foreign_item_types, ForeignTypeIdentAlias,
+};
 use crate::syntax::path::{path_compare_str, path_from_idents};
 use crate::syntax::safety::Safety;
 use crate::{
     parser::{
         externcxxqt::ParsedExternCxxQt, inherit::ParsedInheritedMethod,
-        mappings::ParsedCxxMappings, method::ParsedMethod, qenum::ParsedQEnum,
-        qobject::ParsedQObject, signals::ParsedSignal,
+        mappings::ParsedCxxMappings, method::ParsedMethod, property::ParsedQProperty,
+        qenum::ParsedQEnum, qobject::ParsedQObject, signals::ParsedSignal,
     },
     syntax::expr::expr_to_string,
 };
@@ -22,6 +24,7 @@ use syn::{
 };
 use syn::{Attribute, ItemMacro, Meta};
 
+use super::container::ParsedContainer;
 use super::qnamespace::ParsedQNamespace;
 
 pub struct ParsedCxxQtData {
@@ -36,8 +39,12 @@ pub struct ParsedCxxQtData {
     pub qenums: Vec<ParsedQEnum>,
     /// List of QNamespace declarations
     pub qnamespaces: Vec<ParsedQNamespace>,
+    /// Containers of custom types declared with `impl_qlist_element!` or `impl_qhash_pair!`
+    pub containers: Vec<ParsedContainer>,
     /// Blocks of extern "C++Qt"
     pub extern_cxxqt_blocks: Vec<ParsedExternCxxQt>,
+    /// Types of extern "C++" blocks which are registered as a QMetaType with `#[qmetatype]`
+    pub qmetatypes: Vec<Ident>,
     /// The namespace of the CXX-Qt module
     pub namespace: String,
     /// The ident of the module, used for mappings
@@ -52,7 +59,9 @@ impl ParsedCxxQtData {
             qobjects: BTreeMap::<Ident, ParsedQObject>::default(),
             qenums: vec![],
             qnamespaces: vec![],
+            containers: vec![],
             extern_cxxqt_blocks: Vec::<ParsedExternCxxQt>::default(),
+            qmetatypes: vec![],
             module_ident,
             namespace,
         }
@@ -77,8 +86,19 @@ impl ParsedCxxQtData {
                                 if attribute_take_path(&mut foreign_alias.attrs, &["qobject"])
                                     .is_some()
                                 {
+                                    // Find any struct whose fields should be properties
+                                    let properties_from_struct = attribute_take_path(
+                                        &mut foreign_alias.attrs,
+                                        &["qproperties_from_struct"],
+                                    )
+                                    .map(|attr| Self::find_properties_from_struct(items, &attr))
+                                    .transpose()?;
+
                                     // Load the QObject
                                     let mut qobject = ParsedQObject::try_from(&foreign_alias)?;
+                                    if let Some(mut properties) = properties_from_struct {
+                                        qobject.properties.append(&mut properties);
+                                    }
 
                                     // Inject the bridge namespace if the qobject one is empty
                                     if qobject.namespace.is_empty() && !self.namespace.is_empty() {
@@ -118,6 +138,27 @@ impl ParsedCxxQtData {
         Ok(())
     }
 
+    /// Find the struct named by a `#[qproperties_from_struct(T)]` attribute and build its properties
+    fn find_properties_from_struct(
+        items: &[Item],
+        attr: &Attribute,
+    ) -> Result<Vec<ParsedQProperty>> {
+        let ident: Ident = attr.parse_args()?;
+        items
+            .iter()
+            .find_map(|item| match item {
+                Item::Struct(item_struct) if item_struct.ident == ident => Some(item_struct),
+                _others => None,
+            })
+            .ok_or_else(|| {
+                Error::new(
+                    ident.span(),
+                    format!("Could not find a struct named {ident} in the bridge for #[qproperties_from_struct]"),
+                )
+            })
+            .and_then(ParsedQProperty::parse_from_struct)
+    }
+
     /// Search through Item's and look for a cxx_name, rust_name, or namespace attribute on a type
     ///
     /// We need to know this as it affects the type name used in the C++ generation
@@ -192,6 +233,22 @@ impl ParsedCxxQtData {
                 &self.namespace,
                 &self.module_ident,
             )?;
+            if let Some(flags) = &qenum.flags {
+                self.cxx_mappings.populate(
+                    flags,
+                    &qenum.item.attrs,
+                    &self.namespace,
+                    &self.module_ident,
+                )?;
+            }
+
+            // The error_occurred signal is generated once for the QObject
+            if qenum.qml_error && qobject.qenums.iter().any(|other| other.qml_error) {
+                return Err(Error::new_spanned(
+                    &qenum.ident,
+                    "A QObject can only have one #[qml_error] QEnum",
+                ));
+            }
 
             qobject.qenums.push(qenum);
             Ok(())
@@ -205,6 +262,12 @@ impl ParsedCxxQtData {
 
     fn parse_namespaced_qenum(&mut self, item: ItemEnum) -> Result<()> {
         let mut qenum = ParsedQEnum::parse(item)?;
+        if qenum.qml_error {
+            return Err(syn::Error::new_spanned(
+                &qenum.ident,
+                "#[qml_error] requires the QEnum to be associated with a QObject, such as #[qenum(MyObject)]",
+            ));
+        }
         if qenum.namespace.is_empty() {
             qenum.namespace = self.namespace.clone();
         }
@@ -220,6 +283,14 @@ impl ParsedCxxQtData {
             &self.namespace,
             &self.module_ident,
         )?;
+        if let Some(flags) = &qenum.flags {
+            self.cxx_mappings.populate(
+                flags,
+                &qenum.item.attrs,
+                &self.namespace,
+                &self.module_ident,
+            )?;
+        }
         self.qenums.push(qenum);
         Ok(())
     }
@@ -245,14 +316,42 @@ impl ParsedCxxQtData {
             let qnamespace = ParsedQNamespace::parse(item)?;
             self.qnamespaces.push(qnamespace);
             Ok(None)
+        } else if let Some(container) = ParsedContainer::parse(&item)? {
+            if self.containers.contains(&container) {
+                return Err(Error::new_spanned(
+                    &item,
+                    format!("{} is declared more than once", container.alias_ident()),
+                ));
+            }
+
+            // The alias of the container is declared in the bridge namespace
+            self.cxx_mappings.populate(
+                &container.alias_ident(),
+                &[],
+                &self.namespace,
+                &self.module_ident,
+            )?;
+            self.containers.push(container);
+            Ok(None)
         } else {
             Ok(Some(Item::Macro(item)))
         }
     }
 
-    fn parse_foreign_mod(&mut self, foreign_mod: ItemForeignMod) -> Result<Option<Item>> {
+    fn parse_foreign_mod(&mut self, mut foreign_mod: ItemForeignMod) -> Result<Option<Item>> {
         if let Some(lit_str) = &foreign_mod.abi.name {
             match lit_str.value().as_str() {
+                "C++" => {
+                    // Find any third-party types which are registered as a QMetaType,
+                    // the rest of the block is passed through to CXX
+                    for item in foreign_mod.items.iter_mut() {
+                        if let Some((_, ident)) =
+                            foreign_item_type_take_attribute(item, &["qmetatype"])?
+                        {
+                            self.qmetatypes.push(ident);
+                        }
+                    }
+                }
                 "RustQt" => {
                     self.parse_foreign_mod_rust_qt(foreign_mod)?;
                     return Ok(None);
@@ -276,9 +375,15 @@ impl ParsedCxxQtData {
         } else {
             Safety::Unsafe
         };
+        // An #[inherit] block declares that all of its methods and signals are from the base class
+        let inherit_block = attribute_take_path(&mut foreign_mod.attrs, &["inherit"]).is_some();
 
         for item in foreign_mod.items.drain(..) {
             if let ForeignItem::Fn(mut foreign_fn) = item {
+                if inherit_block && attribute_find_path(&foreign_fn.attrs, &["inherit"]).is_none() {
+                    foreign_fn.attrs.push(syn::parse_quote! { #[inherit] });
+                }
+
                 // Test if the function is a signal
                 if attribute_take_path(&mut foreign_fn.attrs, &["qsignal"]).is_some() {
                     let parsed_signal_method = ParsedSignal::parse(foreign_fn, safe_call)?;
@@ -347,8 +452,11 @@ impl ParsedCxxQtData {
 mod tests {
     use super::*;
 
-    use crate::{generator::naming::CombinedIdent, parser::qobject::tests::create_parsed_qobject};
-    use quote::format_ident;
+    use crate::{
+        generator::naming::CombinedIdent,
+        parser::{qobject::tests::create_parsed_qobject, revision::ParsedQRevision},
+    };
+    use quote::{format_ident, ToTokens};
     use syn::{parse_quote, ItemMod};
 
     /// The QObject ident used in these tests as the ident that already
@@ -445,6 +553,55 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_find_qobjects_properties_from_struct() {
+        let mut cxx_qt_data = ParsedCxxQtData::new(format_ident!("ffi"), "".to_string());
+
+        let module: ItemMod = parse_quote! {
+            mod module {
+                struct Config {
+                    width: i32,
+                    title: QString,
+                }
+
+                extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(bool, enabled)]
+                    #[qproperties_from_struct(Config)]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        cxx_qt_data
+            .find_qobject_types(&module.content.unwrap().1)
+            .unwrap();
+
+        let qobject = cxx_qt_data.qobjects.get(&qobject_ident()).unwrap();
+        assert_eq!(qobject.properties.len(), 3);
+        assert_eq!(qobject.properties[0].ident, "enabled");
+        assert_eq!(qobject.properties[1].ident, "width");
+        assert_eq!(qobject.properties[2].ident, "title");
+        assert!(qobject.qobject_ty.attrs.is_empty());
+    }
+
+    #[test]
+    fn test_find_qobjects_properties_from_struct_missing() {
+        let mut cxx_qt_data = ParsedCxxQtData::new(format_ident!("ffi"), "".to_string());
+
+        let module: ItemMod = parse_quote! {
+            mod module {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qproperties_from_struct(Config)]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        assert!(cxx_qt_data
+            .find_qobject_types(&module.content.unwrap().1)
+            .is_err());
+    }
+
     #[test]
     fn test_find_qobjects_no_qobject() {
         let mut cxx_qt_data = ParsedCxxQtData::new(format_ident!("ffi"), "".to_string());
@@ -595,6 +752,80 @@ mod tests {
         assert!(cxx_qt_data.extern_cxxqt_blocks[0].unsafety.is_some());
     }
 
+    #[test]
+    fn test_find_and_merge_cxx_qt_item_qmetatype() {
+        let mut cxx_qt_data = create_parsed_cxx_qt_data();
+
+        let item: Item = parse_quote! {
+            unsafe extern "C++" {
+                include!("company/money.h");
+
+                #[qmetatype]
+                #[namespace = "company"]
+                type Money = crate::money::Money;
+
+                type Other;
+            }
+        };
+        let result = cxx_qt_data.parse_cxx_qt_item(item).unwrap().unwrap();
+
+        assert_eq!(cxx_qt_data.qmetatypes, vec![format_ident!("Money")]);
+
+        // The block is passed through to CXX without the attribute
+        let expected: Item = parse_quote! {
+            unsafe extern "C++" {
+                include!("company/money.h");
+
+                #[namespace = "company"]
+                type Money = crate::money::Money;
+
+                type Other;
+            }
+        };
+        assert_eq!(
+            result.to_token_stream().to_string(),
+            expected.to_token_stream().to_string()
+        );
+    }
+
+    #[test]
+    fn test_cxx_mappings_extern_cxx_qt_namespace() {
+        let mut cxx_qt_data = create_parsed_cxx_qt_data();
+
+        let item: Item = parse_quote! {
+            #[namespace = "existing"]
+            unsafe extern "C++Qt" {
+                type QPushButton;
+
+                #[namespace = "other"]
+                type ExternObject;
+
+                #[qsignal]
+                fn clicked(self: Pin<&mut QPushButton>, checked: bool);
+            }
+        };
+        let result = cxx_qt_data.parse_cxx_qt_item(item).unwrap();
+        assert!(result.is_none());
+
+        assert_eq!(cxx_qt_data.cxx_mappings.namespaces.len(), 2);
+        assert_eq!(
+            cxx_qt_data
+                .cxx_mappings
+                .namespaces
+                .get("QPushButton")
+                .unwrap(),
+            "existing"
+        );
+        assert_eq!(
+            cxx_qt_data
+                .cxx_mappings
+                .namespaces
+                .get("ExternObject")
+                .unwrap(),
+            "other"
+        );
+    }
+
     #[test]
     fn test_cxx_mappings_cxx_name() {
         let mut cxx_qt_data = create_parsed_cxx_qt_data();
@@ -782,6 +1013,243 @@ mod tests {
         assert_eq!(inherited[2].parameters[0].ident, "arg");
     }
 
+    #[test]
+    fn test_parse_inherit_block() {
+        let mut cxxqtdata = create_parsed_cxx_qt_data();
+
+        let block: Item = parse_quote! {
+            #[inherit]
+            unsafe extern "RustQt" {
+                fn row_count(self: &MyObject, parent: &QModelIndex) -> i32;
+
+                #[qsignal]
+                pub(self) fn rows_inserted(self: Pin<&mut MyObject>, parent: &QModelIndex, first: i32, last: i32);
+
+                #[inherit]
+                #[qsignal]
+                fn data_changed(self: Pin<&mut MyObject>);
+            }
+        };
+        cxxqtdata.parse_cxx_qt_item(block).unwrap();
+
+        let qobject = cxxqtdata.qobjects.get(&qobject_ident()).unwrap();
+        assert!(qobject.methods.is_empty());
+        assert_eq!(qobject.inherited_methods.len(), 1);
+        assert_eq!(qobject.inherited_methods[0].ident.rust, "row_count");
+        assert_eq!(qobject.signals.len(), 2);
+        assert!(qobject.signals[0].inherit);
+        assert!(qobject.signals[0].private);
+        assert!(qobject.signals[1].inherit);
+        assert!(!qobject.signals[1].private);
+    }
+
+    #[test]
+    fn test_parse_invokable_revision() {
+        let mut cxxqtdata = create_parsed_cxx_qt_data();
+        let block: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[qinvokable]
+                #[qrevision(2, 1)]
+                fn invokable(self: &MyObject);
+            }
+        };
+        cxxqtdata.parse_cxx_qt_item(block).unwrap();
+
+        let qobject = cxxqtdata.qobjects.get(&qobject_ident()).unwrap();
+        assert_eq!(qobject.methods.len(), 1);
+        assert_eq!(
+            qobject.methods[0].revision,
+            Some(ParsedQRevision { major: 2, minor: 1 })
+        );
+        assert!(qobject.methods[0].method.attrs.is_empty());
+    }
+
+    #[test]
+    fn test_parse_invokable_revision_not_qinvokable() {
+        let mut cxxqtdata = create_parsed_cxx_qt_data();
+        let block: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[qrevision(2, 1)]
+                fn method(self: &MyObject);
+            }
+        };
+        assert!(cxxqtdata.parse_cxx_qt_item(block).is_err());
+    }
+
+    #[test]
+    fn test_parse_invokable_ownership() {
+        let mut cxxqtdata = create_parsed_cxx_qt_data();
+        let block: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[qinvokable]
+                #[qml_ownership(cpp)]
+                fn invokable(self: &MyObject) -> *mut Other;
+            }
+        };
+        cxxqtdata.parse_cxx_qt_item(block).unwrap();
+
+        let qobject = cxxqtdata.qobjects.get(&qobject_ident()).unwrap();
+        assert_eq!(
+            qobject.methods[0].ownership,
+            Some(crate::parser::ownership::ParsedQmlOwnership::Cpp)
+        );
+        assert!(qobject.methods[0].method.attrs.is_empty());
+    }
+
+    #[test]
+    fn test_parse_invokable_ownership_invalid() {
+        let mut cxxqtdata = create_parsed_cxx_qt_data();
+        let block: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[qml_ownership(cpp)]
+                fn method(self: &MyObject) -> *mut Other;
+            }
+        };
+        assert!(cxxqtdata.parse_cxx_qt_item(block).is_err());
+
+        // The ownership can only be set on a returned pointer
+        let block: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[qinvokable]
+                #[qml_ownership(javascript)]
+                fn invokable(self: &MyObject) -> i32;
+            }
+        };
+        assert!(cxxqtdata.parse_cxx_qt_item(block).is_err());
+    }
+
+    #[test]
+    fn test_parse_invokable_access() {
+        let mut cxxqtdata = create_parsed_cxx_qt_data();
+        let block: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[qinvokable]
+                fn public_invokable(self: &MyObject);
+
+                #[qinvokable(access = "protected")]
+                fn protected_invokable(self: &MyObject);
+
+                #[qinvokable(access = "private")]
+                fn private_invokable(self: &MyObject);
+            }
+        };
+        cxxqtdata.parse_cxx_qt_item(block).unwrap();
+
+        let qobject = cxxqtdata.qobjects.get(&qobject_ident()).unwrap();
+        assert_eq!(
+            qobject.methods[0].access,
+            crate::parser::method::ParsedMethodAccess::Public
+        );
+        assert_eq!(
+            qobject.methods[1].access,
+            crate::parser::method::ParsedMethodAccess::Protected
+        );
+        assert_eq!(
+            qobject.methods[2].access,
+            crate::parser::method::ParsedMethodAccess::Private
+        );
+        assert!(qobject.methods.iter().all(|method| method.is_qinvokable));
+    }
+
+    #[test]
+    fn test_parse_invokable_access_invalid() {
+        let mut cxxqtdata = create_parsed_cxx_qt_data();
+        let block: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[qinvokable(access = "internal")]
+                fn invokable(self: &MyObject);
+            }
+        };
+        assert!(cxxqtdata.parse_cxx_qt_item(block).is_err());
+
+        let block: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[qinvokable(visibility = "protected")]
+                fn invokable(self: &MyObject);
+            }
+        };
+        assert!(cxxqtdata.parse_cxx_qt_item(block).is_err());
+    }
+
+    #[test]
+    fn test_parse_invokable_static() {
+        let mut cxxqtdata = create_parsed_cxx_qt_data();
+        let block: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[qinvokable]
+                #[Self = "MyObject"]
+                fn helper(value: i32) -> i32;
+
+                #[qinvokable]
+                fn invokable(self: &MyObject);
+            }
+        };
+        cxxqtdata.parse_cxx_qt_item(block).unwrap();
+
+        let qobject = cxxqtdata.qobjects.get(&qobject_ident()).unwrap();
+        assert_eq!(qobject.methods.len(), 2);
+        assert!(qobject.methods[0].is_static);
+        assert!(!qobject.methods[0].mutable);
+        assert_eq!(qobject.methods[0].parameters.len(), 1);
+        assert_eq!(qobject.methods[0].parameters[0].ident, "value");
+        assert!(qobject.methods[0].method.attrs.is_empty());
+        assert!(!qobject.methods[1].is_static);
+    }
+
+    #[test]
+    fn test_parse_invokable_static_invalid() {
+        let mut cxxqtdata = create_parsed_cxx_qt_data();
+
+        // The QObject must be named as there is no self receiver
+        let block: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[qinvokable]
+                fn helper(value: i32) -> i32;
+            }
+        };
+        assert!(cxxqtdata.parse_cxx_qt_item(block).is_err());
+
+        // Only invokables can be static
+        let block: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[Self = "MyObject"]
+                fn helper(value: i32) -> i32;
+            }
+        };
+        assert!(cxxqtdata.parse_cxx_qt_item(block).is_err());
+
+        // A method with a self receiver cannot be static
+        let block: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[qinvokable]
+                #[Self = "MyObject"]
+                fn helper(self: &MyObject, value: i32) -> i32;
+            }
+        };
+        assert!(cxxqtdata.parse_cxx_qt_item(block).is_err());
+
+        // Static members cannot be virtual
+        let block: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[qinvokable]
+                #[cxx_virtual]
+                #[Self = "MyObject"]
+                fn helper(value: i32) -> i32;
+            }
+        };
+        assert!(cxxqtdata.parse_cxx_qt_item(block).is_err());
+
+        // The QObject must be known
+        let block: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[qinvokable]
+                #[Self = "UnknownObject"]
+                fn helper(value: i32) -> i32;
+            }
+        };
+        assert!(cxxqtdata.parse_cxx_qt_item(block).is_err());
+    }
+
     #[test]
     fn test_parse_qsignals_safe() {
         let mut cxxqtdata = create_parsed_cxx_qt_data();
@@ -930,4 +1398,37 @@ mod tests {
         assert_eq!(2, cxxqtdata.qenums.len());
         assert_eq!("other_namespace", &cxxqtdata.qenums[1].namespace);
     }
+
+    #[test]
+    fn test_parse_qml_error_qenum() {
+        let mut cxxqtdata = create_parsed_cxx_qt_data();
+
+        let qml_error: Item = parse_quote! {
+            #[qenum(MyObject)]
+            #[qml_error]
+            enum MyError {
+                A,
+            }
+        };
+        assert!(cxxqtdata
+            .parse_cxx_qt_item(qml_error.clone())
+            .unwrap()
+            .is_none());
+        let qobject = cxxqtdata.qobjects.get(&qobject_ident()).unwrap();
+        assert!(qobject.qenums[0].qml_error);
+
+        // A QObject can only have one #[qml_error] QEnum
+        assert!(cxxqtdata.parse_cxx_qt_item(qml_error).is_err());
+
+        // A #[qml_error] QEnum must be associated with a QObject
+        let namespaced: Item = parse_quote! {
+            #[qenum]
+            #[namespace = "my_namespace"]
+            #[qml_error]
+            enum MyError {
+                A,
+            }
+        };
+        assert!(cxxqtdata.parse_cxx_qt_item(namespaced).is_err());
+    }
 }
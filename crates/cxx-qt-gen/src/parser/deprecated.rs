@@ -0,0 +1,123 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::syntax::attribute::attribute_take_path;
+use syn::{spanned::Spanned, Attribute, Error, LitStr, Meta, Result};
+
+/// Describes a property, invokable, or signal which is deprecated
+///
+/// This is declared as `#[qdeprecated]` or `#[qdeprecated("reason")]`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParsedQDeprecated {
+    /// The reason for the deprecation, such as which item to use instead
+    pub reason: Option<String>,
+}
+
+impl ParsedQDeprecated {
+    /// Parse a `qdeprecated` or `qdeprecated("reason")` meta
+    pub fn parse_meta(meta: &Meta) -> Result<Self> {
+        match meta {
+            Meta::Path(_) => Ok(Self { reason: None }),
+            Meta::List(list) => Ok(Self {
+                reason: Some(list.parse_args::<LitStr>()?.value()),
+            }),
+            Meta::NameValue(_) => Err(Error::new(
+                meta.span(),
+                "Expected #[qdeprecated] or #[qdeprecated(\"reason\")]",
+            )),
+        }
+    }
+
+    /// Take any `#[qdeprecated]` attribute from the given attributes and parse it
+    pub fn take_from(attrs: &mut Vec<Attribute>) -> Result<Option<Self>> {
+        attribute_take_path(attrs, &["qdeprecated"])
+            .map(|attr| Self::parse_meta(&attr.meta))
+            .transpose()
+    }
+
+    /// The reason as a C++ string literal
+    fn reason_literal(&self) -> String {
+        let reason = self.reason.as_deref().unwrap_or_default();
+        format!("\"{}\"", reason.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+
+    /// The C++ attribute which warns C++ consumers when the item is used
+    pub fn to_cpp_attribute(&self) -> String {
+        if self.reason.is_some() {
+            format!("[[deprecated({reason})]]", reason = self.reason_literal())
+        } else {
+            "[[deprecated]]".to_owned()
+        }
+    }
+
+    /// The meta object class info which describes the deprecation of the named item to QML tooling
+    pub fn to_cpp_class_info(&self, ident: &str) -> String {
+        format!(
+            "Q_CLASSINFO(\"cxx-qt.deprecated.{ident}\", {reason})",
+            reason = self.reason_literal()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use syn::{parse_quote, ForeignItemFn};
+
+    #[test]
+    fn test_take_from() {
+        let mut method: ForeignItemFn = parse_quote! {
+            #[qdeprecated("use \"foo_v2\"")]
+            #[qinvokable]
+            fn foo(self: &MyObject);
+        };
+        let deprecated = ParsedQDeprecated::take_from(&mut method.attrs)
+            .unwrap()
+            .unwrap();
+        assert_eq!(deprecated.reason.as_deref(), Some("use \"foo_v2\""));
+        assert_eq!(
+            deprecated.to_cpp_attribute(),
+            "[[deprecated(\"use \\\"foo_v2\\\"\")]]"
+        );
+        assert_eq!(
+            deprecated.to_cpp_class_info("foo"),
+            "Q_CLASSINFO(\"cxx-qt.deprecated.foo\", \"use \\\"foo_v2\\\"\")"
+        );
+        assert_eq!(method.attrs.len(), 1);
+    }
+
+    #[test]
+    fn test_take_from_no_reason() {
+        let mut method: ForeignItemFn = parse_quote! {
+            #[qdeprecated]
+            fn foo(self: &MyObject);
+        };
+        let deprecated = ParsedQDeprecated::take_from(&mut method.attrs)
+            .unwrap()
+            .unwrap();
+        assert!(deprecated.reason.is_none());
+        assert_eq!(deprecated.to_cpp_attribute(), "[[deprecated]]");
+        assert_eq!(
+            deprecated.to_cpp_class_info("foo"),
+            "Q_CLASSINFO(\"cxx-qt.deprecated.foo\", \"\")"
+        );
+    }
+
+    #[test]
+    fn test_take_from_invalid() {
+        let mut method: ForeignItemFn = parse_quote! {
+            #[qdeprecated = "reason"]
+            fn foo(self: &MyObject);
+        };
+        assert!(ParsedQDeprecated::take_from(&mut method.attrs).is_err());
+
+        let mut method: ForeignItemFn = parse_quote! {
+            #[qdeprecated(reason)]
+            fn foo(self: &MyObject);
+        };
+        assert!(ParsedQDeprecated::take_from(&mut method.attrs).is_err());
+    }
+}
@@ -53,6 +53,14 @@ impl ParsedInheritedMethod {
             );
         }
 
+        // A rust_name allows for overloads of the same C++ method to be declared
+        if let Some(attr) = attribute_take_path(&mut method.attrs, &["rust_name"]) {
+            ident.rust = format_ident!(
+                "{}",
+                expr_to_string(&attr.meta.require_name_value()?.value)?
+            );
+        }
+
         let safe = method.sig.unsafety.is_none();
 
         Ok(Self {
@@ -156,4 +164,20 @@ mod tests {
         assert!(parsed.mutable);
         assert!(parsed.safe);
     }
+
+    #[test]
+    fn test_parse_rust_name() {
+        let function: ForeignItemFn = parse_quote! {
+            #[rust_name = "data_const"]
+            fn data(self: &T) -> i32;
+        };
+
+        let parsed = ParsedInheritedMethod::parse(function, Safety::Safe).unwrap();
+
+        assert_eq!(parsed.ident.rust, format_ident!("data_const"));
+        assert_eq!(parsed.ident.cpp, format_ident!("data"));
+        assert_eq!(parsed.wrapper_ident(), format_ident!("dataCxxQtInherit"));
+        assert!(parsed.method.attrs.is_empty());
+        assert!(!parsed.mutable);
+    }
 }
@@ -4,11 +4,18 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use crate::{
-    parser::parameter::ParsedFunctionParameter,
+    parser::{
+        conversion::ParsedQtConversions, deprecated::ParsedQDeprecated,
+        ownership::ParsedQmlOwnership, parameter::ParsedFunctionParameter,
+        revision::ParsedQRevision,
+    },
     syntax::{attribute::attribute_take_path, foreignmod, safety::Safety, types},
 };
 use std::collections::HashSet;
-use syn::{spanned::Spanned, Error, ForeignItemFn, Ident, Result};
+use syn::{
+    spanned::Spanned, Attribute, Error, Expr, ExprLit, FnArg, ForeignItemFn, Ident, Lit, LitStr,
+    Meta, MetaNameValue, Result, ReturnType, Type,
+};
 
 /// Describes a C++ specifier for the Q_INVOKABLE
 #[derive(Eq, Hash, PartialEq)]
@@ -28,6 +35,62 @@ impl ParsedQInvokableSpecifiers {
     }
 }
 
+/// Describes the C++ access specifier of the section that a method is declared in
+///
+/// This is declared as `#[qinvokable(access = "protected")]` or `#[qinvokable(access = "private")]`
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ParsedMethodAccess {
+    #[default]
+    Public,
+    Protected,
+    Private,
+}
+
+impl ParsedMethodAccess {
+    /// Parse the arguments of a `qinvokable` meta, such as `qinvokable(access = "protected")`
+    fn parse_meta(meta: &Meta) -> Result<Self> {
+        match meta {
+            Meta::Path(_) => Ok(Self::Public),
+            Meta::List(list) => {
+                let mut access = Self::Public;
+                list.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("access") {
+                        let value = meta.value()?.parse::<LitStr>()?;
+                        access = match value.value().as_str() {
+                            "public" => Self::Public,
+                            "protected" => Self::Protected,
+                            "private" => Self::Private,
+                            _ => {
+                                return Err(Error::new(
+                                    value.span(),
+                                    "Invalid access specifier, expected \"public\", \"protected\", or \"private\"",
+                                ))
+                            }
+                        };
+                        Ok(())
+                    } else {
+                        Err(meta.error("Unsupported qinvokable argument, expected access"))
+                    }
+                })?;
+                Ok(access)
+            }
+            Meta::NameValue(_) => Err(Error::new(
+                meta.span(),
+                "Expected #[qinvokable] or #[qinvokable(access = \"protected\")]",
+            )),
+        }
+    }
+
+    /// The C++ access specifier
+    pub fn as_cpp_str(&self) -> &'static str {
+        match self {
+            Self::Public => "public",
+            Self::Protected => "protected",
+            Self::Private => "private",
+        }
+    }
+}
+
 /// Describes a single method (which could be a Q_INVOKABLE) for a struct
 pub struct ParsedMethod {
     /// The original [syn::ImplItemFn] of the invokable
@@ -44,6 +107,19 @@ pub struct ParsedMethod {
     pub specifiers: HashSet<ParsedQInvokableSpecifiers>,
     /// Whether the method is qinvokable
     pub is_qinvokable: bool,
+    /// The QML revision that the invokable was added in, if any
+    pub revision: Option<ParsedQRevision>,
+    /// Whether the method is deprecated, and why
+    pub deprecated: Option<ParsedQDeprecated>,
+    /// The QML ownership that is set on the QObject returned from the invokable, if any
+    pub ownership: Option<ParsedQmlOwnership>,
+    /// The Rust types that the parameters and return type are converted between
+    pub conversions: ParsedQtConversions,
+    /// The C++ access specifier of the section that the method is declared in
+    pub access: ParsedMethodAccess,
+    /// Whether the method is an associated function without a self receiver,
+    /// which is declared as a static member of the C++ class
+    pub is_static: bool,
 }
 
 impl ParsedMethod {
@@ -55,8 +131,43 @@ impl ParsedMethod {
             ));
         }
 
-        // Determine if the method is invokable
-        let is_qinvokable = attribute_take_path(&mut method.attrs, &["qinvokable"]).is_some();
+        // Determine if the method is invokable, and which C++ section it is declared in
+        let qinvokable = attribute_take_path(&mut method.attrs, &["qinvokable"]);
+        let is_qinvokable = qinvokable.is_some();
+        let access = qinvokable
+            .map(|attr| ParsedMethodAccess::parse_meta(&attr.meta))
+            .transpose()?
+            .unwrap_or_default();
+
+        // Revisions only have a meaning for methods which are in the meta object
+        let revision = ParsedQRevision::take_from(&mut method.attrs)?;
+        if revision.is_some() && !is_qinvokable {
+            return Err(Error::new(
+                method.span(),
+                "#[qrevision] can only be used on #[qinvokable] methods",
+            ));
+        }
+
+        let deprecated = ParsedQDeprecated::take_from(&mut method.attrs)?;
+
+        // An ownership is set on the QObject pointer that is returned to QML
+        let ownership = ParsedQmlOwnership::take_from(&mut method.attrs)?;
+        if ownership.is_some() {
+            if !is_qinvokable {
+                return Err(Error::new(
+                    method.span(),
+                    "#[qml_ownership] can only be used on #[qinvokable] methods",
+                ));
+            }
+
+            if !matches!(&method.sig.output, ReturnType::Type(_, ty) if matches!(**ty, Type::Ptr(_)))
+            {
+                return Err(Error::new(
+                    method.sig.output.span(),
+                    "#[qml_ownership] can only be used on invokables which return a pointer to a QObject, such as `*mut MyObject`",
+                ));
+            }
+        }
 
         // Parse any C++ specifiers
         let mut specifiers = HashSet::new();
@@ -70,12 +181,47 @@ impl ParsedMethod {
             }
         }
 
-        // Determine if the invokable is mutable
-        let self_receiver = foreignmod::self_type_from_foreign_fn(&method.sig)?;
-        let (qobject_ident, mutability) = types::extract_qobject_ident(&self_receiver.ty)?;
-        let mutable = mutability.is_some();
+        let conversions = ParsedQtConversions::take_from(&mut method)?;
+
+        // An associated function without a self receiver names its QObject with #[Self = "MyObject"]
+        let self_attr = attribute_take_path(&mut method.attrs, &["Self"]);
+        let has_receiver = matches!(method.sig.inputs.first(), Some(FnArg::Receiver(_)));
+        let is_static = self_attr.is_some() || (is_qinvokable && !has_receiver);
+
+        let (qobject_ident, mutable, parameters) = if is_static {
+            let qobject_ident = Self::parse_self_attr(self_attr, &method)?;
+
+            if has_receiver {
+                return Err(Error::new(
+                    method.sig.inputs.span(),
+                    "#[Self = \"...\"] can only be used on associated functions without a self receiver",
+                ));
+            }
+
+            if !is_qinvokable {
+                return Err(Error::new(
+                    method.span(),
+                    "Associated functions without a self receiver must be a #[qinvokable]",
+                ));
+            }
+
+            if !specifiers.is_empty() {
+                return Err(Error::new(
+                    method.span(),
+                    "#[cxx_final], #[cxx_override], and #[cxx_virtual] cannot be used on static invokables",
+                ));
+            }
+
+            let parameters = ParsedFunctionParameter::parse_all(&method.sig)?;
+            (qobject_ident, false, parameters)
+        } else {
+            // Determine if the invokable is mutable
+            let self_receiver = foreignmod::self_type_from_foreign_fn(&method.sig)?;
+            let (qobject_ident, mutability) = types::extract_qobject_ident(&self_receiver.ty)?;
 
-        let parameters = ParsedFunctionParameter::parse_all_ignoring_receiver(&method.sig)?;
+            let parameters = ParsedFunctionParameter::parse_all_ignoring_receiver(&method.sig)?;
+            (qobject_ident, mutability.is_some(), parameters)
+        };
 
         let safe = method.sig.unsafety.is_none();
 
@@ -87,6 +233,37 @@ impl ParsedMethod {
             specifiers,
             safe,
             is_qinvokable,
+            revision,
+            deprecated,
+            ownership,
+            conversions,
+            access,
+            is_static,
         })
     }
+
+    /// Parse the QObject that a static invokable belongs to from `#[Self = "MyObject"]`
+    fn parse_self_attr(attr: Option<Attribute>, method: &ForeignItemFn) -> Result<Ident> {
+        let attr = attr.ok_or_else(|| {
+            Error::new(
+                method.sig.span(),
+                "Static invokables must declare their QObject with #[Self = \"MyObject\"]",
+            )
+        })?;
+
+        match &attr.meta {
+            Meta::NameValue(MetaNameValue {
+                value:
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(lit_str),
+                        ..
+                    }),
+                ..
+            }) => lit_str.parse::<Ident>(),
+            _ => Err(Error::new(
+                attr.span(),
+                "Expected #[Self = \"MyObject\"] with the name of the QObject",
+            )),
+        }
+    }
 }
@@ -3,25 +3,80 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+pub mod classinfo;
 pub mod constructor;
+pub mod container;
+pub mod conversion;
 pub mod cxxqtdata;
+pub mod deprecated;
 pub mod externcxxqt;
 pub mod inherit;
 pub mod mappings;
 pub mod method;
+pub mod ownership;
+pub mod panic;
 pub mod parameter;
 pub mod property;
 pub mod qenum;
+pub mod qmltypes;
 pub mod qnamespace;
 pub mod qobject;
+pub mod revision;
 pub mod signals;
 
 use crate::syntax::{attribute::attribute_take_path, expr::expr_to_string};
 use cxxqtdata::ParsedCxxQtData;
+use std::path::PathBuf;
 use syn::{
-    punctuated::Punctuated, spanned::Spanned, token::Brace, Error, ItemMod, Meta, Result, Token,
+    punctuated::Punctuated, spanned::Spanned, token::Brace, Error, Expr, Ident, Item, ItemMod,
+    Meta, Result, Token,
 };
 
+/// Read the items of a bridge extension file
+///
+/// The path is relative to the manifest directory of the crate, as this is the same
+/// for both the macro and the build script.
+fn parse_extension_file(path_expr: &Expr) -> Result<(PathBuf, Vec<Item>)> {
+    let path = expr_to_string(path_expr)?;
+    let path = if let Some(manifest_dir) = std::env::var_os("CARGO_MANIFEST_DIR") {
+        PathBuf::from(manifest_dir).join(path)
+    } else {
+        PathBuf::from(path)
+    };
+
+    let source = std::fs::read_to_string(&path).map_err(|err| {
+        Error::new_spanned(
+            path_expr,
+            format!(
+                "Could not read the extension file {}: {err}",
+                path.display()
+            ),
+        )
+    })?;
+    let file = syn::parse_file(&source).map_err(|err| {
+        Error::new_spanned(
+            path_expr,
+            format!(
+                "Could not parse the extension file {}: {err}",
+                path.display()
+            ),
+        )
+    })?;
+
+    Ok((path, file.items))
+}
+
+/// The directory prefix that generated headers are included with
+///
+/// This is `cxx-qt-gen` unless the `CXXQT_INCLUDE_PREFIX` environment variable is set,
+/// which `cxx-qt-build` sets for the macro when a custom prefix is used.
+fn include_prefix() -> String {
+    std::env::var("CXXQT_INCLUDE_PREFIX")
+        .ok()
+        .filter(|prefix| !prefix.is_empty())
+        .unwrap_or_else(|| "cxx-qt-gen".to_owned())
+}
+
 /// A struct representing a module block with CXX-Qt relevant [syn::Item]'s
 /// parsed into ParsedCxxQtData, to be used later to generate Rust & C++ code.
 ///
@@ -33,6 +88,16 @@ pub struct Parser {
     pub cxx_qt_data: ParsedCxxQtData,
     /// The stem of the file that the CXX headers for this module will be generated into
     pub cxx_file_stem: String,
+    /// The directory prefix that the generated headers are included with, such as `cxx-qt-gen`
+    pub include_prefix: String,
+    /// The name of the module which re-exports the public API of the bridge, if one has been requested
+    pub facade: Option<Ident>,
+    /// The paths of any extension files whose items have been merged into the bridge
+    pub extension_files: Vec<PathBuf>,
+    /// Whether tracing spans are generated around the Rust entry points of the bridge
+    ///
+    /// This is set by the `tracing` feature of `cxx-qt-macro` and is false otherwise.
+    pub trace: bool,
 }
 
 impl Parser {
@@ -41,6 +106,9 @@ impl Parser {
         let mut namespace = "".to_owned();
         let mut others = vec![];
         let mut cxx_file_stem = module.ident.to_string();
+        let mut facade = None;
+        let mut extension_files = vec![];
+        let mut extension_items = vec![];
 
         // Remove the cxx_qt::bridge attribute
         if let Some(attr) = attribute_take_path(&mut module.attrs, &["cxx_qt", "bridge"]) {
@@ -57,6 +125,20 @@ impl Parser {
                             // Parse any custom file stem
                             } else if name_value.path.is_ident("cxx_file_stem") {
                                 cxx_file_stem = expr_to_string(&name_value.value)?;
+                            // Parse any facade module name
+                            } else if name_value.path.is_ident("facade") {
+                                let name = expr_to_string(&name_value.value)?;
+                                facade = Some(syn::parse_str::<Ident>(&name).map_err(|_| {
+                                    Error::new_spanned(
+                                        &name_value.value,
+                                        "Expected a valid module name for the facade",
+                                    )
+                                })?);
+                            // Parse any extension files which declare more items for the bridge
+                            } else if name_value.path.is_ident("extend") {
+                                let (path, mut items) = parse_extension_file(&name_value.value)?;
+                                extension_files.push(path);
+                                extension_items.append(&mut items);
                             }
                         }
                         _others => {}
@@ -72,6 +154,15 @@ impl Parser {
 
         let mut cxx_qt_data = ParsedCxxQtData::new(module.ident.clone(), namespace);
 
+        // Merge the items of any extension files into the module, as if they were declared inline
+        if !extension_items.is_empty() {
+            module
+                .content
+                .get_or_insert_with(|| (Brace::default(), vec![]))
+                .1
+                .append(&mut extension_items);
+        }
+
         // Check that there are items in the module
         if let Some(mut items) = module.content {
             // Find any QObject structs
@@ -95,11 +186,19 @@ impl Parser {
         module.content = Some((Brace::default(), others));
 
         // Return the successful Parser object
-        Ok(Self {
+        let parser = Self {
             passthrough_module: module,
             cxx_qt_data,
             cxx_file_stem,
-        })
+            include_prefix: include_prefix(),
+            facade,
+            extension_files,
+            trace: false,
+        };
+        // Check the QML elements and containers once here, rather than in each of the generators
+        qmltypes::validate(&parser)?;
+        container::validate(&parser)?;
+        Ok(parser)
     }
 }
 
@@ -153,6 +252,43 @@ mod tests {
         assert_eq!(parser.cxx_qt_data.qobjects.len(), 0);
     }
 
+    #[test]
+    fn test_parser_from_extend() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(extend = "test_inputs/extend/invokables.rs")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+
+        assert_eq!(parser.extension_files.len(), 1);
+        assert!(parser.extension_files[0].ends_with("test_inputs/extend/invokables.rs"));
+        assert_eq!(parser.passthrough_module.content.unwrap().1.len(), 0);
+
+        let qobject = parser
+            .cxx_qt_data
+            .qobjects
+            .get(&format_ident!("MyObject"))
+            .unwrap();
+        assert_eq!(qobject.methods.len(), 1);
+        assert_eq!(qobject.methods[0].method.sig.ident, "extended_invokable");
+        assert_eq!(qobject.signals.len(), 1);
+        assert_eq!(qobject.signals[0].method.sig.ident, "extended_signal");
+    }
+
+    #[test]
+    fn test_parser_from_extend_missing() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(extend = "test_inputs/extend/missing.rs")]
+            mod ffi {}
+        };
+        assert!(Parser::from(module).is_err());
+    }
+
     #[test]
     fn test_parser_from_cxx_qt_items() {
         let module: ItemMod = parse_quote! {
@@ -253,4 +389,24 @@ mod tests {
         let parser = Parser::from(module);
         assert!(parser.is_err());
     }
+
+    #[test]
+    fn test_parser_from_facade() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(facade = "api")]
+            mod ffi {}
+        };
+        let parser = Parser::from(module).unwrap();
+        assert_eq!(parser.facade, Some(format_ident!("api")));
+    }
+
+    #[test]
+    fn test_parser_from_error_invalid_facade() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(facade = "not a module")]
+            mod ffi {}
+        };
+        let parser = Parser::from(module);
+        assert!(parser.is_err());
+    }
 }
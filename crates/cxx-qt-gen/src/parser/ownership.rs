@@ -0,0 +1,80 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::syntax::attribute::attribute_take_path;
+use syn::{Attribute, Error, Ident, Result};
+
+/// Describes the QML ownership of a QObject which is returned from an invokable
+///
+/// This is declared as `#[qml_ownership(cpp)]` or `#[qml_ownership(javascript)]`
+/// and is set on the returned QObject with `QQmlEngine::setObjectOwnership`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParsedQmlOwnership {
+    /// The QObject is owned by C++ or Rust code, so it is never destroyed by the QML engine
+    Cpp,
+    /// The QObject is owned by the QML engine, so it is destroyed by the garbage collector
+    JavaScript,
+}
+
+impl ParsedQmlOwnership {
+    /// Take any `#[qml_ownership(...)]` attribute from the given attributes and parse it
+    pub fn take_from(attrs: &mut Vec<Attribute>) -> Result<Option<Self>> {
+        if let Some(attr) = attribute_take_path(attrs, &["qml_ownership"]) {
+            let ownership = attr.parse_args::<Ident>()?;
+            match ownership.to_string().as_str() {
+                "cpp" => Ok(Some(Self::Cpp)),
+                "javascript" => Ok(Some(Self::JavaScript)),
+                _others => Err(Error::new_spanned(
+                    ownership,
+                    "Expected #[qml_ownership(cpp)] or #[qml_ownership(javascript)]",
+                )),
+            }
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// The `QQmlEngine::ObjectOwnership` value of this ownership
+    pub fn to_cpp_ownership(self) -> &'static str {
+        match self {
+            Self::Cpp => "::QQmlEngine::CppOwnership",
+            Self::JavaScript => "::QQmlEngine::JavaScriptOwnership",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use syn::{parse_quote, ForeignItemFn};
+
+    #[test]
+    fn test_take_from() {
+        let mut method: ForeignItemFn = parse_quote! {
+            #[qml_ownership(javascript)]
+            #[qinvokable]
+            fn invokable(self: &MyObject) -> *mut Other;
+        };
+        let ownership = ParsedQmlOwnership::take_from(&mut method.attrs).unwrap();
+        assert_eq!(ownership, Some(ParsedQmlOwnership::JavaScript));
+        assert_eq!(
+            ownership.unwrap().to_cpp_ownership(),
+            "::QQmlEngine::JavaScriptOwnership"
+        );
+        assert_eq!(method.attrs.len(), 1);
+
+        assert_eq!(ParsedQmlOwnership::take_from(&mut vec![]).unwrap(), None);
+    }
+
+    #[test]
+    fn test_take_from_invalid() {
+        let mut attrs: Vec<Attribute> = vec![parse_quote! { #[qml_ownership(rust)] }];
+        assert!(ParsedQmlOwnership::take_from(&mut attrs).is_err());
+
+        let mut attrs: Vec<Attribute> = vec![parse_quote! { #[qml_ownership] }];
+        assert!(ParsedQmlOwnership::take_from(&mut attrs).is_err());
+    }
+}
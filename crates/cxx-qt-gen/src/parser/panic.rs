@@ -0,0 +1,281 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::utils::cpp::Indent,
+    parser::{method::ParsedMethod, signals::ParsedSignal},
+    syntax::{attribute::attribute_take_path, safety::Safety},
+};
+use indoc::formatdoc;
+use syn::{Attribute, Error, Ident, Result, ReturnType, Type};
+
+/// Describes how a panic in the Rust implementation of an invokable or property setter is handled
+///
+/// This is declared as `#[qpanic(abort)]`, `#[qpanic(signal)]`, or `#[qpanic(exception)]` on a QObject
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ParsedQPanic {
+    /// The process is aborted, as for any other panic across the FFI boundary
+    #[default]
+    Abort,
+    /// The panic is logged with qWarning and the `panicked(QString)` signal of the QObject is emitted
+    Signal,
+    /// The panic is thrown as an error into the QML engine of the QObject
+    Exception,
+}
+
+impl ParsedQPanic {
+    /// Take any `#[qpanic(...)]` attribute from the given attributes and parse it
+    pub fn take_from(attrs: &mut Vec<Attribute>) -> Result<Self> {
+        if let Some(attr) = attribute_take_path(attrs, &["qpanic"]) {
+            let policy = attr.parse_args::<Ident>()?;
+            match policy.to_string().as_str() {
+                "abort" => Ok(Self::Abort),
+                "signal" => Ok(Self::Signal),
+                "exception" => Ok(Self::Exception),
+                _others => Err(Error::new_spanned(
+                    policy,
+                    "Expected #[qpanic(abort)], #[qpanic(signal)], or #[qpanic(exception)]",
+                )),
+            }
+        } else {
+            Ok(Self::Abort)
+        }
+    }
+
+    /// Whether panics are caught before they reach the FFI boundary
+    pub fn catches(self) -> bool {
+        self != Self::Abort
+    }
+
+    /// Whether panics in the given method are caught
+    ///
+    /// Methods which return a Result already throw their error to C++, so are left as they are.
+    /// Static methods have no instance to report the panic to, so are also left as they are.
+    pub fn catches_method(self, method: &ParsedMethod) -> Result<bool> {
+        if !self.catches() || method.is_static {
+            return Ok(false);
+        }
+
+        if let ReturnType::Type(_, ty) = &method.method.sig.output {
+            match &**ty {
+                // A reference cannot be returned when the method has panicked
+                Type::Reference(_) => {
+                    return Err(Error::new_spanned(
+                        ty,
+                        "methods of a QObject with #[qpanic(signal)] or #[qpanic(exception)] cannot return references",
+                    ))
+                }
+                Type::Path(ty_path)
+                    if ty_path
+                        .path
+                        .segments
+                        .first()
+                        .is_some_and(|segment| segment.ident == "Result") =>
+                {
+                    return Ok(false)
+                }
+                // The value which is returned when the method has panicked is default constructed,
+                // which is not possible for a rust::Box or a rust::Fn
+                Type::Path(ty_path)
+                    if ty_path
+                        .path
+                        .segments
+                        .last()
+                        .is_some_and(|segment| segment.ident == "Box") =>
+                {
+                    return Err(Error::new_spanned(
+                        ty,
+                        "methods of a QObject with #[qpanic(signal)] or #[qpanic(exception)] cannot return a Box, as it cannot be default constructed",
+                    ))
+                }
+                Type::BareFn(_) => {
+                    return Err(Error::new_spanned(
+                        ty,
+                        "methods of a QObject with #[qpanic(signal)] or #[qpanic(exception)] cannot return a function pointer, as it cannot be default constructed",
+                    ))
+                }
+                _others => {}
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// The `panicked(QString)` signal which is emitted with the panic message, if this policy emits a signal
+    pub fn signal(self, qobject_ident: &Ident) -> Result<Option<ParsedSignal>> {
+        if self != Self::Signal {
+            return Ok(None);
+        }
+
+        ParsedSignal::parse(
+            syn::parse_quote! {
+                #[doc = "Emitted when an invokable or property setter has panicked, with the panic message"]
+                fn panicked(self: Pin<&mut #qobject_ident>, message: QString);
+            },
+            Safety::Safe,
+        )
+        .map(Some)
+    }
+
+    /// The C++ statements which call into Rust and handle a panic according to this policy
+    ///
+    /// The call is a complete statement, such as `return invokableWrapper(value);`,
+    /// and the item is the name of the method that is reported, such as `MyObject::invokable`.
+    pub fn to_cpp_call(self, call: &str, qobject_ident: &str, item: &str, returns: bool) -> String {
+        let handler = match self {
+            Self::Abort => return call.to_owned(),
+            Self::Signal => format!(
+                "qWarning(\"Panic in {item}: %s\", error.what());\nQ_EMIT const_cast<{qobject_ident}*>(this)->panicked(QString::fromUtf8(error.what()));"
+            ),
+            Self::Exception => format!(
+                "if (auto* engine = ::qmlEngine(this)) {{\n  engine->throwError(QString::fromUtf8(error.what()));\n}} else {{\n  qWarning(\"Panic in {item}: %s\", error.what());\n}}"
+            ),
+        };
+        let fallback = if returns { "return {};" } else { "return;" };
+
+        formatdoc! { r#"
+            try {{
+            {call}
+            }} catch (const ::rust::Error& error) {{
+            {handler}
+            {fallback}
+            }}"#,
+            call = call.indented(2),
+            handler = handler.indented(2),
+            fallback = fallback.indented(2),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
+    use syn::{parse_quote, ForeignItemFn};
+
+    #[test]
+    fn test_take_from() {
+        let mut attrs: Vec<Attribute> = vec![parse_quote! { #[qpanic(signal)] }];
+        assert_eq!(
+            ParsedQPanic::take_from(&mut attrs).unwrap(),
+            ParsedQPanic::Signal
+        );
+        assert!(attrs.is_empty());
+
+        let mut attrs: Vec<Attribute> = vec![parse_quote! { #[qpanic(exception)] }];
+        assert_eq!(
+            ParsedQPanic::take_from(&mut attrs).unwrap(),
+            ParsedQPanic::Exception
+        );
+        assert_eq!(
+            ParsedQPanic::take_from(&mut vec![]).unwrap(),
+            ParsedQPanic::Abort
+        );
+    }
+
+    #[test]
+    fn test_take_from_invalid() {
+        let mut attrs: Vec<Attribute> = vec![parse_quote! { #[qpanic(ignore)] }];
+        assert!(ParsedQPanic::take_from(&mut attrs).is_err());
+
+        let mut attrs: Vec<Attribute> = vec![parse_quote! { #[qpanic] }];
+        assert!(ParsedQPanic::take_from(&mut attrs).is_err());
+    }
+
+    #[test]
+    fn test_catches_method() {
+        let parse = |method: ForeignItemFn| ParsedMethod::parse(method, Safety::Safe).unwrap();
+
+        let method = parse(parse_quote! { fn invokable(self: &MyObject) -> i32; });
+        assert!(!ParsedQPanic::Abort.catches_method(&method).unwrap());
+        assert!(ParsedQPanic::Signal.catches_method(&method).unwrap());
+
+        let method = parse(parse_quote! { fn invokable(self: &MyObject) -> Result<i32>; });
+        assert!(!ParsedQPanic::Signal.catches_method(&method).unwrap());
+
+        let method = parse(parse_quote! { fn invokable(self: &MyObject) -> &i32; });
+        assert!(ParsedQPanic::Abort.catches_method(&method).is_ok());
+        assert!(ParsedQPanic::Exception.catches_method(&method).is_err());
+
+        // The fallback value after a panic must be default constructible
+        let method = parse(parse_quote! { fn invokable(self: &MyObject) -> Box<MyRustType>; });
+        assert!(ParsedQPanic::Abort.catches_method(&method).is_ok());
+        assert!(ParsedQPanic::Signal.catches_method(&method).is_err());
+
+        let method = parse(parse_quote! { fn invokable(self: &MyObject) -> fn(i32) -> i32; });
+        assert!(ParsedQPanic::Abort.catches_method(&method).is_ok());
+        assert!(ParsedQPanic::Exception.catches_method(&method).is_err());
+
+        let method = parse(parse_quote! { fn invokable(self: &MyObject) -> UniquePtr<QColor>; });
+        assert!(ParsedQPanic::Signal.catches_method(&method).unwrap());
+    }
+
+    #[test]
+    fn test_signal() {
+        let qobject_ident = quote::format_ident!("MyObject");
+        assert!(ParsedQPanic::Abort
+            .signal(&qobject_ident)
+            .unwrap()
+            .is_none());
+
+        let signal = ParsedQPanic::Signal
+            .signal(&qobject_ident)
+            .unwrap()
+            .unwrap();
+        assert_eq!(signal.ident.rust, "panicked");
+        assert_eq!(signal.parameters.len(), 1);
+        assert_eq!(signal.qobject_ident, "MyObject");
+    }
+
+    #[test]
+    fn test_to_cpp_call() {
+        assert_str_eq!(
+            ParsedQPanic::Abort.to_cpp_call(
+                "return invokableWrapper();",
+                "MyObject",
+                "MyObject::invokable",
+                true
+            ),
+            "return invokableWrapper();"
+        );
+        assert_str_eq!(
+            ParsedQPanic::Signal.to_cpp_call(
+                "invokableWrapper();",
+                "MyObject",
+                "MyObject::invokable",
+                false
+            ),
+            indoc! {r#"
+            try {
+              invokableWrapper();
+            } catch (const ::rust::Error& error) {
+              qWarning("Panic in MyObject::invokable: %s", error.what());
+              Q_EMIT const_cast<MyObject*>(this)->panicked(QString::fromUtf8(error.what()));
+              return;
+            }"#}
+        );
+        assert_str_eq!(
+            ParsedQPanic::Exception.to_cpp_call(
+                "return invokableWrapper();",
+                "MyObject",
+                "MyObject::invokable",
+                true
+            ),
+            indoc! {r#"
+            try {
+              return invokableWrapper();
+            } catch (const ::rust::Error& error) {
+              if (auto* engine = ::qmlEngine(this)) {
+                engine->throwError(QString::fromUtf8(error.what()));
+              } else {
+                qWarning("Panic in MyObject::invokable: %s", error.what());
+              }
+              return {};
+            }"#}
+        );
+    }
+}
@@ -10,7 +10,7 @@ use syn::{
 };
 
 /// Describes a single parameter for a function
-#[derive(Debug, PartialEq)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
 pub struct ParsedFunctionParameter {
     /// The [syn::Ident] of the parameter
     pub ident: Ident,
@@ -41,6 +41,11 @@ impl ParsedFunctionParameter {
         .collect::<Result<Vec<ParsedFunctionParameter>>>()
     }
 
+    /// This function parses the list of arguments of an associated function which has no receiver
+    pub fn parse_all(signature: &Signature) -> Result<Vec<ParsedFunctionParameter>> {
+        Self::parse_remaining(signature.inputs.iter())
+    }
+
     pub fn parse_all_ignoring_receiver(
         signature: &Signature,
     ) -> Result<Vec<ParsedFunctionParameter>> {
@@ -3,7 +3,34 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use syn::{parse::ParseStream, Attribute, Ident, Result, Token, Type};
+use crate::parser::{deprecated::ParsedQDeprecated, revision::ParsedQRevision};
+use syn::{
+    parse::ParseStream, spanned::Spanned, Attribute, Error, Expr, ExprPath, Fields, Ident,
+    ItemStruct, Meta, Result, Token, Type,
+};
+
+/// The flags of a Q_PROPERTY which change the parts of the property that are generated
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct QPropertyFlags {
+    /// Whether the property is CONSTANT, in which case it has no setter or notify signal
+    pub constant: bool,
+    /// Whether the property is read only, in which case it has a notify signal but no setter
+    pub read_only: bool,
+    /// Whether the property is computed by a user defined getter, in which case it has no backing field or setter
+    pub computed: bool,
+    /// Whether the property is REQUIRED, in which case QML must set it when the object is instantiated
+    pub required: bool,
+    /// Whether the property is FINAL, in which case it cannot be overridden by derived types
+    pub is_final: bool,
+    /// The name of the getter, if it is not derived from the name of the property
+    pub read: Option<Ident>,
+    /// The name of the setter, if it is not derived from the name of the property
+    pub write: Option<Ident>,
+    /// The name of the notify signal, if it is not derived from the name of the property
+    pub notify: Option<Ident>,
+    /// The name of the reset function which is implemented by the developer, if the property can be reset
+    pub reset: Option<Ident>,
+}
 
 /// Describes a single Q_PROPERTY for a struct
 pub struct ParsedQProperty {
@@ -11,6 +38,12 @@ pub struct ParsedQProperty {
     pub ident: Ident,
     /// The [syn::Type] of the property
     pub ty: Type,
+    /// The QML revision that the property was added in, if any
+    pub revision: Option<ParsedQRevision>,
+    /// Whether the property is deprecated, and why
+    pub deprecated: Option<ParsedQDeprecated>,
+    /// The flags of the property
+    pub flags: QPropertyFlags,
 }
 
 impl ParsedQProperty {
@@ -18,14 +51,174 @@ impl ParsedQProperty {
         attr.parse_args_with(|input: ParseStream| -> Result<Self> {
             let ty = input.parse()?;
             let _comma = input.parse::<Token![,]>()?;
-            let ident = input.parse()?;
+            let ident: Ident = input.parse()?;
+            let mut revision = None;
+            let mut deprecated = None;
+            let mut flags = QPropertyFlags::default();
 
             // TODO: later we'll need to parse setters and getters here
             // which are key-value, hence this not being parsed as a list
+            while !input.is_empty() {
+                let _comma = input.parse::<Token![,]>()?;
+                if input.is_empty() {
+                    break;
+                }
+
+                // final is a reserved keyword, so it is not parsed as a Meta
+                if input.peek(Token![final]) && !flags.is_final {
+                    let _final = input.parse::<Token![final]>()?;
+                    flags.is_final = true;
+                    continue;
+                }
+
+                match input.parse::<Meta>()? {
+                    Meta::List(list) if list.path.is_ident("qrevision") && revision.is_none() => {
+                        revision = Some(list.parse_args_with(ParsedQRevision::parse_args)?);
+                    }
+                    meta if meta.path().is_ident("qdeprecated") && deprecated.is_none() => {
+                        deprecated = Some(ParsedQDeprecated::parse_meta(&meta)?);
+                    }
+                    Meta::Path(path) if path.is_ident("constant") && !flags.constant => {
+                        flags.constant = true;
+                    }
+                    Meta::Path(path) if path.is_ident("read_only") && !flags.read_only => {
+                        flags.read_only = true;
+                    }
+                    Meta::Path(path) if path.is_ident("computed") && !flags.computed => {
+                        flags.computed = true;
+                    }
+                    Meta::Path(path) if path.is_ident("required") && !flags.required => {
+                        flags.required = true;
+                    }
+                    Meta::NameValue(name_value)
+                        if name_value.path.is_ident("READ") && flags.read.is_none() =>
+                    {
+                        flags.read = Some(Self::parse_name(&name_value.value)?);
+                    }
+                    Meta::NameValue(name_value)
+                        if name_value.path.is_ident("WRITE") && flags.write.is_none() =>
+                    {
+                        flags.write = Some(Self::parse_name(&name_value.value)?);
+                    }
+                    Meta::NameValue(name_value)
+                        if name_value.path.is_ident("NOTIFY") && flags.notify.is_none() =>
+                    {
+                        flags.notify = Some(Self::parse_name(&name_value.value)?);
+                    }
+                    Meta::NameValue(name_value)
+                        if name_value.path.is_ident("RESET") && flags.reset.is_none() =>
+                    {
+                        flags.reset = Some(Self::parse_name(&name_value.value)?);
+                    }
+                    meta => {
+                        return Err(Error::new(meta.span(), "Unsupported qproperty argument"));
+                    }
+                }
+            }
+
+            // A constant property is already read only
+            if flags.constant && flags.read_only {
+                return Err(Error::new(
+                    ident.span(),
+                    "qproperty cannot be both constant and read_only",
+                ));
+            }
+            // A computed property has no setter, so is already read only
+            if flags.computed && flags.read_only {
+                return Err(Error::new(
+                    ident.span(),
+                    "qproperty cannot be both computed and read_only",
+                ));
+            }
+            if let (true, Some(write)) = (
+                flags.constant || flags.read_only || flags.computed,
+                &flags.write,
+            ) {
+                return Err(Error::new(
+                    write.span(),
+                    "WRITE cannot be used with a constant, read_only, or computed qproperty",
+                ));
+            }
+            // A required property is set by QML when the object is instantiated, so it must be writable
+            if flags.required && (flags.constant || flags.read_only || flags.computed) {
+                return Err(Error::new(
+                    ident.span(),
+                    "required cannot be used with a constant, read_only, or computed qproperty",
+                ));
+            }
+            if let (true, Some(notify)) = (flags.constant, &flags.notify) {
+                return Err(Error::new(
+                    notify.span(),
+                    "NOTIFY cannot be used with a constant qproperty",
+                ));
+            }
+            if let (true, Some(reset)) = (flags.constant, &flags.reset) {
+                return Err(Error::new(
+                    reset.span(),
+                    "RESET cannot be used with a constant qproperty",
+                ));
+            }
 
-            Ok(Self { ident, ty })
+            Ok(Self {
+                ident,
+                ty,
+                revision,
+                deprecated,
+                flags,
+            })
         })
     }
+
+    /// Parse the name of a getter, setter, or notify signal, such as `READ = display_name`
+    fn parse_name(expr: &Expr) -> Result<Ident> {
+        if let Expr::Path(ExprPath { path, .. }) = expr {
+            if let Some(ident) = path.get_ident() {
+                return Ok(ident.clone());
+            }
+        }
+
+        Err(Error::new(expr.span(), "Expected the name of a method"))
+    }
+
+    /// Build a property for each of the named fields of a struct, as used by `#[qproperties_from_struct(T)]`
+    pub fn parse_from_struct(item: &ItemStruct) -> Result<Vec<Self>> {
+        if let Fields::Named(fields) = &item.fields {
+            Ok(fields
+                .named
+                .iter()
+                .filter_map(|field| {
+                    field.ident.as_ref().map(|ident| Self {
+                        ident: ident.clone(),
+                        ty: field.ty.clone(),
+                        revision: None,
+                        deprecated: None,
+                        flags: QPropertyFlags::default(),
+                    })
+                })
+                .collect())
+        } else {
+            Err(Error::new(
+                item.span(),
+                "#[qproperties_from_struct] requires a struct with named fields",
+            ))
+        }
+    }
+
+    /// Whether the property can be the source of a binding, which is when it has a notify signal
+    /// and the value can be stored in a QVariant to write it to the target
+    pub fn is_bindable(&self) -> bool {
+        if self.flags.constant {
+            return false;
+        }
+
+        // A QVariant cannot hold a std::unique_ptr
+        !matches!(&self.ty, Type::Path(type_path) if type_path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident == "UniquePtr")
+            .unwrap_or_default())
+    }
 }
 
 #[cfg(test)]
@@ -46,6 +239,234 @@ mod tests {
         assert_eq!(property.ty, parse_quote! { T });
     }
 
+    #[test]
+    fn test_parse_property_revision() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, qrevision(2, 1))]
+            struct MyStruct;
+        };
+        let property = ParsedQProperty::parse(input.attrs.remove(0)).unwrap();
+        assert_eq!(property.ident, format_ident!("name"));
+        assert_eq!(
+            property.revision,
+            Some(ParsedQRevision { major: 2, minor: 1 })
+        );
+    }
+
+    #[test]
+    fn test_parse_property_deprecated() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, qdeprecated("use other_name"), qrevision(2, 1))]
+            struct MyStruct;
+        };
+        let property = ParsedQProperty::parse(input.attrs.remove(0)).unwrap();
+        assert_eq!(
+            property.deprecated,
+            Some(ParsedQDeprecated {
+                reason: Some("use other_name".to_owned())
+            })
+        );
+        assert!(property.revision.is_some());
+
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, qdeprecated)]
+            struct MyStruct;
+        };
+        let property = ParsedQProperty::parse(input.attrs.remove(0)).unwrap();
+        assert_eq!(
+            property.deprecated,
+            Some(ParsedQDeprecated { reason: None })
+        );
+    }
+
+    #[test]
+    fn test_parse_property_constant() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, constant)]
+            struct MyStruct;
+        };
+        let property = ParsedQProperty::parse(input.attrs.remove(0)).unwrap();
+        assert!(property.flags.constant);
+
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, constant, constant)]
+            struct MyStruct;
+        };
+        assert!(ParsedQProperty::parse(input.attrs.remove(0)).is_err());
+    }
+
+    #[test]
+    fn test_parse_property_names() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(QString, name, READ = display_name, WRITE = set_display_name, NOTIFY = name_edited)]
+            struct MyStruct;
+        };
+        let property = ParsedQProperty::parse(input.attrs.remove(0)).unwrap();
+        assert_eq!(property.flags.read.unwrap(), "display_name");
+        assert_eq!(property.flags.write.unwrap(), "set_display_name");
+        assert_eq!(property.flags.notify.unwrap(), "name_edited");
+
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(QString, name, READ = "display_name")]
+            struct MyStruct;
+        };
+        assert!(ParsedQProperty::parse(input.attrs.remove(0)).is_err());
+
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(QString, name, read_only, WRITE = set_display_name)]
+            struct MyStruct;
+        };
+        assert!(ParsedQProperty::parse(input.attrs.remove(0)).is_err());
+
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(QString, name, constant, NOTIFY = name_edited)]
+            struct MyStruct;
+        };
+        assert!(ParsedQProperty::parse(input.attrs.remove(0)).is_err());
+    }
+
+    #[test]
+    fn test_parse_property_reset() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(i32, number, RESET = reset_number)]
+            struct MyStruct;
+        };
+        let property = ParsedQProperty::parse(input.attrs.remove(0)).unwrap();
+        assert_eq!(property.flags.reset.unwrap(), "reset_number");
+
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(i32, number, constant, RESET = reset_number)]
+            struct MyStruct;
+        };
+        assert!(ParsedQProperty::parse(input.attrs.remove(0)).is_err());
+
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(i32, number, RESET = reset_number, RESET = clear_number)]
+            struct MyStruct;
+        };
+        assert!(ParsedQProperty::parse(input.attrs.remove(0)).is_err());
+    }
+
+    #[test]
+    fn test_parse_property_required() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, required)]
+            struct MyStruct;
+        };
+        let property = ParsedQProperty::parse(input.attrs.remove(0)).unwrap();
+        assert!(property.flags.required);
+
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, required, read_only)]
+            struct MyStruct;
+        };
+        assert!(ParsedQProperty::parse(input.attrs.remove(0)).is_err());
+
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, constant, required)]
+            struct MyStruct;
+        };
+        assert!(ParsedQProperty::parse(input.attrs.remove(0)).is_err());
+    }
+
+    #[test]
+    fn test_parse_property_final() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, final, read_only)]
+            struct MyStruct;
+        };
+        let property = ParsedQProperty::parse(input.attrs.remove(0)).unwrap();
+        assert!(property.flags.is_final);
+        assert!(property.flags.read_only);
+
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, final, final)]
+            struct MyStruct;
+        };
+        assert!(ParsedQProperty::parse(input.attrs.remove(0)).is_err());
+    }
+
+    #[test]
+    fn test_parse_property_read_only() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, read_only)]
+            struct MyStruct;
+        };
+        let property = ParsedQProperty::parse(input.attrs.remove(0)).unwrap();
+        assert!(property.flags.read_only);
+        assert!(!property.flags.constant);
+
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, read_only, constant)]
+            struct MyStruct;
+        };
+        assert!(ParsedQProperty::parse(input.attrs.remove(0)).is_err());
+    }
+
+    #[test]
+    fn test_parse_property_computed() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, computed, READ = display_name)]
+            struct MyStruct;
+        };
+        let property = ParsedQProperty::parse(input.attrs.remove(0)).unwrap();
+        assert!(property.flags.computed);
+        assert!(!property.flags.read_only);
+        assert_eq!(property.flags.read.unwrap(), "display_name");
+
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, computed, read_only)]
+            struct MyStruct;
+        };
+        assert!(ParsedQProperty::parse(input.attrs.remove(0)).is_err());
+
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, computed, WRITE = set_display_name)]
+            struct MyStruct;
+        };
+        assert!(ParsedQProperty::parse(input.attrs.remove(0)).is_err());
+    }
+
+    #[test]
+    fn test_parse_property_revision_twice() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, qrevision(2, 1), qrevision(2, 2))]
+            struct MyStruct;
+        };
+        let property = ParsedQProperty::parse(input.attrs.remove(0));
+        assert!(property.is_err());
+    }
+
+    #[test]
+    fn test_is_bindable() {
+        let parse = |attr| ParsedQProperty::parse(attr).unwrap();
+        assert!(parse(parse_quote! { #[qproperty(i32, number)] }).is_bindable());
+        assert!(parse(parse_quote! { #[qproperty(QString, name, read_only)] }).is_bindable());
+        assert!(!parse(parse_quote! { #[qproperty(i32, version, constant)] }).is_bindable());
+        assert!(!parse(parse_quote! { #[qproperty(UniquePtr<QColor>, color)] }).is_bindable());
+    }
+
+    #[test]
+    fn test_parse_from_struct() {
+        let item: ItemStruct = parse_quote! {
+            struct Config {
+                width: i32,
+                title: QString,
+            }
+        };
+        let properties = ParsedQProperty::parse_from_struct(&item).unwrap();
+        assert_eq!(properties.len(), 2);
+        assert_eq!(properties[0].ident, format_ident!("width"));
+        assert_eq!(properties[0].ty, parse_quote! { i32 });
+        assert_eq!(properties[1].ident, format_ident!("title"));
+        assert_eq!(properties[1].ty, parse_quote! { QString });
+
+        let item: ItemStruct = parse_quote! {
+            struct Config(i32);
+        };
+        assert!(ParsedQProperty::parse_from_struct(&item).is_err());
+    }
+
     #[test]
     fn test_parse_property_arg_extra() {
         let mut input: ItemStruct = parse_quote! {
@@ -4,9 +4,20 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use quote::ToTokens;
-use syn::{Ident, ItemEnum, Result, Variant};
+use syn::{parse_quote, Ident, ItemEnum, Result, Variant};
 
-use crate::syntax::{attribute::attribute_find_path, expr::expr_to_string, path::path_compare_str};
+use crate::{
+    parser::signals::ParsedSignal,
+    syntax::{
+        attribute::{attribute_find_path, attribute_take_path},
+        expr::expr_to_string,
+        path::path_compare_str,
+        safety::Safety,
+    },
+};
+
+/// The maximum number of variants of a QEnum registered as QFlags, as each variant is a bit of an i32
+const QFLAGS_MAX_VARIANTS: usize = 31;
 
 pub struct ParsedQEnum {
     /// The ident of the QEnum
@@ -15,6 +26,10 @@ pub struct ParsedQEnum {
     pub namespace: String,
     /// the values of the QEnum
     pub variants: Vec<Ident>,
+    /// The name of the QFlags type if the QEnum is registered as flags with `#[qflags(...)]`
+    pub flags: Option<Ident>,
+    /// Whether the QEnum is the code of the errors of its QObject with `#[qml_error]`
+    pub qml_error: bool,
     /// The original enum item
     pub item: ItemEnum,
 }
@@ -48,7 +63,12 @@ impl ParsedQEnum {
         Ok(variant.ident.clone())
     }
 
-    pub fn parse(qenum: ItemEnum) -> Result<Self> {
+    pub fn parse(mut qenum: ItemEnum) -> Result<Self> {
+        let flags = attribute_take_path(&mut qenum.attrs, &["qflags"])
+            .map(|attr| attr.parse_args::<Ident>())
+            .transpose()?;
+        let qml_error = attribute_take_path(&mut qenum.attrs, &["qml_error"]).is_some();
+
         if qenum.variants.is_empty() {
             return Err(syn::Error::new_spanned(
                 qenum,
@@ -82,13 +102,71 @@ impl ParsedQEnum {
             .map(Self::parse_variant)
             .collect::<Result<_>>()?;
 
+        // Each variant of flags is a single bit, so that they can be OR-ed together
+        if flags.is_some() {
+            if qenum.variants.len() > QFLAGS_MAX_VARIANTS {
+                return Err(syn::Error::new_spanned(
+                    qenum,
+                    format!("QFlags can have at most {QFLAGS_MAX_VARIANTS} variants"),
+                ));
+            }
+
+            for (index, variant) in qenum.variants.iter_mut().enumerate() {
+                let value = proc_macro2::Literal::i32_unsuffixed(1 << index);
+                variant.discriminant = Some((Default::default(), parse_quote! { #value }));
+            }
+        }
+
         Ok(Self {
             namespace,
             ident: qenum.ident.clone(),
             variants,
+            flags,
+            qml_error,
             item: qenum,
         })
     }
+
+    /// The `error_occurred(code, message)` signal of the QObject, if this QEnum has `#[qml_error]`
+    pub fn error_signal(&self, qobject_ident: &Ident) -> Result<Option<ParsedSignal>> {
+        if !self.qml_error {
+            return Ok(None);
+        }
+
+        let qenum_ident = &self.ident;
+        ParsedSignal::parse(
+            parse_quote! {
+                #[doc = "Emitted when an error has occurred, with the code and the message of the error"]
+                fn error_occurred(self: Pin<&mut #qobject_ident>, code: #qenum_ident, message: QString);
+            },
+            Safety::Safe,
+        )
+        .map(Some)
+    }
+
+    /// The message of each variant of a `#[qml_error]` QEnum, which is the doc comment of the variant or otherwise its name
+    pub fn error_messages(&self) -> Vec<String> {
+        self.item
+            .variants
+            .iter()
+            .map(|variant| {
+                let lines = variant
+                    .attrs
+                    .iter()
+                    .filter_map(|attr| attr.meta.require_name_value().ok())
+                    .filter(|name_value| name_value.path.is_ident("doc"))
+                    .filter_map(|name_value| expr_to_string(&name_value.value).ok())
+                    .map(|line| line.trim().to_owned())
+                    .filter(|line| !line.is_empty())
+                    .collect::<Vec<_>>();
+                if lines.is_empty() {
+                    variant.ident.to_string()
+                } else {
+                    lines.join(" ")
+                }
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -96,7 +174,7 @@ mod tests {
     use crate::tests::assert_tokens_eq;
 
     use super::*;
-    use quote::quote;
+    use quote::{format_ident, quote};
     use syn::parse_quote;
 
     fn variants_to_strings(qenum: &ParsedQEnum) -> Vec<String> {
@@ -147,6 +225,77 @@ mod tests {
         assert_tokens_eq(&qenum.item, original_item);
     }
 
+    #[test]
+    fn parse_flags() {
+        let qenum: ItemEnum = parse_quote! {
+            #[qflags(MyFlags)]
+            enum MyFlag {
+                A,
+                B,
+                C,
+            }
+        };
+
+        let qenum = ParsedQEnum::parse(qenum).unwrap();
+        assert_eq!(qenum.ident, "MyFlag");
+        assert_eq!(qenum.flags.as_ref().unwrap(), "MyFlags");
+        assert_eq!(*variants_to_strings(&qenum), ["A", "B", "C"],);
+        assert_tokens_eq(
+            &qenum.item,
+            quote! {
+                enum MyFlag {
+                    A = 1,
+                    B = 2,
+                    C = 4,
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn parse_qml_error() {
+        let qenum: ItemEnum = parse_quote! {
+            #[qml_error]
+            enum MyError {
+                /// The request timed out
+                Timeout,
+                /// The resource
+                /// was not found
+                NotFound,
+                Unknown,
+            }
+        };
+
+        let qenum = ParsedQEnum::parse(qenum).unwrap();
+        assert!(qenum.qml_error);
+        assert!(qenum.item.attrs.is_empty());
+        assert_eq!(
+            qenum.error_messages(),
+            [
+                "The request timed out",
+                "The resource was not found",
+                "Unknown"
+            ]
+        );
+
+        let signal = qenum
+            .error_signal(&format_ident!("MyObject"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(signal.ident.rust, "error_occurred");
+        assert_eq!(signal.ident.cpp, "errorOccurred");
+        assert_eq!(signal.parameters.len(), 2);
+        assert_eq!(signal.parameters[0].ident, "code");
+        assert_eq!(signal.parameters[1].ident, "message");
+
+        let qenum = ParsedQEnum::parse(parse_quote! { enum MyEnum { A } }).unwrap();
+        assert!(!qenum.qml_error);
+        assert!(qenum
+            .error_signal(&format_ident!("MyObject"))
+            .unwrap()
+            .is_none());
+    }
+
     macro_rules! assert_parse_error {
         ($( $input:tt )*) => {
             let qenum: ItemEnum = parse_quote! { $($input)* };
@@ -190,6 +339,12 @@ mod tests {
             }
         }
 
+        assert_parse_error! {
+            // The QFlags type must be an ident
+            #[qflags]
+            enum MyEnum { A }
+        }
+
         // TODO: allow discriminants
         assert_parse_error! {
             enum MyEnum {
@@ -0,0 +1,364 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Validation that the types used by QML elements can be represented in the QML engine.
+//!
+//! Without this moc or the C++ compiler fail later with errors that are hard to relate
+//! back to the bridge.
+
+use crate::parser::{method::ParsedMethodAccess, qobject::ParsedQObject, Parser};
+use syn::{Error, GenericArgument, PathArguments, Result, ReturnType, Type};
+
+/// Where a type is used, as this changes which types are valid
+#[derive(Clone, Copy, PartialEq)]
+enum TypeUsage {
+    /// The type of a Q_PROPERTY
+    Property,
+    /// A parameter of a signal or invokable
+    Parameter,
+    /// The return type of an invokable
+    Return,
+}
+
+/// Suggest a QML compatible alternative for a Rust or CXX type
+fn suggestion_for_ident(ident: &str) -> Option<&'static str> {
+    match ident {
+        "String" | "str" | "CxxString" => Some("use QString instead"),
+        "Vec" | "CxxVector" => Some("use QList<T> or QVector<T> instead"),
+        "Option" => Some("use QVariant instead"),
+        "Box" | "UniquePtr" | "SharedPtr" | "WeakPtr" => {
+            Some("use a value type or a QObject pointer `*mut T` instead")
+        }
+        "usize" | "isize" => Some("use i32 or i64 instead"),
+        "Result" => Some("only the return type of an invokable can be a Result"),
+        _others => None,
+    }
+}
+
+/// Check that the given type can be represented in the QML engine
+fn validate_type(ty: &Type, usage: TypeUsage) -> Result<()> {
+    let error = |message: &str| {
+        Err(Error::new_spanned(
+            ty,
+            format!("Type is not supported by QML, {message}"),
+        ))
+    };
+
+    match ty {
+        Type::Path(type_path) => {
+            if let Some(segment) = type_path.path.segments.last() {
+                let ident = segment.ident.to_string();
+                if let Some(message) = suggestion_for_ident(&ident) {
+                    // Invokables can throw an exception by returning a Result
+                    if !(ident == "Result" && usage == TypeUsage::Return) {
+                        return error(message);
+                    }
+                }
+
+                // Check any generic arguments, eg QList<T>
+                if let PathArguments::AngleBracketed(arguments) = &segment.arguments {
+                    for argument in &arguments.args {
+                        if let GenericArgument::Type(ty) = argument {
+                            validate_type(ty, TypeUsage::Parameter)?;
+                        }
+                    }
+                }
+            }
+            Ok(())
+        }
+        Type::Reference(reference) => match usage {
+            TypeUsage::Property => error("properties must be owned types, use T instead of &T"),
+            _others if reference.mutability.is_some() => {
+                error("mutable references cannot be passed from QML, use &T or T instead")
+            }
+            _others => validate_type(&reference.elem, usage),
+        },
+        // The QML engine cannot convert a std::array or rust::Slice into a JavaScript value
+        Type::Array(_) => error(
+            "use QList<T> or QVector<T> instead, fixed-size arrays can only be used by QObjects which are not QML elements",
+        ),
+        Type::Slice(_) => error("use QList<T> or QVector<T> instead"),
+        Type::Tuple(tuple) if !tuple.elems.is_empty() => {
+            error("use QVariantList or QVariantMap instead")
+        }
+        Type::BareFn(_) | Type::ImplTrait(_) | Type::TraitObject(_) => {
+            error("functions and traits cannot be passed to QML")
+        }
+        Type::Group(group) => validate_type(&group.elem, usage),
+        Type::Paren(paren) => validate_type(&paren.elem, usage),
+        _others => Ok(()),
+    }
+}
+
+/// Check the properties, signals, and invokables of a QObject which is exposed to QML
+fn validate_qobject(qobject: &ParsedQObject) -> Result<()> {
+    if qobject.qml_metadata.is_none() {
+        return Ok(());
+    }
+
+    for property in &qobject.properties {
+        validate_type(&property.ty, TypeUsage::Property)?;
+    }
+
+    // Inherited signals are defined by the base class so are not checked
+    for signal in qobject.signals.iter().filter(|signal| !signal.inherit) {
+        for parameter in &signal.parameters {
+            validate_type(&parameter.ty, TypeUsage::Parameter)?;
+        }
+    }
+
+    // Only public invokables can be called from QML
+    for method in qobject
+        .methods
+        .iter()
+        .filter(|method| method.is_qinvokable && method.access == ParsedMethodAccess::Public)
+    {
+        for parameter in &method.parameters {
+            validate_type(&parameter.ty, TypeUsage::Parameter)?;
+        }
+
+        if let ReturnType::Type(_, ty) = &method.method.sig.output {
+            validate_type(ty, TypeUsage::Return)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that all QML elements only use types that can be represented in the QML engine
+pub fn validate(parser: &Parser) -> Result<()> {
+    parser
+        .cxx_qt_data
+        .qobjects
+        .values()
+        .try_for_each(validate_qobject)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use syn::{parse_quote, ItemMod};
+
+    fn validate_module(module: ItemMod) -> Result<()> {
+        Parser::from(module).map(|_| ())
+    }
+
+    #[test]
+    fn test_validate_qml_element() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    #[qml_element]
+                    #[qproperty(QString, string)]
+                    #[qproperty(*mut QObject, object)]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qsignal]
+                    fn ready(self: Pin<&mut MyObject>, value: &QString, list: QList<i32>);
+
+                    #[qinvokable]
+                    fn invokable(self: &MyObject, value: f64) -> Result<QString>;
+
+                    // Not invokable so not visible to QML
+                    fn rust_only(self: &MyObject, value: &mut String) -> Vec<u8>;
+                }
+            }
+        };
+        assert!(validate_module(module).is_ok());
+    }
+
+    #[test]
+    fn test_validate_not_qml_element() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(String, string)]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        assert!(validate_module(module).is_ok());
+    }
+
+    #[test]
+    fn test_validate_invalid_types() {
+        let invalid_modules: [ItemMod; 7] = [
+            parse_quote! {
+                #[cxx_qt::bridge]
+                mod ffi {
+                    unsafe extern "RustQt" {
+                        #[qobject]
+                        #[qml_element]
+                        #[qproperty(String, string)]
+                        type MyObject = super::MyObjectRust;
+                    }
+                }
+            },
+            parse_quote! {
+                #[cxx_qt::bridge]
+                mod ffi {
+                    unsafe extern "RustQt" {
+                        #[qobject]
+                        #[qml_element]
+                        #[qproperty(&'static QString, string)]
+                        type MyObject = super::MyObjectRust;
+                    }
+                }
+            },
+            parse_quote! {
+                #[cxx_qt::bridge]
+                mod ffi {
+                    unsafe extern "RustQt" {
+                        #[qobject]
+                        #[qml_element]
+                        type MyObject = super::MyObjectRust;
+
+                        #[qsignal]
+                        fn ready(self: Pin<&mut MyObject>, value: UniquePtr<QString>);
+                    }
+                }
+            },
+            parse_quote! {
+                #[cxx_qt::bridge]
+                mod ffi {
+                    unsafe extern "RustQt" {
+                        #[qobject]
+                        #[qml_element]
+                        type MyObject = super::MyObjectRust;
+
+                        #[qinvokable]
+                        fn invokable(self: &MyObject, value: &str);
+                    }
+                }
+            },
+            parse_quote! {
+                #[cxx_qt::bridge]
+                mod ffi {
+                    unsafe extern "RustQt" {
+                        #[qobject]
+                        #[qml_element]
+                        type MyObject = super::MyObjectRust;
+
+                        #[qinvokable]
+                        fn invokable(self: &MyObject, value: &mut QString);
+                    }
+                }
+            },
+            parse_quote! {
+                #[cxx_qt::bridge]
+                mod ffi {
+                    unsafe extern "RustQt" {
+                        #[qobject]
+                        #[qml_element]
+                        type MyObject = super::MyObjectRust;
+
+                        #[qinvokable]
+                        fn invokable(self: &MyObject) -> QList<usize>;
+                    }
+                }
+            },
+            parse_quote! {
+                #[cxx_qt::bridge]
+                mod ffi {
+                    unsafe extern "RustQt" {
+                        #[qobject]
+                        #[qml_element]
+                        type MyObject = super::MyObjectRust;
+
+                        #[qinvokable]
+                        fn invokable(self: &MyObject) -> Result<Vec<i32>>;
+                    }
+                }
+            },
+        ];
+
+        for module in invalid_modules {
+            assert!(validate_module(module).is_err());
+        }
+    }
+
+    #[test]
+    fn test_validate_arrays() {
+        // Arrays are generated as a std::array which QML elements cannot use
+        let qml_element_modules: [ItemMod; 3] = [
+            parse_quote! {
+                #[cxx_qt::bridge]
+                mod ffi {
+                    unsafe extern "RustQt" {
+                        #[qobject]
+                        #[qml_element]
+                        #[qproperty([f32; 4], color)]
+                        type MyObject = super::MyObjectRust;
+                    }
+                }
+            },
+            parse_quote! {
+                #[cxx_qt::bridge]
+                mod ffi {
+                    unsafe extern "RustQt" {
+                        #[qobject]
+                        #[qml_element]
+                        type MyObject = super::MyObjectRust;
+
+                        #[qsignal]
+                        fn frame(self: Pin<&mut MyObject>, samples: [i16; 64]);
+                    }
+                }
+            },
+            parse_quote! {
+                #[cxx_qt::bridge]
+                mod ffi {
+                    unsafe extern "RustQt" {
+                        #[qobject]
+                        #[qml_element]
+                        type MyObject = super::MyObjectRust;
+
+                        #[qinvokable]
+                        fn scale_color(self: &MyObject, color: [f32; 4], factor: f32) -> [f32; 4];
+                    }
+                }
+            },
+        ];
+        for module in qml_element_modules {
+            let error = validate_module(module).unwrap_err();
+            assert!(error.to_string().contains(
+                "fixed-size arrays can only be used by QObjects which are not QML elements"
+            ));
+        }
+
+        // Without qml_element, or when not visible to QML, arrays can be used
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    #[qproperty([[f32; 4]; 4], matrix)]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qsignal]
+                    fn frame(self: Pin<&mut MyObject>, samples: [i16; 64]);
+
+                    #[qinvokable]
+                    fn scale_color(self: &MyObject, color: [f32; 4], factor: f32) -> [f32; 4];
+                }
+
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    #[qml_element]
+                    type OtherObject = super::OtherObjectRust;
+
+                    #[qinvokable(access = "protected")]
+                    fn scale_color(self: &OtherObject, color: [f32; 4], factor: f32) -> [f32; 4];
+                }
+            }
+        };
+        assert!(validate_module(module).is_ok());
+    }
+}
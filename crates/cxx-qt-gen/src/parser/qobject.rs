@@ -5,22 +5,31 @@
 
 use crate::{
     parser::{
-        constructor::Constructor, inherit::ParsedInheritedMethod, method::ParsedMethod,
-        property::ParsedQProperty, qenum::ParsedQEnum, signals::ParsedSignal,
+        classinfo::ParsedQClassInfo, constructor::Constructor, inherit::ParsedInheritedMethod,
+        method::ParsedMethod, panic::ParsedQPanic, property::ParsedQProperty, qenum::ParsedQEnum,
+        revision::ParsedQRevision, signals::ParsedSignal,
     },
     syntax::{
         attribute::attribute_take_path, expr::expr_to_string, foreignmod::ForeignTypeIdentAlias,
         path::path_compare_str,
     },
 };
-use syn::{Attribute, Error, Ident, ItemImpl, Meta, Result};
+use syn::{Attribute, Error, Ident, ItemImpl, LitStr, Meta, Result};
 
 /// Metadata for registering QML element
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct QmlElementMetadata {
     pub name: String,
     pub uncreatable: bool,
+    /// The reason shown in QML errors when an uncreatable element is instantiated
+    pub uncreatable_reason: Option<String>,
     pub singleton: bool,
+    /// Whether the element is registered without a name, so it can only be used as the type of a property or return value
+    pub anonymous: bool,
+    /// The QML import version that the element was added in
+    pub added_in: Option<ParsedQRevision>,
+    /// The type that is created as the attached object for each object which uses the element's attached properties
+    pub attached: Option<Ident>,
 }
 
 /// A representation of a QObject within a CXX-Qt [syn::ItemMod]
@@ -53,10 +62,28 @@ pub struct ParsedQObject {
     pub properties: Vec<ParsedQProperty>,
     /// List of specifiers to register with in QML
     pub qml_metadata: Option<QmlElementMetadata>,
+    /// The property which nested QML objects are assigned to when no property is named
+    pub default_property: Option<Ident>,
+    /// Any class info entries of the meta-object
+    pub class_infos: Vec<ParsedQClassInfo>,
     /// Whether locking is enabled for this QObject
     pub locking: bool,
     /// Whether threading has been enabled for this QObject
     pub threading: bool,
+    /// Whether the Rust struct stores a context which is attached to this QObject once it is constructed
+    pub context: bool,
+    /// Whether weak references have been enabled for this QObject
+    pub weak_ref: bool,
+    /// Whether the factory for creating this QObject with a QML ownership has been enabled
+    pub qml_factory: bool,
+    /// Whether the QQmlParserStatus interface has been enabled for this QObject
+    pub qml_parser_status: bool,
+    /// Whether typed handles for connecting the signals, invokables and properties of this QObject have been enabled
+    pub connect: bool,
+    /// Whether the initialized hook is called once this QObject has been constructed
+    pub initialized: bool,
+    /// How panics in the invokables and property setters of this QObject are handled
+    pub panic: ParsedQPanic,
 }
 
 impl TryFrom<&ForeignTypeIdentAlias> for ParsedQObject {
@@ -69,6 +96,15 @@ impl TryFrom<&ForeignTypeIdentAlias> for ParsedQObject {
         // Find any QML metadata
         let qml_metadata = Self::parse_qml_metadata(&qobject_ty.ident_left, &mut qobject_ty.attrs)?;
 
+        // Find if there is a default property for nested QML objects
+        let default_property =
+            attribute_take_path(&mut qobject_ty.attrs, &["qml_default_property"])
+                .map(|attr| attr.parse_args::<Ident>())
+                .transpose()?;
+
+        // Find any class info entries
+        let class_infos = ParsedQClassInfo::take_all(&mut qobject_ty.attrs)?;
+
         // Find if there is any base class
         let base_class = attribute_take_path(&mut qobject_ty.attrs, &["base"])
             .map(|attr| expr_to_string(&attr.meta.require_name_value()?.value))
@@ -80,6 +116,9 @@ impl TryFrom<&ForeignTypeIdentAlias> for ParsedQObject {
             .transpose()?
             .unwrap_or_else(|| "".to_owned());
 
+        // Find how panics are handled
+        let panic = ParsedQPanic::take_from(&mut qobject_ty.attrs)?;
+
         // Parse any properties in the type
         // and remove the #[qproperty] attribute
         let properties = Self::parse_property_attributes(&mut qobject_ty.attrs)?;
@@ -95,8 +134,17 @@ impl TryFrom<&ForeignTypeIdentAlias> for ParsedQObject {
             constructors: vec![],
             properties,
             qml_metadata,
+            default_property,
+            class_infos,
             locking: true,
             threading: false,
+            context: false,
+            weak_ref: false,
+            qml_factory: false,
+            qml_parser_status: false,
+            connect: false,
+            initialized: false,
+            panic,
         })
     }
 }
@@ -106,24 +154,83 @@ impl ParsedQObject {
         qobject_ident: &Ident,
         attrs: &mut Vec<Attribute>,
     ) -> Result<Option<QmlElementMetadata>> {
+        // Find if there is a qml_anonymous attribute
+        let anonymous = attribute_take_path(attrs, &["qml_anonymous"]);
+
         // Find if there is a qml_element attribute
         if let Some(attr) = attribute_take_path(attrs, &["qml_element"]) {
+            // An anonymous element has no name
+            if let Some(anonymous) = anonymous {
+                return Err(Error::new_spanned(
+                    anonymous,
+                    "#[qml_anonymous] cannot be used together with #[qml_element]",
+                ));
+            }
+
             // Extract the name of the qml_element
             let name = match attr.meta {
                 Meta::NameValue(name_value) => expr_to_string(&name_value.value)?,
                 _ => qobject_ident.to_string(),
             };
 
-            // Determine if this element is uncreatable
-            let uncreatable = attribute_take_path(attrs, &["qml_uncreatable"]).is_some();
+            // Determine if this element is uncreatable and if there is a reason
+            let (uncreatable, uncreatable_reason) =
+                match attribute_take_path(attrs, &["qml_uncreatable"]).map(|attr| attr.meta) {
+                    Some(Meta::Path(_)) => (true, None),
+                    Some(Meta::List(list)) => (true, Some(list.parse_args::<LitStr>()?.value())),
+                    Some(meta) => {
+                        return Err(Error::new_spanned(
+                            meta,
+                            "Expected #[qml_uncreatable] or #[qml_uncreatable(\"reason\")]",
+                        ))
+                    }
+                    None => (false, None),
+                };
 
             // Determine if this element is a singleton
             let singleton = attribute_take_path(attrs, &["qml_singleton"]).is_some();
 
+            // Find the QML import version that this element was added in
+            let added_in = attribute_take_path(attrs, &["qml_added_in"])
+                .map(|attr| attr.parse_args_with(ParsedQRevision::parse_args))
+                .transpose()?;
+
+            // Find the type of the attached object of this element
+            let attached = attribute_take_path(attrs, &["qml_attached"])
+                .map(|attr| attr.parse_args::<Ident>())
+                .transpose()?;
+
             return Ok(Some(QmlElementMetadata {
                 name,
                 uncreatable,
+                uncreatable_reason,
                 singleton,
+                anonymous: false,
+                added_in,
+                attached,
+            }));
+        }
+
+        // The other QML attributes only have an effect on a QML element
+        for path in [
+            "qml_singleton",
+            "qml_uncreatable",
+            "qml_added_in",
+            "qml_attached",
+        ] {
+            if let Some(attr) = attribute_take_path(attrs, &[path]) {
+                return Err(Error::new_spanned(
+                    attr,
+                    format!("#[{path}] can only be used together with #[qml_element]"),
+                ));
+            }
+        }
+
+        if anonymous.is_some() {
+            return Ok(Some(QmlElementMetadata {
+                name: qobject_ident.to_string(),
+                anonymous: true,
+                ..Default::default()
             }));
         }
 
@@ -186,6 +293,66 @@ impl ParsedQObject {
 
             self.threading = true;
             Ok(())
+        } else if path_compare_str(trait_path, &["cxx_qt", "Context"]) {
+            if not.is_some() {
+                return Err(Error::new_spanned(
+                    trait_path,
+                    "Negative impls for cxx_qt::Context are not allowed",
+                ));
+            }
+
+            self.context = true;
+            Ok(())
+        } else if path_compare_str(trait_path, &["cxx_qt", "WeakRef"]) {
+            if not.is_some() {
+                return Err(Error::new_spanned(
+                    trait_path,
+                    "Negative impls for cxx_qt::WeakRef are not allowed",
+                ));
+            }
+
+            self.weak_ref = true;
+            Ok(())
+        } else if path_compare_str(trait_path, &["cxx_qt", "QmlFactory"]) {
+            if not.is_some() {
+                return Err(Error::new_spanned(
+                    trait_path,
+                    "Negative impls for cxx_qt::QmlFactory are not allowed",
+                ));
+            }
+
+            self.qml_factory = true;
+            Ok(())
+        } else if path_compare_str(trait_path, &["cxx_qt", "QmlParserStatus"]) {
+            if not.is_some() {
+                return Err(Error::new_spanned(
+                    trait_path,
+                    "Negative impls for cxx_qt::QmlParserStatus are not allowed",
+                ));
+            }
+
+            self.qml_parser_status = true;
+            Ok(())
+        } else if path_compare_str(trait_path, &["cxx_qt_lib", "Connect"]) {
+            if not.is_some() {
+                return Err(Error::new_spanned(
+                    trait_path,
+                    "Negative impls for cxx_qt_lib::Connect are not allowed",
+                ));
+            }
+
+            self.connect = true;
+            Ok(())
+        } else if path_compare_str(trait_path, &["cxx_qt", "Initialized"]) {
+            if not.is_some() {
+                return Err(Error::new_spanned(
+                    trait_path,
+                    "Negative impls for cxx_qt::Initialized are not allowed",
+                ));
+            }
+
+            self.initialized = true;
+            Ok(())
         } else if path_compare_str(trait_path, &["cxx_qt", "Constructor"]) {
             self.constructors.push(Constructor::parse(imp)?);
             Ok(())
@@ -193,7 +360,7 @@ impl ParsedQObject {
             // TODO: Give suggestions on which trait might have been meant
             Err(Error::new_spanned(
                 trait_path,
-                "Unsupported trait!\nCXX-Qt currently only supports:\n- cxx_qt::Threading\n- cxx_qt::Constructor\n- cxx_qt::Locking\nNote that the trait must always be fully-qualified."
+                "Unsupported trait!\nCXX-Qt currently only supports:\n- cxx_qt::Threading\n- cxx_qt::Context\n- cxx_qt::Constructor\n- cxx_qt::Initialized\n- cxx_qt::Locking\n- cxx_qt::WeakRef\n- cxx_qt::QmlFactory\n- cxx_qt::QmlParserStatus\n- cxx_qt_lib::Connect\nNote that the trait must always be fully-qualified."
             ))
         }
     }
@@ -218,6 +385,7 @@ pub mod tests {
     use super::*;
 
     use crate::parser::tests::f64_type;
+    use quote::format_ident;
     use syn::{parse_quote, ItemImpl};
 
     pub fn create_parsed_qobject() -> ParsedQObject {
@@ -252,6 +420,20 @@ pub mod tests {
         assert_eq!(qobject.base_class.as_ref().unwrap(), "QStringListModel");
     }
 
+    #[test]
+    fn test_from_struct_panic() {
+        let qobject = create_parsed_qobject();
+        assert_eq!(qobject.panic, ParsedQPanic::Abort);
+
+        let qobject_struct: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qpanic(signal)]
+            type MyObject = super::MyObjectRust;
+        };
+        let qobject = ParsedQObject::try_from(&qobject_struct).unwrap();
+        assert_eq!(qobject.panic, ParsedQPanic::Signal);
+    }
+
     #[test]
     fn test_from_struct_properties_and_fields() {
         let qobject_struct: ForeignTypeIdentAlias = parse_quote! {
@@ -285,6 +467,48 @@ pub mod tests {
         assert!(!qobject.threading);
         assert!(qobject.parse_trait_impl(item).is_ok());
         assert!(qobject.threading);
+
+        let item: ItemImpl = parse_quote! {
+            impl cxx_qt::WeakRef for MyObject {}
+        };
+        assert!(!qobject.weak_ref);
+        assert!(qobject.parse_trait_impl(item).is_ok());
+        assert!(qobject.weak_ref);
+
+        let item: ItemImpl = parse_quote! {
+            impl cxx_qt::QmlFactory for MyObject {}
+        };
+        assert!(!qobject.qml_factory);
+        assert!(qobject.parse_trait_impl(item).is_ok());
+        assert!(qobject.qml_factory);
+
+        let item: ItemImpl = parse_quote! {
+            impl cxx_qt::Context for MyObject {}
+        };
+        assert!(!qobject.context);
+        assert!(qobject.parse_trait_impl(item).is_ok());
+        assert!(qobject.context);
+
+        let item: ItemImpl = parse_quote! {
+            impl cxx_qt::Initialized for MyObject {}
+        };
+        assert!(!qobject.initialized);
+        assert!(qobject.parse_trait_impl(item).is_ok());
+        assert!(qobject.initialized);
+
+        let item: ItemImpl = parse_quote! {
+            impl cxx_qt::QmlParserStatus for MyObject {}
+        };
+        assert!(!qobject.qml_parser_status);
+        assert!(qobject.parse_trait_impl(item).is_ok());
+        assert!(qobject.qml_parser_status);
+
+        let item: ItemImpl = parse_quote! {
+            impl cxx_qt_lib::Connect for MyObject {}
+        };
+        assert!(!qobject.connect);
+        assert!(qobject.parse_trait_impl(item).is_ok());
+        assert!(qobject.connect);
     }
 
     #[test]
@@ -310,6 +534,42 @@ pub mod tests {
         };
         assert!(qobject.parse_trait_impl(item).is_err());
 
+        // WeakRef cannot be negative
+        let item: ItemImpl = parse_quote! {
+            impl !cxx_qt::WeakRef for T {}
+        };
+        assert!(qobject.parse_trait_impl(item).is_err());
+
+        // QmlFactory cannot be negative
+        let item: ItemImpl = parse_quote! {
+            impl !cxx_qt::QmlFactory for T {}
+        };
+        assert!(qobject.parse_trait_impl(item).is_err());
+
+        // Context cannot be negative
+        let item: ItemImpl = parse_quote! {
+            impl !cxx_qt::Context for T {}
+        };
+        assert!(qobject.parse_trait_impl(item).is_err());
+
+        // Initialized cannot be negative
+        let item: ItemImpl = parse_quote! {
+            impl !cxx_qt::Initialized for T {}
+        };
+        assert!(qobject.parse_trait_impl(item).is_err());
+
+        // QmlParserStatus cannot be negative
+        let item: ItemImpl = parse_quote! {
+            impl !cxx_qt::QmlParserStatus for T {}
+        };
+        assert!(qobject.parse_trait_impl(item).is_err());
+
+        // Connect cannot be negative
+        let item: ItemImpl = parse_quote! {
+            impl !cxx_qt_lib::Connect for T {}
+        };
+        assert!(qobject.parse_trait_impl(item).is_err());
+
         // must be a known trait
         let item: ItemImpl = parse_quote! {
             #[attr]
@@ -336,6 +596,38 @@ pub mod tests {
         assert_eq!(properties[1].ty, f64_type());
     }
 
+    #[test]
+    fn test_default_property() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_element]
+            #[qml_default_property(children)]
+            type MyObject = super::MyObjectRust;
+        };
+        let qobject = ParsedQObject::try_from(&item).unwrap();
+        assert_eq!(qobject.default_property, Some(format_ident!("children")));
+
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_default_property = "children"]
+            type MyObject = super::MyObjectRust;
+        };
+        assert!(ParsedQObject::try_from(&item).is_err());
+    }
+
+    #[test]
+    fn test_class_infos() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qclassinfo(key = "D-Bus Interface", value = "com.kdab.Example")]
+            type MyObject = super::MyObjectRust;
+        };
+        let qobject = ParsedQObject::try_from(&item).unwrap();
+        assert_eq!(qobject.class_infos.len(), 1);
+        assert_eq!(qobject.class_infos[0].key, "D-Bus Interface");
+        assert_eq!(qobject.class_infos[0].value, "com.kdab.Example");
+    }
+
     #[test]
     fn test_qml_metadata() {
         let item: ForeignTypeIdentAlias = parse_quote! {
@@ -349,7 +641,11 @@ pub mod tests {
             Some(QmlElementMetadata {
                 name: "MyObject".to_string(),
                 uncreatable: false,
+                uncreatable_reason: None,
                 singleton: false,
+                anonymous: false,
+                added_in: None,
+                attached: None,
             })
         );
     }
@@ -367,7 +663,11 @@ pub mod tests {
             Some(QmlElementMetadata {
                 name: "OtherName".to_string(),
                 uncreatable: false,
+                uncreatable_reason: None,
                 singleton: false,
+                anonymous: false,
+                added_in: None,
+                attached: None,
             })
         );
     }
@@ -386,11 +686,25 @@ pub mod tests {
             Some(QmlElementMetadata {
                 name: "MyObject".to_string(),
                 uncreatable: false,
+                uncreatable_reason: None,
                 singleton: true,
+                anonymous: false,
+                added_in: None,
+                attached: None,
             })
         );
     }
 
+    #[test]
+    fn test_qml_metadata_singleton_without_element() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_singleton]
+            type MyObject = super::MyObjectRust;
+        };
+        assert!(ParsedQObject::try_from(&item).is_err());
+    }
+
     #[test]
     fn test_qml_metadata_uncreatable() {
         let item: ForeignTypeIdentAlias = parse_quote! {
@@ -405,8 +719,149 @@ pub mod tests {
             Some(QmlElementMetadata {
                 name: "MyObject".to_string(),
                 uncreatable: true,
+                uncreatable_reason: None,
+                singleton: false,
+                anonymous: false,
+                added_in: None,
+                attached: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_qml_metadata_uncreatable_reason() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_element]
+            #[qml_uncreatable("Created by backend only")]
+            type MyObject = super::MyObjectRust;
+        };
+        let qobject = ParsedQObject::try_from(&item).unwrap();
+        assert_eq!(
+            qobject.qml_metadata,
+            Some(QmlElementMetadata {
+                name: "MyObject".to_string(),
+                uncreatable: true,
+                uncreatable_reason: Some("Created by backend only".to_string()),
+                singleton: false,
+                anonymous: false,
+                added_in: None,
+                attached: None,
+            })
+        );
+
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_element]
+            #[qml_uncreatable = "reason"]
+            type MyObject = super::MyObjectRust;
+        };
+        assert!(ParsedQObject::try_from(&item).is_err());
+    }
+
+    #[test]
+    fn test_qml_metadata_anonymous() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_anonymous]
+            type MyObject = super::MyObjectRust;
+        };
+        let qobject = ParsedQObject::try_from(&item).unwrap();
+        assert_eq!(
+            qobject.qml_metadata,
+            Some(QmlElementMetadata {
+                name: "MyObject".to_string(),
+                uncreatable: false,
+                uncreatable_reason: None,
                 singleton: false,
+                anonymous: true,
+                added_in: None,
+                attached: None,
             })
         );
+
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_element]
+            #[qml_anonymous]
+            type MyObject = super::MyObjectRust;
+        };
+        assert!(ParsedQObject::try_from(&item).is_err());
+    }
+
+    #[test]
+    fn test_qml_metadata_added_in() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_element]
+            #[qml_added_in(2, 1)]
+            type MyObject = super::MyObjectRust;
+        };
+        let qobject = ParsedQObject::try_from(&item).unwrap();
+        assert_eq!(
+            qobject.qml_metadata,
+            Some(QmlElementMetadata {
+                name: "MyObject".to_string(),
+                uncreatable: false,
+                uncreatable_reason: None,
+                singleton: false,
+                anonymous: false,
+                added_in: Some(ParsedQRevision { major: 2, minor: 1 }),
+                attached: None,
+            })
+        );
+
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_element]
+            #[qml_added_in(2)]
+            type MyObject = super::MyObjectRust;
+        };
+        assert!(ParsedQObject::try_from(&item).is_err());
+
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_added_in(2, 1)]
+            type MyObject = super::MyObjectRust;
+        };
+        assert!(ParsedQObject::try_from(&item).is_err());
+    }
+
+    #[test]
+    fn test_qml_metadata_attached() {
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_element]
+            #[qml_attached(MyAttached)]
+            type MyObject = super::MyObjectRust;
+        };
+        let qobject = ParsedQObject::try_from(&item).unwrap();
+        assert_eq!(
+            qobject.qml_metadata,
+            Some(QmlElementMetadata {
+                name: "MyObject".to_string(),
+                uncreatable: false,
+                uncreatable_reason: None,
+                singleton: false,
+                anonymous: false,
+                added_in: None,
+                attached: Some(format_ident!("MyAttached")),
+            })
+        );
+
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_element]
+            #[qml_attached = "MyAttached"]
+            type MyObject = super::MyObjectRust;
+        };
+        assert!(ParsedQObject::try_from(&item).is_err());
+
+        let item: ForeignTypeIdentAlias = parse_quote! {
+            #[qobject]
+            #[qml_attached(MyAttached)]
+            type MyObject = super::MyObjectRust;
+        };
+        assert!(ParsedQObject::try_from(&item).is_err());
     }
 }
@@ -0,0 +1,86 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::syntax::attribute::attribute_take_path;
+use syn::{parse::ParseStream, Attribute, LitInt, Result, Token};
+
+/// Describes the QML import version that a property, invokable, or signal was added in
+///
+/// This is declared as `#[qrevision(major, minor)]` and is generated as `Q_REVISION(major, minor)`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParsedQRevision {
+    /// The major version of the QML module
+    pub major: u8,
+    /// The minor version of the QML module
+    pub minor: u8,
+}
+
+impl ParsedQRevision {
+    /// Parse the `major, minor` arguments of a revision
+    pub fn parse_args(input: ParseStream) -> Result<Self> {
+        let major = input.parse::<LitInt>()?.base10_parse()?;
+        let _comma = input.parse::<Token![,]>()?;
+        let minor = input.parse::<LitInt>()?.base10_parse()?;
+        Ok(Self { major, minor })
+    }
+
+    /// Take any `#[qrevision(major, minor)]` attribute from the given attributes and parse it
+    pub fn take_from(attrs: &mut Vec<Attribute>) -> Result<Option<Self>> {
+        attribute_take_path(attrs, &["qrevision"])
+            .map(|attr| attr.parse_args_with(Self::parse_args))
+            .transpose()
+    }
+
+    /// The arguments of the revision as used by Q_REVISION and REVISION
+    pub fn to_cpp_args(self) -> String {
+        format!("{major}, {minor}", major = self.major, minor = self.minor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use syn::{parse_quote, ForeignItemFn};
+
+    #[test]
+    fn test_take_from() {
+        let mut method: ForeignItemFn = parse_quote! {
+            #[qrevision(2, 1)]
+            #[qinvokable]
+            fn invokable(self: &MyObject);
+        };
+        let revision = ParsedQRevision::take_from(&mut method.attrs).unwrap();
+        assert_eq!(revision, Some(ParsedQRevision { major: 2, minor: 1 }));
+        assert_eq!(revision.unwrap().to_cpp_args(), "2, 1");
+        assert_eq!(method.attrs.len(), 1);
+    }
+
+    #[test]
+    fn test_take_from_missing() {
+        let mut method: ForeignItemFn = parse_quote! {
+            #[qinvokable]
+            fn invokable(self: &MyObject);
+        };
+        assert!(ParsedQRevision::take_from(&mut method.attrs)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_take_from_invalid() {
+        let mut method: ForeignItemFn = parse_quote! {
+            #[qrevision(2)]
+            fn invokable(self: &MyObject);
+        };
+        assert!(ParsedQRevision::take_from(&mut method.attrs).is_err());
+
+        let mut method: ForeignItemFn = parse_quote! {
+            #[qrevision(2, 1000)]
+            fn invokable(self: &MyObject);
+        };
+        assert!(ParsedQRevision::take_from(&mut method.attrs).is_err());
+    }
+}
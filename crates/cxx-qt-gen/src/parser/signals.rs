@@ -3,7 +3,9 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::parser::parameter::ParsedFunctionParameter;
+use crate::parser::{
+    deprecated::ParsedQDeprecated, parameter::ParsedFunctionParameter, revision::ParsedQRevision,
+};
 use crate::syntax::attribute::{attribute_find_path, attribute_take_path};
 use crate::syntax::expr::expr_to_string;
 use crate::syntax::foreignmod;
@@ -31,6 +33,10 @@ pub struct ParsedSignal {
     pub inherit: bool,
     /// Whether the signal is private
     pub private: bool,
+    /// The QML revision that the signal was added in, if any
+    pub revision: Option<ParsedQRevision>,
+    /// Whether the signal is deprecated, and why
+    pub deprecated: Option<ParsedQDeprecated>,
 }
 
 impl ParsedSignal {
@@ -49,6 +55,8 @@ impl ParsedSignal {
             ident,
             inherit: false,
             private: false,
+            revision: None,
+            deprecated: None,
         }
     }
 
@@ -89,6 +97,24 @@ impl ParsedSignal {
         }
 
         let inherit = attribute_take_path(&mut method.attrs, &["inherit"]).is_some();
+
+        // Inherited signals are declared by the base class, so their revision cannot be changed
+        let revision = ParsedQRevision::take_from(&mut method.attrs)?;
+        if revision.is_some() && inherit {
+            return Err(Error::new(
+                method.span(),
+                "#[qrevision] cannot be used on #[inherit] signals",
+            ));
+        }
+
+        // Inherited signals are declared by the base class, so cannot be deprecated here
+        let deprecated = ParsedQDeprecated::take_from(&mut method.attrs)?;
+        if deprecated.is_some() && inherit {
+            return Err(Error::new(
+                method.span(),
+                "#[qdeprecated] cannot be used on #[inherit] signals",
+            ));
+        }
         let safe = method.sig.unsafety.is_none();
         let private = if let Visibility::Restricted(vis_restricted) = &method.vis {
             path_compare_str(&vis_restricted.path, &["self"])
@@ -105,6 +131,8 @@ impl ParsedSignal {
             safe,
             inherit,
             private,
+            revision,
+            deprecated,
         })
     }
 }
@@ -251,6 +279,58 @@ mod tests {
         assert!(signal.private);
     }
 
+    #[test]
+    fn test_parse_signal_revision() {
+        let method: ForeignItemFn = parse_quote! {
+            #[qrevision(2, 1)]
+            fn ready(self: Pin<&mut MyObject>);
+        };
+        let signal = ParsedSignal::parse(method, Safety::Safe).unwrap();
+
+        let expected_method: ForeignItemFn = parse_quote! {
+            fn ready(self: Pin<&mut MyObject>);
+        };
+        assert_eq!(signal.method, expected_method);
+        assert_eq!(
+            signal.revision,
+            Some(ParsedQRevision { major: 2, minor: 1 })
+        );
+        assert!(!signal.inherit);
+    }
+
+    #[test]
+    fn test_parse_signal_revision_inherit() {
+        let method: ForeignItemFn = parse_quote! {
+            #[inherit]
+            #[qrevision(2, 1)]
+            fn ready(self: Pin<&mut MyObject>);
+        };
+        assert!(ParsedSignal::parse(method, Safety::Safe).is_err());
+    }
+
+    #[test]
+    fn test_parse_signal_deprecated() {
+        let method: ForeignItemFn = parse_quote! {
+            #[qdeprecated("use ready_v2")]
+            fn ready(self: Pin<&mut MyObject>);
+        };
+        let signal = ParsedSignal::parse(method, Safety::Safe).unwrap();
+        assert!(signal.method.attrs.is_empty());
+        assert_eq!(
+            signal.deprecated,
+            Some(ParsedQDeprecated {
+                reason: Some("use ready_v2".to_owned())
+            })
+        );
+
+        let method: ForeignItemFn = parse_quote! {
+            #[inherit]
+            #[qdeprecated]
+            fn ready(self: Pin<&mut MyObject>);
+        };
+        assert!(ParsedSignal::parse(method, Safety::Safe).is_err());
+    }
+
     #[test]
     fn test_parse_signal_qobject_self_missing() {
         let method: ForeignItemFn = parse_quote! {
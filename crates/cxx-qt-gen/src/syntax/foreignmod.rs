@@ -3,13 +3,14 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+use crate::syntax::attribute::attribute_take_path;
 use proc_macro2::{TokenStream, TokenTree};
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote, ToTokens};
 use syn::{
     parse::{Parse, ParseStream, Parser},
     spanned::Spanned,
-    Attribute, Error, FnArg, ForeignItem, ForeignItemType, Ident, ItemForeignMod, Path, Receiver,
-    Result, Signature, Token, Visibility,
+    Attribute, Error, FnArg, ForeignItem, ForeignItemType, Ident, ItemForeignMod, Path,
+    PathArguments, Receiver, Result, Signature, Token, Type, Visibility,
 };
 
 /// For a given [syn::ForeignItem] return the [syn::ForeignItemType] if there is one
@@ -87,9 +88,42 @@ fn verbatim_to_foreign_type(tokens: &TokenStream) -> Result<Option<ForeignItemTy
     .parse2(tokens.clone())
 }
 
+/// For a given [syn::ForeignItem] type remove the attribute with the given path
+///
+/// Returns the attribute and the ident of the type, the syntax after the = in type A = ... is kept
+pub(crate) fn foreign_item_type_take_attribute(
+    foreign_item: &mut ForeignItem,
+    path: &[&str],
+) -> Result<Option<(Attribute, Ident)>> {
+    match foreign_item {
+        // type A;
+        ForeignItem::Type(foreign_type) => Ok(attribute_take_path(&mut foreign_type.attrs, path)
+            .map(|attr| (attr, foreign_type.ident.clone()))),
+        // type A = ...;
+        ForeignItem::Verbatim(tokens) => {
+            let ident = if let Some(foreign_type) = verbatim_to_foreign_type(tokens)? {
+                foreign_type.ident
+            } else {
+                return Ok(None);
+            };
+
+            let parse_attrs = |input: ParseStream| -> Result<(Vec<Attribute>, TokenStream)> {
+                Ok((input.call(Attribute::parse_outer)?, input.parse()?))
+            };
+            let (mut attrs, rest) = parse_attrs.parse2(tokens.clone())?;
+            let attr = attribute_take_path(&mut attrs, path);
+            if attr.is_some() {
+                *tokens = quote! { #(#attrs)* #rest };
+            }
+            Ok(attr.map(|attr| (attr, ident)))
+        }
+        _others => Ok(None),
+    }
+}
+
 /// Representation of a specific type alias for CXX-Qt where we map between two idents with a single super.
 ///
-/// `type A = super::B`
+/// `type A = super::B` or `type A = super::B<T>`
 #[derive(Clone)]
 pub struct ForeignTypeIdentAlias {
     /// Attributes on the alias
@@ -97,7 +131,11 @@ pub struct ForeignTypeIdentAlias {
     /// The left side of the alias
     pub ident_left: Ident,
     /// The right side of the alias
+    ///
+    /// When the right side is generic this is the name of an alias to the monomorphized type
     pub ident_right: Ident,
+    /// The monomorphized type of the right side, if it has generic arguments, eg `B<T>`
+    pub ty_right_generic: Option<Type>,
 }
 
 impl Parse for ForeignTypeIdentAlias {
@@ -142,6 +180,22 @@ impl Parse for ForeignTypeIdentAlias {
                 path.segments[1].ident.clone()
             };
 
+            // A generic right side, eg super::B<T>, is monomorphized with an alias named after
+            // the left side, as CXX only supports idents for extern "Rust" types
+            let (ident_right, ty_right_generic) = match &path.segments[1].arguments {
+                PathArguments::None => (ident_right, None),
+                PathArguments::AngleBracketed(arguments) => (
+                    format_ident!("{ident_left}Rust"),
+                    Some(syn::parse_quote! { #ident_right #arguments }),
+                ),
+                PathArguments::Parenthesized(_) => {
+                    return Err(Error::new(
+                        path.span(),
+                        "Type alias path must have angle bracketed generic arguments, super::T<A>",
+                    ));
+                }
+            };
+
             if ident_left == ident_right {
                 return Err(Error::new(
                     path.span(),
@@ -153,6 +207,7 @@ impl Parse for ForeignTypeIdentAlias {
                 attrs,
                 ident_left,
                 ident_right,
+                ty_right_generic,
             })
         } else {
             // Error as we have parsed the attributes and visiblity but have an unknown stream
@@ -231,6 +286,53 @@ mod tests {
         assert_eq!(result[1].ident, "B");
     }
 
+    #[test]
+    fn test_foreign_item_type_take_attribute() {
+        let foreign_mod: ItemForeignMod = parse_quote! {
+            extern "C++" {
+                #[qmetatype]
+                type A;
+                #[qmetatype]
+                #[cxx_name = "D"]
+                type B = crate::C;
+                fn function();
+            }
+        };
+        let mut items = foreign_mod.items;
+
+        let (_, ident) = foreign_item_type_take_attribute(&mut items[0], &["qmetatype"])
+            .unwrap()
+            .unwrap();
+        assert_eq!(ident, "A");
+        let expected: ForeignItem = parse_quote! { type A; };
+        assert_eq!(items[0], expected);
+
+        let (_, ident) = foreign_item_type_take_attribute(&mut items[1], &["qmetatype"])
+            .unwrap()
+            .unwrap();
+        assert_eq!(ident, "B");
+        let expected: ForeignItem = parse_quote! {
+            #[cxx_name = "D"]
+            type B = crate::C;
+        };
+        assert_eq!(
+            items[1].to_token_stream().to_string(),
+            expected.to_token_stream().to_string()
+        );
+
+        // The attribute has already been removed
+        assert!(
+            foreign_item_type_take_attribute(&mut items[1], &["qmetatype"])
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            foreign_item_type_take_attribute(&mut items[2], &["qmetatype"])
+                .unwrap()
+                .is_none()
+        );
+    }
+
     #[test]
     fn test_foreign_fn_self() {
         let foreign_fn: ForeignItemFn = parse_quote! {
@@ -276,6 +378,23 @@ mod tests {
         assert_eq!(alias.ident_right, "B");
     }
 
+    #[test]
+    fn test_foreign_type_ident_alias_generic() {
+        let alias = syn::parse2::<ForeignTypeIdentAlias>(quote! {
+            type A = super::B<i32>;
+        })
+        .unwrap();
+        assert_eq!(alias.ident_left, "A");
+        assert_eq!(alias.ident_right, "ARust");
+        let expected_ty: Type = syn::parse_quote! { B<i32> };
+        assert_eq!(alias.ty_right_generic, Some(expected_ty));
+
+        let parse = syn::parse2::<ForeignTypeIdentAlias>(quote! {
+            type A = super::B(i32);
+        });
+        assert!(parse.is_err());
+    }
+
     #[test]
     fn test_foreign_type_ident_alias_segments_one() {
         let parse = syn::parse2::<ForeignTypeIdentAlias>(quote! {
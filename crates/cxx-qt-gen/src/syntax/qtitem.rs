@@ -9,7 +9,9 @@ use quote::ToTokens;
 use syn::parse::{Parse, ParseStream};
 use syn::{Attribute, Item, ItemMod, Result, Token, Visibility};
 
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone)]
+// The comparison and Debug impls of syn types are only available with the extra-traits feature
+#[cfg_attr(any(test, feature = "extra-traits"), derive(PartialEq, Eq))]
 // This warning is triggered when running clippy on crates that depend on cxx-qt-gen,
 // but not when running clippy on cxx-qt-gen.
 pub enum CxxQtItem {
@@ -21,6 +23,7 @@ pub enum CxxQtItem {
     CxxQt(ItemMod),
 }
 
+#[cfg(any(test, feature = "extra-traits"))]
 impl std::fmt::Debug for CxxQtItem {
     fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
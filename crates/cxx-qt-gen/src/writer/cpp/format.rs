@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use clang_format::{clang_format_with_style, ClangFormatStyle};
+use std::{
+    io::Write,
+    path::PathBuf,
+    process::{Command, Stdio},
+};
+
+/// The style that the generated C++ code is formatted with by clang-format
+///
+/// The order of the includes follows the `IncludeCategories` and `SortIncludes` options of the style.
+/// If clang-format is not found, or fails with the style, the generated C++ code is left unformatted.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum CppFormatStyle {
+    /// Use the `.clang-format` file in the current directory or its parents, this is the default
+    #[default]
+    File,
+    /// Use the `.clang-format` file at the given path, this requires clang-format 14 or later
+    FilePath(PathBuf),
+    /// Use the given predefined style or options, such as `Mozilla` or `{BasedOnStyle: LLVM, IndentWidth: 4}`
+    Style(String),
+    /// Do not format the generated C++ code
+    None,
+}
+
+impl CppFormatStyle {
+    /// Format the given C++ code with this style
+    pub fn format(&self, cpp_code: String) -> String {
+        let style = match self {
+            Self::File => {
+                return clang_format_with_style(&cpp_code, &ClangFormatStyle::File)
+                    .unwrap_or(cpp_code)
+            }
+            Self::FilePath(path) => format!("file:{}", path.display()),
+            Self::Style(style) => style.clone(),
+            Self::None => return cpp_code,
+        };
+
+        clang_format_with_style_arg(&cpp_code, &style).unwrap_or(cpp_code)
+    }
+}
+
+/// Run clang-format with the given `--style` argument, or the binary in `CLANG_FORMAT_BINARY`
+fn clang_format_with_style_arg(cpp_code: &str, style: &str) -> Option<String> {
+    let binary = std::env::var("CLANG_FORMAT_BINARY").unwrap_or_else(|_| "clang-format".to_owned());
+    let mut child = Command::new(binary)
+        .arg(format!("--style={style}"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok()?;
+
+    // stdin is closed when it is dropped at the end of the block
+    {
+        let mut stdin = child.stdin.take()?;
+        stdin.write_all(cpp_code.as_bytes()).ok()?;
+    }
+
+    let output = child.wait_with_output().ok()?;
+    if output.status.success() {
+        String::from_utf8(output.stdout).ok()
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_none() {
+        let cpp_code = "struct  Test  {};\n".to_owned();
+        assert_eq!(CppFormatStyle::None.format(cpp_code.clone()), cpp_code);
+    }
+
+    #[test]
+    fn test_format_invalid_file() {
+        // When clang-format fails, or cannot be found, the code is left as it is
+        let cpp_code = "struct  Test  {};\n".to_owned();
+        assert_eq!(
+            CppFormatStyle::FilePath(PathBuf::from("/does/not/exist/.clang-format"))
+                .format(cpp_code.clone()),
+            cpp_code
+        );
+    }
+}
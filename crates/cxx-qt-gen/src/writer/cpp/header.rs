@@ -74,7 +74,7 @@ fn qobjects_header(generated: &GeneratedCppBlocks) -> Vec<String> {
 
                   virtual ~{ident}() = default;
 
-                {public_methods}
+                {public_methods}{protected_methods}
                 {private_methods}
                 }};
 
@@ -83,6 +83,7 @@ fn qobjects_header(generated: &GeneratedCppBlocks) -> Vec<String> {
             base_classes = qobject.blocks.base_classes.iter().map(|base| format!("public {}", base)).collect::<Vec<String>>().join(", "),
             metaobjects = qobject.blocks.metaobjects.join("\n  "),
             public_methods = create_block("public", &qobject.blocks.methods.iter().filter_map(pair_as_header).collect::<Vec<String>>()),
+            protected_methods = create_block("protected", &qobject.blocks.protected_methods.iter().filter_map(pair_as_header).collect::<Vec<String>>()),
             private_methods = create_block("private", &qobject.blocks.private_methods.iter().filter_map(pair_as_header).collect::<Vec<String>>()),
         });
 
@@ -109,16 +110,21 @@ pub fn write_cpp_header(generated: &GeneratedCppBlocks) -> String {
         {includes}
 
         {forward_declare}
-        #include "cxx-qt-gen/{cxx_file_stem}.cxx.h"
+        #include "{include_prefix}/{cxx_file_stem}.cxx.h"
 
         {extern_cxx_qt}
         {qobjects}
     "#,
     cxx_file_stem = generated.cxx_file_stem,
+    include_prefix = generated.include_prefix,
     forward_declare = forward_declare(generated).join("\n"),
     qobjects = qobjects_header(generated).join("\n"),
     extern_cxx_qt = {
-        let mut out = vec![];
+        let mut out = generated
+            .qmetatypes
+            .iter()
+            .filter_map(pair_as_header)
+            .collect::<Vec<String>>();
         for block in &generated.extern_cxx_qt {
             if let Some(method) = pair_as_header(&block.method) {
                 out.push(namespaced(&block.namespace, &method));
@@ -191,4 +197,21 @@ mod tests {
         let output = write_cpp_header(&generated);
         assert_str_eq!(output, expected_header_no_namespace());
     }
+
+    #[test]
+    fn test_write_cpp_header_qmetatypes() {
+        let mut generated = create_generated_cpp();
+        generated.qmetatypes.push(CppFragment::Pair {
+            header: "Q_DECLARE_METATYPE(::company::Money)".to_owned(),
+            source: String::new(),
+        });
+        let output = write_cpp_header(&generated);
+
+        // The type is declared after the CXX header which includes the third-party header
+        let include = output
+            .find("#include \"cxx-qt-gen/cxx_file_stem.cxx.h\"")
+            .unwrap();
+        let metatype = output.find("Q_DECLARE_METATYPE(::company::Money)").unwrap();
+        assert!(include < metatype);
+    }
 }
@@ -3,11 +3,12 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+pub mod format;
 pub mod header;
 pub mod source;
 
 use crate::generator::cpp::{fragment::CppFragment, GeneratedCppBlocks};
-use clang_format::{clang_format_with_style, ClangFormatStyle};
+use format::CppFormatStyle;
 use header::write_cpp_header;
 use indoc::formatdoc;
 use source::write_cpp_source;
@@ -27,12 +28,14 @@ pub fn namespaced(namespace: &str, cpp_code: &str) -> String {
 
 /// For a given GeneratedCppBlocks write this into a C++ header and source pair
 pub fn write_cpp(generated: &GeneratedCppBlocks) -> CppFragment {
-    let header = write_cpp_header(generated);
-    let source = write_cpp_source(generated);
+    write_cpp_with_style(generated, &CppFormatStyle::default())
+}
 
+/// For a given GeneratedCppBlocks write this into a C++ header and source pair formatted with the given style
+pub fn write_cpp_with_style(generated: &GeneratedCppBlocks, style: &CppFormatStyle) -> CppFragment {
     CppFragment::Pair {
-        header: clang_format_with_style(&header, &ClangFormatStyle::File).unwrap_or(header),
-        source: clang_format_with_style(&source, &ClangFormatStyle::File).unwrap_or(source),
+        header: style.format(write_cpp_header(generated)),
+        source: style.format(write_cpp_source(generated)),
     }
 }
 
@@ -55,7 +58,9 @@ mod tests {
             forward_declares: vec![],
             includes: BTreeSet::default(),
             cxx_file_stem: "cxx_file_stem".to_owned(),
+            include_prefix: "cxx-qt-gen".to_owned(),
             extern_cxx_qt: vec![],
+            qmetatypes: vec![],
             qobjects: vec![
                 GeneratedCppQObject {
                     ident: "MyObject".to_owned(),
@@ -149,6 +154,7 @@ mod tests {
                                 "Q_SIGNAL void toggleChanged();".to_owned(),
                             ),
                         ],
+                        protected_methods: vec![],
                         private_methods: vec![CppFragment::Pair{
                                 header: "void privateMethod() const;".to_owned(),
                                 source: indoc! {r#"
@@ -178,7 +184,9 @@ mod tests {
             forward_declares: vec![],
             includes: BTreeSet::default(),
             cxx_file_stem: "cxx_file_stem".to_owned(),
+            include_prefix: "cxx-qt-gen".to_owned(),
             extern_cxx_qt: vec![],
+            qmetatypes: vec![],
             qobjects: vec![
                 GeneratedCppQObject {
                     ident: "FirstObject".to_owned(),
@@ -221,6 +229,7 @@ mod tests {
                         },
                         CppFragment::Header("Q_SIGNAL void countChanged();".to_owned()),
                         ],
+                        protected_methods: vec![],
                         private_methods: vec![],
                     }
                 },
@@ -265,6 +274,7 @@ mod tests {
                         },
                         CppFragment::Header("Q_SIGNAL void countChanged();".to_owned()),
                         ],
+                        protected_methods: vec![],
                         private_methods: vec![
                             CppFragment::Pair{
                                 header: "void privateMethod() const;".to_owned(),
@@ -26,6 +26,7 @@ fn qobjects_source(generated: &GeneratedCppBlocks) -> Vec<String> {
                 .blocks
                 .methods
                 .iter()
+                .chain(qobject.blocks.protected_methods.iter())
                 .chain(qobject.blocks.private_methods.iter())
                 .filter_map(pair_as_source)
                 .collect::<Vec<String>>()
@@ -38,14 +39,19 @@ fn qobjects_source(generated: &GeneratedCppBlocks) -> Vec<String> {
 /// For a given GeneratedCppBlocks write this into a C++ source
 pub fn write_cpp_source(generated: &GeneratedCppBlocks) -> String {
     formatdoc! {r#"
-        #include "cxx-qt-gen/{cxx_file_stem}.cxxqt.h"
+        #include "{include_prefix}/{cxx_file_stem}.cxxqt.h"
 
         {extern_cxx_qt}
         {qobjects}
     "#,
     cxx_file_stem = generated.cxx_file_stem,
+    include_prefix = generated.include_prefix,
     extern_cxx_qt = {
-        let mut out = vec![];
+        let mut out = generated
+            .qmetatypes
+            .iter()
+            .filter_map(pair_as_source)
+            .collect::<Vec<String>>();
         for block in &generated.extern_cxx_qt {
             if let Some(method) = pair_as_source(&block.method) {
                 out.push(namespaced(&block.namespace, &method));
@@ -3,17 +3,19 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+mod stub;
+
 use crate::generator::rust::GeneratedRustBlocks;
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
+use syn::{Item, ItemMod, Result};
 
-/// For a given GeneratedRustBlocks write this into a Rust TokenStream
-pub fn write_rust(generated: &GeneratedRustBlocks) -> TokenStream {
+/// Build the CXX bridge module and the items outside of it from the GeneratedRustBlocks
+fn assemble_rust(generated: &GeneratedRustBlocks) -> (ItemMod, Vec<Item>) {
     // Retrieve the module contents and namespace
     let mut cxx_mod = generated.cxx_mod.clone();
     let mut cxx_mod_contents = generated.cxx_mod_contents.clone();
     let mut cxx_qt_mod_contents = generated.cxx_qt_mod_contents.clone();
-    let namespace = &generated.namespace;
 
     // Add common includes for all objects
     cxx_mod_contents.insert(
@@ -59,6 +61,14 @@ pub fn write_rust(generated: &GeneratedRustBlocks) -> TokenStream {
         cxx_mod.content = Some((syn::token::Brace::default(), cxx_mod_contents));
     }
 
+    (cxx_mod, cxx_qt_mod_contents)
+}
+
+/// For a given GeneratedRustBlocks write this into a Rust TokenStream
+pub fn write_rust(generated: &GeneratedRustBlocks) -> TokenStream {
+    let (cxx_mod, cxx_qt_mod_contents) = assemble_rust(generated);
+    let namespace = &generated.namespace;
+
     quote! {
         #[cxx::bridge(namespace = #namespace)]
         #cxx_mod
@@ -68,6 +78,22 @@ pub fn write_rust(generated: &GeneratedRustBlocks) -> TokenStream {
     .into_token_stream()
 }
 
+/// For a given GeneratedRustBlocks write simplified stubs into a Rust TokenStream
+///
+/// The stubs have the same Rust API as [write_rust] but the CXX bridge is replaced by plain Rust
+/// items whose function bodies are unreachable. This is intended for IDE tooling such as
+/// rust-analyzer, as the result is quick to expand but cannot be linked.
+pub fn write_rust_stub(generated: &GeneratedRustBlocks) -> Result<TokenStream> {
+    let (cxx_mod, cxx_qt_mod_contents) = assemble_rust(generated);
+    let cxx_mod = stub::stub_cxx_bridge(cxx_mod)?;
+
+    Ok(quote! {
+        #cxx_mod
+
+        #(#cxx_qt_mod_contents)*
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
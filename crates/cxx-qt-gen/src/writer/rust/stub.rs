@@ -0,0 +1,461 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Converts a CXX bridge module into plain Rust stubs.
+//!
+//! The stubs have the same Rust API as the CXX bridge, but every function body is unreachable.
+//! This avoids the expansion of the CXX bridge, which is useful for tools such as rust-analyzer
+//! that only need the types and signatures, but the result cannot be linked.
+
+use crate::syntax::{
+    attribute::attribute_take_path, expr::expr_to_string, path::path_compare_str,
+    types::extract_qobject_ident,
+};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::collections::{BTreeMap, BTreeSet};
+use syn::{
+    parse_quote, Attribute, Expr, FnArg, ForeignItem, ForeignItemFn, Ident, Item, ItemEnum,
+    ItemForeignMod, ItemMod, ItemStruct, ItemType, Meta, Result, Token, Visibility,
+};
+
+/// Attributes which only have a meaning to CXX and are removed from the stubs
+const CXX_ATTRIBUTES: [&str; 4] = ["cxx_name", "namespace", "rust_name", "Self"];
+
+/// Remove any attributes which only have a meaning to CXX
+fn strip_cxx_attributes(attrs: &mut Vec<Attribute>) {
+    attrs.retain(|attr| {
+        !CXX_ATTRIBUTES
+            .iter()
+            .any(|name| path_compare_str(attr.path(), &[name]))
+    });
+}
+
+/// Find the Rust name of an item, using any rust_name attribute
+fn take_rust_name(attrs: &mut Vec<Attribute>, ident: &Ident) -> Result<Ident> {
+    attribute_take_path(attrs, &["rust_name"])
+        .map(|attr| {
+            let name = expr_to_string(&attr.meta.require_name_value()?.value)?;
+            Ok(format_ident!("{name}"))
+        })
+        .unwrap_or_else(|| Ok(ident.clone()))
+}
+
+/// The abi of the extern block, eg "C++" or "Rust"
+fn foreign_mod_abi(foreign_mod: &ItemForeignMod) -> Option<String> {
+    foreign_mod.abi.name.as_ref().map(|name| name.value())
+}
+
+/// The stubs that are generated from the items of a CXX bridge
+#[derive(Default)]
+struct Stubs {
+    /// Idents of shared enums, these can also be declared as extern C++ types
+    shared_enums: BTreeSet<Ident>,
+    /// Idents of opaque C++ types, methods can only be implemented on these
+    opaque_types: BTreeSet<Ident>,
+    /// Methods for each opaque C++ type
+    methods: BTreeMap<Ident, Vec<TokenStream>>,
+    /// Any other items for the module
+    items: Vec<TokenStream>,
+}
+
+impl Stubs {
+    fn stub_extern_cxx(&mut self, foreign_mod: ItemForeignMod) -> Result<()> {
+        // Functions in an extern "C++" block without unsafe are unsafe to call
+        let block_safe = foreign_mod.unsafety.is_some();
+
+        for item in foreign_mod.items {
+            match item {
+                ForeignItem::Type(mut foreign_type) => {
+                    let ident = take_rust_name(&mut foreign_type.attrs, &foreign_type.ident)?;
+                    // A shared enum declared as extern is defined by the enum itself
+                    if self.shared_enums.contains(&ident) {
+                        continue;
+                    }
+                    strip_cxx_attributes(&mut foreign_type.attrs);
+                    let attrs = &foreign_type.attrs;
+                    self.items.push(quote! {
+                        #(#attrs)*
+                        #[repr(C)]
+                        pub struct #ident {
+                            _private: [u8; 0],
+                            _pinned: core::marker::PhantomPinned,
+                        }
+                    });
+                    self.opaque_types.insert(ident);
+                }
+                ForeignItem::Verbatim(tokens) => {
+                    // An alias to a type from another bridge, type A = path::B;
+                    let mut item_type: ItemType = syn::parse2(tokens)?;
+                    item_type.ident = take_rust_name(&mut item_type.attrs, &item_type.ident)?;
+                    strip_cxx_attributes(&mut item_type.attrs);
+                    item_type.vis = parse_quote! { pub };
+                    self.items.push(quote! { #item_type });
+                }
+                ForeignItem::Fn(foreign_fn) => self.stub_extern_cxx_fn(foreign_fn, block_safe)?,
+                // include! has no meaning without C++
+                ForeignItem::Macro(_) => {}
+                _others => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn stub_extern_cxx_fn(
+        &mut self,
+        mut foreign_fn: ForeignItemFn,
+        block_safe: bool,
+    ) -> Result<()> {
+        foreign_fn.sig.ident = take_rust_name(&mut foreign_fn.attrs, &foreign_fn.sig.ident)?;
+        strip_cxx_attributes(&mut foreign_fn.attrs);
+        if !block_safe && foreign_fn.sig.unsafety.is_none() {
+            foreign_fn.sig.unsafety = Some(Token![unsafe](foreign_fn.sig.ident.span()));
+        }
+
+        let attrs = &foreign_fn.attrs;
+        let sig = &foreign_fn.sig;
+        let stub = quote! {
+            #(#attrs)*
+            #[allow(unused_variables)]
+            pub #sig {
+                unreachable!("cxx-qt stubs cannot be called")
+            }
+        };
+
+        // Methods are placed in an impl of their self type, if the type is from another
+        // crate then an impl is not possible so the method is skipped
+        if let Some(FnArg::Receiver(receiver)) = sig.inputs.first() {
+            if let Ok((ident, _)) = extract_qobject_ident(&receiver.ty) {
+                self.methods.entry(ident).or_default().push(stub);
+            }
+        } else {
+            self.items.push(stub);
+        }
+
+        Ok(())
+    }
+
+    fn stub_extern_rust(&mut self, foreign_mod: ItemForeignMod) {
+        // Functions of an extern "Rust" block are implemented by the developer
+        // so only the types need to be brought into scope
+        for item in foreign_mod.items {
+            if let ForeignItem::Type(foreign_type) = item {
+                let ident = &foreign_type.ident;
+                self.items.push(quote! {
+                    #[allow(unused_imports)]
+                    use super::#ident;
+                });
+            }
+        }
+    }
+
+    fn stub_shared_struct(&mut self, mut item_struct: ItemStruct) {
+        strip_cxx_attributes(&mut item_struct.attrs);
+        item_struct.vis = parse_quote! { pub };
+        for field in item_struct.fields.iter_mut() {
+            strip_cxx_attributes(&mut field.attrs);
+            field.vis = parse_quote! { pub };
+        }
+        self.items.push(quote! { #item_struct });
+    }
+
+    fn stub_shared_enum(&mut self, mut item_enum: ItemEnum) -> Result<()> {
+        strip_cxx_attributes(&mut item_enum.attrs);
+
+        // CXX represents shared enums as a struct with a repr field and associated constants
+        let repr = attribute_take_path(&mut item_enum.attrs, &["repr"])
+            .map(|attr| attr.parse_args::<Ident>())
+            .transpose()?
+            .unwrap_or_else(|| format_ident!("i32"));
+
+        // CXX always derives these traits, so remove them from any user derives
+        let mut derives = vec![];
+        while let Some(attr) = attribute_take_path(&mut item_enum.attrs, &["derive"]) {
+            if let Meta::List(list) = &attr.meta {
+                let paths = list.parse_args_with(
+                    syn::punctuated::Punctuated::<syn::Path, Token![,]>::parse_terminated,
+                )?;
+                derives.extend(paths.into_iter().filter(|path| {
+                    !["Clone", "Copy", "Eq", "PartialEq"]
+                        .iter()
+                        .any(|name| path.is_ident(name))
+                }));
+            }
+        }
+
+        // Each variant is either the explicit discriminant or the previous one plus one
+        let mut discriminant: Expr = parse_quote! { 0 };
+        let mut offset = 0_usize;
+        let variants = item_enum
+            .variants
+            .iter()
+            .map(|variant| {
+                if let Some((_, expr)) = &variant.discriminant {
+                    discriminant = expr.clone();
+                    offset = 0;
+                }
+                let ident = &variant.ident;
+                let attrs = &variant.attrs;
+                let offset_lit = syn::LitInt::new(&offset.to_string(), ident.span());
+                offset += 1;
+                quote! {
+                    #(#attrs)*
+                    pub const #ident: Self = Self { repr: (#discriminant) + #offset_lit };
+                }
+            })
+            .collect::<Vec<TokenStream>>();
+
+        let attrs = &item_enum.attrs;
+        let ident = &item_enum.ident;
+        self.items.push(quote! {
+            #(#attrs)*
+            #[derive(Copy, Clone, PartialEq, Eq, #(#derives),*)]
+            #[repr(transparent)]
+            pub struct #ident {
+                pub repr: #repr,
+            }
+
+            #[allow(non_upper_case_globals)]
+            impl #ident {
+                #(#variants)*
+            }
+        });
+
+        Ok(())
+    }
+}
+
+/// Convert the items of a CXX bridge module into stubs which have the same Rust API
+pub fn stub_cxx_bridge(module: ItemMod) -> Result<TokenStream> {
+    let mut stubs = Stubs::default();
+
+    if let Some((_, items)) = module.content {
+        stubs.shared_enums = items
+            .iter()
+            .filter_map(|item| match item {
+                Item::Enum(item_enum) => Some(item_enum.ident.clone()),
+                _others => None,
+            })
+            .collect();
+
+        for item in items {
+            match item {
+                Item::ForeignMod(foreign_mod) => match foreign_mod_abi(&foreign_mod).as_deref() {
+                    Some("C++") => stubs.stub_extern_cxx(foreign_mod)?,
+                    Some("Rust") => stubs.stub_extern_rust(foreign_mod),
+                    _others => {}
+                },
+                Item::Struct(item_struct) => stubs.stub_shared_struct(item_struct),
+                Item::Enum(item_enum) => stubs.stub_shared_enum(item_enum)?,
+                // impl UniquePtr<T> {} etc only instruct CXX to generate C++ code
+                Item::Impl(_) => {}
+                others => stubs.items.push(quote! { #others }),
+            }
+        }
+    }
+
+    let methods = stubs
+        .methods
+        .iter()
+        // Methods can only be implemented on types which are defined in this bridge
+        .filter(|(ident, _)| stubs.opaque_types.contains(*ident))
+        .map(|(ident, methods)| {
+            quote! {
+                impl #ident {
+                    #(#methods)*
+                }
+            }
+        });
+    let items = &stubs.items;
+    let attrs = &module.attrs;
+    let vis: &Visibility = &module.vis;
+    let ident = &module.ident;
+
+    Ok(quote! {
+        #(#attrs)*
+        #[allow(dead_code, unused_imports, clippy::all)]
+        #vis mod #ident {
+            use core::pin::Pin;
+            use cxx::{CxxString, CxxVector, SharedPtr, UniquePtr, WeakPtr};
+
+            // CXX uses Result<T> to mean a result with a C++ exception
+            type Result<T> = core::result::Result<T, cxx::Exception>;
+
+            #(#items)*
+            #(#methods)*
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::assert_tokens_eq;
+
+    #[test]
+    fn test_stub_extern_cxx() {
+        let module: ItemMod = parse_quote! {
+            mod ffi {
+                unsafe extern "C++" {
+                    include!("cxx-qt-lib/qstring.h");
+                    #[rust_name = "CxxQString"]
+                    type QString = cxx_qt_lib::QString;
+
+                    /// My object
+                    #[namespace = "my_namespace"]
+                    type MyObject;
+
+                    #[rust_name = "get_value"]
+                    fn getValue(self: &MyObject) -> i32;
+
+                    #[cxx_name = "setValue"]
+                    fn set_value(self: Pin<&mut MyObject>, value: i32);
+
+                    fn is_empty(self: &QString) -> bool;
+
+                    fn create() -> UniquePtr<MyObject>;
+                }
+
+                extern "C++" {
+                    fn unsafe_create() -> UniquePtr<MyObject>;
+                }
+
+                impl UniquePtr<MyObject> {}
+            }
+        };
+
+        assert_tokens_eq(
+            &stub_cxx_bridge(module).unwrap(),
+            quote! {
+                #[allow(dead_code, unused_imports, clippy::all)]
+                mod ffi {
+                    use core::pin::Pin;
+                    use cxx::{CxxString, CxxVector, SharedPtr, UniquePtr, WeakPtr};
+
+                    type Result<T> = core::result::Result<T, cxx::Exception>;
+
+                    pub type CxxQString = cxx_qt_lib::QString;
+
+                    /// My object
+                    #[repr(C)]
+                    pub struct MyObject {
+                        _private: [u8; 0],
+                        _pinned: core::marker::PhantomPinned,
+                    }
+
+                    #[allow(unused_variables)]
+                    pub fn create() -> UniquePtr<MyObject> {
+                        unreachable!("cxx-qt stubs cannot be called")
+                    }
+
+                    #[allow(unused_variables)]
+                    pub unsafe fn unsafe_create() -> UniquePtr<MyObject> {
+                        unreachable!("cxx-qt stubs cannot be called")
+                    }
+
+                    impl MyObject {
+                        #[allow(unused_variables)]
+                        pub fn get_value(self: &MyObject) -> i32 {
+                            unreachable!("cxx-qt stubs cannot be called")
+                        }
+
+                        #[allow(unused_variables)]
+                        pub fn set_value(self: Pin<&mut MyObject>, value: i32) {
+                            unreachable!("cxx-qt stubs cannot be called")
+                        }
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_stub_extern_rust() {
+        let module: ItemMod = parse_quote! {
+            pub mod ffi {
+                extern "Rust" {
+                    type MyObjectRust;
+
+                    fn invokable(self: &MyObject);
+                }
+            }
+        };
+
+        assert_tokens_eq(
+            &stub_cxx_bridge(module).unwrap(),
+            quote! {
+                #[allow(dead_code, unused_imports, clippy::all)]
+                pub mod ffi {
+                    use core::pin::Pin;
+                    use cxx::{CxxString, CxxVector, SharedPtr, UniquePtr, WeakPtr};
+
+                    type Result<T> = core::result::Result<T, cxx::Exception>;
+
+                    #[allow(unused_imports)]
+                    use super::MyObjectRust;
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_stub_shared_types() {
+        let module: ItemMod = parse_quote! {
+            mod ffi {
+                #[namespace = "my_namespace"]
+                struct Point {
+                    #[cxx_name = "xPos"]
+                    x: i32,
+                    y: i32,
+                }
+
+                #[derive(Debug, PartialEq)]
+                #[repr(u8)]
+                enum Color {
+                    Red,
+                    Green = 5,
+                    Blue,
+                }
+                extern "C++" {
+                    #[namespace = "my_namespace"]
+                    type Color;
+                }
+            }
+        };
+
+        assert_tokens_eq(
+            &stub_cxx_bridge(module).unwrap(),
+            quote! {
+                #[allow(dead_code, unused_imports, clippy::all)]
+                mod ffi {
+                    use core::pin::Pin;
+                    use cxx::{CxxString, CxxVector, SharedPtr, UniquePtr, WeakPtr};
+
+                    type Result<T> = core::result::Result<T, cxx::Exception>;
+
+                    pub struct Point {
+                        pub x: i32,
+                        pub y: i32,
+                    }
+
+                    #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+                    #[repr(transparent)]
+                    pub struct Color {
+                        pub repr: u8,
+                    }
+
+                    #[allow(non_upper_case_globals)]
+                    impl Color {
+                        pub const Red: Self = Self { repr: (0) + 0 };
+                        pub const Green: Self = Self { repr: (5) + 0 };
+                        pub const Blue: Self = Self { repr: (5) + 1 };
+                    }
+                }
+            },
+        );
+    }
+}
@@ -0,0 +1,11 @@
+// Items which are merged into a bridge using #[cxx_qt::bridge(extend = "test_inputs/extend/invokables.rs")]
+
+unsafe extern "RustQt" {
+    #[qinvokable]
+    fn extended_invokable(self: &MyObject);
+}
+
+unsafe extern "RustQt" {
+    #[qsignal]
+    fn extended_signal(self: Pin<&mut MyObject>);
+}
@@ -15,8 +15,10 @@ MyObject::hasChildren(QModelIndex const& _parent) const
 }
 
 MyObject::MyObject(QObject* parent)
-  : QAbstractItemModel(parent)
+  : QAbstractItemModel(
+      ::rust::cxxqtlib1::constructorParent<QAbstractItemModel>(parent))
   , ::rust::cxxqtlib1::CxxQtType<MyObjectRust>(::cxx_qt_my_object::createRs())
   , ::rust::cxxqtlib1::CxxQtLocking()
 {
+  ::rust::cxxqtlib1::setConstructorParent<QAbstractItemModel>(*this, parent);
 }
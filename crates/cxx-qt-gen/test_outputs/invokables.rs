@@ -118,6 +118,10 @@ mod ffi {
         #[namespace = "rust::cxxqtlib1"]
         #[cxx_name = "cxxQtThreadDrop"]
         fn cxx_qt_ffi_my_object_threading_drop(cxx_qt_thread: &mut MyObjectCxxQtThread);
+        #[doc(hidden)]
+        #[namespace = "rust::cxxqtlib1"]
+        #[cxx_name = "cxxQtThreadIsDestroyed"]
+        fn cxx_qt_ffi_my_object_is_destroyed(cxx_qt_thread: &MyObjectCxxQtThread) -> bool;
     }
     extern "Rust" {
         #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
@@ -257,6 +261,10 @@ impl cxx_qt::Threading for ffi::MyObject {
     fn threading_drop(cxx_qt_thread: &mut ffi::MyObjectCxxQtThread) {
         ffi::cxx_qt_ffi_my_object_threading_drop(cxx_qt_thread);
     }
+    #[doc(hidden)]
+    fn is_destroyed(cxx_qt_thread: &ffi::MyObjectCxxQtThread) -> bool {
+        ffi::cxx_qt_ffi_my_object_is_destroyed(cxx_qt_thread)
+    }
 }
 #[doc(hidden)]
 pub struct MyObjectCxxQtThreadQueuedFn {
@@ -114,11 +114,13 @@ MyObject::readyConnect(::rust::Fn<void(MyObject&)> func,
 }
 
 MyObject::MyObject(QObject* parent)
-  : QStringListModel(parent)
+  : QStringListModel(
+      ::rust::cxxqtlib1::constructorParent<QStringListModel>(parent))
   , ::rust::cxxqtlib1::CxxQtType<MyObjectRust>(
       ::cxx_qt::multi_object::cxx_qt_my_object::createRs())
   , ::rust::cxxqtlib1::CxxQtLocking()
 {
+  ::rust::cxxqtlib1::setConstructorParent<QStringListModel>(*this, parent);
 }
 
 } // namespace cxx_qt::multi_object
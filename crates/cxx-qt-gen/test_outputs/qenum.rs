@@ -86,6 +86,30 @@ mod ffi {
         fn cxx_qt_ffi_rust_mut(self: Pin<&mut MyObject>) -> Pin<&mut MyObjectRust>;
     }
 }
+impl ffi::MyNamespacedEnum {
+    #[doc = r" The variants of the QEnum in the order they are declared"]
+    pub const VARIANTS: [Self; 3] = [Self::A, Self::B, Self::C];
+    #[doc = r" The names of the variants of the QEnum as seen from QML, in the same order as VARIANTS"]
+    pub const NAMES: [&str; 3] = ["A", "B", "C"];
+}
+impl ffi::MyOtherNamespacedEnum {
+    #[doc = r" The variants of the QEnum in the order they are declared"]
+    pub const VARIANTS: [Self; 2] = [Self::Variant1, Self::Variant2];
+    #[doc = r" The names of the variants of the QEnum as seen from QML, in the same order as VARIANTS"]
+    pub const NAMES: [&str; 2] = ["Variant1", "Variant2"];
+}
+impl ffi::MyEnum {
+    #[doc = r" The variants of the QEnum in the order they are declared"]
+    pub const VARIANTS: [Self; 1] = [Self::A];
+    #[doc = r" The names of the variants of the QEnum as seen from QML, in the same order as VARIANTS"]
+    pub const NAMES: [&str; 1] = ["A"];
+}
+impl ffi::MyOtherEnum {
+    #[doc = r" The variants of the QEnum in the order they are declared"]
+    pub const VARIANTS: [Self; 3] = [Self::X, Self::Y, Self::Z];
+    #[doc = r" The names of the variants of the QEnum as seen from QML, in the same order as VARIANTS"]
+    pub const NAMES: [&str; 3] = ["X", "Y", "Z"];
+}
 impl cxx_qt::Locking for ffi::MyObject {}
 #[doc(hidden)]
 pub fn create_rs_my_object_rust() -> std::boxed::Box<MyObjectRust> {
@@ -0,0 +1,25 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_build::CxxQtBuilder;
+
+fn main() {
+    CxxQtBuilder::new()
+        .file("src/cxxqtutils.rs")
+        .file("src/lineplotitem.rs")
+        .cc_builder(|cc| {
+            cc.include("include");
+            cc.file("src/cxxqtutils.cpp");
+            cc.file("src/lineplotitem.cpp");
+        })
+        // The scene graph and QQuickItem are in Qt Quick
+        .qt_module("Quick")
+        .build();
+
+    println!("cargo:rerun-if-changed=include/cxx-qt-lib-extras/cxxqtutils.h");
+    println!("cargo:rerun-if-changed=src/cxxqtutils.cpp");
+    println!("cargo:rerun-if-changed=include/cxx-qt-lib-extras/lineplotitem.h");
+    println!("cargo:rerun-if-changed=src/lineplotitem.cpp");
+}
@@ -0,0 +1,23 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QString>
+
+namespace rust {
+namespace cxxqtlibextras1 {
+
+// The decimal point of the default locale, which is a QChar in Qt 5
+QString
+cxxqtutilsDecimalPoint();
+
+// Register the CxxQtUtils singleton in the com.kdab.cxx_qt.extras module
+void
+cxxqtutilsRegisterQmlType();
+
+}
+}
@@ -0,0 +1,49 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+
+#include <QtGui/QColor>
+#include <QtQuick/QQuickItem>
+#include <QtQuick/QSGGeometryNode>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlibextras1 {
+
+// Nested types cannot be named from a cxx bridge, so alias them here
+using QQuickItemUpdatePaintNodeData = QQuickItem::UpdatePaintNodeData;
+
+// Returns a geometry node which draws a line strip with the given number
+// of vertices, reusing the node from the previous frame if there is one
+QSGGeometryNode*
+lineplotitemGeometryNode(QSGNode* oldNode,
+                         ::std::int32_t vertexCount,
+                         const QColor& color,
+                         float lineWidth);
+
+// The vertices of the geometry as pairs of x and y, which are written in Rust
+::rust::Slice<float>
+lineplotitemVertices(QSGGeometryNode& node);
+
+// Register the QQuickItems of this crate in the com.kdab.cxx_qt.extras module
+void
+lineplotitemRegisterQmlType();
+
+// The item type is only complete in the generated bridge,
+// so this is a template which is instantiated there
+template<typename T>
+void
+lineplotitemSetHasContents(T& item)
+{
+  item.setFlag(QQuickItem::ItemHasContents, true);
+}
+
+}
+}
@@ -0,0 +1,37 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/cxxqtutils.h"
+
+#include <QtCore/QLocale>
+#include <QtQml/QQmlEngine>
+
+#include "cxx-qt-gen/cxxqtutils.cxxqt.h"
+
+namespace rust {
+namespace cxxqtlibextras1 {
+
+QString
+cxxqtutilsDecimalPoint()
+{
+  return QString(QLocale().decimalPoint());
+}
+
+void
+cxxqtutilsRegisterQmlType()
+{
+  // The engine takes ownership of the singleton that is returned
+  qmlRegisterSingletonType<CxxQtUtils>(
+    "com.kdab.cxx_qt.extras",
+    1,
+    0,
+    "CxxQtUtils",
+    [](QQmlEngine*, QJSEngine*) -> QObject* { return new CxxQtUtils(); });
+}
+
+}
+}
@@ -0,0 +1,187 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! A QML singleton which exposes formatting and parsing helpers implemented in Rust.
+
+/// A CXX-Qt bridge which defines the CxxQtUtils QObject
+#[cxx_qt::bridge(cxx_file_stem = "cxxqtutils")]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        /// QString from cxx_qt_lib
+        type QString = cxx_qt_lib::QString;
+    }
+
+    #[namespace = "rust::cxxqtlibextras1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/cxxqtutils.h");
+
+        #[rust_name = "cxxqtutils_decimal_point"]
+        fn cxxqtutilsDecimalPoint() -> QString;
+
+        #[rust_name = "cxxqtutils_register_qml_type"]
+        fn cxxqtutilsRegisterQmlType();
+    }
+
+    unsafe extern "RustQt" {
+        /// A QObject which is registered as a QML singleton, so that QML does not need
+        /// to reimplement these formatting and parsing helpers in JS.
+        ///
+        /// Numbers use the decimal point of the default locale.
+        #[qobject]
+        type CxxQtUtils = super::CxxQtUtilsRust;
+
+        /// Format a size in bytes as a human readable string with the given number of decimals, eg `1.5 MiB`
+        #[qinvokable]
+        #[cxx_name = "formatBytes"]
+        fn format_bytes(self: &CxxQtUtils, bytes: i64, precision: i32) -> QString;
+
+        /// Format a duration in milliseconds as hours, minutes, and seconds, eg `1:02:03`
+        #[qinvokable]
+        #[cxx_name = "formatDuration"]
+        fn format_duration(self: &CxxQtUtils, msecs: i64) -> QString;
+
+        /// Parse a human readable size such as `1.5 MiB` or `20 kB` into bytes, or `-1` if it is invalid
+        #[qinvokable]
+        #[cxx_name = "parseBytes"]
+        fn parse_bytes(self: &CxxQtUtils, text: &QString) -> i64;
+    }
+}
+
+use cxx_qt_lib::QString;
+
+/// Register the CxxQtUtils singleton in the `com.kdab.cxx_qt.extras` QML module,
+/// this is optional and should be called before the QML is loaded.
+///
+/// ```qml
+/// import com.kdab.cxx_qt.extras 1.0
+///
+/// Label {
+///     text: CxxQtUtils.formatBytes(download.received, 1)
+/// }
+/// ```
+pub fn register_utils_singleton() {
+    qobject::cxxqtutils_register_qml_type();
+}
+
+/// The Rust struct for the CxxQtUtils
+#[derive(Default)]
+pub struct CxxQtUtilsRust;
+
+impl qobject::CxxQtUtils {
+    /// Format a size in bytes as a human readable string with the given number of decimals
+    pub fn format_bytes(&self, bytes: i64, precision: i32) -> QString {
+        let decimal_point = String::from(&qobject::cxxqtutils_decimal_point());
+        QString::from(&format_bytes(
+            bytes,
+            precision.max(0) as usize,
+            &decimal_point,
+        ))
+    }
+
+    /// Format a duration in milliseconds as hours, minutes, and seconds
+    pub fn format_duration(&self, msecs: i64) -> QString {
+        QString::from(&format_duration(msecs))
+    }
+
+    /// Parse a human readable size into bytes, or -1 if it is invalid
+    pub fn parse_bytes(&self, text: &QString) -> i64 {
+        let decimal_point = String::from(&qobject::cxxqtutils_decimal_point());
+        parse_bytes(&String::from(text), &decimal_point).unwrap_or(-1)
+    }
+}
+
+/// The units for byte sizes which are a power of 1024
+const BINARY_UNITS: [&str; 7] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+/// The units for byte sizes which are a power of 1000
+const DECIMAL_UNITS: [&str; 7] = ["B", "kB", "MB", "GB", "TB", "PB", "EB"];
+
+fn format_bytes(bytes: i64, precision: usize, decimal_point: &str) -> String {
+    let mut value = bytes.unsigned_abs() as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < BINARY_UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    // A whole number of bytes has no fractional part
+    let precision = if unit == 0 { 0 } else { precision };
+    let sign = if bytes < 0 { "-" } else { "" };
+    let number = format!("{value:.precision$}").replace('.', decimal_point);
+    format!("{sign}{number} {}", BINARY_UNITS[unit])
+}
+
+fn format_duration(msecs: i64) -> String {
+    let sign = if msecs < 0 { "-" } else { "" };
+    let secs = msecs.unsigned_abs() / 1000;
+    let (hours, minutes, seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+    if hours > 0 {
+        format!("{sign}{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{sign}{minutes}:{seconds:02}")
+    }
+}
+
+fn parse_bytes(text: &str, decimal_point: &str) -> Option<i64> {
+    let text = text.trim();
+    let (number, unit) = text.split_at(text.find(char::is_alphabetic).unwrap_or(text.len()));
+    let number: f64 = number.trim().replace(decimal_point, ".").parse().ok()?;
+
+    // Units are matched ignoring case, so "KB" and "kb" are 1000 bytes but "KiB" is 1024 bytes
+    let unit = unit.trim();
+    let unit = if unit.is_empty() { "B" } else { unit };
+    let multiplier = BINARY_UNITS
+        .iter()
+        .position(|binary| binary.eq_ignore_ascii_case(unit))
+        .map(|index| 1024_f64.powi(index as i32))
+        .or_else(|| {
+            DECIMAL_UNITS
+                .iter()
+                .position(|decimal| decimal.eq_ignore_ascii_case(unit))
+                .map(|index| 1000_f64.powi(index as i32))
+        })?;
+
+    let bytes = (number * multiplier).round();
+    if (0.0..=i64::MAX as f64).contains(&bytes) {
+        Some(bytes as i64)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytes_to_human_readable() {
+        assert_eq!(format_bytes(0, 1, "."), "0 B");
+        assert_eq!(format_bytes(1023, 1, "."), "1023 B");
+        assert_eq!(format_bytes(1536, 1, "."), "1.5 KiB");
+        assert_eq!(format_bytes(1536, 1, ","), "1,5 KiB");
+        assert_eq!(format_bytes(5 * 1024 * 1024, 0, "."), "5 MiB");
+        assert_eq!(format_bytes(-2048, 2, "."), "-2.00 KiB");
+    }
+
+    #[test]
+    fn duration_to_string() {
+        assert_eq!(format_duration(0), "0:00");
+        assert_eq!(format_duration(61_500), "1:01");
+        assert_eq!(format_duration(3_723_000), "1:02:03");
+        assert_eq!(format_duration(-5_000), "-0:05");
+    }
+
+    #[test]
+    fn human_readable_to_bytes() {
+        assert_eq!(parse_bytes("512", "."), Some(512));
+        assert_eq!(parse_bytes("1.5 KiB", "."), Some(1536));
+        assert_eq!(parse_bytes("1,5 kib", ","), Some(1536));
+        assert_eq!(parse_bytes("20kB", "."), Some(20_000));
+        assert_eq!(parse_bytes("2 MB", "."), Some(2_000_000));
+        assert_eq!(parse_bytes("1 parsec", "."), None);
+        assert_eq!(parse_bytes("MiB", "."), None);
+        assert_eq!(parse_bytes("-1 B", "."), None);
+    }
+}
@@ -0,0 +1,13 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Ready-made QObjects and QQuickItems which are written in Rust with CXX-Qt,
+//! for common needs which are not covered by the Qt types in `cxx-qt-lib`.
+
+mod cxxqtutils;
+mod lineplotitem;
+
+pub use cxxqtutils::{qobject::CxxQtUtils, register_utils_singleton, CxxQtUtilsRust};
+pub use lineplotitem::{qobject::LinePlotItem, register_qml_types, LinePlotItemRust};
@@ -0,0 +1,71 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/lineplotitem.h"
+
+#include <QtQml/QQmlEngine>
+#include <QtQuick/QSGFlatColorMaterial>
+
+#include "cxx-qt-gen/lineplotitem.cxxqt.h"
+
+namespace rust {
+namespace cxxqtlibextras1 {
+
+QSGGeometryNode*
+lineplotitemGeometryNode(QSGNode* oldNode,
+                         ::std::int32_t vertexCount,
+                         const QColor& color,
+                         float lineWidth)
+{
+  auto* node = static_cast<QSGGeometryNode*>(oldNode);
+  if (node == nullptr) {
+    auto* geometry =
+      new QSGGeometry(QSGGeometry::defaultAttributes_Point2D(), vertexCount);
+    geometry->setDrawingMode(QSGGeometry::DrawLineStrip);
+
+    node = new QSGGeometryNode;
+    node->setGeometry(geometry);
+    node->setMaterial(new QSGFlatColorMaterial);
+    node->setFlags(QSGNode::OwnsGeometry | QSGNode::OwnsMaterial);
+  }
+
+  auto* geometry = node->geometry();
+  if (geometry->vertexCount() != vertexCount) {
+    geometry->allocate(vertexCount);
+  }
+  geometry->setLineWidth(lineWidth);
+
+  auto* material = static_cast<QSGFlatColorMaterial*>(node->material());
+  if (material->color() != color) {
+    material->setColor(color);
+    node->markDirty(QSGNode::DirtyMaterial);
+  }
+
+  // The vertices are always rewritten by Rust after this
+  node->markDirty(QSGNode::DirtyGeometry);
+  return node;
+}
+
+::rust::Slice<float>
+lineplotitemVertices(QSGGeometryNode& node)
+{
+  auto* geometry = node.geometry();
+  static_assert(sizeof(QSGGeometry::Point2D) == 2 * sizeof(float));
+  return ::rust::Slice<float>(
+    reinterpret_cast<float*>(geometry->vertexDataAsPoint2D()),
+    static_cast<::std::size_t>(geometry->vertexCount()) * 2);
+}
+
+void
+lineplotitemRegisterQmlType()
+{
+  qmlRegisterType<LinePlotItem>(
+    "com.kdab.cxx_qt.extras", 1, 0, "LinePlotItem");
+}
+
+}
+}
@@ -0,0 +1,275 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! A QQuickItem which plots a line through a large number of points with the Qt Quick scene graph.
+
+/// A CXX-Qt bridge which defines the LinePlotItem QQuickItem
+#[cxx_qt::bridge(cxx_file_stem = "lineplotitem")]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qcolor.h");
+        /// QColor from cxx_qt_lib
+        type QColor = cxx_qt_lib::QColor;
+
+        include!("cxx-qt-lib/qsizef.h");
+        /// QSizeF from cxx_qt_lib
+        type QSizeF = cxx_qt_lib::QSizeF;
+    }
+
+    // Define the API from the Qt Quick scene graph that we need
+    unsafe extern "C++" {
+        include!(<QtQuick/QQuickItem>);
+        include!("cxx-qt-lib-extras/lineplotitem.h");
+
+        /// QSGNode from Qt
+        type QSGNode;
+        /// QSGGeometryNode from Qt
+        type QSGGeometryNode;
+
+        /// QQuickItem::UpdatePaintNodeData from Qt
+        #[namespace = "rust::cxxqtlibextras1"]
+        type QQuickItemUpdatePaintNodeData;
+    }
+
+    #[namespace = "rust::cxxqtlibextras1"]
+    unsafe extern "C++" {
+        #[rust_name = "lineplotitem_geometry_node"]
+        unsafe fn lineplotitemGeometryNode(
+            old_node: *mut QSGNode,
+            vertex_count: i32,
+            color: &QColor,
+            line_width: f32,
+        ) -> *mut QSGGeometryNode;
+
+        #[rust_name = "lineplotitem_register_qml_type"]
+        fn lineplotitemRegisterQmlType();
+
+        #[rust_name = "lineplotitem_set_has_contents"]
+        fn lineplotitemSetHasContents(item: Pin<&mut LinePlotItem>);
+
+        #[rust_name = "lineplotitem_vertices"]
+        fn lineplotitemVertices(node: Pin<&mut QSGGeometryNode>) -> &mut [f32];
+    }
+
+    unsafe extern "RustQt" {
+        /// A QQuickItem which draws a line through the points given from Rust,
+        /// fitting the bounds of the points to the size of the item.
+        ///
+        /// The points are written straight into the vertices of scene graph geometry,
+        /// so millions of points can be plotted without Qt Charts.
+        #[qobject]
+        #[base = "QQuickItem"]
+        #[qproperty(QColor, color)]
+        #[qproperty(f32, line_width)]
+        type LinePlotItem = super::LinePlotItemRust;
+
+        /// Override QQuickItem::updatePaintNode to write the points into the geometry of the node
+        #[cxx_name = "updatePaintNode"]
+        #[cxx_override]
+        unsafe fn update_paint_node(
+            self: Pin<&mut LinePlotItem>,
+            old_node: *mut QSGNode,
+            data: *mut QQuickItemUpdatePaintNodeData,
+        ) -> *mut QSGNode;
+
+        /// Inherit size() from QQuickItem
+        #[inherit]
+        fn size(self: &LinePlotItem) -> QSizeF;
+
+        /// Inherit update() from QQuickItem
+        #[inherit]
+        fn update(self: Pin<&mut LinePlotItem>);
+    }
+
+    impl cxx_qt::Constructor<()> for LinePlotItem {}
+}
+
+use core::pin::Pin;
+use cxx_qt::CxxQtType;
+use cxx_qt_lib::{QColor, QPointF, QSizeF};
+
+/// Register the QQuickItems of this crate as types in the `com.kdab.cxx_qt.extras` QML module,
+/// this should be called before the QML is loaded.
+///
+/// ```qml
+/// import com.kdab.cxx_qt.extras 1.0
+///
+/// LinePlotItem {
+///     color: "steelblue"
+///     lineWidth: 1
+/// }
+/// ```
+pub fn register_qml_types() {
+    qobject::lineplotitem_register_qml_type();
+}
+
+/// The Rust struct for the LinePlotItem
+pub struct LinePlotItemRust {
+    color: QColor,
+    line_width: f32,
+    points: Vec<QPointF>,
+    bounds: PlotBounds,
+}
+
+impl Default for LinePlotItemRust {
+    fn default() -> Self {
+        Self {
+            color: QColor::from_rgb(0, 0, 0),
+            line_width: 1.0,
+            points: vec![],
+            bounds: PlotBounds::default(),
+        }
+    }
+}
+
+impl qobject::LinePlotItem {
+    /// The points which are plotted
+    pub fn points(&self) -> &[QPointF] {
+        &self.rust().points
+    }
+
+    /// Replace the points which are plotted and schedule a repaint
+    ///
+    /// The points are taken by value so that large plots are not copied.
+    pub fn set_points(mut self: Pin<&mut Self>, points: Vec<QPointF>) {
+        let bounds = PlotBounds::from_points(&points);
+        let mut rust = self.as_mut().rust_mut();
+        rust.points = points;
+        rust.bounds = bounds;
+        self.update();
+    }
+
+    /// Override QQuickItem::updatePaintNode to write the points into the geometry of the node
+    ///
+    /// # Safety
+    ///
+    /// This is called by the scene graph with the node returned from the previous frame, or null
+    pub unsafe fn update_paint_node(
+        self: Pin<&mut Self>,
+        old_node: *mut qobject::QSGNode,
+        _data: *mut qobject::QQuickItemUpdatePaintNodeData,
+    ) -> *mut qobject::QSGNode {
+        let rust = self.rust();
+        let vertex_count = i32::try_from(rust.points.len()).unwrap_or(i32::MAX);
+        let node = qobject::lineplotitem_geometry_node(
+            old_node,
+            vertex_count,
+            &rust.color,
+            rust.line_width,
+        );
+
+        if let Some(node) = node.as_mut() {
+            let vertices = qobject::lineplotitem_vertices(Pin::new_unchecked(node));
+            let size = self.size();
+            for (vertex, point) in vertices.chunks_exact_mut(2).zip(&rust.points) {
+                let (x, y) = rust.bounds.map(point, &size);
+                vertex[0] = x;
+                vertex[1] = y;
+            }
+        }
+
+        node.cast()
+    }
+}
+
+impl cxx_qt::Initialize for qobject::LinePlotItem {
+    fn initialize(mut self: Pin<&mut Self>) {
+        // Without this flag updatePaintNode is never called
+        qobject::lineplotitem_set_has_contents(self.as_mut());
+
+        self.as_mut()
+            .on_color_changed(|qobject| qobject.update())
+            .release();
+        self.on_line_width_changed(|qobject| qobject.update())
+            .release();
+    }
+}
+
+/// The area covered by the points, which is fitted to the size of the item
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct PlotBounds {
+    x_min: f64,
+    x_max: f64,
+    y_min: f64,
+    y_max: f64,
+}
+
+impl PlotBounds {
+    fn from_points(points: &[QPointF]) -> Self {
+        match points.split_first() {
+            Some((first, rest)) => rest.iter().fold(
+                Self {
+                    x_min: first.x(),
+                    x_max: first.x(),
+                    y_min: first.y(),
+                    y_max: first.y(),
+                },
+                |bounds, point| Self {
+                    x_min: bounds.x_min.min(point.x()),
+                    x_max: bounds.x_max.max(point.x()),
+                    y_min: bounds.y_min.min(point.y()),
+                    y_max: bounds.y_max.max(point.y()),
+                },
+            ),
+            None => Self::default(),
+        }
+    }
+
+    /// Map a point to item coordinates, where y increases downwards
+    fn map(&self, point: &QPointF, size: &QSizeF) -> (f32, f32) {
+        // A line which is flat in either direction is drawn in the middle of the item
+        let fit = |value: f64, min: f64, max: f64| {
+            if max > min {
+                (value - min) / (max - min)
+            } else {
+                0.5
+            }
+        };
+
+        let x = fit(point.x(), self.x_min, self.x_max) * size.width();
+        let y = (1.0 - fit(point.y(), self.y_min, self.y_max)) * size.height();
+        (x as f32, y as f32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds_from_points() {
+        let points = vec![
+            QPointF::new(1.0, -2.0),
+            QPointF::new(-3.0, 4.0),
+            QPointF::new(5.0, 0.0),
+        ];
+        assert_eq!(
+            PlotBounds::from_points(&points),
+            PlotBounds {
+                x_min: -3.0,
+                x_max: 5.0,
+                y_min: -2.0,
+                y_max: 4.0,
+            }
+        );
+        assert_eq!(PlotBounds::from_points(&[]), PlotBounds::default());
+    }
+
+    #[test]
+    fn bounds_map_to_item() {
+        let bounds = PlotBounds {
+            x_min: 0.0,
+            x_max: 10.0,
+            y_min: 0.0,
+            y_max: 100.0,
+        };
+        let size = QSizeF::new(200.0, 50.0);
+        assert_eq!(bounds.map(&QPointF::new(0.0, 0.0), &size), (0.0, 50.0));
+        assert_eq!(bounds.map(&QPointF::new(5.0, 100.0), &size), (100.0, 0.0));
+
+        let flat = PlotBounds::from_points(&[QPointF::new(1.0, 1.0)]);
+        assert_eq!(flat.map(&QPointF::new(1.0, 1.0), &size), (100.0, 25.0));
+    }
+}
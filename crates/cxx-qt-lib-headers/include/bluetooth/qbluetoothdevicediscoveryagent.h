@@ -0,0 +1,79 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_BLUETOOTH_FEATURE
+
+#include <memory>
+
+#include <QtBluetooth/QBluetoothDeviceDiscoveryAgent>
+#include <QtCore/QtGlobal>
+
+#include "rust/cxx.h"
+
+#include "cxx-qt-lib/common.h"
+#include "cxx-qt-lib/qbluetoothdeviceinfo.h"
+#include "cxx-qt-lib/qmetaobjectconnection.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QBluetoothDeviceDiscoveryAgent>
+qbluetoothdevicediscoveryagentNew();
+void
+qbluetoothdevicediscoveryagentStart(QBluetoothDeviceDiscoveryAgent& agent,
+                                    bool lowEnergyOnly);
+
+template<typename T>
+QMetaObjectConnection
+qbluetoothdevicediscoveryagentOnDeviceDiscovered(
+  QBluetoothDeviceDiscoveryAgent& agent,
+  ::rust::Box<T> callback)
+{
+  auto shared = shareCallback(::std::move(callback));
+  return QObject::connect(
+    &agent,
+    &QBluetoothDeviceDiscoveryAgent::deviceDiscovered,
+    &agent,
+    [shared](const QBluetoothDeviceInfo& info) { (*shared)->call(info); });
+}
+
+template<typename T>
+QMetaObjectConnection
+qbluetoothdevicediscoveryagentOnErrorOccurred(
+  QBluetoothDeviceDiscoveryAgent& agent,
+  ::rust::Box<T> callback)
+{
+  auto shared = shareCallback(::std::move(callback));
+#if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+  const auto signal = &QBluetoothDeviceDiscoveryAgent::errorOccurred;
+#else
+  const auto signal = QOverload<QBluetoothDeviceDiscoveryAgent::Error>::of(
+    &QBluetoothDeviceDiscoveryAgent::error);
+#endif
+  return QObject::connect(
+    &agent, signal, &agent, [shared, agent = &agent]() {
+      (*shared)->call(agent->errorString());
+    });
+}
+
+template<typename T>
+QMetaObjectConnection
+qbluetoothdevicediscoveryagentOnFinished(QBluetoothDeviceDiscoveryAgent& agent,
+                                         ::rust::Box<T> callback)
+{
+  auto shared = shareCallback(::std::move(callback));
+  return QObject::connect(&agent,
+                          &QBluetoothDeviceDiscoveryAgent::finished,
+                          &agent,
+                          [shared]() { (*shared)->call(); });
+}
+
+}
+}
+
+#endif
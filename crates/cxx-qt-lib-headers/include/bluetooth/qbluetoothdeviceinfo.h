@@ -0,0 +1,43 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_BLUETOOTH_FEATURE
+
+#include <cstdint>
+#include <memory>
+
+#include <QtBluetooth/QBluetoothDeviceInfo>
+#include <QtBluetooth/QBluetoothUuid>
+#include <QtCore/QString>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QString
+qbluetoothdeviceinfoAddress(const QBluetoothDeviceInfo& info);
+::std::unique_ptr<QBluetoothDeviceInfo>
+qbluetoothdeviceinfoClone(const QBluetoothDeviceInfo& info);
+QString
+qbluetoothdeviceinfoDeviceUuid(const QBluetoothDeviceInfo& info);
+bool
+qbluetoothdeviceinfoIsLowEnergy(const QBluetoothDeviceInfo& info);
+::std::int16_t
+qbluetoothdeviceinfoRssi(const QBluetoothDeviceInfo& info);
+
+// Bluetooth UUIDs are exchanged with Rust as strings without braces
+QBluetoothUuid
+qbluetoothuuidFromQString(const QString& uuid);
+QString
+qbluetoothuuidToQString(const QBluetoothUuid& uuid);
+
+}
+}
+
+#endif
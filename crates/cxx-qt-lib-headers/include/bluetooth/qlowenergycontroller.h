@@ -0,0 +1,98 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_BLUETOOTH_FEATURE
+
+#include <memory>
+
+#include <QtBluetooth/QLowEnergyController>
+#include <QtBluetooth/QLowEnergyService>
+#include <QtCore/QStringList>
+#include <QtCore/QtGlobal>
+
+#include "rust/cxx.h"
+
+#include "cxx-qt-lib/common.h"
+#include "cxx-qt-lib/qbluetoothdeviceinfo.h"
+#include "cxx-qt-lib/qmetaobjectconnection.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QLowEnergyControllerControllerState =
+  QLowEnergyController::ControllerState;
+
+::std::unique_ptr<QLowEnergyController>
+qlowenergycontrollerNewCentral(const QBluetoothDeviceInfo& device);
+::std::unique_ptr<QLowEnergyService>
+qlowenergycontrollerCreateService(QLowEnergyController& controller,
+                                  const QString& uuid);
+QStringList
+qlowenergycontrollerServices(const QLowEnergyController& controller);
+
+template<typename T, typename Signal>
+QMetaObjectConnection
+qlowenergycontrollerConnect(QLowEnergyController& controller,
+                            Signal signal,
+                            ::rust::Box<T> callback)
+{
+  auto shared = shareCallback(::std::move(callback));
+  return QObject::connect(
+    &controller, signal, &controller, [shared]() { (*shared)->call(); });
+}
+
+template<typename T>
+QMetaObjectConnection
+qlowenergycontrollerOnConnected(QLowEnergyController& controller,
+                                ::rust::Box<T> callback)
+{
+  return qlowenergycontrollerConnect(
+    controller, &QLowEnergyController::connected, ::std::move(callback));
+}
+
+template<typename T>
+QMetaObjectConnection
+qlowenergycontrollerOnDisconnected(QLowEnergyController& controller,
+                                   ::rust::Box<T> callback)
+{
+  return qlowenergycontrollerConnect(
+    controller, &QLowEnergyController::disconnected, ::std::move(callback));
+}
+
+template<typename T>
+QMetaObjectConnection
+qlowenergycontrollerOnDiscoveryFinished(QLowEnergyController& controller,
+                                        ::rust::Box<T> callback)
+{
+  return qlowenergycontrollerConnect(controller,
+                                     &QLowEnergyController::discoveryFinished,
+                                     ::std::move(callback));
+}
+
+template<typename T>
+QMetaObjectConnection
+qlowenergycontrollerOnErrorOccurred(QLowEnergyController& controller,
+                                    ::rust::Box<T> callback)
+{
+  auto shared = shareCallback(::std::move(callback));
+#if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+  const auto signal = &QLowEnergyController::errorOccurred;
+#else
+  const auto signal = QOverload<QLowEnergyController::Error>::of(
+    &QLowEnergyController::error);
+#endif
+  return QObject::connect(
+    &controller, signal, &controller, [shared, controller = &controller]() {
+      (*shared)->call(controller->errorString());
+    });
+}
+
+}
+}
+
+#endif
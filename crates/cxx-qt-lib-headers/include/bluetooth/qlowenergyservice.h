@@ -0,0 +1,136 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_BLUETOOTH_FEATURE
+
+#include <memory>
+
+#include <QtBluetooth/QLowEnergyCharacteristic>
+#include <QtBluetooth/QLowEnergyService>
+#include <QtCore/QByteArray>
+#include <QtCore/QStringList>
+#include <QtCore/QtGlobal>
+
+#include "rust/cxx.h"
+
+#include "cxx-qt-lib/common.h"
+#include "cxx-qt-lib/qbluetoothdeviceinfo.h"
+#include "cxx-qt-lib/qmetaobjectconnection.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QLowEnergyServiceServiceError = QLowEnergyService::ServiceError;
+
+QStringList
+qlowenergyserviceCharacteristics(const QLowEnergyService& service);
+void
+qlowenergyserviceDiscoverDetails(QLowEnergyService& service);
+bool
+qlowenergyserviceIsDetailsDiscovered(const QLowEnergyService& service);
+bool
+qlowenergyserviceReadCharacteristic(QLowEnergyService& service,
+                                    const QString& uuid);
+bool
+qlowenergyserviceSetNotificationsEnabled(QLowEnergyService& service,
+                                         const QString& uuid,
+                                         bool enabled);
+QString
+qlowenergyserviceUuid(const QLowEnergyService& service);
+bool
+qlowenergyserviceWriteCharacteristic(QLowEnergyService& service,
+                                     const QString& uuid,
+                                     const QByteArray& value,
+                                     bool withResponse);
+
+template<typename T, typename Signal>
+QMetaObjectConnection
+qlowenergyserviceConnectCharacteristic(QLowEnergyService& service,
+                                       Signal signal,
+                                       ::rust::Box<T> callback)
+{
+  auto shared = shareCallback(::std::move(callback));
+  return QObject::connect(
+    &service,
+    signal,
+    &service,
+    [shared](const QLowEnergyCharacteristic& characteristic,
+             const QByteArray& value) {
+      (*shared)->call(qbluetoothuuidToQString(characteristic.uuid()), value);
+    });
+}
+
+template<typename T>
+QMetaObjectConnection
+qlowenergyserviceOnCharacteristicChanged(QLowEnergyService& service,
+                                         ::rust::Box<T> callback)
+{
+  return qlowenergyserviceConnectCharacteristic(
+    service, &QLowEnergyService::characteristicChanged, ::std::move(callback));
+}
+
+template<typename T>
+QMetaObjectConnection
+qlowenergyserviceOnCharacteristicRead(QLowEnergyService& service,
+                                      ::rust::Box<T> callback)
+{
+  return qlowenergyserviceConnectCharacteristic(
+    service, &QLowEnergyService::characteristicRead, ::std::move(callback));
+}
+
+template<typename T>
+QMetaObjectConnection
+qlowenergyserviceOnCharacteristicWritten(QLowEnergyService& service,
+                                         ::rust::Box<T> callback)
+{
+  return qlowenergyserviceConnectCharacteristic(
+    service, &QLowEnergyService::characteristicWritten, ::std::move(callback));
+}
+
+template<typename T>
+QMetaObjectConnection
+qlowenergyserviceOnDetailsDiscovered(QLowEnergyService& service,
+                                     ::rust::Box<T> callback)
+{
+  auto shared = shareCallback(::std::move(callback));
+  return QObject::connect(
+    &service,
+    &QLowEnergyService::stateChanged,
+    &service,
+    [shared](QLowEnergyService::ServiceState state) {
+      if (state == QLowEnergyService::ServiceDiscovered) {
+        (*shared)->call();
+      }
+    });
+}
+
+template<typename T>
+QMetaObjectConnection
+qlowenergyserviceOnErrorOccurred(QLowEnergyService& service,
+                                 ::rust::Box<T> callback)
+{
+  auto shared = shareCallback(::std::move(callback));
+#if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+  const auto signal = &QLowEnergyService::errorOccurred;
+#else
+  const auto signal =
+    QOverload<QLowEnergyService::ServiceError>::of(&QLowEnergyService::error);
+#endif
+  return QObject::connect(
+    &service,
+    signal,
+    &service,
+    [shared](QLowEnergyService::ServiceError error) {
+      (*shared)->call(error);
+    });
+}
+
+}
+}
+
+#endif
@@ -10,6 +10,13 @@
 #include <QtCore/QDebug>
 #include <QtCore/QString>
 #include <cinttypes>
+#include <memory>
+
+#include "rust/cxx.h"
+
+// Nested enums, such as QQuick3DGeometry::Attribute::ComponentType, cannot be
+// named from a CXX bridge, so the headers alias them with a flat name, such as
+// QQuick3DGeometryAttributeComponentType, which the bridge can then declare.
 
 namespace rust {
 namespace cxxqtlib1 {
@@ -87,5 +94,19 @@ operatorDiv(const S scalar, const T& t)
   return t / scalar;
 }
 
+// A Rust callback is only a complete type in the generated bridge which passes
+// it, so the helpers which take a callback are templates that are instantiated
+// there.
+//
+// Qt requires the functor of a connection to be copyable, so the ownership of a
+// Rust callback is shared between the copies of the functor. The callback is
+// dropped once the connection is disconnected and the functor is destroyed.
+template<typename T>
+::std::shared_ptr<::rust::Box<T>>
+shareCallback(::rust::Box<T> callback)
+{
+  return ::std::make_shared<::rust::Box<T>>(::std::move(callback));
+}
+
 } // namespace cxxqtlib1
 } // namespace rust
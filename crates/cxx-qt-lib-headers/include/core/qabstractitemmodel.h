@@ -0,0 +1,28 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <type_traits>
+
+#include <QtCore/QAbstractItemModel>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+// The model is any subclass of QAbstractItemModel, such as a CXX-Qt QObject,
+// so this is a template which is instantiated by the bridge declaring it
+template<typename T>
+QAbstractItemModel*
+qabstractitemmodelUpcast(T* model)
+{
+  static_assert(::std::is_base_of<QAbstractItemModel, T>::value,
+                "The model must inherit from QAbstractItemModel");
+  return model;
+}
+
+}
+}
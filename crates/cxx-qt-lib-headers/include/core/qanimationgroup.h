@@ -0,0 +1,63 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+#include <type_traits>
+
+#include <QtCore/QAbstractAnimation>
+#include <QtCore/QAnimationGroup>
+
+#include "rust/cxx.h"
+
+#include "cxx-qt-lib/common.h"
+#include "cxx-qt-lib/qmetaobjectconnection.h"
+#include "cxx-qt-lib/qvariantanimation.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+void
+qanimationgroupAddAnimation(QAnimationGroup& group,
+                            QAbstractAnimation* animation);
+void
+qanimationgroupStart(QAnimationGroup& group);
+
+// The animation is any subclass of QAbstractAnimation, such as a
+// QPropertyAnimation or an animation group, so this is a template which is
+// instantiated by the bridge declaring it
+template<typename T>
+QAbstractAnimation*
+qabstractanimationUpcast(T* animation)
+{
+  static_assert(::std::is_base_of<QAbstractAnimation, T>::value,
+                "The animation must inherit from QAbstractAnimation");
+  return animation;
+}
+
+template<typename T>
+QAnimationGroup*
+qanimationgroupUpcast(T* group)
+{
+  static_assert(::std::is_base_of<QAnimationGroup, T>::value,
+                "The group must inherit from QAnimationGroup");
+  return group;
+}
+
+template<typename T>
+QMetaObjectConnection
+qanimationgroupOnFinished(QAnimationGroup& group, ::rust::Box<T> callback)
+{
+  auto shared = shareCallback(::std::move(callback));
+  return QObject::connect(&group,
+                          &QAbstractAnimation::finished,
+                          &group,
+                          [shared]() { (*shared)->call(); });
+}
+
+}
+}
@@ -9,6 +9,9 @@
 #include <cstdint>
 
 #include <QtCore/QByteArray>
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+#include <QtCore/QByteArrayView>
+#endif
 
 #include "rust/cxx.h"
 
@@ -63,5 +66,23 @@ qbytearrayToUpper(const QByteArray& byteArray);
 QByteArray
 qbytearrayTrimmed(const QByteArray& byteArray);
 
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+// Borrow a &[u8] from Rust as a QByteArrayView and back again without a copy,
+// the view is only valid for as long as the Rust slice
+inline QByteArrayView
+qbytearrayViewFromSlice(::rust::Slice<const ::std::uint8_t> slice)
+{
+  return QByteArrayView(slice.data(), static_cast<qsizetype>(slice.size()));
+}
+
+inline ::rust::Slice<const ::std::uint8_t>
+qbytearrayViewAsSlice(QByteArrayView view)
+{
+  return ::rust::Slice<const ::std::uint8_t>(
+    reinterpret_cast<const ::std::uint8_t*>(view.data()),
+    static_cast<::std::size_t>(view.size()));
+}
+#endif
+
 }
 }
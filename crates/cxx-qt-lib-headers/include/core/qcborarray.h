@@ -0,0 +1,38 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCborArray>
+#include <QtCore/QCborValue>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<>
+struct IsRelocatable<QCborArray> : ::std::true_type
+{
+};
+
+} // namespace rust
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QCborValue
+qcborarrayAt(const QCborArray& array, ::rust::isize i);
+void
+qcborarrayInsert(QCborArray& array, ::rust::isize i, const QCborValue& value);
+::rust::isize
+qcborarrayLen(const QCborArray& array);
+void
+qcborarrayRemoveAt(QCborArray& array, ::rust::isize i);
+
+}
+}
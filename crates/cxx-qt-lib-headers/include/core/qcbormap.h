@@ -0,0 +1,39 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCborArray>
+#include <QtCore/QCborMap>
+#include <QtCore/QCborValue>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<>
+struct IsRelocatable<QCborMap> : ::std::true_type
+{
+};
+
+} // namespace rust
+
+namespace rust {
+namespace cxxqtlib1 {
+
+void
+qcbormapInsert(QCborMap& map, const QCborValue& key, const QCborValue& value);
+QCborValue
+qcbormapKeyAt(const QCborMap& map, ::rust::isize i);
+::rust::isize
+qcbormapLen(const QCborMap& map);
+QCborValue
+qcbormapValueAt(const QCborMap& map, ::rust::isize i);
+
+}
+}
@@ -0,0 +1,50 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+
+#include <QtCore/QByteArray>
+#include <QtCore/QCborArray>
+#include <QtCore/QCborMap>
+#include <QtCore/QCborValue>
+#include <QtCore/QString>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<>
+struct IsRelocatable<QCborValue> : ::std::true_type
+{
+};
+
+} // namespace rust
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QCborValueType = QCborValue::Type;
+
+bool
+qcborvalueFromCbor(const QByteArray& bytes,
+                   QCborValue& value,
+                   ::std::int64_t& offset,
+                   QString& errorString);
+QCborValue
+qcborvalueInitFromInteger(::std::int64_t value);
+QByteArray
+qcborvalueToCbor(const QCborValue& value);
+QString
+qcborvalueToDiagnosticNotation(const QCborValue& value);
+::std::int64_t
+qcborvalueToInteger(const QCborValue& value, ::std::int64_t defaultValue);
+
+}
+}
@@ -8,6 +8,7 @@
 
 #include <cstdint>
 #include <memory>
+#include <type_traits>
 #include <vector>
 
 #include <QtCore/QByteArray>
@@ -16,6 +17,11 @@
 #include <QtCore/QStringList>
 #include <QtCore/QVector>
 
+#include "rust/cxx.h"
+
+#include "cxx-qt-lib/common.h"
+#include "cxx-qt-lib/qmetaobjectconnection.h"
+
 namespace rust {
 namespace cxxqtlib1 {
 
@@ -72,6 +78,26 @@ qapplicationLibraryPaths(const T& app)
   return app.libraryPaths();
 }
 
+template<typename C>
+QMetaObjectConnection
+qcoreapplicationOnAboutToQuit(QCoreApplication& app, ::rust::Box<C> callback)
+{
+  auto shared = shareCallback(::std::move(callback));
+  return QObject::connect(&app,
+                          &QCoreApplication::aboutToQuit,
+                          &app,
+                          [shared]() { (*shared)->call(); });
+}
+
+template<typename T>
+QCoreApplication*
+qcoreapplicationUpcast(T* app)
+{
+  static_assert(::std::is_base_of<QCoreApplication, T>::value,
+                "The application must inherit from QCoreApplication");
+  return app;
+}
+
 template<typename T>
 QString
 qapplicationOrganizationDomain(const T& app)
@@ -86,6 +112,14 @@ qapplicationOrganizationName(const T& app)
   return app.organizationName();
 }
 
+// QCoreApplication::postEvent is static so the receiver can be any QObject
+template<typename T>
+void
+qcoreapplicationPostEvent(T& receiver, ::std::unique_ptr<QEvent> event)
+{
+  QCoreApplication::postEvent(&receiver, event.release());
+}
+
 template<typename T>
 void
 qapplicationSetApplicationName(T& app, const QString& name)
@@ -0,0 +1,65 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QByteArray>
+#include <QtCore/QDataStream>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QDataStreamByteOrder = QDataStream::ByteOrder;
+using QDataStreamFloatingPointPrecision = QDataStream::FloatingPointPrecision;
+using QDataStreamStatus = QDataStream::Status;
+
+::std::unique_ptr<QDataStream>
+qdatastreamNewReader(const QByteArray& bytes);
+::std::unique_ptr<QDataStream>
+qdatastreamNewWriter(QByteArray& bytes);
+::std::int32_t
+qdatastreamVersion(const QDataStream& stream);
+void
+qdatastreamSetVersion(QDataStream& stream, ::std::int32_t version);
+
+template<typename T>
+T
+qdatastreamRead(QDataStream& stream)
+{
+  T value{};
+  stream >> value;
+  return value;
+}
+
+template<typename T>
+void
+qdatastreamWrite(QDataStream& stream, const T& value)
+{
+  stream << value;
+}
+
+// ::std::int64_t may not be the same type as qint64, which makes the
+// stream operators ambiguous, so these are specialised to use the Qt types
+template<>
+::std::int64_t
+qdatastreamRead(QDataStream& stream);
+template<>
+::std::uint64_t
+qdatastreamRead(QDataStream& stream);
+template<>
+void
+qdatastreamWrite(QDataStream& stream, const ::std::int64_t& value);
+template<>
+void
+qdatastreamWrite(QDataStream& stream, const ::std::uint64_t& value);
+
+}
+}
@@ -0,0 +1,29 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QEasingCurve>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+// QEasingCurve has a single pointer as it's member
+template<>
+struct IsRelocatable<QEasingCurve> : ::std::true_type
+{
+};
+
+namespace cxxqtlib1 {
+
+using QEasingCurveType = QEasingCurve::Type;
+
+}
+
+} // namespace rust
@@ -0,0 +1,35 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstddef>
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QEvent>
+#include <QtCore/QTimerEvent>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QEventType = QEvent::Type;
+
+const QTimerEvent*
+qeventAsTimerEvent(const QEvent& event);
+::std::size_t
+qeventCustomPayload(const QEvent& event);
+::std::unique_ptr<QEvent>
+qeventNewCustom(QEventType type,
+                ::std::size_t payload,
+                ::rust::Fn<void(::std::size_t)> drop);
+QEventType
+qeventRegisterEventType();
+
+}
+}
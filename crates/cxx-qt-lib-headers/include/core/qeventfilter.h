@@ -0,0 +1,55 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QEvent>
+#include <QtCore/QObject>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+// A QObject which forwards the events of the watched object to a Rust closure
+//
+// The filter is a child of the watched object so it is destroyed with it,
+// which then drops the closure
+template<typename T>
+class CxxQtEventFilter : public QObject
+{
+public:
+  CxxQtEventFilter(QObject& watched, ::rust::Box<T> callback)
+    : QObject(&watched)
+    , m_watched(watched)
+    , m_callback(::std::move(callback))
+  {
+  }
+
+  bool eventFilter(QObject* watched, QEvent* event) override
+  {
+    if (watched != &m_watched || event == nullptr) {
+      return false;
+    }
+
+    return m_callback->call(*event);
+  }
+
+private:
+  QObject& m_watched;
+  ::rust::Box<T> m_callback;
+};
+
+template<typename T>
+void
+qobjectInstallEventFilter(QObject& watched, ::rust::Box<T> callback)
+{
+  watched.installEventFilter(
+    new CxxQtEventFilter<T>(watched, ::std::move(callback)));
+}
+
+}
+}
@@ -0,0 +1,61 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QFileSystemWatcher>
+#include <QtCore/QString>
+
+#include "rust/cxx.h"
+
+#include "cxx-qt-lib/common.h"
+#include "cxx-qt-lib/qmetaobjectconnection.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QFileSystemWatcher>
+qfilesystemwatcherNew();
+
+template<typename T, typename Signal>
+QMetaObjectConnection
+qfilesystemwatcherConnect(QFileSystemWatcher& watcher,
+                          Signal signal,
+                          ::rust::Box<T> callback)
+{
+  auto shared = shareCallback(::std::move(callback));
+
+  // Using the watcher as the context means that the callback is called on the
+  // thread of the watcher
+  return QObject::connect(
+    &watcher, signal, &watcher, [shared](const QString& path) {
+      (*shared)->call(path);
+    });
+}
+
+template<typename T>
+QMetaObjectConnection
+qfilesystemwatcherOnDirectoryChanged(QFileSystemWatcher& watcher,
+                                     ::rust::Box<T> callback)
+{
+  return qfilesystemwatcherConnect(watcher,
+                                   &QFileSystemWatcher::directoryChanged,
+                                   ::std::move(callback));
+}
+
+template<typename T>
+QMetaObjectConnection
+qfilesystemwatcherOnFileChanged(QFileSystemWatcher& watcher,
+                                ::rust::Box<T> callback)
+{
+  return qfilesystemwatcherConnect(
+    watcher, &QFileSystemWatcher::fileChanged, ::std::move(callback));
+}
+
+}
+}
@@ -36,6 +36,20 @@ template<typename K, typename V>
 ::rust::isize
 qhashLen(const QHash<K, V>& h) noexcept;
 
+template<typename K, typename V>
+void
+qhashClear(QHash<K, V>& h) noexcept
+{
+  h.clear();
+}
+
+template<typename K, typename V>
+bool
+qhashContains(const QHash<K, V>& h, const K& key) noexcept
+{
+  return h.contains(key);
+}
+
 template<typename K, typename V>
 V
 qhashGetOrDefault(const QHash<K, V>& h, const K& key) noexcept
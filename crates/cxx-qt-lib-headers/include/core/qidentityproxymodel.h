@@ -0,0 +1,22 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QIdentityProxyModel>
+
+#include "cxx-qt-lib/qabstractitemmodel.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QIdentityProxyModel>
+qidentityproxymodelNew();
+
+}
+}
@@ -15,6 +15,7 @@
 #include <QtCore/QDateTime>
 #include <QtCore/QMargins>
 #include <QtCore/QMarginsF>
+#include <QtCore/QModelIndex>
 #include <QtCore/QPersistentModelIndex>
 #include <QtCore/QPoint>
 #include <QtCore/QPointF>
@@ -31,6 +32,10 @@
 #include <QtGui/QColor>
 #endif
 
+#ifdef CXX_QT_SSL_FEATURE
+#include <QtNetwork/QSslCertificate>
+#endif
+
 #include "rust/cxx.h"
 
 // In Qt 6 QList and QVector are the same, so we only need IsRelocatable defined
@@ -67,6 +72,20 @@ qlistAppend(QList<T>& v, const T& value) noexcept
   v.append(value);
 }
 
+template<typename T>
+void
+qlistClear(QList<T>& v) noexcept
+{
+  v.clear();
+}
+
+template<typename T>
+bool
+qlistContains(const QList<T>& v, const T& value) noexcept
+{
+  return v.contains(value);
+}
+
 template<typename T>
 const T&
 qlistGetUnchecked(const QList<T>& v, ::rust::isize pos) noexcept
@@ -157,6 +176,7 @@ using QList_QDate = QList<::QDate>;
 using QList_QDateTime = QList<::QDateTime>;
 using QList_QMargins = QList<::QMargins>;
 using QList_QMarginsF = QList<::QMarginsF>;
+using QList_QModelIndex = QList<::QModelIndex>;
 using QList_QPersistentModelIndex = QList<::QPersistentModelIndex>;
 using QList_QPoint = QList<::QPoint>;
 using QList_QPointF = QList<::QPointF>;
@@ -164,6 +184,9 @@ using QList_QRect = QList<::QRect>;
 using QList_QRectF = QList<::QRectF>;
 using QList_QSize = QList<::QSize>;
 using QList_QSizeF = QList<::QSizeF>;
+#ifdef CXX_QT_SSL_FEATURE
+using QList_QSslCertificate = QList<::QSslCertificate>;
+#endif
 using QList_QString = QList<::QString>;
 using QList_QTime = QList<::QTime>;
 using QList_QUrl = QList<::QUrl>;
@@ -0,0 +1,32 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QLockFile>
+#include <QtCore/QString>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QLockFileLockError = QLockFile::LockError;
+
+::std::unique_ptr<QLockFile>
+qlockfileNew(const QString& fileName);
+void
+qlockfileSetStaleLockTime(QLockFile& lockFile, ::std::int32_t staleLockTime);
+::std::int32_t
+qlockfileStaleLockTime(const QLockFile& lockFile);
+bool
+qlockfileTryLock(QLockFile& lockFile, ::std::int32_t timeout);
+
+}
+}
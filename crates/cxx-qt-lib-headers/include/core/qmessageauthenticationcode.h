@@ -0,0 +1,37 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QByteArray>
+#include <QtCore/QMessageAuthenticationCode>
+
+#include "rust/cxx.h"
+
+#include "cxx-qt-lib/qcryptographichash.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QMessageAuthenticationCode>
+qmessageauthenticationcodeNew(QCryptographicHashAlgorithm method,
+                              ::rust::Slice<const ::std::uint8_t> key);
+void
+qmessageauthenticationcodeAddData(QMessageAuthenticationCode& code,
+                                  ::rust::Slice<const ::std::uint8_t> data);
+QByteArray
+qmessageauthenticationcodeHash(::rust::Slice<const ::std::uint8_t> message,
+                               ::rust::Slice<const ::std::uint8_t> key,
+                               QCryptographicHashAlgorithm method);
+void
+qmessageauthenticationcodeSetKey(QMessageAuthenticationCode& code,
+                                 ::rust::Slice<const ::std::uint8_t> key);
+
+}
+}
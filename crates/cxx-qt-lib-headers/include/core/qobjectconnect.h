@@ -0,0 +1,68 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QByteArray>
+#include <QtCore/QMetaMethod>
+#include <QtCore/QMetaObject>
+#include <QtCore/QMetaProperty>
+#include <QtCore/QObject>
+
+#include <stdexcept>
+#include <string>
+
+#include "rust/cxx.h"
+
+#include "cxx-qt-lib/qmetaobjectconnection.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+// CXX does not support upcasting so this is declared in the bridge of each
+// QObject which implements cxx_qt_lib::Connect
+template<typename T>
+const QObject&
+qobjectUpcast(const T& object) noexcept
+{
+  return object;
+}
+
+template<typename T>
+QObject&
+qobjectUpcastMut(T& object) noexcept
+{
+  return object;
+}
+
+// Find the most derived method with the given name and number of parameters,
+// an invalid QMetaMethod is returned if there is no such method
+QMetaMethod
+qobjectFindMethod(const QObject& object,
+                  ::rust::Str name,
+                  ::rust::usize parameterCount);
+
+// Find the meta property with the given name, such as "number"
+QMetaProperty
+qobjectFindProperty(const QObject& object, ::rust::Str name);
+
+// Find the meta property with the given name which can be written to,
+// such as the target of a property binding
+QMetaProperty
+qobjectFindWritableProperty(const QObject& object, ::rust::Str name);
+
+// Connect the signal of the sender to the signal or invokable of the receiver,
+// the types of the parameters have been checked by the QSignal and QSlot
+QMetaObjectConnection
+qobjectConnectSignalTo(const QObject& sender,
+                       ::rust::Str signal,
+                       const QObject& receiver,
+                       ::rust::Str method,
+                       ::rust::usize parameterCount,
+                       ::Qt::ConnectionType type);
+
+}
+}
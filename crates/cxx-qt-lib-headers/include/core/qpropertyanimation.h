@@ -0,0 +1,42 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+#include <stdexcept>
+#include <string>
+
+#include <QtCore/QByteArray>
+#include <QtCore/QPropertyAnimation>
+
+#include "rust/cxx.h"
+
+#include "cxx-qt-lib/qobjectconnect.h"
+#include "cxx-qt-lib/qvariantanimation.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+// CXX does not support generic functions so this is declared in a bridge
+// for each QObject type whose properties are animated
+template<typename T>
+::std::unique_ptr<QPropertyAnimation>
+qpropertyanimationNew(T& target, ::rust::Str property)
+{
+  const auto meta = qobjectFindProperty(target, property);
+  if (!meta.isWritable()) {
+    throw ::std::invalid_argument(
+      ::std::string("property is not writable: ") +
+      ::std::string(meta.name()));
+  }
+
+  return ::std::make_unique<QPropertyAnimation>(&target,
+                                                QByteArray(meta.name()));
+}
+
+}
+}
@@ -0,0 +1,29 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QSequentialAnimationGroup>
+
+#include "cxx-qt-lib/qanimationgroup.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QSequentialAnimationGroup>
+qsequentialanimationgroupNew();
+void
+qsequentialanimationgroupAddPause(QSequentialAnimationGroup& group,
+                                  ::std::int32_t msecs);
+::std::int32_t
+qsequentialanimationgroupCurrentAnimationIndex(
+  const QSequentialAnimationGroup& group);
+
+}
+}
@@ -0,0 +1,37 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QSharedMemory>
+#include <QtCore/QString>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QSharedMemoryAccessMode = QSharedMemory::AccessMode;
+using QSharedMemorySharedMemoryError = QSharedMemory::SharedMemoryError;
+
+bool
+qsharedmemoryCreate(QSharedMemory& sharedMemory,
+                    ::rust::isize size,
+                    QSharedMemoryAccessMode mode);
+::rust::Slice<const ::std::uint8_t>
+qsharedmemoryData(const QSharedMemory& sharedMemory);
+::rust::Slice<::std::uint8_t>
+qsharedmemoryDataMut(QSharedMemory& sharedMemory);
+::std::unique_ptr<QSharedMemory>
+qsharedmemoryNew(const QString& key);
+::rust::isize
+qsharedmemorySize(const QSharedMemory& sharedMemory);
+
+}
+}
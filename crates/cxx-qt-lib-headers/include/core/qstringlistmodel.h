@@ -0,0 +1,42 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QString>
+#include <QtCore/QStringList>
+#include <QtCore/QStringListModel>
+
+#include "rust/cxx.h"
+
+#include "cxx-qt-lib/common.h"
+#include "cxx-qt-lib/qmetaobjectconnection.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QStringListModel>
+qstringlistmodelNew();
+void
+qstringlistmodelSetStringList(QStringListModel& model,
+                              ::rust::Slice<const QString> strings);
+
+template<typename T>
+QMetaObjectConnection
+qstringlistmodelOnDataChanged(QStringListModel& model, ::rust::Box<T> callback)
+{
+  auto shared = shareCallback(::std::move(callback));
+  auto* modelPtr = &model;
+  return QObject::connect(
+    modelPtr, &QStringListModel::dataChanged, modelPtr, [shared, modelPtr]() {
+      (*shared)->call(modelPtr->stringList());
+    });
+}
+
+}
+}
@@ -0,0 +1,37 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QString>
+#include <QtCore/QSystemSemaphore>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QSystemSemaphoreAccessMode = QSystemSemaphore::AccessMode;
+using QSystemSemaphoreSystemSemaphoreError =
+  QSystemSemaphore::SystemSemaphoreError;
+
+::std::unique_ptr<QSystemSemaphore>
+qsystemsemaphoreNew(const QString& key,
+                    ::std::int32_t initialValue,
+                    QSystemSemaphoreAccessMode mode);
+bool
+qsystemsemaphoreRelease(QSystemSemaphore& semaphore, ::std::int32_t n);
+void
+qsystemsemaphoreSetKey(QSystemSemaphore& semaphore,
+                       const QString& key,
+                       ::std::int32_t initialValue,
+                       QSystemSemaphoreAccessMode mode);
+
+}
+}
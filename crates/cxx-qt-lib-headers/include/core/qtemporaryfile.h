@@ -0,0 +1,29 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QString>
+#include <QtCore/QTemporaryFile>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QTemporaryFile>
+qtemporaryfileNew();
+::std::unique_ptr<QTemporaryFile>
+qtemporaryfileNewWithTemplate(const QString& templateName);
+::std::int64_t
+qtemporaryfileWrite(QTemporaryFile& file,
+                    ::rust::Slice<const ::std::uint8_t> data);
+
+}
+}
@@ -0,0 +1,34 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+
+#include <QtCore/QString>
+#include <QtCore/QtGlobal>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+const char*
+qmessagelogcontextCategory(const QMessageLogContext& context);
+const char*
+qmessagelogcontextFile(const QMessageLogContext& context);
+const char*
+qmessagelogcontextFunction(const QMessageLogContext& context);
+::std::int32_t
+qmessagelogcontextLine(const QMessageLogContext& context);
+
+void
+qtloggingInstallMessageHandler();
+void
+qtloggingResetMessageHandler();
+
+}
+}
@@ -8,6 +8,7 @@
 #pragma once
 
 #include <cstdint>
+#include <memory>
 
 #include <QtCore/QVariant>
 
@@ -97,6 +98,44 @@ CXX_QT_QVARIANT_CAN_CONVERT(U16)
 CXX_QT_QVARIANT_CAN_CONVERT(U32)
 CXX_QT_QVARIANT_CAN_CONVERT(U64)
 
+}
+
+// The Rust value of a QVariantRustHandle, which is defined by the qvariant bridge
+struct QVariantRustValue;
+
+// An opaque Rust value which is stored in a QVariant without a conversion,
+// copies of the QVariant share the value and it is dropped with the last copy
+class QVariantRustHandle
+{
+public:
+  ::std::shared_ptr<QVariantRustValue> data;
+};
+
+inline bool
+operator==(const QVariantRustHandle& a, const QVariantRustHandle& b)
+{
+  return a.data == b.data;
+}
+
+}
+}
+
+Q_DECLARE_METATYPE(::rust::cxxqtlib1::QVariantRustHandle)
+
+namespace rust {
+namespace cxxqtlib1 {
+namespace qvariant {
+
+QVariant
+qvariantFromRustHandle(::rust::Box<QVariantRustValue> value);
+
+bool
+qvariantIsRustHandle(const QVariant& variant);
+
+// The variant must be a Rust handle, as checked by qvariantIsRustHandle
+const QVariantRustValue&
+qvariantRustHandle(const QVariant& variant);
+
 }
 }
 }
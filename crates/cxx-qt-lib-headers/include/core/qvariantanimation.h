@@ -0,0 +1,69 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+#include <type_traits>
+
+#include <QtCore/QAbstractAnimation>
+#include <QtCore/QVariant>
+#include <QtCore/QVariantAnimation>
+
+#include "rust/cxx.h"
+
+#include "cxx-qt-lib/common.h"
+#include "cxx-qt-lib/qmetaobjectconnection.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QAbstractAnimationState = QAbstractAnimation::State;
+
+::std::unique_ptr<QVariantAnimation>
+qvariantanimationNew();
+void
+qvariantanimationStart(QVariantAnimation& animation);
+
+// The animation is any subclass of QVariantAnimation, such as a
+// QPropertyAnimation, so this is a template which is instantiated by the
+// bridge declaring it
+template<typename T>
+QVariantAnimation*
+qvariantanimationUpcast(T* animation)
+{
+  static_assert(::std::is_base_of<QVariantAnimation, T>::value,
+                "The animation must inherit from QVariantAnimation");
+  return animation;
+}
+
+template<typename T>
+QMetaObjectConnection
+qvariantanimationOnFinished(QVariantAnimation& animation,
+                            ::rust::Box<T> callback)
+{
+  auto shared = shareCallback(::std::move(callback));
+  return QObject::connect(&animation,
+                          &QAbstractAnimation::finished,
+                          &animation,
+                          [shared]() { (*shared)->call(); });
+}
+
+template<typename T>
+QMetaObjectConnection
+qvariantanimationOnValueChanged(QVariantAnimation& animation,
+                                ::rust::Box<T> callback)
+{
+  auto shared = shareCallback(::std::move(callback));
+  return QObject::connect(
+    &animation,
+    &QVariantAnimation::valueChanged,
+    &animation,
+    [shared](const QVariant& value) { (*shared)->call(value); });
+}
+
+}
+}
@@ -0,0 +1,166 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+// This header includes all of the cxx-qt-lib headers,
+// the headers of optional Qt modules are only included when their CXX_QT_*_FEATURE is defined.
+#include "cxx-qt-lib/qabstractitemmodel.h"
+#include "cxx-qt-lib/qanimationgroup.h"
+#include "cxx-qt-lib/qbytearray.h"
+#include "cxx-qt-lib/qcborarray.h"
+#include "cxx-qt-lib/qcbormap.h"
+#include "cxx-qt-lib/qcborvalue.h"
+#include "cxx-qt-lib/qconcatenatetablesproxymodel.h"
+#include "cxx-qt-lib/qcoreapplication.h"
+#include "cxx-qt-lib/qcryptographichash.h"
+#include "cxx-qt-lib/qdatastream.h"
+#include "cxx-qt-lib/qdate.h"
+#include "cxx-qt-lib/qdatetime.h"
+#include "cxx-qt-lib/qeasingcurve.h"
+#include "cxx-qt-lib/qevent.h"
+#include "cxx-qt-lib/qeventfilter.h"
+#include "cxx-qt-lib/qfilesystemwatcher.h"
+#include "cxx-qt-lib/qhash.h"
+#include "cxx-qt-lib/qidentityproxymodel.h"
+#include "cxx-qt-lib/qlist.h"
+#include "cxx-qt-lib/qlockfile.h"
+#include "cxx-qt-lib/qmap.h"
+#include "cxx-qt-lib/qmargins.h"
+#include "cxx-qt-lib/qmarginsf.h"
+#include "cxx-qt-lib/qmessageauthenticationcode.h"
+#include "cxx-qt-lib/qmetaobjectconnection.h"
+#include "cxx-qt-lib/qmodelindex.h"
+#include "cxx-qt-lib/qobjectconnect.h"
+#include "cxx-qt-lib/qparallelanimationgroup.h"
+#include "cxx-qt-lib/qpersistentmodelindex.h"
+#include "cxx-qt-lib/qpoint.h"
+#include "cxx-qt-lib/qpointf.h"
+#include "cxx-qt-lib/qpropertyanimation.h"
+#include "cxx-qt-lib/qrect.h"
+#include "cxx-qt-lib/qrectf.h"
+#include "cxx-qt-lib/qsequentialanimationgroup.h"
+#include "cxx-qt-lib/qset.h"
+#include "cxx-qt-lib/qsharedmemory.h"
+#include "cxx-qt-lib/qsize.h"
+#include "cxx-qt-lib/qsizef.h"
+#include "cxx-qt-lib/qstring.h"
+#include "cxx-qt-lib/qstringlist.h"
+#include "cxx-qt-lib/qstringlistmodel.h"
+#include "cxx-qt-lib/qsystemsemaphore.h"
+#include "cxx-qt-lib/qt.h"
+#include "cxx-qt-lib/qtlogging.h"
+#include "cxx-qt-lib/qtemporarydir.h"
+#include "cxx-qt-lib/qtemporaryfile.h"
+#include "cxx-qt-lib/qtime.h"
+#include "cxx-qt-lib/qtimezone.h"
+#include "cxx-qt-lib/qurl.h"
+#include "cxx-qt-lib/qvariant.h"
+#include "cxx-qt-lib/qvariantanimation.h"
+#include "cxx-qt-lib/qvector.h"
+
+#ifdef CXX_QT_BLUETOOTH_FEATURE
+#include "cxx-qt-lib/qbluetoothdevicediscoveryagent.h"
+#include "cxx-qt-lib/qbluetoothdeviceinfo.h"
+#include "cxx-qt-lib/qlowenergycontroller.h"
+#include "cxx-qt-lib/qlowenergyservice.h"
+#endif
+
+#ifdef CXX_QT_GUI_FEATURE
+#include "cxx-qt-lib/qcolor.h"
+#include "cxx-qt-lib/qguiapplication.h"
+#include "cxx-qt-lib/qimage.h"
+#include "cxx-qt-lib/qkeyevent.h"
+#include "cxx-qt-lib/qmatrix4x4.h"
+#include "cxx-qt-lib/qmouseevent.h"
+#include "cxx-qt-lib/qpixmap.h"
+#include "cxx-qt-lib/qquaternion.h"
+#include "cxx-qt-lib/qshortcutevent.h"
+#include "cxx-qt-lib/qvector2d.h"
+#include "cxx-qt-lib/qvector3d.h"
+#include "cxx-qt-lib/qvector4d.h"
+#endif
+
+#ifdef CXX_QT_KEYCHAIN_FEATURE
+#include "cxx-qt-lib/qkeychain.h"
+#endif
+
+#ifdef CXX_QT_NETWORK_FEATURE
+#include "cxx-qt-lib/qlocalserver.h"
+#include "cxx-qt-lib/qlocalsocket.h"
+#include "cxx-qt-lib/qnetworkinformation.h"
+#endif
+
+#ifdef CXX_QT_QML_FEATURE
+#include "cxx-qt-lib/qqmlapplicationengine.h"
+#include "cxx-qt-lib/qqmlengine.h"
+#include "cxx-qt-lib/qqmlerror.h"
+#endif
+
+#ifdef CXX_QT_QUICK3D_FEATURE
+#include "cxx-qt-lib/qquick3dgeometry.h"
+#endif
+
+#ifdef CXX_QT_SENSORS_FEATURE
+#include "cxx-qt-lib/qaccelerometer.h"
+#include "cxx-qt-lib/qgyroscope.h"
+#endif
+
+#ifdef CXX_QT_SERIALPORT_FEATURE
+#include "cxx-qt-lib/qserialport.h"
+#endif
+
+#ifdef CXX_QT_SQL_FEATURE
+#include "cxx-qt-lib/qsqldatabase.h"
+#include "cxx-qt-lib/qsqlquery.h"
+#include "cxx-qt-lib/qsqlquerymodel.h"
+#include "cxx-qt-lib/qsqltablemodel.h"
+#endif
+
+#ifdef CXX_QT_SSL_FEATURE
+#include "cxx-qt-lib/qsslcertificate.h"
+#include "cxx-qt-lib/qsslconfiguration.h"
+#endif
+
+#ifdef CXX_QT_STATEMACHINE_FEATURE
+#include "cxx-qt-lib/qstatemachine.h"
+#endif
+
+#ifdef CXX_QT_WEBSOCKETS_FEATURE
+#include "cxx-qt-lib/qwebsocket.h"
+#include "cxx-qt-lib/qwebsocketserver.h"
+#endif
+
+#include <QtCore/QMetaType>
+#include <QtGlobal>
+
+#include <cstdint>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+// Initialize cxx-qt-lib for C++ code which is not built with cxx-qt-build
+//
+// For versions less than Qt 6 this registers the std numerics, so that they can be used with QML,
+// as cxx-qt-build would otherwise do.
+inline void
+init()
+{
+#if (QT_VERSION < QT_VERSION_CHECK(6, 0, 0))
+  qRegisterMetaType<::std::int8_t>("::std::int8_t");
+  qRegisterMetaType<::std::int16_t>("::std::int16_t");
+  qRegisterMetaType<::std::int32_t>("::std::int32_t");
+  qRegisterMetaType<::std::int64_t>("::std::int64_t");
+
+  qRegisterMetaType<::std::uint8_t>("::std::uint8_t");
+  qRegisterMetaType<::std::uint16_t>("::std::uint16_t");
+  qRegisterMetaType<::std::uint32_t>("::std::uint32_t");
+  qRegisterMetaType<::std::uint64_t>("::std::uint64_t");
+#endif
+}
+
+}
+}
@@ -0,0 +1,85 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_GUI_FEATURE
+#include <cstdint>
+
+#include <QtGui/QImage>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<>
+struct IsRelocatable<QImage> : ::std::true_type
+{
+};
+
+} // namespace rust
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QImageFormat = QImage::Format;
+
+::rust::Slice<const ::std::uint8_t>
+qimageAsSlice(const QImage& image);
+::rust::isize
+qimageBytesPerLine(const QImage& image);
+QImage
+qimageConvertToFormat(const QImage& image, QImageFormat format);
+
+// The buffer is a Rust type which is only complete in the generated bridge,
+// so this is a template which is instantiated there
+//
+// The image refers to the data without a copy and the buffer is dropped once
+// the last copy of the image is destroyed. A null image is returned if the
+// data is too small for the size and format.
+template<typename T>
+QImage
+qimageInitFromBuffer(::rust::Box<T> buffer,
+                     ::std::uint8_t* data,
+                     ::std::size_t len,
+                     ::std::int32_t width,
+                     ::std::int32_t height,
+                     ::rust::isize bytesPerLine,
+                     QImageFormat format)
+{
+  if (format == QImage::Format_Invalid || width <= 0 || height <= 0) {
+    return QImage();
+  }
+
+  const auto bitsPerPixel =
+    static_cast<::rust::isize>(QImage::toPixelFormat(format).bitsPerPixel());
+  if (bytesPerLine * 8 < static_cast<::rust::isize>(width) * bitsPerPixel ||
+      static_cast<::std::size_t>(bytesPerLine) *
+          static_cast<::std::size_t>(height) >
+        len) {
+    return QImage();
+  }
+
+  return QImage(
+    data,
+    width,
+    height,
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+    static_cast<qsizetype>(bytesPerLine),
+#else
+    static_cast<int>(bytesPerLine),
+#endif
+    format,
+    [](void* info) { ::rust::Box<T>::from_raw(static_cast<T*>(info)); },
+    buffer.into_raw());
+}
+
+}
+}
+
+#endif
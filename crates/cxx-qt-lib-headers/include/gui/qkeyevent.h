@@ -0,0 +1,25 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_GUI_FEATURE
+#include <QtGui/QKeyEvent>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+const QKeyEvent*
+qeventAsKeyEvent(const QEvent& event);
+::std::int32_t
+qkeyeventModifiers(const QKeyEvent& event);
+
+}
+}
+
+#endif
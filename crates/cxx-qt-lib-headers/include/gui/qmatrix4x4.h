@@ -0,0 +1,33 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_GUI_FEATURE
+#include <cstdint>
+
+#include <QtGui/QMatrix4x4>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+float
+qmatrix4x4Determinant(const QMatrix4x4& matrix);
+QMatrix4x4
+qmatrix4x4InitRowMajor(::rust::Slice<const float> values);
+QMatrix4x4
+qmatrix4x4Inverted(const QMatrix4x4& matrix, bool& invertible);
+void
+qmatrix4x4Set(QMatrix4x4& matrix,
+              ::std::int32_t row,
+              ::std::int32_t column,
+              float value);
+
+}
+}
+#endif
@@ -0,0 +1,34 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_GUI_FEATURE
+#include <QtCore/QPointF>
+#include <QtGui/QMouseEvent>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+const QMouseEvent*
+qeventAsMouseEvent(const QEvent& event);
+::std::int32_t
+qmouseeventButton(const QMouseEvent& event);
+::std::int32_t
+qmouseeventButtons(const QMouseEvent& event);
+QPointF
+qmouseeventGlobalPosition(const QMouseEvent& event);
+::std::int32_t
+qmouseeventModifiers(const QMouseEvent& event);
+QPointF
+qmouseeventPosition(const QMouseEvent& event);
+
+}
+}
+
+#endif
@@ -0,0 +1,31 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_GUI_FEATURE
+#include <QtGui/QQuaternion>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+// CXX does not support static methods so we create proxies
+float
+qquaternionDotProduct(const QQuaternion& q1, const QQuaternion& q2);
+QQuaternion
+qquaternionFromAxisAndAngle(const QVector3D& axis, float angle);
+QQuaternion
+qquaternionFromEulerAngles(const QVector3D& angles);
+QQuaternion
+qquaternionNlerp(const QQuaternion& q1, const QQuaternion& q2, float t);
+QQuaternion
+qquaternionRotationTo(const QVector3D& from, const QVector3D& to);
+QQuaternion
+qquaternionSlerp(const QQuaternion& q1, const QQuaternion& q2, float t);
+
+}
+}
+#endif
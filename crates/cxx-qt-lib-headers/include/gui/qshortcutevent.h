@@ -0,0 +1,24 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_GUI_FEATURE
+#include <QtCore/QString>
+#include <QtGui/QShortcutEvent>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+const QShortcutEvent*
+qeventAsShortcutEvent(const QEvent& event);
+QString
+qshortcuteventKey(const QShortcutEvent& event);
+
+}
+}
+
+#endif
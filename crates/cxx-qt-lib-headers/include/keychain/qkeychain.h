@@ -0,0 +1,88 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_KEYCHAIN_FEATURE
+
+#include <memory>
+
+#include <QtCore/QString>
+#include <QtCore/QtGlobal>
+
+#if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+#include <qt6keychain/keychain.h>
+#else
+#include <qt5keychain/keychain.h>
+#endif
+
+#include "rust/cxx.h"
+
+#include "cxx-qt-lib/common.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+template<typename T>
+void
+qkeychainStart(QKeychain::Job* job, ::rust::Box<T> callback)
+{
+  // The job deletes itself once finished which drops the callback
+  auto shared = shareCallback(::std::move(callback));
+
+  job->setAutoDelete(true);
+  QObject::connect(job, &QKeychain::Job::finished, [shared](QKeychain::Job* job) {
+    QString data;
+    if (auto* readJob = qobject_cast<QKeychain::ReadPasswordJob*>(job)) {
+      data = readJob->textData();
+    }
+
+    (*shared)->call(static_cast<::std::int32_t>(job->error()),
+                    job->errorString(),
+                    data);
+  });
+  job->start();
+}
+
+template<typename T>
+void
+qkeychainReadPassword(const QString& service,
+                      const QString& key,
+                      ::rust::Box<T> callback)
+{
+  auto* job = new QKeychain::ReadPasswordJob(service);
+  job->setKey(key);
+  qkeychainStart(job, ::std::move(callback));
+}
+
+template<typename T>
+void
+qkeychainWritePassword(const QString& service,
+                       const QString& key,
+                       const QString& password,
+                       ::rust::Box<T> callback)
+{
+  auto* job = new QKeychain::WritePasswordJob(service);
+  job->setKey(key);
+  job->setTextData(password);
+  qkeychainStart(job, ::std::move(callback));
+}
+
+template<typename T>
+void
+qkeychainDeletePassword(const QString& service,
+                        const QString& key,
+                        ::rust::Box<T> callback)
+{
+  auto* job = new QKeychain::DeletePasswordJob(service);
+  job->setKey(key);
+  qkeychainStart(job, ::std::move(callback));
+}
+
+}
+}
+
+#endif
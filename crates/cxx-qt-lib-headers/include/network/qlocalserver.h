@@ -0,0 +1,45 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_NETWORK_FEATURE
+
+#include <memory>
+
+#include <QtNetwork/QLocalServer>
+#include <QtNetwork/QLocalSocket>
+
+#include "rust/cxx.h"
+
+#include "cxx-qt-lib/common.h"
+#include "cxx-qt-lib/qmetaobjectconnection.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QLocalServer>
+qlocalserverNew();
+::std::unique_ptr<QLocalSocket>
+qlocalserverNextPendingConnection(QLocalServer& server);
+bool
+qlocalserverRemoveServer(const QString& name);
+
+template<typename T>
+QMetaObjectConnection
+qlocalserverOnNewConnection(QLocalServer& server, ::rust::Box<T> callback)
+{
+  auto shared = shareCallback(::std::move(callback));
+  return QObject::connect(&server,
+                          &QLocalServer::newConnection,
+                          &server,
+                          [shared]() { (*shared)->call(); });
+}
+
+}
+}
+
+#endif
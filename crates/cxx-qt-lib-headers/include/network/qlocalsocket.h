@@ -0,0 +1,93 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_NETWORK_FEATURE
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QByteArray>
+#include <QtNetwork/QLocalSocket>
+
+#include "rust/cxx.h"
+
+#include "cxx-qt-lib/common.h"
+#include "cxx-qt-lib/qmetaobjectconnection.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QLocalSocketLocalSocketError = QLocalSocket::LocalSocketError;
+using QLocalSocketLocalSocketState = QLocalSocket::LocalSocketState;
+
+::std::unique_ptr<QLocalSocket>
+qlocalsocketNew();
+::std::int64_t
+qlocalsocketBytesAvailable(const QLocalSocket& socket);
+void
+qlocalsocketConnectToServer(QLocalSocket& socket, const QString& name);
+bool
+qlocalsocketWaitForBytesWritten(QLocalSocket& socket, ::std::int32_t msecs);
+bool
+qlocalsocketWaitForConnected(QLocalSocket& socket, ::std::int32_t msecs);
+bool
+qlocalsocketWaitForReadyRead(QLocalSocket& socket, ::std::int32_t msecs);
+::std::int64_t
+qlocalsocketWrite(QLocalSocket& socket, const QByteArray& data);
+
+template<typename T, typename Signal>
+QMetaObjectConnection
+qlocalsocketConnect(QLocalSocket& socket,
+                    Signal signal,
+                    ::rust::Box<T> callback)
+{
+  auto shared = shareCallback(::std::move(callback));
+  return QObject::connect(
+    &socket, signal, &socket, [shared]() { (*shared)->call(); });
+}
+
+template<typename T>
+QMetaObjectConnection
+qlocalsocketOnConnected(QLocalSocket& socket, ::rust::Box<T> callback)
+{
+  return qlocalsocketConnect(
+    socket, &QLocalSocket::connected, ::std::move(callback));
+}
+
+template<typename T>
+QMetaObjectConnection
+qlocalsocketOnDisconnected(QLocalSocket& socket, ::rust::Box<T> callback)
+{
+  return qlocalsocketConnect(
+    socket, &QLocalSocket::disconnected, ::std::move(callback));
+}
+
+template<typename T>
+QMetaObjectConnection
+qlocalsocketOnErrorOccurred(QLocalSocket& socket, ::rust::Box<T> callback)
+{
+  auto shared = shareCallback(::std::move(callback));
+  return QObject::connect(
+    &socket,
+    &QLocalSocket::errorOccurred,
+    &socket,
+    [shared](QLocalSocket::LocalSocketError error) { (*shared)->call(error); });
+}
+
+template<typename T>
+QMetaObjectConnection
+qlocalsocketOnReadyRead(QLocalSocket& socket, ::rust::Box<T> callback)
+{
+  return qlocalsocketConnect(
+    socket, &QIODevice::readyRead, ::std::move(callback));
+}
+
+}
+}
+
+#endif
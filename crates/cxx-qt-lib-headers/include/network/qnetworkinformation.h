@@ -0,0 +1,139 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_NETWORK_FEATURE
+
+#include <QtCore/QtGlobal>
+
+// QNetworkInformation was added in Qt 6.1
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 1, 0))
+
+#include <cstdint>
+#include <memory>
+
+#include <QtNetwork/QNetworkInformation>
+
+#include "rust/cxx.h"
+
+#include "cxx-qt-lib/common.h"
+#include "cxx-qt-lib/qmetaobjectconnection.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QNetworkInformationReachability = QNetworkInformation::Reachability;
+
+// The transport medium was added in Qt 6.3, so define it for earlier versions
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 3, 0))
+using QNetworkInformationTransportMedium = QNetworkInformation::TransportMedium;
+#else
+enum class QNetworkInformationTransportMedium : ::std::int32_t
+{
+  Unknown = 0,
+  Ethernet = 1,
+  Cellular = 2,
+  WiFi = 3,
+  Bluetooth = 4,
+};
+#endif
+
+const QNetworkInformation*
+qnetworkinformationInstance();
+bool
+qnetworkinformationIsBehindCaptivePortal(
+  const QNetworkInformation& information);
+bool
+qnetworkinformationIsMetered(const QNetworkInformation& information);
+bool
+qnetworkinformationLoadDefaultBackend();
+QNetworkInformationTransportMedium
+qnetworkinformationTransportMedium(const QNetworkInformation& information);
+
+template<typename T>
+QMetaObjectConnection
+qnetworkinformationOnIsBehindCaptivePortalChanged(
+  const QNetworkInformation& information,
+  ::rust::Box<T> callback)
+{
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 2, 0))
+  auto shared = shareCallback(::std::move(callback));
+  return QObject::connect(
+    &information,
+    &QNetworkInformation::isBehindCaptivePortalChanged,
+    &information,
+    [shared](bool state) { (*shared)->call(state); });
+#else
+  // The captive portal is never detected, so the callback is never called
+  Q_UNUSED(information);
+  Q_UNUSED(callback);
+  return QMetaObjectConnection();
+#endif
+}
+
+template<typename T>
+QMetaObjectConnection
+qnetworkinformationOnIsMeteredChanged(const QNetworkInformation& information,
+                                      ::rust::Box<T> callback)
+{
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 3, 0))
+  auto shared = shareCallback(::std::move(callback));
+  return QObject::connect(
+    &information,
+    &QNetworkInformation::isMeteredChanged,
+    &information,
+    [shared](bool isMetered) { (*shared)->call(isMetered); });
+#else
+  Q_UNUSED(information);
+  Q_UNUSED(callback);
+  return QMetaObjectConnection();
+#endif
+}
+
+template<typename T>
+QMetaObjectConnection
+qnetworkinformationOnReachabilityChanged(const QNetworkInformation& information,
+                                         ::rust::Box<T> callback)
+{
+  auto shared = shareCallback(::std::move(callback));
+  return QObject::connect(
+    &information,
+    &QNetworkInformation::reachabilityChanged,
+    &information,
+    [shared](QNetworkInformationReachability reachability) {
+      (*shared)->call(reachability);
+    });
+}
+
+template<typename T>
+QMetaObjectConnection
+qnetworkinformationOnTransportMediumChanged(
+  const QNetworkInformation& information,
+  ::rust::Box<T> callback)
+{
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 3, 0))
+  auto shared = shareCallback(::std::move(callback));
+  return QObject::connect(
+    &information,
+    &QNetworkInformation::transportMediumChanged,
+    &information,
+    [shared](QNetworkInformationTransportMedium current) {
+      (*shared)->call(current);
+    });
+#else
+  Q_UNUSED(information);
+  Q_UNUSED(callback);
+  return QMetaObjectConnection();
+#endif
+}
+
+}
+}
+
+#endif
+
+#endif
@@ -0,0 +1,43 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_SSL_FEATURE
+
+#include <QtCore/QByteArray>
+#include <QtCore/QList>
+#include <QtNetwork/QSsl>
+#include <QtNetwork/QSslCertificate>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<>
+struct IsRelocatable<QSslCertificate> : ::std::true_type
+{
+};
+
+} // namespace rust
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QSslCertificateSubjectInfo = QSslCertificate::SubjectInfo;
+using QSslEncodingFormat = QSsl::EncodingFormat;
+
+QSslCertificate
+qsslcertificateInitFromData(const QByteArray& data, QSslEncodingFormat format);
+QList<QSslCertificate>
+qsslcertificateFromData(const QByteArray& data, QSslEncodingFormat format);
+
+}
+}
+
+#endif
@@ -0,0 +1,45 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_SSL_FEATURE
+
+#include <QtCore/QList>
+#include <QtNetwork/QSslCertificate>
+#include <QtNetwork/QSslConfiguration>
+#include <QtNetwork/QSslSocket>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<>
+struct IsRelocatable<QSslConfiguration> : ::std::true_type
+{
+};
+
+} // namespace rust
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QSslSocketPeerVerifyMode = QSslSocket::PeerVerifyMode;
+
+QSslConfiguration
+qsslconfigurationDefaultConfiguration();
+void
+qsslconfigurationSetDefaultConfiguration(
+  const QSslConfiguration& configuration);
+QList<QSslCertificate>
+qsslconfigurationSystemCaCertificates();
+
+}
+}
+
+#endif
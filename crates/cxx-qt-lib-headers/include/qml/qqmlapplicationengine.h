@@ -12,6 +12,8 @@
 
 #include <QtQml/QQmlApplicationEngine>
 
+#include "cxx-qt-lib/qqmlengine.h"
+
 namespace rust {
 namespace cxxqtlib1 {
 
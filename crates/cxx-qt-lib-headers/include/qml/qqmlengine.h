@@ -11,6 +11,12 @@
 #include <memory>
 
 #include <QtQml/QQmlEngine>
+#include <QtQml/QQmlError>
+
+#include "rust/cxx.h"
+
+#include "cxx-qt-lib/qmetaobjectconnection.h"
+#include "cxx-qt-lib/qqmlerror.h"
 
 namespace rust {
 namespace cxxqtlib1 {
@@ -18,6 +24,31 @@ namespace cxxqtlib1 {
 ::std::unique_ptr<QQmlEngine>
 qqmlengineNew();
 
+// This is a template so that it can be used for QQmlEngine and any subclass
+// such as QQmlApplicationEngine
+template<typename T>
+QMetaObjectConnection
+qqmlengineOnWarnings(
+  T& engine,
+  ::rust::Fn<void(T&, ::rust::Slice<const QQmlError>)> func)
+{
+  return QObject::connect(
+    &engine,
+    &QQmlEngine::warnings,
+    &engine,
+    [&engine, func](const QList<QQmlError>& warnings) {
+      // A slice cannot be constructed from the null data of an empty list
+      if (warnings.isEmpty()) {
+        return;
+      }
+
+      func(engine,
+           ::rust::Slice<const QQmlError>(
+             warnings.constData(),
+             static_cast<::std::size_t>(warnings.size())));
+    });
+}
+
 }
 }
 
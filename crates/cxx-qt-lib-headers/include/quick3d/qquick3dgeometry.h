@@ -0,0 +1,23 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_QUICK3D_FEATURE
+#include <QtQuick3D/QQuick3DGeometry>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QQuick3DGeometryAttributeComponentType =
+  QQuick3DGeometry::Attribute::ComponentType;
+using QQuick3DGeometryAttributeSemantic = QQuick3DGeometry::Attribute::Semantic;
+using QQuick3DGeometryPrimitiveType = QQuick3DGeometry::PrimitiveType;
+
+}
+}
+
+#endif
@@ -0,0 +1,46 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_SENSORS_FEATURE
+
+#include <memory>
+
+#include <QtSensors/QAccelerometer>
+
+#include "rust/cxx.h"
+
+#include "cxx-qt-lib/common.h"
+#include "cxx-qt-lib/qmetaobjectconnection.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QAccelerometerAccelerationMode = QAccelerometer::AccelerationMode;
+
+::std::unique_ptr<QAccelerometer>
+qaccelerometerNew();
+
+template<typename T>
+QMetaObjectConnection
+qaccelerometerOnReadingChanged(QAccelerometer& sensor, ::rust::Box<T> callback)
+{
+  auto shared = shareCallback(::std::move(callback));
+  return QObject::connect(
+    &sensor, &QSensor::readingChanged, &sensor, [shared, sensor = &sensor]() {
+      const auto* reading = sensor->reading();
+      if (reading != nullptr) {
+        (*shared)->call(
+          reading->x(), reading->y(), reading->z(), reading->timestamp());
+      }
+    });
+}
+
+}
+}
+
+#endif
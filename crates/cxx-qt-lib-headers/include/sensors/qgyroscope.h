@@ -0,0 +1,44 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_SENSORS_FEATURE
+
+#include <memory>
+
+#include <QtSensors/QGyroscope>
+
+#include "rust/cxx.h"
+
+#include "cxx-qt-lib/common.h"
+#include "cxx-qt-lib/qmetaobjectconnection.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QGyroscope>
+qgyroscopeNew();
+
+template<typename T>
+QMetaObjectConnection
+qgyroscopeOnReadingChanged(QGyroscope& sensor, ::rust::Box<T> callback)
+{
+  auto shared = shareCallback(::std::move(callback));
+  return QObject::connect(
+    &sensor, &QSensor::readingChanged, &sensor, [shared, sensor = &sensor]() {
+      const auto* reading = sensor->reading();
+      if (reading != nullptr) {
+        (*shared)->call(
+          reading->x(), reading->y(), reading->z(), reading->timestamp());
+      }
+    });
+}
+
+}
+}
+
+#endif
@@ -0,0 +1,80 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_SERIALPORT_FEATURE
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QByteArray>
+#include <QtCore/QIODevice>
+#include <QtCore/QStringList>
+#include <QtSerialPort/QSerialPort>
+
+#include "rust/cxx.h"
+
+#include "cxx-qt-lib/common.h"
+#include "cxx-qt-lib/qmetaobjectconnection.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QIODeviceOpenModeFlag = QIODevice::OpenModeFlag;
+using QSerialPortDataBits = QSerialPort::DataBits;
+using QSerialPortFlowControl = QSerialPort::FlowControl;
+using QSerialPortParity = QSerialPort::Parity;
+using QSerialPortSerialPortError = QSerialPort::SerialPortError;
+using QSerialPortStopBits = QSerialPort::StopBits;
+
+::std::unique_ptr<QSerialPort>
+qserialportNew();
+QStringList
+qserialportAvailablePorts();
+::std::int32_t
+qserialportBaudRate(const QSerialPort& port);
+::std::int64_t
+qserialportBytesAvailable(const QSerialPort& port);
+bool
+qserialportOpen(QSerialPort& port, QIODeviceOpenModeFlag mode);
+bool
+qserialportSetBaudRate(QSerialPort& port, ::std::int32_t baudRate);
+::std::int64_t
+qserialportWrite(QSerialPort& port, const QByteArray& data);
+
+template<typename T>
+QMetaObjectConnection
+qserialportOnErrorOccurred(QSerialPort& port, ::rust::Box<T> callback)
+{
+  auto shared = shareCallback(::std::move(callback));
+  return QObject::connect(&port,
+                          &QSerialPort::errorOccurred,
+                          &port,
+                          [shared](QSerialPort::SerialPortError error) {
+                            // The error is reset to NoError when the port is
+                            // closed or cleared, which is not an error
+                            if (error != QSerialPort::NoError) {
+                              (*shared)->call(error);
+                            }
+                          });
+}
+
+template<typename T>
+QMetaObjectConnection
+qserialportOnReadyRead(QSerialPort& port, ::rust::Box<T> callback)
+{
+  auto shared = shareCallback(::std::move(callback));
+  return QObject::connect(&port,
+                          &QIODevice::readyRead,
+                          &port,
+                          [shared]() { (*shared)->call(); });
+}
+
+}
+}
+
+#endif
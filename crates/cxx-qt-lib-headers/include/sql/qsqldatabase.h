@@ -0,0 +1,45 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_SQL_FEATURE
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QString>
+#include <QtCore/QStringList>
+#include <QtSql/QSqlDatabase>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QSqlDatabase>
+qsqldatabaseAddDatabase(const QString& type, const QString& connectionName);
+bool
+qsqldatabaseContains(const QString& connectionName);
+::std::unique_ptr<QSqlDatabase>
+qsqldatabaseDatabase(const QString& connectionName, bool open);
+QStringList
+qsqldatabaseDrivers();
+bool
+qsqldatabaseIsDriverAvailable(const QString& name);
+QString
+qsqldatabaseLastErrorText(const QSqlDatabase& database);
+void
+qsqldatabaseRemoveDatabase(const QString& connectionName);
+void
+qsqldatabaseSetConnectOptions(QSqlDatabase& database, const QString& options);
+QStringList
+qsqldatabaseTables(const QSqlDatabase& database);
+
+}
+}
+
+#endif
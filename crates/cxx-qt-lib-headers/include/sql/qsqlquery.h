@@ -0,0 +1,51 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_SQL_FEATURE
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QList>
+#include <QtCore/QString>
+#include <QtCore/QVariant>
+#include <QtSql/QSqlDatabase>
+#include <QtSql/QSqlQuery>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QSqlQuery>
+qsqlqueryNew(const QSqlDatabase& database);
+void
+qsqlqueryAddBindValue(QSqlQuery& query, const QVariant& value);
+void
+qsqlqueryBindValue(QSqlQuery& query,
+                   const QString& placeholder,
+                   const QVariant& value);
+::std::int32_t
+qsqlqueryColumnCount(const QSqlQuery& query);
+bool
+qsqlqueryExecQuery(QSqlQuery& query, const QString& text);
+QString
+qsqlqueryLastErrorText(const QSqlQuery& query);
+QList<QVariant>
+qsqlqueryRowValues(const QSqlQuery& query);
+bool
+qsqlquerySeek(QSqlQuery& query, ::std::int32_t index);
+QVariant
+qsqlqueryValue(const QSqlQuery& query, ::std::int32_t index);
+QVariant
+qsqlqueryValueByName(const QSqlQuery& query, const QString& name);
+
+}
+}
+
+#endif
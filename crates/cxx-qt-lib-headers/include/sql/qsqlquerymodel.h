@@ -0,0 +1,38 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_SQL_FEATURE
+
+#include <memory>
+
+#include <QtCore/QString>
+#include <QtSql/QSqlDatabase>
+#include <QtSql/QSqlQuery>
+#include <QtSql/QSqlQueryModel>
+
+#include "cxx-qt-lib/qabstractitemmodel.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QSqlQueryModel>
+qsqlquerymodelNew();
+QString
+qsqlquerymodelLastErrorText(const QSqlQueryModel& model);
+void
+qsqlquerymodelSetExecutedQuery(QSqlQueryModel& model,
+                               ::std::unique_ptr<QSqlQuery> query);
+void
+qsqlquerymodelSetQuery(QSqlQueryModel& model,
+                       const QString& query,
+                       const QSqlDatabase& database);
+
+}
+}
+
+#endif
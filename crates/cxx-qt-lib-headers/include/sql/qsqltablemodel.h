@@ -0,0 +1,34 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_SQL_FEATURE
+
+#include <memory>
+
+#include <QtCore/QString>
+#include <QtSql/QSqlDatabase>
+#include <QtSql/QSqlTableModel>
+
+#include "cxx-qt-lib/qabstractitemmodel.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QSqlTableModelEditStrategy = QSqlTableModel::EditStrategy;
+
+::std::unique_ptr<QSqlTableModel>
+qsqltablemodelNew(const QSqlDatabase& database);
+bool
+qsqltablemodelIsDirty(const QSqlTableModel& model);
+QString
+qsqltablemodelLastErrorText(const QSqlTableModel& model);
+
+}
+}
+
+#endif
@@ -0,0 +1,123 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_STATEMACHINE_FEATURE
+
+#include <memory>
+#include <stdexcept>
+
+#include <QtCore/QByteArray>
+#include <QtCore/QMetaObject>
+#include <QtCore/QString>
+#include <QtCore/QStringList>
+#include <QtCore/QVariant>
+#include <QtCore/QtGlobal>
+
+#if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+#include <QtStateMachine/QFinalState>
+#include <QtStateMachine/QState>
+#include <QtStateMachine/QStateMachine>
+#else
+#include <QtCore/QFinalState>
+#include <QtCore/QState>
+#include <QtCore/QStateMachine>
+#endif
+
+#include "rust/cxx.h"
+
+#include "cxx-qt-lib/common.h"
+#include "cxx-qt-lib/qmetaobjectconnection.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QStateMachine>
+qstatemachineNew();
+bool
+qstatemachineAddState(QStateMachine& machine, const QString& name);
+bool
+qstatemachineAddFinalState(QStateMachine& machine, const QString& name);
+QStringList
+qstatemachineActiveStates(const QStateMachine& machine);
+QAbstractState*
+qstatemachineFindState(const QStateMachine& machine, const QString& name);
+bool
+qstatemachineSetInitialState(QStateMachine& machine, const QString& name);
+
+template<typename T>
+QMetaObjectConnection
+qstatemachineOnStateEntered(QStateMachine& machine,
+                            const QString& name,
+                            ::rust::Box<T> callback)
+{
+  auto* state = qstatemachineFindState(machine, name);
+  if (state == nullptr) {
+    throw ::std::invalid_argument("state not found");
+  }
+
+  auto shared = shareCallback(::std::move(callback));
+  return QObject::connect(
+    state, &QAbstractState::entered, &machine, [shared, name]() {
+      (*shared)->call(name);
+    });
+}
+
+// CXX does not support generic functions so this is declared in a bridge
+// for each QObject type whose signal drives a transition
+//
+// The signal is described by its signature, such as "started()"
+template<typename T>
+bool
+qstatemachineAddTransition(QStateMachine& machine,
+                           const QString& from,
+                           const T& sender,
+                           ::rust::Str signal,
+                           const QString& to)
+{
+  auto* source = qobject_cast<QState*>(qstatemachineFindState(machine, from));
+  auto* target = qstatemachineFindState(machine, to);
+  if (source == nullptr || target == nullptr) {
+    return false;
+  }
+
+  const auto normalized = QMetaObject::normalizedSignature(
+    QByteArray(signal.data(), static_cast<int>(signal.size())).constData());
+  if (sender.metaObject()->indexOfSignal(normalized.constData()) < 0) {
+    return false;
+  }
+
+  // QSignalTransition expects the signature in the form created by SIGNAL()
+  const auto code = QByteArray::number(QSIGNAL_CODE) + normalized;
+  return source->addTransition(&sender, code.constData(), target) != nullptr;
+}
+
+// CXX does not support generic functions so this is declared in a bridge
+// for each QObject type whose property is assigned when a state is entered
+template<typename T>
+bool
+qstatemachineAssignProperty(QStateMachine& machine,
+                            const QString& name,
+                            T& object,
+                            ::rust::Str property,
+                            const QVariant& value)
+{
+  auto* state = qobject_cast<QState*>(qstatemachineFindState(machine, name));
+  if (state == nullptr) {
+    return false;
+  }
+
+  const auto propertyName =
+    QByteArray(property.data(), static_cast<int>(property.size()));
+  state->assignProperty(&object, propertyName.constData(), value);
+  return true;
+}
+
+}
+}
+
+#endif
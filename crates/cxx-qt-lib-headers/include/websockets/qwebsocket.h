@@ -0,0 +1,87 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_WEBSOCKETS_FEATURE
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QByteArray>
+#include <QtCore/QString>
+#include <QtNetwork/QAbstractSocket>
+#include <QtWebSockets/QWebSocket>
+
+#include "rust/cxx.h"
+
+#include "cxx-qt-lib/common.h"
+#include "cxx-qt-lib/qmetaobjectconnection.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QAbstractSocketSocketState = QAbstractSocket::SocketState;
+
+::std::unique_ptr<QWebSocket>
+qwebsocketNew();
+void
+qwebsocketClose(QWebSocket& socket);
+::std::int64_t
+qwebsocketSendBinaryMessage(QWebSocket& socket, const QByteArray& data);
+::std::int64_t
+qwebsocketSendTextMessage(QWebSocket& socket, const QString& message);
+
+template<typename T>
+QMetaObjectConnection
+qwebsocketOnBinaryMessageReceived(QWebSocket& socket, ::rust::Box<T> callback)
+{
+  auto shared = shareCallback(::std::move(callback));
+  return QObject::connect(
+    &socket,
+    &QWebSocket::binaryMessageReceived,
+    &socket,
+    [shared](const QByteArray& data) { (*shared)->call(data); });
+}
+
+template<typename T>
+QMetaObjectConnection
+qwebsocketOnConnected(QWebSocket& socket, ::rust::Box<T> callback)
+{
+  auto shared = shareCallback(::std::move(callback));
+  return QObject::connect(&socket,
+                          &QWebSocket::connected,
+                          &socket,
+                          [shared]() { (*shared)->call(); });
+}
+
+template<typename T>
+QMetaObjectConnection
+qwebsocketOnDisconnected(QWebSocket& socket, ::rust::Box<T> callback)
+{
+  auto shared = shareCallback(::std::move(callback));
+  return QObject::connect(&socket,
+                          &QWebSocket::disconnected,
+                          &socket,
+                          [shared]() { (*shared)->call(); });
+}
+
+template<typename T>
+QMetaObjectConnection
+qwebsocketOnTextMessageReceived(QWebSocket& socket, ::rust::Box<T> callback)
+{
+  auto shared = shareCallback(::std::move(callback));
+  return QObject::connect(
+    &socket,
+    &QWebSocket::textMessageReceived,
+    &socket,
+    [shared](const QString& message) { (*shared)->call(message); });
+}
+
+}
+}
+
+#endif
@@ -0,0 +1,50 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_WEBSOCKETS_FEATURE
+
+#include <cstdint>
+#include <memory>
+
+#include <QtCore/QString>
+#include <QtWebSockets/QWebSocket>
+#include <QtWebSockets/QWebSocketServer>
+
+#include "rust/cxx.h"
+
+#include "cxx-qt-lib/common.h"
+#include "cxx-qt-lib/qmetaobjectconnection.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QWebSocketServerSslMode = QWebSocketServer::SslMode;
+
+::std::unique_ptr<QWebSocketServer>
+qwebsocketserverNew(const QString& serverName, QWebSocketServerSslMode mode);
+bool
+qwebsocketserverListen(QWebSocketServer& server, ::std::uint16_t port);
+::std::unique_ptr<QWebSocket>
+qwebsocketserverNextPendingConnection(QWebSocketServer& server);
+
+template<typename T>
+QMetaObjectConnection
+qwebsocketserverOnNewConnection(QWebSocketServer& server,
+                                ::rust::Box<T> callback)
+{
+  auto shared = shareCallback(::std::move(callback));
+  return QObject::connect(&server,
+                          &QWebSocketServer::newConnection,
+                          &server,
+                          [shared]() { (*shared)->call(); });
+}
+
+}
+}
+
+#endif
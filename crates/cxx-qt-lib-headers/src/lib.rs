@@ -12,27 +12,74 @@
 
 use std::{fs::File, io::Write, path::Path};
 
-/// Write the cxx-qt-lib headers to the specified directory.
-pub fn write_headers(directory: impl AsRef<Path>) {
-    let directory = directory.as_ref();
-    std::fs::create_dir_all(directory).expect("Could not create cxx-qt-lib header directory");
-    for (file_contents, file_name) in [
+/// The contents and file names of the cxx-qt-lib headers which are enabled by the features of this crate
+///
+/// This includes `cxx-qt-lib.h` which includes all of the other headers and declares `rust::cxxqtlib1::init()`,
+/// so that build systems other than Cargo can use the cxx-qt-lib types from C++.
+pub fn headers() -> &'static [(&'static str, &'static str)] {
+    &[
+        (
+            include_str!("../include/core/qabstractitemmodel.h"),
+            "qabstractitemmodel.h",
+        ),
+        (
+            include_str!("../include/core/qanimationgroup.h"),
+            "qanimationgroup.h",
+        ),
         (include_str!("../include/core/qbytearray.h"), "qbytearray.h"),
+        (include_str!("../include/core/qcborarray.h"), "qcborarray.h"),
+        (include_str!("../include/core/qcbormap.h"), "qcbormap.h"),
+        (include_str!("../include/core/qcborvalue.h"), "qcborvalue.h"),
+        (
+            include_str!("../include/core/qconcatenatetablesproxymodel.h"),
+            "qconcatenatetablesproxymodel.h",
+        ),
         (
             include_str!("../include/core/qcoreapplication.h"),
             "qcoreapplication.h",
         ),
+        (
+            include_str!("../include/core/qcryptographichash.h"),
+            "qcryptographichash.h",
+        ),
+        (
+            include_str!("../include/core/qdatastream.h"),
+            "qdatastream.h",
+        ),
         (include_str!("../include/core/qdate.h"), "qdate.h"),
         (include_str!("../include/core/qdatetime.h"), "qdatetime.h"),
+        (
+            include_str!("../include/core/qeasingcurve.h"),
+            "qeasingcurve.h",
+        ),
+        (include_str!("../include/core/qevent.h"), "qevent.h"),
+        (
+            include_str!("../include/core/qeventfilter.h"),
+            "qeventfilter.h",
+        ),
+        (
+            include_str!("../include/core/qfilesystemwatcher.h"),
+            "qfilesystemwatcher.h",
+        ),
         (include_str!("../include/core/qhash.h"), "qhash.h"),
+        (
+            include_str!("../include/core/qidentityproxymodel.h"),
+            "qidentityproxymodel.h",
+        ),
         (include_str!("../include/core/qlist.h"), "qlist.h"),
         (
             include_str!("../include/core/qlist_qvector.h"),
             "qlist_qvector.h",
         ),
+        (include_str!("../include/core/qlockfile.h"), "qlockfile.h"),
+        (include_str!("../include/core/qtlogging.h"), "qtlogging.h"),
         (include_str!("../include/core/qmap.h"), "qmap.h"),
         (include_str!("../include/core/qmargins.h"), "qmargins.h"),
         (include_str!("../include/core/qmarginsf.h"), "qmarginsf.h"),
+        (
+            include_str!("../include/core/qmessageauthenticationcode.h"),
+            "qmessageauthenticationcode.h",
+        ),
         (
             include_str!("../include/core/qmetaobjectconnection.h"),
             "qmetaobjectconnection.h",
@@ -41,15 +88,35 @@ pub fn write_headers(directory: impl AsRef<Path>) {
             include_str!("../include/core/qmodelindex.h"),
             "qmodelindex.h",
         ),
+        (
+            include_str!("../include/core/qobjectconnect.h"),
+            "qobjectconnect.h",
+        ),
+        (
+            include_str!("../include/core/qparallelanimationgroup.h"),
+            "qparallelanimationgroup.h",
+        ),
         (
             include_str!("../include/core/qpersistentmodelindex.h"),
             "qpersistentmodelindex.h",
         ),
         (include_str!("../include/core/qpoint.h"), "qpoint.h"),
         (include_str!("../include/core/qpointf.h"), "qpointf.h"),
+        (
+            include_str!("../include/core/qpropertyanimation.h"),
+            "qpropertyanimation.h",
+        ),
         (include_str!("../include/core/qrect.h"), "qrect.h"),
         (include_str!("../include/core/qrectf.h"), "qrectf.h"),
+        (
+            include_str!("../include/core/qsequentialanimationgroup.h"),
+            "qsequentialanimationgroup.h",
+        ),
         (include_str!("../include/core/qset.h"), "qset.h"),
+        (
+            include_str!("../include/core/qsharedmemory.h"),
+            "qsharedmemory.h",
+        ),
         (include_str!("../include/core/qsize.h"), "qsize.h"),
         (include_str!("../include/core/qsizef.h"), "qsizef.h"),
         (include_str!("../include/core/qstring.h"), "qstring.h"),
@@ -57,12 +124,52 @@ pub fn write_headers(directory: impl AsRef<Path>) {
             include_str!("../include/core/qstringlist.h"),
             "qstringlist.h",
         ),
+        (
+            include_str!("../include/core/qstringlistmodel.h"),
+            "qstringlistmodel.h",
+        ),
+        (
+            include_str!("../include/core/qsystemsemaphore.h"),
+            "qsystemsemaphore.h",
+        ),
         (include_str!("../include/core/qt.h"), "qt.h"),
+        (
+            include_str!("../include/core/qtemporarydir.h"),
+            "qtemporarydir.h",
+        ),
+        (
+            include_str!("../include/core/qtemporaryfile.h"),
+            "qtemporaryfile.h",
+        ),
         (include_str!("../include/core/qtime.h"), "qtime.h"),
         (include_str!("../include/core/qtimezone.h"), "qtimezone.h"),
         (include_str!("../include/core/qurl.h"), "qurl.h"),
         (include_str!("../include/core/qvariant.h"), "qvariant.h"),
+        (
+            include_str!("../include/core/qvariantanimation.h"),
+            "qvariantanimation.h",
+        ),
         (include_str!("../include/core/qvector.h"), "qvector.h"),
+        #[cfg(feature = "qt_bluetooth")]
+        (
+            include_str!("../include/bluetooth/qbluetoothdevicediscoveryagent.h"),
+            "qbluetoothdevicediscoveryagent.h",
+        ),
+        #[cfg(feature = "qt_bluetooth")]
+        (
+            include_str!("../include/bluetooth/qbluetoothdeviceinfo.h"),
+            "qbluetoothdeviceinfo.h",
+        ),
+        #[cfg(feature = "qt_bluetooth")]
+        (
+            include_str!("../include/bluetooth/qlowenergycontroller.h"),
+            "qlowenergycontroller.h",
+        ),
+        #[cfg(feature = "qt_bluetooth")]
+        (
+            include_str!("../include/bluetooth/qlowenergyservice.h"),
+            "qlowenergyservice.h",
+        ),
         #[cfg(feature = "qt_gui")]
         (include_str!("../include/gui/qcolor.h"), "qcolor.h"),
         #[cfg(feature = "qt_gui")]
@@ -71,11 +178,64 @@ pub fn write_headers(directory: impl AsRef<Path>) {
             "qguiapplication.h",
         ),
         #[cfg(feature = "qt_gui")]
+        (include_str!("../include/gui/qimage.h"), "qimage.h"),
+        #[cfg(feature = "qt_gui")]
+        (include_str!("../include/gui/qkeyevent.h"), "qkeyevent.h"),
+        #[cfg(feature = "qt_gui")]
+        (include_str!("../include/gui/qmatrix4x4.h"), "qmatrix4x4.h"),
+        #[cfg(feature = "qt_gui")]
+        (
+            include_str!("../include/gui/qmouseevent.h"),
+            "qmouseevent.h",
+        ),
+        #[cfg(feature = "qt_gui")]
+        (include_str!("../include/gui/qpixmap.h"), "qpixmap.h"),
+        #[cfg(feature = "qt_gui")]
+        (
+            include_str!("../include/gui/qquaternion.h"),
+            "qquaternion.h",
+        ),
+        #[cfg(feature = "qt_gui")]
+        (
+            include_str!("../include/gui/qshortcutevent.h"),
+            "qshortcutevent.h",
+        ),
+        #[cfg(feature = "qt_gui")]
         (include_str!("../include/gui/qvector2d.h"), "qvector2d.h"),
         #[cfg(feature = "qt_gui")]
         (include_str!("../include/gui/qvector3d.h"), "qvector3d.h"),
         #[cfg(feature = "qt_gui")]
         (include_str!("../include/gui/qvector4d.h"), "qvector4d.h"),
+        #[cfg(feature = "qt_keychain")]
+        (
+            include_str!("../include/keychain/qkeychain.h"),
+            "qkeychain.h",
+        ),
+        #[cfg(feature = "qt_network")]
+        (
+            include_str!("../include/network/qlocalserver.h"),
+            "qlocalserver.h",
+        ),
+        #[cfg(feature = "qt_network")]
+        (
+            include_str!("../include/network/qlocalsocket.h"),
+            "qlocalsocket.h",
+        ),
+        #[cfg(feature = "qt_network")]
+        (
+            include_str!("../include/network/qnetworkinformation.h"),
+            "qnetworkinformation.h",
+        ),
+        #[cfg(feature = "qt_ssl")]
+        (
+            include_str!("../include/network/qsslcertificate.h"),
+            "qsslcertificate.h",
+        ),
+        #[cfg(feature = "qt_ssl")]
+        (
+            include_str!("../include/network/qsslconfiguration.h"),
+            "qsslconfiguration.h",
+        ),
         #[cfg(feature = "qt_qml")]
         (
             include_str!("../include/qml/qqmlapplicationengine.h"),
@@ -83,8 +243,70 @@ pub fn write_headers(directory: impl AsRef<Path>) {
         ),
         #[cfg(feature = "qt_qml")]
         (include_str!("../include/qml/qqmlengine.h"), "qqmlengine.h"),
+        #[cfg(feature = "qt_qml")]
+        (include_str!("../include/qml/qqmlerror.h"), "qqmlerror.h"),
+        #[cfg(feature = "qt_quick3d")]
+        (
+            include_str!("../include/quick3d/qquick3dgeometry.h"),
+            "qquick3dgeometry.h",
+        ),
+        #[cfg(feature = "qt_sensors")]
+        (
+            include_str!("../include/sensors/qaccelerometer.h"),
+            "qaccelerometer.h",
+        ),
+        #[cfg(feature = "qt_sensors")]
+        (
+            include_str!("../include/sensors/qgyroscope.h"),
+            "qgyroscope.h",
+        ),
+        #[cfg(feature = "qt_serialport")]
+        (
+            include_str!("../include/serialport/qserialport.h"),
+            "qserialport.h",
+        ),
+        #[cfg(feature = "qt_sql")]
+        (
+            include_str!("../include/sql/qsqldatabase.h"),
+            "qsqldatabase.h",
+        ),
+        #[cfg(feature = "qt_sql")]
+        (include_str!("../include/sql/qsqlquery.h"), "qsqlquery.h"),
+        #[cfg(feature = "qt_sql")]
+        (
+            include_str!("../include/sql/qsqlquerymodel.h"),
+            "qsqlquerymodel.h",
+        ),
+        #[cfg(feature = "qt_sql")]
+        (
+            include_str!("../include/sql/qsqltablemodel.h"),
+            "qsqltablemodel.h",
+        ),
+        #[cfg(feature = "qt_statemachine")]
+        (
+            include_str!("../include/statemachine/qstatemachine.h"),
+            "qstatemachine.h",
+        ),
+        #[cfg(feature = "qt_websockets")]
+        (
+            include_str!("../include/websockets/qwebsocket.h"),
+            "qwebsocket.h",
+        ),
+        #[cfg(feature = "qt_websockets")]
+        (
+            include_str!("../include/websockets/qwebsocketserver.h"),
+            "qwebsocketserver.h",
+        ),
         (include_str!("../include/common.h"), "common.h"),
-    ] {
+        (include_str!("../include/cxx-qt-lib.h"), "cxx-qt-lib.h"),
+    ]
+}
+
+/// Write the cxx-qt-lib headers to the specified directory.
+pub fn write_headers(directory: impl AsRef<Path>) {
+    let directory = directory.as_ref();
+    std::fs::create_dir_all(directory).expect("Could not create cxx-qt-lib header directory");
+    for (file_contents, file_name) in headers() {
         // Note that we do not need rerun-if-changed for these files
         // as include_str causes a rerun when the header changes
         // and the files are always written to the target.
@@ -93,3 +315,28 @@ pub fn write_headers(directory: impl AsRef<Path>) {
         write!(header, "{file_contents}").expect("Could not write cxx-qt-lib header");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_headers_amalgamated() {
+        let (amalgamated, _) = headers()
+            .iter()
+            .find(|(_, file_name)| *file_name == "cxx-qt-lib.h")
+            .unwrap();
+
+        // Every header is included by cxx-qt-lib.h, apart from the internal ones
+        for (_, file_name) in headers() {
+            if ["common.h", "cxx-qt-lib.h", "qlist_qvector.h"].contains(file_name) {
+                continue;
+            }
+
+            assert!(
+                amalgamated.contains(&format!("#include \"cxx-qt-lib/{file_name}\"")),
+                "{file_name} is not included in cxx-qt-lib.h"
+            );
+        }
+    }
+}
@@ -3,23 +3,70 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+/// Stop the build script with an error for a Qt installation which could not be used,
+/// rather than a panic with a backtrace
+fn exit_with_qt_error(err: qt_build_utils::QtBuildError) -> ! {
+    eprintln!("error: Could not find a usable Qt installation: {err}");
+    std::process::exit(1)
+}
+
 fn main() {
+    let feature_qt_bluetooth_enabled = std::env::var("CARGO_FEATURE_QT_BLUETOOTH").is_ok();
     let feature_qt_gui_enabled = std::env::var("CARGO_FEATURE_QT_GUI").is_ok();
+    let feature_qt_keychain_enabled = std::env::var("CARGO_FEATURE_QT_KEYCHAIN").is_ok();
+    let feature_qt_network_enabled = std::env::var("CARGO_FEATURE_QT_NETWORK").is_ok();
     let feature_qt_qml_enabled = std::env::var("CARGO_FEATURE_QT_QML").is_ok();
+    let feature_qt_quick3d_enabled = std::env::var("CARGO_FEATURE_QT_QUICK3D").is_ok();
+    let feature_qt_sensors_enabled = std::env::var("CARGO_FEATURE_QT_SENSORS").is_ok();
+    let feature_qt_serialport_enabled = std::env::var("CARGO_FEATURE_QT_SERIALPORT").is_ok();
+    let feature_qt_sql_enabled = std::env::var("CARGO_FEATURE_QT_SQL").is_ok();
+    let feature_qt_ssl_enabled = std::env::var("CARGO_FEATURE_QT_SSL").is_ok();
+    let feature_qt_statemachine_enabled = std::env::var("CARGO_FEATURE_QT_STATEMACHINE").is_ok();
+    let feature_qt_websockets_enabled = std::env::var("CARGO_FEATURE_QT_WEBSOCKETS").is_ok();
     let emscripten_targeted = match std::env::var("CARGO_CFG_TARGET_OS") {
         Ok(val) => val == "emscripten",
         Err(_) => false,
     };
 
     let mut qt_modules = vec!["Core".to_owned()];
+    if feature_qt_bluetooth_enabled {
+        qt_modules.push("Bluetooth".to_owned());
+    }
     if feature_qt_gui_enabled {
         qt_modules.push("Gui".to_owned());
     }
+    if feature_qt_network_enabled {
+        qt_modules.push("Network".to_owned());
+    }
     if feature_qt_qml_enabled {
         qt_modules.push("Qml".to_owned());
     }
+    if feature_qt_quick3d_enabled {
+        qt_modules.push("Quick3D".to_owned());
+    }
+    if feature_qt_sensors_enabled {
+        qt_modules.push("Sensors".to_owned());
+    }
+    if feature_qt_serialport_enabled {
+        qt_modules.push("SerialPort".to_owned());
+    }
+    if feature_qt_sql_enabled {
+        qt_modules.push("Sql".to_owned());
+    }
+    if feature_qt_websockets_enabled {
+        qt_modules.push("WebSockets".to_owned());
+    }
 
-    let qtbuild = qt_build_utils::QtBuild::new(qt_modules).expect("Could not find Qt installation");
+    let mut qtbuild = qt_build_utils::QtBuild::new(qt_modules.clone())
+        .unwrap_or_else(|err| exit_with_qt_error(err));
+
+    // QStateMachine moved from Qt Core to its own module in Qt 6,
+    // which is only known once the version of the Qt installation has been found
+    if feature_qt_statemachine_enabled && qtbuild.version().major >= 6 {
+        qt_modules.push("StateMachine".to_owned());
+        qtbuild =
+            qt_build_utils::QtBuild::new(qt_modules).unwrap_or_else(|err| exit_with_qt_error(err));
+    }
 
     // Required for tests
     qt_build_utils::setup_linker();
@@ -32,11 +79,24 @@ fn main() {
     );
 
     let mut rust_bridges = vec![
+        "core/qabstractitemmodel",
+        "core/qanimationgroup",
         "core/qbytearray",
+        "core/qcborarray",
+        "core/qcbormap",
+        "core/qcborvalue",
+        "core/qconcatenatetablesproxymodel",
         "core/qcoreapplication",
+        "core/qcryptographichash",
+        "core/qdatastream/mod",
         "core/qdate",
+        "core/qeasingcurve",
+        "core/qevent",
+        "core/qeventfilter",
+        "core/qfilesystemwatcher",
         "core/qhash/qhash_i32_qbytearray",
         "core/qhash/qhash_qstring_qvariant",
+        "core/qidentityproxymodel",
         "core/qlist/qlist_bool",
         "core/qlist/qlist_f32",
         "core/qlist/qlist_f64",
@@ -48,6 +108,7 @@ fn main() {
         "core/qlist/qlist_qdate",
         "core/qlist/qlist_qmargins",
         "core/qlist/qlist_qmarginsf",
+        "core/qlist/qlist_qmodelindex",
         "core/qlist/qlist_qpersistentmodelindex",
         "core/qlist/qlist_qpoint",
         "core/qlist/qlist_qpointf",
@@ -63,16 +124,22 @@ fn main() {
         "core/qlist/qlist_u16",
         "core/qlist/qlist_u32",
         "core/qlist/qlist_u64",
+        "core/qlockfile",
         "core/qmap/qmap_qstring_qvariant",
         "core/qmargins",
         "core/qmarginsf",
+        "core/qmessageauthenticationcode",
         "core/qmetaobjectconnection",
         "core/qmodelindex",
+        "core/qobjectconnect",
+        "core/qparallelanimationgroup",
         "core/qpersistentmodelindex",
         "core/qpoint",
         "core/qpointf",
+        "core/qpropertyanimation",
         "core/qrect",
         "core/qrectf",
+        "core/qsequentialanimationgroup",
         "core/qset/qset_bool",
         "core/qset/qset_f32",
         "core/qset/qset_f64",
@@ -94,7 +161,11 @@ fn main() {
         "core/qsizef",
         "core/qstring",
         "core/qstringlist",
+        "core/qstringlistmodel",
         "core/qt",
+        "core/qtlogging",
+        "core/qtemporarydir",
+        "core/qtemporaryfile",
         "core/qtime",
         "core/qurl",
         "core/qvariant/mod",
@@ -123,6 +194,7 @@ fn main() {
         "core/qvariant/qvariant_u16",
         "core/qvariant/qvariant_u32",
         "core/qvariant/qvariant_u64",
+        "core/qvariantanimation",
         "core/qvector/qvector_bool",
         "core/qvector/qvector_f32",
         "core/qvector/qvector_f64",
@@ -158,20 +230,93 @@ fn main() {
             "core/qvector/qvector_qcolor",
             "gui/qcolor",
             "gui/qguiapplication",
+            "gui/qimage",
+            "gui/qkeyevent",
+            "gui/qmatrix4x4",
+            "gui/qmouseevent",
+            "gui/qpixmap",
+            "gui/qquaternion",
+            "gui/qshortcutevent",
             "gui/qvector2d",
             "gui/qvector3d",
             "gui/qvector4d",
         ]);
     }
 
+    if feature_qt_bluetooth_enabled {
+        rust_bridges.extend([
+            "bluetooth/qbluetoothdevicediscoveryagent",
+            "bluetooth/qbluetoothdeviceinfo",
+            "bluetooth/qlowenergycontroller",
+            "bluetooth/qlowenergyservice",
+        ]);
+    }
+
+    if feature_qt_keychain_enabled {
+        rust_bridges.extend(["keychain/qkeychain"]);
+    }
+
+    if feature_qt_network_enabled {
+        rust_bridges.extend(["network/qlocalserver", "network/qlocalsocket"]);
+
+        // QNetworkInformation was added in Qt 6
+        if qtbuild.version().major >= 6 {
+            rust_bridges.extend(["network/qnetworkinformation"]);
+        }
+    }
+
+    if feature_qt_ssl_enabled {
+        rust_bridges.extend([
+            "core/qlist/qlist_qsslcertificate",
+            "network/qsslcertificate",
+            "network/qsslconfiguration",
+        ]);
+    }
+
     if feature_qt_qml_enabled {
-        rust_bridges.extend(["qml/qqmlapplicationengine", "qml/qqmlengine"]);
+        rust_bridges.extend([
+            "qml/qqmlapplicationengine",
+            "qml/qqmlengine",
+            "qml/qqmlerror",
+        ]);
+    }
+
+    if feature_qt_quick3d_enabled {
+        rust_bridges.extend(["quick3d/qquick3dgeometry"]);
+    }
+
+    if feature_qt_sensors_enabled {
+        rust_bridges.extend(["sensors/qaccelerometer", "sensors/qgyroscope"]);
+    }
+
+    if feature_qt_serialport_enabled {
+        rust_bridges.extend(["serialport/qserialport"]);
+    }
+
+    if feature_qt_sql_enabled {
+        rust_bridges.extend([
+            "sql/qsqldatabase",
+            "sql/qsqlquery",
+            "sql/qsqlquerymodel",
+            "sql/qsqltablemodel",
+        ]);
+    }
+
+    if feature_qt_statemachine_enabled {
+        rust_bridges.extend(["statemachine/qstatemachine"]);
+    }
+
+    if feature_qt_websockets_enabled {
+        rust_bridges.extend(["websockets/qwebsocket", "websockets/qwebsocketserver"]);
     }
 
     if !emscripten_targeted {
         rust_bridges.extend([
             "core/qdatetime",
+            "core/qsharedmemory",
+            "core/qsystemsemaphore",
             "core/qtimezone",
+            "core/qdatastream/qdatastream_qdatetime",
             "core/qlist/qlist_qdatetime",
             "core/qset/qset_qdatetime",
             "core/qvariant/qvariant_qdatetime",
@@ -195,29 +340,51 @@ fn main() {
     qtbuild.cargo_link_libraries(&mut builder);
 
     let mut cpp_files = vec![
+        "core/qanimationgroup",
         "core/qbytearray",
+        "core/qcborarray",
+        "core/qcbormap",
+        "core/qcborvalue",
+        "core/qconcatenatetablesproxymodel",
         "core/qcoreapplication",
+        "core/qcryptographichash",
+        "core/qdatastream/qdatastream",
         "core/qdate",
+        "core/qeasingcurve",
+        "core/qevent",
+        "core/qfilesystemwatcher",
         "core/qhash/qhash",
+        "core/qidentityproxymodel",
         "core/qlist/qlist",
+        "core/qlockfile",
         "core/qmap/qmap",
         "core/qmargins",
         "core/qmarginsf",
+        "core/qmessageauthenticationcode",
         "core/qmetaobjectconnection",
         "core/qmodelindex",
+        "core/qobjectconnect",
+        "core/qparallelanimationgroup",
         "core/qpersistentmodelindex",
         "core/qpoint",
         "core/qpointf",
         "core/qrect",
         "core/qrectf",
+        "core/qsequentialanimationgroup",
         "core/qset/qset",
         "core/qsize",
         "core/qsizef",
         "core/qstring",
         "core/qstringlist",
+        "core/qstringlistmodel",
+        "core/qt",
+        "core/qtlogging",
+        "core/qtemporarydir",
+        "core/qtemporaryfile",
         "core/qtime",
         "core/qurl",
         "core/qvariant/qvariant",
+        "core/qvariantanimation",
         "core/qvector/qvector",
     ];
 
@@ -225,18 +392,80 @@ fn main() {
         cpp_files.extend([
             "gui/qcolor",
             "gui/qguiapplication",
+            "gui/qimage",
+            "gui/qkeyevent",
+            "gui/qmatrix4x4",
+            "gui/qmouseevent",
+            "gui/qpixmap",
+            "gui/qquaternion",
+            "gui/qshortcutevent",
             "gui/qvector2d",
             "gui/qvector3d",
             "gui/qvector4d",
         ]);
     }
 
+    if feature_qt_bluetooth_enabled {
+        cpp_files.extend([
+            "bluetooth/qbluetoothdevicediscoveryagent",
+            "bluetooth/qbluetoothdeviceinfo",
+            "bluetooth/qlowenergycontroller",
+            "bluetooth/qlowenergyservice",
+        ]);
+    }
+
+    if feature_qt_network_enabled {
+        cpp_files.extend(["network/qlocalserver", "network/qlocalsocket"]);
+
+        if qtbuild.version().major >= 6 {
+            cpp_files.extend(["network/qnetworkinformation"]);
+        }
+    }
+
+    if feature_qt_ssl_enabled {
+        cpp_files.extend(["network/qsslcertificate", "network/qsslconfiguration"]);
+    }
+
     if feature_qt_qml_enabled {
-        cpp_files.extend(["qml/qqmlapplicationengine", "qml/qqmlengine"]);
+        cpp_files.extend([
+            "qml/qqmlapplicationengine",
+            "qml/qqmlengine",
+            "qml/qqmlerror",
+        ]);
+    }
+
+    if feature_qt_sensors_enabled {
+        cpp_files.extend(["sensors/qaccelerometer", "sensors/qgyroscope"]);
+    }
+
+    if feature_qt_serialport_enabled {
+        cpp_files.extend(["serialport/qserialport"]);
+    }
+
+    if feature_qt_sql_enabled {
+        cpp_files.extend([
+            "sql/qsqldatabase",
+            "sql/qsqlquery",
+            "sql/qsqlquerymodel",
+            "sql/qsqltablemodel",
+        ]);
+    }
+
+    if feature_qt_statemachine_enabled {
+        cpp_files.extend(["statemachine/qstatemachine"]);
+    }
+
+    if feature_qt_websockets_enabled {
+        cpp_files.extend(["websockets/qwebsocket", "websockets/qwebsocketserver"]);
     }
 
     if !emscripten_targeted {
-        cpp_files.extend(["core/qdatetime", "core/qtimezone"]);
+        cpp_files.extend([
+            "core/qdatetime",
+            "core/qsharedmemory",
+            "core/qsystemsemaphore",
+            "core/qtimezone",
+        ]);
     }
 
     for cpp_file in &cpp_files {
@@ -252,16 +481,76 @@ fn main() {
     cxx_qt_lib_headers::write_headers(format!("{out_dir}/cxx-qt-lib"));
     builder.include(out_dir);
 
+    // Enable Qt Bluetooth in C++ if the feature is enabled
+    if feature_qt_bluetooth_enabled {
+        builder.define("CXX_QT_BLUETOOTH_FEATURE", None);
+    }
+
     // Enable Qt Gui in C++ if the feature is enabled
     if feature_qt_gui_enabled {
         builder.define("CXX_QT_GUI_FEATURE", None);
     }
 
+    // Enable QtKeychain in C++ if the feature is enabled
+    //
+    // QtKeychain is not a Qt module so it is linked separately, QTKEYCHAIN_DIR can be
+    // set to the prefix it is installed into if it is not in the system paths
+    if feature_qt_keychain_enabled {
+        builder.define("CXX_QT_KEYCHAIN_FEATURE", None);
+
+        println!("cargo:rerun-if-env-changed=QTKEYCHAIN_DIR");
+        if let Ok(qtkeychain_dir) = std::env::var("QTKEYCHAIN_DIR") {
+            builder.include(format!("{qtkeychain_dir}/include"));
+            println!("cargo:rustc-link-search={qtkeychain_dir}/lib");
+        }
+        println!("cargo:rustc-link-lib=qt{}keychain", qtbuild.version().major);
+    }
+
+    // Enable Qt Network in C++ if the feature is enabled
+    if feature_qt_network_enabled {
+        builder.define("CXX_QT_NETWORK_FEATURE", None);
+    }
+
+    // Enable the TLS types of Qt Network in C++ if the feature is enabled
+    if feature_qt_ssl_enabled {
+        builder.define("CXX_QT_SSL_FEATURE", None);
+    }
+
     // Enable Qt Qml in C++ if the feature is enabled
     if feature_qt_gui_enabled {
         builder.define("CXX_QT_QML_FEATURE", None);
     }
 
+    // Enable Qt Quick3D in C++ if the feature is enabled
+    if feature_qt_quick3d_enabled {
+        builder.define("CXX_QT_QUICK3D_FEATURE", None);
+    }
+
+    // Enable Qt Sensors in C++ if the feature is enabled
+    if feature_qt_sensors_enabled {
+        builder.define("CXX_QT_SENSORS_FEATURE", None);
+    }
+
+    // Enable Qt SerialPort in C++ if the feature is enabled
+    if feature_qt_serialport_enabled {
+        builder.define("CXX_QT_SERIALPORT_FEATURE", None);
+    }
+
+    // Enable Qt SQL in C++ if the feature is enabled
+    if feature_qt_sql_enabled {
+        builder.define("CXX_QT_SQL_FEATURE", None);
+    }
+
+    // Enable Qt StateMachine in C++ if the feature is enabled
+    if feature_qt_statemachine_enabled {
+        builder.define("CXX_QT_STATEMACHINE_FEATURE", None);
+    }
+
+    // Enable Qt WebSockets in C++ if the feature is enabled
+    if feature_qt_websockets_enabled {
+        builder.define("CXX_QT_WEBSOCKETS_FEATURE", None);
+    }
+
     // Note, ensure our settings stay in sync across cxx-qt-build and cxx-qt-lib
     builder.cpp(true);
     // MSVC
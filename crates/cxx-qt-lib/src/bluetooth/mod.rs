@@ -0,0 +1,16 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+mod qbluetoothdevicediscoveryagent;
+pub use qbluetoothdevicediscoveryagent::QBluetoothDeviceDiscoveryAgent;
+
+mod qbluetoothdeviceinfo;
+pub use qbluetoothdeviceinfo::QBluetoothDeviceInfo;
+
+mod qlowenergycontroller;
+pub use qlowenergycontroller::{QLowEnergyController, QLowEnergyControllerControllerState};
+
+mod qlowenergyservice;
+pub use qlowenergyservice::{QLowEnergyService, QLowEnergyServiceServiceError};
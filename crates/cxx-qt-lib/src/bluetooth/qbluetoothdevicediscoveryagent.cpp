@@ -0,0 +1,33 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_BLUETOOTH_FEATURE
+#include "cxx-qt-lib/qbluetoothdevicediscoveryagent.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QBluetoothDeviceDiscoveryAgent>
+qbluetoothdevicediscoveryagentNew()
+{
+  return ::std::make_unique<QBluetoothDeviceDiscoveryAgent>();
+}
+
+void
+qbluetoothdevicediscoveryagentStart(QBluetoothDeviceDiscoveryAgent& agent,
+                                    bool lowEnergyOnly)
+{
+  if (lowEnergyOnly) {
+    agent.start(QBluetoothDeviceDiscoveryAgent::LowEnergyMethod);
+  } else {
+    agent.start();
+  }
+}
+
+}
+}
+#endif
@@ -0,0 +1,215 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbluetoothdevicediscoveryagent.h");
+
+        include!("cxx-qt-lib/qbluetoothdeviceinfo.h");
+        type QBluetoothDeviceInfo = crate::QBluetoothDeviceInfo;
+        include!("cxx-qt-lib/qmetaobjectconnection.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QMetaObjectConnection = crate::QMetaObjectConnection;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        /// The QBluetoothDeviceDiscoveryAgent class discovers the Bluetooth devices nearby.
+        ///
+        /// Devices are delivered to Rust closures on the thread of the agent by the Qt event loop.
+        ///
+        /// ```ignore
+        /// let mut agent = QBluetoothDeviceDiscoveryAgent::new();
+        /// agent
+        ///     .pin_mut()
+        ///     .on_device_discovered(|device| println!("Found {}", device.name()))
+        ///     .release();
+        /// agent.pin_mut().start(true);
+        /// ```
+        type QBluetoothDeviceDiscoveryAgent;
+
+        /// Returns a human-readable description of the last error.
+        #[rust_name = "error_string"]
+        fn errorString(self: &QBluetoothDeviceDiscoveryAgent) -> QString;
+
+        /// Returns true if the agent is currently discovering Bluetooth devices, otherwise returns false.
+        #[rust_name = "is_active"]
+        fn isActive(self: &QBluetoothDeviceDiscoveryAgent) -> bool;
+
+        /// Returns the maximum search time for Bluetooth Low Energy device search in milliseconds,
+        /// 0 means that the search continues until it is stopped.
+        #[rust_name = "low_energy_discovery_timeout"]
+        fn lowEnergyDiscoveryTimeout(self: &QBluetoothDeviceDiscoveryAgent) -> i32;
+
+        /// Sets the maximum search time for Bluetooth Low Energy device search in milliseconds.
+        #[rust_name = "set_low_energy_discovery_timeout"]
+        fn setLowEnergyDiscoveryTimeout(self: Pin<&mut QBluetoothDeviceDiscoveryAgent>, msecs: i32);
+
+        /// Stops the device discovery.
+        fn stop(self: Pin<&mut QBluetoothDeviceDiscoveryAgent>);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qbluetoothdevicediscoveryagent_new"]
+        fn qbluetoothdevicediscoveryagentNew() -> UniquePtr<QBluetoothDeviceDiscoveryAgent>;
+        #[doc(hidden)]
+        #[rust_name = "qbluetoothdevicediscoveryagent_on_device_discovered"]
+        fn qbluetoothdevicediscoveryagentOnDeviceDiscovered(
+            agent: Pin<&mut QBluetoothDeviceDiscoveryAgent>,
+            callback: Box<QBluetoothDeviceDiscoveryAgentDeviceCallback>,
+        ) -> QMetaObjectConnection;
+        #[doc(hidden)]
+        #[rust_name = "qbluetoothdevicediscoveryagent_on_error_occurred"]
+        fn qbluetoothdevicediscoveryagentOnErrorOccurred(
+            agent: Pin<&mut QBluetoothDeviceDiscoveryAgent>,
+            callback: Box<QBluetoothDeviceDiscoveryAgentErrorCallback>,
+        ) -> QMetaObjectConnection;
+        #[doc(hidden)]
+        #[rust_name = "qbluetoothdevicediscoveryagent_on_finished"]
+        fn qbluetoothdevicediscoveryagentOnFinished(
+            agent: Pin<&mut QBluetoothDeviceDiscoveryAgent>,
+            callback: Box<QBluetoothDeviceDiscoveryAgentCallback>,
+        ) -> QMetaObjectConnection;
+        #[doc(hidden)]
+        #[rust_name = "qbluetoothdevicediscoveryagent_start"]
+        fn qbluetoothdevicediscoveryagentStart(
+            agent: Pin<&mut QBluetoothDeviceDiscoveryAgent>,
+            low_energy_only: bool,
+        );
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        type QBluetoothDeviceDiscoveryAgentCallback;
+        #[doc(hidden)]
+        type QBluetoothDeviceDiscoveryAgentDeviceCallback;
+        #[doc(hidden)]
+        type QBluetoothDeviceDiscoveryAgentErrorCallback;
+
+        #[doc(hidden)]
+        fn call(self: &mut QBluetoothDeviceDiscoveryAgentCallback);
+        #[doc(hidden)]
+        fn call(
+            self: &mut QBluetoothDeviceDiscoveryAgentDeviceCallback,
+            info: &QBluetoothDeviceInfo,
+        );
+        #[doc(hidden)]
+        fn call(self: &mut QBluetoothDeviceDiscoveryAgentErrorCallback, message: &QString);
+    }
+}
+
+pub use ffi::QBluetoothDeviceDiscoveryAgent;
+
+/// A Rust closure which is called when a [QBluetoothDeviceDiscoveryAgent] finishes
+#[doc(hidden)]
+pub struct QBluetoothDeviceDiscoveryAgentCallback {
+    callback: Box<dyn FnMut()>,
+}
+
+impl QBluetoothDeviceDiscoveryAgentCallback {
+    fn new(callback: impl FnMut() + 'static) -> Box<Self> {
+        Box::new(Self {
+            callback: Box::new(callback),
+        })
+    }
+
+    fn call(&mut self) {
+        (self.callback)();
+    }
+}
+
+/// A Rust closure which is called when a [QBluetoothDeviceDiscoveryAgent] discovers a device
+#[doc(hidden)]
+pub struct QBluetoothDeviceDiscoveryAgentDeviceCallback {
+    callback: Box<dyn FnMut(&ffi::QBluetoothDeviceInfo)>,
+}
+
+impl QBluetoothDeviceDiscoveryAgentDeviceCallback {
+    fn new(callback: impl FnMut(&ffi::QBluetoothDeviceInfo) + 'static) -> Box<Self> {
+        Box::new(Self {
+            callback: Box::new(callback),
+        })
+    }
+
+    fn call(&mut self, info: &ffi::QBluetoothDeviceInfo) {
+        (self.callback)(info);
+    }
+}
+
+/// A Rust closure which is called when an error occurs in a [QBluetoothDeviceDiscoveryAgent]
+#[doc(hidden)]
+pub struct QBluetoothDeviceDiscoveryAgentErrorCallback {
+    callback: Box<dyn FnMut(&ffi::QString)>,
+}
+
+impl QBluetoothDeviceDiscoveryAgentErrorCallback {
+    fn new(callback: impl FnMut(&ffi::QString) + 'static) -> Box<Self> {
+        Box::new(Self {
+            callback: Box::new(callback),
+        })
+    }
+
+    fn call(&mut self, message: &ffi::QString) {
+        (self.callback)(message);
+    }
+}
+
+impl QBluetoothDeviceDiscoveryAgent {
+    /// Constructs a new agent for the default local Bluetooth adapter.
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qbluetoothdevicediscoveryagent_new()
+    }
+
+    /// Connect a closure which is called with the information of each device that is discovered.
+    ///
+    /// The closure is called on the thread of the agent, and is dropped when the connection is disconnected.
+    pub fn on_device_discovered(
+        self: Pin<&mut Self>,
+        callback: impl FnMut(&ffi::QBluetoothDeviceInfo) + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qbluetoothdevicediscoveryagent_on_device_discovered(
+            self,
+            QBluetoothDeviceDiscoveryAgentDeviceCallback::new(callback),
+        )
+    }
+
+    /// Connect a closure which is called with a description of the error each time an error occurs.
+    ///
+    /// The closure is called on the thread of the agent, and is dropped when the connection is disconnected.
+    pub fn on_error_occurred(
+        self: Pin<&mut Self>,
+        callback: impl FnMut(&ffi::QString) + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qbluetoothdevicediscoveryagent_on_error_occurred(
+            self,
+            QBluetoothDeviceDiscoveryAgentErrorCallback::new(callback),
+        )
+    }
+
+    /// Connect a closure which is called when the discovery has finished, this is not called if it is stopped.
+    ///
+    /// The closure is called on the thread of the agent, and is dropped when the connection is disconnected.
+    pub fn on_finished(
+        self: Pin<&mut Self>,
+        callback: impl FnMut() + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qbluetoothdevicediscoveryagent_on_finished(
+            self,
+            QBluetoothDeviceDiscoveryAgentCallback::new(callback),
+        )
+    }
+
+    /// Starts the discovery of Bluetooth devices, when low_energy_only is true only
+    /// Bluetooth Low Energy devices are discovered.
+    pub fn start(self: Pin<&mut Self>, low_energy_only: bool) {
+        ffi::qbluetoothdevicediscoveryagent_start(self, low_energy_only);
+    }
+}
@@ -0,0 +1,62 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_BLUETOOTH_FEATURE
+#include "cxx-qt-lib/qbluetoothdeviceinfo.h"
+
+#include <QtBluetooth/QBluetoothAddress>
+#include <QtCore/QUuid>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QString
+qbluetoothdeviceinfoAddress(const QBluetoothDeviceInfo& info)
+{
+  return info.address().toString();
+}
+
+::std::unique_ptr<QBluetoothDeviceInfo>
+qbluetoothdeviceinfoClone(const QBluetoothDeviceInfo& info)
+{
+  return ::std::make_unique<QBluetoothDeviceInfo>(info);
+}
+
+QString
+qbluetoothdeviceinfoDeviceUuid(const QBluetoothDeviceInfo& info)
+{
+  return qbluetoothuuidToQString(info.deviceUuid());
+}
+
+bool
+qbluetoothdeviceinfoIsLowEnergy(const QBluetoothDeviceInfo& info)
+{
+  return info.coreConfigurations().testFlag(
+    QBluetoothDeviceInfo::LowEnergyCoreConfiguration);
+}
+
+::std::int16_t
+qbluetoothdeviceinfoRssi(const QBluetoothDeviceInfo& info)
+{
+  return static_cast<::std::int16_t>(info.rssi());
+}
+
+QBluetoothUuid
+qbluetoothuuidFromQString(const QString& uuid)
+{
+  return QBluetoothUuid(QUuid(uuid));
+}
+
+QString
+qbluetoothuuidToQString(const QBluetoothUuid& uuid)
+{
+  return uuid.toString(QUuid::WithoutBraces);
+}
+
+}
+}
+#endif
@@ -0,0 +1,81 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::UniquePtr;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbluetoothdeviceinfo.h");
+
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        /// The QBluetoothDeviceInfo class stores information about the Bluetooth device.
+        ///
+        /// This is delivered to Rust by [QBluetoothDeviceDiscoveryAgent::on_device_discovered](crate::QBluetoothDeviceDiscoveryAgent::on_device_discovered),
+        /// use [QBluetoothDeviceInfo::clone_unique] to keep the information so that it can be connected to later.
+        type QBluetoothDeviceInfo;
+
+        /// Returns true if the QBluetoothDeviceInfo object is valid, otherwise returns false.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QBluetoothDeviceInfo) -> bool;
+
+        /// Returns the name assigned to the device.
+        fn name(self: &QBluetoothDeviceInfo) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qbluetoothdeviceinfo_address"]
+        fn qbluetoothdeviceinfoAddress(info: &QBluetoothDeviceInfo) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qbluetoothdeviceinfo_clone"]
+        fn qbluetoothdeviceinfoClone(
+            info: &QBluetoothDeviceInfo,
+        ) -> UniquePtr<QBluetoothDeviceInfo>;
+        #[doc(hidden)]
+        #[rust_name = "qbluetoothdeviceinfo_device_uuid"]
+        fn qbluetoothdeviceinfoDeviceUuid(info: &QBluetoothDeviceInfo) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qbluetoothdeviceinfo_is_low_energy"]
+        fn qbluetoothdeviceinfoIsLowEnergy(info: &QBluetoothDeviceInfo) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qbluetoothdeviceinfo_rssi"]
+        fn qbluetoothdeviceinfoRssi(info: &QBluetoothDeviceInfo) -> i16;
+    }
+}
+
+pub use ffi::QBluetoothDeviceInfo;
+
+impl QBluetoothDeviceInfo {
+    /// Returns the address of the device, such as "00:11:22:33:44:55".
+    ///
+    /// On macOS and iOS the address is not available, instead the device is identified by [QBluetoothDeviceInfo::device_uuid].
+    pub fn address(&self) -> ffi::QString {
+        ffi::qbluetoothdeviceinfo_address(self)
+    }
+
+    /// Returns a copy of the device information which is owned by Rust.
+    pub fn clone_unique(&self) -> UniquePtr<Self> {
+        ffi::qbluetoothdeviceinfo_clone(self)
+    }
+
+    /// Returns the unique identifier of the device on macOS and iOS, otherwise it is a null UUID.
+    pub fn device_uuid(&self) -> ffi::QString {
+        ffi::qbluetoothdeviceinfo_device_uuid(self)
+    }
+
+    /// Returns true if the device supports Bluetooth Low Energy.
+    pub fn is_low_energy(&self) -> bool {
+        ffi::qbluetoothdeviceinfo_is_low_energy(self)
+    }
+
+    /// Returns the signal strength when the device was last scanned.
+    pub fn rssi(&self) -> i16 {
+        ffi::qbluetoothdeviceinfo_rssi(self)
+    }
+}
@@ -0,0 +1,43 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_BLUETOOTH_FEATURE
+#include "cxx-qt-lib/qlowenergycontroller.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QLowEnergyController>
+qlowenergycontrollerNewCentral(const QBluetoothDeviceInfo& device)
+{
+  return ::std::unique_ptr<QLowEnergyController>(
+    QLowEnergyController::createCentral(device));
+}
+
+::std::unique_ptr<QLowEnergyService>
+qlowenergycontrollerCreateService(QLowEnergyController& controller,
+                                  const QString& uuid)
+{
+  // The service has no parent so that it is owned by the unique_ptr,
+  // it is null if the service has not been discovered
+  return ::std::unique_ptr<QLowEnergyService>(
+    controller.createServiceObject(qbluetoothuuidFromQString(uuid)));
+}
+
+QStringList
+qlowenergycontrollerServices(const QLowEnergyController& controller)
+{
+  QStringList uuids;
+  for (const auto& uuid : controller.services()) {
+    uuids.append(qbluetoothuuidToQString(uuid));
+  }
+  return uuids;
+}
+
+}
+}
+#endif
@@ -0,0 +1,247 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+#[cxx::bridge]
+mod ffi {
+    /// The states of a Bluetooth Low Energy controller.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QLowEnergyControllerControllerState {
+        /// The controller is not connected to a remote device.
+        UnconnectedState = 0,
+        /// The controller is attempting to connect to a remote device.
+        ConnectingState,
+        /// The controller is connected to a remote device.
+        ConnectedState,
+        /// The controller is retrieving the list of services offered by the remote device.
+        DiscoveringState,
+        /// The controller has discovered all services offered by the remote device.
+        DiscoveredState,
+        /// The controller is about to be disconnected from the remote device.
+        ClosingState,
+        /// The controller is currently advertising data.
+        AdvertisingState,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qlowenergycontroller.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QLowEnergyControllerControllerState;
+
+        include!("cxx-qt-lib/qbluetoothdeviceinfo.h");
+        type QBluetoothDeviceInfo = crate::QBluetoothDeviceInfo;
+        include!("cxx-qt-lib/qlowenergyservice.h");
+        type QLowEnergyService = crate::QLowEnergyService;
+        include!("cxx-qt-lib/qmetaobjectconnection.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QMetaObjectConnection = crate::QMetaObjectConnection;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = crate::QStringList;
+
+        /// The QLowEnergyController class provides access to Bluetooth Low Energy devices.
+        ///
+        /// The controller connects to a remote device and discovers its services, the services
+        /// are identified by their UUID as a string, such as "0000180d-0000-1000-8000-00805f9b34fb".
+        ///
+        /// ```ignore
+        /// let mut controller = QLowEnergyController::new_central(&device);
+        /// controller
+        ///     .pin_mut()
+        ///     .on_connected(|| println!("Connected"))
+        ///     .release();
+        /// controller.pin_mut().connect_to_device();
+        /// ```
+        type QLowEnergyController;
+
+        /// Connects to the remote Bluetooth Low Energy device.
+        #[rust_name = "connect_to_device"]
+        fn connectToDevice(self: Pin<&mut QLowEnergyController>);
+
+        /// Disconnects from the remote device.
+        #[rust_name = "disconnect_from_device"]
+        fn disconnectFromDevice(self: Pin<&mut QLowEnergyController>);
+
+        /// Initiates the service discovery process, once it has finished the services can be created.
+        #[rust_name = "discover_services"]
+        fn discoverServices(self: Pin<&mut QLowEnergyController>);
+
+        /// Returns a textual representation of the last occurred error.
+        #[rust_name = "error_string"]
+        fn errorString(self: &QLowEnergyController) -> QString;
+
+        /// Returns the name of the remote Bluetooth Low Energy device.
+        #[rust_name = "remote_name"]
+        fn remoteName(self: &QLowEnergyController) -> QString;
+
+        /// Returns the current state of the controller.
+        fn state(self: &QLowEnergyController) -> QLowEnergyControllerControllerState;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qlowenergycontroller_new_central"]
+        fn qlowenergycontrollerNewCentral(
+            device: &QBluetoothDeviceInfo,
+        ) -> UniquePtr<QLowEnergyController>;
+        #[doc(hidden)]
+        #[rust_name = "qlowenergycontroller_create_service"]
+        fn qlowenergycontrollerCreateService(
+            controller: Pin<&mut QLowEnergyController>,
+            uuid: &QString,
+        ) -> UniquePtr<QLowEnergyService>;
+        #[doc(hidden)]
+        #[rust_name = "qlowenergycontroller_on_connected"]
+        fn qlowenergycontrollerOnConnected(
+            controller: Pin<&mut QLowEnergyController>,
+            callback: Box<QLowEnergyControllerCallback>,
+        ) -> QMetaObjectConnection;
+        #[doc(hidden)]
+        #[rust_name = "qlowenergycontroller_on_disconnected"]
+        fn qlowenergycontrollerOnDisconnected(
+            controller: Pin<&mut QLowEnergyController>,
+            callback: Box<QLowEnergyControllerCallback>,
+        ) -> QMetaObjectConnection;
+        #[doc(hidden)]
+        #[rust_name = "qlowenergycontroller_on_discovery_finished"]
+        fn qlowenergycontrollerOnDiscoveryFinished(
+            controller: Pin<&mut QLowEnergyController>,
+            callback: Box<QLowEnergyControllerCallback>,
+        ) -> QMetaObjectConnection;
+        #[doc(hidden)]
+        #[rust_name = "qlowenergycontroller_on_error_occurred"]
+        fn qlowenergycontrollerOnErrorOccurred(
+            controller: Pin<&mut QLowEnergyController>,
+            callback: Box<QLowEnergyControllerErrorCallback>,
+        ) -> QMetaObjectConnection;
+        #[doc(hidden)]
+        #[rust_name = "qlowenergycontroller_services"]
+        fn qlowenergycontrollerServices(controller: &QLowEnergyController) -> QStringList;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        type QLowEnergyControllerCallback;
+        #[doc(hidden)]
+        type QLowEnergyControllerErrorCallback;
+
+        #[doc(hidden)]
+        fn call(self: &mut QLowEnergyControllerCallback);
+        #[doc(hidden)]
+        fn call(self: &mut QLowEnergyControllerErrorCallback, message: &QString);
+    }
+}
+
+pub use ffi::{QLowEnergyController, QLowEnergyControllerControllerState};
+
+/// A Rust closure which is called by a connection of a [QLowEnergyController]
+#[doc(hidden)]
+pub struct QLowEnergyControllerCallback {
+    callback: Box<dyn FnMut()>,
+}
+
+impl QLowEnergyControllerCallback {
+    fn new(callback: impl FnMut() + 'static) -> Box<Self> {
+        Box::new(Self {
+            callback: Box::new(callback),
+        })
+    }
+
+    fn call(&mut self) {
+        (self.callback)();
+    }
+}
+
+/// A Rust closure which is called when an error occurs in a [QLowEnergyController]
+#[doc(hidden)]
+pub struct QLowEnergyControllerErrorCallback {
+    callback: Box<dyn FnMut(&ffi::QString)>,
+}
+
+impl QLowEnergyControllerErrorCallback {
+    fn new(callback: impl FnMut(&ffi::QString) + 'static) -> Box<Self> {
+        Box::new(Self {
+            callback: Box::new(callback),
+        })
+    }
+
+    fn call(&mut self, message: &ffi::QString) {
+        (self.callback)(message);
+    }
+}
+
+impl QLowEnergyController {
+    /// Creates a service for the UUID of a service which has been discovered, the service is owned by Rust.
+    ///
+    /// Returns a null pointer if the service has not been discovered.
+    pub fn create_service(
+        self: Pin<&mut Self>,
+        uuid: &ffi::QString,
+    ) -> UniquePtr<ffi::QLowEnergyService> {
+        ffi::qlowenergycontroller_create_service(self, uuid)
+    }
+
+    /// Constructs a controller in the central role for the remote device.
+    pub fn new_central(device: &ffi::QBluetoothDeviceInfo) -> UniquePtr<Self> {
+        ffi::qlowenergycontroller_new_central(device)
+    }
+
+    /// Connect a closure which is called when the controller has connected to the remote device.
+    ///
+    /// The closure is called on the thread of the controller, and is dropped when the connection is disconnected.
+    pub fn on_connected(
+        self: Pin<&mut Self>,
+        callback: impl FnMut() + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qlowenergycontroller_on_connected(self, QLowEnergyControllerCallback::new(callback))
+    }
+
+    /// Connect a closure which is called when the controller has disconnected from the remote device.
+    ///
+    /// The closure is called on the thread of the controller, and is dropped when the connection is disconnected.
+    pub fn on_disconnected(
+        self: Pin<&mut Self>,
+        callback: impl FnMut() + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qlowenergycontroller_on_disconnected(self, QLowEnergyControllerCallback::new(callback))
+    }
+
+    /// Connect a closure which is called when the service discovery has finished.
+    ///
+    /// The closure is called on the thread of the controller, and is dropped when the connection is disconnected.
+    pub fn on_discovery_finished(
+        self: Pin<&mut Self>,
+        callback: impl FnMut() + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qlowenergycontroller_on_discovery_finished(
+            self,
+            QLowEnergyControllerCallback::new(callback),
+        )
+    }
+
+    /// Connect a closure which is called with a description of the error each time an error occurs.
+    ///
+    /// The closure is called on the thread of the controller, and is dropped when the connection is disconnected.
+    pub fn on_error_occurred(
+        self: Pin<&mut Self>,
+        callback: impl FnMut(&ffi::QString) + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qlowenergycontroller_on_error_occurred(
+            self,
+            QLowEnergyControllerErrorCallback::new(callback),
+        )
+    }
+
+    /// Returns the UUIDs of the services which have been discovered on the remote device.
+    pub fn services(&self) -> ffi::QStringList {
+        ffi::qlowenergycontroller_services(self)
+    }
+}
@@ -0,0 +1,105 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_BLUETOOTH_FEATURE
+#include "cxx-qt-lib/qlowenergyservice.h"
+
+#include <QtBluetooth/QLowEnergyDescriptor>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QStringList
+qlowenergyserviceCharacteristics(const QLowEnergyService& service)
+{
+  QStringList uuids;
+  for (const auto& characteristic : service.characteristics()) {
+    uuids.append(qbluetoothuuidToQString(characteristic.uuid()));
+  }
+  return uuids;
+}
+
+void
+qlowenergyserviceDiscoverDetails(QLowEnergyService& service)
+{
+  service.discoverDetails();
+}
+
+bool
+qlowenergyserviceIsDetailsDiscovered(const QLowEnergyService& service)
+{
+  return service.state() == QLowEnergyService::ServiceDiscovered;
+}
+
+bool
+qlowenergyserviceReadCharacteristic(QLowEnergyService& service,
+                                    const QString& uuid)
+{
+  const auto characteristic =
+    service.characteristic(qbluetoothuuidFromQString(uuid));
+  if (!characteristic.isValid()) {
+    return false;
+  }
+
+  service.readCharacteristic(characteristic);
+  return true;
+}
+
+bool
+qlowenergyserviceSetNotificationsEnabled(QLowEnergyService& service,
+                                         const QString& uuid,
+                                         bool enabled)
+{
+  const auto characteristic =
+    service.characteristic(qbluetoothuuidFromQString(uuid));
+  if (!characteristic.isValid()) {
+    return false;
+  }
+
+  // Notifications are enabled by writing to the client characteristic
+  // configuration descriptor of the characteristic
+  const auto descriptor = characteristic.descriptor(
+    QBluetoothUuid::DescriptorType::ClientCharacteristicConfiguration);
+  if (!descriptor.isValid()) {
+    return false;
+  }
+
+  service.writeDescriptor(descriptor,
+                          enabled ? QByteArray::fromHex("0100")
+                                  : QByteArray::fromHex("0000"));
+  return true;
+}
+
+QString
+qlowenergyserviceUuid(const QLowEnergyService& service)
+{
+  return qbluetoothuuidToQString(service.serviceUuid());
+}
+
+bool
+qlowenergyserviceWriteCharacteristic(QLowEnergyService& service,
+                                     const QString& uuid,
+                                     const QByteArray& value,
+                                     bool withResponse)
+{
+  const auto characteristic =
+    service.characteristic(qbluetoothuuidFromQString(uuid));
+  if (!characteristic.isValid()) {
+    return false;
+  }
+
+  service.writeCharacteristic(
+    characteristic,
+    value,
+    withResponse ? QLowEnergyService::WriteWithResponse
+                 : QLowEnergyService::WriteWithoutResponse);
+  return true;
+}
+
+}
+}
+#endif
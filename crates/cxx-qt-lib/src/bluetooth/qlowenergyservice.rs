@@ -0,0 +1,319 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+
+#[cxx::bridge]
+mod ffi {
+    /// The errors which can occur in a Bluetooth Low Energy service.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QLowEnergyServiceServiceError {
+        /// No error has occurred.
+        NoError = 0,
+        /// An operation was attempted while the service was not ready.
+        OperationError = 1,
+        /// An attempt to write a new value to a characteristic failed.
+        CharacteristicWriteError = 2,
+        /// An attempt to write a new value to a descriptor failed.
+        DescriptorWriteError = 3,
+        /// An unknown error occurred when interacting with the service.
+        UnknownError = 4,
+        /// An attempt to read a characteristic value failed.
+        CharacteristicReadError = 5,
+        /// An attempt to read a descriptor value failed.
+        DescriptorReadError = 6,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qlowenergyservice.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QLowEnergyServiceServiceError;
+
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qmetaobjectconnection.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QMetaObjectConnection = crate::QMetaObjectConnection;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = crate::QStringList;
+
+        /// The QLowEnergyService class represents an individual service on a Bluetooth Low Energy device.
+        ///
+        /// Services are created by [QLowEnergyController::create_service](crate::QLowEnergyController::create_service),
+        /// the details of the service must be discovered before its characteristics can be used.
+        /// Characteristics are identified by their UUID as a string.
+        type QLowEnergyService;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qlowenergyservice_characteristics"]
+        fn qlowenergyserviceCharacteristics(service: &QLowEnergyService) -> QStringList;
+        #[doc(hidden)]
+        #[rust_name = "qlowenergyservice_discover_details"]
+        fn qlowenergyserviceDiscoverDetails(service: Pin<&mut QLowEnergyService>);
+        #[doc(hidden)]
+        #[rust_name = "qlowenergyservice_is_details_discovered"]
+        fn qlowenergyserviceIsDetailsDiscovered(service: &QLowEnergyService) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qlowenergyservice_on_characteristic_changed"]
+        fn qlowenergyserviceOnCharacteristicChanged(
+            service: Pin<&mut QLowEnergyService>,
+            callback: Box<QLowEnergyServiceCharacteristicCallback>,
+        ) -> QMetaObjectConnection;
+        #[doc(hidden)]
+        #[rust_name = "qlowenergyservice_on_characteristic_read"]
+        fn qlowenergyserviceOnCharacteristicRead(
+            service: Pin<&mut QLowEnergyService>,
+            callback: Box<QLowEnergyServiceCharacteristicCallback>,
+        ) -> QMetaObjectConnection;
+        #[doc(hidden)]
+        #[rust_name = "qlowenergyservice_on_characteristic_written"]
+        fn qlowenergyserviceOnCharacteristicWritten(
+            service: Pin<&mut QLowEnergyService>,
+            callback: Box<QLowEnergyServiceCharacteristicCallback>,
+        ) -> QMetaObjectConnection;
+        #[doc(hidden)]
+        #[rust_name = "qlowenergyservice_on_details_discovered"]
+        fn qlowenergyserviceOnDetailsDiscovered(
+            service: Pin<&mut QLowEnergyService>,
+            callback: Box<QLowEnergyServiceCallback>,
+        ) -> QMetaObjectConnection;
+        #[doc(hidden)]
+        #[rust_name = "qlowenergyservice_on_error_occurred"]
+        fn qlowenergyserviceOnErrorOccurred(
+            service: Pin<&mut QLowEnergyService>,
+            callback: Box<QLowEnergyServiceErrorCallback>,
+        ) -> QMetaObjectConnection;
+        #[doc(hidden)]
+        #[rust_name = "qlowenergyservice_read_characteristic"]
+        fn qlowenergyserviceReadCharacteristic(
+            service: Pin<&mut QLowEnergyService>,
+            uuid: &QString,
+        ) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qlowenergyservice_set_notifications_enabled"]
+        fn qlowenergyserviceSetNotificationsEnabled(
+            service: Pin<&mut QLowEnergyService>,
+            uuid: &QString,
+            enabled: bool,
+        ) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qlowenergyservice_uuid"]
+        fn qlowenergyserviceUuid(service: &QLowEnergyService) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qlowenergyservice_write_characteristic"]
+        fn qlowenergyserviceWriteCharacteristic(
+            service: Pin<&mut QLowEnergyService>,
+            uuid: &QString,
+            value: &QByteArray,
+            with_response: bool,
+        ) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        type QLowEnergyServiceCallback;
+        #[doc(hidden)]
+        type QLowEnergyServiceCharacteristicCallback;
+        #[doc(hidden)]
+        type QLowEnergyServiceErrorCallback;
+
+        #[doc(hidden)]
+        fn call(self: &mut QLowEnergyServiceCallback);
+        #[doc(hidden)]
+        fn call(
+            self: &mut QLowEnergyServiceCharacteristicCallback,
+            uuid: &QString,
+            value: &QByteArray,
+        );
+        #[doc(hidden)]
+        fn call(self: &mut QLowEnergyServiceErrorCallback, error: QLowEnergyServiceServiceError);
+    }
+
+    // The service is created by the controller bridge
+    impl UniquePtr<QLowEnergyService> {}
+}
+
+pub use ffi::{QLowEnergyService, QLowEnergyServiceServiceError};
+
+/// A Rust closure which is called once the details of a [QLowEnergyService] have been discovered
+#[doc(hidden)]
+pub struct QLowEnergyServiceCallback {
+    callback: Box<dyn FnMut()>,
+}
+
+impl QLowEnergyServiceCallback {
+    fn new(callback: impl FnMut() + 'static) -> Box<Self> {
+        Box::new(Self {
+            callback: Box::new(callback),
+        })
+    }
+
+    fn call(&mut self) {
+        (self.callback)();
+    }
+}
+
+type QLowEnergyServiceCharacteristicChanged = dyn FnMut(&ffi::QString, &ffi::QByteArray);
+
+/// A Rust closure which is called with the UUID and value of a characteristic of a [QLowEnergyService]
+#[doc(hidden)]
+pub struct QLowEnergyServiceCharacteristicCallback {
+    callback: Box<QLowEnergyServiceCharacteristicChanged>,
+}
+
+impl QLowEnergyServiceCharacteristicCallback {
+    fn new(callback: impl FnMut(&ffi::QString, &ffi::QByteArray) + 'static) -> Box<Self> {
+        Box::new(Self {
+            callback: Box::new(callback),
+        })
+    }
+
+    fn call(&mut self, uuid: &ffi::QString, value: &ffi::QByteArray) {
+        (self.callback)(uuid, value);
+    }
+}
+
+/// A Rust closure which is called when an error occurs in a [QLowEnergyService]
+#[doc(hidden)]
+pub struct QLowEnergyServiceErrorCallback {
+    callback: Box<dyn FnMut(QLowEnergyServiceServiceError)>,
+}
+
+impl QLowEnergyServiceErrorCallback {
+    fn new(callback: impl FnMut(QLowEnergyServiceServiceError) + 'static) -> Box<Self> {
+        Box::new(Self {
+            callback: Box::new(callback),
+        })
+    }
+
+    fn call(&mut self, error: QLowEnergyServiceServiceError) {
+        (self.callback)(error);
+    }
+}
+
+impl QLowEnergyService {
+    /// Returns the UUIDs of the characteristics of the service, which are only known once the details have been discovered.
+    pub fn characteristics(&self) -> ffi::QStringList {
+        ffi::qlowenergyservice_characteristics(self)
+    }
+
+    /// Initiates the discovery of the characteristics and descriptors of the service.
+    pub fn discover_details(self: Pin<&mut Self>) {
+        ffi::qlowenergyservice_discover_details(self);
+    }
+
+    /// Returns true if the details of the service have been discovered, otherwise returns false.
+    pub fn is_details_discovered(&self) -> bool {
+        ffi::qlowenergyservice_is_details_discovered(self)
+    }
+
+    /// Connect a closure which is called with the UUID and new value when a characteristic
+    /// is changed by the remote device, notifications must be enabled for the characteristic.
+    ///
+    /// The closure is called on the thread of the service, and is dropped when the connection is disconnected.
+    pub fn on_characteristic_changed(
+        self: Pin<&mut Self>,
+        callback: impl FnMut(&ffi::QString, &ffi::QByteArray) + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qlowenergyservice_on_characteristic_changed(
+            self,
+            QLowEnergyServiceCharacteristicCallback::new(callback),
+        )
+    }
+
+    /// Connect a closure which is called with the UUID and value when a read of a characteristic has finished.
+    ///
+    /// The closure is called on the thread of the service, and is dropped when the connection is disconnected.
+    pub fn on_characteristic_read(
+        self: Pin<&mut Self>,
+        callback: impl FnMut(&ffi::QString, &ffi::QByteArray) + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qlowenergyservice_on_characteristic_read(
+            self,
+            QLowEnergyServiceCharacteristicCallback::new(callback),
+        )
+    }
+
+    /// Connect a closure which is called with the UUID and value when a write with response to a characteristic has finished.
+    ///
+    /// The closure is called on the thread of the service, and is dropped when the connection is disconnected.
+    pub fn on_characteristic_written(
+        self: Pin<&mut Self>,
+        callback: impl FnMut(&ffi::QString, &ffi::QByteArray) + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qlowenergyservice_on_characteristic_written(
+            self,
+            QLowEnergyServiceCharacteristicCallback::new(callback),
+        )
+    }
+
+    /// Connect a closure which is called once the details of the service have been discovered.
+    ///
+    /// The closure is called on the thread of the service, and is dropped when the connection is disconnected.
+    pub fn on_details_discovered(
+        self: Pin<&mut Self>,
+        callback: impl FnMut() + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qlowenergyservice_on_details_discovered(self, QLowEnergyServiceCallback::new(callback))
+    }
+
+    /// Connect a closure which is called each time an error occurs in the service.
+    ///
+    /// The closure is called on the thread of the service, and is dropped when the connection is disconnected.
+    pub fn on_error_occurred(
+        self: Pin<&mut Self>,
+        callback: impl FnMut(QLowEnergyServiceServiceError) + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qlowenergyservice_on_error_occurred(
+            self,
+            QLowEnergyServiceErrorCallback::new(callback),
+        )
+    }
+
+    /// Reads the value of the characteristic with the UUID, the value is delivered to
+    /// [QLowEnergyService::on_characteristic_read].
+    ///
+    /// Returns false if the service does not have the characteristic.
+    pub fn read_characteristic(self: Pin<&mut Self>, uuid: &ffi::QString) -> bool {
+        ffi::qlowenergyservice_read_characteristic(self, uuid)
+    }
+
+    /// Enables or disables notifications for the characteristic with the UUID, changes are delivered to
+    /// [QLowEnergyService::on_characteristic_changed].
+    ///
+    /// Returns false if the service does not have the characteristic or it does not support notifications.
+    pub fn set_notifications_enabled(
+        self: Pin<&mut Self>,
+        uuid: &ffi::QString,
+        enabled: bool,
+    ) -> bool {
+        ffi::qlowenergyservice_set_notifications_enabled(self, uuid, enabled)
+    }
+
+    /// Returns the UUID of the service.
+    pub fn uuid(&self) -> ffi::QString {
+        ffi::qlowenergyservice_uuid(self)
+    }
+
+    /// Writes the value to the characteristic with the UUID, if with_response is true the write is
+    /// confirmed by [QLowEnergyService::on_characteristic_written].
+    ///
+    /// Returns false if the service does not have the characteristic.
+    pub fn write_characteristic(
+        self: Pin<&mut Self>,
+        uuid: &ffi::QString,
+        value: &ffi::QByteArray,
+        with_response: bool,
+    ) -> bool {
+        ffi::qlowenergyservice_write_characteristic(self, uuid, value, with_response)
+    }
+}
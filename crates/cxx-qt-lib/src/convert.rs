@@ -0,0 +1,93 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Conversions between Rust types and Qt types which are used by the generated code of CXX-Qt.
+//!
+//! A parameter of an invokable which is declared as `#[from_qt(String)] name: QString`
+//! is converted with [FromQt] before the Rust implementation is called with a `String`,
+//! and an invokable which is declared with `#[into_qt(String)]` returns a `String` which is converted with [IntoQt].
+//!
+//! Both traits are only implemented through the `From` conversions of the Qt types,
+//! so a conversion between a Qt type and a Rust type is supported by adding `From` impls for it.
+
+/// Construct a Rust type from a reference to a Qt type which has been received from C++.
+///
+/// This is implemented for any type which implements `From<&Q>`, such as `String` for [QString](crate::QString)
+/// and `Vec<u8>` for [QByteArray](crate::QByteArray). Implement this trait for your own types to use them with `#[from_qt(T)]`.
+pub trait FromQt<Q>: Sized {
+    /// Convert the Qt value into this type
+    fn from_qt(value: &Q) -> Self;
+}
+
+impl<Q, R> FromQt<Q> for R
+where
+    R: for<'a> From<&'a Q>,
+{
+    fn from_qt(value: &Q) -> Self {
+        Self::from(value)
+    }
+}
+
+/// Convert a Rust type into a Qt type which is returned to C++.
+///
+/// This is implemented for any type whose Qt type implements `From<T>`, such as `String` for [QString](crate::QString)
+/// and `Vec<u8>` for [QByteArray](crate::QByteArray). Implement this trait for your own types to use them with `#[into_qt(T)]`.
+pub trait IntoQt<Q> {
+    /// Convert this value into the Qt type
+    fn into_qt(self) -> Q;
+}
+
+impl<R, Q> IntoQt<Q> for R
+where
+    Q: From<R>,
+{
+    fn into_qt(self) -> Q {
+        Q::from(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{QByteArray, QString};
+
+    struct Celsius(f64);
+
+    impl IntoQt<QString> for Celsius {
+        fn into_qt(self) -> QString {
+            QString::from(&format!("{} °C", self.0))
+        }
+    }
+
+    #[test]
+    fn test_string_from_qt() {
+        let string = String::from_qt(&QString::from("KDAB"));
+        assert_eq!(string, "KDAB");
+    }
+
+    #[test]
+    fn test_string_into_qt() {
+        let qstring: QString = String::from("KDAB").into_qt();
+        assert_eq!(qstring.to_string(), "KDAB");
+    }
+
+    #[test]
+    fn test_vec_u8_from_qt() {
+        let bytes = Vec::<u8>::from_qt(&QByteArray::from("KDAB"));
+        assert_eq!(bytes, b"KDAB");
+    }
+
+    #[test]
+    fn test_vec_u8_into_qt() {
+        let qbytearray: QByteArray = b"KDAB".to_vec().into_qt();
+        assert_eq!(qbytearray.as_ref(), b"KDAB");
+    }
+
+    #[test]
+    fn test_custom_into_qt() {
+        let qstring: QString = Celsius(21.5).into_qt();
+        assert_eq!(qstring.to_string(), "21.5 °C");
+    }
+}
@@ -3,12 +3,39 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+mod qabstractitemmodel;
+pub use qabstractitemmodel::QAbstractItemModel;
+
+mod qanimationgroup;
+pub use qanimationgroup::{QAbstractAnimation, QAnimationGroup, QAnimationGroupChild};
+
 mod qbytearray;
 pub use qbytearray::QByteArray;
 
+mod qcborarray;
+pub use qcborarray::QCborArray;
+
+mod qcbormap;
+pub use qcbormap::QCborMap;
+
+mod qcborvalue;
+pub use qcborvalue::{QCborParserError, QCborValue, QCborValueType};
+
+mod qconcatenatetablesproxymodel;
+pub use qconcatenatetablesproxymodel::QConcatenateTablesProxyModel;
+
 mod qcoreapplication;
 pub use qcoreapplication::QCoreApplication;
 
+mod qcryptographichash;
+pub use qcryptographichash::{QCryptographicHash, QCryptographicHashAlgorithm};
+
+mod qdatastream;
+pub use qdatastream::{
+    QDataStream, QDataStreamByteOrder, QDataStreamFloatingPointPrecision, QDataStreamStatus,
+    QDataStreamValue,
+};
+
 mod qdate;
 pub use qdate::QDate;
 
@@ -17,12 +44,29 @@ mod qdatetime;
 #[cfg(not(target_os = "emscripten"))]
 pub use qdatetime::QDateTime;
 
+mod qeasingcurve;
+pub use qeasingcurve::{QEasingCurve, QEasingCurveType};
+
+mod qevent;
+pub use qevent::{QEvent, QEventType, QTimerEvent};
+
+mod qeventfilter;
+
+mod qfilesystemwatcher;
+pub use qfilesystemwatcher::QFileSystemWatcher;
+
 mod qhash;
 pub use qhash::{QHash, QHashPair, QHashPair_QString_QVariant, QHashPair_i32_QByteArray};
 
+mod qidentityproxymodel;
+pub use qidentityproxymodel::QIdentityProxyModel;
+
 mod qlist;
 pub use qlist::{QList, QListElement};
 
+mod qlockfile;
+pub use qlockfile::{QLockFile, QLockFileLockError};
+
 mod qmap;
 pub use qmap::{QMap, QMapPair, QMapPair_QString_QVariant};
 
@@ -32,24 +76,47 @@ pub use qmargins::QMargins;
 mod qmarginsf;
 pub use qmarginsf::QMarginsF;
 
+mod qmessageauthenticationcode;
+pub use qmessageauthenticationcode::QMessageAuthenticationCode;
+
 mod qmetaobjectconnection;
 pub use qmetaobjectconnection::QMetaObjectConnection;
 
 mod qmodelindex;
 pub use qmodelindex::QModelIndex;
 
+mod qobjectconnect;
+pub use qobjectconnect::{
+    bind_property, connect_signal_to, Connect, QObject, QProperty, QSignal, QSlot,
+};
+
+mod qparallelanimationgroup;
+pub use qparallelanimationgroup::QParallelAnimationGroup;
+
 mod qpersistentmodelindex;
 pub use qpersistentmodelindex::QPersistentModelIndex;
 
+mod qpropertyanimation;
+pub use qpropertyanimation::QPropertyAnimation;
+
 mod qrect;
 pub use qrect::QRect;
 
 mod qrectf;
 pub use qrectf::QRectF;
 
+mod qsequentialanimationgroup;
+pub use qsequentialanimationgroup::QSequentialAnimationGroup;
+
 mod qset;
 pub use qset::{QSet, QSetElement};
 
+// Qt for WebAssembly does not have shared memory between processes
+#[cfg(not(target_os = "emscripten"))]
+mod qsharedmemory;
+#[cfg(not(target_os = "emscripten"))]
+pub use qsharedmemory::{QSharedMemory, QSharedMemoryAccessMode, QSharedMemorySharedMemoryError};
+
 mod qsize;
 pub use qsize::QSize;
 
@@ -62,11 +129,34 @@ pub use qstring::QString;
 mod qstringlist;
 pub use qstringlist::QStringList;
 
+mod qstringlistmodel;
+pub use qstringlistmodel::QStringListModel;
+
+#[cfg(not(target_os = "emscripten"))]
+mod qsystemsemaphore;
+#[cfg(not(target_os = "emscripten"))]
+pub use qsystemsemaphore::{
+    QSystemSemaphore, QSystemSemaphoreAccessMode, QSystemSemaphoreSystemSemaphoreError,
+};
+
 mod qt;
 pub use qt::{
-    AspectRatioMode, CaseSensitivity, ConnectionType, DateFormat, SplitBehaviorFlags, TimeSpec,
+    AspectRatioMode, CaseSensitivity, CheckState, ConnectionType, DateFormat, ItemDataRole,
+    ItemFlag, ItemFlags, SplitBehaviorFlags, TimeSpec,
+};
+
+mod qtlogging;
+pub use qtlogging::{
+    q_format_log_message, q_install_message_handler, q_reset_message_handler,
+    q_set_message_pattern, QMessageLogContext, QtMsgType,
 };
 
+mod qtemporarydir;
+pub use qtemporarydir::QTemporaryDir;
+
+mod qtemporaryfile;
+pub use qtemporaryfile::QTemporaryFile;
+
 mod qtime;
 pub use qtime::QTime;
 
@@ -87,5 +177,8 @@ pub use qurl::QUrl;
 mod qvariant;
 pub use qvariant::{QVariant, QVariantValue};
 
+mod qvariantanimation;
+pub use qvariantanimation::{QAbstractAnimationState, QVariantAnimation};
+
 mod qvector;
 pub use qvector::{QVector, QVectorElement};
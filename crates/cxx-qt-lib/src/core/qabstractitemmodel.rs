@@ -0,0 +1,56 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qabstractitemmodel.h");
+
+        include!("cxx-qt-lib/qmodelindex.h");
+        type QModelIndex = crate::QModelIndex;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+
+        /// The QAbstractItemModel class provides the abstract interface for item model classes.
+        ///
+        /// This is used to pass any model, such as a CXX-Qt QObject which inherits from QAbstractListModel,
+        /// to the proxy models. A pointer to a model can be converted into a `*mut QAbstractItemModel`
+        /// by declaring the `qabstractitemmodelUpcast` helper for the model in a bridge.
+        ///
+        /// ```ignore
+        /// unsafe extern "C++" {
+        ///     include!("cxx-qt-lib/qabstractitemmodel.h");
+        ///     type QAbstractItemModel = cxx_qt_lib::QAbstractItemModel;
+        ///
+        ///     #[cxx_name = "qabstractitemmodelUpcast"]
+        ///     #[namespace = "rust::cxxqtlib1"]
+        ///     unsafe fn upcast_custom_base_class(model: *mut CustomBaseClass) -> *mut QAbstractItemModel;
+        /// }
+        /// ```
+        type QAbstractItemModel;
+
+        /// Returns the number of columns for the children of the given parent.
+        #[rust_name = "column_count"]
+        fn columnCount(self: &QAbstractItemModel, parent: &QModelIndex) -> i32;
+
+        /// Returns the data stored under the given role for the item referred to by the index.
+        fn data(self: &QAbstractItemModel, index: &QModelIndex, role: i32) -> QVariant;
+
+        /// Returns the index of the item in the model specified by the given row, column and parent index.
+        fn index(
+            self: &QAbstractItemModel,
+            row: i32,
+            column: i32,
+            parent: &QModelIndex,
+        ) -> QModelIndex;
+
+        /// Returns the number of rows under the given parent.
+        /// When the parent is valid it means that is returning the number of children of parent.
+        #[rust_name = "row_count"]
+        fn rowCount(self: &QAbstractItemModel, parent: &QModelIndex) -> i32;
+    }
+}
+
+pub use ffi::QAbstractItemModel;
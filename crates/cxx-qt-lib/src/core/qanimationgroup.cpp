@@ -0,0 +1,30 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qanimationgroup.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+void
+qanimationgroupAddAnimation(QAnimationGroup& group,
+                            QAbstractAnimation* animation)
+{
+  // The group takes ownership of the animation
+  if (animation != nullptr) {
+    group.addAnimation(animation);
+  }
+}
+
+void
+qanimationgroupStart(QAnimationGroup& group)
+{
+  // The group is owned by Rust so it must not be deleted when it stops
+  group.start(QAbstractAnimation::KeepWhenStopped);
+}
+
+}
+}
@@ -0,0 +1,177 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::{memory::UniquePtrTarget, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qanimationgroup.h");
+
+        include!("cxx-qt-lib/qmetaobjectconnection.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QMetaObjectConnection = crate::QMetaObjectConnection;
+        include!("cxx-qt-lib/qpropertyanimation.h");
+        type QPropertyAnimation = crate::QPropertyAnimation;
+        include!("cxx-qt-lib/qvariantanimation.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QAbstractAnimationState = crate::QAbstractAnimationState;
+        type QVariantAnimation = crate::QVariantAnimation;
+
+        /// The QAbstractAnimation class is the base of all animations.
+        ///
+        /// It is used when transferring the ownership of an animation to a [QAnimationGroup].
+        type QAbstractAnimation;
+
+        /// The QAnimationGroup class is an abstract base class for groups of animations.
+        ///
+        /// The methods of a [QSequentialAnimationGroup](crate::QSequentialAnimationGroup) or a
+        /// [QParallelAnimationGroup](crate::QParallelAnimationGroup) which are common to all groups are available through this type.
+        type QAnimationGroup;
+
+        /// Returns the number of animations managed by this group.
+        #[rust_name = "animation_count"]
+        fn animationCount(self: &QAnimationGroup) -> i32;
+
+        /// Removes and deletes all animations in this animation group, and resets the current time to 0.
+        fn clear(self: Pin<&mut QAnimationGroup>);
+
+        /// Returns the current loop of the group, starting from 0.
+        #[rust_name = "current_loop"]
+        fn currentLoop(self: &QAnimationGroup) -> i32;
+
+        /// Returns the duration of the group in milliseconds, which is calculated from the animations it contains.
+        fn duration(self: &QAnimationGroup) -> i32;
+
+        /// Returns the number of times the group should loop before it finishes, -1 loops forever.
+        #[rust_name = "loop_count"]
+        fn loopCount(self: &QAnimationGroup) -> i32;
+
+        /// Pauses the group, keeping its current time.
+        fn pause(self: Pin<&mut QAnimationGroup>);
+
+        /// Resumes the group after it was paused.
+        fn resume(self: Pin<&mut QAnimationGroup>);
+
+        /// Sets the number of times the group should loop before it finishes, -1 loops forever.
+        #[rust_name = "set_loop_count"]
+        fn setLoopCount(self: Pin<&mut QAnimationGroup>, loop_count: i32);
+
+        /// Returns the state of the group.
+        fn state(self: &QAnimationGroup) -> QAbstractAnimationState;
+
+        /// Stops the group, the current time is kept.
+        fn stop(self: Pin<&mut QAnimationGroup>);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qanimationgroup_add_animation"]
+        unsafe fn qanimationgroupAddAnimation(
+            group: Pin<&mut QAnimationGroup>,
+            animation: *mut QAbstractAnimation,
+        );
+        #[doc(hidden)]
+        #[rust_name = "qanimationgroup_on_finished"]
+        fn qanimationgroupOnFinished(
+            group: Pin<&mut QAnimationGroup>,
+            callback: Box<QAnimationGroupCallback>,
+        ) -> QMetaObjectConnection;
+        #[doc(hidden)]
+        #[rust_name = "qanimationgroup_start"]
+        fn qanimationgroupStart(group: Pin<&mut QAnimationGroup>);
+
+        #[doc(hidden)]
+        #[cxx_name = "qabstractanimationUpcast"]
+        #[rust_name = "qpropertyanimation_upcast"]
+        unsafe fn upcast(animation: *mut QPropertyAnimation) -> *mut QAbstractAnimation;
+        #[doc(hidden)]
+        #[cxx_name = "qabstractanimationUpcast"]
+        #[rust_name = "qvariantanimation_upcast"]
+        unsafe fn upcast(animation: *mut QVariantAnimation) -> *mut QAbstractAnimation;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        type QAnimationGroupCallback;
+
+        #[doc(hidden)]
+        fn call(self: &mut QAnimationGroupCallback);
+    }
+}
+
+pub use ffi::{QAbstractAnimation, QAnimationGroup};
+
+/// An animation which can be added to a [QAnimationGroup], including other groups.
+pub trait QAnimationGroupChild: UniquePtrTarget + Sized {
+    /// Returns a pointer to the animation as a QAbstractAnimation.
+    ///
+    /// # Safety
+    ///
+    /// The animation must be a valid pointer.
+    #[doc(hidden)]
+    unsafe fn upcast_abstract_animation(animation: *mut Self) -> *mut QAbstractAnimation;
+}
+
+impl QAnimationGroupChild for ffi::QPropertyAnimation {
+    unsafe fn upcast_abstract_animation(animation: *mut Self) -> *mut QAbstractAnimation {
+        ffi::qpropertyanimation_upcast(animation)
+    }
+}
+
+impl QAnimationGroupChild for ffi::QVariantAnimation {
+    unsafe fn upcast_abstract_animation(animation: *mut Self) -> *mut QAbstractAnimation {
+        ffi::qvariantanimation_upcast(animation)
+    }
+}
+
+/// A Rust closure which is called when a [QAnimationGroup] finishes
+#[doc(hidden)]
+pub struct QAnimationGroupCallback {
+    callback: Box<dyn FnMut()>,
+}
+
+impl QAnimationGroupCallback {
+    fn new(callback: impl FnMut() + 'static) -> Box<Self> {
+        Box::new(Self {
+            callback: Box::new(callback),
+        })
+    }
+
+    fn call(&mut self) {
+        (self.callback)();
+    }
+}
+
+impl QAnimationGroup {
+    /// Adds an animation to the end of this group, the group takes ownership of the animation.
+    pub fn add_animation<T: QAnimationGroupChild>(self: Pin<&mut Self>, animation: UniquePtr<T>) {
+        unsafe {
+            ffi::qanimationgroup_add_animation(
+                self,
+                T::upcast_abstract_animation(animation.into_raw()),
+            );
+        }
+    }
+
+    /// Connect a closure which is called when the group has finished,
+    /// which is after the last loop of the group or when it is stopped.
+    ///
+    /// The closure is called on the thread of the group, and is dropped when the connection is disconnected.
+    pub fn on_finished(
+        self: Pin<&mut Self>,
+        callback: impl FnMut() + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qanimationgroup_on_finished(self, QAnimationGroupCallback::new(callback))
+    }
+
+    /// Starts the group from the current time, the group is not deleted when it stops.
+    pub fn start(self: Pin<&mut Self>) {
+        ffi::qanimationgroup_start(self);
+    }
+}
@@ -3,6 +3,7 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 use cxx::{type_id, ExternType};
+use std::borrow::Cow;
 use std::mem::MaybeUninit;
 
 #[cxx::bridge]
@@ -151,8 +152,8 @@ impl std::cmp::Eq for QByteArray {}
 impl std::fmt::Display for QByteArray {
     /// Convert the QByteArray to a Rust string
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        if let Ok(string) = String::from_utf8(self.into()) {
-            write!(f, "{string}")
+        if let Ok(string) = self.to_str() {
+            f.write_str(string)
         } else {
             write!(f, "{:?}", self.as_slice())
         }
@@ -193,6 +194,13 @@ impl From<&[u8]> for QByteArray {
     }
 }
 
+impl From<Vec<u8>> for QByteArray {
+    /// Constructs a QByteArray from a `Vec<u8>`. This makes a deep copy of the data.
+    fn from(bytes: Vec<u8>) -> Self {
+        ffi::qbytearray_from_slice_u8(bytes.as_slice())
+    }
+}
+
 impl From<&QByteArray> for Vec<u8> {
     /// Convert the QByteArray to a `Vec<u8>`. This makes a deep copy of the data.
     fn from(bytearray: &QByteArray) -> Self {
@@ -232,6 +240,20 @@ impl QByteArray {
         ffi::qbytearray_as_slice(self)
     }
 
+    /// Borrow the QByteArray as a string slice without copying
+    ///
+    /// Returns an error if the QByteArray is not valid UTF-8
+    pub fn to_str(&self) -> Result<&str, std::str::Utf8Error> {
+        std::str::from_utf8(self.as_slice())
+    }
+
+    /// Convert the QByteArray to a string, replacing any invalid UTF-8 with U+FFFD REPLACEMENT CHARACTER
+    ///
+    /// If the QByteArray is valid UTF-8 the data is borrowed without copying
+    pub fn to_string_lossy(&self) -> Cow<'_, str> {
+        String::from_utf8_lossy(self.as_slice())
+    }
+
     /// Sets every byte in the byte array to ch.
     /// If size is different from -1 (the default),
     /// the byte array is resized to size size beforehand.
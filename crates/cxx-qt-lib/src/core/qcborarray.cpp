@@ -0,0 +1,54 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qcborarray.h"
+
+#include "../assertion_utils.h"
+
+// QCborArray has a single pointer as it's member
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/serialization/qcborarray.h?h=v5.15.6-lts-lgpl#n289
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/serialization/qcborarray.h?h=v6.2.4#n286
+assert_alignment_and_size(QCborArray,
+                          alignof(::std::size_t),
+                          sizeof(::std::size_t));
+
+static_assert(!::std::is_trivially_copy_assignable<QCborArray>::value);
+static_assert(!::std::is_trivially_copy_constructible<QCborArray>::value);
+
+static_assert(!::std::is_trivially_destructible<QCborArray>::value);
+
+static_assert(QTypeInfo<QCborArray>::isRelocatable);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QCborValue
+qcborarrayAt(const QCborArray& array, ::rust::isize i)
+{
+  return array.at(static_cast<qsizetype>(i));
+}
+
+void
+qcborarrayInsert(QCborArray& array, ::rust::isize i, const QCborValue& value)
+{
+  array.insert(static_cast<qsizetype>(i), value);
+}
+
+::rust::isize
+qcborarrayLen(const QCborArray& array)
+{
+  return static_cast<::rust::isize>(array.size());
+}
+
+void
+qcborarrayRemoveAt(QCborArray& array, ::rust::isize i)
+{
+  array.removeAt(static_cast<qsizetype>(i));
+}
+
+}
+}
@@ -0,0 +1,162 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::fmt;
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qcborarray.h");
+        type QCborArray = super::QCborArray;
+        include!("cxx-qt-lib/qcborvalue.h");
+        type QCborValue = crate::QCborValue;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        /// Adds value to the end of this array.
+        fn append(self: &mut QCborArray, value: &QCborValue);
+
+        /// Empties this array.
+        fn clear(self: &mut QCborArray);
+
+        /// Returns true if this array contains an element that is equal to value.
+        fn contains(self: &QCborArray, value: &QCborValue) -> bool;
+
+        /// Returns true if this array is empty.
+        #[rust_name = "is_empty"]
+        fn isEmpty(self: &QCborArray) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qcborarray_at"]
+        fn qcborarrayAt(array: &QCborArray, i: isize) -> QCborValue;
+        #[doc(hidden)]
+        #[rust_name = "qcborarray_insert"]
+        fn qcborarrayInsert(array: &mut QCborArray, i: isize, value: &QCborValue);
+        #[doc(hidden)]
+        #[rust_name = "qcborarray_len"]
+        fn qcborarrayLen(array: &QCborArray) -> isize;
+        #[doc(hidden)]
+        #[rust_name = "qcborarray_remove_at"]
+        fn qcborarrayRemoveAt(array: &mut QCborArray, i: isize);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qcborarray_drop"]
+        fn drop(array: &mut QCborArray);
+
+        #[doc(hidden)]
+        #[rust_name = "qcborarray_init_default"]
+        fn construct() -> QCborArray;
+        #[doc(hidden)]
+        #[rust_name = "qcborarray_init_from_qcborarray"]
+        fn construct(array: &QCborArray) -> QCborArray;
+
+        #[doc(hidden)]
+        #[rust_name = "qcborarray_eq"]
+        fn operatorEq(a: &QCborArray, b: &QCborArray) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qcborarray_to_debug_qstring"]
+        fn toQString(array: &QCborArray) -> QString;
+    }
+}
+
+/// The QCborArray class is used to hold an array of CBOR elements.
+#[repr(C)]
+pub struct QCborArray {
+    _space: MaybeUninit<usize>,
+}
+
+impl QCborArray {
+    /// Returns the element at index position i in the array, or None if i is out of bounds.
+    pub fn get(&self, i: isize) -> Option<ffi::QCborValue> {
+        if 0 <= i && i < self.len() {
+            Some(ffi::qcborarray_at(self, i))
+        } else {
+            None
+        }
+    }
+
+    /// Inserts value into the array before position i in the array.
+    /// If i is 0, the value is prepended to the array. If i is len(), the value is appended to the array.
+    pub fn insert(&mut self, i: isize, value: &ffi::QCborValue) {
+        ffi::qcborarray_insert(self, i, value);
+    }
+
+    /// An iterator visiting all the elements of the array in order.
+    pub fn iter(&self) -> impl Iterator<Item = ffi::QCborValue> + '_ {
+        (0..self.len()).map(|i| ffi::qcborarray_at(self, i))
+    }
+
+    /// Returns the number of elements in this array.
+    pub fn len(&self) -> isize {
+        ffi::qcborarray_len(self)
+    }
+
+    /// Removes the item at index position i.
+    pub fn remove_at(&mut self, i: isize) {
+        ffi::qcborarray_remove_at(self, i);
+    }
+}
+
+impl Clone for QCborArray {
+    /// Constructs a copy of other.
+    fn clone(&self) -> Self {
+        ffi::qcborarray_init_from_qcborarray(self)
+    }
+}
+
+impl Default for QCborArray {
+    /// Constructs an empty QCborArray.
+    fn default() -> Self {
+        ffi::qcborarray_init_default()
+    }
+}
+
+impl std::cmp::PartialEq for QCborArray {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qcborarray_eq(self, other)
+    }
+}
+
+impl fmt::Debug for QCborArray {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", ffi::qcborarray_to_debug_qstring(self))
+    }
+}
+
+impl Drop for QCborArray {
+    /// Destructor; called immediately before the object is deleted.
+    fn drop(&mut self) {
+        ffi::qcborarray_drop(self)
+    }
+}
+
+impl<'a> FromIterator<&'a ffi::QCborValue> for QCborArray {
+    /// Constructs a QCborArray with the values from the iterator
+    fn from_iter<I: IntoIterator<Item = &'a ffi::QCborValue>>(iter: I) -> Self {
+        let mut array = QCborArray::default();
+        for value in iter {
+            array.append(value);
+        }
+        array
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QCborArray {
+    type Id = type_id!("QCborArray");
+    type Kind = cxx::kind::Trivial;
+}
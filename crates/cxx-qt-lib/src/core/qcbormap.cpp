@@ -0,0 +1,54 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qcbormap.h"
+
+#include "../assertion_utils.h"
+
+// QCborMap has a single pointer as it's member
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/serialization/qcbormap.h?h=v5.15.6-lts-lgpl#n345
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/serialization/qcbormap.h?h=v6.2.4#n338
+assert_alignment_and_size(QCborMap,
+                          alignof(::std::size_t),
+                          sizeof(::std::size_t));
+
+static_assert(!::std::is_trivially_copy_assignable<QCborMap>::value);
+static_assert(!::std::is_trivially_copy_constructible<QCborMap>::value);
+
+static_assert(!::std::is_trivially_destructible<QCborMap>::value);
+
+static_assert(QTypeInfo<QCborMap>::isRelocatable);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+void
+qcbormapInsert(QCborMap& map, const QCborValue& key, const QCborValue& value)
+{
+  map.insert(key, value);
+}
+
+QCborValue
+qcbormapKeyAt(const QCborMap& map, ::rust::isize i)
+{
+  return (map.constBegin() + static_cast<qsizetype>(i)).key();
+}
+
+::rust::isize
+qcbormapLen(const QCborMap& map)
+{
+  return static_cast<::rust::isize>(map.size());
+}
+
+QCborValue
+qcbormapValueAt(const QCborMap& map, ::rust::isize i)
+{
+  return (map.constBegin() + static_cast<qsizetype>(i)).value();
+}
+
+}
+}
@@ -0,0 +1,170 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::fmt;
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qcbormap.h");
+        type QCborMap = super::QCborMap;
+        include!("cxx-qt-lib/qcborvalue.h");
+        type QCborValue = crate::QCborValue;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        /// Empties this map.
+        fn clear(self: &mut QCborMap);
+
+        /// Returns true if this map contains a key-value pair identified by key.
+        fn contains(self: &QCborMap, key: &QCborValue) -> bool;
+
+        /// Returns true if this map is empty.
+        #[rust_name = "is_empty"]
+        fn isEmpty(self: &QCborMap) -> bool;
+
+        /// Removes the element identified by key key from the map, if it is present.
+        fn remove(self: &mut QCborMap, key: &QCborValue);
+
+        #[doc(hidden)]
+        #[rust_name = "value_or_undefined"]
+        fn value(self: &QCborMap, key: &QCborValue) -> QCborValue;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qcbormap_insert"]
+        fn qcbormapInsert(map: &mut QCborMap, key: &QCborValue, value: &QCborValue);
+        #[doc(hidden)]
+        #[rust_name = "qcbormap_key_at"]
+        fn qcbormapKeyAt(map: &QCborMap, i: isize) -> QCborValue;
+        #[doc(hidden)]
+        #[rust_name = "qcbormap_len"]
+        fn qcbormapLen(map: &QCborMap) -> isize;
+        #[doc(hidden)]
+        #[rust_name = "qcbormap_value_at"]
+        fn qcbormapValueAt(map: &QCborMap, i: isize) -> QCborValue;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qcbormap_drop"]
+        fn drop(map: &mut QCborMap);
+
+        #[doc(hidden)]
+        #[rust_name = "qcbormap_init_default"]
+        fn construct() -> QCborMap;
+        #[doc(hidden)]
+        #[rust_name = "qcbormap_init_from_qcbormap"]
+        fn construct(map: &QCborMap) -> QCborMap;
+
+        #[doc(hidden)]
+        #[rust_name = "qcbormap_eq"]
+        fn operatorEq(a: &QCborMap, b: &QCborMap) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qcbormap_to_debug_qstring"]
+        fn toQString(map: &QCborMap) -> QString;
+    }
+}
+
+/// The QCborMap class is used to hold an associative container representable in CBOR.
+///
+/// Keys can be any QCborValue, which are usually integers or strings.
+#[repr(C)]
+pub struct QCborMap {
+    _space: MaybeUninit<usize>,
+}
+
+impl QCborMap {
+    /// Returns the value in the map which corresponds to key, or None if the key is not found.
+    pub fn get(&self, key: &ffi::QCborValue) -> Option<ffi::QCborValue> {
+        if self.contains(key) {
+            Some(self.value_or_undefined(key))
+        } else {
+            None
+        }
+    }
+
+    /// Inserts the key and value into this map.
+    /// If the map already had an equal key, its value will be overwritten by value.
+    pub fn insert(&mut self, key: &ffi::QCborValue, value: &ffi::QCborValue) {
+        ffi::qcbormap_insert(self, key, value);
+    }
+
+    /// An iterator visiting all key-value pairs in the order they are stored in the map.
+    pub fn iter(&self) -> impl Iterator<Item = (ffi::QCborValue, ffi::QCborValue)> + '_ {
+        (0..self.len()).map(|i| {
+            (
+                ffi::qcbormap_key_at(self, i),
+                ffi::qcbormap_value_at(self, i),
+            )
+        })
+    }
+
+    /// Returns the number of elements in this map.
+    pub fn len(&self) -> isize {
+        ffi::qcbormap_len(self)
+    }
+}
+
+impl Clone for QCborMap {
+    /// Constructs a copy of other.
+    fn clone(&self) -> Self {
+        ffi::qcbormap_init_from_qcbormap(self)
+    }
+}
+
+impl Default for QCborMap {
+    /// Constructs an empty QCborMap.
+    fn default() -> Self {
+        ffi::qcbormap_init_default()
+    }
+}
+
+impl std::cmp::PartialEq for QCborMap {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qcbormap_eq(self, other)
+    }
+}
+
+impl fmt::Debug for QCborMap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", ffi::qcbormap_to_debug_qstring(self))
+    }
+}
+
+impl Drop for QCborMap {
+    /// Destructor; called immediately before the object is deleted.
+    fn drop(&mut self) {
+        ffi::qcbormap_drop(self)
+    }
+}
+
+impl<'a> FromIterator<(&'a ffi::QCborValue, &'a ffi::QCborValue)> for QCborMap {
+    /// Constructs a QCborMap with the key-value pairs from the iterator
+    fn from_iter<I: IntoIterator<Item = (&'a ffi::QCborValue, &'a ffi::QCborValue)>>(
+        iter: I,
+    ) -> Self {
+        let mut map = QCborMap::default();
+        for (key, value) in iter {
+            map.insert(key, value);
+        }
+        map
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QCborMap {
+    type Id = type_id!("QCborMap");
+    type Kind = cxx::kind::Trivial;
+}
@@ -0,0 +1,72 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qcborvalue.h"
+
+#include "../assertion_utils.h"
+
+// QCborValue has a qint64, a pointer, and a Type enum as it's members
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/serialization/qcborvalue.h?h=v5.15.6-lts-lgpl#n309
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/serialization/qcborvalue.h?h=v6.2.4#n309
+assert_alignment_and_size(QCborValue,
+                          alignof(::std::int64_t),
+                          sizeof(::std::int64_t) + sizeof(::std::size_t) * 2);
+
+static_assert(!::std::is_trivially_copy_assignable<QCborValue>::value);
+static_assert(!::std::is_trivially_copy_constructible<QCborValue>::value);
+
+static_assert(!::std::is_trivially_destructible<QCborValue>::value);
+
+static_assert(QTypeInfo<QCborValue>::isRelocatable);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+bool
+qcborvalueFromCbor(const QByteArray& bytes,
+                   QCborValue& value,
+                   ::std::int64_t& offset,
+                   QString& errorString)
+{
+  QCborParserError error;
+  value = QCborValue::fromCbor(bytes, &error);
+  if (error.error == QCborError::NoError) {
+    return true;
+  }
+
+  offset = static_cast<::std::int64_t>(error.offset);
+  errorString = error.errorString();
+  return false;
+}
+
+QCborValue
+qcborvalueInitFromInteger(::std::int64_t value)
+{
+  return QCborValue(static_cast<qint64>(value));
+}
+
+QByteArray
+qcborvalueToCbor(const QCborValue& value)
+{
+  return value.toCbor();
+}
+
+QString
+qcborvalueToDiagnosticNotation(const QCborValue& value)
+{
+  return value.toDiagnosticNotation();
+}
+
+::std::int64_t
+qcborvalueToInteger(const QCborValue& value, ::std::int64_t defaultValue)
+{
+  return static_cast<::std::int64_t>(
+    value.toInteger(static_cast<qint64>(defaultValue)));
+}
+
+}
+}
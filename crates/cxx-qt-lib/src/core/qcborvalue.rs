@@ -0,0 +1,493 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::fmt;
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    /// The type of the value stored in a QCborValue.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QCborValueType {
+        /// A positive or negative integer which fits in 64 bits
+        Integer = 0x00,
+        /// An array of binary data
+        ByteArray = 0x40,
+        /// A string of text
+        String = 0x60,
+        /// An array of QCborValue
+        Array = 0x80,
+        /// A map of QCborValue keys to QCborValue values
+        Map = 0xa0,
+        /// A tagged value which has not been converted into a specific type
+        Tag = 0xc0,
+        /// A simple type which has not been converted into a specific type
+        SimpleType = 0x100,
+        /// The simple type for false
+        False = 0x114,
+        /// The simple type for true
+        True = 0x115,
+        /// The simple type for null
+        Null = 0x116,
+        /// The simple type for an undefined value
+        Undefined = 0x117,
+        /// A 64-bit floating point number
+        Double = 0x202,
+        /// A date and time stamp
+        DateTime = 0x10000,
+        /// A URL
+        Url = 0x10020,
+        /// A regular expression
+        RegularExpression = 0x10023,
+        /// A UUID
+        Uuid = 0x10025,
+        /// An invalid value, such as one returned by an out of bounds lookup
+        Invalid = -1,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qcborvalue.h");
+        type QCborValue = super::QCborValue;
+        #[namespace = "rust::cxxqtlib1"]
+        type QCborValueType;
+
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qcborarray.h");
+        type QCborArray = crate::QCborArray;
+        include!("cxx-qt-lib/qcbormap.h");
+        type QCborMap = crate::QCborMap;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        /// Returns true if this QCborValue is of the array type.
+        #[rust_name = "is_array"]
+        fn isArray(self: &QCborValue) -> bool;
+
+        /// Returns true if this QCborValue is of one of the boolean types.
+        #[rust_name = "is_bool"]
+        fn isBool(self: &QCborValue) -> bool;
+
+        /// Returns true if this QCborValue is of the byte array type.
+        #[rust_name = "is_byte_array"]
+        fn isByteArray(self: &QCborValue) -> bool;
+
+        /// Returns true if this QCborValue is of the floating-point type.
+        #[rust_name = "is_double"]
+        fn isDouble(self: &QCborValue) -> bool;
+
+        /// Returns true if this QCborValue is of the integer type.
+        #[rust_name = "is_integer"]
+        fn isInteger(self: &QCborValue) -> bool;
+
+        /// Returns true if this QCborValue is not of any valid type.
+        #[rust_name = "is_invalid"]
+        fn isInvalid(self: &QCborValue) -> bool;
+
+        /// Returns true if this QCborValue is of the map type.
+        #[rust_name = "is_map"]
+        fn isMap(self: &QCborValue) -> bool;
+
+        /// Returns true if this QCborValue is of the null type.
+        #[rust_name = "is_null"]
+        fn isNull(self: &QCborValue) -> bool;
+
+        /// Returns true if this QCborValue is of the string type.
+        #[rust_name = "is_string"]
+        fn isString(self: &QCborValue) -> bool;
+
+        /// Returns true if this QCborValue is of the undefined type.
+        #[rust_name = "is_undefined"]
+        fn isUndefined(self: &QCborValue) -> bool;
+
+        /// Returns the array stored in this QCborValue, if it is of the array type.
+        /// Otherwise, it returns an empty array.
+        #[rust_name = "to_array_or_default"]
+        fn toArray(self: &QCborValue) -> QCborArray;
+
+        /// Returns the boolean value stored in this QCborValue, if it is of a boolean type.
+        /// Otherwise, it returns default_value.
+        #[rust_name = "to_bool_or"]
+        fn toBool(self: &QCborValue, default_value: bool) -> bool;
+
+        /// Returns the byte array stored in this QCborValue, if it is of the byte array type.
+        /// Otherwise, it returns default_value.
+        #[rust_name = "to_qbytearray_or"]
+        fn toByteArray(self: &QCborValue, default_value: &QByteArray) -> QByteArray;
+
+        /// Returns the floating point value stored in this QCborValue, if it is of the Double type.
+        /// If it is of the Integer type, this function returns the integer value converted to double.
+        /// Otherwise, it returns default_value.
+        #[rust_name = "to_double_or"]
+        fn toDouble(self: &QCborValue, default_value: f64) -> f64;
+
+        /// Returns the map stored in this QCborValue, if it is of the map type.
+        /// Otherwise, it returns an empty map.
+        #[rust_name = "to_map_or_default"]
+        fn toMap(self: &QCborValue) -> QCborMap;
+
+        /// Returns the string stored in this QCborValue, if it is of the string type.
+        /// Otherwise, it returns default_value.
+        #[rust_name = "to_qstring_or"]
+        fn toString(self: &QCborValue, default_value: &QString) -> QString;
+
+        /// Returns the type of this QCborValue.
+        #[cxx_name = "type"]
+        fn value_type(self: &QCborValue) -> QCborValueType;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qcborvalue_from_cbor"]
+        fn qcborvalueFromCbor(
+            bytes: &QByteArray,
+            value: &mut QCborValue,
+            offset: &mut i64,
+            error_string: &mut QString,
+        ) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qcborvalue_init_from_integer"]
+        fn qcborvalueInitFromInteger(value: i64) -> QCborValue;
+        #[doc(hidden)]
+        #[rust_name = "qcborvalue_to_cbor"]
+        fn qcborvalueToCbor(value: &QCborValue) -> QByteArray;
+        #[doc(hidden)]
+        #[rust_name = "qcborvalue_to_diagnostic_notation"]
+        fn qcborvalueToDiagnosticNotation(value: &QCborValue) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qcborvalue_to_integer"]
+        fn qcborvalueToInteger(value: &QCborValue, default_value: i64) -> i64;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qcborvalue_drop"]
+        fn drop(value: &mut QCborValue);
+
+        #[doc(hidden)]
+        #[rust_name = "qcborvalue_init_default"]
+        fn construct() -> QCborValue;
+        #[doc(hidden)]
+        #[rust_name = "qcborvalue_init_from_bool"]
+        fn construct(value: bool) -> QCborValue;
+        #[doc(hidden)]
+        #[rust_name = "qcborvalue_init_from_f64"]
+        fn construct(value: f64) -> QCborValue;
+        #[doc(hidden)]
+        #[rust_name = "qcborvalue_init_from_qbytearray"]
+        fn construct(value: &QByteArray) -> QCborValue;
+        #[doc(hidden)]
+        #[rust_name = "qcborvalue_init_from_qcborarray"]
+        fn construct(value: &QCborArray) -> QCborValue;
+        #[doc(hidden)]
+        #[rust_name = "qcborvalue_init_from_qcbormap"]
+        fn construct(value: &QCborMap) -> QCborValue;
+        #[doc(hidden)]
+        #[rust_name = "qcborvalue_init_from_qcborvalue"]
+        fn construct(value: &QCborValue) -> QCborValue;
+        #[doc(hidden)]
+        #[rust_name = "qcborvalue_init_from_qstring"]
+        fn construct(value: &QString) -> QCborValue;
+        #[doc(hidden)]
+        #[rust_name = "qcborvalue_init_from_type"]
+        fn construct(value_type: QCborValueType) -> QCborValue;
+
+        #[doc(hidden)]
+        #[rust_name = "qcborvalue_eq"]
+        fn operatorEq(a: &QCborValue, b: &QCborValue) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qcborvalue_to_debug_qstring"]
+        fn toQString(value: &QCborValue) -> QString;
+    }
+}
+
+pub use ffi::QCborValueType;
+
+/// The QCborValue class encapsulates a value in CBOR.
+///
+/// CBOR is a binary data format which can be used to exchange data with Qt applications
+/// using [QCborValue::from_cbor] and [QCborValue::to_cbor].
+///
+/// When the `ciborium` feature is enabled values can be converted to and from
+/// [ciborium::value::Value], which can then be used with [serde](https://serde.rs/).
+#[repr(C)]
+pub struct QCborValue {
+    _n: MaybeUninit<i64>,
+    _container: MaybeUninit<usize>,
+    _type: MaybeUninit<usize>,
+}
+
+/// The error which occurred while parsing CBOR with [QCborValue::from_cbor].
+#[derive(Debug)]
+pub struct QCborParserError {
+    /// The offset of the data which could not be parsed
+    pub offset: i64,
+    /// A human readable description of the error
+    pub error_string: ffi::QString,
+}
+
+impl fmt::Display for QCborParserError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at offset {}", self.error_string, self.offset)
+    }
+}
+
+impl std::error::Error for QCborParserError {}
+
+impl QCborValue {
+    /// Decodes one CBOR item from the CBOR data in the byte array.
+    ///
+    /// If the data is not valid CBOR an error is returned with where and why the parsing failed.
+    pub fn from_cbor(bytes: &ffi::QByteArray) -> Result<Self, QCborParserError> {
+        let mut value = Self::default();
+        let mut offset = 0;
+        let mut error_string = ffi::QString::default();
+        if ffi::qcborvalue_from_cbor(bytes, &mut value, &mut offset, &mut error_string) {
+            Ok(value)
+        } else {
+            Err(QCborParserError {
+                offset,
+                error_string,
+            })
+        }
+    }
+
+    /// Creates a QCborValue of the null type.
+    pub fn null() -> Self {
+        ffi::qcborvalue_init_from_type(QCborValueType::Null)
+    }
+
+    /// Returns the array stored in this QCborValue, if it is of the array type.
+    pub fn to_array(&self) -> Option<ffi::QCborArray> {
+        if self.is_array() {
+            Some(self.to_array_or_default())
+        } else {
+            None
+        }
+    }
+
+    /// Returns the boolean value stored in this QCborValue, if it is of a boolean type.
+    pub fn to_bool(&self) -> Option<bool> {
+        if self.is_bool() {
+            Some(self.to_bool_or(false))
+        } else {
+            None
+        }
+    }
+
+    /// Encodes this QCborValue object to its CBOR representation.
+    pub fn to_cbor(&self) -> ffi::QByteArray {
+        ffi::qcborvalue_to_cbor(self)
+    }
+
+    /// Returns the floating point value stored in this QCborValue, if it is of the Double type.
+    pub fn to_double(&self) -> Option<f64> {
+        if self.is_double() {
+            Some(self.to_double_or(0.0))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the integer value stored in this QCborValue, if it is of the integer type.
+    pub fn to_integer(&self) -> Option<i64> {
+        if self.is_integer() {
+            Some(self.to_integer_or(0))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the integer value stored in this QCborValue, if it is of the integer type.
+    /// If it is of the Double type, this function returns the floating point value converted to integer.
+    /// Otherwise, it returns default_value.
+    pub fn to_integer_or(&self, default_value: i64) -> i64 {
+        ffi::qcborvalue_to_integer(self, default_value)
+    }
+
+    /// Returns the map stored in this QCborValue, if it is of the map type.
+    pub fn to_map(&self) -> Option<ffi::QCborMap> {
+        if self.is_map() {
+            Some(self.to_map_or_default())
+        } else {
+            None
+        }
+    }
+
+    /// Returns the byte array stored in this QCborValue, if it is of the byte array type.
+    pub fn to_qbytearray(&self) -> Option<ffi::QByteArray> {
+        if self.is_byte_array() {
+            Some(self.to_qbytearray_or(&ffi::QByteArray::default()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the string stored in this QCborValue, if it is of the string type.
+    pub fn to_qstring(&self) -> Option<ffi::QString> {
+        if self.is_string() {
+            Some(self.to_qstring_or(&ffi::QString::default()))
+        } else {
+            None
+        }
+    }
+}
+
+impl Clone for QCborValue {
+    /// Constructs a copy of other.
+    fn clone(&self) -> Self {
+        ffi::qcborvalue_init_from_qcborvalue(self)
+    }
+}
+
+impl Default for QCborValue {
+    /// Creates a QCborValue of the undefined type.
+    fn default() -> Self {
+        ffi::qcborvalue_init_default()
+    }
+}
+
+impl std::cmp::PartialEq for QCborValue {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qcborvalue_eq(self, other)
+    }
+}
+
+impl fmt::Display for QCborValue {
+    /// Convert the QCborValue to the extended CBOR diagnostic notation
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", ffi::qcborvalue_to_diagnostic_notation(self))
+    }
+}
+
+impl fmt::Debug for QCborValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", ffi::qcborvalue_to_debug_qstring(self))
+    }
+}
+
+impl Drop for QCborValue {
+    /// Destructor; called immediately before the object is deleted.
+    fn drop(&mut self) {
+        ffi::qcborvalue_drop(self)
+    }
+}
+
+impl From<bool> for QCborValue {
+    /// Creates a QCborValue of a boolean type
+    fn from(value: bool) -> Self {
+        ffi::qcborvalue_init_from_bool(value)
+    }
+}
+
+impl From<f64> for QCborValue {
+    /// Creates a QCborValue of the Double type
+    fn from(value: f64) -> Self {
+        ffi::qcborvalue_init_from_f64(value)
+    }
+}
+
+impl From<i64> for QCborValue {
+    /// Creates a QCborValue of the integer type
+    fn from(value: i64) -> Self {
+        ffi::qcborvalue_init_from_integer(value)
+    }
+}
+
+impl From<&ffi::QByteArray> for QCborValue {
+    /// Creates a QCborValue of the byte array type
+    fn from(value: &ffi::QByteArray) -> Self {
+        ffi::qcborvalue_init_from_qbytearray(value)
+    }
+}
+
+impl From<&ffi::QCborArray> for QCborValue {
+    /// Creates a QCborValue of the array type
+    fn from(value: &ffi::QCborArray) -> Self {
+        ffi::qcborvalue_init_from_qcborarray(value)
+    }
+}
+
+impl From<&ffi::QCborMap> for QCborValue {
+    /// Creates a QCborValue of the map type
+    fn from(value: &ffi::QCborMap) -> Self {
+        ffi::qcborvalue_init_from_qcbormap(value)
+    }
+}
+
+impl From<&ffi::QString> for QCborValue {
+    /// Creates a QCborValue of the string type
+    fn from(value: &ffi::QString) -> Self {
+        ffi::qcborvalue_init_from_qstring(value)
+    }
+}
+
+impl From<&str> for QCborValue {
+    /// Creates a QCborValue of the string type
+    ///
+    /// Note that this converts from UTF-8 to UTF-16
+    fn from(value: &str) -> Self {
+        QCborValue::from(&ffi::QString::from(value))
+    }
+}
+
+#[cfg(feature = "ciborium")]
+impl From<&ciborium::value::Value> for QCborValue {
+    /// Creates a QCborValue from a ciborium value, by encoding it as CBOR
+    fn from(value: &ciborium::value::Value) -> Self {
+        let mut bytes = Vec::new();
+        ciborium::ser::into_writer(value, &mut bytes)
+            .expect("a ciborium value can always be written to memory");
+        QCborValue::from_cbor(&ffi::QByteArray::from(bytes.as_slice()))
+            .expect("ciborium always writes valid CBOR")
+    }
+}
+
+#[cfg(feature = "ciborium")]
+impl TryFrom<&QCborValue> for ciborium::value::Value {
+    type Error = ciborium::de::Error<std::io::Error>;
+
+    /// Creates a ciborium value from a QCborValue, by encoding it as CBOR
+    fn try_from(value: &QCborValue) -> Result<Self, Self::Error> {
+        ciborium::de::from_reader(value.to_cbor().as_slice())
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QCborValue {
+    type Id = type_id!("QCborValue");
+    type Kind = cxx::kind::Trivial;
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "ciborium")]
+    use super::*;
+
+    #[cfg(feature = "ciborium")]
+    #[test]
+    fn test_ciborium() {
+        let value = ciborium::value::Value::Map(vec![(
+            ciborium::value::Value::Text("name".to_owned()),
+            ciborium::value::Value::Array(vec![
+                ciborium::value::Value::Integer(1.into()),
+                ciborium::value::Value::Bool(true),
+            ]),
+        )]);
+        let qcborvalue = QCborValue::from(&value);
+        assert!(qcborvalue.is_map());
+
+        let ciborium_value = ciborium::value::Value::try_from(&qcborvalue).unwrap();
+        assert_eq!(ciborium_value, value);
+    }
+}
@@ -0,0 +1,19 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qconcatenatetablesproxymodel.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QConcatenateTablesProxyModel>
+qconcatenatetablesproxymodelNew()
+{
+  return ::std::make_unique<QConcatenateTablesProxyModel>();
+}
+
+}
+}
@@ -0,0 +1,118 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qconcatenatetablesproxymodel.h");
+
+        include!("cxx-qt-lib/qabstractitemmodel.h");
+        type QAbstractItemModel = crate::QAbstractItemModel;
+        include!("cxx-qt-lib/qmodelindex.h");
+        type QModelIndex = crate::QModelIndex;
+
+        /// The QConcatenateTablesProxyModel class proxies multiple source models, concatenating their rows.
+        ///
+        /// This allows multiple Rust models to be stacked into a single view without writing a combining model.
+        ///
+        /// ```ignore
+        /// let mut proxy = QConcatenateTablesProxyModel::new();
+        /// unsafe {
+        ///     proxy.pin_mut().add_source_model(first_model.pin_mut().as_abstract_item_model_ptr());
+        ///     proxy.pin_mut().add_source_model(second_model.pin_mut().as_abstract_item_model_ptr());
+        /// }
+        /// ```
+        type QConcatenateTablesProxyModel;
+
+        #[doc(hidden)]
+        #[rust_name = "cxx_add_source_model"]
+        unsafe fn addSourceModel(
+            self: Pin<&mut QConcatenateTablesProxyModel>,
+            source_model: *mut QAbstractItemModel,
+        );
+
+        /// Returns the number of columns, which is the smallest number of columns of the source models.
+        #[rust_name = "column_count"]
+        fn columnCount(self: &QConcatenateTablesProxyModel, parent: &QModelIndex) -> i32;
+
+        /// Returns the proxy index for a given source index. The source index must originate from one of the source models.
+        #[rust_name = "map_from_source"]
+        fn mapFromSource(
+            self: &QConcatenateTablesProxyModel,
+            source_index: &QModelIndex,
+        ) -> QModelIndex;
+
+        /// Returns the source index for a given proxy index.
+        #[rust_name = "map_to_source"]
+        fn mapToSource(
+            self: &QConcatenateTablesProxyModel,
+            proxy_index: &QModelIndex,
+        ) -> QModelIndex;
+
+        #[doc(hidden)]
+        #[rust_name = "cxx_remove_source_model"]
+        unsafe fn removeSourceModel(
+            self: Pin<&mut QConcatenateTablesProxyModel>,
+            source_model: *mut QAbstractItemModel,
+        );
+
+        /// Returns the number of rows, which is the sum of the rows of the source models.
+        #[rust_name = "row_count"]
+        fn rowCount(self: &QConcatenateTablesProxyModel, parent: &QModelIndex) -> i32;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qconcatenatetablesproxymodel_new"]
+        fn qconcatenatetablesproxymodelNew() -> UniquePtr<QConcatenateTablesProxyModel>;
+        #[doc(hidden)]
+        #[cxx_name = "qabstractitemmodelUpcast"]
+        #[rust_name = "qconcatenatetablesproxymodel_upcast"]
+        unsafe fn upcast(model: *mut QConcatenateTablesProxyModel) -> *mut QAbstractItemModel;
+    }
+}
+
+pub use ffi::QConcatenateTablesProxyModel;
+
+impl QConcatenateTablesProxyModel {
+    /// Adds a source model. This emits rowsInserted for the rows of the source model.
+    ///
+    /// # Safety
+    ///
+    /// The source model must be a valid pointer and must remain valid until it is removed from the proxy,
+    /// or the proxy is destroyed.
+    pub unsafe fn add_source_model(
+        self: Pin<&mut Self>,
+        source_model: *mut ffi::QAbstractItemModel,
+    ) {
+        self.cxx_add_source_model(source_model);
+    }
+
+    /// Returns a pointer to the proxy as a QAbstractItemModel, so that it can be used as the source of another proxy.
+    pub fn as_abstract_item_model_ptr(self: Pin<&mut Self>) -> *mut ffi::QAbstractItemModel {
+        unsafe { ffi::qconcatenatetablesproxymodel_upcast(self.get_unchecked_mut()) }
+    }
+
+    /// Constructs a new concatenating model with no source models.
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qconcatenatetablesproxymodel_new()
+    }
+
+    /// Removes the source model. This emits rowsAboutToBeRemoved and rowsRemoved for the rows of the source model.
+    ///
+    /// # Safety
+    ///
+    /// The source model must be a valid pointer.
+    pub unsafe fn remove_source_model(
+        self: Pin<&mut Self>,
+        source_model: *mut ffi::QAbstractItemModel,
+    ) {
+        self.cxx_remove_source_model(source_model);
+    }
+}
@@ -12,6 +12,9 @@ mod ffi {
     unsafe extern "C++" {
         include!("cxx-qt-lib/qbytearray.h");
         type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qmetaobjectconnection.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QMetaObjectConnection = crate::QMetaObjectConnection;
         include!("cxx-qt-lib/qstring.h");
         type QString = crate::QString;
         include!("cxx-qt-lib/qstringlist.h");
@@ -49,6 +52,12 @@ mod ffi {
         #[rust_name = "qcoreapplication_library_paths"]
         fn qapplicationLibraryPaths(app: &QCoreApplication) -> QStringList;
         #[doc(hidden)]
+        #[rust_name = "qcoreapplication_on_about_to_quit"]
+        fn qcoreapplicationOnAboutToQuit(
+            app: Pin<&mut QCoreApplication>,
+            callback: Box<QCoreApplicationAboutToQuitCallback>,
+        ) -> QMetaObjectConnection;
+        #[doc(hidden)]
         #[rust_name = "qcoreapplication_organization_domain"]
         fn qapplicationOrganizationDomain(app: &QCoreApplication) -> QString;
         #[doc(hidden)]
@@ -76,10 +85,37 @@ mod ffi {
     // and need to use references or pointers.
     // static_assert(QTypeInfo<QCoreApplication>::isRelocatable);
     impl UniquePtr<QCoreApplication> {}
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        type QCoreApplicationAboutToQuitCallback;
+
+        #[doc(hidden)]
+        fn call(self: &mut QCoreApplicationAboutToQuitCallback);
+    }
 }
 
 pub use ffi::QCoreApplication;
 
+/// A Rust closure which is called when a [QCoreApplication] is about to quit
+#[doc(hidden)]
+pub struct QCoreApplicationAboutToQuitCallback {
+    callback: Box<dyn FnMut()>,
+}
+
+impl QCoreApplicationAboutToQuitCallback {
+    fn new(callback: impl FnMut() + 'static) -> Box<Self> {
+        Box::new(Self {
+            callback: Box::new(callback),
+        })
+    }
+
+    fn call(&mut self) {
+        (self.callback)();
+    }
+}
+
 impl QCoreApplication {
     /// Prepends path to the beginning of the library path list,
     /// ensuring that it is searched for libraries first.
@@ -133,6 +169,29 @@ impl QCoreApplication {
         ffi::qcoreapplication_new(&vector)
     }
 
+    /// Connect a closure which is called when the application is about to quit the main event loop,
+    /// for example when the last window is closed or quit() is called.
+    ///
+    /// The closure is called from exec() before it returns, while the event loop and any QObjects
+    /// such as a QQmlApplicationEngine are still alive, so it is the place to shut down Rust subsystems
+    /// such as database connections or worker threads which may still be used by QObjects.
+    ///
+    /// This is the only ordering which is guaranteed, the closure is not called if the application
+    /// is destroyed without exec() having returned, and it does not change the order in which QObjects
+    /// are destroyed afterwards. QObjects owned by Rust values are destroyed when those values are dropped,
+    /// so they should be declared after the application to be dropped before it.
+    ///
+    /// The closure is dropped when the connection is disconnected or the application is destroyed.
+    pub fn on_about_to_quit(
+        self: Pin<&mut Self>,
+        callback: impl FnMut() + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qcoreapplication_on_about_to_quit(
+            self,
+            QCoreApplicationAboutToQuitCallback::new(callback),
+        )
+    }
+
     /// The Internet domain of the organization that wrote this application
     pub fn organization_domain(&self) -> QString {
         ffi::qcoreapplication_organization_domain(self)
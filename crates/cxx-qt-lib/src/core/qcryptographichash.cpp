@@ -0,0 +1,41 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qcryptographichash.h"
+#include "cxx-qt-lib/qbytearray.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QCryptographicHash>
+qcryptographichashNew(QCryptographicHashAlgorithm method)
+{
+  return ::std::make_unique<QCryptographicHash>(method);
+}
+
+void
+qcryptographichashAddData(QCryptographicHash& hash,
+                          ::rust::Slice<const ::std::uint8_t> data)
+{
+  // Note that rust::Slice here is borrowed and only used for this call
+  hash.addData(qbytearrayFromRawData(data));
+}
+
+QByteArray
+qcryptographichashHash(::rust::Slice<const ::std::uint8_t> data,
+                       QCryptographicHashAlgorithm method)
+{
+  return QCryptographicHash::hash(qbytearrayFromRawData(data), method);
+}
+
+::std::int32_t
+qcryptographichashHashLength(QCryptographicHashAlgorithm method)
+{
+  return static_cast<::std::int32_t>(QCryptographicHash::hashLength(method));
+}
+
+}
+}
@@ -0,0 +1,116 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+#[cxx::bridge]
+mod ffi {
+    /// The cryptographic hash algorithms supported by QCryptographicHash.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QCryptographicHashAlgorithm {
+        /// Generate an MD4 hash sum
+        Md4 = 0,
+        /// Generate an MD5 hash sum
+        Md5 = 1,
+        /// Generate an SHA-1 hash sum
+        Sha1 = 2,
+        /// Generate an SHA-224 hash sum (SHA-2)
+        Sha224 = 3,
+        /// Generate an SHA-256 hash sum (SHA-2)
+        Sha256 = 4,
+        /// Generate an SHA-384 hash sum (SHA-2)
+        Sha384 = 5,
+        /// Generate an SHA-512 hash sum (SHA-2)
+        Sha512 = 6,
+        /// Generate a Keccak-224 hash sum
+        Keccak_224 = 7,
+        /// Generate a Keccak-256 hash sum
+        Keccak_256 = 8,
+        /// Generate a Keccak-384 hash sum
+        Keccak_384 = 9,
+        /// Generate a Keccak-512 hash sum
+        Keccak_512 = 10,
+        /// Generate an SHA3-224 hash sum
+        RealSha3_224 = 11,
+        /// Generate an SHA3-256 hash sum
+        RealSha3_256 = 12,
+        /// Generate an SHA3-384 hash sum
+        RealSha3_384 = 13,
+        /// Generate an SHA3-512 hash sum
+        RealSha3_512 = 14,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qcryptographichash.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QCryptographicHashAlgorithm;
+
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+
+        /// The QCryptographicHash class provides a way to generate cryptographic hashes.
+        ///
+        /// The hashes are the same as those generated by Qt, so can be used to compare against
+        /// checksums computed by C++ code.
+        ///
+        /// ```ignore
+        /// let mut hash = QCryptographicHash::new(QCryptographicHashAlgorithm::Sha256);
+        /// hash.pin_mut().add_data(b"Hello ");
+        /// hash.pin_mut().add_data(b"World");
+        /// let result = hash.result();
+        /// ```
+        type QCryptographicHash;
+
+        /// Resets the object.
+        fn reset(self: Pin<&mut QCryptographicHash>);
+
+        /// Returns the final hash value.
+        fn result(self: &QCryptographicHash) -> QByteArray;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qcryptographichash_new"]
+        fn qcryptographichashNew(
+            method: QCryptographicHashAlgorithm,
+        ) -> UniquePtr<QCryptographicHash>;
+        #[doc(hidden)]
+        #[rust_name = "qcryptographichash_add_data"]
+        fn qcryptographichashAddData(hash: Pin<&mut QCryptographicHash>, data: &[u8]);
+        #[doc(hidden)]
+        #[rust_name = "qcryptographichash_hash"]
+        fn qcryptographichashHash(data: &[u8], method: QCryptographicHashAlgorithm) -> QByteArray;
+        #[doc(hidden)]
+        #[rust_name = "qcryptographichash_hash_length"]
+        fn qcryptographichashHashLength(method: QCryptographicHashAlgorithm) -> i32;
+    }
+}
+
+pub use ffi::{QCryptographicHash, QCryptographicHashAlgorithm};
+
+impl QCryptographicHash {
+    /// Adds the data to the cryptographic hash.
+    pub fn add_data(self: Pin<&mut Self>, data: &[u8]) {
+        ffi::qcryptographichash_add_data(self, data);
+    }
+
+    /// Returns the hash of data using method.
+    pub fn hash(data: &[u8], method: QCryptographicHashAlgorithm) -> ffi::QByteArray {
+        ffi::qcryptographichash_hash(data, method)
+    }
+
+    /// Returns the size of the output of the selected hash method in bytes.
+    pub fn hash_length(method: QCryptographicHashAlgorithm) -> i32 {
+        ffi::qcryptographichash_hash_length(method)
+    }
+
+    /// Constructs an object that can be used to create a cryptographic hash from data using method.
+    pub fn new(method: QCryptographicHashAlgorithm) -> UniquePtr<Self> {
+        ffi::qcryptographichash_new(method)
+    }
+}
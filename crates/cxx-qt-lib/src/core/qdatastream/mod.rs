@@ -0,0 +1,434 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+#[cfg(not(target_os = "emscripten"))]
+mod qdatastream_qdatetime;
+
+#[cxx::bridge]
+mod ffi {
+    /// The byte order used for reading and writing data.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QDataStreamByteOrder {
+        /// Most significant byte first (the default)
+        BigEndian = 0,
+        /// Least significant byte first
+        LittleEndian = 1,
+    }
+
+    /// The precision of floating point numbers used for reading and writing data.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QDataStreamFloatingPointPrecision {
+        /// All floating point numbers in the data stream have 32-bit precision.
+        SinglePrecision = 0,
+        /// All floating point numbers in the data stream have 64-bit precision.
+        DoublePrecision = 1,
+    }
+
+    /// The current status of the data stream.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QDataStreamStatus {
+        /// The data stream is operating normally.
+        Ok = 0,
+        /// The data stream has read past the end of the data in the underlying device.
+        ReadPastEnd = 1,
+        /// The data stream has read corrupt data.
+        ReadCorruptData = 2,
+        /// The data stream cannot write to the underlying device.
+        WriteFailed = 3,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qdatastream.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QDataStreamByteOrder;
+        #[namespace = "rust::cxxqtlib1"]
+        type QDataStreamFloatingPointPrecision;
+        #[namespace = "rust::cxxqtlib1"]
+        type QDataStreamStatus;
+
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qdate.h");
+        type QDate = crate::QDate;
+        include!("cxx-qt-lib/qmargins.h");
+        type QMargins = crate::QMargins;
+        include!("cxx-qt-lib/qmarginsf.h");
+        type QMarginsF = crate::QMarginsF;
+        include!("cxx-qt-lib/qpoint.h");
+        type QPoint = crate::QPoint;
+        include!("cxx-qt-lib/qpointf.h");
+        type QPointF = crate::QPointF;
+        include!("cxx-qt-lib/qrect.h");
+        type QRect = crate::QRect;
+        include!("cxx-qt-lib/qrectf.h");
+        type QRectF = crate::QRectF;
+        include!("cxx-qt-lib/qsize.h");
+        type QSize = crate::QSize;
+        include!("cxx-qt-lib/qsizef.h");
+        type QSizeF = crate::QSizeF;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = crate::QStringList;
+        include!("cxx-qt-lib/qtime.h");
+        type QTime = crate::QTime;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = crate::QUrl;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+
+        /// The QDataStream class provides serialization of binary data to a QByteArray.
+        ///
+        /// The format is compatible with data serialized by Qt applications, as long as
+        /// the same [version](QDataStream::set_version) of the format is used for reading as for writing.
+        ///
+        /// Values are read and written with [QDataStream::read] and [QDataStream::write],
+        /// for any type which implements [QDataStreamValue].
+        ///
+        /// ```ignore
+        /// let bytes = QDataStream::write_to_bytearray(|mut stream| {
+        ///     stream.as_mut().write(&QString::from("KDAB"));
+        ///     stream.as_mut().write(&42_i32);
+        /// });
+        ///
+        /// let mut stream = QDataStream::from_bytearray(&bytes);
+        /// let name: QString = stream.pin_mut().read();
+        /// let number: i32 = stream.pin_mut().read();
+        /// ```
+        type QDataStream;
+
+        /// Returns true if the I/O device has reached the end position (end of the stream or file)
+        /// or if there is no I/O device set; otherwise returns false.
+        #[rust_name = "at_end"]
+        fn atEnd(self: &QDataStream) -> bool;
+
+        /// Returns the current byte order setting.
+        #[rust_name = "byte_order"]
+        fn byteOrder(self: &QDataStream) -> QDataStreamByteOrder;
+
+        /// Returns the floating point precision of the data stream.
+        #[rust_name = "floating_point_precision"]
+        fn floatingPointPrecision(self: &QDataStream) -> QDataStreamFloatingPointPrecision;
+
+        /// Resets the status of the data stream.
+        #[rust_name = "reset_status"]
+        fn resetStatus(self: Pin<&mut QDataStream>);
+
+        /// Sets the serialization byte order to bo.
+        #[rust_name = "set_byte_order"]
+        fn setByteOrder(self: Pin<&mut QDataStream>, bo: QDataStreamByteOrder);
+
+        /// Sets the floating point precision of the data stream to precision.
+        #[rust_name = "set_floating_point_precision"]
+        fn setFloatingPointPrecision(
+            self: Pin<&mut QDataStream>,
+            precision: QDataStreamFloatingPointPrecision,
+        );
+
+        /// Sets the status of the data stream to the status given.
+        ///
+        /// Subsequent calls to set_status() are ignored until reset_status() is called.
+        #[rust_name = "set_status"]
+        fn setStatus(self: Pin<&mut QDataStream>, status: QDataStreamStatus);
+
+        /// Returns the status of the data stream.
+        fn status(self: &QDataStream) -> QDataStreamStatus;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qdatastream_new_reader"]
+        fn qdatastreamNewReader(bytes: &QByteArray) -> UniquePtr<QDataStream>;
+        #[doc(hidden)]
+        #[rust_name = "qdatastream_new_writer"]
+        fn qdatastreamNewWriter(bytes: &mut QByteArray) -> UniquePtr<QDataStream>;
+        #[doc(hidden)]
+        #[rust_name = "qdatastream_version"]
+        fn qdatastreamVersion(stream: &QDataStream) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qdatastream_set_version"]
+        fn qdatastreamSetVersion(stream: Pin<&mut QDataStream>, version: i32);
+
+        #[rust_name = "qdatastream_read_bool"]
+        fn qdatastreamRead(stream: Pin<&mut QDataStream>) -> bool;
+        #[rust_name = "qdatastream_read_f32"]
+        fn qdatastreamRead(stream: Pin<&mut QDataStream>) -> f32;
+        #[rust_name = "qdatastream_read_f64"]
+        fn qdatastreamRead(stream: Pin<&mut QDataStream>) -> f64;
+        #[rust_name = "qdatastream_read_i8"]
+        fn qdatastreamRead(stream: Pin<&mut QDataStream>) -> i8;
+        #[rust_name = "qdatastream_read_i16"]
+        fn qdatastreamRead(stream: Pin<&mut QDataStream>) -> i16;
+        #[rust_name = "qdatastream_read_i32"]
+        fn qdatastreamRead(stream: Pin<&mut QDataStream>) -> i32;
+        #[rust_name = "qdatastream_read_i64"]
+        fn qdatastreamRead(stream: Pin<&mut QDataStream>) -> i64;
+        #[rust_name = "qdatastream_read_u8"]
+        fn qdatastreamRead(stream: Pin<&mut QDataStream>) -> u8;
+        #[rust_name = "qdatastream_read_u16"]
+        fn qdatastreamRead(stream: Pin<&mut QDataStream>) -> u16;
+        #[rust_name = "qdatastream_read_u32"]
+        fn qdatastreamRead(stream: Pin<&mut QDataStream>) -> u32;
+        #[rust_name = "qdatastream_read_u64"]
+        fn qdatastreamRead(stream: Pin<&mut QDataStream>) -> u64;
+        #[rust_name = "qdatastream_read_qbytearray"]
+        fn qdatastreamRead(stream: Pin<&mut QDataStream>) -> QByteArray;
+        #[rust_name = "qdatastream_read_qdate"]
+        fn qdatastreamRead(stream: Pin<&mut QDataStream>) -> QDate;
+        #[rust_name = "qdatastream_read_qmargins"]
+        fn qdatastreamRead(stream: Pin<&mut QDataStream>) -> QMargins;
+        #[rust_name = "qdatastream_read_qmarginsf"]
+        fn qdatastreamRead(stream: Pin<&mut QDataStream>) -> QMarginsF;
+        #[rust_name = "qdatastream_read_qpoint"]
+        fn qdatastreamRead(stream: Pin<&mut QDataStream>) -> QPoint;
+        #[rust_name = "qdatastream_read_qpointf"]
+        fn qdatastreamRead(stream: Pin<&mut QDataStream>) -> QPointF;
+        #[rust_name = "qdatastream_read_qrect"]
+        fn qdatastreamRead(stream: Pin<&mut QDataStream>) -> QRect;
+        #[rust_name = "qdatastream_read_qrectf"]
+        fn qdatastreamRead(stream: Pin<&mut QDataStream>) -> QRectF;
+        #[rust_name = "qdatastream_read_qsize"]
+        fn qdatastreamRead(stream: Pin<&mut QDataStream>) -> QSize;
+        #[rust_name = "qdatastream_read_qsizef"]
+        fn qdatastreamRead(stream: Pin<&mut QDataStream>) -> QSizeF;
+        #[rust_name = "qdatastream_read_qstring"]
+        fn qdatastreamRead(stream: Pin<&mut QDataStream>) -> QString;
+        #[rust_name = "qdatastream_read_qstringlist"]
+        fn qdatastreamRead(stream: Pin<&mut QDataStream>) -> QStringList;
+        #[rust_name = "qdatastream_read_qtime"]
+        fn qdatastreamRead(stream: Pin<&mut QDataStream>) -> QTime;
+        #[rust_name = "qdatastream_read_qurl"]
+        fn qdatastreamRead(stream: Pin<&mut QDataStream>) -> QUrl;
+        #[rust_name = "qdatastream_read_qvariant"]
+        fn qdatastreamRead(stream: Pin<&mut QDataStream>) -> QVariant;
+
+        #[rust_name = "qdatastream_write_bool"]
+        fn qdatastreamWrite(stream: Pin<&mut QDataStream>, value: &bool);
+        #[rust_name = "qdatastream_write_f32"]
+        fn qdatastreamWrite(stream: Pin<&mut QDataStream>, value: &f32);
+        #[rust_name = "qdatastream_write_f64"]
+        fn qdatastreamWrite(stream: Pin<&mut QDataStream>, value: &f64);
+        #[rust_name = "qdatastream_write_i8"]
+        fn qdatastreamWrite(stream: Pin<&mut QDataStream>, value: &i8);
+        #[rust_name = "qdatastream_write_i16"]
+        fn qdatastreamWrite(stream: Pin<&mut QDataStream>, value: &i16);
+        #[rust_name = "qdatastream_write_i32"]
+        fn qdatastreamWrite(stream: Pin<&mut QDataStream>, value: &i32);
+        #[rust_name = "qdatastream_write_i64"]
+        fn qdatastreamWrite(stream: Pin<&mut QDataStream>, value: &i64);
+        #[rust_name = "qdatastream_write_u8"]
+        fn qdatastreamWrite(stream: Pin<&mut QDataStream>, value: &u8);
+        #[rust_name = "qdatastream_write_u16"]
+        fn qdatastreamWrite(stream: Pin<&mut QDataStream>, value: &u16);
+        #[rust_name = "qdatastream_write_u32"]
+        fn qdatastreamWrite(stream: Pin<&mut QDataStream>, value: &u32);
+        #[rust_name = "qdatastream_write_u64"]
+        fn qdatastreamWrite(stream: Pin<&mut QDataStream>, value: &u64);
+        #[rust_name = "qdatastream_write_qbytearray"]
+        fn qdatastreamWrite(stream: Pin<&mut QDataStream>, value: &QByteArray);
+        #[rust_name = "qdatastream_write_qdate"]
+        fn qdatastreamWrite(stream: Pin<&mut QDataStream>, value: &QDate);
+        #[rust_name = "qdatastream_write_qmargins"]
+        fn qdatastreamWrite(stream: Pin<&mut QDataStream>, value: &QMargins);
+        #[rust_name = "qdatastream_write_qmarginsf"]
+        fn qdatastreamWrite(stream: Pin<&mut QDataStream>, value: &QMarginsF);
+        #[rust_name = "qdatastream_write_qpoint"]
+        fn qdatastreamWrite(stream: Pin<&mut QDataStream>, value: &QPoint);
+        #[rust_name = "qdatastream_write_qpointf"]
+        fn qdatastreamWrite(stream: Pin<&mut QDataStream>, value: &QPointF);
+        #[rust_name = "qdatastream_write_qrect"]
+        fn qdatastreamWrite(stream: Pin<&mut QDataStream>, value: &QRect);
+        #[rust_name = "qdatastream_write_qrectf"]
+        fn qdatastreamWrite(stream: Pin<&mut QDataStream>, value: &QRectF);
+        #[rust_name = "qdatastream_write_qsize"]
+        fn qdatastreamWrite(stream: Pin<&mut QDataStream>, value: &QSize);
+        #[rust_name = "qdatastream_write_qsizef"]
+        fn qdatastreamWrite(stream: Pin<&mut QDataStream>, value: &QSizeF);
+        #[rust_name = "qdatastream_write_qstring"]
+        fn qdatastreamWrite(stream: Pin<&mut QDataStream>, value: &QString);
+        #[rust_name = "qdatastream_write_qstringlist"]
+        fn qdatastreamWrite(stream: Pin<&mut QDataStream>, value: &QStringList);
+        #[rust_name = "qdatastream_write_qtime"]
+        fn qdatastreamWrite(stream: Pin<&mut QDataStream>, value: &QTime);
+        #[rust_name = "qdatastream_write_qurl"]
+        fn qdatastreamWrite(stream: Pin<&mut QDataStream>, value: &QUrl);
+        #[rust_name = "qdatastream_write_qvariant"]
+        fn qdatastreamWrite(stream: Pin<&mut QDataStream>, value: &QVariant);
+    }
+}
+
+pub use ffi::{
+    QDataStream, QDataStreamByteOrder, QDataStreamFloatingPointPrecision, QDataStreamStatus,
+};
+
+impl QDataStream {
+    /// Constructs a read-only data stream that operates on a copy of the byte array bytes.
+    pub fn from_bytearray(bytes: &ffi::QByteArray) -> UniquePtr<Self> {
+        ffi::qdatastream_new_reader(bytes)
+    }
+
+    /// Reads a value of type T from the stream.
+    ///
+    /// If the stream does not contain a valid value then a default value is returned
+    /// and the [status](QDataStream::status) of the stream is changed.
+    pub fn read<T: QDataStreamValue>(self: Pin<&mut Self>) -> T {
+        T::read(self)
+    }
+
+    /// Sets the version number of the data serialization format to version.
+    ///
+    /// This should match the version used by the Qt application which reads or wrote the data,
+    /// the versions are listed in the `QDataStream::Version` enum of Qt (e.g. 19 is `Qt_5_15`).
+    pub fn set_version(self: Pin<&mut Self>, version: i32) {
+        ffi::qdatastream_set_version(self, version)
+    }
+
+    /// Returns the version number of the data serialization format.
+    pub fn version(&self) -> i32 {
+        ffi::qdatastream_version(self)
+    }
+
+    /// Writes a value of type T to the stream.
+    pub fn write<T: QDataStreamValue>(self: Pin<&mut Self>, value: &T) {
+        T::write(self, value)
+    }
+
+    /// Constructs a write-only data stream, calls the given function with the stream,
+    /// and then returns the data which was written.
+    pub fn write_to_bytearray(f: impl FnOnce(Pin<&mut QDataStream>)) -> ffi::QByteArray {
+        let mut bytes = ffi::QByteArray::default();
+        {
+            // The stream refers to the bytes, so ensure that it is dropped
+            // before the bytes are moved
+            let mut stream = ffi::qdatastream_new_writer(&mut bytes);
+            f(stream.pin_mut());
+        }
+        bytes
+    }
+}
+
+/// Trait implementation for a value which can be read from and written to a [QDataStream].
+pub trait QDataStreamValue: Sized {
+    fn read(stream: Pin<&mut QDataStream>) -> Self;
+    fn write(stream: Pin<&mut QDataStream>, value: &Self);
+}
+
+macro_rules! impl_qdatastream_value {
+    ( $typeName:ty, $readFn:path, $writeFn:path ) => {
+        impl QDataStreamValue for $typeName {
+            fn read(stream: Pin<&mut QDataStream>) -> Self {
+                $readFn(stream)
+            }
+
+            fn write(stream: Pin<&mut QDataStream>, value: &Self) {
+                $writeFn(stream, value)
+            }
+        }
+    };
+}
+
+impl_qdatastream_value!(
+    bool,
+    ffi::qdatastream_read_bool,
+    ffi::qdatastream_write_bool
+);
+impl_qdatastream_value!(f32, ffi::qdatastream_read_f32, ffi::qdatastream_write_f32);
+impl_qdatastream_value!(f64, ffi::qdatastream_read_f64, ffi::qdatastream_write_f64);
+impl_qdatastream_value!(i8, ffi::qdatastream_read_i8, ffi::qdatastream_write_i8);
+impl_qdatastream_value!(i16, ffi::qdatastream_read_i16, ffi::qdatastream_write_i16);
+impl_qdatastream_value!(i32, ffi::qdatastream_read_i32, ffi::qdatastream_write_i32);
+impl_qdatastream_value!(i64, ffi::qdatastream_read_i64, ffi::qdatastream_write_i64);
+impl_qdatastream_value!(u8, ffi::qdatastream_read_u8, ffi::qdatastream_write_u8);
+impl_qdatastream_value!(u16, ffi::qdatastream_read_u16, ffi::qdatastream_write_u16);
+impl_qdatastream_value!(u32, ffi::qdatastream_read_u32, ffi::qdatastream_write_u32);
+impl_qdatastream_value!(u64, ffi::qdatastream_read_u64, ffi::qdatastream_write_u64);
+impl_qdatastream_value!(
+    crate::QByteArray,
+    ffi::qdatastream_read_qbytearray,
+    ffi::qdatastream_write_qbytearray
+);
+impl_qdatastream_value!(
+    crate::QDate,
+    ffi::qdatastream_read_qdate,
+    ffi::qdatastream_write_qdate
+);
+#[cfg(not(target_os = "emscripten"))]
+impl_qdatastream_value!(
+    crate::QDateTime,
+    qdatastream_qdatetime::qdatastream_read_qdatetime,
+    qdatastream_qdatetime::qdatastream_write_qdatetime
+);
+impl_qdatastream_value!(
+    crate::QMargins,
+    ffi::qdatastream_read_qmargins,
+    ffi::qdatastream_write_qmargins
+);
+impl_qdatastream_value!(
+    crate::QMarginsF,
+    ffi::qdatastream_read_qmarginsf,
+    ffi::qdatastream_write_qmarginsf
+);
+impl_qdatastream_value!(
+    crate::QPoint,
+    ffi::qdatastream_read_qpoint,
+    ffi::qdatastream_write_qpoint
+);
+impl_qdatastream_value!(
+    crate::QPointF,
+    ffi::qdatastream_read_qpointf,
+    ffi::qdatastream_write_qpointf
+);
+impl_qdatastream_value!(
+    crate::QRect,
+    ffi::qdatastream_read_qrect,
+    ffi::qdatastream_write_qrect
+);
+impl_qdatastream_value!(
+    crate::QRectF,
+    ffi::qdatastream_read_qrectf,
+    ffi::qdatastream_write_qrectf
+);
+impl_qdatastream_value!(
+    crate::QSize,
+    ffi::qdatastream_read_qsize,
+    ffi::qdatastream_write_qsize
+);
+impl_qdatastream_value!(
+    crate::QSizeF,
+    ffi::qdatastream_read_qsizef,
+    ffi::qdatastream_write_qsizef
+);
+impl_qdatastream_value!(
+    crate::QString,
+    ffi::qdatastream_read_qstring,
+    ffi::qdatastream_write_qstring
+);
+impl_qdatastream_value!(
+    crate::QStringList,
+    ffi::qdatastream_read_qstringlist,
+    ffi::qdatastream_write_qstringlist
+);
+impl_qdatastream_value!(
+    crate::QTime,
+    ffi::qdatastream_read_qtime,
+    ffi::qdatastream_write_qtime
+);
+impl_qdatastream_value!(
+    crate::QUrl,
+    ffi::qdatastream_read_qurl,
+    ffi::qdatastream_write_qurl
+);
+impl_qdatastream_value!(
+    crate::QVariant,
+    ffi::qdatastream_read_qvariant,
+    ffi::qdatastream_write_qvariant
+);
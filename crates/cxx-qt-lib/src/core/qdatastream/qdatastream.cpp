@@ -0,0 +1,74 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qdatastream.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QDataStream>
+qdatastreamNewReader(const QByteArray& bytes)
+{
+  // The stream keeps an implicitly shared copy of the QByteArray
+  return ::std::make_unique<QDataStream>(bytes);
+}
+
+::std::unique_ptr<QDataStream>
+qdatastreamNewWriter(QByteArray& bytes)
+{
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  return ::std::make_unique<QDataStream>(&bytes, QIODeviceBase::WriteOnly);
+#else
+  return ::std::make_unique<QDataStream>(&bytes, QIODevice::WriteOnly);
+#endif
+}
+
+::std::int32_t
+qdatastreamVersion(const QDataStream& stream)
+{
+  return static_cast<::std::int32_t>(stream.version());
+}
+
+void
+qdatastreamSetVersion(QDataStream& stream, ::std::int32_t version)
+{
+  stream.setVersion(static_cast<int>(version));
+}
+
+template<>
+::std::int64_t
+qdatastreamRead(QDataStream& stream)
+{
+  qint64 value = 0;
+  stream >> value;
+  return static_cast<::std::int64_t>(value);
+}
+
+template<>
+::std::uint64_t
+qdatastreamRead(QDataStream& stream)
+{
+  quint64 value = 0;
+  stream >> value;
+  return static_cast<::std::uint64_t>(value);
+}
+
+template<>
+void
+qdatastreamWrite(QDataStream& stream, const ::std::int64_t& value)
+{
+  stream << static_cast<qint64>(value);
+}
+
+template<>
+void
+qdatastreamWrite(QDataStream& stream, const ::std::uint64_t& value)
+{
+  stream << static_cast<quint64>(value);
+}
+
+}
+}
@@ -0,0 +1,25 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+pub mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qdatastream.h");
+        type QDataStream = crate::QDataStream;
+
+        include!("cxx-qt-lib/qdatetime.h");
+        type QDateTime = crate::QDateTime;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[rust_name = "qdatastream_read_qdatetime"]
+        fn qdatastreamRead(stream: Pin<&mut QDataStream>) -> QDateTime;
+        #[rust_name = "qdatastream_write_qdatetime"]
+        fn qdatastreamWrite(stream: Pin<&mut QDataStream>, value: &QDateTime);
+    }
+}
+
+pub(crate) use ffi::{qdatastream_read_qdatetime, qdatastream_write_qdatetime};
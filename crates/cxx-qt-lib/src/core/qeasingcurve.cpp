@@ -0,0 +1,24 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qeasingcurve.h"
+
+#include "../assertion_utils.h"
+
+// QEasingCurve has a single pointer as it's member
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/tools/qeasingcurve.h?h=v5.15.6-lts-lgpl#n118
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/tools/qeasingcurve.h?h=v6.2.4#n103
+assert_alignment_and_size(QEasingCurve,
+                          alignof(::std::size_t),
+                          sizeof(::std::size_t));
+
+static_assert(!::std::is_trivially_copy_assignable<QEasingCurve>::value);
+static_assert(!::std::is_trivially_copy_constructible<QEasingCurve>::value);
+
+static_assert(!::std::is_trivially_destructible<QEasingCurve>::value);
+
+static_assert(QTypeInfo<QEasingCurve>::isRelocatable);
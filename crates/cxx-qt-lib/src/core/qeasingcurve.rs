@@ -0,0 +1,277 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::fmt;
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    /// The types of easing curve, see the Qt documentation for graphs of each curve.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QEasingCurveType {
+        /// Easing curve for a linear (t) function: velocity is constant.
+        Linear = 0,
+        /// Easing curve for a quadratic (t^2) function: accelerating from zero velocity.
+        InQuad,
+        /// Easing curve for a quadratic (t^2) function: decelerating to zero velocity.
+        OutQuad,
+        /// Easing curve for a quadratic (t^2) function: acceleration until halfway, then deceleration.
+        InOutQuad,
+        /// Easing curve for a quadratic (t^2) function: deceleration until halfway, then acceleration.
+        OutInQuad,
+        /// Easing curve for a cubic (t^3) function: accelerating from zero velocity.
+        InCubic,
+        /// Easing curve for a cubic (t^3) function: decelerating to zero velocity.
+        OutCubic,
+        /// Easing curve for a cubic (t^3) function: acceleration until halfway, then deceleration.
+        InOutCubic,
+        /// Easing curve for a cubic (t^3) function: deceleration until halfway, then acceleration.
+        OutInCubic,
+        /// Easing curve for a quartic (t^4) function: accelerating from zero velocity.
+        InQuart,
+        /// Easing curve for a quartic (t^4) function: decelerating to zero velocity.
+        OutQuart,
+        /// Easing curve for a quartic (t^4) function: acceleration until halfway, then deceleration.
+        InOutQuart,
+        /// Easing curve for a quartic (t^4) function: deceleration until halfway, then acceleration.
+        OutInQuart,
+        /// Easing curve for a quintic (t^5) function: accelerating from zero velocity.
+        InQuint,
+        /// Easing curve for a quintic (t^5) function: decelerating to zero velocity.
+        OutQuint,
+        /// Easing curve for a quintic (t^5) function: acceleration until halfway, then deceleration.
+        InOutQuint,
+        /// Easing curve for a quintic (t^5) function: deceleration until halfway, then acceleration.
+        OutInQuint,
+        /// Easing curve for a sinusoidal (sin(t)) function: accelerating from zero velocity.
+        InSine,
+        /// Easing curve for a sinusoidal (sin(t)) function: decelerating to zero velocity.
+        OutSine,
+        /// Easing curve for a sinusoidal (sin(t)) function: acceleration until halfway, then deceleration.
+        InOutSine,
+        /// Easing curve for a sinusoidal (sin(t)) function: deceleration until halfway, then acceleration.
+        OutInSine,
+        /// Easing curve for an exponential (2^t) function: accelerating from zero velocity.
+        InExpo,
+        /// Easing curve for an exponential (2^t) function: decelerating to zero velocity.
+        OutExpo,
+        /// Easing curve for an exponential (2^t) function: acceleration until halfway, then deceleration.
+        InOutExpo,
+        /// Easing curve for an exponential (2^t) function: deceleration until halfway, then acceleration.
+        OutInExpo,
+        /// Easing curve for a circular (sqrt(1-t^2)) function: accelerating from zero velocity.
+        InCirc,
+        /// Easing curve for a circular (sqrt(1-t^2)) function: decelerating to zero velocity.
+        OutCirc,
+        /// Easing curve for a circular (sqrt(1-t^2)) function: acceleration until halfway, then deceleration.
+        InOutCirc,
+        /// Easing curve for a circular (sqrt(1-t^2)) function: deceleration until halfway, then acceleration.
+        OutInCirc,
+        /// Easing curve for an elastic (exponentially decaying sine wave) function: accelerating from zero velocity.
+        InElastic,
+        /// Easing curve for an elastic (exponentially decaying sine wave) function: decelerating to zero velocity.
+        OutElastic,
+        /// Easing curve for an elastic (exponentially decaying sine wave) function: acceleration until halfway, then deceleration.
+        InOutElastic,
+        /// Easing curve for an elastic (exponentially decaying sine wave) function: deceleration until halfway, then acceleration.
+        OutInElastic,
+        /// Easing curve for a back (overshooting cubic) function: accelerating from zero velocity.
+        InBack,
+        /// Easing curve for a back (overshooting cubic) function: decelerating to zero velocity.
+        OutBack,
+        /// Easing curve for a back (overshooting cubic) function: acceleration until halfway, then deceleration.
+        InOutBack,
+        /// Easing curve for a back (overshooting cubic) function: deceleration until halfway, then acceleration.
+        OutInBack,
+        /// Easing curve for a bounce (exponentially decaying parabolic bounce) function: accelerating from zero velocity.
+        InBounce,
+        /// Easing curve for a bounce (exponentially decaying parabolic bounce) function: decelerating to zero velocity.
+        OutBounce,
+        /// Easing curve for a bounce (exponentially decaying parabolic bounce) function: acceleration until halfway, then deceleration.
+        InOutBounce,
+        /// Easing curve for a bounce (exponentially decaying parabolic bounce) function: deceleration until halfway, then acceleration.
+        OutInBounce,
+        /// Easing curve which starts slowly and then accelerates.
+        InCurve,
+        /// Easing curve which starts quickly and then decelerates.
+        OutCurve,
+        /// Easing curve for a sine wave.
+        SineCurve,
+        /// Easing curve for a cosine wave.
+        CosineCurve,
+        /// Easing curve defined by cubic bezier segments.
+        BezierSpline,
+        /// Easing curve defined by TCB (tension, continuity, bias) segments.
+        TCBSpline,
+        /// Easing curve defined by a custom function.
+        Custom,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qeasingcurve.h");
+        type QEasingCurve = super::QEasingCurve;
+        #[namespace = "rust::cxxqtlib1"]
+        type QEasingCurveType;
+
+        include!("cxx-qt-lib/qpointf.h");
+        type QPointF = crate::QPointF;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        /// Adds a segment of a cubic bezier spline to define a custom easing curve.
+        /// It is only applicable if the type is BezierSpline.
+        ///
+        /// Note that the spline implicitly starts at (0.0, 0.0) and has to end at (1.0, 1.0) to be a valid easing curve.
+        /// c1 and c2 are the control points used for drawing the curve, end is the endpoint of the curve.
+        #[rust_name = "add_cubic_bezier_segment"]
+        fn addCubicBezierSegment(
+            self: &mut QEasingCurve,
+            c1: &QPointF,
+            c2: &QPointF,
+            end_point: &QPointF,
+        );
+
+        /// Adds a segment of a TCB bezier spline to define a custom easing curve.
+        /// It is only applicable if the type is TCBSpline.
+        ///
+        /// The spline has to start explicitly at (0.0, 0.0) and has to end at (1.0, 1.0) to be a valid easing curve.
+        #[rust_name = "add_tcb_segment"]
+        fn addTCBSegment(self: &mut QEasingCurve, next_point: &QPointF, t: f64, c: f64, b: f64);
+
+        /// Returns the amplitude. This is not applicable for all curve types.
+        ///
+        /// It is only applicable for bounce and elastic curves.
+        fn amplitude(self: &QEasingCurve) -> f64;
+
+        /// Returns the type of the easing curve.
+        #[cxx_name = "type"]
+        fn curve_type(self: &QEasingCurve) -> QEasingCurveType;
+
+        /// Returns the overshoot. This is not applicable for all curve types.
+        ///
+        /// It is only applicable if the type is InBack, OutBack, InOutBack or OutInBack.
+        fn overshoot(self: &QEasingCurve) -> f64;
+
+        /// Returns the period. This is not applicable for all curve types.
+        ///
+        /// It is only applicable if the type is InElastic, OutElastic, InOutElastic or OutInElastic.
+        fn period(self: &QEasingCurve) -> f64;
+
+        /// Sets the amplitude to amplitude.
+        ///
+        /// This will set the amplitude of the bounce or the amplitude of the elastic "spring" effect.
+        /// The higher the number, the higher the amplitude.
+        #[rust_name = "set_amplitude"]
+        fn setAmplitude(self: &mut QEasingCurve, amplitude: f64);
+
+        /// Sets the overshoot to overshoot.
+        ///
+        /// 0 produces no overshoot, and the default value of 1.70158 produces an overshoot of 10 percent.
+        #[rust_name = "set_overshoot"]
+        fn setOvershoot(self: &mut QEasingCurve, overshoot: f64);
+
+        /// Sets the period to period.
+        ///
+        /// Setting a small period value will give a high frequency of the curve.
+        /// A large period will give it a small frequency.
+        #[rust_name = "set_period"]
+        fn setPeriod(self: &mut QEasingCurve, period: f64);
+
+        /// Sets the type of the easing curve to curve_type.
+        #[rust_name = "set_curve_type"]
+        fn setType(self: &mut QEasingCurve, curve_type: QEasingCurveType);
+
+        /// Return the effective progress for the easing curve at progress.
+        /// Whereas progress must be between 0 and 1, the returned effective progress can be outside those bounds.
+        #[rust_name = "value_for_progress"]
+        fn valueForProgress(self: &QEasingCurve, progress: f64) -> f64;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qeasingcurve_drop"]
+        fn drop(curve: &mut QEasingCurve);
+
+        #[doc(hidden)]
+        #[rust_name = "qeasingcurve_init_default"]
+        fn construct() -> QEasingCurve;
+        #[doc(hidden)]
+        #[rust_name = "qeasingcurve_init_from_qeasingcurve"]
+        fn construct(curve: &QEasingCurve) -> QEasingCurve;
+        #[doc(hidden)]
+        #[rust_name = "qeasingcurve_init_from_type"]
+        fn construct(curve_type: QEasingCurveType) -> QEasingCurve;
+
+        #[doc(hidden)]
+        #[rust_name = "qeasingcurve_eq"]
+        fn operatorEq(a: &QEasingCurve, b: &QEasingCurve) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qeasingcurve_debug"]
+        fn toQString(curve: &QEasingCurve) -> QString;
+    }
+}
+
+pub use ffi::QEasingCurveType;
+
+/// The QEasingCurve class provides easing curves for controlling animation.
+///
+/// Easing curves describe a function that controls how the speed of the interpolation
+/// between 0 and 1 should be.
+#[repr(C)]
+pub struct QEasingCurve {
+    _space: MaybeUninit<usize>,
+}
+
+impl Clone for QEasingCurve {
+    /// Construct a copy of other.
+    fn clone(&self) -> Self {
+        ffi::qeasingcurve_init_from_qeasingcurve(self)
+    }
+}
+
+impl Default for QEasingCurve {
+    /// Constructs a linear easing curve.
+    fn default() -> Self {
+        ffi::qeasingcurve_init_default()
+    }
+}
+
+impl From<ffi::QEasingCurveType> for QEasingCurve {
+    /// Constructs an easing curve of the given type.
+    fn from(curve_type: ffi::QEasingCurveType) -> Self {
+        ffi::qeasingcurve_init_from_type(curve_type)
+    }
+}
+
+impl PartialEq for QEasingCurve {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qeasingcurve_eq(self, other)
+    }
+}
+
+impl fmt::Debug for QEasingCurve {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", ffi::qeasingcurve_debug(self))
+    }
+}
+
+impl Drop for QEasingCurve {
+    fn drop(&mut self) {
+        ffi::qeasingcurve_drop(self);
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QEasingCurve {
+    type Id = type_id!("QEasingCurve");
+    type Kind = cxx::kind::Trivial;
+}
@@ -0,0 +1,74 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qevent.h"
+
+namespace {
+
+// An event which owns a payload from Rust, the payload is dropped with the event
+class CxxQtCustomEvent : public QEvent
+{
+public:
+  CxxQtCustomEvent(QEvent::Type type,
+                   ::std::size_t payload,
+                   ::rust::Fn<void(::std::size_t)> drop)
+    : QEvent(type)
+    , m_payload(payload)
+    , m_drop(drop)
+  {
+  }
+
+  ~CxxQtCustomEvent() override { m_drop(m_payload); }
+
+  ::std::size_t payload() const { return m_payload; }
+
+private:
+  ::std::size_t m_payload;
+  ::rust::Fn<void(::std::size_t)> m_drop;
+};
+
+}
+
+namespace rust {
+namespace cxxqtlib1 {
+
+const QTimerEvent*
+qeventAsTimerEvent(const QEvent& event)
+{
+  if (event.type() == QEvent::Timer) {
+    return static_cast<const QTimerEvent*>(&event);
+  }
+
+  return nullptr;
+}
+
+::std::size_t
+qeventCustomPayload(const QEvent& event)
+{
+  const auto* customEvent = dynamic_cast<const CxxQtCustomEvent*>(&event);
+  if (customEvent != nullptr) {
+    return customEvent->payload();
+  }
+
+  return 0;
+}
+
+::std::unique_ptr<QEvent>
+qeventNewCustom(QEventType type,
+                ::std::size_t payload,
+                ::rust::Fn<void(::std::size_t)> drop)
+{
+  return ::std::make_unique<CxxQtCustomEvent>(type, payload, drop);
+}
+
+QEventType
+qeventRegisterEventType()
+{
+  return static_cast<QEventType>(QEvent::registerEventType());
+}
+
+}
+}
@@ -0,0 +1,182 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use std::any::Any;
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum type defines the valid event types in Qt.
+    ///
+    /// Note that only common event types are listed here, as the type is a plain value
+    /// other event types, such as custom types from `QEventType::User` onwards, can still be compared.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QEventType {
+        /// Not an event.
+        None = 0,
+        /// Regular timer events (QTimerEvent).
+        Timer = 1,
+        /// Mouse press (QMouseEvent).
+        MouseButtonPress = 2,
+        /// Mouse release (QMouseEvent).
+        MouseButtonRelease = 3,
+        /// Mouse press again (QMouseEvent).
+        MouseButtonDblClick = 4,
+        /// Mouse move (QMouseEvent).
+        MouseMove = 5,
+        /// Key press (QKeyEvent).
+        KeyPress = 6,
+        /// Key release (QKeyEvent).
+        KeyRelease = 7,
+        /// Widget or Window gains keyboard focus.
+        FocusIn = 8,
+        /// Widget or Window loses keyboard focus.
+        FocusOut = 9,
+        /// Mouse enters widget's boundaries.
+        Enter = 10,
+        /// Mouse leaves widget's boundaries.
+        Leave = 11,
+        /// Widget's position changed.
+        Move = 13,
+        /// Widget's size changed.
+        Resize = 14,
+        /// Widget was shown on screen.
+        Show = 17,
+        /// Widget was hidden.
+        Hide = 18,
+        /// Widget was closed.
+        Close = 19,
+        /// Mouse wheel rolled.
+        Wheel = 31,
+        /// Key press in child, for overriding shortcut key handling (QKeyEvent).
+        ShortcutOverride = 51,
+        /// The object will be deleted after it has cleaned up.
+        DeferredDelete = 52,
+        /// An object gets a child.
+        ChildAdded = 68,
+        /// An object loses a child.
+        ChildRemoved = 71,
+        /// Key press in child for shortcut key handling (QShortcutEvent).
+        Shortcut = 117,
+        /// User-defined event.
+        User = 1000,
+        /// Last user event ID.
+        MaxUser = 65535,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qevent.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QEventType;
+
+        /// The QEvent class is the base class of all event classes.
+        ///
+        /// Events of a QObject can be observed from Rust with [QObject::install_event_filter](crate::QObject::install_event_filter),
+        /// the given closure is then called for each event of the object and returning true stops the event being handled further.
+        type QEvent;
+
+        /// The QTimerEvent class contains parameters that describe a timer event.
+        type QTimerEvent;
+
+        /// Returns true if the accept flag of the event object is set.
+        #[rust_name = "is_accepted"]
+        fn isAccepted(self: &QEvent) -> bool;
+
+        /// Returns true if the event originated outside the application (a system event); otherwise returns false.
+        fn spontaneous(self: &QEvent) -> bool;
+
+        /// Returns the event type.
+        #[cxx_name = "type"]
+        fn event_type(self: &QEvent) -> QEventType;
+
+        /// Returns the unique timer identifier, which is the same identifier as returned from QObject::startTimer().
+        #[rust_name = "timer_id"]
+        fn timerId(self: &QTimerEvent) -> i32;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qevent_as_timer_event"]
+        fn qeventAsTimerEvent(event: &QEvent) -> *const QTimerEvent;
+        #[doc(hidden)]
+        #[rust_name = "qevent_custom_payload"]
+        fn qeventCustomPayload(event: &QEvent) -> usize;
+        #[doc(hidden)]
+        #[rust_name = "qevent_new_custom"]
+        fn qeventNewCustom(
+            event_type: QEventType,
+            payload: usize,
+            drop: fn(usize),
+        ) -> UniquePtr<QEvent>;
+        #[doc(hidden)]
+        #[rust_name = "qevent_register_event_type"]
+        fn qeventRegisterEventType() -> QEventType;
+    }
+
+    // QEvent is polymorphic and has no public copy constructor, so it is only used behind a pointer or reference.
+    impl UniquePtr<QEvent> {}
+}
+
+pub use ffi::{QEvent, QEventType, QTimerEvent};
+
+/// The payload of a custom event, this is boxed again so that it can be passed to C++ as a thin pointer
+type CustomEventPayload = Box<dyn Any + Send>;
+
+fn custom_event_payload_drop(payload: usize) {
+    // SAFETY: the payload was created by Box::into_raw in QEvent::new_custom
+    // and the C++ event only calls this once when it is destroyed
+    drop(unsafe { Box::from_raw(payload as *mut CustomEventPayload) });
+}
+
+impl QEventType {
+    /// Registers and returns a custom event type, which is unique for the lifetime of the application.
+    ///
+    /// This can then be used with [QEvent::new_custom] to create events for the type.
+    pub fn register() -> Self {
+        ffi::qevent_register_event_type()
+    }
+}
+
+impl QEvent {
+    /// Returns the payload of the event if it is a custom event created with [QEvent::new_custom]
+    /// and the payload is of the type `T`.
+    pub fn custom_payload<T: Any>(&self) -> Option<&T> {
+        let payload = ffi::qevent_custom_payload(self) as *const CustomEventPayload;
+        // SAFETY: the pointer is either null or points to the payload owned by this event,
+        // so has the same lifetime
+        unsafe { payload.as_ref() }.and_then(|payload| (**payload).downcast_ref::<T>())
+    }
+
+    /// Create a custom event of the given type which owns the given payload.
+    ///
+    /// The event can be sent to a QObject with `QCoreApplication::postEvent`, by declaring
+    /// `qcoreapplicationPostEvent` from `cxx-qt-lib/qcoreapplication.h` for the receiver type in a bridge.
+    /// The receiver can then handle the event in an event filter and read the payload with [QEvent::custom_payload].
+    /// Note that the payload is dropped on the thread that destroys the event, which is the thread of the receiver.
+    ///
+    /// ```ignore
+    /// unsafe extern "C++" {
+    ///     include!("cxx-qt-lib/qcoreapplication.h");
+    ///     #[namespace = "rust::cxxqtlib1"]
+    ///     #[rust_name = "post_event"]
+    ///     fn qcoreapplicationPostEvent(receiver: Pin<&mut MyObject>, event: UniquePtr<QEvent>);
+    /// }
+    /// ```
+    pub fn new_custom<T: Any + Send>(event_type: QEventType, payload: T) -> cxx::UniquePtr<Self> {
+        let payload: Box<CustomEventPayload> = Box::new(Box::new(payload));
+        ffi::qevent_new_custom(
+            event_type,
+            Box::into_raw(payload) as usize,
+            custom_event_payload_drop,
+        )
+    }
+
+    /// Returns the event as a [QTimerEvent] if it is a [QEventType::Timer] event
+    pub fn as_timer_event(&self) -> Option<&QTimerEvent> {
+        // SAFETY: the pointer is either null or points to this event, so has the same lifetime
+        unsafe { ffi::qevent_as_timer_event(self).as_ref() }
+    }
+}
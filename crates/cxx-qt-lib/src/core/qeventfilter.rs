@@ -0,0 +1,81 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QEvent, QObject};
+use core::pin::Pin;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qevent.h");
+        type QEvent = crate::QEvent;
+        include!("cxx-qt-lib/qobjectconnect.h");
+        type QObject = crate::QObject;
+
+        include!("cxx-qt-lib/qeventfilter.h");
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qobject_install_event_filter"]
+        fn qobjectInstallEventFilter(
+            watched: Pin<&mut QObject>,
+            callback: Box<QEventFilterCallback>,
+        );
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        type QEventFilterCallback;
+
+        #[doc(hidden)]
+        fn call(self: &mut QEventFilterCallback, event: &QEvent) -> bool;
+    }
+}
+
+/// A Rust closure which is called for each event of a [QObject] with an event filter
+#[doc(hidden)]
+pub struct QEventFilterCallback {
+    callback: Box<dyn FnMut(&QEvent) -> bool>,
+}
+
+impl QEventFilterCallback {
+    fn new(callback: impl FnMut(&QEvent) -> bool + 'static) -> Box<Self> {
+        Box::new(Self {
+            callback: Box::new(callback),
+        })
+    }
+
+    fn call(&mut self, event: &QEvent) -> bool {
+        (self.callback)(event)
+    }
+}
+
+impl QObject {
+    /// Install a closure as an event filter on this object, which is called for each event
+    /// of the object before it is handled. Returning true stops the event being handled any further.
+    ///
+    /// The closure is called on the thread of the object, and is dropped when the object is destroyed.
+    /// A QObject defined by CXX-Qt can be upcast with [Connect::as_qobject_mut](crate::Connect::as_qobject_mut).
+    ///
+    /// ```ignore
+    /// let key_presses = Rc::new(Cell::new(0));
+    /// let counter = key_presses.clone();
+    /// my_object.as_qobject_mut().install_event_filter(move |event| {
+    ///     if event.event_type() == QEventType::KeyPress {
+    ///         counter.set(counter.get() + 1);
+    ///     }
+    ///     false
+    /// });
+    /// ```
+    pub fn install_event_filter(
+        self: Pin<&mut Self>,
+        filter: impl FnMut(&QEvent) -> bool + 'static,
+    ) {
+        ffi::qobject_install_event_filter(self, QEventFilterCallback::new(filter))
+    }
+}
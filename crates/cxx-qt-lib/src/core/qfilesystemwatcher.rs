@@ -0,0 +1,148 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qfilesystemwatcher.h");
+
+        include!("cxx-qt-lib/qmetaobjectconnection.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QMetaObjectConnection = crate::QMetaObjectConnection;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = crate::QStringList;
+
+        /// The QFileSystemWatcher class provides an interface for monitoring files and directories for modifications.
+        ///
+        /// Changes are delivered to Rust closures on the thread of the watcher by the Qt event loop.
+        ///
+        /// ```ignore
+        /// let mut watcher = QFileSystemWatcher::new();
+        /// watcher.pin_mut().add_path(&QString::from("config.toml"));
+        /// watcher
+        ///     .pin_mut()
+        ///     .on_file_changed(|path| println!("{path} changed"))
+        ///     .release();
+        /// ```
+        type QFileSystemWatcher;
+
+        /// Adds path to the file system watcher if path exists.
+        /// The path is not added if it does not exist, or if it is already being monitored by the file system watcher.
+        ///
+        /// Returns true if path was added successfully, otherwise returns false.
+        #[rust_name = "add_path"]
+        fn addPath(self: Pin<&mut QFileSystemWatcher>, path: &QString) -> bool;
+
+        /// Adds each path in paths to the file system watcher.
+        /// Paths are not added if they do not exist, or if they are already being monitored by the file system watcher.
+        ///
+        /// Returns a list of paths that could not be added.
+        #[rust_name = "add_paths"]
+        fn addPaths(self: Pin<&mut QFileSystemWatcher>, paths: &QStringList) -> QStringList;
+
+        /// Returns a list of paths to directories that are being watched.
+        fn directories(self: &QFileSystemWatcher) -> QStringList;
+
+        /// Returns a list of paths to files that are being watched.
+        fn files(self: &QFileSystemWatcher) -> QStringList;
+
+        /// Removes the specified path from the file system watcher.
+        ///
+        /// If the watch is successfully removed, true is returned.
+        #[rust_name = "remove_path"]
+        fn removePath(self: Pin<&mut QFileSystemWatcher>, path: &QString) -> bool;
+
+        /// Removes the specified paths from the file system watcher.
+        ///
+        /// The return value is a list of paths which were not able to be unwatched successfully.
+        #[rust_name = "remove_paths"]
+        fn removePaths(self: Pin<&mut QFileSystemWatcher>, paths: &QStringList) -> QStringList;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qfilesystemwatcher_new"]
+        fn qfilesystemwatcherNew() -> UniquePtr<QFileSystemWatcher>;
+        #[doc(hidden)]
+        #[rust_name = "qfilesystemwatcher_on_directory_changed"]
+        fn qfilesystemwatcherOnDirectoryChanged(
+            watcher: Pin<&mut QFileSystemWatcher>,
+            callback: Box<QFileSystemWatcherCallback>,
+        ) -> QMetaObjectConnection;
+        #[doc(hidden)]
+        #[rust_name = "qfilesystemwatcher_on_file_changed"]
+        fn qfilesystemwatcherOnFileChanged(
+            watcher: Pin<&mut QFileSystemWatcher>,
+            callback: Box<QFileSystemWatcherCallback>,
+        ) -> QMetaObjectConnection;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        type QFileSystemWatcherCallback;
+
+        #[doc(hidden)]
+        fn call(self: &mut QFileSystemWatcherCallback, path: &QString);
+    }
+}
+
+pub use ffi::QFileSystemWatcher;
+
+/// A Rust closure which is called by a connection of a [QFileSystemWatcher]
+#[doc(hidden)]
+pub struct QFileSystemWatcherCallback {
+    callback: Box<dyn FnMut(&ffi::QString)>,
+}
+
+impl QFileSystemWatcherCallback {
+    fn new(callback: impl FnMut(&ffi::QString) + 'static) -> Box<Self> {
+        Box::new(Self {
+            callback: Box::new(callback),
+        })
+    }
+
+    fn call(&mut self, path: &ffi::QString) {
+        (self.callback)(path);
+    }
+}
+
+impl QFileSystemWatcher {
+    /// Constructs a new file system watcher object.
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qfilesystemwatcher_new()
+    }
+
+    /// Connect a closure which is called when the directory at a specified path is modified
+    /// (e.g., when a file is added or deleted) or removed from disk.
+    ///
+    /// The closure is called on the thread of the watcher, and is dropped when the connection is disconnected.
+    pub fn on_directory_changed(
+        self: Pin<&mut Self>,
+        callback: impl FnMut(&ffi::QString) + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qfilesystemwatcher_on_directory_changed(
+            self,
+            QFileSystemWatcherCallback::new(callback),
+        )
+    }
+
+    /// Connect a closure which is called when the file at the specified path is modified,
+    /// renamed or removed from disk.
+    ///
+    /// The closure is called on the thread of the watcher, and is dropped when the connection is disconnected.
+    pub fn on_file_changed(
+        self: Pin<&mut Self>,
+        callback: impl FnMut(&ffi::QString) + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qfilesystemwatcher_on_file_changed(self, QFileSystemWatcherCallback::new(callback))
+    }
+}
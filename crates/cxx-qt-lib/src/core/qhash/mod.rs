@@ -10,7 +10,8 @@ use cxx::{type_id, ExternType};
 /// Note that this means that T needs to have a C++ global
 /// [`qHash()` function](https://doc.qt.io/qt-6/qhash.html#qhash).
 ///
-/// To use QHash with a custom pair, implement the [`QHashPair`] trait for T.
+/// To use QHash with a custom pair, declare `impl_qhash_pair!(K, V);` in a `#[cxx_qt::bridge]`,
+/// this generates the `QHash_K_V` type alias and a `QHashPair_K_V` type implementing [`QHashPair`].
 #[repr(C)]
 pub struct QHash<T>
 where
@@ -0,0 +1,98 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qidentityproxymodel.h");
+
+        include!("cxx-qt-lib/qabstractitemmodel.h");
+        type QAbstractItemModel = crate::QAbstractItemModel;
+        include!("cxx-qt-lib/qmodelindex.h");
+        type QModelIndex = crate::QModelIndex;
+
+        /// The QIdentityProxyModel class proxies its source model unmodified.
+        ///
+        /// This can be used as a base for stacking multiple models, or to expose a Rust model to a view
+        /// indirectly so that the source model can be swapped at runtime.
+        ///
+        /// ```ignore
+        /// let mut proxy = QIdentityProxyModel::new();
+        /// unsafe {
+        ///     proxy.pin_mut().set_source_model(model.pin_mut().as_abstract_item_model_ptr());
+        /// }
+        /// ```
+        type QIdentityProxyModel;
+
+        /// Returns the number of columns for the children of the given parent in the source model.
+        #[rust_name = "column_count"]
+        fn columnCount(self: &QIdentityProxyModel, parent: &QModelIndex) -> i32;
+
+        /// Returns the proxy index for a given source index, which has the same row and column.
+        #[rust_name = "map_from_source"]
+        fn mapFromSource(self: &QIdentityProxyModel, source_index: &QModelIndex) -> QModelIndex;
+
+        /// Returns the source index for a given proxy index, which has the same row and column.
+        #[rust_name = "map_to_source"]
+        fn mapToSource(self: &QIdentityProxyModel, proxy_index: &QModelIndex) -> QModelIndex;
+
+        /// Returns the number of rows under the given parent in the source model.
+        #[rust_name = "row_count"]
+        fn rowCount(self: &QIdentityProxyModel, parent: &QModelIndex) -> i32;
+
+        #[doc(hidden)]
+        #[rust_name = "cxx_set_source_model"]
+        unsafe fn setSourceModel(
+            self: Pin<&mut QIdentityProxyModel>,
+            source_model: *mut QAbstractItemModel,
+        );
+
+        /// Returns the model that contains the data that is available through the proxy model,
+        /// this is null when no source model has been set.
+        #[rust_name = "source_model"]
+        fn sourceModel(self: &QIdentityProxyModel) -> *mut QAbstractItemModel;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qidentityproxymodel_new"]
+        fn qidentityproxymodelNew() -> UniquePtr<QIdentityProxyModel>;
+        #[doc(hidden)]
+        #[cxx_name = "qabstractitemmodelUpcast"]
+        #[rust_name = "qidentityproxymodel_upcast"]
+        unsafe fn upcast(model: *mut QIdentityProxyModel) -> *mut QAbstractItemModel;
+    }
+}
+
+pub use ffi::QIdentityProxyModel;
+
+impl QIdentityProxyModel {
+    /// Returns a pointer to the proxy as a QAbstractItemModel, so that it can be used as the source of another proxy.
+    pub fn as_abstract_item_model_ptr(self: Pin<&mut Self>) -> *mut ffi::QAbstractItemModel {
+        unsafe { ffi::qidentityproxymodel_upcast(self.get_unchecked_mut()) }
+    }
+
+    /// Constructs an identity model with no source model.
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qidentityproxymodel_new()
+    }
+
+    /// Sets the given source model to be processed by the proxy model.
+    ///
+    /// # Safety
+    ///
+    /// The source model must be a valid pointer, or null to clear the source model,
+    /// and must remain valid until it is replaced or the proxy is destroyed.
+    pub unsafe fn set_source_model(
+        self: Pin<&mut Self>,
+        source_model: *mut ffi::QAbstractItemModel,
+    ) {
+        self.cxx_set_source_model(source_model);
+    }
+}
@@ -217,6 +217,7 @@ generate_bridge_qt "QDate" "qdate"
 generate_bridge_qt "QDateTime" "qdatetime"
 generate_bridge_qt "QMargins" "qmargins"
 generate_bridge_qt "QMarginsF" "qmarginsf"
+generate_bridge_qt "QModelIndex" "qmodelindex"
 generate_bridge_qt "QPersistentModelIndex" "qpersistentmodelindex"
 generate_bridge_qt "QPoint" "qpoint"
 generate_bridge_qt "QPointF" "qpointf"
@@ -224,6 +225,7 @@ generate_bridge_qt "QRect" "qrect"
 generate_bridge_qt "QRectF" "qrectf"
 generate_bridge_qt "QSize" "qsize"
 generate_bridge_qt "QSizeF" "qsizef"
+generate_bridge_qt "QSslCertificate" "qsslcertificate"
 generate_bridge_qt "QString" "qstring"
 generate_bridge_qt "QTime" "qtime"
 generate_bridge_qt "QUrl" "qurl"
@@ -7,16 +7,19 @@
 use crate::QColor;
 #[cfg(not(target_os = "emscripten"))]
 use crate::QDateTime;
+#[cfg(feature = "qt_ssl")]
+use crate::QSslCertificate;
 use crate::{
-    QByteArray, QDate, QMargins, QMarginsF, QPersistentModelIndex, QPoint, QPointF, QRect, QRectF,
-    QSize, QSizeF, QString, QTime, QUrl, QVariant,
+    QByteArray, QDate, QMargins, QMarginsF, QModelIndex, QPersistentModelIndex, QPoint, QPointF,
+    QRect, QRectF, QSize, QSizeF, QString, QTime, QUrl, QVariant,
 };
 use core::{marker::PhantomData, mem::MaybeUninit};
 use cxx::{type_id, ExternType};
 
 /// The QList class is a template class that provides a dynamic array.
 ///
-/// To use QList with a custom type, implement the [`QListElement`] trait for T.
+/// To use QList with a custom type, declare `impl_qlist_element!(T);` in the `#[cxx_qt::bridge]`
+/// which declares T, this generates the `QList_T` type alias and implements [`QListElement`] for T.
 #[repr(C)]
 pub struct QList<T>
 where
@@ -357,6 +360,7 @@ impl_qlist_element!(QDate, qlist_qdate, "QList_QDate");
 impl_qlist_element!(QDateTime, qlist_qdatetime, "QList_QDateTime");
 impl_qlist_element!(QMargins, qlist_qmargins, "QList_QMargins");
 impl_qlist_element!(QMarginsF, qlist_qmarginsf, "QList_QMarginsF");
+impl_qlist_element!(QModelIndex, qlist_qmodelindex, "QList_QModelIndex");
 impl_qlist_element!(
     QPersistentModelIndex,
     qlist_qpersistentmodelindex,
@@ -368,6 +372,12 @@ impl_qlist_element!(QRect, qlist_qrect, "QList_QRect");
 impl_qlist_element!(QRectF, qlist_qrectf, "QList_QRectF");
 impl_qlist_element!(QSize, qlist_qsize, "QList_QSize");
 impl_qlist_element!(QSizeF, qlist_qsizef, "QList_QSizeF");
+#[cfg(feature = "qt_ssl")]
+impl_qlist_element!(
+    QSslCertificate,
+    qlist_qsslcertificate,
+    "QList_QSslCertificate"
+);
 impl_qlist_element!(QString, qlist_qstring, "QList_QString");
 impl_qlist_element!(QTime, qlist_qtime, "QList_QTime");
 impl_qlist_element!(QUrl, qlist_qurl, "QList_QUrl");
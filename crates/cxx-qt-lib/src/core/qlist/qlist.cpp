@@ -45,6 +45,7 @@ CXX_QT_QLIST_ASSERTS(::QDate, QDate);
 CXX_QT_QLIST_ASSERTS(::QDateTime, QDateTime);
 CXX_QT_QLIST_ASSERTS(::QMargins, QMargins);
 CXX_QT_QLIST_ASSERTS(::QMarginsF, QMarginsF);
+CXX_QT_QLIST_ASSERTS(::QModelIndex, QModelIndex);
 CXX_QT_QLIST_ASSERTS(::QPersistentModelIndex, QPersistentModelIndex);
 CXX_QT_QLIST_ASSERTS(::QPoint, QPoint);
 CXX_QT_QLIST_ASSERTS(::QPointF, QPointF);
@@ -52,6 +53,9 @@ CXX_QT_QLIST_ASSERTS(::QRect, QRect);
 CXX_QT_QLIST_ASSERTS(::QRectF, QRectF);
 CXX_QT_QLIST_ASSERTS(::QSize, QSize);
 CXX_QT_QLIST_ASSERTS(::QSizeF, QSizeF);
+#ifdef CXX_QT_SSL_FEATURE
+CXX_QT_QLIST_ASSERTS(::QSslCertificate, QSslCertificate);
+#endif
 CXX_QT_QLIST_ASSERTS(::QString, QString);
 CXX_QT_QLIST_ASSERTS(::QTime, QTime);
 CXX_QT_QLIST_ASSERTS(::QUrl, QUrl);
@@ -88,6 +92,8 @@ static const int register_QList_QMargins =
   qRegisterMetaType<::QList_QMargins>("QList_QMargins");
 static const int register_QList_QMarginsF =
   qRegisterMetaType<::QList_QMarginsF>("QList_QMarginsF");
+static const int register_QList_QModelIndex =
+  qRegisterMetaType<::QList_QModelIndex>("QList_QModelIndex");
 static const int register_QList_QPersistentModelIndex =
   qRegisterMetaType<::QList_QPersistentModelIndex>(
     "QList_QPersistentModelIndex");
@@ -103,6 +109,10 @@ static const int register_QList_QSize =
   qRegisterMetaType<::QList_QSize>("QList_QSize");
 static const int register_QList_QSizeF =
   qRegisterMetaType<::QList_QSizeF>("QList_QSizeF");
+#ifdef CXX_QT_SSL_FEATURE
+static const int register_QList_QSslCertificate =
+  qRegisterMetaType<::QList_QSslCertificate>("QList_QSslCertificate");
+#endif
 static const int register_QList_QString =
   qRegisterMetaType<::QList_QString>("QList_QString");
 static const int register_QList_QTime =
@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+pub mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmodelindex.h");
+        type QModelIndex = crate::QModelIndex;
+
+        include!("cxx-qt-lib/qlist.h");
+        type QList_QModelIndex = crate::QList<QModelIndex>;
+    }
+
+    unsafe extern "C++" {
+        #[rust_name = "cxx_clear"]
+        fn clear(self: &mut QList_QModelIndex);
+        #[rust_name = "cxx_contains"]
+        fn contains(self: &QList_QModelIndex, _: &QModelIndex) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qlist_clone_QModelIndex"]
+        fn construct(_: &QList_QModelIndex) -> QList_QModelIndex;
+        #[rust_name = "qlist_default_QModelIndex"]
+        fn construct() -> QList_QModelIndex;
+        #[rust_name = "qlist_drop_QModelIndex"]
+        fn drop(_: &mut QList_QModelIndex);
+    }
+
+    #[namespace = "rust::cxxqtlib1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "reserve_QModelIndex"]
+        fn qlistReserve(_: &mut QList_QModelIndex, size: isize);
+        #[rust_name = "append_QModelIndex"]
+        fn qlistAppend(_: &mut QList_QModelIndex, _: &QModelIndex);
+        #[rust_name = "get_unchecked_QModelIndex"]
+        unsafe fn qlistGetUnchecked(set: &QList_QModelIndex, pos: isize) -> &QModelIndex;
+        #[rust_name = "index_of_QModelIndex"]
+        fn qlistIndexOf(_: &QList_QModelIndex, _: &QModelIndex) -> isize;
+        #[rust_name = "insert_QModelIndex"]
+        fn qlistInsert(_: &mut QList_QModelIndex, _: isize, _: &QModelIndex);
+        #[rust_name = "remove_QModelIndex"]
+        fn qlistRemove(_: &mut QList_QModelIndex, _: isize);
+        #[rust_name = "len_QModelIndex"]
+        fn qlistLen(_: &QList_QModelIndex) -> isize;
+    }
+}
+
+pub(crate) fn reserve(v: &mut ffi::QList_QModelIndex, size: isize) {
+    ffi::reserve_QModelIndex(v, size);
+}
+
+pub(crate) fn append(v: &mut ffi::QList_QModelIndex, value: &ffi::QModelIndex) {
+    ffi::append_QModelIndex(v, value);
+}
+
+pub(crate) fn clone(s: &ffi::QList_QModelIndex) -> ffi::QList_QModelIndex {
+    ffi::qlist_clone_QModelIndex(s)
+}
+
+pub(crate) fn default() -> ffi::QList_QModelIndex {
+    ffi::qlist_default_QModelIndex()
+}
+
+pub(crate) fn drop(s: &mut ffi::QList_QModelIndex) {
+    ffi::qlist_drop_QModelIndex(s);
+}
+
+pub(crate) unsafe fn get_unchecked(s: &ffi::QList_QModelIndex, pos: isize) -> &ffi::QModelIndex {
+    ffi::get_unchecked_QModelIndex(s, pos)
+}
+
+pub(crate) fn index_of(v: &ffi::QList_QModelIndex, value: &ffi::QModelIndex) -> isize {
+    ffi::index_of_QModelIndex(v, value)
+}
+
+pub(crate) fn insert(s: &mut ffi::QList_QModelIndex, pos: isize, value: &ffi::QModelIndex) {
+    ffi::insert_QModelIndex(s, pos, value);
+}
+
+pub(crate) fn len(s: &ffi::QList_QModelIndex) -> isize {
+    ffi::len_QModelIndex(s)
+}
+
+pub(crate) fn remove(s: &mut ffi::QList_QModelIndex, pos: isize) {
+    ffi::remove_QModelIndex(s, pos);
+}
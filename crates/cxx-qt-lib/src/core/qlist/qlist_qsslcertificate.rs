@@ -0,0 +1,95 @@
+// SPDX-FileCopyrightText: 2022 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+pub mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qsslcertificate.h");
+        type QSslCertificate = crate::QSslCertificate;
+
+        include!("cxx-qt-lib/qlist.h");
+        type QList_QSslCertificate = crate::QList<QSslCertificate>;
+    }
+
+    unsafe extern "C++" {
+        #[rust_name = "cxx_clear"]
+        fn clear(self: &mut QList_QSslCertificate);
+        #[rust_name = "cxx_contains"]
+        fn contains(self: &QList_QSslCertificate, _: &QSslCertificate) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qlist_clone_QSslCertificate"]
+        fn construct(_: &QList_QSslCertificate) -> QList_QSslCertificate;
+        #[rust_name = "qlist_default_QSslCertificate"]
+        fn construct() -> QList_QSslCertificate;
+        #[rust_name = "qlist_drop_QSslCertificate"]
+        fn drop(_: &mut QList_QSslCertificate);
+    }
+
+    #[namespace = "rust::cxxqtlib1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "reserve_QSslCertificate"]
+        fn qlistReserve(_: &mut QList_QSslCertificate, size: isize);
+        #[rust_name = "append_QSslCertificate"]
+        fn qlistAppend(_: &mut QList_QSslCertificate, _: &QSslCertificate);
+        #[rust_name = "get_unchecked_QSslCertificate"]
+        unsafe fn qlistGetUnchecked(set: &QList_QSslCertificate, pos: isize) -> &QSslCertificate;
+        #[rust_name = "index_of_QSslCertificate"]
+        fn qlistIndexOf(_: &QList_QSslCertificate, _: &QSslCertificate) -> isize;
+        #[rust_name = "insert_QSslCertificate"]
+        fn qlistInsert(_: &mut QList_QSslCertificate, _: isize, _: &QSslCertificate);
+        #[rust_name = "remove_QSslCertificate"]
+        fn qlistRemove(_: &mut QList_QSslCertificate, _: isize);
+        #[rust_name = "len_QSslCertificate"]
+        fn qlistLen(_: &QList_QSslCertificate) -> isize;
+    }
+}
+
+pub(crate) fn reserve(v: &mut ffi::QList_QSslCertificate, size: isize) {
+    ffi::reserve_QSslCertificate(v, size);
+}
+
+pub(crate) fn append(v: &mut ffi::QList_QSslCertificate, value: &ffi::QSslCertificate) {
+    ffi::append_QSslCertificate(v, value);
+}
+
+pub(crate) fn clone(s: &ffi::QList_QSslCertificate) -> ffi::QList_QSslCertificate {
+    ffi::qlist_clone_QSslCertificate(s)
+}
+
+pub(crate) fn default() -> ffi::QList_QSslCertificate {
+    ffi::qlist_default_QSslCertificate()
+}
+
+pub(crate) fn drop(s: &mut ffi::QList_QSslCertificate) {
+    ffi::qlist_drop_QSslCertificate(s);
+}
+
+pub(crate) unsafe fn get_unchecked(
+    s: &ffi::QList_QSslCertificate,
+    pos: isize,
+) -> &ffi::QSslCertificate {
+    ffi::get_unchecked_QSslCertificate(s, pos)
+}
+
+pub(crate) fn index_of(v: &ffi::QList_QSslCertificate, value: &ffi::QSslCertificate) -> isize {
+    ffi::index_of_QSslCertificate(v, value)
+}
+
+pub(crate) fn insert(s: &mut ffi::QList_QSslCertificate, pos: isize, value: &ffi::QSslCertificate) {
+    ffi::insert_QSslCertificate(s, pos, value);
+}
+
+pub(crate) fn len(s: &ffi::QList_QSslCertificate) -> isize {
+    ffi::len_QSslCertificate(s)
+}
+
+pub(crate) fn remove(s: &mut ffi::QList_QSslCertificate, pos: isize) {
+    ffi::remove_QSslCertificate(s, pos);
+}
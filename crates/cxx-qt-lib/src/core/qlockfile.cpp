@@ -0,0 +1,37 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qlockfile.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QLockFile>
+qlockfileNew(const QString& fileName)
+{
+  return ::std::make_unique<QLockFile>(fileName);
+}
+
+void
+qlockfileSetStaleLockTime(QLockFile& lockFile, ::std::int32_t staleLockTime)
+{
+  lockFile.setStaleLockTime(static_cast<int>(staleLockTime));
+}
+
+::std::int32_t
+qlockfileStaleLockTime(const QLockFile& lockFile)
+{
+  return static_cast<::std::int32_t>(lockFile.staleLockTime());
+}
+
+bool
+qlockfileTryLock(QLockFile& lockFile, ::std::int32_t timeout)
+{
+  return lockFile.tryLock(static_cast<int>(timeout));
+}
+
+}
+}
@@ -0,0 +1,116 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+#[cxx::bridge]
+mod ffi {
+    /// The errors which can occur when locking a QLockFile.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QLockFileLockError {
+        /// The lock was acquired successfully.
+        NoError = 0,
+        /// The lock could not be acquired because another process holds it.
+        LockFailedError = 1,
+        /// The lock file could not be created, for lack of permissions in the parent directory.
+        PermissionError = 2,
+        /// Another error happened, for instance a full partition prevented writing out the lock file.
+        UnknownError = 3,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qlockfile.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QLockFileLockError;
+
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        /// The QLockFile class provides locking between processes using a file.
+        ///
+        /// This is the same locking which is used by Qt applications, so a lock
+        /// can be shared with C++ code, for example to ensure only a single instance is running.
+        ///
+        /// ```ignore
+        /// let mut lock_file = QLockFile::new(&QString::from("/tmp/my_app.lock"));
+        /// if !lock_file.pin_mut().try_lock(100) {
+        ///     println!("Another instance is already running");
+        /// }
+        /// ```
+        type QLockFile;
+
+        /// Returns the lock file error status.
+        fn error(self: &QLockFile) -> QLockFileLockError;
+
+        /// Returns true if the lock was acquired by this QLockFile instance, otherwise returns false.
+        #[rust_name = "is_locked"]
+        fn isLocked(self: &QLockFile) -> bool;
+
+        /// Creates the lock file.
+        ///
+        /// If another process (or another thread) has created the lock file already,
+        /// this function will block until that process (or thread) releases it.
+        fn lock(self: Pin<&mut QLockFile>) -> bool;
+
+        /// Attempts to forcefully remove an existing lock file.
+        ///
+        /// Calling this is not recommended when protecting a short-lived operation,
+        /// as it can leave the lock held by another process.
+        #[rust_name = "remove_stale_lock_file"]
+        fn removeStaleLockFile(self: Pin<&mut QLockFile>) -> bool;
+
+        /// Releases the lock, by deleting the lock file.
+        fn unlock(self: Pin<&mut QLockFile>);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qlockfile_new"]
+        fn qlockfileNew(file_name: &QString) -> UniquePtr<QLockFile>;
+        #[doc(hidden)]
+        #[rust_name = "qlockfile_set_stale_lock_time"]
+        fn qlockfileSetStaleLockTime(lock_file: Pin<&mut QLockFile>, stale_lock_time: i32);
+        #[doc(hidden)]
+        #[rust_name = "qlockfile_stale_lock_time"]
+        fn qlockfileStaleLockTime(lock_file: &QLockFile) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qlockfile_try_lock"]
+        fn qlockfileTryLock(lock_file: Pin<&mut QLockFile>, timeout: i32) -> bool;
+    }
+}
+
+pub use ffi::{QLockFile, QLockFileLockError};
+
+impl QLockFile {
+    /// Constructs a new lock file object.
+    /// The object is created in an unlocked state.
+    pub fn new(file_name: &ffi::QString) -> UniquePtr<Self> {
+        ffi::qlockfile_new(file_name)
+    }
+
+    /// Sets stale_lock_time to be the time in milliseconds after which a lock file is considered stale.
+    /// The default value is 30000, i.e. 30 seconds.
+    pub fn set_stale_lock_time(self: Pin<&mut Self>, stale_lock_time: i32) {
+        ffi::qlockfile_set_stale_lock_time(self, stale_lock_time);
+    }
+
+    /// Returns the time in milliseconds after which a lock file is considered stale.
+    pub fn stale_lock_time(&self) -> i32 {
+        ffi::qlockfile_stale_lock_time(self)
+    }
+
+    /// Attempts to create the lock file. This function returns true if the lock was obtained;
+    /// otherwise it returns false.
+    ///
+    /// If another process (or another thread) has created the lock file already,
+    /// this function will wait for at most timeout milliseconds for the lock file to become available.
+    /// Passing a negative number as the timeout is equivalent to calling lock().
+    pub fn try_lock(self: Pin<&mut Self>, timeout: i32) -> bool {
+        ffi::qlockfile_try_lock(self, timeout)
+    }
+}
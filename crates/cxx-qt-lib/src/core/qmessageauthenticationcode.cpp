@@ -0,0 +1,47 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qmessageauthenticationcode.h"
+#include "cxx-qt-lib/qbytearray.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QMessageAuthenticationCode>
+qmessageauthenticationcodeNew(QCryptographicHashAlgorithm method,
+                              ::rust::Slice<const ::std::uint8_t> key)
+{
+  // The key is copied as the code keeps it for reset()
+  return ::std::make_unique<QMessageAuthenticationCode>(
+    method, qbytearrayFromSliceU8(key));
+}
+
+void
+qmessageauthenticationcodeAddData(QMessageAuthenticationCode& code,
+                                  ::rust::Slice<const ::std::uint8_t> data)
+{
+  // Note that rust::Slice here is borrowed and only used for this call
+  code.addData(qbytearrayFromRawData(data));
+}
+
+QByteArray
+qmessageauthenticationcodeHash(::rust::Slice<const ::std::uint8_t> message,
+                               ::rust::Slice<const ::std::uint8_t> key,
+                               QCryptographicHashAlgorithm method)
+{
+  return QMessageAuthenticationCode::hash(
+    qbytearrayFromRawData(message), qbytearrayFromRawData(key), method);
+}
+
+void
+qmessageauthenticationcodeSetKey(QMessageAuthenticationCode& code,
+                                 ::rust::Slice<const ::std::uint8_t> key)
+{
+  code.setKey(qbytearrayFromSliceU8(key));
+}
+
+}
+}
@@ -0,0 +1,93 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmessageauthenticationcode.h");
+
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qcryptographichash.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QCryptographicHashAlgorithm = crate::QCryptographicHashAlgorithm;
+
+        /// The QMessageAuthenticationCode class provides a way to generate hash-based message authentication codes.
+        ///
+        /// The codes are the same as those generated by Qt, so can be used to verify
+        /// messages authenticated by C++ code.
+        ///
+        /// ```ignore
+        /// let mut code = QMessageAuthenticationCode::new(QCryptographicHashAlgorithm::Sha256, b"key");
+        /// code.pin_mut().add_data(b"message");
+        /// let result = code.result();
+        /// ```
+        type QMessageAuthenticationCode;
+
+        /// Resets message data. Calling this method doesn't affect the key.
+        fn reset(self: Pin<&mut QMessageAuthenticationCode>);
+
+        /// Returns the final authentication code.
+        fn result(self: &QMessageAuthenticationCode) -> QByteArray;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qmessageauthenticationcode_new"]
+        fn qmessageauthenticationcodeNew(
+            method: QCryptographicHashAlgorithm,
+            key: &[u8],
+        ) -> UniquePtr<QMessageAuthenticationCode>;
+        #[doc(hidden)]
+        #[rust_name = "qmessageauthenticationcode_add_data"]
+        fn qmessageauthenticationcodeAddData(
+            code: Pin<&mut QMessageAuthenticationCode>,
+            data: &[u8],
+        );
+        #[doc(hidden)]
+        #[rust_name = "qmessageauthenticationcode_hash"]
+        fn qmessageauthenticationcodeHash(
+            message: &[u8],
+            key: &[u8],
+            method: QCryptographicHashAlgorithm,
+        ) -> QByteArray;
+        #[doc(hidden)]
+        #[rust_name = "qmessageauthenticationcode_set_key"]
+        fn qmessageauthenticationcodeSetKey(code: Pin<&mut QMessageAuthenticationCode>, key: &[u8]);
+    }
+}
+
+pub use ffi::QMessageAuthenticationCode;
+
+impl QMessageAuthenticationCode {
+    /// Adds the data to the message.
+    pub fn add_data(self: Pin<&mut Self>, data: &[u8]) {
+        ffi::qmessageauthenticationcode_add_data(self, data);
+    }
+
+    /// Returns the authentication code for the message using the key and the hash method.
+    pub fn hash(
+        message: &[u8],
+        key: &[u8],
+        method: ffi::QCryptographicHashAlgorithm,
+    ) -> ffi::QByteArray {
+        ffi::qmessageauthenticationcode_hash(message, key, method)
+    }
+
+    /// Constructs an object that can be used to create a cryptographic hash from data
+    /// using method and key.
+    pub fn new(method: ffi::QCryptographicHashAlgorithm, key: &[u8]) -> UniquePtr<Self> {
+        ffi::qmessageauthenticationcode_new(method, key)
+    }
+
+    /// Sets the secret key. Calling this method automatically resets the object state.
+    pub fn set_key(self: Pin<&mut Self>, key: &[u8]) {
+        ffi::qmessageauthenticationcode_set_key(self, key);
+    }
+}
@@ -0,0 +1,82 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qobjectconnect.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QMetaMethod
+qobjectFindMethod(const QObject& object,
+                  ::rust::Str name,
+                  ::rust::usize parameterCount)
+{
+  const auto nameBytes =
+    QByteArray(name.data(), static_cast<int>(name.size()));
+  const auto* metaObject = object.metaObject();
+  // Search from the most derived class, so that a method which hides a method
+  // of the base class with the same name is found first
+  for (int index = metaObject->methodCount() - 1; index >= 0; --index) {
+    const auto method = metaObject->method(index);
+    if (method.name() == nameBytes &&
+        method.parameterCount() == static_cast<int>(parameterCount)) {
+      return method;
+    }
+  }
+
+  return QMetaMethod();
+}
+
+QMetaProperty
+qobjectFindProperty(const QObject& object, ::rust::Str name)
+{
+  const auto nameBytes =
+    QByteArray(name.data(), static_cast<int>(name.size()));
+  const auto* metaObject = object.metaObject();
+  const auto index = metaObject->indexOfProperty(nameBytes.constData());
+  if (index < 0) {
+    throw ::std::invalid_argument(::std::string("property not found: ") +
+                                  ::std::string(metaObject->className()) +
+                                  "::" + ::std::string(nameBytes.constData()));
+  }
+
+  return metaObject->property(index);
+}
+
+QMetaProperty
+qobjectFindWritableProperty(const QObject& object, ::rust::Str name)
+{
+  const auto property = qobjectFindProperty(object, name);
+  if (!property.isWritable()) {
+    throw ::std::invalid_argument(
+      ::std::string("property is not writable: ") +
+      ::std::string(object.metaObject()->className()) +
+      "::" + ::std::string(property.name()));
+  }
+
+  return property;
+}
+
+QMetaObjectConnection
+qobjectConnectSignalTo(const QObject& sender,
+                       ::rust::Str signal,
+                       const QObject& receiver,
+                       ::rust::Str method,
+                       ::rust::usize parameterCount,
+                       ::Qt::ConnectionType type)
+{
+  const auto signalMethod = qobjectFindMethod(sender, signal, parameterCount);
+  Q_ASSERT(signalMethod.methodType() == QMetaMethod::Signal);
+  const auto receiverMethod =
+    qobjectFindMethod(receiver, method, parameterCount);
+  Q_ASSERT(receiverMethod.isValid());
+
+  return QObject::connect(
+    &sender, signalMethod, &receiver, receiverMethod, type);
+}
+
+}
+}
@@ -0,0 +1,246 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{ConnectionType, QMetaObjectConnection};
+use core::{marker::PhantomData, pin::Pin};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmetaobjectconnection.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QMetaObjectConnection = crate::QMetaObjectConnection;
+        include!("cxx-qt-lib/qt.h");
+        #[namespace = "Qt"]
+        type ConnectionType = crate::ConnectionType;
+
+        include!("cxx-qt-lib/qobjectconnect.h");
+        /// The QObject class is the base class of all Qt objects, including the QObjects defined by CXX-Qt.
+        ///
+        /// A QObject which implements [Connect](crate::Connect) can be upcast to this type.
+        type QObject;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qobject_connect_signal_to"]
+        fn qobjectConnectSignalTo(
+            sender: &QObject,
+            signal: &str,
+            receiver: &QObject,
+            method: &str,
+            parameter_count: usize,
+            conn_type: ConnectionType,
+        ) -> QMetaObjectConnection;
+    }
+}
+
+pub use ffi::QObject;
+
+/// Indicates that the signals, invokables, and properties of a QObject can be connected
+/// to those of another QObject with [connect_signal_to] and [bind_property].
+///
+/// This trait is implemented by CxxQt automatically.
+/// To enable this for a `T`, add `impl cxx_qt_lib::Connect for T {}` to your `#[cxx_qt::bridge]`.
+/// This generates an associated function on `T` which returns a handle for each item, such as
+/// `T::data_changed_signal()` for a signal, `T::refresh_slot()` for an invokable, and `T::number_property()`
+/// for a property. The handles carry the types of the parameters or the value, so connecting a signal
+/// to a slot with different parameters or binding properties of different types fails to compile.
+pub trait Connect {
+    /// Returns the QObject base class of this object
+    fn as_qobject(&self) -> &QObject;
+
+    /// Returns the mutable QObject base class of this object
+    fn as_qobject_mut(self: Pin<&mut Self>) -> Pin<&mut QObject>;
+}
+
+/// A signal of the QObject `T` whose parameters are described by the function pointer `Args`
+///
+/// This is created by the `T::<signal>_signal()` function generated for a QObject which implements [Connect].
+pub struct QSignal<T, Args> {
+    name: &'static str,
+    parameter_count: usize,
+    _marker: PhantomData<(fn(&T), Args)>,
+}
+
+impl<T, Args> Clone for QSignal<T, Args> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, Args> Copy for QSignal<T, Args> {}
+
+impl<T, Args> QSignal<T, Args> {
+    #[doc(hidden)]
+    /// # Safety
+    ///
+    /// `T` must have a signal with the given C++ name and number of parameters, whose types match `Args`.
+    pub const unsafe fn new_unchecked(name: &'static str, parameter_count: usize) -> Self {
+        Self {
+            name,
+            parameter_count,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the C++ name of the signal
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+/// A signal or invokable of the QObject `T` whose parameters are described by the function pointer `Args`,
+/// which is invoked each time a connected [QSignal] is emitted
+///
+/// This is created by the `T::<invokable>_slot()` function generated for a QObject which implements [Connect],
+/// or from a [QSignal] to forward one signal to another.
+pub struct QSlot<T, Args> {
+    name: &'static str,
+    _marker: PhantomData<(fn(&T), Args)>,
+}
+
+impl<T, Args> Clone for QSlot<T, Args> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, Args> Copy for QSlot<T, Args> {}
+
+impl<T, Args> QSlot<T, Args> {
+    #[doc(hidden)]
+    /// # Safety
+    ///
+    /// `T` must have a signal or invokable with the given C++ name, whose parameter types match `Args`.
+    pub const unsafe fn new_unchecked(name: &'static str) -> Self {
+        Self {
+            name,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the C++ name of the signal or invokable
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl<T, Args> From<QSignal<T, Args>> for QSlot<T, Args> {
+    fn from(signal: QSignal<T, Args>) -> Self {
+        Self {
+            name: signal.name,
+            _marker: PhantomData,
+        }
+    }
+}
+
+type BindFn<T> = fn(&T, Pin<&mut QObject>, &str) -> Result<QMetaObjectConnection, cxx::Exception>;
+
+/// A property of the QObject `T` whose value is of type `V`
+///
+/// This is created by the `T::<property>_property()` function generated for a QObject which implements [Connect],
+/// for each property which has a notify signal.
+pub struct QProperty<T, V> {
+    name: &'static str,
+    bind: BindFn<T>,
+    _marker: PhantomData<fn() -> V>,
+}
+
+impl<T, V> Clone for QProperty<T, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, V> Copy for QProperty<T, V> {}
+
+impl<T, V> QProperty<T, V> {
+    #[doc(hidden)]
+    /// # Safety
+    ///
+    /// `T` must have a property with the given C++ name and a value of type `V`,
+    /// `bind` must write the value to the named property of the target each time the property changes.
+    pub const unsafe fn new_unchecked(name: &'static str, bind: BindFn<T>) -> Self {
+        Self {
+            name,
+            bind,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the C++ name of the property
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+/// Connect the signal of the sender to a signal or invokable of the receiver,
+/// so that each time the signal is emitted the slot is invoked with the same arguments.
+///
+/// This is the same as `QObject::connect(sender, &Sender::signal, receiver, &Receiver::slot, conn_type)` in C++.
+/// The connection is removed when either object is destroyed or when the returned [QMetaObjectConnection] is dropped,
+/// use [QMetaObjectConnection::release] to keep it for the lifetime of the objects.
+///
+/// ```ignore
+/// cxx_qt_lib::connect_signal_to(
+///     &model,
+///     qobject::Model::data_changed_signal(),
+///     &view,
+///     qobject::View::refresh_slot(),
+///     ConnectionType::AutoConnection,
+/// )
+/// .release();
+/// ```
+pub fn connect_signal_to<S, R, Args>(
+    sender: &S,
+    signal: QSignal<S, Args>,
+    receiver: &R,
+    slot: impl Into<QSlot<R, Args>>,
+    conn_type: ConnectionType,
+) -> QMetaObjectConnection
+where
+    S: Connect,
+    R: Connect,
+{
+    let slot = slot.into();
+    ffi::qobject_connect_signal_to(
+        sender.as_qobject(),
+        signal.name,
+        receiver.as_qobject(),
+        slot.name,
+        signal.parameter_count,
+        conn_type,
+    )
+}
+
+/// Bind a property of the target to a property of the source, the target property is written
+/// with the value of the source property now and each time the source property changes.
+///
+/// The binding is removed when either object is destroyed or when the returned [QMetaObjectConnection] is dropped,
+/// use [QMetaObjectConnection::release] to keep it for the lifetime of the objects.
+/// An error is returned if the target property is not writable.
+///
+/// ```ignore
+/// cxx_qt_lib::bind_property(
+///     &model,
+///     qobject::Model::number_property(),
+///     view.as_mut(),
+///     qobject::View::number_property(),
+/// )?
+/// .release();
+/// ```
+pub fn bind_property<S, T, V>(
+    source: &S,
+    source_property: QProperty<S, V>,
+    target: Pin<&mut T>,
+    target_property: QProperty<T, V>,
+) -> Result<QMetaObjectConnection, cxx::Exception>
+where
+    T: Connect,
+{
+    (source_property.bind)(source, target.as_qobject_mut(), target_property.name)
+}
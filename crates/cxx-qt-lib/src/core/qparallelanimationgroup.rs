@@ -0,0 +1,79 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::QAnimationGroupChild;
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qparallelanimationgroup.h");
+
+        include!("cxx-qt-lib/qanimationgroup.h");
+        type QAbstractAnimation = crate::QAbstractAnimation;
+        type QAnimationGroup = crate::QAnimationGroup;
+
+        /// The QParallelAnimationGroup class provides a parallel group of animations.
+        ///
+        /// All of the animations are started at the same time, the group finishes when the longest animation has finished.
+        ///
+        /// ```ignore
+        /// let mut group = QParallelAnimationGroup::new();
+        /// group.pin_mut().as_animation_group_mut().add_animation(fade_in);
+        /// group.pin_mut().as_animation_group_mut().add_animation(slide_in);
+        /// group.pin_mut().as_animation_group_mut().start();
+        /// ```
+        type QParallelAnimationGroup;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qparallelanimationgroup_new"]
+        fn qparallelanimationgroupNew() -> UniquePtr<QParallelAnimationGroup>;
+        #[doc(hidden)]
+        #[cxx_name = "qabstractanimationUpcast"]
+        #[rust_name = "qparallelanimationgroup_upcast_abstract_animation"]
+        unsafe fn upcast(group: *mut QParallelAnimationGroup) -> *mut QAbstractAnimation;
+        #[doc(hidden)]
+        #[cxx_name = "qanimationgroupUpcast"]
+        #[rust_name = "qparallelanimationgroup_upcast"]
+        unsafe fn upcast(group: *mut QParallelAnimationGroup) -> *mut QAnimationGroup;
+    }
+}
+
+pub use ffi::QParallelAnimationGroup;
+
+impl QParallelAnimationGroup {
+    /// Returns the group as a QAnimationGroup, so that animations can be added and the group can be started and stopped.
+    pub fn as_animation_group_mut(self: Pin<&mut Self>) -> Pin<&mut ffi::QAnimationGroup> {
+        unsafe {
+            Pin::new_unchecked(&mut *ffi::qparallelanimationgroup_upcast(
+                self.get_unchecked_mut(),
+            ))
+        }
+    }
+
+    /// Constructs an empty group.
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qparallelanimationgroup_new()
+    }
+}
+
+impl std::ops::Deref for QParallelAnimationGroup {
+    type Target = ffi::QAnimationGroup;
+
+    fn deref(&self) -> &Self::Target {
+        // The upcast does not modify the group, it only adjusts the pointer to the base class
+        unsafe { &*ffi::qparallelanimationgroup_upcast(self as *const Self as *mut Self) }
+    }
+}
+
+impl QAnimationGroupChild for QParallelAnimationGroup {
+    unsafe fn upcast_abstract_animation(animation: *mut Self) -> *mut ffi::QAbstractAnimation {
+        ffi::qparallelanimationgroup_upcast_abstract_animation(animation)
+    }
+}
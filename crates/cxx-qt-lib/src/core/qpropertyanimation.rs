@@ -0,0 +1,78 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qpropertyanimation.h");
+
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qvariantanimation.h");
+        type QVariantAnimation = crate::QVariantAnimation;
+
+        /// The QPropertyAnimation class animates a property of a QObject, including the properties of QObjects defined by CXX-Qt.
+        ///
+        /// The animation is constructed by declaring the `qpropertyanimationNew` helper for the type of the target in a bridge,
+        /// the property is given by its name and an error is returned if it cannot be found or is not writable.
+        ///
+        /// ```ignore
+        /// unsafe extern "C++" {
+        ///     include!("cxx-qt-lib/qpropertyanimation.h");
+        ///     type QPropertyAnimation = cxx_qt_lib::QPropertyAnimation;
+        ///
+        ///     #[namespace = "rust::cxxqtlib1"]
+        ///     #[rust_name = "animate_property"]
+        ///     fn qpropertyanimationNew(
+        ///         target: Pin<&mut MyObject>,
+        ///         property: &str,
+        ///     ) -> Result<UniquePtr<QPropertyAnimation>>;
+        /// }
+        /// ```
+        ///
+        /// The methods of [QVariantAnimation], such as setting the end value and starting the animation,
+        /// are available through [QPropertyAnimation::as_variant_animation_mut].
+        type QPropertyAnimation;
+
+        /// Returns the name of the property that is animated.
+        #[rust_name = "property_name"]
+        fn propertyName(self: &QPropertyAnimation) -> QByteArray;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "qvariantanimationUpcast"]
+        #[rust_name = "qpropertyanimation_upcast"]
+        unsafe fn upcast(animation: *mut QPropertyAnimation) -> *mut QVariantAnimation;
+    }
+
+    // The animation is constructed by a bridge of another crate, so the UniquePtr support is instantiated here
+    impl UniquePtr<QPropertyAnimation> {}
+}
+
+pub use ffi::QPropertyAnimation;
+
+impl QPropertyAnimation {
+    /// Returns the animation as a QVariantAnimation, so that it can be configured, started, and stopped.
+    pub fn as_variant_animation_mut(self: Pin<&mut Self>) -> Pin<&mut ffi::QVariantAnimation> {
+        unsafe {
+            Pin::new_unchecked(&mut *ffi::qpropertyanimation_upcast(
+                self.get_unchecked_mut(),
+            ))
+        }
+    }
+}
+
+impl std::ops::Deref for QPropertyAnimation {
+    type Target = ffi::QVariantAnimation;
+
+    fn deref(&self) -> &Self::Target {
+        // The upcast does not modify the animation, it only adjusts the pointer to the base class
+        unsafe { &*ffi::qpropertyanimation_upcast(self as *const Self as *mut Self) }
+    }
+}
@@ -0,0 +1,35 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qsequentialanimationgroup.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QSequentialAnimationGroup>
+qsequentialanimationgroupNew()
+{
+  return ::std::make_unique<QSequentialAnimationGroup>();
+}
+
+void
+qsequentialanimationgroupAddPause(QSequentialAnimationGroup& group,
+                                  ::std::int32_t msecs)
+{
+  // The pause animation is owned by the group
+  group.addPause(static_cast<int>(msecs));
+}
+
+::std::int32_t
+qsequentialanimationgroupCurrentAnimationIndex(
+  const QSequentialAnimationGroup& group)
+{
+  return static_cast<::std::int32_t>(
+    group.indexOfAnimation(group.currentAnimation()));
+}
+
+}
+}
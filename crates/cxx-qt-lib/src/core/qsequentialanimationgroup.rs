@@ -0,0 +1,99 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::QAnimationGroupChild;
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qsequentialanimationgroup.h");
+
+        include!("cxx-qt-lib/qanimationgroup.h");
+        type QAbstractAnimation = crate::QAbstractAnimation;
+        type QAnimationGroup = crate::QAnimationGroup;
+
+        /// The QSequentialAnimationGroup class provides a sequential group of animations.
+        ///
+        /// Each animation is started after the previous animation has finished, pauses can be added between them.
+        ///
+        /// ```ignore
+        /// let mut group = QSequentialAnimationGroup::new();
+        /// group.pin_mut().add_pause(100);
+        /// group.pin_mut().as_animation_group_mut().add_animation(fade_in);
+        /// group.pin_mut().as_animation_group_mut().start();
+        /// ```
+        type QSequentialAnimationGroup;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qsequentialanimationgroup_new"]
+        fn qsequentialanimationgroupNew() -> UniquePtr<QSequentialAnimationGroup>;
+        #[doc(hidden)]
+        #[rust_name = "qsequentialanimationgroup_add_pause"]
+        fn qsequentialanimationgroupAddPause(
+            group: Pin<&mut QSequentialAnimationGroup>,
+            msecs: i32,
+        );
+        #[doc(hidden)]
+        #[rust_name = "qsequentialanimationgroup_current_animation_index"]
+        fn qsequentialanimationgroupCurrentAnimationIndex(group: &QSequentialAnimationGroup)
+            -> i32;
+        #[doc(hidden)]
+        #[cxx_name = "qabstractanimationUpcast"]
+        #[rust_name = "qsequentialanimationgroup_upcast_abstract_animation"]
+        unsafe fn upcast(group: *mut QSequentialAnimationGroup) -> *mut QAbstractAnimation;
+        #[doc(hidden)]
+        #[cxx_name = "qanimationgroupUpcast"]
+        #[rust_name = "qsequentialanimationgroup_upcast"]
+        unsafe fn upcast(group: *mut QSequentialAnimationGroup) -> *mut QAnimationGroup;
+    }
+}
+
+pub use ffi::QSequentialAnimationGroup;
+
+impl QSequentialAnimationGroup {
+    /// Adds a pause of msecs to the end of this group, the pause is owned by the group.
+    pub fn add_pause(self: Pin<&mut Self>, msecs: i32) {
+        ffi::qsequentialanimationgroup_add_pause(self, msecs);
+    }
+
+    /// Returns the group as a QAnimationGroup, so that animations can be added and the group can be started and stopped.
+    pub fn as_animation_group_mut(self: Pin<&mut Self>) -> Pin<&mut ffi::QAnimationGroup> {
+        unsafe {
+            Pin::new_unchecked(&mut *ffi::qsequentialanimationgroup_upcast(
+                self.get_unchecked_mut(),
+            ))
+        }
+    }
+
+    /// Returns the index of the animation which is currently running, or -1 if there is none.
+    pub fn current_animation_index(&self) -> i32 {
+        ffi::qsequentialanimationgroup_current_animation_index(self)
+    }
+
+    /// Constructs an empty group.
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qsequentialanimationgroup_new()
+    }
+}
+
+impl std::ops::Deref for QSequentialAnimationGroup {
+    type Target = ffi::QAnimationGroup;
+
+    fn deref(&self) -> &Self::Target {
+        // The upcast does not modify the group, it only adjusts the pointer to the base class
+        unsafe { &*ffi::qsequentialanimationgroup_upcast(self as *const Self as *mut Self) }
+    }
+}
+
+impl QAnimationGroupChild for QSequentialAnimationGroup {
+    unsafe fn upcast_abstract_animation(animation: *mut Self) -> *mut ffi::QAbstractAnimation {
+        ffi::qsequentialanimationgroup_upcast_abstract_animation(animation)
+    }
+}
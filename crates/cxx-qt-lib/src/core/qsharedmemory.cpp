@@ -0,0 +1,61 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qsharedmemory.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+bool
+qsharedmemoryCreate(QSharedMemory& sharedMemory,
+                    ::rust::isize size,
+                    QSharedMemoryAccessMode mode)
+{
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  return sharedMemory.create(static_cast<qsizetype>(size), mode);
+#else
+  return sharedMemory.create(static_cast<int>(size), mode);
+#endif
+}
+
+::rust::Slice<const ::std::uint8_t>
+qsharedmemoryData(const QSharedMemory& sharedMemory)
+{
+  const auto* data = static_cast<const ::std::uint8_t*>(sharedMemory.data());
+  if (data == nullptr) {
+    return ::rust::Slice<const ::std::uint8_t>();
+  }
+
+  return ::rust::Slice<const ::std::uint8_t>(
+    data, static_cast<::std::size_t>(sharedMemory.size()));
+}
+
+::rust::Slice<::std::uint8_t>
+qsharedmemoryDataMut(QSharedMemory& sharedMemory)
+{
+  auto* data = static_cast<::std::uint8_t*>(sharedMemory.data());
+  if (data == nullptr) {
+    return ::rust::Slice<::std::uint8_t>();
+  }
+
+  return ::rust::Slice<::std::uint8_t>(
+    data, static_cast<::std::size_t>(sharedMemory.size()));
+}
+
+::std::unique_ptr<QSharedMemory>
+qsharedmemoryNew(const QString& key)
+{
+  return ::std::make_unique<QSharedMemory>(key);
+}
+
+::rust::isize
+qsharedmemorySize(const QSharedMemory& sharedMemory)
+{
+  return static_cast<::rust::isize>(sharedMemory.size());
+}
+
+}
+}
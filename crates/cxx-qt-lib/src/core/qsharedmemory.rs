@@ -0,0 +1,185 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+#[cxx::bridge]
+mod ffi {
+    /// The modes in which a QSharedMemory segment can be attached.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QSharedMemoryAccessMode {
+        /// The shared memory segment is read-only, writing to it causes the process to crash.
+        ReadOnly = 0,
+        /// Reading and writing the shared memory segment are both allowed.
+        ReadWrite = 1,
+    }
+
+    /// The errors which can occur in a QSharedMemory.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QSharedMemorySharedMemoryError {
+        /// No error occurred.
+        NoError = 0,
+        /// The operation failed because the caller didn't have the required permissions.
+        PermissionDenied = 1,
+        /// A create operation failed because the requested size was invalid.
+        InvalidSize = 2,
+        /// The operation failed because of an invalid key.
+        KeyError = 3,
+        /// A create operation failed because a shared memory segment with the specified key already existed.
+        AlreadyExists = 4,
+        /// An attach failed because a shared memory segment with the specified key could not be found.
+        NotFound = 5,
+        /// The attempt to lock the shared memory segment failed.
+        LockError = 6,
+        /// A create operation failed because there was not enough memory available.
+        OutOfResources = 7,
+        /// Something else happened and it was bad.
+        UnknownError = 8,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qsharedmemory.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QSharedMemoryAccessMode;
+        #[namespace = "rust::cxxqtlib1"]
+        type QSharedMemorySharedMemoryError;
+
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        /// The QSharedMemory class provides access to a shared memory segment.
+        ///
+        /// The segment is identified by a key, so memory can be shared with C++ processes
+        /// which use a QSharedMemory with the same key.
+        ///
+        /// ```ignore
+        /// let mut shared_memory = QSharedMemory::new(&QString::from("my_app_state"));
+        /// if shared_memory.pin_mut().create(1024, QSharedMemoryAccessMode::ReadWrite) {
+        ///     shared_memory.pin_mut().with_lock(|data| data[0] = 1);
+        /// }
+        /// ```
+        type QSharedMemory;
+
+        /// Attempts to attach the process to the shared memory segment identified by the key.
+        ///
+        /// Returns true if the attach operation is successful.
+        fn attach(self: Pin<&mut QSharedMemory>, mode: QSharedMemoryAccessMode) -> bool;
+
+        /// Detaches the process from the shared memory segment.
+        ///
+        /// If this was the last process attached to the segment, the segment is released by the system.
+        fn detach(self: Pin<&mut QSharedMemory>) -> bool;
+
+        /// Returns a value indicating whether an error occurred, and, if so, which error it was.
+        fn error(self: &QSharedMemory) -> QSharedMemorySharedMemoryError;
+
+        /// Returns a text description of the last error that occurred.
+        #[rust_name = "error_string"]
+        fn errorString(self: &QSharedMemory) -> QString;
+
+        /// Returns true if this process is attached to the shared memory segment.
+        #[rust_name = "is_attached"]
+        fn isAttached(self: &QSharedMemory) -> bool;
+
+        /// Returns the key assigned with set_key() to this shared memory.
+        fn key(self: &QSharedMemory) -> QString;
+
+        /// This is a semaphore that locks the shared memory segment for access by this process
+        /// and returns true. If another process has locked the segment, this function blocks until the lock is released.
+        fn lock(self: Pin<&mut QSharedMemory>) -> bool;
+
+        /// Sets the platform independent key for this shared memory object.
+        ///
+        /// If this shared memory is attached to a segment, it is detached first.
+        #[rust_name = "set_key"]
+        fn setKey(self: Pin<&mut QSharedMemory>, key: &QString);
+
+        /// Releases the lock on the shared memory segment and returns true, if the lock is currently held by this process.
+        fn unlock(self: Pin<&mut QSharedMemory>) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qsharedmemory_create"]
+        fn qsharedmemoryCreate(
+            shared_memory: Pin<&mut QSharedMemory>,
+            size: isize,
+            mode: QSharedMemoryAccessMode,
+        ) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qsharedmemory_data"]
+        fn qsharedmemoryData(shared_memory: &QSharedMemory) -> &[u8];
+        #[doc(hidden)]
+        #[rust_name = "qsharedmemory_data_mut"]
+        fn qsharedmemoryDataMut(shared_memory: Pin<&mut QSharedMemory>) -> &mut [u8];
+        #[doc(hidden)]
+        #[rust_name = "qsharedmemory_new"]
+        fn qsharedmemoryNew(key: &QString) -> UniquePtr<QSharedMemory>;
+        #[doc(hidden)]
+        #[rust_name = "qsharedmemory_size"]
+        fn qsharedmemorySize(shared_memory: &QSharedMemory) -> isize;
+    }
+}
+
+pub use ffi::{QSharedMemory, QSharedMemoryAccessMode, QSharedMemorySharedMemoryError};
+
+impl QSharedMemory {
+    /// Returns the contents of the shared memory segment, which is empty if this process is not attached.
+    ///
+    /// # Safety
+    ///
+    /// Other processes can write to the segment at any time,
+    /// so the caller must hold the lock while the slice is used.
+    pub unsafe fn as_slice(&self) -> &[u8] {
+        ffi::qsharedmemory_data(self)
+    }
+
+    /// Returns the contents of the shared memory segment, which is empty if this process is not attached.
+    ///
+    /// # Safety
+    ///
+    /// Other processes can read and write the segment at any time,
+    /// so the caller must hold the lock while the slice is used.
+    pub unsafe fn as_mut_slice(self: Pin<&mut Self>) -> &mut [u8] {
+        ffi::qsharedmemory_data_mut(self)
+    }
+
+    /// Creates a shared memory segment of size bytes with the key and attaches to it in the given mode.
+    ///
+    /// Returns false if a segment identified by the key already exists, use attach() instead.
+    pub fn create(self: Pin<&mut Self>, size: isize, mode: QSharedMemoryAccessMode) -> bool {
+        ffi::qsharedmemory_create(self, size, mode)
+    }
+
+    /// Constructs a shared memory object with the given key.
+    ///
+    /// No shared memory segment is attached until create() or attach() is called.
+    pub fn new(key: &ffi::QString) -> UniquePtr<Self> {
+        ffi::qsharedmemory_new(key)
+    }
+
+    /// Returns the size of the attached shared memory segment, or 0 if no segment is attached.
+    pub fn size(&self) -> isize {
+        ffi::qsharedmemory_size(self)
+    }
+
+    /// Lock the shared memory segment and call the closure with its contents, then unlock it.
+    ///
+    /// Returns None if the segment could not be locked.
+    pub fn with_lock<R>(mut self: Pin<&mut Self>, f: impl FnOnce(&mut [u8]) -> R) -> Option<R> {
+        if !self.as_mut().lock() {
+            return None;
+        }
+
+        // SAFETY: the lock is held until the closure has finished with the slice
+        let result = f(unsafe { self.as_mut().as_mut_slice() });
+        self.unlock();
+        Some(result)
+    }
+}
@@ -54,6 +54,14 @@ qstringToRustString(const QString& string)
   return ::rust::String(byteArray.constData(), byteArray.size());
 }
 
+::rust::Slice<const ::std::uint16_t>
+qstringAsSlice(const QString& string)
+{
+  return ::rust::Slice<const ::std::uint16_t>(
+    reinterpret_cast<const ::std::uint16_t*>(string.utf16()),
+    static_cast<::std::size_t>(string.size()));
+}
+
 QString
 qstringArg(const QString& string, const QString& a)
 {
@@ -111,6 +111,9 @@ mod ffi {
         #[doc(hidden)]
         #[rust_name = "qstring_to_rust_string"]
         fn qstringToRustString(string: &QString) -> String;
+        #[doc(hidden)]
+        #[rust_name = "qstring_as_slice"]
+        fn qstringAsSlice(string: &QString) -> &[u16];
 
         #[doc(hidden)]
         #[rust_name = "qstring_arg"]
@@ -227,9 +230,14 @@ impl Ord for QString {
 impl fmt::Display for QString {
     /// Convert the QString to a Rust string
     ///
-    /// Note that this converts from UTF-16 to UTF-8
+    /// Note that this converts from UTF-16 to UTF-8 without allocating,
+    /// invalid UTF-16 is replaced with U+FFFD REPLACEMENT CHARACTER
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{}", <&QString as Into<String>>::into(self))
+        use fmt::Write;
+
+        char::decode_utf16(self.as_slice().iter().copied())
+            .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+            .try_for_each(|c| f.write_char(c))
     }
 }
 
@@ -273,6 +281,15 @@ impl From<&String> for QString {
     }
 }
 
+impl From<String> for QString {
+    /// Constructs a QString from a Rust string
+    ///
+    /// Note that this converts from UTF-8 to UTF-16
+    fn from(str: String) -> Self {
+        ffi::qstring_init_from_rust_string(&str)
+    }
+}
+
 impl From<&QString> for String {
     /// Convert the QString to a Rust string
     ///
@@ -288,6 +305,11 @@ impl QString {
         ffi::qstring_arg(self, a)
     }
 
+    /// Borrow the UTF-16 code units of the QString as a slice without copying
+    pub fn as_slice(&self) -> &[u16] {
+        ffi::qstring_as_slice(self)
+    }
+
     /// Lexically compares this string with the other string and
     /// returns if this string is less than, equal to, or greater than the other string.
     pub fn compare(&self, other: &QString, cs: ffi::CaseSensitivity) -> Ordering {
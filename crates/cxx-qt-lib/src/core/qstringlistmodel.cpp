@@ -0,0 +1,31 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qstringlistmodel.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QStringListModel>
+qstringlistmodelNew()
+{
+  return ::std::make_unique<QStringListModel>();
+}
+
+void
+qstringlistmodelSetStringList(QStringListModel& model,
+                              ::rust::Slice<const QString> strings)
+{
+  QStringList list;
+  list.reserve(static_cast<qsizetype>(strings.size()));
+  for (const auto& string : strings) {
+    list.append(string);
+  }
+  model.setStringList(list);
+}
+
+}
+}
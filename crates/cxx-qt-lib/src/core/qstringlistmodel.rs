@@ -0,0 +1,131 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstringlistmodel.h");
+
+        include!("cxx-qt-lib/qabstractitemmodel.h");
+        type QAbstractItemModel = crate::QAbstractItemModel;
+        include!("cxx-qt-lib/qmetaobjectconnection.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QMetaObjectConnection = crate::QMetaObjectConnection;
+        include!("cxx-qt-lib/qmodelindex.h");
+        type QModelIndex = crate::QModelIndex;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = crate::QStringList;
+
+        /// The QStringListModel class provides a model that supplies strings to views.
+        ///
+        /// This is the simplest way to provide a list of strings to a QML view from Rust,
+        /// for more complex data implement a model by inheriting from QAbstractListModel instead.
+        ///
+        /// The model can be used from a bridge as `type QStringListModel = cxx_qt_lib::QStringListModel;`
+        /// and exposed to QML as a `*mut QStringListModel` property.
+        ///
+        /// ```ignore
+        /// let mut model = QStringListModel::new();
+        /// model
+        ///     .pin_mut()
+        ///     .set_string_list(vec![QString::from("Red"), QString::from("Green")]);
+        /// ```
+        type QStringListModel;
+
+        /// Returns the number of rows in the model.
+        ///
+        /// This value corresponds to the number of items in the model's internal string list.
+        /// The optional parent argument is in most models used to specify the parent of the rows to be counted.
+        /// Because this is a list if a valid parent is specified, the result will always be 0.
+        #[rust_name = "row_count"]
+        fn rowCount(self: &QStringListModel, parent: &QModelIndex) -> i32;
+
+        /// Returns the string list used by the model to store data.
+        #[rust_name = "string_list"]
+        fn stringList(self: &QStringListModel) -> QStringList;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qstringlistmodel_new"]
+        fn qstringlistmodelNew() -> UniquePtr<QStringListModel>;
+        #[doc(hidden)]
+        #[cxx_name = "qabstractitemmodelUpcast"]
+        #[rust_name = "qstringlistmodel_upcast"]
+        unsafe fn upcast(model: *mut QStringListModel) -> *mut QAbstractItemModel;
+        #[doc(hidden)]
+        #[rust_name = "qstringlistmodel_on_data_changed"]
+        fn qstringlistmodelOnDataChanged(
+            model: Pin<&mut QStringListModel>,
+            callback: Box<QStringListModelCallback>,
+        ) -> QMetaObjectConnection;
+        #[doc(hidden)]
+        #[rust_name = "qstringlistmodel_set_string_list"]
+        fn qstringlistmodelSetStringList(model: Pin<&mut QStringListModel>, strings: &[QString]);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        type QStringListModelCallback;
+
+        #[doc(hidden)]
+        fn call(self: &mut QStringListModelCallback, strings: &QStringList);
+    }
+}
+
+pub use ffi::QStringListModel;
+
+/// A Rust closure which is called by a connection of a [QStringListModel]
+#[doc(hidden)]
+pub struct QStringListModelCallback {
+    callback: Box<dyn FnMut(&ffi::QStringList)>,
+}
+
+impl QStringListModelCallback {
+    fn call(&mut self, strings: &ffi::QStringList) {
+        (self.callback)(strings);
+    }
+}
+
+impl QStringListModel {
+    /// Returns a pointer to the model as a QAbstractItemModel, so that it can be used as the source of a proxy model.
+    pub fn as_abstract_item_model_ptr(self: Pin<&mut Self>) -> *mut ffi::QAbstractItemModel {
+        unsafe { ffi::qstringlistmodel_upcast(self.get_unchecked_mut()) }
+    }
+
+    /// Constructs a new empty string list model.
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qstringlistmodel_new()
+    }
+
+    /// Connect a closure which is called with the new string list whenever
+    /// the data of the model is changed, for example when a view edits an item.
+    ///
+    /// The closure is called on the thread of the model, and is dropped when the connection is disconnected.
+    pub fn on_data_changed(
+        self: Pin<&mut Self>,
+        callback: impl FnMut(&ffi::QStringList) + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qstringlistmodel_on_data_changed(
+            self,
+            Box::new(QStringListModelCallback {
+                callback: Box::new(callback),
+            }),
+        )
+    }
+
+    /// Sets the model's internal string list to strings.
+    /// The model will notify any attached views that its underlying data has changed.
+    pub fn set_string_list(self: Pin<&mut Self>, strings: Vec<ffi::QString>) {
+        ffi::qstringlistmodel_set_string_list(self, &strings);
+    }
+}
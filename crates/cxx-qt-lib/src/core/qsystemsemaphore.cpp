@@ -0,0 +1,37 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qsystemsemaphore.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QSystemSemaphore>
+qsystemsemaphoreNew(const QString& key,
+                    ::std::int32_t initialValue,
+                    QSystemSemaphoreAccessMode mode)
+{
+  return ::std::make_unique<QSystemSemaphore>(
+    key, static_cast<int>(initialValue), mode);
+}
+
+bool
+qsystemsemaphoreRelease(QSystemSemaphore& semaphore, ::std::int32_t n)
+{
+  return semaphore.release(static_cast<int>(n));
+}
+
+void
+qsystemsemaphoreSetKey(QSystemSemaphore& semaphore,
+                       const QString& key,
+                       ::std::int32_t initialValue,
+                       QSystemSemaphoreAccessMode mode)
+{
+  semaphore.setKey(key, static_cast<int>(initialValue), mode);
+}
+
+}
+}
@@ -0,0 +1,131 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+#[cxx::bridge]
+mod ffi {
+    /// How a QSystemSemaphore accesses the semaphore of the system.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QSystemSemaphoreAccessMode {
+        /// If the semaphore already exists, its initial resource count is not reset.
+        /// If it does not already exist, it is created and its initial resource count set.
+        Open = 0,
+        /// The semaphore takes ownership of the semaphore and its resource count is set,
+        /// even if it already exists.
+        Create = 1,
+    }
+
+    /// The errors which can occur in a QSystemSemaphore.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QSystemSemaphoreSystemSemaphoreError {
+        /// No error occurred.
+        NoError = 0,
+        /// The operation failed because the caller didn't have the required permissions.
+        PermissionDenied = 1,
+        /// The operation failed because of an invalid key.
+        KeyError = 2,
+        /// The operation failed because a system semaphore with the specified key already existed.
+        AlreadyExists = 3,
+        /// The operation failed because a system semaphore with the specified key could not be found.
+        NotFound = 4,
+        /// The operation failed because there was not enough memory available to fill the request.
+        OutOfResources = 5,
+        /// Something else happened and it was bad.
+        UnknownError = 6,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qsystemsemaphore.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QSystemSemaphoreAccessMode;
+        #[namespace = "rust::cxxqtlib1"]
+        type QSystemSemaphoreSystemSemaphoreError;
+
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        /// The QSystemSemaphore class provides a general counting system semaphore.
+        ///
+        /// The semaphore is identified by a key, so it can be shared with C++ processes
+        /// which use a QSystemSemaphore with the same key.
+        ///
+        /// ```ignore
+        /// let mut semaphore = QSystemSemaphore::new(&QString::from("my_app_jobs"), 3, QSystemSemaphoreAccessMode::Open);
+        /// if semaphore.pin_mut().acquire() {
+        ///     semaphore.pin_mut().release(1);
+        /// }
+        /// ```
+        type QSystemSemaphore;
+
+        /// Acquires one of the resources guarded by this semaphore, if there is one available, and returns true.
+        ///
+        /// If all the resources guarded by this semaphore have already been acquired, the call blocks until one is released.
+        fn acquire(self: Pin<&mut QSystemSemaphore>) -> bool;
+
+        /// Returns a value indicating whether an error occurred, and, if so, which error it was.
+        fn error(self: &QSystemSemaphore) -> QSystemSemaphoreSystemSemaphoreError;
+
+        /// Returns a text description of the last error that occurred.
+        #[rust_name = "error_string"]
+        fn errorString(self: &QSystemSemaphore) -> QString;
+
+        /// Returns the key assigned to this system semaphore.
+        fn key(self: &QSystemSemaphore) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qsystemsemaphore_new"]
+        fn qsystemsemaphoreNew(
+            key: &QString,
+            initial_value: i32,
+            mode: QSystemSemaphoreAccessMode,
+        ) -> UniquePtr<QSystemSemaphore>;
+        #[doc(hidden)]
+        #[rust_name = "qsystemsemaphore_release"]
+        fn qsystemsemaphoreRelease(semaphore: Pin<&mut QSystemSemaphore>, n: i32) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qsystemsemaphore_set_key"]
+        fn qsystemsemaphoreSetKey(
+            semaphore: Pin<&mut QSystemSemaphore>,
+            key: &QString,
+            initial_value: i32,
+            mode: QSystemSemaphoreAccessMode,
+        );
+    }
+}
+
+pub use ffi::{QSystemSemaphore, QSystemSemaphoreAccessMode, QSystemSemaphoreSystemSemaphoreError};
+
+impl QSystemSemaphore {
+    /// Requests a system semaphore for the specified key, with initial_value resources when it is created.
+    pub fn new(
+        key: &ffi::QString,
+        initial_value: i32,
+        mode: QSystemSemaphoreAccessMode,
+    ) -> UniquePtr<Self> {
+        ffi::qsystemsemaphore_new(key, initial_value, mode)
+    }
+
+    /// Releases n resources guarded by the semaphore, returns true unless there is a system error.
+    pub fn release(self: Pin<&mut Self>, n: i32) -> bool {
+        ffi::qsystemsemaphore_release(self, n)
+    }
+
+    /// Sets the key of the semaphore, which is then requested as if it was constructed with these arguments.
+    pub fn set_key(
+        self: Pin<&mut Self>,
+        key: &ffi::QString,
+        initial_value: i32,
+        mode: QSystemSemaphoreAccessMode,
+    ) {
+        ffi::qsystemsemaphore_set_key(self, key, initial_value, mode);
+    }
+}
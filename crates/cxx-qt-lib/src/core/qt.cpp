@@ -0,0 +1,22 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qt.h"
+
+#include "../assertion_utils.h"
+
+#include <cstdint>
+
+// Qt::ItemFlags is a QFlags<Qt::ItemFlag> which has a single "int" member
+// Rust represents this as a 32-bit integer type.
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/global/qflags.h?h=v5.15.6-lts-lgpl#n166
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/global/qflags.h?h=v6.2.4#n169
+assert_alignment_and_size(Qt::ItemFlags,
+                          alignof(::std::int32_t),
+                          sizeof(::std::int32_t));
+
+static_assert(::std::is_trivially_copyable<Qt::ItemFlags>::value);
@@ -2,6 +2,8 @@
 // SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::fmt;
 
 #[cxx::bridge(namespace = "Qt")]
 mod ffi {
@@ -25,6 +27,18 @@ mod ffi {
     /// This enum describes the types of connection that can be used with signals.
     ///
     /// Note that UniqueConnection is not supported.
+    /// This enum describes the state of checkable items, controls, and widgets.
+    #[repr(i32)]
+    enum CheckState {
+        /// The item is unchecked.
+        Unchecked = 0,
+        /// The item is partially checked.
+        /// Items in hierarchical models may be partially checked if some, but not all, of their children are checked.
+        PartiallyChecked = 1,
+        /// The item is checked.
+        Checked = 2,
+    }
+
     #[repr(i32)]
     enum ConnectionType {
         /// If the receiver lives in the thread that emits the signal, Qt::DirectConnection is used.
@@ -49,6 +63,73 @@ mod ffi {
         RFC2822Date = 8,
     }
 
+    /// Each item in the model has a set of data elements associated with it, each with its own role.
+    /// The roles are used by the view to indicate to the model which type of data it needs.
+    ///
+    /// Custom roles should start at UserRole.
+    #[repr(i32)]
+    enum ItemDataRole {
+        /// The key data to be rendered in the form of text.
+        DisplayRole = 0,
+        /// The data to be rendered as a decoration in the form of an icon.
+        DecorationRole = 1,
+        /// The data in a form suitable for editing in an editor.
+        EditRole = 2,
+        /// The data displayed in the item's tooltip.
+        ToolTipRole = 3,
+        /// The data displayed in the status bar.
+        StatusTipRole = 4,
+        /// The data displayed for the item in "What's This?" mode.
+        WhatsThisRole = 5,
+        /// The font used for items rendered with the default delegate.
+        FontRole = 6,
+        /// The alignment of the text for items rendered with the default delegate.
+        TextAlignmentRole = 7,
+        /// The background brush used for items rendered with the default delegate.
+        BackgroundRole = 8,
+        /// The foreground brush used for items rendered with the default delegate.
+        ForegroundRole = 9,
+        /// This role is used to obtain the checked state of an item.
+        CheckStateRole = 10,
+        /// The text to be used by accessibility extensions and plugins, such as screen readers.
+        AccessibleTextRole = 11,
+        /// A description of the item for accessibility purposes.
+        AccessibleDescriptionRole = 12,
+        /// The size hint for the item that will be supplied to views.
+        SizeHintRole = 13,
+        /// This role is used to obtain the initial sort order of a header view section.
+        InitialSortOrderRole = 14,
+        /// The first role that can be used for application-specific purposes.
+        UserRole = 0x0100,
+    }
+
+    /// This enum describes the properties of an item.
+    ///
+    /// Multiple flags are combined into [ItemFlags](crate::ItemFlags).
+    #[repr(i32)]
+    enum ItemFlag {
+        /// It does not have any properties set.
+        NoItemFlags = 0,
+        /// It can be selected.
+        ItemIsSelectable = 1,
+        /// It can be edited.
+        ItemIsEditable = 2,
+        /// It can be dragged.
+        ItemIsDragEnabled = 4,
+        /// It can be used as a drop target.
+        ItemIsDropEnabled = 8,
+        /// It can be checked or unchecked by the user.
+        ItemIsUserCheckable = 16,
+        /// The user can interact with the item.
+        ItemIsEnabled = 32,
+        /// The item's state depends on the state of its children.
+        ItemIsAutoTristate = 64,
+        /// The item never has child items.
+        ItemNeverHasChildren = 128,
+        /// The user can cycle through three separate states.
+        ItemIsUserTristate = 256,
+    }
+
     #[repr(i32)]
     enum SplitBehaviorFlags {
         KeepEmptyParts,
@@ -71,13 +152,98 @@ mod ffi {
         include!("cxx-qt-lib/qt.h");
         type AspectRatioMode;
         type CaseSensitivity;
+        type CheckState;
         type ConnectionType;
         type DateFormat;
+        type ItemDataRole;
+        type ItemFlag;
         type SplitBehaviorFlags;
         type TimeSpec;
     }
 }
 
 pub use ffi::{
-    AspectRatioMode, CaseSensitivity, ConnectionType, DateFormat, SplitBehaviorFlags, TimeSpec,
+    AspectRatioMode, CaseSensitivity, CheckState, ConnectionType, DateFormat, ItemDataRole,
+    ItemFlag, SplitBehaviorFlags, TimeSpec,
 };
+
+/// The ItemFlags type stores an OR-combination of [ItemFlag] values.
+///
+/// This is the type returned from an overridden `flags` method of a QAbstractItemModel,
+/// declare it in a bridge as `#[namespace = "Qt"] type ItemFlags = cxx_qt_lib::ItemFlags;`.
+#[repr(C)]
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct ItemFlags {
+    i: i32,
+}
+
+impl ItemFlags {
+    /// Sets the flag if on is true or unsets it if on is false.
+    pub fn set_flag(&mut self, flag: ItemFlag, on: bool) {
+        if on {
+            self.i |= flag.repr;
+        } else {
+            self.i &= !flag.repr;
+        }
+    }
+
+    /// Returns true if the flag is set, otherwise false.
+    ///
+    /// NoItemFlags is only considered set when no other flags are set.
+    pub fn test_flag(&self, flag: ItemFlag) -> bool {
+        if flag.repr == 0 {
+            self.i == 0
+        } else {
+            (self.i & flag.repr) == flag.repr
+        }
+    }
+
+    /// Returns the value stored in the ItemFlags as an integer.
+    pub fn to_int(&self) -> i32 {
+        self.i
+    }
+}
+
+impl From<ItemFlag> for ItemFlags {
+    fn from(flag: ItemFlag) -> Self {
+        Self { i: flag.repr }
+    }
+}
+
+impl std::ops::BitOr<ItemFlag> for ItemFlags {
+    type Output = Self;
+
+    fn bitor(self, flag: ItemFlag) -> Self {
+        Self {
+            i: self.i | flag.repr,
+        }
+    }
+}
+
+impl std::ops::BitOr for ItemFlag {
+    type Output = ItemFlags;
+
+    fn bitor(self, other: Self) -> ItemFlags {
+        ItemFlags::from(self) | other
+    }
+}
+
+impl std::ops::BitOrAssign<ItemFlag> for ItemFlags {
+    fn bitor_assign(&mut self, flag: ItemFlag) {
+        self.i |= flag.repr;
+    }
+}
+
+impl fmt::Debug for ItemFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ItemFlags({:#x})", self.i)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for ItemFlags {
+    type Id = type_id!("Qt::ItemFlags");
+    type Kind = cxx::kind::Trivial;
+}
@@ -0,0 +1,25 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qtemporarydir.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QTemporaryDir>
+qtemporarydirNew()
+{
+  return ::std::make_unique<QTemporaryDir>();
+}
+
+::std::unique_ptr<QTemporaryDir>
+qtemporarydirNewWithTemplate(const QString& templatePath)
+{
+  return ::std::make_unique<QTemporaryDir>(templatePath);
+}
+
+}
+}
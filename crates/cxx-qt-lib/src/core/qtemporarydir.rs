@@ -0,0 +1,82 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::UniquePtr;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qtemporarydir.h");
+
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        /// The QTemporaryDir class creates a unique directory for temporary use.
+        ///
+        /// The directory is created with the same naming as Qt applications use,
+        /// and by default is removed when the QTemporaryDir is dropped.
+        type QTemporaryDir;
+
+        /// Returns true if the QTemporaryDir is in auto remove mode.
+        #[rust_name = "auto_remove"]
+        fn autoRemove(self: &QTemporaryDir) -> bool;
+
+        /// If is_valid() returns false, this function returns the error string that explains
+        /// why the creation of the temporary directory failed.
+        #[rust_name = "error_string"]
+        fn errorString(self: &QTemporaryDir) -> QString;
+
+        /// Returns the path name of a file in the temporary directory.
+        /// Does not check if the file actually exists in the directory.
+        #[rust_name = "file_path"]
+        fn filePath(self: &QTemporaryDir, file_name: &QString) -> QString;
+
+        /// Returns true if the QTemporaryDir was created successfully.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QTemporaryDir) -> bool;
+
+        /// Returns the path to the temporary directory. Empty if the QTemporaryDir could not be created.
+        fn path(self: &QTemporaryDir) -> QString;
+
+        /// Removes the temporary directory, including all its contents.
+        ///
+        /// Returns true if removing was successful.
+        fn remove(self: Pin<&mut QTemporaryDir>) -> bool;
+
+        /// Sets the QTemporaryDir into auto-remove mode if b is true.
+        #[rust_name = "set_auto_remove"]
+        fn setAutoRemove(self: Pin<&mut QTemporaryDir>, b: bool);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qtemporarydir_new"]
+        fn qtemporarydirNew() -> UniquePtr<QTemporaryDir>;
+        #[doc(hidden)]
+        #[rust_name = "qtemporarydir_new_with_template"]
+        fn qtemporarydirNewWithTemplate(template_path: &QString) -> UniquePtr<QTemporaryDir>;
+    }
+}
+
+pub use ffi::QTemporaryDir;
+
+impl QTemporaryDir {
+    /// Constructs a QTemporaryDir using as template the application name returned by
+    /// QCoreApplication::application_name() (otherwise qt_temp).
+    /// The directory is stored in the system's temporary directory.
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qtemporarydir_new()
+    }
+
+    /// Constructs a QTemporaryDir with a template of template_path.
+    ///
+    /// If template_path is a relative path, the path will be relative to the current working directory.
+    /// If the template_path ends with XXXXXX it will be used as the dynamic portion of the directory name,
+    /// otherwise it will be appended.
+    pub fn new_with_template(template_path: &ffi::QString) -> UniquePtr<Self> {
+        ffi::qtemporarydir_new_with_template(template_path)
+    }
+}
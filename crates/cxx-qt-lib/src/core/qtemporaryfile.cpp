@@ -0,0 +1,34 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qtemporaryfile.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QTemporaryFile>
+qtemporaryfileNew()
+{
+  return ::std::make_unique<QTemporaryFile>();
+}
+
+::std::unique_ptr<QTemporaryFile>
+qtemporaryfileNewWithTemplate(const QString& templateName)
+{
+  return ::std::make_unique<QTemporaryFile>(templateName);
+}
+
+::std::int64_t
+qtemporaryfileWrite(QTemporaryFile& file,
+                    ::rust::Slice<const ::std::uint8_t> data)
+{
+  return static_cast<::std::int64_t>(
+    file.write(reinterpret_cast<const char*>(data.data()),
+               static_cast<qint64>(data.size())));
+}
+
+}
+}
@@ -0,0 +1,108 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qtemporaryfile.h");
+
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        /// The QTemporaryFile class is an I/O device that operates on temporary files.
+        ///
+        /// The file is created with the same naming as Qt applications use,
+        /// and by default is removed when the QTemporaryFile is dropped.
+        ///
+        /// ```ignore
+        /// let mut file = QTemporaryFile::new();
+        /// if file.pin_mut().open() {
+        ///     file.pin_mut().write(b"Hello World");
+        ///     let file_name = file.file_name();
+        /// }
+        /// ```
+        type QTemporaryFile;
+
+        /// Returns true if the QTemporaryFile is in auto remove mode.
+        #[rust_name = "auto_remove"]
+        fn autoRemove(self: &QTemporaryFile) -> bool;
+
+        /// Calls flush() and closes the file. Errors from flush are ignored.
+        fn close(self: Pin<&mut QTemporaryFile>);
+
+        /// Returns the complete unique filename backing the QTemporaryFile object.
+        ///
+        /// This string is empty before the QTemporaryFile is opened,
+        /// afterwards it will contain the file_template() plus additional characters to make it unique.
+        #[rust_name = "file_name"]
+        fn fileName(self: &QTemporaryFile) -> QString;
+
+        /// Returns the set file template.
+        #[rust_name = "file_template"]
+        fn fileTemplate(self: &QTemporaryFile) -> QString;
+
+        /// Flushes any buffered data to the file. Returns true if successful; otherwise returns false.
+        fn flush(self: Pin<&mut QTemporaryFile>) -> bool;
+
+        /// Returns true if the device is open; otherwise returns false.
+        #[rust_name = "is_open"]
+        fn isOpen(self: &QTemporaryFile) -> bool;
+
+        /// A QTemporaryFile will always be opened in ReadWrite mode,
+        /// this allows easy access to the data in the file. This function will return true upon success
+        /// and will set the file_name() to the unique filename used.
+        fn open(self: Pin<&mut QTemporaryFile>) -> bool;
+
+        /// Sets the QTemporaryFile into auto-remove mode if b is true.
+        #[rust_name = "set_auto_remove"]
+        fn setAutoRemove(self: Pin<&mut QTemporaryFile>, b: bool);
+
+        /// Sets the static portion of the file name to name.
+        /// If the file template contains XXXXXX that will automatically be replaced with the unique part of the filename,
+        /// otherwise a filename will be determined automatically based on the static portion specified.
+        #[rust_name = "set_file_template"]
+        fn setFileTemplate(self: Pin<&mut QTemporaryFile>, name: &QString);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qtemporaryfile_new"]
+        fn qtemporaryfileNew() -> UniquePtr<QTemporaryFile>;
+        #[doc(hidden)]
+        #[rust_name = "qtemporaryfile_new_with_template"]
+        fn qtemporaryfileNewWithTemplate(template_name: &QString) -> UniquePtr<QTemporaryFile>;
+        #[doc(hidden)]
+        #[rust_name = "qtemporaryfile_write"]
+        fn qtemporaryfileWrite(file: Pin<&mut QTemporaryFile>, data: &[u8]) -> i64;
+    }
+}
+
+pub use ffi::QTemporaryFile;
+
+impl QTemporaryFile {
+    /// Constructs a QTemporaryFile using as file template the application name returned by
+    /// QCoreApplication::application_name() (otherwise qt_temp) followed by ".XXXXXX".
+    /// The file is stored in the system's temporary directory.
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qtemporaryfile_new()
+    }
+
+    /// Constructs a QTemporaryFile with a template filename of template_name.
+    ///
+    /// If template_name is a relative path, the path will be relative to the current working directory.
+    pub fn new_with_template(template_name: &ffi::QString) -> UniquePtr<Self> {
+        ffi::qtemporaryfile_new_with_template(template_name)
+    }
+
+    /// Writes the data to the file. Returns the number of bytes that were actually written,
+    /// or -1 if an error occurred.
+    pub fn write(self: Pin<&mut Self>, data: &[u8]) -> i64 {
+        ffi::qtemporaryfile_write(self, data)
+    }
+}
@@ -0,0 +1,56 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qtlogging.h"
+
+// For the declaration of qtloggingMessageHandler
+#include "cxx-qt-lib/src/core/qtlogging.rs.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+const char*
+qmessagelogcontextCategory(const QMessageLogContext& context)
+{
+  return context.category;
+}
+
+const char*
+qmessagelogcontextFile(const QMessageLogContext& context)
+{
+  return context.file;
+}
+
+const char*
+qmessagelogcontextFunction(const QMessageLogContext& context)
+{
+  return context.function;
+}
+
+::std::int32_t
+qmessagelogcontextLine(const QMessageLogContext& context)
+{
+  return static_cast<::std::int32_t>(context.line);
+}
+
+void
+qtloggingInstallMessageHandler()
+{
+  qInstallMessageHandler([](QtMsgType type,
+                            const QMessageLogContext& context,
+                            const QString& message) {
+    qtloggingMessageHandler(type, context, message);
+  });
+}
+
+void
+qtloggingResetMessageHandler()
+{
+  qInstallMessageHandler(nullptr);
+}
+
+}
+}
@@ -0,0 +1,181 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::sync::{Arc, RwLock};
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum describes the messages that can be sent to a message handler.
+    #[repr(i32)]
+    enum QtMsgType {
+        /// A message generated by the qDebug() function.
+        QtDebugMsg = 0,
+        /// A message generated by the qWarning() function.
+        QtWarningMsg = 1,
+        /// A message generated by the qCritical() function.
+        QtCriticalMsg = 2,
+        /// A message generated by the qFatal() function.
+        QtFatalMsg = 3,
+        /// A message generated by the qInfo() function.
+        QtInfoMsg = 4,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qtlogging.h");
+        type QtMsgType;
+
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        /// The QMessageLogContext class provides additional information about a log message.
+        ///
+        /// It is passed to the handler which is installed with [q_install_message_handler](crate::q_install_message_handler).
+        type QMessageLogContext;
+
+        /// Generates a formatted string out of the type, context and message arguments,
+        /// using the pattern which is set with [q_set_message_pattern](crate::q_set_message_pattern).
+        ///
+        /// This is useful for a message handler which should still format messages as Qt does.
+        #[rust_name = "q_format_log_message"]
+        fn qFormatLogMessage(
+            msg_type: QtMsgType,
+            context: &QMessageLogContext,
+            message: &QString,
+        ) -> QString;
+
+        /// Changes the output of the default message handler.
+        ///
+        /// The pattern can contain placeholders such as `%{type}`, `%{category}`, `%{file}`, `%{line}`, and `%{message}`,
+        /// the environment variable `QT_MESSAGE_PATTERN` takes precedence over the pattern which is set here.
+        #[rust_name = "q_set_message_pattern"]
+        fn qSetMessagePattern(pattern: &QString);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qmessagelogcontext_category"]
+        fn qmessagelogcontextCategory(context: &QMessageLogContext) -> *const c_char;
+        #[doc(hidden)]
+        #[rust_name = "qmessagelogcontext_file"]
+        fn qmessagelogcontextFile(context: &QMessageLogContext) -> *const c_char;
+        #[doc(hidden)]
+        #[rust_name = "qmessagelogcontext_function"]
+        fn qmessagelogcontextFunction(context: &QMessageLogContext) -> *const c_char;
+        #[doc(hidden)]
+        #[rust_name = "qmessagelogcontext_line"]
+        fn qmessagelogcontextLine(context: &QMessageLogContext) -> i32;
+
+        #[doc(hidden)]
+        #[rust_name = "qtlogging_install_message_handler"]
+        fn qtloggingInstallMessageHandler();
+        #[doc(hidden)]
+        #[rust_name = "qtlogging_reset_message_handler"]
+        fn qtloggingResetMessageHandler();
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        #[cxx_name = "qtloggingMessageHandler"]
+        fn qtlogging_message_handler(
+            msg_type: QtMsgType,
+            context: &QMessageLogContext,
+            message: &QString,
+        );
+    }
+}
+
+pub use ffi::{q_format_log_message, q_set_message_pattern, QMessageLogContext, QtMsgType};
+
+/// A Rust closure which handles the messages of Qt
+type QtMessageHandler = dyn Fn(QtMsgType, &QMessageLogContext, &ffi::QString) + Send + Sync;
+
+/// The message handler which is currently installed
+static MESSAGE_HANDLER: RwLock<Option<Arc<QtMessageHandler>>> = RwLock::new(None);
+
+/// Calls the installed message handler from the message handler on the C++ side
+fn qtlogging_message_handler(
+    msg_type: QtMsgType,
+    context: &QMessageLogContext,
+    message: &ffi::QString,
+) {
+    // Take a copy of the handler so that the lock is not held while it is called,
+    // this allows for the handler to log messages or to install another handler
+    let handler = MESSAGE_HANDLER
+        .read()
+        .ok()
+        .and_then(|handler| handler.as_ref().cloned());
+    if let Some(handler) = handler {
+        handler(msg_type, context, message);
+    }
+}
+
+/// Convert a C string from a QMessageLogContext, which is null when the information is not available
+fn context_str(_context: &QMessageLogContext, ptr: *const c_char) -> Option<&CStr> {
+    if ptr.is_null() {
+        None
+    } else {
+        // SAFETY: the strings of a QMessageLogContext are valid for as long as the context
+        Some(unsafe { CStr::from_ptr(ptr) })
+    }
+}
+
+impl QMessageLogContext {
+    /// Returns the name of the logging category of the message, such as `default` or `qt.qml.binding`.
+    pub fn category(&self) -> Option<&CStr> {
+        context_str(self, ffi::qmessagelogcontext_category(self))
+    }
+
+    /// Returns the source file which the message was logged from.
+    ///
+    /// Note that this is only available in debug builds of Qt or when `QT_MESSAGELOGCONTEXT` is defined.
+    pub fn file(&self) -> Option<&CStr> {
+        context_str(self, ffi::qmessagelogcontext_file(self))
+    }
+
+    /// Returns the signature of the function which the message was logged from.
+    ///
+    /// Note that this is only available in debug builds of Qt or when `QT_MESSAGELOGCONTEXT` is defined.
+    pub fn function(&self) -> Option<&CStr> {
+        context_str(self, ffi::qmessagelogcontext_function(self))
+    }
+
+    /// Returns the line in the source file which the message was logged from, or zero if it is not available.
+    pub fn line(&self) -> i32 {
+        ffi::qmessagelogcontext_line(self)
+    }
+}
+
+/// Installs a closure which is called for every message of Qt and QML, such as from qDebug() or a QML binding error,
+/// instead of the default message handler which prints to the console.
+///
+/// The closure can be called on any thread, and replaces any handler which has been installed before.
+/// After a message of type [QtMsgType::QtFatalMsg] has been handled the application is aborted.
+///
+/// ```ignore
+/// q_install_message_handler(|msg_type, context, message| {
+///     let category = context.category().map(|category| category.to_string_lossy());
+///     eprintln!("{msg_type:?} {category:?}: {message}");
+/// });
+/// ```
+pub fn q_install_message_handler(
+    handler: impl Fn(QtMsgType, &QMessageLogContext, &ffi::QString) + Send + Sync + 'static,
+) {
+    if let Ok(mut message_handler) = MESSAGE_HANDLER.write() {
+        *message_handler = Some(Arc::new(handler));
+    }
+    ffi::qtlogging_install_message_handler();
+}
+
+/// Restores the default message handler of Qt and drops any closure installed with [q_install_message_handler].
+pub fn q_reset_message_handler() {
+    ffi::qtlogging_reset_message_handler();
+    if let Ok(mut message_handler) = MESSAGE_HANDLER.write() {
+        *message_handler = None;
+    }
+}
@@ -4,6 +4,7 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 use cxx::{type_id, ExternType};
+use std::any::Any;
 use std::mem::MaybeUninit;
 
 #[cxx::bridge]
@@ -39,6 +40,31 @@ mod ffi {
         #[rust_name = "qvariant_eq"]
         fn operatorEq(a: &QVariant, b: &QVariant) -> bool;
     }
+
+    #[namespace = "rust::cxxqtlib1::qvariant"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qvariant_from_rust_handle"]
+        fn qvariantFromRustHandle(value: Box<QVariantRustValue>) -> QVariant;
+        #[doc(hidden)]
+        #[rust_name = "qvariant_is_rust_handle"]
+        fn qvariantIsRustHandle(variant: &QVariant) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qvariant_rust_handle"]
+        fn qvariantRustHandle(variant: &QVariant) -> &QVariantRustValue;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        type QVariantRustValue;
+    }
+}
+
+/// A Rust value which is stored in a [QVariant] as an opaque handle
+#[doc(hidden)]
+pub struct QVariantRustValue {
+    value: Box<dyn Any + Send + Sync>,
 }
 
 /// The QVariant class acts like a union for the most common Qt data types.
@@ -122,6 +148,27 @@ impl QVariant {
     pub fn value_or_default<T: QVariantValue>(&self) -> T {
         T::value_or_default(self)
     }
+
+    /// Constructs a QVariant which stores a Rust value as an opaque handle.
+    ///
+    /// The value is not converted or serialized, instead copies of the QVariant share the value
+    /// and it is dropped when the last copy is destroyed.
+    /// This allows for Rust values to be passed through QML, for example from an invokable of one QObject to another.
+    pub fn from_rust_handle<T: Any + Send + Sync>(value: T) -> Self {
+        ffi::qvariant_from_rust_handle(Box::new(QVariantRustValue {
+            value: Box::new(value),
+        }))
+    }
+
+    /// Returns a reference to the Rust value of the handle which is stored in this QVariant,
+    /// if it was constructed with [QVariant::from_rust_handle] from a value of type T, otherwise returns None.
+    pub fn rust_handle<T: Any>(&self) -> Option<&T> {
+        if ffi::qvariant_is_rust_handle(self) {
+            ffi::qvariant_rust_handle(self).value.downcast_ref::<T>()
+        } else {
+            None
+        }
+    }
 }
 
 impl std::cmp::PartialEq for QVariant {
@@ -11,6 +11,9 @@
 
 #include "../../assertion_utils.h"
 
+// For the definition of QVariantRustValue
+#include "cxx-qt-lib/src/core/qvariant/mod.rs.h"
+
 // The layout has changed between Qt 5 and Qt 6
 //
 // Qt6 QVariant has one member, which contains three pointers and a union
@@ -105,6 +108,31 @@ CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::std::uint16_t, U16)
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::std::uint32_t, U32)
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::std::uint64_t, U64)
 
+QVariant
+qvariantFromRustHandle(::rust::Box<QVariantRustValue> value)
+{
+  QVariantRustHandle handle;
+  handle.data = ::std::shared_ptr<QVariantRustValue>(
+    value.into_raw(), [](QVariantRustValue* ptr) {
+      ::rust::Box<QVariantRustValue>::from_raw(ptr);
+    });
+  return QVariant::fromValue(handle);
+}
+
+bool
+qvariantIsRustHandle(const QVariant& variant)
+{
+  // Use the exact type so that a handle is never converted from another type
+  return variant.userType() == qMetaTypeId<QVariantRustHandle>();
+}
+
+const QVariantRustValue&
+qvariantRustHandle(const QVariant& variant)
+{
+  // The QVariant owns a copy of the handle, so the value lives as long as the QVariant
+  return *static_cast<const QVariantRustHandle*>(variant.constData())->data;
+}
+
 }
 }
 }
@@ -0,0 +1,26 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qvariantanimation.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QVariantAnimation>
+qvariantanimationNew()
+{
+  return ::std::make_unique<QVariantAnimation>();
+}
+
+void
+qvariantanimationStart(QVariantAnimation& animation)
+{
+  // The animation is owned by Rust so it must not be deleted when it stops
+  animation.start(QAbstractAnimation::KeepWhenStopped);
+}
+
+}
+}
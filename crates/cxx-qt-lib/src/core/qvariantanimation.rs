@@ -0,0 +1,231 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+#[cxx::bridge]
+mod ffi {
+    /// The states of an animation.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QAbstractAnimationState {
+        /// The animation is not running, this is the initial state of an animation.
+        Stopped = 0,
+        /// The animation is paused, it keeps its current time until it is resumed.
+        Paused = 1,
+        /// The animation is running.
+        Running = 2,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qvariantanimation.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QAbstractAnimationState;
+
+        include!("cxx-qt-lib/qeasingcurve.h");
+        type QEasingCurve = crate::QEasingCurve;
+        include!("cxx-qt-lib/qmetaobjectconnection.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QMetaObjectConnection = crate::QMetaObjectConnection;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+
+        /// The QVariantAnimation class provides a base class for animations.
+        ///
+        /// The value is interpolated from the start value to the end value over the duration,
+        /// changes of the value are delivered to Rust closures by the Qt event loop.
+        ///
+        /// ```ignore
+        /// let mut animation = QVariantAnimation::new();
+        /// animation.pin_mut().set_start_value(&QVariant::from(&0.0_f64));
+        /// animation.pin_mut().set_end_value(&QVariant::from(&1.0_f64));
+        /// animation.pin_mut().set_duration(250);
+        /// animation
+        ///     .pin_mut()
+        ///     .on_value_changed(|value| println!("{value:?}"))
+        ///     .release();
+        /// animation.pin_mut().start();
+        /// ```
+        type QVariantAnimation;
+
+        /// Returns the current loop of the animation, starting from 0.
+        #[rust_name = "current_loop"]
+        fn currentLoop(self: &QVariantAnimation) -> i32;
+
+        /// Returns the current time of the animation in milliseconds, excluding previous loops.
+        #[rust_name = "current_loop_time"]
+        fn currentLoopTime(self: &QVariantAnimation) -> i32;
+
+        /// Returns the current value of the animation, interpolated between the start and end values.
+        #[rust_name = "current_value"]
+        fn currentValue(self: &QVariantAnimation) -> QVariant;
+
+        /// Returns the duration of the animation in milliseconds.
+        fn duration(self: &QVariantAnimation) -> i32;
+
+        /// Returns the easing curve of the animation, the default is a linear curve.
+        #[rust_name = "easing_curve"]
+        fn easingCurve(self: &QVariantAnimation) -> QEasingCurve;
+
+        /// Returns the end value of the animation.
+        #[rust_name = "end_value"]
+        fn endValue(self: &QVariantAnimation) -> QVariant;
+
+        /// Returns the number of times the animation should loop before it finishes, -1 loops forever.
+        #[rust_name = "loop_count"]
+        fn loopCount(self: &QVariantAnimation) -> i32;
+
+        /// Pauses the animation, keeping its current time.
+        fn pause(self: Pin<&mut QVariantAnimation>);
+
+        /// Resumes the animation after it was paused.
+        fn resume(self: Pin<&mut QVariantAnimation>);
+
+        /// Sets the current time of the animation in milliseconds, including previous loops.
+        #[rust_name = "set_current_time"]
+        fn setCurrentTime(self: Pin<&mut QVariantAnimation>, msecs: i32);
+
+        /// Sets the duration of the animation in milliseconds.
+        #[rust_name = "set_duration"]
+        fn setDuration(self: Pin<&mut QVariantAnimation>, msecs: i32);
+
+        /// Sets the easing curve which controls the progress of the interpolation between the start and end values.
+        #[rust_name = "set_easing_curve"]
+        fn setEasingCurve(self: Pin<&mut QVariantAnimation>, easing: &QEasingCurve);
+
+        /// Sets the end value of the animation.
+        #[rust_name = "set_end_value"]
+        fn setEndValue(self: Pin<&mut QVariantAnimation>, value: &QVariant);
+
+        /// Creates a key frame at the given step with the given value, the step is between 0.0 and 1.0.
+        #[rust_name = "set_key_value_at"]
+        fn setKeyValueAt(self: Pin<&mut QVariantAnimation>, step: f64, value: &QVariant);
+
+        /// Sets the number of times the animation should loop before it finishes, -1 loops forever.
+        #[rust_name = "set_loop_count"]
+        fn setLoopCount(self: Pin<&mut QVariantAnimation>, loop_count: i32);
+
+        /// Sets the start value of the animation.
+        #[rust_name = "set_start_value"]
+        fn setStartValue(self: Pin<&mut QVariantAnimation>, value: &QVariant);
+
+        /// Returns the start value of the animation.
+        #[rust_name = "start_value"]
+        fn startValue(self: &QVariantAnimation) -> QVariant;
+
+        /// Returns the state of the animation.
+        fn state(self: &QVariantAnimation) -> QAbstractAnimationState;
+
+        /// Stops the animation, the current time is kept.
+        fn stop(self: Pin<&mut QVariantAnimation>);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qvariantanimation_new"]
+        fn qvariantanimationNew() -> UniquePtr<QVariantAnimation>;
+        #[doc(hidden)]
+        #[rust_name = "qvariantanimation_on_finished"]
+        fn qvariantanimationOnFinished(
+            animation: Pin<&mut QVariantAnimation>,
+            callback: Box<QVariantAnimationFinishedCallback>,
+        ) -> QMetaObjectConnection;
+        #[doc(hidden)]
+        #[rust_name = "qvariantanimation_on_value_changed"]
+        fn qvariantanimationOnValueChanged(
+            animation: Pin<&mut QVariantAnimation>,
+            callback: Box<QVariantAnimationCallback>,
+        ) -> QMetaObjectConnection;
+        #[doc(hidden)]
+        #[rust_name = "qvariantanimation_start"]
+        fn qvariantanimationStart(animation: Pin<&mut QVariantAnimation>);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        type QVariantAnimationCallback;
+        #[doc(hidden)]
+        type QVariantAnimationFinishedCallback;
+
+        #[doc(hidden)]
+        fn call(self: &mut QVariantAnimationCallback, value: &QVariant);
+        #[doc(hidden)]
+        fn call(self: &mut QVariantAnimationFinishedCallback);
+    }
+}
+
+pub use ffi::{QAbstractAnimationState, QVariantAnimation};
+
+/// A Rust closure which is called when the value of a [QVariantAnimation] changes
+#[doc(hidden)]
+pub struct QVariantAnimationCallback {
+    callback: Box<dyn FnMut(&ffi::QVariant)>,
+}
+
+impl QVariantAnimationCallback {
+    fn new(callback: impl FnMut(&ffi::QVariant) + 'static) -> Box<Self> {
+        Box::new(Self {
+            callback: Box::new(callback),
+        })
+    }
+
+    fn call(&mut self, value: &ffi::QVariant) {
+        (self.callback)(value);
+    }
+}
+
+/// A Rust closure which is called when a [QVariantAnimation] finishes
+#[doc(hidden)]
+pub struct QVariantAnimationFinishedCallback {
+    callback: Box<dyn FnMut()>,
+}
+
+impl QVariantAnimationFinishedCallback {
+    fn new(callback: impl FnMut() + 'static) -> Box<Self> {
+        Box::new(Self {
+            callback: Box::new(callback),
+        })
+    }
+
+    fn call(&mut self) {
+        (self.callback)();
+    }
+}
+
+impl QVariantAnimation {
+    /// Constructs a new animation without a start or end value.
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qvariantanimation_new()
+    }
+
+    /// Connect a closure which is called when the animation has finished,
+    /// which is after the last loop of the animation or when it is stopped.
+    ///
+    /// The closure is called on the thread of the animation, and is dropped when the connection is disconnected.
+    pub fn on_finished(
+        self: Pin<&mut Self>,
+        callback: impl FnMut() + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qvariantanimation_on_finished(self, QVariantAnimationFinishedCallback::new(callback))
+    }
+
+    /// Connect a closure which is called with the current value each time it changes.
+    ///
+    /// The closure is called on the thread of the animation, and is dropped when the connection is disconnected.
+    pub fn on_value_changed(
+        self: Pin<&mut Self>,
+        callback: impl FnMut(&ffi::QVariant) + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qvariantanimation_on_value_changed(self, QVariantAnimationCallback::new(callback))
+    }
+
+    /// Starts the animation from the current time, the animation is not deleted when it stops.
+    pub fn start(self: Pin<&mut Self>) {
+        ffi::qvariantanimation_start(self);
+    }
+}
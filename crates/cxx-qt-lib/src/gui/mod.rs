@@ -9,6 +9,27 @@ pub use qcolor::QColor;
 mod qguiapplication;
 pub use qguiapplication::QGuiApplication;
 
+mod qimage;
+pub use qimage::{QImage, QImageFormat};
+
+mod qkeyevent;
+pub use qkeyevent::QKeyEvent;
+
+mod qmatrix4x4;
+pub use qmatrix4x4::QMatrix4x4;
+
+mod qmouseevent;
+pub use qmouseevent::QMouseEvent;
+
+mod qpixmap;
+pub use qpixmap::QPixmap;
+
+mod qquaternion;
+pub use qquaternion::QQuaternion;
+
+mod qshortcutevent;
+pub use qshortcutevent::QShortcutEvent;
+
 mod qvector2d;
 pub use qvector2d::QVector2D;
 
@@ -4,7 +4,7 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::{QByteArray, QString, QStringList, QVector};
+use crate::{QByteArray, QCoreApplication, QMetaObjectConnection, QString, QStringList, QVector};
 use core::pin::Pin;
 
 #[cxx::bridge]
@@ -12,6 +12,8 @@ mod ffi {
     unsafe extern "C++" {
         include!("cxx-qt-lib/qbytearray.h");
         type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qcoreapplication.h");
+        type QCoreApplication = crate::QCoreApplication;
         include!("cxx-qt-lib/qstring.h");
         type QString = crate::QString;
         include!("cxx-qt-lib/qstringlist.h");
@@ -52,6 +54,10 @@ mod ffi {
         #[rust_name = "qguiapplication_library_paths"]
         fn qapplicationLibraryPaths(app: &QGuiApplication) -> QStringList;
         #[doc(hidden)]
+        #[cxx_name = "qcoreapplicationUpcast"]
+        #[rust_name = "qguiapplication_upcast"]
+        unsafe fn upcast(app: *mut QGuiApplication) -> *mut QCoreApplication;
+        #[doc(hidden)]
         #[rust_name = "qguiapplication_organization_domain"]
         fn qapplicationOrganizationDomain(app: &QGuiApplication) -> QString;
         #[doc(hidden)]
@@ -101,6 +107,11 @@ impl QGuiApplication {
         ffi::qguiapplication_application_version(self)
     }
 
+    /// Returns the application as a QCoreApplication, which it inherits from.
+    pub fn as_core_application_mut(self: Pin<&mut Self>) -> Pin<&mut QCoreApplication> {
+        unsafe { Pin::new_unchecked(&mut *ffi::qguiapplication_upcast(self.get_unchecked_mut())) }
+    }
+
     /// Enters the main event loop and waits until exit() is called,
     /// and then returns the value that was set to exit() (which is 0 if exit() is called via quit()).
     pub fn exec(self: Pin<&mut Self>) -> i32 {
@@ -137,6 +148,15 @@ impl QGuiApplication {
         ffi::qguiapplication_new(&vector)
     }
 
+    /// Connect a closure which is called when the application is about to quit the main event loop,
+    /// see [QCoreApplication::on_about_to_quit].
+    pub fn on_about_to_quit(
+        self: Pin<&mut Self>,
+        callback: impl FnMut() + 'static,
+    ) -> QMetaObjectConnection {
+        self.as_core_application_mut().on_about_to_quit(callback)
+    }
+
     /// The Internet domain of the organization that wrote this application
     pub fn organization_domain(&self) -> QString {
         ffi::qguiapplication_organization_domain(self)
@@ -0,0 +1,55 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_GUI_FEATURE
+#include "cxx-qt-lib/qimage.h"
+
+#include "../assertion_utils.h"
+
+// QImage inherits from QPaintDevice which has a v-table, a ushort of painters
+// (which is padded to the size of a pointer) and a pointer to reserved data,
+// then QImage adds its d-pointer. This is a total of four pointers.
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/painting/qpaintdevice.h?h=v5.15.6-lts-lgpl#n89
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/painting/qpaintdevice.h?h=v6.2.4#n89
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/image/qimage.h?h=v6.2.4#n358
+assert_alignment_and_size(QImage,
+                          alignof(::std::size_t),
+                          sizeof(::std::size_t) * 4);
+
+static_assert(!::std::is_trivially_copy_assignable<QImage>::value);
+static_assert(!::std::is_trivially_copy_constructible<QImage>::value);
+
+static_assert(!::std::is_trivially_destructible<QImage>::value);
+
+static_assert(QTypeInfo<QImage>::isRelocatable);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::rust::Slice<const ::std::uint8_t>
+qimageAsSlice(const QImage& image)
+{
+  return ::rust::Slice<const ::std::uint8_t>(
+    image.constBits(), static_cast<::std::size_t>(image.sizeInBytes()));
+}
+
+::rust::isize
+qimageBytesPerLine(const QImage& image)
+{
+  return static_cast<::rust::isize>(image.bytesPerLine());
+}
+
+QImage
+qimageConvertToFormat(const QImage& image, QImageFormat format)
+{
+  return image.convertToFormat(format);
+}
+
+}
+}
+
+#endif
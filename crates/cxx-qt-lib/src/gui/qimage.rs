@@ -0,0 +1,440 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    /// The format of the pixels which are stored in an image.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QImageFormat {
+        /// The image is invalid.
+        Format_Invalid,
+        /// The image is stored using 1-bit per pixel, with the most significant bit first.
+        Format_Mono,
+        /// The image is stored using 1-bit per pixel, with the less significant bit first.
+        Format_MonoLSB,
+        /// The image is stored using 8-bit indexes into a colormap.
+        Format_Indexed8,
+        /// The image is stored using a 32-bit RGB format (0xffRRGGBB).
+        Format_RGB32,
+        /// The image is stored using a 32-bit ARGB format (0xAARRGGBB).
+        Format_ARGB32,
+        /// The image is stored using a premultiplied 32-bit ARGB format (0xAARRGGBB).
+        Format_ARGB32_Premultiplied,
+        /// The image is stored using a 16-bit RGB format (5-6-5).
+        Format_RGB16,
+        /// The image is stored using a premultiplied 24-bit ARGB format (8-5-6-5).
+        Format_ARGB8565_Premultiplied,
+        /// The image is stored using a 24-bit RGB format (6-6-6).
+        Format_RGB666,
+        /// The image is stored using a premultiplied 24-bit ARGB format (6-6-6-6).
+        Format_ARGB6666_Premultiplied,
+        /// The image is stored using a 16-bit RGB format (5-5-5).
+        Format_RGB555,
+        /// The image is stored using a premultiplied 24-bit ARGB format (8-5-5-5).
+        Format_ARGB8555_Premultiplied,
+        /// The image is stored using a 24-bit RGB format (8-8-8).
+        Format_RGB888,
+        /// The image is stored using a 16-bit RGB format (4-4-4).
+        Format_RGB444,
+        /// The image is stored using a premultiplied 16-bit ARGB format (4-4-4-4).
+        Format_ARGB4444_Premultiplied,
+        /// The image is stored using a 32-bit byte-ordered RGB(x) format (8-8-8-8).
+        Format_RGBX8888,
+        /// The image is stored using a 32-bit byte-ordered RGBA format (8-8-8-8).
+        Format_RGBA8888,
+        /// The image is stored using a premultiplied 32-bit byte-ordered RGBA format (8-8-8-8).
+        Format_RGBA8888_Premultiplied,
+        /// The image is stored using a 32-bit BGR format (x-10-10-10).
+        Format_BGR30,
+        /// The image is stored using a premultiplied 32-bit ABGR format (2-10-10-10).
+        Format_A2BGR30_Premultiplied,
+        /// The image is stored using a 32-bit RGB format (x-10-10-10).
+        Format_RGB30,
+        /// The image is stored using a premultiplied 32-bit ARGB format (2-10-10-10).
+        Format_A2RGB30_Premultiplied,
+        /// The image is stored using an 8-bit alpha only format.
+        Format_Alpha8,
+        /// The image is stored using an 8-bit grayscale format.
+        Format_Grayscale8,
+        /// The image is stored using a 64-bit halfword-ordered RGB(x) format (16-16-16-16).
+        Format_RGBX64,
+        /// The image is stored using a 64-bit halfword-ordered RGBA format (16-16-16-16).
+        Format_RGBA64,
+        /// The image is stored using a premultiplied 64-bit halfword-ordered RGBA format (16-16-16-16).
+        Format_RGBA64_Premultiplied,
+        /// The image is stored using a 16-bit grayscale format.
+        Format_Grayscale16,
+        /// The image is stored using a 24-bit BGR format.
+        Format_BGR888,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qimage.h");
+        type QImage = super::QImage;
+        #[namespace = "rust::cxxqtlib1"]
+        type QImageFormat;
+
+        /// Returns the depth of the image, which is the number of bits used to store a single pixel.
+        fn depth(self: &QImage) -> i32;
+
+        /// Returns the format of the image.
+        fn format(self: &QImage) -> QImageFormat;
+
+        /// Returns true if the image has a format that respects the alpha channel, otherwise returns false.
+        #[rust_name = "has_alpha_channel"]
+        fn hasAlphaChannel(self: &QImage) -> bool;
+
+        /// Returns the height of the image.
+        fn height(self: &QImage) -> i32;
+
+        /// Returns true if it is a null image, otherwise returns false.
+        ///
+        /// A null image has all parameters set to zero and no allocated data.
+        #[rust_name = "is_null"]
+        fn isNull(self: &QImage) -> bool;
+
+        /// Returns the width of the image.
+        fn width(self: &QImage) -> i32;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qimage_as_slice"]
+        fn qimageAsSlice(image: &QImage) -> &[u8];
+        #[doc(hidden)]
+        #[rust_name = "qimage_bytes_per_line"]
+        fn qimageBytesPerLine(image: &QImage) -> isize;
+        #[doc(hidden)]
+        #[rust_name = "qimage_convert_to_format"]
+        fn qimageConvertToFormat(image: &QImage, format: QImageFormat) -> QImage;
+        #[doc(hidden)]
+        #[rust_name = "qimage_init_from_buffer"]
+        unsafe fn qimageInitFromBuffer(
+            buffer: Box<QImageBuffer>,
+            data: *mut u8,
+            len: usize,
+            width: i32,
+            height: i32,
+            bytes_per_line: isize,
+            format: QImageFormat,
+        ) -> QImage;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qimage_drop"]
+        fn drop(image: &mut QImage);
+
+        #[doc(hidden)]
+        #[rust_name = "qimage_init_default"]
+        fn construct() -> QImage;
+        #[doc(hidden)]
+        #[rust_name = "qimage_init_from_qimage"]
+        fn construct(image: &QImage) -> QImage;
+
+        #[doc(hidden)]
+        #[rust_name = "qimage_eq"]
+        fn operatorEq(a: &QImage, b: &QImage) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        type QImageBuffer;
+    }
+}
+
+pub use ffi::QImageFormat;
+
+/// The pixel data from Rust which a [QImage] refers to without a copy
+///
+/// This is dropped when the last copy of the QImage is destroyed.
+#[doc(hidden)]
+pub struct QImageBuffer {
+    _data: Box<dyn Send>,
+}
+
+/// The QImage class provides a hardware-independent image representation
+/// that allows direct access to the pixel data.
+///
+/// Images can be shared with threads other than the GUI thread, unlike a [QPixmap](crate::QPixmap).
+#[repr(C)]
+pub struct QImage {
+    // A QImage inherits from QPaintDevice which has a v-table,
+    // see qimage.cpp for the static checks that this is the same size.
+    _space: MaybeUninit<[usize; 4]>,
+}
+
+impl QImage {
+    /// Returns the pixel data of the image, which has [QImage::bytes_per_line] for each row.
+    pub fn as_slice(&self) -> &[u8] {
+        ffi::qimage_as_slice(self)
+    }
+
+    /// Returns the number of bytes per image scanline.
+    pub fn bytes_per_line(&self) -> isize {
+        ffi::qimage_bytes_per_line(self)
+    }
+
+    /// Returns a copy of the image in the given format.
+    pub fn convert_to_format(&self, format: QImageFormat) -> Self {
+        ffi::qimage_convert_to_format(self, format)
+    }
+
+    /// Constructs an image which refers to the pixel data in the Vec without a copy,
+    /// the Vec is dropped when the last copy of the image is destroyed.
+    ///
+    /// Returns None if the data is too small for an image of the width, height, bytes per line and format.
+    pub fn from_vec<T: Copy + Send + 'static>(
+        mut data: Vec<T>,
+        width: i32,
+        height: i32,
+        bytes_per_line: isize,
+        format: QImageFormat,
+    ) -> Option<Self> {
+        // Moving the Vec into the buffer does not move its heap allocation
+        let ptr = data.as_mut_ptr().cast::<u8>();
+        let len = std::mem::size_of_val(data.as_slice());
+        let buffer = Box::new(QImageBuffer {
+            _data: Box::new(data),
+        });
+        // SAFETY: the data is kept alive by the buffer which the image owns,
+        // and the size of the data is checked against the image in C++
+        let image = unsafe {
+            ffi::qimage_init_from_buffer(buffer, ptr, len, width, height, bytes_per_line, format)
+        };
+        if image.is_null() {
+            None
+        } else {
+            Some(image)
+        }
+    }
+}
+
+impl Clone for QImage {
+    /// Constructs a shallow copy of the image.
+    fn clone(&self) -> Self {
+        ffi::qimage_init_from_qimage(self)
+    }
+}
+
+impl Default for QImage {
+    /// Constructs a null image.
+    fn default() -> Self {
+        ffi::qimage_init_default()
+    }
+}
+
+impl std::cmp::PartialEq for QImage {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qimage_eq(self, other)
+    }
+}
+
+impl std::cmp::Eq for QImage {}
+
+impl Drop for QImage {
+    /// Destroys the image.
+    fn drop(&mut self) {
+        ffi::qimage_drop(self);
+    }
+}
+
+#[cfg(feature = "image")]
+impl From<image::DynamicImage> for QImage {
+    /// Convert an `image::DynamicImage` to a QImage.
+    ///
+    /// The pixel data is moved into the QImage without a copy when Qt has a matching format,
+    /// otherwise the image is converted to 8-bit or 16-bit RGBA first.
+    fn from(value: image::DynamicImage) -> Self {
+        use image::DynamicImage;
+
+        fn from_buffer<T: Copy + Send + 'static>(
+            data: Vec<T>,
+            width: u32,
+            height: u32,
+            channels: usize,
+            format: QImageFormat,
+        ) -> QImage {
+            let bytes_per_line = width as usize * channels * std::mem::size_of::<T>();
+            match (
+                i32::try_from(width),
+                i32::try_from(height),
+                isize::try_from(bytes_per_line),
+            ) {
+                (Ok(width), Ok(height), Ok(bytes_per_line)) => {
+                    QImage::from_vec(data, width, height, bytes_per_line, format)
+                        .unwrap_or_default()
+                }
+                _ => QImage::default(),
+            }
+        }
+
+        let (width, height) = (value.width(), value.height());
+        match value {
+            DynamicImage::ImageLuma8(buffer) => from_buffer(
+                buffer.into_raw(),
+                width,
+                height,
+                1,
+                QImageFormat::Format_Grayscale8,
+            ),
+            DynamicImage::ImageRgb8(buffer) => from_buffer(
+                buffer.into_raw(),
+                width,
+                height,
+                3,
+                QImageFormat::Format_RGB888,
+            ),
+            DynamicImage::ImageRgba8(buffer) => from_buffer(
+                buffer.into_raw(),
+                width,
+                height,
+                4,
+                QImageFormat::Format_RGBA8888,
+            ),
+            DynamicImage::ImageLuma16(buffer) => from_buffer(
+                buffer.into_raw(),
+                width,
+                height,
+                1,
+                QImageFormat::Format_Grayscale16,
+            ),
+            DynamicImage::ImageRgba16(buffer) => from_buffer(
+                buffer.into_raw(),
+                width,
+                height,
+                4,
+                QImageFormat::Format_RGBA64,
+            ),
+            other @ (DynamicImage::ImageLumaA16(_) | DynamicImage::ImageRgb16(_)) => from_buffer(
+                other.into_rgba16().into_raw(),
+                width,
+                height,
+                4,
+                QImageFormat::Format_RGBA64,
+            ),
+            other => from_buffer(
+                other.into_rgba8().into_raw(),
+                width,
+                height,
+                4,
+                QImageFormat::Format_RGBA8888,
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "image")]
+impl From<&QImage> for image::DynamicImage {
+    /// Convert a QImage to an `image::DynamicImage`, this makes a deep copy of the pixel data.
+    ///
+    /// Formats which the image crate does not have are converted to 8-bit RGBA first,
+    /// a null QImage is converted to an empty image.
+    fn from(value: &QImage) -> Self {
+        use image::{DynamicImage, ImageBuffer};
+
+        // Copy the subpixels of each row, skipping the padding at the end of the row
+        fn subpixels<T>(image: &QImage, channels: usize, from_bytes: fn(&[u8]) -> T) -> Vec<T> {
+            let row_len = image.width() as usize * channels * std::mem::size_of::<T>();
+            image
+                .as_slice()
+                .chunks_exact(image.bytes_per_line() as usize)
+                .flat_map(|row| {
+                    row[..row_len]
+                        .chunks_exact(std::mem::size_of::<T>())
+                        .map(from_bytes)
+                })
+                .collect()
+        }
+
+        fn from_u8(bytes: &[u8]) -> u8 {
+            bytes[0]
+        }
+
+        fn from_u16(bytes: &[u8]) -> u16 {
+            u16::from_ne_bytes([bytes[0], bytes[1]])
+        }
+
+        fn to_dynamic_image(image: &QImage) -> Option<DynamicImage> {
+            if image.is_null() {
+                return None;
+            }
+
+            let (width, height) = (image.width() as u32, image.height() as u32);
+            match image.format() {
+                QImageFormat::Format_Grayscale8 => {
+                    ImageBuffer::from_raw(width, height, subpixels(image, 1, from_u8))
+                        .map(DynamicImage::ImageLuma8)
+                }
+                QImageFormat::Format_RGB888 => {
+                    ImageBuffer::from_raw(width, height, subpixels(image, 3, from_u8))
+                        .map(DynamicImage::ImageRgb8)
+                }
+                QImageFormat::Format_RGBA8888 => {
+                    ImageBuffer::from_raw(width, height, subpixels(image, 4, from_u8))
+                        .map(DynamicImage::ImageRgba8)
+                }
+                QImageFormat::Format_Grayscale16 => {
+                    ImageBuffer::from_raw(width, height, subpixels(image, 1, from_u16))
+                        .map(DynamicImage::ImageLuma16)
+                }
+                QImageFormat::Format_RGBA64 => {
+                    ImageBuffer::from_raw(width, height, subpixels(image, 4, from_u16))
+                        .map(DynamicImage::ImageRgba16)
+                }
+                _ => to_dynamic_image(&image.convert_to_format(QImageFormat::Format_RGBA8888)),
+            }
+        }
+
+        to_dynamic_image(value).unwrap_or_else(|| DynamicImage::new_rgba8(0, 0))
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QImage {
+    type Id = type_id!("QImage");
+    type Kind = cxx::kind::Trivial;
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "image")]
+    use super::*;
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn test_image() {
+        let rgba = image::RgbaImage::from_fn(3, 2, |x, y| image::Rgba([x as u8, y as u8, 7, 255]));
+        let dynamic = image::DynamicImage::ImageRgba8(rgba);
+        let qimage = QImage::from(dynamic.clone());
+        assert_eq!(qimage.format(), QImageFormat::Format_RGBA8888);
+        assert_eq!((qimage.width(), qimage.height()), (3, 2));
+
+        let roundtrip = image::DynamicImage::from(&qimage);
+        assert_eq!(dynamic, roundtrip);
+
+        // Rows of RGB888 images which are allocated by Qt are padded to 32-bit
+        let rgb = QImage::from(image::DynamicImage::ImageRgb8(dynamic.to_rgb8()));
+        let padded = rgb
+            .convert_to_format(QImageFormat::Format_RGB32)
+            .convert_to_format(QImageFormat::Format_RGB888);
+        assert_eq!(padded.bytes_per_line(), 12);
+        assert_eq!(
+            image::DynamicImage::from(&padded).to_rgb8(),
+            dynamic.to_rgb8()
+        );
+    }
+}
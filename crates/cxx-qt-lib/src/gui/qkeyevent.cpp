@@ -0,0 +1,34 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#ifdef CXX_QT_GUI_FEATURE
+#include "cxx-qt-lib/qkeyevent.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+const QKeyEvent*
+qeventAsKeyEvent(const QEvent& event)
+{
+  switch (event.type()) {
+    case QEvent::KeyPress:
+    case QEvent::KeyRelease:
+    case QEvent::ShortcutOverride:
+      return static_cast<const QKeyEvent*>(&event);
+    default:
+      return nullptr;
+  }
+}
+
+::std::int32_t
+qkeyeventModifiers(const QKeyEvent& event)
+{
+  return static_cast<::std::int32_t>(event.modifiers());
+}
+
+}
+}
+#endif
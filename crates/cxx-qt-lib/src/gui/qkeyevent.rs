@@ -0,0 +1,61 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::QEvent;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qevent.h");
+        type QEvent = crate::QEvent;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        include!("cxx-qt-lib/qkeyevent.h");
+        /// The QKeyEvent class describes a key event.
+        type QKeyEvent;
+
+        /// Returns the number of keys involved in this event.
+        fn count(self: &QKeyEvent) -> i32;
+
+        /// Returns true if this event comes from an auto-repeating key; returns false if it comes from an initial key press.
+        #[rust_name = "is_auto_repeat"]
+        fn isAutoRepeat(self: &QKeyEvent) -> bool;
+
+        /// Returns the code of the key that was pressed or released, as a `Qt::Key` value.
+        fn key(self: &QKeyEvent) -> i32;
+
+        /// Returns the Unicode text that this key generated.
+        fn text(self: &QKeyEvent) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qevent_as_key_event"]
+        fn qeventAsKeyEvent(event: &QEvent) -> *const QKeyEvent;
+        #[doc(hidden)]
+        #[rust_name = "qkeyevent_modifiers"]
+        fn qkeyeventModifiers(event: &QKeyEvent) -> i32;
+    }
+}
+
+pub use ffi::QKeyEvent;
+
+impl QKeyEvent {
+    /// Returns the keyboard modifier flags, as a combination of `Qt::KeyboardModifier` values,
+    /// that existed immediately after the event occurred.
+    pub fn modifiers(&self) -> i32 {
+        ffi::qkeyevent_modifiers(self)
+    }
+}
+
+impl QEvent {
+    /// Returns the event as a [QKeyEvent] if it is a key press, key release or shortcut override event
+    pub fn as_key_event(&self) -> Option<&QKeyEvent> {
+        // SAFETY: the pointer is either null or points to this event, so has the same lifetime
+        unsafe { ffi::qevent_as_key_event(self).as_ref() }
+    }
+}
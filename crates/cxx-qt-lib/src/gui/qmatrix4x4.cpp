@@ -0,0 +1,61 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_GUI_FEATURE
+#include "cxx-qt-lib/qmatrix4x4.h"
+
+#include "../assertion_utils.h"
+
+// QMatrix4x4 has a float[4][4] member in column-major order and an int of flags
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/math3d/qmatrix4x4.h?h=v5.15.6-lts-lgpl#n200
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/math3d/qmatrix4x4.h?h=v6.2.4#n181
+assert_alignment_and_size(QMatrix4x4,
+                          alignof(float),
+                          sizeof(float[4][4]) + sizeof(::std::int32_t));
+
+static_assert(::std::is_trivially_copyable<QMatrix4x4>::value,
+              "QMatrix4x4 should be trivially copyable");
+
+namespace rust {
+namespace cxxqtlib1 {
+
+float
+qmatrix4x4Determinant(const QMatrix4x4& matrix)
+{
+  // Qt 5 returns a float and Qt 6 returns a double
+  return static_cast<float>(matrix.determinant());
+}
+
+QMatrix4x4
+qmatrix4x4InitRowMajor(::rust::Slice<const float> values)
+{
+  Q_ASSERT(values.size() == 16);
+  return QMatrix4x4(values.data());
+}
+
+QMatrix4x4
+qmatrix4x4Inverted(const QMatrix4x4& matrix, bool& invertible)
+{
+  return matrix.inverted(&invertible);
+}
+
+void
+qmatrix4x4Set(QMatrix4x4& matrix,
+              ::std::int32_t row,
+              ::std::int32_t column,
+              float value)
+{
+  Q_ASSERT(row >= 0 && row < 4);
+  Q_ASSERT(column >= 0 && column < 4);
+  // Use the non-const operator so that the internal flags are updated
+  matrix(row, column) = value;
+}
+
+}
+}
+#endif
@@ -0,0 +1,369 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::{type_id, ExternType};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        include!("cxx-qt-lib/qmatrix4x4.h");
+        type QMatrix4x4 = super::QMatrix4x4;
+        include!("cxx-qt-lib/qquaternion.h");
+        type QQuaternion = crate::QQuaternion;
+        include!("cxx-qt-lib/qvector3d.h");
+        type QVector3D = crate::QVector3D;
+        include!("cxx-qt-lib/qvector4d.h");
+        type QVector4D = crate::QVector4D;
+
+        /// Returns the elements of column index as a 4D vector.
+        fn column(self: &QMatrix4x4, index: i32) -> QVector4D;
+
+        /// Fills all elements of this matrix with value.
+        fn fill(self: &mut QMatrix4x4, value: f32);
+
+        /// Multiplies this matrix by another that applies a perspective frustum projection for a window
+        /// with lower-left corner (left, bottom), upper-right corner (right, top), and the specified
+        /// nearPlane and farPlane clipping planes.
+        fn frustum(
+            self: &mut QMatrix4x4,
+            left: f32,
+            right: f32,
+            bottom: f32,
+            top: f32,
+            near_plane: f32,
+            far_plane: f32,
+        );
+
+        /// Returns true if this matrix is affine matrix; false otherwise.
+        ///
+        /// An affine matrix is a 4x4 matrix with row 3 equal to (0, 0, 0, 1), e.g. no projective coefficients.
+        #[rust_name = "is_affine"]
+        fn isAffine(self: &QMatrix4x4) -> bool;
+
+        /// Returns true if this matrix is the identity; false otherwise.
+        #[rust_name = "is_identity"]
+        fn isIdentity(self: &QMatrix4x4) -> bool;
+
+        /// Multiplies this matrix by a viewing matrix derived from an eye point.
+        ///
+        /// The center value indicates the center of the view that the eye is looking at.
+        /// The up value indicates which direction should be considered up with respect to the eye.
+        #[rust_name = "look_at"]
+        fn lookAt(self: &mut QMatrix4x4, eye: &QVector3D, center: &QVector3D, up: &QVector3D);
+
+        /// Returns the result of transforming point according to this matrix,
+        /// with the matrix applied pre-point.
+        fn map(self: &QMatrix4x4, point: &QVector3D) -> QVector3D;
+
+        /// Returns the result of transforming vector according to this matrix,
+        /// with the matrix applied pre-vector. The translation component of the matrix is ignored.
+        #[rust_name = "map_vector"]
+        fn mapVector(self: &QMatrix4x4, vector: &QVector3D) -> QVector3D;
+
+        /// Optimize the usage of this matrix from its current elements.
+        ///
+        /// Some operations such as translate(), scale(), and rotate() can be performed more efficiently
+        /// if the matrix being modified is already known to be the identity, a previous translate(),
+        /// a previous scale(), etc.
+        fn optimize(self: &mut QMatrix4x4);
+
+        /// Multiplies this matrix by another that applies an orthographic projection for a window
+        /// with lower-left corner (left, bottom), upper-right corner (right, top), and the specified
+        /// nearPlane and farPlane clipping planes.
+        fn ortho(
+            self: &mut QMatrix4x4,
+            left: f32,
+            right: f32,
+            bottom: f32,
+            top: f32,
+            near_plane: f32,
+            far_plane: f32,
+        );
+
+        /// Multiplies this matrix by another that applies a perspective projection.
+        ///
+        /// The vertical field of view will be verticalAngle degrees within a window with a given aspectRatio
+        /// that determines the horizontal field of view. The projection will have the specified nearPlane
+        /// and farPlane clipping planes which are the distances from the viewer to the corresponding planes.
+        fn perspective(
+            self: &mut QMatrix4x4,
+            vertical_angle: f32,
+            aspect_ratio: f32,
+            near_plane: f32,
+            far_plane: f32,
+        );
+
+        /// Multiples this matrix by another that rotates coordinates through angle degrees about vector.
+        fn rotate(self: &mut QMatrix4x4, angle: f32, vector: &QVector3D);
+
+        /// Multiples this matrix by another that rotates coordinates according to a specified quaternion.
+        /// The quaternion is assumed to have been normalized.
+        #[rust_name = "rotate_quaternion"]
+        fn rotate(self: &mut QMatrix4x4, quaternion: &QQuaternion);
+
+        /// Returns the elements of row index as a 4D vector.
+        fn row(self: &QMatrix4x4, index: i32) -> QVector4D;
+
+        /// Multiplies this matrix by another that scales coordinates by the components of vector.
+        fn scale(self: &mut QMatrix4x4, vector: &QVector3D);
+
+        /// Multiplies this matrix by another that scales coordinates by the given factor.
+        #[rust_name = "scale_factor"]
+        fn scale(self: &mut QMatrix4x4, factor: f32);
+
+        /// Sets the elements of column index to the components of value.
+        #[rust_name = "set_column"]
+        fn setColumn(self: &mut QMatrix4x4, index: i32, value: &QVector4D);
+
+        /// Sets the elements of row index to the components of value.
+        #[rust_name = "set_row"]
+        fn setRow(self: &mut QMatrix4x4, index: i32, value: &QVector4D);
+
+        /// Sets this matrix to the identity.
+        #[rust_name = "set_to_identity"]
+        fn setToIdentity(self: &mut QMatrix4x4);
+
+        /// Multiplies this matrix by another that translates coordinates by the components of vector.
+        fn translate(self: &mut QMatrix4x4, vector: &QVector3D);
+
+        /// Returns this matrix, transposed about its diagonal.
+        fn transposed(self: &QMatrix4x4) -> QMatrix4x4;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qmatrix4x4_init_row_major"]
+        fn qmatrix4x4InitRowMajor(values: &[f32]) -> QMatrix4x4;
+        #[doc(hidden)]
+        #[rust_name = "qmatrix4x4_init_default"]
+        fn construct() -> QMatrix4x4;
+
+        #[doc(hidden)]
+        #[rust_name = "qmatrix4x4_determinant"]
+        fn qmatrix4x4Determinant(matrix: &QMatrix4x4) -> f32;
+        #[doc(hidden)]
+        #[rust_name = "qmatrix4x4_inverted"]
+        fn qmatrix4x4Inverted(matrix: &QMatrix4x4, invertible: &mut bool) -> QMatrix4x4;
+        #[doc(hidden)]
+        #[rust_name = "qmatrix4x4_set"]
+        fn qmatrix4x4Set(matrix: &mut QMatrix4x4, row: i32, column: i32, value: f32);
+
+        #[doc(hidden)]
+        #[rust_name = "qmatrix4x4_to_qstring"]
+        fn toQString(value: &QMatrix4x4) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qmatrix4x4_eq"]
+        fn operatorEq(a: &QMatrix4x4, b: &QMatrix4x4) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qmatrix4x4_plus"]
+        fn operatorPlus(a: &QMatrix4x4, b: &QMatrix4x4) -> QMatrix4x4;
+        #[doc(hidden)]
+        #[rust_name = "qmatrix4x4_minus"]
+        fn operatorMinus(a: &QMatrix4x4, b: &QMatrix4x4) -> QMatrix4x4;
+        #[doc(hidden)]
+        #[rust_name = "qmatrix4x4_mul"]
+        fn operatorMul(a: f32, b: &QMatrix4x4) -> QMatrix4x4;
+        #[doc(hidden)]
+        #[rust_name = "qmatrix4x4_mul_qmatrix4x4"]
+        fn operatorMul(a: QMatrix4x4, b: &QMatrix4x4) -> QMatrix4x4;
+        #[doc(hidden)]
+        #[rust_name = "qmatrix4x4_mul_qvector4d"]
+        fn operatorMul(a: QMatrix4x4, b: &QVector4D) -> QVector4D;
+        #[doc(hidden)]
+        #[rust_name = "qmatrix4x4_div"]
+        fn operatorDiv(a: f32, b: &QMatrix4x4) -> QMatrix4x4;
+    }
+}
+
+/// The QMatrix4x4 class represents a 4x4 transformation matrix in 3D space.
+#[derive(Debug, Clone)]
+#[repr(C)]
+pub struct QMatrix4x4 {
+    /// The elements of the matrix in column-major order
+    m: [[f32; 4]; 4],
+    _flag_bits: i32,
+}
+
+impl QMatrix4x4 {
+    /// Constructs a matrix from the given 16 floating-point values.
+    ///
+    /// The contents of the array values is assumed to be in row-major order.
+    pub fn from_row_major(values: &[f32; 16]) -> Self {
+        ffi::qmatrix4x4_init_row_major(values)
+    }
+
+    /// Constructs a matrix from the given 16 floating-point values.
+    ///
+    /// The contents of the array values is assumed to be in column-major order.
+    pub fn from_column_major(values: &[f32; 16]) -> Self {
+        let mut row_major = [0.0; 16];
+        for row in 0..4 {
+            for column in 0..4 {
+                row_major[row * 4 + column] = values[column * 4 + row];
+            }
+        }
+        Self::from_row_major(&row_major)
+    }
+
+    /// Returns the determinant of this matrix.
+    pub fn determinant(&self) -> f32 {
+        ffi::qmatrix4x4_determinant(self)
+    }
+
+    /// Returns the element at position (row, column) in this matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if row or column is not within 0..4.
+    pub fn get(&self, row: usize, column: usize) -> f32 {
+        self.m[column][row]
+    }
+
+    /// Returns the inverse of this matrix if it can be inverted, otherwise returns None.
+    pub fn inverted(&self) -> Option<Self> {
+        let mut invertible = false;
+        let inverted = ffi::qmatrix4x4_inverted(self, &mut invertible);
+        if invertible {
+            Some(inverted)
+        } else {
+            None
+        }
+    }
+
+    /// Sets the element at position (row, column) in this matrix to value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if row or column is not within 0..4.
+    pub fn set(&mut self, row: usize, column: usize, value: f32) {
+        assert!(row < 4 && column < 4, "row and column must be within 0..4");
+        ffi::qmatrix4x4_set(self, row as i32, column as i32, value);
+    }
+
+    /// Returns the 16 elements of this matrix in column-major order.
+    pub fn to_column_major(&self) -> [f32; 16] {
+        let mut values = [0.0; 16];
+        for (column, elements) in self.m.iter().enumerate() {
+            values[column * 4..column * 4 + 4].copy_from_slice(elements);
+        }
+        values
+    }
+
+    /// Returns the 16 elements of this matrix in row-major order.
+    pub fn to_row_major(&self) -> [f32; 16] {
+        let mut values = [0.0; 16];
+        for (column, elements) in self.m.iter().enumerate() {
+            for (row, element) in elements.iter().enumerate() {
+                values[row * 4 + column] = *element;
+            }
+        }
+        values
+    }
+}
+
+impl Default for QMatrix4x4 {
+    /// Constructs an identity matrix.
+    fn default() -> Self {
+        ffi::qmatrix4x4_init_default()
+    }
+}
+
+impl std::fmt::Display for QMatrix4x4 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", ffi::qmatrix4x4_to_qstring(self))
+    }
+}
+
+impl PartialEq for QMatrix4x4 {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qmatrix4x4_eq(self, other)
+    }
+}
+
+impl std::ops::Add for QMatrix4x4 {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        ffi::qmatrix4x4_plus(&self, &other)
+    }
+}
+
+impl std::ops::Sub for QMatrix4x4 {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        ffi::qmatrix4x4_minus(&self, &other)
+    }
+}
+
+impl std::ops::Mul for QMatrix4x4 {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        ffi::qmatrix4x4_mul_qmatrix4x4(self, &other)
+    }
+}
+
+impl std::ops::Mul<crate::QVector4D> for QMatrix4x4 {
+    type Output = crate::QVector4D;
+    fn mul(self, vector: crate::QVector4D) -> crate::QVector4D {
+        ffi::qmatrix4x4_mul_qvector4d(self, &vector)
+    }
+}
+
+impl std::ops::Mul<f32> for QMatrix4x4 {
+    type Output = Self;
+    fn mul(self, rhs: f32) -> Self {
+        ffi::qmatrix4x4_mul(rhs, &self)
+    }
+}
+
+impl std::ops::Div<f32> for QMatrix4x4 {
+    type Output = Self;
+    fn div(self, rhs: f32) -> Self {
+        ffi::qmatrix4x4_div(rhs, &self)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::Mat4> for QMatrix4x4 {
+    fn from(value: glam::Mat4) -> Self {
+        QMatrix4x4::from_column_major(&value.to_cols_array())
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<QMatrix4x4> for glam::Mat4 {
+    fn from(value: QMatrix4x4) -> Self {
+        glam::Mat4::from_cols_array(&value.to_column_major())
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Matrix4<f32>> for QMatrix4x4 {
+    fn from(value: nalgebra::Matrix4<f32>) -> Self {
+        let mut values = [0.0; 16];
+        values.copy_from_slice(value.as_slice());
+        QMatrix4x4::from_column_major(&values)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<QMatrix4x4> for nalgebra::Matrix4<f32> {
+    fn from(value: QMatrix4x4) -> Self {
+        nalgebra::Matrix4::from_column_slice(&value.to_column_major())
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side ensure that QMatrix4x4 is trivial.
+unsafe impl ExternType for QMatrix4x4 {
+    type Id = type_id!("QMatrix4x4");
+    type Kind = cxx::kind::Trivial;
+}
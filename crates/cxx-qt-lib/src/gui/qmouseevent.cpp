@@ -0,0 +1,67 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#ifdef CXX_QT_GUI_FEATURE
+#include "cxx-qt-lib/qmouseevent.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+const QMouseEvent*
+qeventAsMouseEvent(const QEvent& event)
+{
+  switch (event.type()) {
+    case QEvent::MouseButtonPress:
+    case QEvent::MouseButtonRelease:
+    case QEvent::MouseButtonDblClick:
+    case QEvent::MouseMove:
+      return static_cast<const QMouseEvent*>(&event);
+    default:
+      return nullptr;
+  }
+}
+
+::std::int32_t
+qmouseeventButton(const QMouseEvent& event)
+{
+  return static_cast<::std::int32_t>(event.button());
+}
+
+::std::int32_t
+qmouseeventButtons(const QMouseEvent& event)
+{
+  return static_cast<::std::int32_t>(event.buttons());
+}
+
+QPointF
+qmouseeventGlobalPosition(const QMouseEvent& event)
+{
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  return event.globalPosition();
+#else
+  return event.screenPos();
+#endif
+}
+
+::std::int32_t
+qmouseeventModifiers(const QMouseEvent& event)
+{
+  return static_cast<::std::int32_t>(event.modifiers());
+}
+
+QPointF
+qmouseeventPosition(const QMouseEvent& event)
+{
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  return event.position();
+#else
+  return event.localPos();
+#endif
+}
+
+}
+}
+#endif
@@ -0,0 +1,80 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QEvent, QPointF};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qevent.h");
+        type QEvent = crate::QEvent;
+        include!("cxx-qt-lib/qpointf.h");
+        type QPointF = crate::QPointF;
+
+        include!("cxx-qt-lib/qmouseevent.h");
+        /// The QMouseEvent class contains parameters that describe a mouse event.
+        type QMouseEvent;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qevent_as_mouse_event"]
+        fn qeventAsMouseEvent(event: &QEvent) -> *const QMouseEvent;
+        #[doc(hidden)]
+        #[rust_name = "qmouseevent_button"]
+        fn qmouseeventButton(event: &QMouseEvent) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qmouseevent_buttons"]
+        fn qmouseeventButtons(event: &QMouseEvent) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qmouseevent_global_position"]
+        fn qmouseeventGlobalPosition(event: &QMouseEvent) -> QPointF;
+        #[doc(hidden)]
+        #[rust_name = "qmouseevent_modifiers"]
+        fn qmouseeventModifiers(event: &QMouseEvent) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qmouseevent_position"]
+        fn qmouseeventPosition(event: &QMouseEvent) -> QPointF;
+    }
+}
+
+pub use ffi::QMouseEvent;
+
+impl QMouseEvent {
+    /// Returns the button, as a `Qt::MouseButton` value, that caused the event.
+    pub fn button(&self) -> i32 {
+        ffi::qmouseevent_button(self)
+    }
+
+    /// Returns the button state, as a combination of `Qt::MouseButton` values, when the event was generated.
+    pub fn buttons(&self) -> i32 {
+        ffi::qmouseevent_buttons(self)
+    }
+
+    /// Returns the position of the point in this event on the screen or virtual desktop.
+    pub fn global_position(&self) -> QPointF {
+        ffi::qmouseevent_global_position(self)
+    }
+
+    /// Returns the keyboard modifier flags, as a combination of `Qt::KeyboardModifier` values,
+    /// that existed immediately before the event occurred.
+    pub fn modifiers(&self) -> i32 {
+        ffi::qmouseevent_modifiers(self)
+    }
+
+    /// Returns the position of the point in this event, relative to the widget or item that received the event.
+    pub fn position(&self) -> QPointF {
+        ffi::qmouseevent_position(self)
+    }
+}
+
+impl QEvent {
+    /// Returns the event as a [QMouseEvent] if it is a mouse button or mouse move event
+    pub fn as_mouse_event(&self) -> Option<&QMouseEvent> {
+        // SAFETY: the pointer is either null or points to this event, so has the same lifetime
+        unsafe { ffi::qevent_as_mouse_event(self).as_ref() }
+    }
+}
@@ -0,0 +1,41 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_GUI_FEATURE
+#include "cxx-qt-lib/qpixmap.h"
+
+#include "../assertion_utils.h"
+
+// QPixmap inherits from QPaintDevice which has a v-table, a ushort of painters
+// (which is padded to the size of a pointer) and a pointer to reserved data,
+// then QPixmap adds a shared data pointer. This is a total of four pointers.
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/painting/qpaintdevice.h?h=v5.15.6-lts-lgpl#n89
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/image/qpixmap.h?h=v6.2.4#n163
+assert_alignment_and_size(QPixmap,
+                          alignof(::std::size_t),
+                          sizeof(::std::size_t) * 4);
+
+static_assert(!::std::is_trivially_copy_assignable<QPixmap>::value);
+static_assert(!::std::is_trivially_copy_constructible<QPixmap>::value);
+
+static_assert(!::std::is_trivially_destructible<QPixmap>::value);
+
+static_assert(QTypeInfo<QPixmap>::isRelocatable);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QPixmap
+qpixmapFromImage(const QImage& image)
+{
+  return QPixmap::fromImage(image);
+}
+
+}
+}
+
+#endif
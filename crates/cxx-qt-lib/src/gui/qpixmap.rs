@@ -0,0 +1,117 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qimage.h");
+        type QImage = crate::QImage;
+        include!("cxx-qt-lib/qpixmap.h");
+        type QPixmap = super::QPixmap;
+
+        /// Returns the height of the pixmap.
+        fn height(self: &QPixmap) -> i32;
+
+        /// Returns true if this is a null pixmap, otherwise returns false.
+        #[rust_name = "is_null"]
+        fn isNull(self: &QPixmap) -> bool;
+
+        /// Converts the pixmap to a QImage.
+        #[rust_name = "to_image"]
+        fn toImage(self: &QPixmap) -> QImage;
+
+        /// Returns the width of the pixmap.
+        fn width(self: &QPixmap) -> i32;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qpixmap_from_image"]
+        fn qpixmapFromImage(image: &QImage) -> QPixmap;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qpixmap_drop"]
+        fn drop(pixmap: &mut QPixmap);
+
+        #[doc(hidden)]
+        #[rust_name = "qpixmap_init_default"]
+        fn construct() -> QPixmap;
+        #[doc(hidden)]
+        #[rust_name = "qpixmap_init_from_qpixmap"]
+        fn construct(pixmap: &QPixmap) -> QPixmap;
+    }
+}
+
+/// The QPixmap class is an off-screen image representation that can be used as a paint device.
+///
+/// A pixmap is optimised for showing on screen, so it can only be used once a
+/// [QGuiApplication](crate::QGuiApplication) has been constructed and only on the GUI thread.
+/// Use a [QImage](crate::QImage) for processing pixel data.
+#[repr(C)]
+pub struct QPixmap {
+    // A QPixmap inherits from QPaintDevice which has a v-table,
+    // see qpixmap.cpp for the static checks that this is the same size.
+    _space: MaybeUninit<[usize; 4]>,
+}
+
+impl Clone for QPixmap {
+    /// Constructs a shallow copy of the pixmap.
+    fn clone(&self) -> Self {
+        ffi::qpixmap_init_from_qpixmap(self)
+    }
+}
+
+impl Default for QPixmap {
+    /// Constructs a null pixmap.
+    fn default() -> Self {
+        ffi::qpixmap_init_default()
+    }
+}
+
+impl Drop for QPixmap {
+    /// Destroys the pixmap.
+    fn drop(&mut self) {
+        ffi::qpixmap_drop(self);
+    }
+}
+
+impl From<&ffi::QImage> for QPixmap {
+    /// Converts the image to a pixmap.
+    fn from(image: &ffi::QImage) -> Self {
+        ffi::qpixmap_from_image(image)
+    }
+}
+
+#[cfg(feature = "image")]
+impl From<image::DynamicImage> for QPixmap {
+    /// Convert an `image::DynamicImage` to a QPixmap, via a [QImage](crate::QImage) which refers to the pixel data
+    fn from(value: image::DynamicImage) -> Self {
+        Self::from(&ffi::QImage::from(value))
+    }
+}
+
+#[cfg(feature = "image")]
+impl From<&QPixmap> for image::DynamicImage {
+    /// Convert a QPixmap to an `image::DynamicImage`, via a [QImage](crate::QImage)
+    fn from(value: &QPixmap) -> Self {
+        Self::from(&value.to_image())
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QPixmap {
+    type Id = type_id!("QPixmap");
+    type Kind = cxx::kind::Trivial;
+}
@@ -0,0 +1,63 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_GUI_FEATURE
+#include "cxx-qt-lib/qquaternion.h"
+
+#include "../assertion_utils.h"
+
+// QQuaternion has four float members - wp, xp, yp and zp
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/math3d/qquaternion.h?h=v5.15.6-lts-lgpl#n165
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/math3d/qquaternion.h?h=v6.2.4#n158
+assert_alignment_and_size(QQuaternion, alignof(float), sizeof(float[4]));
+
+static_assert(::std::is_trivially_copyable<QQuaternion>::value,
+              "QQuaternion should be trivially copyable");
+
+namespace rust {
+namespace cxxqtlib1 {
+
+float
+qquaternionDotProduct(const QQuaternion& q1, const QQuaternion& q2)
+{
+  return QQuaternion::dotProduct(q1, q2);
+}
+
+QQuaternion
+qquaternionFromAxisAndAngle(const QVector3D& axis, float angle)
+{
+  return QQuaternion::fromAxisAndAngle(axis, angle);
+}
+
+QQuaternion
+qquaternionFromEulerAngles(const QVector3D& angles)
+{
+  return QQuaternion::fromEulerAngles(angles);
+}
+
+QQuaternion
+qquaternionNlerp(const QQuaternion& q1, const QQuaternion& q2, float t)
+{
+  return QQuaternion::nlerp(q1, q2, t);
+}
+
+QQuaternion
+qquaternionRotationTo(const QVector3D& from, const QVector3D& to)
+{
+  return QQuaternion::rotationTo(from, to);
+}
+
+QQuaternion
+qquaternionSlerp(const QQuaternion& q1, const QQuaternion& q2, float t)
+{
+  return QQuaternion::slerp(q1, q2, t);
+}
+
+}
+}
+#endif
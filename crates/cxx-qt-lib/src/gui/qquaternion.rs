@@ -0,0 +1,334 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::{type_id, ExternType};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        include!("cxx-qt-lib/qquaternion.h");
+        type QQuaternion = super::QQuaternion;
+        include!("cxx-qt-lib/qvector3d.h");
+        type QVector3D = crate::QVector3D;
+        include!("cxx-qt-lib/qvector4d.h");
+        type QVector4D = crate::QVector4D;
+
+        /// Returns the conjugate of this quaternion, which is (-x, -y, -z, scalar).
+        fn conjugated(self: &QQuaternion) -> QQuaternion;
+
+        /// Returns the inverse of this quaternion. If this quaternion is null, then a null quaternion is returned.
+        fn inverted(self: &QQuaternion) -> QQuaternion;
+
+        /// Returns true if the x, y, and z components of this quaternion are set to 0.0,
+        /// and the scalar component is set to 1.0; otherwise returns false.
+        #[rust_name = "is_identity"]
+        fn isIdentity(self: &QQuaternion) -> bool;
+
+        /// Returns true if the x, y, z, and scalar components of this quaternion are
+        /// set to 0.0; otherwise returns false.
+        #[rust_name = "is_null"]
+        fn isNull(self: &QQuaternion) -> bool;
+
+        /// Returns the length of the quaternion. This is also called the "norm".
+        fn length(self: &QQuaternion) -> f32;
+
+        /// Returns the squared length of the quaternion.
+        #[rust_name = "length_squared"]
+        fn lengthSquared(self: &QQuaternion) -> f32;
+
+        /// Normalizes the current quaternion in place. Nothing happens if this is a null quaternion
+        /// or the length of the quaternion is very close to 1.
+        fn normalize(self: &mut QQuaternion);
+
+        /// Returns the normalized unit form of this quaternion.
+        ///
+        /// If this quaternion is null, then a null quaternion is returned.
+        /// If the length of the quaternion is very close to 1, then the quaternion will be returned as-is.
+        /// Otherwise the normalized form of the quaternion of length 1 will be returned.
+        fn normalized(self: &QQuaternion) -> QQuaternion;
+
+        /// Rotates vector with this quaternion to produce a new vector in 3D space.
+        #[rust_name = "rotated_vector"]
+        fn rotatedVector(self: &QQuaternion, vector: &QVector3D) -> QVector3D;
+
+        /// Returns the scalar component of this quaternion.
+        fn scalar(self: &QQuaternion) -> f32;
+
+        /// Sets the scalar component of this quaternion to scalar.
+        #[rust_name = "set_scalar"]
+        fn setScalar(self: &mut QQuaternion, scalar: f32);
+        /// Sets the vector component of this quaternion to vector.
+        #[rust_name = "set_vector"]
+        fn setVector(self: &mut QQuaternion, vector: &QVector3D);
+        /// Sets the x coordinate of this quaternion's vector to the given x coordinate.
+        #[rust_name = "set_x"]
+        fn setX(self: &mut QQuaternion, x: f32);
+        /// Sets the y coordinate of this quaternion's vector to the given y coordinate.
+        #[rust_name = "set_y"]
+        fn setY(self: &mut QQuaternion, y: f32);
+        /// Sets the z coordinate of this quaternion's vector to the given z coordinate.
+        #[rust_name = "set_z"]
+        fn setZ(self: &mut QQuaternion, z: f32);
+
+        /// Calculates roll, pitch, and yaw Euler angles (in degrees) that corresponds to this quaternion.
+        ///
+        /// The returned vector contains the pitch as x, the yaw as y and the roll as z.
+        #[rust_name = "to_euler_angles"]
+        fn toEulerAngles(self: &QQuaternion) -> QVector3D;
+
+        // From trait is more idiomatic to Rust and implemented in QVector4D
+        #[doc(hidden)]
+        #[rust_name = "to_vector_4d"]
+        fn toVector4D(self: &QQuaternion) -> QVector4D;
+
+        /// Returns the vector component of this quaternion.
+        fn vector(self: &QQuaternion) -> QVector3D;
+
+        /// Returns the x coordinate of this quaternion's vector.
+        fn x(self: &QQuaternion) -> f32;
+        /// Returns the y coordinate of this quaternion's vector.
+        fn y(self: &QQuaternion) -> f32;
+        /// Returns the z coordinate of this quaternion's vector.
+        fn z(self: &QQuaternion) -> f32;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_init_qvector4d"]
+        fn construct(vector: &QVector4D) -> QQuaternion;
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_init_scalar_qvector3d"]
+        fn construct(scalar: f32, vector: &QVector3D) -> QQuaternion;
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_init"]
+        fn construct(scalar: f32, xpos: f32, ypos: f32, zpos: f32) -> QQuaternion;
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_init_default"]
+        fn construct() -> QQuaternion;
+
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_dot_product"]
+        fn qquaternionDotProduct(q1: &QQuaternion, q2: &QQuaternion) -> f32;
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_from_axis_and_angle"]
+        fn qquaternionFromAxisAndAngle(axis: &QVector3D, angle: f32) -> QQuaternion;
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_from_euler_angles"]
+        fn qquaternionFromEulerAngles(angles: &QVector3D) -> QQuaternion;
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_nlerp"]
+        fn qquaternionNlerp(q1: &QQuaternion, q2: &QQuaternion, t: f32) -> QQuaternion;
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_rotation_to"]
+        fn qquaternionRotationTo(from: &QVector3D, to: &QVector3D) -> QQuaternion;
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_slerp"]
+        fn qquaternionSlerp(q1: &QQuaternion, q2: &QQuaternion, t: f32) -> QQuaternion;
+
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_to_qstring"]
+        fn toQString(value: &QQuaternion) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_plus"]
+        fn operatorPlus(a: &QQuaternion, b: &QQuaternion) -> QQuaternion;
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_minus"]
+        fn operatorMinus(a: &QQuaternion, b: &QQuaternion) -> QQuaternion;
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_mul"]
+        fn operatorMul(a: f32, b: &QQuaternion) -> QQuaternion;
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_mul_qquaternion"]
+        fn operatorMul(a: QQuaternion, b: &QQuaternion) -> QQuaternion;
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_mul_qvector3d"]
+        fn operatorMul(a: QQuaternion, b: &QVector3D) -> QVector3D;
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_div"]
+        fn operatorDiv(a: f32, b: &QQuaternion) -> QQuaternion;
+    }
+}
+
+/// The QQuaternion class represents a quaternion consisting of a vector and scalar.
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct QQuaternion {
+    wp: f32,
+    xp: f32,
+    yp: f32,
+    zp: f32,
+}
+
+impl QQuaternion {
+    /// Constructs a quaternion with the vector (xpos, ypos, zpos) and scalar.
+    pub fn new(scalar: f32, xpos: f32, ypos: f32, zpos: f32) -> Self {
+        ffi::qquaternion_init(scalar, xpos, ypos, zpos)
+    }
+
+    /// Constructs a quaternion vector from the specified vector and scalar.
+    pub fn from_scalar_and_vector(scalar: f32, vector: &crate::QVector3D) -> Self {
+        ffi::qquaternion_init_scalar_qvector3d(scalar, vector)
+    }
+
+    /// Returns the dot product of two quaternions.
+    pub fn dot_product(q1: &QQuaternion, q2: &QQuaternion) -> f32 {
+        ffi::qquaternion_dot_product(q1, q2)
+    }
+
+    /// Creates a normalized quaternion that corresponds to rotating through angle degrees about the specified 3D axis.
+    pub fn from_axis_and_angle(axis: &crate::QVector3D, angle: f32) -> Self {
+        ffi::qquaternion_from_axis_and_angle(axis, angle)
+    }
+
+    /// Creates a quaternion that corresponds to a rotation of the Euler angles (in degrees),
+    /// where the pitch is the x, the yaw is the y and the roll is the z coordinate of the vector.
+    pub fn from_euler_angles(angles: &crate::QVector3D) -> Self {
+        ffi::qquaternion_from_euler_angles(angles)
+    }
+
+    /// Interpolates along the shortest linear path between the rotational positions q1 and q2.
+    /// The value t should be between 0 and 1, indicating the distance to travel between q1 and q2.
+    /// The result will be normalized().
+    ///
+    /// This function is typically faster than [QQuaternion::slerp] and will give approximate results to spherical interpolation
+    /// that are good enough for some applications.
+    pub fn nlerp(q1: &QQuaternion, q2: &QQuaternion, t: f32) -> Self {
+        ffi::qquaternion_nlerp(q1, q2, t)
+    }
+
+    /// Returns the shortest arc quaternion to rotate from the direction described by the vector from
+    /// to the direction described by the vector to.
+    pub fn rotation_to(from: &crate::QVector3D, to: &crate::QVector3D) -> Self {
+        ffi::qquaternion_rotation_to(from, to)
+    }
+
+    /// Interpolates along the shortest spherical path between the rotational positions q1 and q2.
+    /// The value t should be between 0 and 1, indicating the spherical distance to travel between q1 and q2.
+    ///
+    /// If t is less than or equal to 0, then q1 will be returned. If t is greater than or equal to 1, then q2 will be returned.
+    pub fn slerp(q1: &QQuaternion, q2: &QQuaternion, t: f32) -> Self {
+        ffi::qquaternion_slerp(q1, q2, t)
+    }
+}
+
+impl Default for QQuaternion {
+    /// Constructs an identity quaternion (1, 0, 0, 0), i.e. with the vector (0, 0, 0) and scalar 1.
+    fn default() -> Self {
+        ffi::qquaternion_init_default()
+    }
+}
+
+impl std::fmt::Display for QQuaternion {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", ffi::qquaternion_to_qstring(self))
+    }
+}
+
+impl std::ops::Add for QQuaternion {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        ffi::qquaternion_plus(&self, &other)
+    }
+}
+
+impl std::ops::Sub for QQuaternion {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        ffi::qquaternion_minus(&self, &other)
+    }
+}
+
+impl std::ops::Mul for QQuaternion {
+    type Output = Self;
+    /// Multiplies the quaternions using the Hamilton product, which combines both rotations.
+    fn mul(self, other: Self) -> Self {
+        ffi::qquaternion_mul_qquaternion(self, &other)
+    }
+}
+
+impl std::ops::Mul<crate::QVector3D> for QQuaternion {
+    type Output = crate::QVector3D;
+    /// Rotates the vector with this quaternion to produce a new vector in 3D space.
+    fn mul(self, vector: crate::QVector3D) -> crate::QVector3D {
+        ffi::qquaternion_mul_qvector3d(self, &vector)
+    }
+}
+
+impl std::ops::Mul<f32> for QQuaternion {
+    type Output = Self;
+    fn mul(self, rhs: f32) -> Self {
+        ffi::qquaternion_mul(rhs, &self)
+    }
+}
+
+impl std::ops::Div<f32> for QQuaternion {
+    type Output = Self;
+    fn div(self, rhs: f32) -> Self {
+        ffi::qquaternion_div(rhs, &self)
+    }
+}
+
+impl From<crate::QVector4D> for QQuaternion {
+    /// Constructs a quaternion from the components of vector.
+    fn from(value: crate::QVector4D) -> Self {
+        ffi::qquaternion_init_qvector4d(&value)
+    }
+}
+
+impl From<QQuaternion> for crate::QVector4D {
+    /// Returns this quaternion as a 4D vector.
+    fn from(value: QQuaternion) -> Self {
+        value.to_vector_4d()
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::Quat> for QQuaternion {
+    fn from(value: glam::Quat) -> Self {
+        QQuaternion::new(value.w, value.x, value.y, value.z)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<QQuaternion> for glam::Quat {
+    fn from(value: QQuaternion) -> Self {
+        glam::Quat::from_xyzw(value.x(), value.y(), value.z(), value.scalar())
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Quaternion<f32>> for QQuaternion {
+    fn from(value: nalgebra::Quaternion<f32>) -> Self {
+        QQuaternion::new(value.w, value.i, value.j, value.k)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<QQuaternion> for nalgebra::Quaternion<f32> {
+    fn from(value: QQuaternion) -> Self {
+        nalgebra::Quaternion::new(value.scalar(), value.x(), value.y(), value.z())
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::UnitQuaternion<f32>> for QQuaternion {
+    fn from(value: nalgebra::UnitQuaternion<f32>) -> Self {
+        QQuaternion::from(value.into_inner())
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side ensure that QQuaternion is trivial.
+unsafe impl ExternType for QQuaternion {
+    type Id = type_id!("QQuaternion");
+    type Kind = cxx::kind::Trivial;
+}
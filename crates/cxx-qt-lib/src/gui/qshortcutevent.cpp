@@ -0,0 +1,31 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#ifdef CXX_QT_GUI_FEATURE
+#include "cxx-qt-lib/qshortcutevent.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+const QShortcutEvent*
+qeventAsShortcutEvent(const QEvent& event)
+{
+  if (event.type() == QEvent::Shortcut) {
+    return static_cast<const QShortcutEvent*>(&event);
+  }
+
+  return nullptr;
+}
+
+QString
+qshortcuteventKey(const QShortcutEvent& event)
+{
+  return event.key().toString();
+}
+
+}
+}
+#endif
@@ -0,0 +1,51 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QEvent, QString};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qevent.h");
+        type QEvent = crate::QEvent;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        include!("cxx-qt-lib/qshortcutevent.h");
+        /// The QShortcutEvent class provides an event which is generated when the user presses a key combination.
+        type QShortcutEvent;
+
+        /// Returns true if the key sequence that triggered the event is ambiguous.
+        #[rust_name = "is_ambiguous"]
+        fn isAmbiguous(self: &QShortcutEvent) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qevent_as_shortcut_event"]
+        fn qeventAsShortcutEvent(event: &QEvent) -> *const QShortcutEvent;
+        #[doc(hidden)]
+        #[rust_name = "qshortcutevent_key"]
+        fn qshortcuteventKey(event: &QShortcutEvent) -> QString;
+    }
+}
+
+pub use ffi::QShortcutEvent;
+
+impl QShortcutEvent {
+    /// Returns the key sequence that triggered the event, in the portable text format of `QKeySequence`.
+    pub fn key(&self) -> QString {
+        ffi::qshortcutevent_key(self)
+    }
+}
+
+impl QEvent {
+    /// Returns the event as a [QShortcutEvent] if it is a [QEventType::Shortcut](crate::QEventType::Shortcut) event
+    pub fn as_shortcut_event(&self) -> Option<&QShortcutEvent> {
+        // SAFETY: the pointer is either null or points to this event, so has the same lifetime
+        unsafe { ffi::qevent_as_shortcut_event(self).as_ref() }
+    }
+}
@@ -237,6 +237,34 @@ impl From<QVector2D> for crate::QPoint {
     }
 }
 
+#[cfg(feature = "glam")]
+impl From<glam::Vec2> for QVector2D {
+    fn from(value: glam::Vec2) -> Self {
+        QVector2D::new(value.x, value.y)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<QVector2D> for glam::Vec2 {
+    fn from(value: QVector2D) -> Self {
+        glam::Vec2::new(value.x(), value.y())
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Vector2<f32>> for QVector2D {
+    fn from(value: nalgebra::Vector2<f32>) -> Self {
+        QVector2D::new(value.x, value.y)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<QVector2D> for nalgebra::Vector2<f32> {
+    fn from(value: QVector2D) -> Self {
+        nalgebra::Vector2::new(value.x(), value.y())
+    }
+}
+
 // Safety:
 //
 // Static checks on the C++ side ensure that QVector2D is trivial.
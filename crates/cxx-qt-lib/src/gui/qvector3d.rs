@@ -251,6 +251,34 @@ impl From<QVector3D> for crate::QPoint {
     }
 }
 
+#[cfg(feature = "glam")]
+impl From<glam::Vec3> for QVector3D {
+    fn from(value: glam::Vec3) -> Self {
+        QVector3D::new(value.x, value.y, value.z)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<QVector3D> for glam::Vec3 {
+    fn from(value: QVector3D) -> Self {
+        glam::Vec3::new(value.x(), value.y(), value.z())
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Vector3<f32>> for QVector3D {
+    fn from(value: nalgebra::Vector3<f32>) -> Self {
+        QVector3D::new(value.x, value.y, value.z)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<QVector3D> for nalgebra::Vector3<f32> {
+    fn from(value: QVector3D) -> Self {
+        nalgebra::Vector3::new(value.x(), value.y(), value.z())
+    }
+}
+
 // Safety:
 //
 // Static checks on the C++ side ensure that QVector3D is trivial.
@@ -232,6 +232,34 @@ impl From<QVector4D> for crate::QPoint {
     }
 }
 
+#[cfg(feature = "glam")]
+impl From<glam::Vec4> for QVector4D {
+    fn from(value: glam::Vec4) -> Self {
+        QVector4D::new(value.x, value.y, value.z, value.w)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<QVector4D> for glam::Vec4 {
+    fn from(value: QVector4D) -> Self {
+        glam::Vec4::new(value.x(), value.y(), value.z(), value.w())
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Vector4<f32>> for QVector4D {
+    fn from(value: nalgebra::Vector4<f32>) -> Self {
+        QVector4D::new(value.x, value.y, value.z, value.w)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<QVector4D> for nalgebra::Vector4<f32> {
+    fn from(value: QVector4D) -> Self {
+        nalgebra::Vector4::new(value.x(), value.y(), value.z(), value.w())
+    }
+}
+
 // Safety:
 //
 // Static checks on the C++ side ensure that QVector4D is trivial.
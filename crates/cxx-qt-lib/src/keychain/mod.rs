@@ -0,0 +1,7 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+mod qkeychain;
+pub use qkeychain::{QKeychain, QKeychainError, QKeychainErrorKind};
@@ -0,0 +1,204 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use std::fmt;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qkeychain.h");
+
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qkeychain_delete_password"]
+        fn qkeychainDeletePassword(
+            service: &QString,
+            key: &QString,
+            callback: Box<QKeychainCallback>,
+        );
+        #[doc(hidden)]
+        #[rust_name = "qkeychain_read_password"]
+        fn qkeychainReadPassword(
+            service: &QString,
+            key: &QString,
+            callback: Box<QKeychainCallback>,
+        );
+        #[doc(hidden)]
+        #[rust_name = "qkeychain_write_password"]
+        fn qkeychainWritePassword(
+            service: &QString,
+            key: &QString,
+            password: &QString,
+            callback: Box<QKeychainCallback>,
+        );
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        type QKeychainCallback;
+
+        #[doc(hidden)]
+        fn call(self: &mut QKeychainCallback, error: i32, message: &QString, data: &QString);
+    }
+}
+
+/// The kind of error which occurred when accessing the keychain
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QKeychainErrorKind {
+    /// No data was found for the key
+    EntryNotFound,
+    /// The entry could not be deleted
+    CouldNotDeleteEntry,
+    /// The user denied access to the keychain
+    AccessDeniedByUser,
+    /// Access to the keychain was denied for another reason, such as the keychain being locked
+    AccessDenied,
+    /// No platform secure storage is available
+    NoBackendAvailable,
+    /// The operation is not implemented on this platform
+    NotImplemented,
+    /// Any other error, see the message for more details
+    OtherError,
+}
+
+impl QKeychainErrorKind {
+    /// Convert from a `QKeychain::Error`, returning None for `QKeychain::NoError`
+    fn from_i32(error: i32) -> Option<Self> {
+        match error {
+            0 => None,
+            1 => Some(Self::EntryNotFound),
+            2 => Some(Self::CouldNotDeleteEntry),
+            3 => Some(Self::AccessDeniedByUser),
+            4 => Some(Self::AccessDenied),
+            5 => Some(Self::NoBackendAvailable),
+            6 => Some(Self::NotImplemented),
+            _ => Some(Self::OtherError),
+        }
+    }
+}
+
+/// An error which occurred when accessing the keychain
+#[derive(Clone, Debug)]
+pub struct QKeychainError {
+    /// The kind of error
+    pub kind: QKeychainErrorKind,
+    /// The human readable message from the keychain backend
+    pub message: ffi::QString,
+}
+
+impl fmt::Display for QKeychainError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}: {}", self.kind, self.message)
+    }
+}
+
+impl std::error::Error for QKeychainError {}
+
+type QKeychainJobFinished = dyn FnOnce(Result<&ffi::QString, QKeychainError>);
+
+/// A Rust closure which is called once a keychain job has finished
+#[doc(hidden)]
+pub struct QKeychainCallback {
+    callback: Option<Box<QKeychainJobFinished>>,
+}
+
+impl QKeychainCallback {
+    fn new(callback: impl FnOnce(Result<&ffi::QString, QKeychainError>) + 'static) -> Box<Self> {
+        Box::new(Self {
+            callback: Some(Box::new(callback)),
+        })
+    }
+
+    fn call(&mut self, error: i32, message: &ffi::QString, data: &ffi::QString) {
+        // The finished signal of a job is only emitted once
+        if let Some(callback) = self.callback.take() {
+            callback(match QKeychainErrorKind::from_i32(error) {
+                Some(kind) => Err(QKeychainError {
+                    kind,
+                    message: message.clone(),
+                }),
+                None => Ok(data),
+            });
+        }
+    }
+}
+
+/// Secure storage of credentials in the keychain of the platform, using [QtKeychain](https://github.com/frankosterfeld/qtkeychain).
+///
+/// Entries are identified by the name of the service, usually the name of the application, and a key.
+/// Each operation is asynchronous, the closure is called by the Qt event loop of the current thread once it has finished.
+///
+/// ```ignore
+/// let keychain = QKeychain::new(&QString::from("com.kdab.cxx_qt.demo"));
+/// keychain.read_password(&QString::from("token"), |result| match result {
+///     Ok(token) => println!("Token: {token}"),
+///     Err(err) => println!("Could not read token: {err}"),
+/// });
+/// ```
+#[derive(Clone, Debug)]
+pub struct QKeychain {
+    service: ffi::QString,
+}
+
+impl QKeychain {
+    /// Create a keychain for the entries of the given service
+    pub fn new(service: &ffi::QString) -> Self {
+        Self {
+            service: service.clone(),
+        }
+    }
+
+    /// The name of the service that entries are stored for
+    pub fn service(&self) -> &ffi::QString {
+        &self.service
+    }
+
+    /// Delete the password stored for the key
+    pub fn delete_password(
+        &self,
+        key: &ffi::QString,
+        callback: impl FnOnce(Result<(), QKeychainError>) + 'static,
+    ) {
+        ffi::qkeychain_delete_password(
+            &self.service,
+            key,
+            QKeychainCallback::new(move |result| callback(result.map(|_| ()))),
+        );
+    }
+
+    /// Read the password stored for the key
+    pub fn read_password(
+        &self,
+        key: &ffi::QString,
+        callback: impl FnOnce(Result<ffi::QString, QKeychainError>) + 'static,
+    ) {
+        ffi::qkeychain_read_password(
+            &self.service,
+            key,
+            QKeychainCallback::new(move |result| callback(result.cloned())),
+        );
+    }
+
+    /// Store the password for the key, replacing any existing password
+    pub fn write_password(
+        &self,
+        key: &ffi::QString,
+        password: &ffi::QString,
+        callback: impl FnOnce(Result<(), QKeychainError>) + 'static,
+    ) {
+        ffi::qkeychain_write_password(
+            &self.service,
+            key,
+            password,
+            QKeychainCallback::new(move |result| callback(result.map(|_| ()))),
+        );
+    }
+}
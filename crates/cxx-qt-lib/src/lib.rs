@@ -7,16 +7,63 @@
 #[cfg(not(any(qt_version_major = "5", qt_version_major = "6")))]
 compile_error!("qt_version_major must be either \"5\" or \"6\"");
 
+mod convert;
 mod core;
 
+pub use crate::convert::{FromQt, IntoQt};
 pub use crate::core::*;
 
+#[cfg(feature = "qt_bluetooth")]
+mod bluetooth;
+#[cfg(feature = "qt_bluetooth")]
+pub use crate::bluetooth::*;
+
 #[cfg(feature = "qt_gui")]
 mod gui;
 #[cfg(feature = "qt_gui")]
 pub use crate::gui::*;
 
+#[cfg(feature = "qt_keychain")]
+mod keychain;
+#[cfg(feature = "qt_keychain")]
+pub use crate::keychain::*;
+
+#[cfg(feature = "qt_network")]
+mod network;
+#[cfg(feature = "qt_network")]
+pub use crate::network::*;
+
 #[cfg(feature = "qt_qml")]
 mod qml;
 #[cfg(feature = "qt_qml")]
 pub use crate::qml::*;
+
+#[cfg(feature = "qt_quick3d")]
+mod quick3d;
+#[cfg(feature = "qt_quick3d")]
+pub use crate::quick3d::*;
+
+#[cfg(feature = "qt_sensors")]
+mod sensors;
+#[cfg(feature = "qt_sensors")]
+pub use crate::sensors::*;
+
+#[cfg(feature = "qt_serialport")]
+mod serialport;
+#[cfg(feature = "qt_serialport")]
+pub use crate::serialport::*;
+
+#[cfg(feature = "qt_sql")]
+mod sql;
+#[cfg(feature = "qt_sql")]
+pub use crate::sql::*;
+
+#[cfg(feature = "qt_statemachine")]
+mod statemachine;
+#[cfg(feature = "qt_statemachine")]
+pub use crate::statemachine::*;
+
+#[cfg(feature = "qt_websockets")]
+mod websockets;
+#[cfg(feature = "qt_websockets")]
+pub use crate::websockets::*;
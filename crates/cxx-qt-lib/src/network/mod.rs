@@ -0,0 +1,30 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+mod qlocalserver;
+pub use qlocalserver::QLocalServer;
+
+mod qlocalsocket;
+pub use qlocalsocket::{QLocalSocket, QLocalSocketLocalSocketError, QLocalSocketLocalSocketState};
+
+#[cfg(qt_version_major = "6")]
+mod qnetworkinformation;
+#[cfg(qt_version_major = "6")]
+pub use qnetworkinformation::{
+    QNetworkInformation, QNetworkInformationReachability, QNetworkInformationTransportMedium,
+};
+
+#[cfg(feature = "qt_ssl")]
+mod qsslcertificate;
+#[cfg(feature = "qt_ssl")]
+pub use qsslcertificate::{QSslCertificate, QSslCertificateSubjectInfo, QSslEncodingFormat};
+
+#[cfg(feature = "qt_ssl")]
+mod qsslconfiguration;
+#[cfg(feature = "qt_ssl")]
+pub use qsslconfiguration::{QSslConfiguration, QSslSocketPeerVerifyMode};
+
+mod singleinstanceguard;
+pub use singleinstanceguard::{SingleInstanceError, SingleInstanceGuard};
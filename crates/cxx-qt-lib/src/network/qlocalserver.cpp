@@ -0,0 +1,40 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_NETWORK_FEATURE
+#include "cxx-qt-lib/qlocalserver.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QLocalServer>
+qlocalserverNew()
+{
+  return ::std::make_unique<QLocalServer>();
+}
+
+::std::unique_ptr<QLocalSocket>
+qlocalserverNextPendingConnection(QLocalServer& server)
+{
+  // The socket is a child of the server, instead give ownership to Rust
+  auto* socket = server.nextPendingConnection();
+  if (socket != nullptr) {
+    socket->setParent(nullptr);
+  }
+  return ::std::unique_ptr<QLocalSocket>(socket);
+}
+
+bool
+qlocalserverRemoveServer(const QString& name)
+{
+  return QLocalServer::removeServer(name);
+}
+
+}
+}
+
+#endif
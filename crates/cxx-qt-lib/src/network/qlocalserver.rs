@@ -0,0 +1,152 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qlocalserver.h");
+
+        include!("cxx-qt-lib/qlocalsocket.h");
+        type QLocalSocket = crate::QLocalSocket;
+        include!("cxx-qt-lib/qmetaobjectconnection.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QMetaObjectConnection = crate::QMetaObjectConnection;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        /// The QLocalServer class provides a local socket based server.
+        ///
+        /// Clients can connect with a [QLocalSocket](crate::QLocalSocket) using the name of the server,
+        /// including clients which are written in C++.
+        ///
+        /// ```ignore
+        /// let mut server = QLocalServer::new();
+        /// let server_ptr = server.pin_mut().get_unchecked_mut() as *mut QLocalServer;
+        /// server
+        ///     .pin_mut()
+        ///     .on_new_connection(move || {
+        ///         let server = unsafe { Pin::new_unchecked(&mut *server_ptr) };
+        ///         let socket = server.next_pending_connection();
+        ///     })
+        ///     .release();
+        /// server.pin_mut().listen(&QString::from("my_app"));
+        /// ```
+        type QLocalServer;
+
+        /// Stop listening for incoming connections.
+        ///
+        /// Existing connections are not affected, but any new connections will be refused.
+        fn close(self: Pin<&mut QLocalServer>);
+
+        /// Returns the human-readable message appropriate to the current error reported by server_error().
+        #[rust_name = "error_string"]
+        fn errorString(self: &QLocalServer) -> QString;
+
+        /// Returns the full path that the server is listening on.
+        #[rust_name = "full_server_name"]
+        fn fullServerName(self: &QLocalServer) -> QString;
+
+        /// Returns true if the server has a pending connection, otherwise returns false.
+        #[rust_name = "has_pending_connections"]
+        fn hasPendingConnections(self: &QLocalServer) -> bool;
+
+        /// Returns true if the server is listening for incoming connections, otherwise returns false.
+        #[rust_name = "is_listening"]
+        fn isListening(self: &QLocalServer) -> bool;
+
+        /// Tells the server to listen for incoming connections on name.
+        ///
+        /// Returns false if the server is already listening or the name is in use,
+        /// on Unix a stale socket file from a crashed server can be removed with remove_server().
+        fn listen(self: Pin<&mut QLocalServer>, name: &QString) -> bool;
+
+        /// Returns the server name if the server is listening for connections, otherwise returns an empty string.
+        #[rust_name = "server_name"]
+        fn serverName(self: &QLocalServer) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qlocalserver_new"]
+        fn qlocalserverNew() -> UniquePtr<QLocalServer>;
+        #[doc(hidden)]
+        #[rust_name = "qlocalserver_next_pending_connection"]
+        fn qlocalserverNextPendingConnection(
+            server: Pin<&mut QLocalServer>,
+        ) -> UniquePtr<QLocalSocket>;
+        #[doc(hidden)]
+        #[rust_name = "qlocalserver_on_new_connection"]
+        fn qlocalserverOnNewConnection(
+            server: Pin<&mut QLocalServer>,
+            callback: Box<QLocalServerCallback>,
+        ) -> QMetaObjectConnection;
+        #[doc(hidden)]
+        #[rust_name = "qlocalserver_remove_server"]
+        fn qlocalserverRemoveServer(name: &QString) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        type QLocalServerCallback;
+
+        #[doc(hidden)]
+        fn call(self: &mut QLocalServerCallback);
+    }
+}
+
+pub use ffi::QLocalServer;
+
+/// A Rust closure which is called when a [QLocalServer] has a new connection
+#[doc(hidden)]
+pub struct QLocalServerCallback {
+    callback: Box<dyn FnMut()>,
+}
+
+impl QLocalServerCallback {
+    fn new(callback: impl FnMut() + 'static) -> Box<Self> {
+        Box::new(Self {
+            callback: Box::new(callback),
+        })
+    }
+
+    fn call(&mut self) {
+        (self.callback)();
+    }
+}
+
+impl QLocalServer {
+    /// Create a new local socket server.
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qlocalserver_new()
+    }
+
+    /// Returns the next pending connection as a connected socket which is owned by Rust,
+    /// or a null pointer if there are no pending connections.
+    pub fn next_pending_connection(self: Pin<&mut Self>) -> UniquePtr<ffi::QLocalSocket> {
+        ffi::qlocalserver_next_pending_connection(self)
+    }
+
+    /// Connect a closure which is called every time a new connection is available.
+    ///
+    /// The closure is called on the thread of the server, and is dropped when the connection is disconnected.
+    pub fn on_new_connection(
+        self: Pin<&mut Self>,
+        callback: impl FnMut() + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qlocalserver_on_new_connection(self, QLocalServerCallback::new(callback))
+    }
+
+    /// Removes any server instance that might cause a call to listen() to fail and returns true if successful.
+    ///
+    /// On Unix this removes the socket file left behind after a crash, on Windows this does nothing.
+    pub fn remove_server(name: &ffi::QString) -> bool {
+        ffi::qlocalserver_remove_server(name)
+    }
+}
@@ -0,0 +1,59 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_NETWORK_FEATURE
+#include "cxx-qt-lib/qlocalsocket.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QLocalSocket>
+qlocalsocketNew()
+{
+  return ::std::make_unique<QLocalSocket>();
+}
+
+::std::int64_t
+qlocalsocketBytesAvailable(const QLocalSocket& socket)
+{
+  return static_cast<::std::int64_t>(socket.bytesAvailable());
+}
+
+void
+qlocalsocketConnectToServer(QLocalSocket& socket, const QString& name)
+{
+  socket.connectToServer(name);
+}
+
+bool
+qlocalsocketWaitForBytesWritten(QLocalSocket& socket, ::std::int32_t msecs)
+{
+  return socket.waitForBytesWritten(static_cast<int>(msecs));
+}
+
+bool
+qlocalsocketWaitForConnected(QLocalSocket& socket, ::std::int32_t msecs)
+{
+  return socket.waitForConnected(static_cast<int>(msecs));
+}
+
+bool
+qlocalsocketWaitForReadyRead(QLocalSocket& socket, ::std::int32_t msecs)
+{
+  return socket.waitForReadyRead(static_cast<int>(msecs));
+}
+
+::std::int64_t
+qlocalsocketWrite(QLocalSocket& socket, const QByteArray& data)
+{
+  return static_cast<::std::int64_t>(socket.write(data));
+}
+
+}
+}
+
+#endif
@@ -0,0 +1,314 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+#[cxx::bridge]
+mod ffi {
+    /// The errors which can occur in a QLocalSocket.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QLocalSocketLocalSocketError {
+        /// The connection was refused by the peer (or timed out).
+        ConnectionRefusedError = 0,
+        /// The remote socket closed the connection.
+        PeerClosedError = 1,
+        /// The local socket name was not found.
+        ServerNotFoundError = 2,
+        /// The socket operation failed because the application lacked the required privileges.
+        SocketAccessError = 3,
+        /// The local system ran out of resources.
+        SocketResourceError = 4,
+        /// The socket operation timed out.
+        SocketTimeoutError = 5,
+        /// The datagram was larger than the operating system's limit.
+        DatagramTooLargeError = 6,
+        /// An error occurred with the connection.
+        ConnectionError = 7,
+        /// The requested socket operation is not supported by the local operating system.
+        UnsupportedSocketOperationError = 10,
+        /// An operation was attempted while the socket was in a state that did not permit it.
+        OperationError = 19,
+        /// An unidentified error occurred.
+        UnknownSocketError = -1,
+    }
+
+    /// The states of a QLocalSocket.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QLocalSocketLocalSocketState {
+        /// The socket is not connected.
+        UnconnectedState = 0,
+        /// The socket has started establishing a connection.
+        ConnectingState = 2,
+        /// A connection is established.
+        ConnectedState = 3,
+        /// The socket is about to close.
+        ClosingState = 6,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qlocalsocket.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QLocalSocketLocalSocketError;
+        #[namespace = "rust::cxxqtlib1"]
+        type QLocalSocketLocalSocketState;
+
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qmetaobjectconnection.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QMetaObjectConnection = crate::QMetaObjectConnection;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        /// The QLocalSocket class provides a local socket, which is a named pipe on Windows
+        /// and a Unix domain socket on other platforms.
+        ///
+        /// The data is delivered to Rust closures on the thread of the socket by the Qt event loop.
+        ///
+        /// ```ignore
+        /// let mut socket = QLocalSocket::new();
+        /// socket
+        ///     .pin_mut()
+        ///     .on_connected(|| println!("Connected"))
+        ///     .release();
+        /// socket.pin_mut().connect_to_server(&QString::from("my_app"));
+        /// ```
+        type QLocalSocket;
+
+        /// Aborts the current connection and resets the socket.
+        ///
+        /// Unlike disconnect_from_server(), this function immediately closes the socket, clearing any pending data in the write buffer.
+        fn abort(self: Pin<&mut QLocalSocket>);
+
+        /// Attempts to close the socket. If there is pending data waiting to be written,
+        /// the socket will enter ClosingState and wait until all data has been written.
+        #[rust_name = "disconnect_from_server"]
+        fn disconnectFromServer(self: Pin<&mut QLocalSocket>);
+
+        /// Returns the type of error that last occurred.
+        fn error(self: &QLocalSocket) -> QLocalSocketLocalSocketError;
+
+        /// Returns a human-readable description of the last device error that occurred.
+        #[rust_name = "error_string"]
+        fn errorString(self: &QLocalSocket) -> QString;
+
+        /// Writes as much as possible from the internal write buffer to the socket, without blocking.
+        ///
+        /// Returns true if any data was written.
+        fn flush(self: Pin<&mut QLocalSocket>) -> bool;
+
+        /// Returns the server path that the socket is connected to.
+        #[rust_name = "full_server_name"]
+        fn fullServerName(self: &QLocalSocket) -> QString;
+
+        /// Returns true if the socket is valid and ready for use, otherwise returns false.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QLocalSocket) -> bool;
+
+        /// Reads all remaining data from the socket, and returns it as a byte array.
+        #[rust_name = "read_all"]
+        fn readAll(self: Pin<&mut QLocalSocket>) -> QByteArray;
+
+        /// Returns the name of the peer as specified by set_server_name(),
+        /// or an empty QString if set_server_name() has not been called or connect_to_server() failed.
+        #[rust_name = "server_name"]
+        fn serverName(self: &QLocalSocket) -> QString;
+
+        /// Set the name of the peer to connect to, this must be called when the socket is not connected.
+        #[rust_name = "set_server_name"]
+        fn setServerName(self: Pin<&mut QLocalSocket>, name: &QString);
+
+        /// Returns the state of the socket.
+        fn state(self: &QLocalSocket) -> QLocalSocketLocalSocketState;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qlocalsocket_new"]
+        fn qlocalsocketNew() -> UniquePtr<QLocalSocket>;
+        #[doc(hidden)]
+        #[rust_name = "qlocalsocket_bytes_available"]
+        fn qlocalsocketBytesAvailable(socket: &QLocalSocket) -> i64;
+        #[doc(hidden)]
+        #[rust_name = "qlocalsocket_connect_to_server"]
+        fn qlocalsocketConnectToServer(socket: Pin<&mut QLocalSocket>, name: &QString);
+        #[doc(hidden)]
+        #[rust_name = "qlocalsocket_on_connected"]
+        fn qlocalsocketOnConnected(
+            socket: Pin<&mut QLocalSocket>,
+            callback: Box<QLocalSocketCallback>,
+        ) -> QMetaObjectConnection;
+        #[doc(hidden)]
+        #[rust_name = "qlocalsocket_on_disconnected"]
+        fn qlocalsocketOnDisconnected(
+            socket: Pin<&mut QLocalSocket>,
+            callback: Box<QLocalSocketCallback>,
+        ) -> QMetaObjectConnection;
+        #[doc(hidden)]
+        #[rust_name = "qlocalsocket_on_error_occurred"]
+        fn qlocalsocketOnErrorOccurred(
+            socket: Pin<&mut QLocalSocket>,
+            callback: Box<QLocalSocketErrorCallback>,
+        ) -> QMetaObjectConnection;
+        #[doc(hidden)]
+        #[rust_name = "qlocalsocket_on_ready_read"]
+        fn qlocalsocketOnReadyRead(
+            socket: Pin<&mut QLocalSocket>,
+            callback: Box<QLocalSocketCallback>,
+        ) -> QMetaObjectConnection;
+        #[doc(hidden)]
+        #[rust_name = "qlocalsocket_wait_for_bytes_written"]
+        fn qlocalsocketWaitForBytesWritten(socket: Pin<&mut QLocalSocket>, msecs: i32) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qlocalsocket_wait_for_connected"]
+        fn qlocalsocketWaitForConnected(socket: Pin<&mut QLocalSocket>, msecs: i32) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qlocalsocket_wait_for_ready_read"]
+        fn qlocalsocketWaitForReadyRead(socket: Pin<&mut QLocalSocket>, msecs: i32) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qlocalsocket_write"]
+        fn qlocalsocketWrite(socket: Pin<&mut QLocalSocket>, data: &QByteArray) -> i64;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        type QLocalSocketCallback;
+        #[doc(hidden)]
+        type QLocalSocketErrorCallback;
+
+        #[doc(hidden)]
+        fn call(self: &mut QLocalSocketCallback);
+        #[doc(hidden)]
+        fn call(self: &mut QLocalSocketErrorCallback, error: QLocalSocketLocalSocketError);
+    }
+}
+
+pub use ffi::{QLocalSocket, QLocalSocketLocalSocketError, QLocalSocketLocalSocketState};
+
+/// A Rust closure which is called by a connection of a [QLocalSocket]
+#[doc(hidden)]
+pub struct QLocalSocketCallback {
+    callback: Box<dyn FnMut()>,
+}
+
+impl QLocalSocketCallback {
+    fn new(callback: impl FnMut() + 'static) -> Box<Self> {
+        Box::new(Self {
+            callback: Box::new(callback),
+        })
+    }
+
+    fn call(&mut self) {
+        (self.callback)();
+    }
+}
+
+/// A Rust closure which is called when an error occurs in a [QLocalSocket]
+#[doc(hidden)]
+pub struct QLocalSocketErrorCallback {
+    callback: Box<dyn FnMut(QLocalSocketLocalSocketError)>,
+}
+
+impl QLocalSocketErrorCallback {
+    fn new(callback: impl FnMut(QLocalSocketLocalSocketError) + 'static) -> Box<Self> {
+        Box::new(Self {
+            callback: Box::new(callback),
+        })
+    }
+
+    fn call(&mut self, error: QLocalSocketLocalSocketError) {
+        (self.callback)(error);
+    }
+}
+
+impl QLocalSocket {
+    /// Returns the number of bytes that are available for reading.
+    pub fn bytes_available(&self) -> i64 {
+        ffi::qlocalsocket_bytes_available(self)
+    }
+
+    /// Attempts to make a connection to the server with the name, for reading and writing.
+    ///
+    /// The socket is opened with the result delivered to [QLocalSocket::on_connected] or [QLocalSocket::on_error_occurred].
+    pub fn connect_to_server(self: Pin<&mut Self>, name: &ffi::QString) {
+        ffi::qlocalsocket_connect_to_server(self, name);
+    }
+
+    /// Constructs a new local socket.
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qlocalsocket_new()
+    }
+
+    /// Connect a closure which is called when the socket has connected to the server.
+    ///
+    /// The closure is called on the thread of the socket, and is dropped when the connection is disconnected.
+    pub fn on_connected(
+        self: Pin<&mut Self>,
+        callback: impl FnMut() + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qlocalsocket_on_connected(self, QLocalSocketCallback::new(callback))
+    }
+
+    /// Connect a closure which is called when the socket has been disconnected.
+    ///
+    /// The closure is called on the thread of the socket, and is dropped when the connection is disconnected.
+    pub fn on_disconnected(
+        self: Pin<&mut Self>,
+        callback: impl FnMut() + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qlocalsocket_on_disconnected(self, QLocalSocketCallback::new(callback))
+    }
+
+    /// Connect a closure which is called with the type of error each time an error occurs.
+    ///
+    /// The closure is called on the thread of the socket, and is dropped when the connection is disconnected.
+    pub fn on_error_occurred(
+        self: Pin<&mut Self>,
+        callback: impl FnMut(QLocalSocketLocalSocketError) + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qlocalsocket_on_error_occurred(self, QLocalSocketErrorCallback::new(callback))
+    }
+
+    /// Connect a closure which is called each time new data is available for reading.
+    ///
+    /// The closure is called on the thread of the socket, and is dropped when the connection is disconnected.
+    pub fn on_ready_read(
+        self: Pin<&mut Self>,
+        callback: impl FnMut() + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qlocalsocket_on_ready_read(self, QLocalSocketCallback::new(callback))
+    }
+
+    /// Waits until the pending data has been written to the socket, up to msecs milliseconds.
+    ///
+    /// This blocks the thread of the socket, so should only be used without an event loop.
+    pub fn wait_for_bytes_written(self: Pin<&mut Self>, msecs: i32) -> bool {
+        ffi::qlocalsocket_wait_for_bytes_written(self, msecs)
+    }
+
+    /// Waits until the socket is connected, up to msecs milliseconds.
+    ///
+    /// This blocks the thread of the socket, so should only be used without an event loop.
+    pub fn wait_for_connected(self: Pin<&mut Self>, msecs: i32) -> bool {
+        ffi::qlocalsocket_wait_for_connected(self, msecs)
+    }
+
+    /// Waits until data is available for reading, up to msecs milliseconds.
+    ///
+    /// This blocks the thread of the socket, so should only be used without an event loop.
+    pub fn wait_for_ready_read(self: Pin<&mut Self>, msecs: i32) -> bool {
+        ffi::qlocalsocket_wait_for_ready_read(self, msecs)
+    }
+
+    /// Writes the data to the socket, returning the number of bytes that were written or -1 if an error occurred.
+    pub fn write(self: Pin<&mut Self>, data: &ffi::QByteArray) -> i64 {
+        ffi::qlocalsocket_write(self, data)
+    }
+}
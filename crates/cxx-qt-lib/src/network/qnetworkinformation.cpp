@@ -0,0 +1,70 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_NETWORK_FEATURE
+#include "cxx-qt-lib/qnetworkinformation.h"
+
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 1, 0))
+
+namespace rust {
+namespace cxxqtlib1 {
+
+const QNetworkInformation*
+qnetworkinformationInstance()
+{
+  return QNetworkInformation::instance();
+}
+
+bool
+qnetworkinformationIsBehindCaptivePortal(const QNetworkInformation& information)
+{
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 2, 0))
+  return information.isBehindCaptivePortal();
+#else
+  Q_UNUSED(information);
+  return false;
+#endif
+}
+
+bool
+qnetworkinformationIsMetered(const QNetworkInformation& information)
+{
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 3, 0))
+  return information.isMetered();
+#else
+  Q_UNUSED(information);
+  return false;
+#endif
+}
+
+bool
+qnetworkinformationLoadDefaultBackend()
+{
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 3, 0))
+  return QNetworkInformation::loadDefaultBackend();
+#else
+  return QNetworkInformation::load(QNetworkInformation::Feature::Reachability);
+#endif
+}
+
+QNetworkInformationTransportMedium
+qnetworkinformationTransportMedium(const QNetworkInformation& information)
+{
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 3, 0))
+  return information.transportMedium();
+#else
+  Q_UNUSED(information);
+  return QNetworkInformationTransportMedium::Unknown;
+#endif
+}
+
+}
+}
+
+#endif
+
+#endif
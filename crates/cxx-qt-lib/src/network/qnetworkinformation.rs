@@ -0,0 +1,298 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    /// The reachability of the network, which is ordered from least to most reachable.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QNetworkInformationReachability {
+        /// The reachability is unknown, or the backend does not support checking it.
+        Unknown = 0,
+        /// There is no network interface, or it is not connected.
+        Disconnected = 1,
+        /// Only the local network or the device itself is reachable.
+        Local = 2,
+        /// Only the site, such as the local network of an organization, is reachable.
+        Site = 3,
+        /// The internet is reachable.
+        Online = 4,
+    }
+
+    /// The transport medium of the network connection that is used by default.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QNetworkInformationTransportMedium {
+        /// The medium is unknown, or the backend does not support checking it.
+        Unknown = 0,
+        /// The connection uses Ethernet.
+        Ethernet = 1,
+        /// The connection uses a cellular network.
+        Cellular = 2,
+        /// The connection uses Wi-Fi.
+        WiFi = 3,
+        /// The connection uses Bluetooth.
+        Bluetooth = 4,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qnetworkinformation.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QNetworkInformationReachability;
+        #[namespace = "rust::cxxqtlib1"]
+        type QNetworkInformationTransportMedium;
+
+        include!("cxx-qt-lib/qmetaobjectconnection.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QMetaObjectConnection = crate::QMetaObjectConnection;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        /// QNetworkInformation exposes various network information through a native backend.
+        ///
+        /// This is a singleton which is created by [QNetworkInformation::load_default_backend],
+        /// and requires Qt 6.1 or later. Detecting a captive portal requires Qt 6.2,
+        /// and the transport medium and whether the connection is metered require Qt 6.3.
+        ///
+        /// ```ignore
+        /// if QNetworkInformation::load_default_backend() {
+        ///     if let Some(information) = QNetworkInformation::instance() {
+        ///         information
+        ///             .on_reachability_changed(|reachability| {
+        ///                 println!("Online: {}", reachability == QNetworkInformationReachability::Online);
+        ///             })
+        ///             .release();
+        ///     }
+        /// }
+        /// ```
+        type QNetworkInformation;
+
+        /// Returns the name of the currently loaded backend.
+        #[rust_name = "backend_name"]
+        fn backendName(self: &QNetworkInformation) -> QString;
+
+        /// Returns the reachability of the network, or Unknown if the backend does not support it.
+        fn reachability(self: &QNetworkInformation) -> QNetworkInformationReachability;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qnetworkinformation_instance"]
+        fn qnetworkinformationInstance() -> *const QNetworkInformation;
+        #[doc(hidden)]
+        #[rust_name = "qnetworkinformation_is_behind_captive_portal"]
+        fn qnetworkinformationIsBehindCaptivePortal(information: &QNetworkInformation) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qnetworkinformation_is_metered"]
+        fn qnetworkinformationIsMetered(information: &QNetworkInformation) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qnetworkinformation_load_default_backend"]
+        fn qnetworkinformationLoadDefaultBackend() -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qnetworkinformation_on_is_behind_captive_portal_changed"]
+        fn qnetworkinformationOnIsBehindCaptivePortalChanged(
+            information: &QNetworkInformation,
+            callback: Box<QNetworkInformationBoolCallback>,
+        ) -> QMetaObjectConnection;
+        #[doc(hidden)]
+        #[rust_name = "qnetworkinformation_on_is_metered_changed"]
+        fn qnetworkinformationOnIsMeteredChanged(
+            information: &QNetworkInformation,
+            callback: Box<QNetworkInformationBoolCallback>,
+        ) -> QMetaObjectConnection;
+        #[doc(hidden)]
+        #[rust_name = "qnetworkinformation_on_reachability_changed"]
+        fn qnetworkinformationOnReachabilityChanged(
+            information: &QNetworkInformation,
+            callback: Box<QNetworkInformationReachabilityCallback>,
+        ) -> QMetaObjectConnection;
+        #[doc(hidden)]
+        #[rust_name = "qnetworkinformation_on_transport_medium_changed"]
+        fn qnetworkinformationOnTransportMediumChanged(
+            information: &QNetworkInformation,
+            callback: Box<QNetworkInformationTransportMediumCallback>,
+        ) -> QMetaObjectConnection;
+        #[doc(hidden)]
+        #[rust_name = "qnetworkinformation_transport_medium"]
+        fn qnetworkinformationTransportMedium(
+            information: &QNetworkInformation,
+        ) -> QNetworkInformationTransportMedium;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        type QNetworkInformationBoolCallback;
+        #[doc(hidden)]
+        type QNetworkInformationReachabilityCallback;
+        #[doc(hidden)]
+        type QNetworkInformationTransportMediumCallback;
+
+        #[doc(hidden)]
+        fn call(self: &mut QNetworkInformationBoolCallback, value: bool);
+        #[doc(hidden)]
+        fn call(
+            self: &mut QNetworkInformationReachabilityCallback,
+            reachability: QNetworkInformationReachability,
+        );
+        #[doc(hidden)]
+        fn call(
+            self: &mut QNetworkInformationTransportMediumCallback,
+            medium: QNetworkInformationTransportMedium,
+        );
+    }
+}
+
+pub use ffi::{
+    QNetworkInformation, QNetworkInformationReachability, QNetworkInformationTransportMedium,
+};
+
+/// A Rust closure which is called when a boolean property of [QNetworkInformation] changes
+#[doc(hidden)]
+pub struct QNetworkInformationBoolCallback {
+    callback: Box<dyn FnMut(bool)>,
+}
+
+impl QNetworkInformationBoolCallback {
+    fn new(callback: impl FnMut(bool) + 'static) -> Box<Self> {
+        Box::new(Self {
+            callback: Box::new(callback),
+        })
+    }
+
+    fn call(&mut self, value: bool) {
+        (self.callback)(value);
+    }
+}
+
+/// A Rust closure which is called when the reachability of [QNetworkInformation] changes
+#[doc(hidden)]
+pub struct QNetworkInformationReachabilityCallback {
+    callback: Box<dyn FnMut(QNetworkInformationReachability)>,
+}
+
+impl QNetworkInformationReachabilityCallback {
+    fn new(callback: impl FnMut(QNetworkInformationReachability) + 'static) -> Box<Self> {
+        Box::new(Self {
+            callback: Box::new(callback),
+        })
+    }
+
+    fn call(&mut self, reachability: QNetworkInformationReachability) {
+        (self.callback)(reachability);
+    }
+}
+
+/// A Rust closure which is called when the transport medium of [QNetworkInformation] changes
+#[doc(hidden)]
+pub struct QNetworkInformationTransportMediumCallback {
+    callback: Box<dyn FnMut(QNetworkInformationTransportMedium)>,
+}
+
+impl QNetworkInformationTransportMediumCallback {
+    fn new(callback: impl FnMut(QNetworkInformationTransportMedium) + 'static) -> Box<Self> {
+        Box::new(Self {
+            callback: Box::new(callback),
+        })
+    }
+
+    fn call(&mut self, medium: QNetworkInformationTransportMedium) {
+        (self.callback)(medium);
+    }
+}
+
+impl QNetworkInformation {
+    /// Returns the loaded backend, or None if no backend has been loaded
+    /// with [QNetworkInformation::load_default_backend].
+    ///
+    /// The backend is destroyed when the application exits.
+    pub fn instance() -> Option<&'static Self> {
+        // SAFETY: the instance is owned by Qt and is only destroyed as the application exits
+        unsafe { ffi::qnetworkinformation_instance().as_ref() }
+    }
+
+    /// Returns true if the network is behind a captive portal, such as a hotel Wi-Fi
+    /// which requires a login before the internet is reachable.
+    ///
+    /// This is always false before Qt 6.2 or if the backend does not support it.
+    pub fn is_behind_captive_portal(&self) -> bool {
+        ffi::qnetworkinformation_is_behind_captive_portal(self)
+    }
+
+    /// Returns true if the network connection is metered, such as a cellular connection,
+    /// which can be used to defer large transfers.
+    ///
+    /// This is always false before Qt 6.3 or if the backend does not support it.
+    pub fn is_metered(&self) -> bool {
+        ffi::qnetworkinformation_is_metered(self)
+    }
+
+    /// Loads the default backend for the platform, returns true if a backend was already loaded or is now loaded.
+    ///
+    /// This must be called after the QCoreApplication has been created.
+    pub fn load_default_backend() -> bool {
+        ffi::qnetworkinformation_load_default_backend()
+    }
+
+    /// Connect a closure which is called when the network is detected to be behind a captive portal or not.
+    ///
+    /// The closure is called on the main thread, and is dropped when the connection is disconnected.
+    pub fn on_is_behind_captive_portal_changed(
+        &self,
+        callback: impl FnMut(bool) + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qnetworkinformation_on_is_behind_captive_portal_changed(
+            self,
+            QNetworkInformationBoolCallback::new(callback),
+        )
+    }
+
+    /// Connect a closure which is called when the network connection becomes metered or not.
+    ///
+    /// The closure is called on the main thread, and is dropped when the connection is disconnected.
+    pub fn on_is_metered_changed(
+        &self,
+        callback: impl FnMut(bool) + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qnetworkinformation_on_is_metered_changed(
+            self,
+            QNetworkInformationBoolCallback::new(callback),
+        )
+    }
+
+    /// Connect a closure which is called with the new reachability when it changes.
+    ///
+    /// The closure is called on the main thread, and is dropped when the connection is disconnected.
+    pub fn on_reachability_changed(
+        &self,
+        callback: impl FnMut(QNetworkInformationReachability) + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qnetworkinformation_on_reachability_changed(
+            self,
+            QNetworkInformationReachabilityCallback::new(callback),
+        )
+    }
+
+    /// Connect a closure which is called with the new transport medium when it changes.
+    ///
+    /// The closure is called on the main thread, and is dropped when the connection is disconnected.
+    pub fn on_transport_medium_changed(
+        &self,
+        callback: impl FnMut(QNetworkInformationTransportMedium) + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qnetworkinformation_on_transport_medium_changed(
+            self,
+            QNetworkInformationTransportMediumCallback::new(callback),
+        )
+    }
+
+    /// Returns the transport medium of the network connection that is used by default.
+    ///
+    /// This is always Unknown before Qt 6.3 or if the backend does not support it.
+    pub fn transport_medium(&self) -> QNetworkInformationTransportMedium {
+        ffi::qnetworkinformation_transport_medium(self)
+    }
+}
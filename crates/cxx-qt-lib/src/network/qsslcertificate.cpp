@@ -0,0 +1,46 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_SSL_FEATURE
+#include "cxx-qt-lib/qsslcertificate.h"
+
+#include "../assertion_utils.h"
+
+// QSslCertificate has a single pointer as it's member
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/network/ssl/qsslcertificate.h?h=v5.15.6-lts-lgpl
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/network/ssl/qsslcertificate.h?h=v6.2.4
+assert_alignment_and_size(QSslCertificate,
+                          alignof(::std::size_t),
+                          sizeof(::std::size_t));
+
+static_assert(!::std::is_trivially_copy_assignable<QSslCertificate>::value);
+static_assert(!::std::is_trivially_copy_constructible<QSslCertificate>::value);
+
+static_assert(!::std::is_trivially_destructible<QSslCertificate>::value);
+
+static_assert(QTypeInfo<QSslCertificate>::isRelocatable);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QSslCertificate
+qsslcertificateInitFromData(const QByteArray& data, QSslEncodingFormat format)
+{
+  return QSslCertificate(data, format);
+}
+
+QList<QSslCertificate>
+qsslcertificateFromData(const QByteArray& data, QSslEncodingFormat format)
+{
+  return QSslCertificate::fromData(data, format);
+}
+
+}
+}
+
+#endif
@@ -0,0 +1,243 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::fmt;
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    /// The encoding of a certificate or key.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QSslEncodingFormat {
+        /// The PEM format.
+        Pem = 0,
+        /// The DER format.
+        Der = 1,
+    }
+
+    /// The fields of the subject or issuer of a certificate.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QSslCertificateSubjectInfo {
+        /// "O" The name of the organization.
+        Organization = 0,
+        /// "CN" The common name; most often this is used to store the host name.
+        CommonName = 1,
+        /// "L" The locality.
+        LocalityName = 2,
+        /// "OU" The organizational unit name.
+        OrganizationalUnitName = 3,
+        /// "C" The country.
+        CountryName = 4,
+        /// "ST" The state or province.
+        StateOrProvinceName = 5,
+        /// The distinguished name qualifier.
+        DistinguishedNameQualifier = 6,
+        /// The certificate's serial number.
+        SerialNumber = 7,
+        /// The email address associated with the certificate.
+        EmailAddress = 8,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qsslcertificate.h");
+        type QSslCertificate = super::QSslCertificate;
+        #[namespace = "rust::cxxqtlib1"]
+        type QSslCertificateSubjectInfo;
+        #[namespace = "rust::cxxqtlib1"]
+        type QSslEncodingFormat;
+
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qcryptographichash.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QCryptographicHashAlgorithm = crate::QCryptographicHashAlgorithm;
+        include!("cxx-qt-lib/qdatetime.h");
+        type QDateTime = crate::QDateTime;
+        include!("cxx-qt-lib/qlist.h");
+        type QList_QSslCertificate = crate::QList<QSslCertificate>;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = crate::QStringList;
+
+        /// Clears the contents of this certificate, making it a null certificate.
+        fn clear(self: &mut QSslCertificate);
+
+        /// Returns a cryptographic digest of this certificate, such as a SHA-256 fingerprint
+        /// which can be compared against a pinned value.
+        fn digest(self: &QSslCertificate, algorithm: QCryptographicHashAlgorithm) -> QByteArray;
+
+        /// Returns the date-time that the certificate becomes valid,
+        /// or an empty QDateTime if this is a null certificate.
+        #[rust_name = "effective_date"]
+        fn effectiveDate(self: &QSslCertificate) -> QDateTime;
+
+        /// Returns the date-time that the certificate expires,
+        /// or an empty QDateTime if this is a null certificate.
+        #[rust_name = "expiry_date"]
+        fn expiryDate(self: &QSslCertificate) -> QDateTime;
+
+        /// Returns true if this certificate is blacklisted, otherwise returns false.
+        #[rust_name = "is_blacklisted"]
+        fn isBlacklisted(self: &QSslCertificate) -> bool;
+
+        /// Returns true if this is a null certificate (i.e., a certificate with no contents), otherwise returns false.
+        #[rust_name = "is_null"]
+        fn isNull(self: &QSslCertificate) -> bool;
+
+        /// Returns true if this certificate is self signed, otherwise returns false.
+        #[rust_name = "is_self_signed"]
+        fn isSelfSigned(self: &QSslCertificate) -> bool;
+
+        /// Returns a name that describes the issuer.
+        #[rust_name = "issuer_display_name"]
+        fn issuerDisplayName(self: &QSslCertificate) -> QString;
+
+        /// Returns the issuer information for the subject from the certificate,
+        /// or an empty list if there is no information for subject in the certificate.
+        #[rust_name = "issuer_info"]
+        fn issuerInfo(self: &QSslCertificate, subject: QSslCertificateSubjectInfo) -> QStringList;
+
+        /// Returns the certificate's serial number string in hexadecimal format.
+        #[rust_name = "serial_number"]
+        fn serialNumber(self: &QSslCertificate) -> QByteArray;
+
+        /// Returns a name that describes the subject.
+        #[rust_name = "subject_display_name"]
+        fn subjectDisplayName(self: &QSslCertificate) -> QString;
+
+        /// Returns the information for the subject, or an empty list if there is no information for subject in the certificate.
+        #[rust_name = "subject_info"]
+        fn subjectInfo(self: &QSslCertificate, subject: QSslCertificateSubjectInfo) -> QStringList;
+
+        /// Returns this certificate converted to a DER (binary) encoded representation.
+        #[rust_name = "to_der"]
+        fn toDer(self: &QSslCertificate) -> QByteArray;
+
+        /// Returns this certificate converted to a PEM (Base64) encoded representation.
+        #[rust_name = "to_pem"]
+        fn toPem(self: &QSslCertificate) -> QByteArray;
+
+        /// Returns this certificate converted to a human-readable text representation.
+        #[rust_name = "to_text"]
+        fn toText(self: &QSslCertificate) -> QString;
+
+        /// Returns the certificate's version string.
+        fn version(self: &QSslCertificate) -> QByteArray;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qsslcertificate_init_from_data"]
+        fn qsslcertificateInitFromData(
+            data: &QByteArray,
+            format: QSslEncodingFormat,
+        ) -> QSslCertificate;
+        #[doc(hidden)]
+        #[rust_name = "qsslcertificate_from_data"]
+        fn qsslcertificateFromData(
+            data: &QByteArray,
+            format: QSslEncodingFormat,
+        ) -> QList_QSslCertificate;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qsslcertificate_drop"]
+        fn drop(certificate: &mut QSslCertificate);
+
+        #[doc(hidden)]
+        #[rust_name = "qsslcertificate_init_default"]
+        fn construct() -> QSslCertificate;
+        #[doc(hidden)]
+        #[rust_name = "qsslcertificate_clone"]
+        fn construct(other: &QSslCertificate) -> QSslCertificate;
+
+        #[doc(hidden)]
+        #[rust_name = "qsslcertificate_eq"]
+        fn operatorEq(a: &QSslCertificate, b: &QSslCertificate) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qsslcertificate_to_qstring"]
+        fn toQString(value: &QSslCertificate) -> QString;
+    }
+}
+
+pub use ffi::{QSslCertificateSubjectInfo, QSslEncodingFormat};
+
+/// The QSslCertificate class provides a convenient API for an X509 certificate.
+///
+/// Certificates can be inspected to pin the certificate of a peer, for example by
+/// comparing the [QSslCertificate::digest] of the peer certificate with a known value.
+#[repr(C)]
+pub struct QSslCertificate {
+    _space: MaybeUninit<usize>,
+}
+
+impl QSslCertificate {
+    /// Returns all the certificates found in the PEM or DER encoded data,
+    /// which is useful for loading a chain of certificates.
+    pub fn from_data(
+        data: &ffi::QByteArray,
+        format: QSslEncodingFormat,
+    ) -> ffi::QList_QSslCertificate {
+        ffi::qsslcertificate_from_data(data, format)
+    }
+
+    /// Constructs a certificate from the first certificate found in the PEM or DER encoded data,
+    /// if there is no valid certificate then a null certificate is returned.
+    pub fn new(data: &ffi::QByteArray, format: QSslEncodingFormat) -> Self {
+        ffi::qsslcertificate_init_from_data(data, format)
+    }
+}
+
+impl Clone for QSslCertificate {
+    /// Constructs an identical copy of other.
+    fn clone(&self) -> Self {
+        ffi::qsslcertificate_clone(self)
+    }
+}
+
+impl Default for QSslCertificate {
+    /// Constructs a null certificate.
+    fn default() -> Self {
+        ffi::qsslcertificate_init_default()
+    }
+}
+
+impl std::cmp::PartialEq for QSslCertificate {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qsslcertificate_eq(self, other)
+    }
+}
+
+impl std::cmp::Eq for QSslCertificate {}
+
+impl fmt::Debug for QSslCertificate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", ffi::qsslcertificate_to_qstring(self))
+    }
+}
+
+impl Drop for QSslCertificate {
+    /// Destroys the QSslCertificate.
+    fn drop(&mut self) {
+        ffi::qsslcertificate_drop(self)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QSslCertificate {
+    type Id = type_id!("QSslCertificate");
+    type Kind = cxx::kind::Trivial;
+}
@@ -0,0 +1,53 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_SSL_FEATURE
+#include "cxx-qt-lib/qsslconfiguration.h"
+
+#include "../assertion_utils.h"
+
+// QSslConfiguration has a single pointer as it's member
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/network/ssl/qsslconfiguration.h?h=v5.15.6-lts-lgpl
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/network/ssl/qsslconfiguration.h?h=v6.2.4
+assert_alignment_and_size(QSslConfiguration,
+                          alignof(::std::size_t),
+                          sizeof(::std::size_t));
+
+static_assert(!::std::is_trivially_copy_assignable<QSslConfiguration>::value);
+static_assert(
+  !::std::is_trivially_copy_constructible<QSslConfiguration>::value);
+
+static_assert(!::std::is_trivially_destructible<QSslConfiguration>::value);
+
+static_assert(QTypeInfo<QSslConfiguration>::isRelocatable);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QSslConfiguration
+qsslconfigurationDefaultConfiguration()
+{
+  return QSslConfiguration::defaultConfiguration();
+}
+
+void
+qsslconfigurationSetDefaultConfiguration(const QSslConfiguration& configuration)
+{
+  QSslConfiguration::setDefaultConfiguration(configuration);
+}
+
+QList<QSslCertificate>
+qsslconfigurationSystemCaCertificates()
+{
+  return QSslConfiguration::systemCaCertificates();
+}
+
+}
+}
+
+#endif
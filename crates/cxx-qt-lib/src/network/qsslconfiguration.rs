@@ -0,0 +1,207 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    /// How the certificate of the peer of a connection is verified.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QSslSocketPeerVerifyMode {
+        /// The peer will not be asked for a certificate and the connection is not verified.
+        VerifyNone = 0,
+        /// The peer is asked for a certificate, but the connection continues if it is not valid.
+        QueryPeer = 1,
+        /// The peer is required to present a valid certificate, otherwise the connection fails.
+        VerifyPeer = 2,
+        /// Uses QueryPeer for server sockets and VerifyPeer for client sockets.
+        AutoVerifyPeer = 3,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qsslconfiguration.h");
+        type QSslConfiguration = super::QSslConfiguration;
+        #[namespace = "rust::cxxqtlib1"]
+        type QSslSocketPeerVerifyMode;
+
+        include!("cxx-qt-lib/qlist.h");
+        type QList_QSslCertificate = crate::QList<QSslCertificate>;
+        include!("cxx-qt-lib/qsslcertificate.h");
+        type QSslCertificate = crate::QSslCertificate;
+
+        /// Adds certificate to this configuration's CA certificate database,
+        /// which is used to verify the certificate chain of the peer.
+        #[rust_name = "add_ca_certificate"]
+        fn addCaCertificate(self: &mut QSslConfiguration, certificate: &QSslCertificate);
+
+        /// Returns this connection's CA certificate database.
+        #[rust_name = "ca_certificates"]
+        fn caCertificates(self: &QSslConfiguration) -> QList_QSslCertificate;
+
+        /// Returns true if this is a null QSslConfiguration object.
+        #[rust_name = "is_null"]
+        fn isNull(self: &QSslConfiguration) -> bool;
+
+        /// Returns the certificate to be presented to the peer during the SSL handshake process.
+        #[rust_name = "local_certificate"]
+        fn localCertificate(self: &QSslConfiguration) -> QSslCertificate;
+
+        /// Returns the certificate chain to be presented to the peer during the SSL handshake process.
+        #[rust_name = "local_certificate_chain"]
+        fn localCertificateChain(self: &QSslConfiguration) -> QList_QSslCertificate;
+
+        /// Returns the peer's digital certificate (i.e., the immediate certificate of the host you are connected to),
+        /// or a null certificate, if the peer has not assigned a certificate.
+        #[rust_name = "peer_certificate"]
+        fn peerCertificate(self: &QSslConfiguration) -> QSslCertificate;
+
+        /// Returns the peer's chain of digital certificates, starting with the peer's immediate certificate and ending with the CA's certificate.
+        #[rust_name = "peer_certificate_chain"]
+        fn peerCertificateChain(self: &QSslConfiguration) -> QList_QSslCertificate;
+
+        /// Returns the maximum number of certificates in the peer's certificate chain to be checked during the SSL handshake phase,
+        /// or 0 (the default) if no maximum depth has been set, indicating that the whole certificate chain should be checked.
+        #[rust_name = "peer_verify_depth"]
+        fn peerVerifyDepth(self: &QSslConfiguration) -> i32;
+
+        /// Returns the verify mode.
+        #[rust_name = "peer_verify_mode"]
+        fn peerVerifyMode(self: &QSslConfiguration) -> QSslSocketPeerVerifyMode;
+
+        /// Sets this configuration's CA certificate database to be certificates,
+        /// setting only the pinned certificates means that the peer must present a chain to one of them.
+        #[rust_name = "set_ca_certificates"]
+        fn setCaCertificates(self: &mut QSslConfiguration, certificates: &QList_QSslCertificate);
+
+        /// Sets the certificate to be presented to the peer during SSL handshake to be certificate.
+        #[rust_name = "set_local_certificate"]
+        fn setLocalCertificate(self: &mut QSslConfiguration, certificate: &QSslCertificate);
+
+        /// Sets the certificate chain to be presented to the peer during the SSL handshake to be localChain.
+        #[rust_name = "set_local_certificate_chain"]
+        fn setLocalCertificateChain(
+            self: &mut QSslConfiguration,
+            local_chain: &QList_QSslCertificate,
+        );
+
+        /// Sets the maximum number of certificates in the peer's certificate chain to be checked during the SSL handshake phase, to depth.
+        #[rust_name = "set_peer_verify_depth"]
+        fn setPeerVerifyDepth(self: &mut QSslConfiguration, depth: i32);
+
+        /// Sets the verify mode to mode.
+        #[rust_name = "set_peer_verify_mode"]
+        fn setPeerVerifyMode(self: &mut QSslConfiguration, mode: QSslSocketPeerVerifyMode);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qsslconfiguration_default_configuration"]
+        fn qsslconfigurationDefaultConfiguration() -> QSslConfiguration;
+        #[doc(hidden)]
+        #[rust_name = "qsslconfiguration_set_default_configuration"]
+        fn qsslconfigurationSetDefaultConfiguration(configuration: &QSslConfiguration);
+        #[doc(hidden)]
+        #[rust_name = "qsslconfiguration_system_ca_certificates"]
+        fn qsslconfigurationSystemCaCertificates() -> QList_QSslCertificate;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qsslconfiguration_drop"]
+        fn drop(configuration: &mut QSslConfiguration);
+
+        #[doc(hidden)]
+        #[rust_name = "qsslconfiguration_init_default"]
+        fn construct() -> QSslConfiguration;
+        #[doc(hidden)]
+        #[rust_name = "qsslconfiguration_clone"]
+        fn construct(other: &QSslConfiguration) -> QSslConfiguration;
+
+        #[doc(hidden)]
+        #[rust_name = "qsslconfiguration_eq"]
+        fn operatorEq(a: &QSslConfiguration, b: &QSslConfiguration) -> bool;
+    }
+}
+
+pub use ffi::QSslSocketPeerVerifyMode;
+
+/// The QSslConfiguration class holds the configuration and state of an SSL connection.
+///
+/// The default configuration is used by the connections which Qt manages, such as a
+/// [QWebSocket](crate::QWebSocket), so certificates can be pinned for all of them.
+///
+/// ```ignore
+/// let mut configuration = QSslConfiguration::default_configuration();
+/// configuration.set_ca_certificates(&QSslCertificate::from_data(&pinned_pem, QSslEncodingFormat::Pem));
+/// QSslConfiguration::set_default_configuration(&configuration);
+/// ```
+#[repr(C)]
+pub struct QSslConfiguration {
+    _space: MaybeUninit<usize>,
+}
+
+impl QSslConfiguration {
+    /// Returns the default SSL configuration to be used in new SSL connections.
+    pub fn default_configuration() -> Self {
+        ffi::qsslconfiguration_default_configuration()
+    }
+
+    /// Sets the default SSL configuration to be used in new SSL connections to be configuration.
+    ///
+    /// Existing connections are not affected by this call.
+    pub fn set_default_configuration(configuration: &Self) {
+        ffi::qsslconfiguration_set_default_configuration(configuration)
+    }
+
+    /// Returns the default CA certificate database for the system,
+    /// which can be used to extend rather than replace the CA certificates of a configuration.
+    pub fn system_ca_certificates() -> ffi::QList_QSslCertificate {
+        ffi::qsslconfiguration_system_ca_certificates()
+    }
+}
+
+impl Clone for QSslConfiguration {
+    /// Copies the configuration and state of other.
+    fn clone(&self) -> Self {
+        ffi::qsslconfiguration_clone(self)
+    }
+}
+
+impl Default for QSslConfiguration {
+    /// Constructs an empty SSL configuration.
+    ///
+    /// Use [QSslConfiguration::default_configuration] for a configuration with the system CA certificates.
+    fn default() -> Self {
+        ffi::qsslconfiguration_init_default()
+    }
+}
+
+impl std::cmp::PartialEq for QSslConfiguration {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qsslconfiguration_eq(self, other)
+    }
+}
+
+impl std::cmp::Eq for QSslConfiguration {}
+
+impl Drop for QSslConfiguration {
+    /// Releases any resources held by QSslConfiguration.
+    fn drop(&mut self) {
+        ffi::qsslconfiguration_drop(self)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QSslConfiguration {
+    type Id = type_id!("QSslConfiguration");
+    type Kind = cxx::kind::Trivial;
+}
@@ -0,0 +1,218 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    QByteArray, QLocalServer, QLocalSocket, QLocalSocketLocalSocketError,
+    QLocalSocketLocalSocketState, QString,
+};
+use core::pin::Pin;
+use cxx::UniquePtr;
+use std::{cell::RefCell, fmt, rc::Rc};
+
+/// How long to wait for a running instance to accept the connection and the arguments
+const TIMEOUT_MSECS: i32 = 1000;
+
+/// An error which occurred when acquiring a [SingleInstanceGuard]
+#[derive(Clone, Debug)]
+pub enum SingleInstanceError {
+    /// Another instance is already running and the arguments have been forwarded to it,
+    /// so this instance should exit
+    AlreadyRunning,
+    /// The name could not be listened on, with the human readable message of the server
+    Listen(QString),
+}
+
+impl fmt::Display for SingleInstanceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::AlreadyRunning => write!(f, "another instance is already running"),
+            Self::Listen(message) => write!(f, "could not listen for other instances: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for SingleInstanceError {}
+
+type ActivatedCallback = Rc<RefCell<Option<Box<dyn FnMut(Vec<String>)>>>>;
+
+/// Ensures that only one instance of an application is running, using a [QLocalServer] with the given name.
+///
+/// The first instance to acquire the guard listens for other instances. Later instances forward
+/// their arguments to the first instance and receive [SingleInstanceError::AlreadyRunning],
+/// the first instance is then activated with the arguments by the Qt event loop, for example to raise its window.
+///
+/// ```ignore
+/// let arguments: Vec<String> = std::env::args().collect();
+/// let guard = match SingleInstanceGuard::acquire(&QString::from("com.kdab.cxx_qt.demo"), &arguments) {
+///     Ok(guard) => guard,
+///     Err(SingleInstanceError::AlreadyRunning) => return,
+///     Err(err) => panic!("{err}"),
+/// };
+/// guard.on_activated(|arguments| println!("Activated with: {arguments:?}"));
+/// ```
+pub struct SingleInstanceGuard {
+    activated: ActivatedCallback,
+    server: UniquePtr<QLocalServer>,
+}
+
+impl SingleInstanceGuard {
+    /// Acquire the guard for the name, or forward the arguments to the instance which has already acquired it
+    ///
+    /// The guard must be kept alive for as long as other instances should be detected,
+    /// usually until the Qt event loop has finished.
+    pub fn acquire(name: &QString, arguments: &[String]) -> Result<Self, SingleInstanceError> {
+        let mut socket = QLocalSocket::new();
+        socket.pin_mut().connect_to_server(name);
+        if socket.pin_mut().wait_for_connected(TIMEOUT_MSECS) {
+            let data = QByteArray::from(encode_arguments(arguments).as_slice());
+            socket.pin_mut().write(&data);
+            socket.pin_mut().wait_for_bytes_written(TIMEOUT_MSECS);
+            socket.pin_mut().disconnect_from_server();
+            return Err(SingleInstanceError::AlreadyRunning);
+        }
+
+        // On Unix a crashed instance leaves the socket file behind, which refuses connections
+        // and prevents listening on the name, so remove it unless the server could be busy
+        if matches!(
+            socket.error(),
+            QLocalSocketLocalSocketError::ConnectionRefusedError
+                | QLocalSocketLocalSocketError::ServerNotFoundError
+        ) {
+            QLocalServer::remove_server(name);
+        }
+
+        let mut server = QLocalServer::new();
+        if !server.pin_mut().listen(name) {
+            return Err(SingleInstanceError::Listen(server.error_string()));
+        }
+
+        let activated: ActivatedCallback = Rc::new(RefCell::new(None));
+        {
+            let activated = activated.clone();
+            // Sockets of other instances which are still sending their arguments
+            let sockets = Rc::new(RefCell::new(vec![]));
+            // The server is heap allocated and its connections are destroyed with it,
+            // so the pointer is valid whenever the closure is called
+            let server_ptr = unsafe { server.pin_mut().get_unchecked_mut() as *mut QLocalServer };
+            server
+                .pin_mut()
+                .on_new_connection(move || {
+                    let server = unsafe { Pin::new_unchecked(&mut *server_ptr) };
+                    accept_pending_connections(server, &activated, &sockets);
+                })
+                .release();
+        }
+
+        Ok(Self { activated, server })
+    }
+
+    /// Set the closure which is called with the arguments of each other instance that is started
+    ///
+    /// The closure is called on the thread of the guard, replacing any previous closure.
+    pub fn on_activated(&self, callback: impl FnMut(Vec<String>) + 'static) {
+        *self.activated.borrow_mut() = Some(Box::new(callback));
+    }
+
+    /// The name that the guard is listening on
+    pub fn server_name(&self) -> QString {
+        self.server.server_name()
+    }
+}
+
+fn accept_pending_connections(
+    mut server: Pin<&mut QLocalServer>,
+    activated: &ActivatedCallback,
+    sockets: &Rc<RefCell<Vec<UniquePtr<QLocalSocket>>>>,
+) {
+    let mut sockets = sockets.borrow_mut();
+    // Sockets are not destroyed from their own signals, instead the finished ones
+    // are removed when the next instance connects
+    sockets.retain(|socket| socket.state() != QLocalSocketLocalSocketState::UnconnectedState);
+
+    while server.has_pending_connections() {
+        let mut socket = server.as_mut().next_pending_connection();
+        if socket.is_null() {
+            break;
+        }
+
+        let activated = activated.clone();
+        let mut data = vec![];
+        // The socket is owned by the guard and its connections are destroyed with it,
+        // so the pointer is valid whenever the closure is called
+        let socket_ptr = unsafe { socket.pin_mut().get_unchecked_mut() as *mut QLocalSocket };
+        socket
+            .pin_mut()
+            .on_ready_read(move || {
+                let socket = unsafe { Pin::new_unchecked(&mut *socket_ptr) };
+                data.extend_from_slice(socket.read_all().as_slice());
+                if let Some(arguments) = decode_arguments(&data) {
+                    data.clear();
+                    if let Some(callback) = activated.borrow_mut().as_mut() {
+                        callback(arguments);
+                    }
+                }
+            })
+            .release();
+        sockets.push(socket);
+    }
+}
+
+/// Encode the arguments as a count followed by the length and UTF-8 bytes of each argument
+fn encode_arguments(arguments: &[String]) -> Vec<u8> {
+    let mut data = (arguments.len() as u32).to_le_bytes().to_vec();
+    for argument in arguments {
+        data.extend_from_slice(&(argument.len() as u32).to_le_bytes());
+        data.extend_from_slice(argument.as_bytes());
+    }
+    data
+}
+
+/// Decode the arguments from the data, returning None if not all of the data has been received yet
+fn decode_arguments(data: &[u8]) -> Option<Vec<String>> {
+    fn read_u32(data: &mut &[u8]) -> Option<usize> {
+        if data.len() < 4 {
+            return None;
+        }
+        let (value, rest) = data.split_at(4);
+        *data = rest;
+        Some(u32::from_le_bytes([value[0], value[1], value[2], value[3]]) as usize)
+    }
+
+    let mut data = data;
+    let count = read_u32(&mut data)?;
+    let mut arguments = Vec::with_capacity(count);
+    for _ in 0..count {
+        let len = read_u32(&mut data)?;
+        if data.len() < len {
+            return None;
+        }
+        let (argument, rest) = data.split_at(len);
+        data = rest;
+        arguments.push(String::from_utf8_lossy(argument).into_owned());
+    }
+    Some(arguments)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arguments_round_trip() {
+        let arguments = vec!["app".to_owned(), "".to_owned(), "--open=ünïcode".to_owned()];
+        assert_eq!(
+            decode_arguments(&encode_arguments(&arguments)),
+            Some(arguments)
+        );
+    }
+
+    #[test]
+    fn arguments_incomplete() {
+        let data = encode_arguments(&["app".to_owned(), "file.txt".to_owned()]);
+        assert_eq!(decode_arguments(&data[..data.len() - 1]), None);
+        assert_eq!(decode_arguments(&data[..2]), None);
+        assert_eq!(decode_arguments(&[]), None);
+    }
+}
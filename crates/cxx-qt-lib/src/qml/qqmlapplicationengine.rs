@@ -6,6 +6,11 @@
 #[cxx::bridge]
 mod ffi {
     unsafe extern "C++" {
+        include!("cxx-qt-lib/qmetaobjectconnection.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QMetaObjectConnection = crate::QMetaObjectConnection;
+        include!("cxx-qt-lib/qqmlerror.h");
+        type QQmlError = crate::QQmlError;
         include!("cxx-qt-lib/qstring.h");
         type QString = crate::QString;
         include!("cxx-qt-lib/qstringlist.h");
@@ -36,6 +41,10 @@ mod ffi {
         /// Loads the root QML file located at url.
         fn load(self: Pin<&mut QQmlApplicationEngine>, url: &QUrl);
 
+        /// Returns true if warning messages will be output to stderr in addition to being emitted by the warnings() signal, otherwise false.
+        #[rust_name = "output_warnings_to_standard_error"]
+        fn outputWarningsToStandardError(self: &QQmlApplicationEngine) -> bool;
+
         /// Returns the list of directories where the engine searches for native plugins for imported modules (referenced in the qmldir file).
         #[rust_name = "plugin_path_list"]
         fn pluginPathList(self: &QQmlApplicationEngine) -> QStringList;
@@ -48,6 +57,13 @@ mod ffi {
         #[rust_name = "set_import_path_list"]
         fn setImportPathList(self: Pin<&mut QQmlApplicationEngine>, paths: &QStringList);
 
+        /// Set whether warning messages will be output to stderr to enabled.
+        ///
+        /// If enabled is true, any warning messages generated by QML will be output to stderr and emitted by the warnings() signal.
+        /// If enabled is false, only the warnings() signal will be emitted. This allows applications to handle warning output themselves.
+        #[rust_name = "set_output_warnings_to_standard_error"]
+        fn setOutputWarningsToStandardError(self: Pin<&mut QQmlApplicationEngine>, enabled: bool);
+
         /// Sets the list of directories where the engine searches for native plugins for imported modules (referenced in the qmldir file) to paths.
         #[rust_name = "set_plugin_path_list"]
         fn setPluginPathList(self: Pin<&mut QQmlApplicationEngine>, paths: &QStringList);
@@ -58,6 +74,13 @@ mod ffi {
         #[doc(hidden)]
         #[rust_name = "qqmlapplicationengine_new"]
         fn qqmlapplicationengineNew() -> UniquePtr<QQmlApplicationEngine>;
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlapplicationengine_on_warnings"]
+        fn qqmlengineOnWarnings(
+            engine: Pin<&mut QQmlApplicationEngine>,
+            func: fn(Pin<&mut QQmlApplicationEngine>, &[QQmlError]),
+        ) -> QMetaObjectConnection;
     }
 
     // QQmlApplicationEngine is not a trivial to CXX and is not relocatable in Qt
@@ -74,4 +97,17 @@ impl QQmlApplicationEngine {
     pub fn new() -> cxx::UniquePtr<Self> {
         ffi::qqmlapplicationengine_new()
     }
+
+    /// Connect to the warnings signal of the engine, which is emitted when QML generates warning messages.
+    ///
+    /// Use [QQmlApplicationEngine::set_output_warnings_to_standard_error] to stop the warnings also being output to stderr.
+    ///
+    /// Note that dropping the returned [QMetaObjectConnection](crate::QMetaObjectConnection) disconnects the callback,
+    /// use [QMetaObjectConnection::release](crate::QMetaObjectConnection::release) to keep it connected.
+    pub fn on_warnings(
+        self: core::pin::Pin<&mut Self>,
+        func: fn(core::pin::Pin<&mut Self>, &[crate::QQmlError]),
+    ) -> crate::QMetaObjectConnection {
+        ffi::qqmlapplicationengine_on_warnings(self, func)
+    }
 }
@@ -6,6 +6,11 @@
 #[cxx::bridge]
 mod ffi {
     unsafe extern "C++" {
+        include!("cxx-qt-lib/qmetaobjectconnection.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QMetaObjectConnection = crate::QMetaObjectConnection;
+        include!("cxx-qt-lib/qqmlerror.h");
+        type QQmlError = crate::QQmlError;
         include!("cxx-qt-lib/qstring.h");
         type QString = crate::QString;
         include!("cxx-qt-lib/qstringlist.h");
@@ -33,6 +38,10 @@ mod ffi {
         #[rust_name = "import_path_list"]
         fn importPathList(self: &QQmlEngine) -> QStringList;
 
+        /// Returns true if warning messages will be output to stderr in addition to being emitted by the warnings() signal, otherwise false.
+        #[rust_name = "output_warnings_to_standard_error"]
+        fn outputWarningsToStandardError(self: &QQmlEngine) -> bool;
+
         /// Returns the list of directories where the engine searches for native plugins for imported modules (referenced in the qmldir file).
         #[rust_name = "plugin_path_list"]
         fn pluginPathList(self: &QQmlEngine) -> QStringList;
@@ -45,6 +54,13 @@ mod ffi {
         #[rust_name = "set_import_path_list"]
         fn setImportPathList(self: Pin<&mut QQmlEngine>, paths: &QStringList);
 
+        /// Set whether warning messages will be output to stderr to enabled.
+        ///
+        /// If enabled is true, any warning messages generated by QML will be output to stderr and emitted by the warnings() signal.
+        /// If enabled is false, only the warnings() signal will be emitted. This allows applications to handle warning output themselves.
+        #[rust_name = "set_output_warnings_to_standard_error"]
+        fn setOutputWarningsToStandardError(self: Pin<&mut QQmlEngine>, enabled: bool);
+
         /// Sets the list of directories where the engine searches for native plugins for imported modules (referenced in the qmldir file) to paths.
         #[rust_name = "set_plugin_path_list"]
         fn setPluginPathList(self: Pin<&mut QQmlEngine>, paths: &QStringList);
@@ -55,6 +71,13 @@ mod ffi {
         #[doc(hidden)]
         #[rust_name = "qqmlengine_new"]
         fn qqmlengineNew() -> UniquePtr<QQmlEngine>;
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlengine_on_warnings"]
+        fn qqmlengineOnWarnings(
+            engine: Pin<&mut QQmlEngine>,
+            func: fn(Pin<&mut QQmlEngine>, &[QQmlError]),
+        ) -> QMetaObjectConnection;
     }
 
     // QQmlEngine is not a trivial to CXX and is not relocatable in Qt
@@ -71,4 +94,17 @@ impl QQmlEngine {
     pub fn new() -> cxx::UniquePtr<Self> {
         ffi::qqmlengine_new()
     }
+
+    /// Connect to the warnings signal of the engine, which is emitted when QML generates warning messages.
+    ///
+    /// Use [QQmlEngine::set_output_warnings_to_standard_error] to stop the warnings also being output to stderr.
+    ///
+    /// Note that dropping the returned [QMetaObjectConnection](crate::QMetaObjectConnection) disconnects the callback,
+    /// use [QMetaObjectConnection::release](crate::QMetaObjectConnection::release) to keep it connected.
+    pub fn on_warnings(
+        self: core::pin::Pin<&mut Self>,
+        func: fn(core::pin::Pin<&mut Self>, &[crate::QQmlError]),
+    ) -> crate::QMetaObjectConnection {
+        ffi::qqmlengine_on_warnings(self, func)
+    }
 }
@@ -0,0 +1,25 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_QML_FEATURE
+#include "cxx-qt-lib/qqmlerror.h"
+
+#include "../assertion_utils.h"
+
+// QQmlError has a single pointer as it's member
+//
+// https://code.qt.io/cgit/qt/qtdeclarative.git/tree/src/qml/qml/qqmlerror.h?h=v5.15.6-lts-lgpl#n95
+// https://code.qt.io/cgit/qt/qtdeclarative.git/tree/src/qml/qml/qqmlerror.h?h=v6.2.4#n91
+assert_alignment_and_size(QQmlError,
+                          alignof(::std::size_t),
+                          sizeof(::std::size_t));
+
+static_assert(!::std::is_trivially_copy_assignable<QQmlError>::value);
+static_assert(!::std::is_trivially_copy_constructible<QQmlError>::value);
+
+static_assert(!::std::is_trivially_destructible<QQmlError>::value);
+#endif
@@ -0,0 +1,112 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::fmt;
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = crate::QUrl;
+
+        include!("cxx-qt-lib/qqmlerror.h");
+        type QQmlError = super::QQmlError;
+
+        /// Returns the error column number.
+        fn column(self: &QQmlError) -> i32;
+
+        /// Returns the error description.
+        fn description(self: &QQmlError) -> QString;
+
+        /// Returns true if this error is valid, otherwise false.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QQmlError) -> bool;
+
+        /// Returns the error line number.
+        fn line(self: &QQmlError) -> i32;
+
+        /// Returns the error as a human readable string.
+        #[rust_name = "to_qstring"]
+        fn toString(self: &QQmlError) -> QString;
+
+        /// Returns the url for the file that caused this error.
+        fn url(self: &QQmlError) -> QUrl;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlerror_drop"]
+        fn drop(error: &mut QQmlError);
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlerror_init_default"]
+        fn construct() -> QQmlError;
+        #[doc(hidden)]
+        #[rust_name = "qqmlerror_init_from_qqmlerror"]
+        fn construct(error: &QQmlError) -> QQmlError;
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlerror_debug"]
+        fn toQString(error: &QQmlError) -> QString;
+    }
+}
+
+/// The QQmlError class encapsulates a QML error.
+///
+/// These are delivered to Rust by
+/// [QQmlEngine::on_warnings](crate::QQmlEngine::on_warnings) and
+/// [QQmlApplicationEngine::on_warnings](crate::QQmlApplicationEngine::on_warnings).
+#[repr(C)]
+pub struct QQmlError {
+    _space: MaybeUninit<usize>,
+}
+
+impl Clone for QQmlError {
+    /// Creates a copy of other.
+    fn clone(&self) -> Self {
+        ffi::qqmlerror_init_from_qqmlerror(self)
+    }
+}
+
+impl Default for QQmlError {
+    /// Creates an empty error object.
+    fn default() -> Self {
+        ffi::qqmlerror_init_default()
+    }
+}
+
+impl fmt::Display for QQmlError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_qstring())
+    }
+}
+
+impl fmt::Debug for QQmlError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", ffi::qqmlerror_debug(self))
+    }
+}
+
+impl Drop for QQmlError {
+    fn drop(&mut self) {
+        ffi::qqmlerror_drop(self);
+    }
+}
+
+impl std::error::Error for QQmlError {}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QQmlError {
+    type Id = type_id!("QQmlError");
+    type Kind = cxx::kind::Trivial;
+}
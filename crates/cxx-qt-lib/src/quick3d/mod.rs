@@ -0,0 +1,11 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+mod qquick3dgeometry;
+pub use qquick3dgeometry::{
+    qquick3dgeometry_buffer, QQuick3DGeometryAttributeComponentType,
+    QQuick3DGeometryAttributeSemantic, QQuick3DGeometryBufferElement,
+    QQuick3DGeometryPrimitiveType,
+};
@@ -0,0 +1,159 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Helpers for implementing a `QQuick3DGeometry` subclass in Rust.
+//!
+//! The subclass itself is declared in a bridge with `#[base = "QQuick3DGeometry"]` and the
+//! methods that are needed are exposed with `#[inherit]`, the enums and buffer helpers here
+//! can then be used to upload procedurally generated meshes.
+//!
+//! ```ignore
+//! #[cxx_qt::bridge]
+//! mod ffi {
+//!     unsafe extern "C++" {
+//!         include!("cxx-qt-lib/qbytearray.h");
+//!         type QByteArray = cxx_qt_lib::QByteArray;
+//!
+//!         include!("cxx-qt-lib/qquick3dgeometry.h");
+//!         #[namespace = "rust::cxxqtlib1"]
+//!         type QQuick3DGeometryAttributeComponentType = cxx_qt_lib::QQuick3DGeometryAttributeComponentType;
+//!         #[namespace = "rust::cxxqtlib1"]
+//!         type QQuick3DGeometryAttributeSemantic = cxx_qt_lib::QQuick3DGeometryAttributeSemantic;
+//!     }
+//!
+//!     unsafe extern "RustQt" {
+//!         #[qobject]
+//!         #[qml_element]
+//!         #[base = "QQuick3DGeometry"]
+//!         type TriangleGeometry = super::TriangleGeometryRust;
+//!
+//!         #[inherit]
+//!         #[rust_name = "set_vertex_data"]
+//!         fn setVertexData(self: Pin<&mut TriangleGeometry>, data: &QByteArray);
+//!
+//!         #[inherit]
+//!         #[rust_name = "set_stride"]
+//!         fn setStride(self: Pin<&mut TriangleGeometry>, stride: i32);
+//!
+//!         #[inherit]
+//!         #[rust_name = "add_attribute"]
+//!         fn addAttribute(
+//!             self: Pin<&mut TriangleGeometry>,
+//!             semantic: QQuick3DGeometryAttributeSemantic,
+//!             offset: i32,
+//!             component_type: QQuick3DGeometryAttributeComponentType,
+//!         );
+//!     }
+//! }
+//!
+//! let vertices: [[f32; 3]; 3] = [[-1.0, -1.0, 0.0], [1.0, -1.0, 0.0], [0.0, 1.0, 0.0]];
+//! geometry.as_mut().set_vertex_data(&cxx_qt_lib::qquick3dgeometry_buffer(&vertices));
+//! geometry.as_mut().set_stride(std::mem::size_of::<[f32; 3]>() as i32);
+//! geometry.as_mut().add_attribute(
+//!     QQuick3DGeometryAttributeSemantic::PositionSemantic,
+//!     0,
+//!     QQuick3DGeometryAttributeComponentType::F32Type,
+//! );
+//! ```
+
+use crate::QByteArray;
+
+#[cxx::bridge(namespace = "rust::cxxqtlib1")]
+mod ffi {
+    /// The type of the components of an attribute.
+    #[repr(i32)]
+    enum QQuick3DGeometryAttributeComponentType {
+        /// The attribute is an unsigned 16-bit integer.
+        U16Type,
+        /// The attribute is an unsigned 32-bit integer.
+        U32Type,
+        /// The attribute is a signed 32-bit integer.
+        I32Type,
+        /// The attribute is a single-precision float.
+        F32Type,
+    }
+
+    /// The semantic of an attribute, this describes what the attribute is used for.
+    #[repr(i32)]
+    enum QQuick3DGeometryAttributeSemantic {
+        /// The attribute is an index into the vertex buffer.
+        IndexSemantic,
+        /// The attribute is a position.
+        PositionSemantic,
+        /// The attribute is a normal vector.
+        NormalSemantic,
+        /// The attribute is a texture coordinate.
+        TexCoord0Semantic,
+        /// The attribute is a tangent vector.
+        TangentSemantic,
+        /// The attribute is a binormal vector.
+        BinormalSemantic,
+        /// The attribute is a joint index vector for skinning.
+        JointSemantic,
+        /// The attribute is a weight vector for skinning.
+        WeightSemantic,
+        /// The attribute is a vertex color vector.
+        ColorSemantic,
+    }
+
+    /// The primitive type used for rendering the geometry.
+    #[repr(i32)]
+    enum QQuick3DGeometryPrimitiveType {
+        /// The primitives are points.
+        Points,
+        /// The primitives are lines in a strip.
+        LineStrip,
+        /// The primitives are lines in a list.
+        Lines,
+        /// The primitives are triangles in a strip.
+        TriangleStrip,
+        /// The primitives are triangles in a fan.
+        TriangleFan,
+        /// The primitives are triangles in a list.
+        Triangles,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qquick3dgeometry.h");
+        type QQuick3DGeometryAttributeComponentType;
+        type QQuick3DGeometryAttributeSemantic;
+        type QQuick3DGeometryPrimitiveType;
+    }
+}
+
+pub use ffi::{
+    QQuick3DGeometryAttributeComponentType, QQuick3DGeometryAttributeSemantic,
+    QQuick3DGeometryPrimitiveType,
+};
+
+/// Types which can be uploaded as elements of a vertex or index buffer of a `QQuick3DGeometry`.
+///
+/// # Safety
+///
+/// The type must be plain old data, so it must be `Copy`, have a defined layout (eg `#[repr(C)]`)
+/// and must not contain any padding bytes, as every byte of the value is copied into the buffer.
+pub unsafe trait QQuick3DGeometryBufferElement: Copy {}
+
+unsafe impl QQuick3DGeometryBufferElement for f32 {}
+unsafe impl QQuick3DGeometryBufferElement for i32 {}
+unsafe impl QQuick3DGeometryBufferElement for u16 {}
+unsafe impl QQuick3DGeometryBufferElement for u32 {}
+unsafe impl<T: QQuick3DGeometryBufferElement, const N: usize> QQuick3DGeometryBufferElement
+    for [T; N]
+{
+}
+
+/// Copy the given slice into a [QByteArray] so that it can be passed to
+/// `QQuick3DGeometry::setVertexData` or `QQuick3DGeometry::setIndexData`.
+///
+/// Note that elements are copied with the native endianness of the target.
+pub fn qquick3dgeometry_buffer<T: QQuick3DGeometryBufferElement>(data: &[T]) -> QByteArray {
+    // SAFETY: QQuick3DGeometryBufferElement guarantees that T has no padding bytes
+    // so every byte in the slice is initialised and can be read as a u8
+    let bytes = unsafe {
+        std::slice::from_raw_parts(data.as_ptr() as *const u8, std::mem::size_of_val(data))
+    };
+    QByteArray::from(bytes)
+}
@@ -0,0 +1,10 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+mod qaccelerometer;
+pub use qaccelerometer::{QAccelerometer, QAccelerometerAccelerationMode, QAccelerometerReading};
+
+mod qgyroscope;
+pub use qgyroscope::{QGyroscope, QGyroscopeReading};
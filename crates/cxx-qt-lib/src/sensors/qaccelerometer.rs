@@ -0,0 +1,159 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+#[cxx::bridge]
+mod ffi {
+    /// The acceleration modes of an accelerometer.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QAccelerometerAccelerationMode {
+        /// Both the acceleration caused by gravity and the acceleration caused by the user moving the device is reported.
+        Combined = 0,
+        /// Only the acceleration caused by gravity is reported.
+        Gravity = 1,
+        /// Only the acceleration caused by the user moving the device is reported, the effect of gravity is canceled out.
+        User = 2,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qaccelerometer.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QAccelerometerAccelerationMode;
+
+        include!("cxx-qt-lib/qmetaobjectconnection.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QMetaObjectConnection = crate::QMetaObjectConnection;
+
+        /// The QAccelerometer class reports on linear acceleration along the X, Y and Z axes.
+        ///
+        /// Readings are delivered to Rust closures on the thread of the sensor by the Qt event loop.
+        ///
+        /// ```ignore
+        /// let mut accelerometer = QAccelerometer::new();
+        /// accelerometer
+        ///     .pin_mut()
+        ///     .on_reading_changed(|reading| println!("{} {} {}", reading.x, reading.y, reading.z))
+        ///     .release();
+        /// accelerometer.pin_mut().start();
+        /// ```
+        type QAccelerometer;
+
+        /// Returns the acceleration mode which controls how the acceleration values are reported.
+        #[rust_name = "acceleration_mode"]
+        fn accelerationMode(self: &QAccelerometer) -> QAccelerometerAccelerationMode;
+
+        /// Try to connect to a sensor backend, returns true if a backend is available.
+        #[rust_name = "connect_to_backend"]
+        fn connectToBackend(self: Pin<&mut QAccelerometer>) -> bool;
+
+        /// Returns the data rate that the sensor should be run at in Hz, 0 is the default data rate of the backend.
+        #[rust_name = "data_rate"]
+        fn dataRate(self: &QAccelerometer) -> i32;
+
+        /// Returns true if the sensor is active, which is after it has been started and before it has been stopped.
+        #[rust_name = "is_active"]
+        fn isActive(self: &QAccelerometer) -> bool;
+
+        /// Returns true if the sensor is busy, which is when it is being used by another process.
+        #[rust_name = "is_busy"]
+        fn isBusy(self: &QAccelerometer) -> bool;
+
+        /// Returns true if the sensor is connected to a backend.
+        #[rust_name = "is_connected_to_backend"]
+        fn isConnectedToBackend(self: &QAccelerometer) -> bool;
+
+        /// Sets the acceleration mode which controls how the acceleration values are reported.
+        #[rust_name = "set_acceleration_mode"]
+        fn setAccelerationMode(
+            self: Pin<&mut QAccelerometer>,
+            mode: QAccelerometerAccelerationMode,
+        );
+
+        /// Sets the data rate that the sensor should be run at in Hz, 0 is the default data rate of the backend.
+        #[rust_name = "set_data_rate"]
+        fn setDataRate(self: Pin<&mut QAccelerometer>, rate: i32);
+
+        /// Start retrieving values from the sensor, returns true if the sensor was started.
+        fn start(self: Pin<&mut QAccelerometer>) -> bool;
+
+        /// Stop retrieving values from the sensor.
+        fn stop(self: Pin<&mut QAccelerometer>);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qaccelerometer_new"]
+        fn qaccelerometerNew() -> UniquePtr<QAccelerometer>;
+        #[doc(hidden)]
+        #[rust_name = "qaccelerometer_on_reading_changed"]
+        fn qaccelerometerOnReadingChanged(
+            sensor: Pin<&mut QAccelerometer>,
+            callback: Box<QAccelerometerCallback>,
+        ) -> QMetaObjectConnection;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        type QAccelerometerCallback;
+
+        #[doc(hidden)]
+        fn call(self: &mut QAccelerometerCallback, x: f64, y: f64, z: f64, timestamp: u64);
+    }
+}
+
+pub use ffi::{QAccelerometer, QAccelerometerAccelerationMode};
+
+/// A reading of a [QAccelerometer], the acceleration in m/s^2 along
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct QAccelerometerReading {
+    /// The acceleration in m/s^2 along the X axis
+    pub x: f64,
+    /// The acceleration in m/s^2 along the Y axis
+    pub y: f64,
+    /// The acceleration in m/s^2 along the Z axis
+    pub z: f64,
+    /// The time of the reading in microseconds, the epoch is defined by the backend
+    pub timestamp: u64,
+}
+
+/// A Rust closure which is called by a connection of a [QAccelerometer]
+#[doc(hidden)]
+pub struct QAccelerometerCallback {
+    callback: Box<dyn FnMut(&QAccelerometerReading)>,
+}
+
+impl QAccelerometerCallback {
+    fn new(callback: impl FnMut(&QAccelerometerReading) + 'static) -> Box<Self> {
+        Box::new(Self {
+            callback: Box::new(callback),
+        })
+    }
+
+    fn call(&mut self, x: f64, y: f64, z: f64, timestamp: u64) {
+        (self.callback)(&QAccelerometerReading { x, y, z, timestamp });
+    }
+}
+
+impl QAccelerometer {
+    /// Constructs a new sensor, which is connected to the default backend once it is started.
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qaccelerometer_new()
+    }
+
+    /// Connect a closure which is called with each new reading of the sensor.
+    ///
+    /// The closure is called on the thread of the sensor, and is dropped when the connection is disconnected.
+    pub fn on_reading_changed(
+        self: Pin<&mut Self>,
+        callback: impl FnMut(&QAccelerometerReading) + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qaccelerometer_on_reading_changed(self, QAccelerometerCallback::new(callback))
+    }
+}
@@ -0,0 +1,22 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_SENSORS_FEATURE
+#include "cxx-qt-lib/qgyroscope.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QGyroscope>
+qgyroscopeNew()
+{
+  return ::std::make_unique<QGyroscope>();
+}
+
+}
+}
+#endif
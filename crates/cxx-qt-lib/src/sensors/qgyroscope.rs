@@ -0,0 +1,134 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qgyroscope.h");
+
+        include!("cxx-qt-lib/qmetaobjectconnection.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QMetaObjectConnection = crate::QMetaObjectConnection;
+
+        /// The QGyroscope class reports on the angular velocity around the X, Y and Z axes.
+        ///
+        /// Readings are delivered to Rust closures on the thread of the sensor by the Qt event loop.
+        ///
+        /// ```ignore
+        /// let mut gyroscope = QGyroscope::new();
+        /// gyroscope
+        ///     .pin_mut()
+        ///     .on_reading_changed(|reading| println!("{} {} {}", reading.x, reading.y, reading.z))
+        ///     .release();
+        /// gyroscope.pin_mut().start();
+        /// ```
+        type QGyroscope;
+
+        /// Try to connect to a sensor backend, returns true if a backend is available.
+        #[rust_name = "connect_to_backend"]
+        fn connectToBackend(self: Pin<&mut QGyroscope>) -> bool;
+
+        /// Returns the data rate that the sensor should be run at in Hz, 0 is the default data rate of the backend.
+        #[rust_name = "data_rate"]
+        fn dataRate(self: &QGyroscope) -> i32;
+
+        /// Returns true if the sensor is active, which is after it has been started and before it has been stopped.
+        #[rust_name = "is_active"]
+        fn isActive(self: &QGyroscope) -> bool;
+
+        /// Returns true if the sensor is busy, which is when it is being used by another process.
+        #[rust_name = "is_busy"]
+        fn isBusy(self: &QGyroscope) -> bool;
+
+        /// Returns true if the sensor is connected to a backend.
+        #[rust_name = "is_connected_to_backend"]
+        fn isConnectedToBackend(self: &QGyroscope) -> bool;
+
+        /// Sets the data rate that the sensor should be run at in Hz, 0 is the default data rate of the backend.
+        #[rust_name = "set_data_rate"]
+        fn setDataRate(self: Pin<&mut QGyroscope>, rate: i32);
+
+        /// Start retrieving values from the sensor, returns true if the sensor was started.
+        fn start(self: Pin<&mut QGyroscope>) -> bool;
+
+        /// Stop retrieving values from the sensor.
+        fn stop(self: Pin<&mut QGyroscope>);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qgyroscope_new"]
+        fn qgyroscopeNew() -> UniquePtr<QGyroscope>;
+        #[doc(hidden)]
+        #[rust_name = "qgyroscope_on_reading_changed"]
+        fn qgyroscopeOnReadingChanged(
+            sensor: Pin<&mut QGyroscope>,
+            callback: Box<QGyroscopeCallback>,
+        ) -> QMetaObjectConnection;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        type QGyroscopeCallback;
+
+        #[doc(hidden)]
+        fn call(self: &mut QGyroscopeCallback, x: f64, y: f64, z: f64, timestamp: u64);
+    }
+}
+
+pub use ffi::QGyroscope;
+
+/// A reading of a [QGyroscope], the angular velocity in degrees per second around
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct QGyroscopeReading {
+    /// The angular velocity in degrees per second around the X axis
+    pub x: f64,
+    /// The angular velocity in degrees per second around the Y axis
+    pub y: f64,
+    /// The angular velocity in degrees per second around the Z axis
+    pub z: f64,
+    /// The time of the reading in microseconds, the epoch is defined by the backend
+    pub timestamp: u64,
+}
+
+/// A Rust closure which is called by a connection of a [QGyroscope]
+#[doc(hidden)]
+pub struct QGyroscopeCallback {
+    callback: Box<dyn FnMut(&QGyroscopeReading)>,
+}
+
+impl QGyroscopeCallback {
+    fn new(callback: impl FnMut(&QGyroscopeReading) + 'static) -> Box<Self> {
+        Box::new(Self {
+            callback: Box::new(callback),
+        })
+    }
+
+    fn call(&mut self, x: f64, y: f64, z: f64, timestamp: u64) {
+        (self.callback)(&QGyroscopeReading { x, y, z, timestamp });
+    }
+}
+
+impl QGyroscope {
+    /// Constructs a new sensor, which is connected to the default backend once it is started.
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qgyroscope_new()
+    }
+
+    /// Connect a closure which is called with each new reading of the sensor.
+    ///
+    /// The closure is called on the thread of the sensor, and is dropped when the connection is disconnected.
+    pub fn on_reading_changed(
+        self: Pin<&mut Self>,
+        callback: impl FnMut(&QGyroscopeReading) + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qgyroscope_on_reading_changed(self, QGyroscopeCallback::new(callback))
+    }
+}
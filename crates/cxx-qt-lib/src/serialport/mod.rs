@@ -0,0 +1,10 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+mod qserialport;
+pub use qserialport::{
+    QIODeviceOpenModeFlag, QSerialPort, QSerialPortDataBits, QSerialPortFlowControl,
+    QSerialPortParity, QSerialPortSerialPortError, QSerialPortStopBits,
+};
@@ -0,0 +1,64 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_SERIALPORT_FEATURE
+#include "cxx-qt-lib/qserialport.h"
+
+#include <QtSerialPort/QSerialPortInfo>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QSerialPort>
+qserialportNew()
+{
+  return ::std::make_unique<QSerialPort>();
+}
+
+QStringList
+qserialportAvailablePorts()
+{
+  QStringList names;
+  for (const auto& info : QSerialPortInfo::availablePorts()) {
+    names.append(info.portName());
+  }
+  return names;
+}
+
+::std::int32_t
+qserialportBaudRate(const QSerialPort& port)
+{
+  return static_cast<::std::int32_t>(port.baudRate());
+}
+
+::std::int64_t
+qserialportBytesAvailable(const QSerialPort& port)
+{
+  return static_cast<::std::int64_t>(port.bytesAvailable());
+}
+
+bool
+qserialportOpen(QSerialPort& port, QIODeviceOpenModeFlag mode)
+{
+  return port.open(mode);
+}
+
+bool
+qserialportSetBaudRate(QSerialPort& port, ::std::int32_t baudRate)
+{
+  return port.setBaudRate(static_cast<qint32>(baudRate));
+}
+
+::std::int64_t
+qserialportWrite(QSerialPort& port, const QByteArray& data)
+{
+  return static_cast<::std::int64_t>(port.write(data));
+}
+
+}
+}
+#endif
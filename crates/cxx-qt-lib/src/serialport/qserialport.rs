@@ -0,0 +1,367 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+#[cxx::bridge]
+mod ffi {
+    /// The modes that a device can be opened in.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QIODeviceOpenModeFlag {
+        /// The device is open for reading.
+        ReadOnly = 1,
+        /// The device is open for writing.
+        WriteOnly = 2,
+        /// The device is open for reading and writing.
+        ReadWrite = 3,
+    }
+
+    /// The number of data bits in each character.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QSerialPortDataBits {
+        /// The number of data bits in each character is 5.
+        Data5 = 5,
+        /// The number of data bits in each character is 6.
+        Data6 = 6,
+        /// The number of data bits in each character is 7.
+        Data7 = 7,
+        /// The number of data bits in each character is 8, this is used for most kinds of data.
+        Data8 = 8,
+    }
+
+    /// The flow control used by the port.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QSerialPortFlowControl {
+        /// No flow control.
+        NoFlowControl = 0,
+        /// Hardware flow control (RTS/CTS).
+        HardwareControl = 1,
+        /// Software flow control (XON/XOFF).
+        SoftwareControl = 2,
+    }
+
+    /// The parity scheme used by the port.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QSerialPortParity {
+        /// No parity bit is sent.
+        NoParity = 0,
+        /// The number of 1 bits in each character, including the parity bit, is always even.
+        EvenParity = 2,
+        /// The number of 1 bits in each character, including the parity bit, is always odd.
+        OddParity = 3,
+        /// The parity bit is always 0.
+        SpaceParity = 4,
+        /// The parity bit is always 1.
+        MarkParity = 5,
+    }
+
+    /// The errors which can occur when using a serial port.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QSerialPortSerialPortError {
+        /// No error occurred.
+        NoError = 0,
+        /// An error occurred while attempting to open a non-existing device.
+        DeviceNotFoundError = 1,
+        /// An error occurred while attempting to open an already opened device by another process
+        /// or a user not having enough permission and credentials to open.
+        PermissionError = 2,
+        /// An error occurred while attempting to open an already opened device in this object.
+        OpenError = 3,
+        /// An I/O error occurred while writing the data.
+        WriteError = 7,
+        /// An I/O error occurred while reading the data.
+        ReadError = 8,
+        /// An I/O error occurred when a resource becomes unavailable, such as when the device is unexpectedly removed.
+        ResourceError = 9,
+        /// The requested device operation is not supported or prohibited by the running operating system.
+        UnsupportedOperationError = 10,
+        /// An unidentified error occurred.
+        UnknownError = 11,
+        /// A timeout error occurred.
+        TimeoutError = 12,
+        /// An operation which can only be performed when the device is open was attempted.
+        NotOpenError = 13,
+    }
+
+    /// The number of stop bits used by the port.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QSerialPortStopBits {
+        /// 1 stop bit.
+        OneStop = 1,
+        /// 1.5 stop bits, this is only for the Windows platform.
+        OneAndHalfStop = 3,
+        /// 2 stop bits.
+        TwoStop = 2,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qserialport.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QIODeviceOpenModeFlag;
+        #[namespace = "rust::cxxqtlib1"]
+        type QSerialPortDataBits;
+        #[namespace = "rust::cxxqtlib1"]
+        type QSerialPortFlowControl;
+        #[namespace = "rust::cxxqtlib1"]
+        type QSerialPortParity;
+        #[namespace = "rust::cxxqtlib1"]
+        type QSerialPortSerialPortError;
+        #[namespace = "rust::cxxqtlib1"]
+        type QSerialPortStopBits;
+
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qmetaobjectconnection.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QMetaObjectConnection = crate::QMetaObjectConnection;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = crate::QStringList;
+
+        /// The QSerialPort class provides functions to access serial ports.
+        ///
+        /// The port is read and written on the thread of the port, with the data that is ready
+        /// to be read delivered to Rust closures by the Qt event loop.
+        ///
+        /// ```ignore
+        /// let mut port = QSerialPort::new();
+        /// port.pin_mut().set_port_name(&QString::from("ttyUSB0"));
+        /// port.pin_mut().set_baud_rate(115200);
+        /// if port.pin_mut().open(QIODeviceOpenModeFlag::ReadWrite) {
+        ///     port.pin_mut().write(&QByteArray::from("AT\r\n"));
+        /// }
+        /// ```
+        type QSerialPort;
+
+        /// Clears the error status of the port, setting it to NoError.
+        #[rust_name = "clear_error"]
+        fn clearError(self: Pin<&mut QSerialPort>);
+
+        /// Closes the serial port.
+        fn close(self: Pin<&mut QSerialPort>);
+
+        /// Returns the number of data bits in a frame.
+        #[rust_name = "data_bits"]
+        fn dataBits(self: &QSerialPort) -> QSerialPortDataBits;
+
+        /// Returns the error status of the serial port.
+        fn error(self: &QSerialPort) -> QSerialPortSerialPortError;
+
+        /// Returns a human-readable description of the last device error that occurred.
+        #[rust_name = "error_string"]
+        fn errorString(self: &QSerialPort) -> QString;
+
+        /// Returns the desired flow control mode.
+        #[rust_name = "flow_control"]
+        fn flowControl(self: &QSerialPort) -> QSerialPortFlowControl;
+
+        /// Writes as much as possible from the internal write buffer to the underlying serial port without blocking.
+        /// If any data was written, this function returns true; otherwise returns false.
+        fn flush(self: Pin<&mut QSerialPort>) -> bool;
+
+        /// Returns true if the port is open, otherwise returns false.
+        #[rust_name = "is_open"]
+        fn isOpen(self: &QSerialPort) -> bool;
+
+        /// Returns the parity checking mode.
+        fn parity(self: &QSerialPort) -> QSerialPortParity;
+
+        /// Returns the name set by set_port_name.
+        #[rust_name = "port_name"]
+        fn portName(self: &QSerialPort) -> QString;
+
+        /// Reads all remaining data from the port, and returns it as a byte array.
+        #[rust_name = "read_all"]
+        fn readAll(self: Pin<&mut QSerialPort>) -> QByteArray;
+
+        /// Sets the data bits in a frame, returns true if successful.
+        #[rust_name = "set_data_bits"]
+        fn setDataBits(self: Pin<&mut QSerialPort>, data_bits: QSerialPortDataBits) -> bool;
+
+        /// Sets the desired flow control mode, returns true if successful.
+        #[rust_name = "set_flow_control"]
+        fn setFlowControl(
+            self: Pin<&mut QSerialPort>,
+            flow_control: QSerialPortFlowControl,
+        ) -> bool;
+
+        /// Sets the parity checking mode, returns true if successful.
+        #[rust_name = "set_parity"]
+        fn setParity(self: Pin<&mut QSerialPort>, parity: QSerialPortParity) -> bool;
+
+        /// Sets the name of the serial port, such as "COM1" or "ttyUSB0".
+        #[rust_name = "set_port_name"]
+        fn setPortName(self: Pin<&mut QSerialPort>, name: &QString);
+
+        /// Sets the number of stop bits in a frame, returns true if successful.
+        #[rust_name = "set_stop_bits"]
+        fn setStopBits(self: Pin<&mut QSerialPort>, stop_bits: QSerialPortStopBits) -> bool;
+
+        /// Returns the number of stop bits in a frame.
+        #[rust_name = "stop_bits"]
+        fn stopBits(self: &QSerialPort) -> QSerialPortStopBits;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qserialport_new"]
+        fn qserialportNew() -> UniquePtr<QSerialPort>;
+        #[doc(hidden)]
+        #[rust_name = "qserialport_available_ports"]
+        fn qserialportAvailablePorts() -> QStringList;
+        #[doc(hidden)]
+        #[rust_name = "qserialport_baud_rate"]
+        fn qserialportBaudRate(port: &QSerialPort) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qserialport_bytes_available"]
+        fn qserialportBytesAvailable(port: &QSerialPort) -> i64;
+        #[doc(hidden)]
+        #[rust_name = "qserialport_on_error_occurred"]
+        fn qserialportOnErrorOccurred(
+            port: Pin<&mut QSerialPort>,
+            callback: Box<QSerialPortErrorCallback>,
+        ) -> QMetaObjectConnection;
+        #[doc(hidden)]
+        #[rust_name = "qserialport_on_ready_read"]
+        fn qserialportOnReadyRead(
+            port: Pin<&mut QSerialPort>,
+            callback: Box<QSerialPortCallback>,
+        ) -> QMetaObjectConnection;
+        #[doc(hidden)]
+        #[rust_name = "qserialport_open"]
+        fn qserialportOpen(port: Pin<&mut QSerialPort>, mode: QIODeviceOpenModeFlag) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qserialport_set_baud_rate"]
+        fn qserialportSetBaudRate(port: Pin<&mut QSerialPort>, baud_rate: i32) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qserialport_write"]
+        fn qserialportWrite(port: Pin<&mut QSerialPort>, data: &QByteArray) -> i64;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        type QSerialPortCallback;
+        #[doc(hidden)]
+        type QSerialPortErrorCallback;
+
+        #[doc(hidden)]
+        fn call(self: &mut QSerialPortCallback);
+        #[doc(hidden)]
+        fn call(self: &mut QSerialPortErrorCallback, error: QSerialPortSerialPortError);
+    }
+}
+
+pub use ffi::{
+    QIODeviceOpenModeFlag, QSerialPort, QSerialPortDataBits, QSerialPortFlowControl,
+    QSerialPortParity, QSerialPortSerialPortError, QSerialPortStopBits,
+};
+
+/// A Rust closure which is called when data is ready to be read from a [QSerialPort]
+#[doc(hidden)]
+pub struct QSerialPortCallback {
+    callback: Box<dyn FnMut()>,
+}
+
+impl QSerialPortCallback {
+    fn new(callback: impl FnMut() + 'static) -> Box<Self> {
+        Box::new(Self {
+            callback: Box::new(callback),
+        })
+    }
+
+    fn call(&mut self) {
+        (self.callback)();
+    }
+}
+
+/// A Rust closure which is called when an error occurs on a [QSerialPort]
+#[doc(hidden)]
+pub struct QSerialPortErrorCallback {
+    callback: Box<dyn FnMut(ffi::QSerialPortSerialPortError)>,
+}
+
+impl QSerialPortErrorCallback {
+    fn new(callback: impl FnMut(ffi::QSerialPortSerialPortError) + 'static) -> Box<Self> {
+        Box::new(Self {
+            callback: Box::new(callback),
+        })
+    }
+
+    fn call(&mut self, error: ffi::QSerialPortSerialPortError) {
+        (self.callback)(error);
+    }
+}
+
+impl QSerialPort {
+    /// Returns the names of the serial ports available on the system, which can be given to [QSerialPort::set_port_name].
+    pub fn available_ports() -> ffi::QStringList {
+        ffi::qserialport_available_ports()
+    }
+
+    /// Returns the baud rate that the port is configured with for both directions.
+    pub fn baud_rate(&self) -> i32 {
+        ffi::qserialport_baud_rate(self)
+    }
+
+    /// Returns the number of bytes that are available for reading.
+    pub fn bytes_available(&self) -> i64 {
+        ffi::qserialport_bytes_available(self)
+    }
+
+    /// Constructs a new serial port, the name of the port must be set before it is opened.
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qserialport_new()
+    }
+
+    /// Connect a closure which is called with the error each time an error occurs.
+    ///
+    /// The closure is called on the thread of the port, and is dropped when the connection is disconnected.
+    pub fn on_error_occurred(
+        self: Pin<&mut Self>,
+        callback: impl FnMut(ffi::QSerialPortSerialPortError) + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qserialport_on_error_occurred(self, QSerialPortErrorCallback::new(callback))
+    }
+
+    /// Connect a closure which is called each time new data is available for reading,
+    /// the data can then be read with [QSerialPort::read_all].
+    ///
+    /// The closure is called on the thread of the port, and is dropped when the connection is disconnected.
+    pub fn on_ready_read(
+        self: Pin<&mut Self>,
+        callback: impl FnMut() + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qserialport_on_ready_read(self, QSerialPortCallback::new(callback))
+    }
+
+    /// Opens the serial port using the mode, returns true if successful.
+    ///
+    /// The configuration of the port, such as the baud rate, is applied when it is opened.
+    pub fn open(self: Pin<&mut Self>, mode: ffi::QIODeviceOpenModeFlag) -> bool {
+        ffi::qserialport_open(self, mode)
+    }
+
+    /// Sets the baud rate for both directions, returns true if successful.
+    pub fn set_baud_rate(self: Pin<&mut Self>, baud_rate: i32) -> bool {
+        ffi::qserialport_set_baud_rate(self, baud_rate)
+    }
+
+    /// Writes the data to the port, returns the number of bytes that were written or -1 if an error occurred.
+    ///
+    /// The data is written asynchronously by the Qt event loop of the thread of the port.
+    pub fn write(self: Pin<&mut Self>, data: &ffi::QByteArray) -> i64 {
+        ffi::qserialport_write(self, data)
+    }
+}
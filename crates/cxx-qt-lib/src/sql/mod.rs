@@ -0,0 +1,16 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+mod qsqldatabase;
+pub use qsqldatabase::QSqlDatabase;
+
+mod qsqlquery;
+pub use qsqlquery::{QSqlQuery, QSqlQueryRows};
+
+mod qsqlquerymodel;
+pub use qsqlquerymodel::QSqlQueryModel;
+
+mod qsqltablemodel;
+pub use qsqltablemodel::{QSqlTableModel, QSqlTableModelEditStrategy};
@@ -0,0 +1,75 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_SQL_FEATURE
+#include "cxx-qt-lib/qsqldatabase.h"
+
+#include <QtSql/QSqlError>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QSqlDatabase>
+qsqldatabaseAddDatabase(const QString& type, const QString& connectionName)
+{
+  return ::std::make_unique<QSqlDatabase>(
+    QSqlDatabase::addDatabase(type, connectionName));
+}
+
+bool
+qsqldatabaseContains(const QString& connectionName)
+{
+  return QSqlDatabase::contains(connectionName);
+}
+
+::std::unique_ptr<QSqlDatabase>
+qsqldatabaseDatabase(const QString& connectionName, bool open)
+{
+  return ::std::make_unique<QSqlDatabase>(
+    QSqlDatabase::database(connectionName, open));
+}
+
+QStringList
+qsqldatabaseDrivers()
+{
+  return QSqlDatabase::drivers();
+}
+
+bool
+qsqldatabaseIsDriverAvailable(const QString& name)
+{
+  return QSqlDatabase::isDriverAvailable(name);
+}
+
+QString
+qsqldatabaseLastErrorText(const QSqlDatabase& database)
+{
+  return database.lastError().text();
+}
+
+void
+qsqldatabaseRemoveDatabase(const QString& connectionName)
+{
+  QSqlDatabase::removeDatabase(connectionName);
+}
+
+void
+qsqldatabaseSetConnectOptions(QSqlDatabase& database, const QString& options)
+{
+  database.setConnectOptions(options);
+}
+
+QStringList
+qsqldatabaseTables(const QSqlDatabase& database)
+{
+  return database.tables();
+}
+
+}
+}
+
+#endif
@@ -0,0 +1,215 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = crate::QStringList;
+
+        include!("cxx-qt-lib/qsqldatabase.h");
+        /// The QSqlDatabase class handles a connection to a database.
+        ///
+        /// A connection is registered by name with [QSqlDatabase::add_database], and can only be used
+        /// from the thread it was created in. Queries are then run with a [QSqlQuery](crate::QSqlQuery).
+        ///
+        /// ```ignore
+        /// let mut database = QSqlDatabase::add_database(&QString::from("QODBC"), &QString::from("reports"));
+        /// database.pin_mut().set_database_name(&QString::from("DRIVER={SQL Server};SERVER=localhost"));
+        /// if !database.pin_mut().open() {
+        ///     println!("Failed to open: {}", database.last_error_text());
+        /// }
+        /// ```
+        type QSqlDatabase;
+
+        /// Closes the database connection, freeing any resources acquired, and invalidating any existing QSqlQuery objects that are used with the database.
+        fn close(self: Pin<&mut QSqlDatabase>);
+
+        /// Commits a transaction to the database if the driver supports transactions and a transaction() has been started.
+        ///
+        /// Returns true if the operation succeeded. Otherwise it returns false.
+        fn commit(self: Pin<&mut QSqlDatabase>) -> bool;
+
+        /// Returns the connection name, which may be empty.
+        #[rust_name = "connection_name"]
+        fn connectionName(self: &QSqlDatabase) -> QString;
+
+        /// Returns the connection's database name, which may be empty.
+        #[rust_name = "database_name"]
+        fn databaseName(self: &QSqlDatabase) -> QString;
+
+        /// Returns the connection's driver name.
+        #[rust_name = "driver_name"]
+        fn driverName(self: &QSqlDatabase) -> QString;
+
+        /// Returns the connection's host name; it may be empty.
+        #[rust_name = "host_name"]
+        fn hostName(self: &QSqlDatabase) -> QString;
+
+        /// Returns true if the database connection is currently open; otherwise returns false.
+        #[rust_name = "is_open"]
+        fn isOpen(self: &QSqlDatabase) -> bool;
+
+        /// Returns true if the QSqlDatabase has a valid driver.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QSqlDatabase) -> bool;
+
+        /// Opens the database connection using the current connection values.
+        ///
+        /// Returns true on success; otherwise returns false, the error can be retrieved using [QSqlDatabase::last_error_text].
+        fn open(self: Pin<&mut QSqlDatabase>) -> bool;
+
+        /// Returns the connection's port number. The value is undefined if the port number has not been set.
+        fn port(self: &QSqlDatabase) -> i32;
+
+        /// Rolls back a transaction on the database, if the driver supports transactions and a transaction() has been started.
+        ///
+        /// Returns true if the operation succeeded. Otherwise it returns false.
+        fn rollback(self: Pin<&mut QSqlDatabase>) -> bool;
+
+        /// Sets the connection's database name to name, which has a different meaning for each driver,
+        /// such as the file name for QSQLITE or a connection string for QODBC.
+        ///
+        /// To have effect, the database name must be set before the connection is opened.
+        #[rust_name = "set_database_name"]
+        fn setDatabaseName(self: Pin<&mut QSqlDatabase>, name: &QString);
+
+        /// Sets the connection's host name to host.
+        ///
+        /// To have effect, the host name must be set before the connection is opened.
+        #[rust_name = "set_host_name"]
+        fn setHostName(self: Pin<&mut QSqlDatabase>, host: &QString);
+
+        /// Sets the connection's password to password.
+        ///
+        /// To have effect, the password must be set before the connection is opened.
+        #[rust_name = "set_password"]
+        fn setPassword(self: Pin<&mut QSqlDatabase>, password: &QString);
+
+        /// Sets the connection's port number to port.
+        ///
+        /// To have effect, the port number must be set before the connection is opened.
+        #[rust_name = "set_port"]
+        fn setPort(self: Pin<&mut QSqlDatabase>, port: i32);
+
+        /// Sets the connection's user name to name.
+        ///
+        /// To have effect, the user name must be set before the connection is opened.
+        #[rust_name = "set_user_name"]
+        fn setUserName(self: Pin<&mut QSqlDatabase>, name: &QString);
+
+        /// Begins a transaction on the database if the driver supports transactions.
+        ///
+        /// Returns true if the operation succeeded. Otherwise it returns false.
+        fn transaction(self: Pin<&mut QSqlDatabase>) -> bool;
+
+        /// Returns the connection's user name; it may be empty.
+        #[rust_name = "user_name"]
+        fn userName(self: &QSqlDatabase) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qsqldatabase_add_database"]
+        fn qsqldatabaseAddDatabase(
+            driver: &QString,
+            connection_name: &QString,
+        ) -> UniquePtr<QSqlDatabase>;
+        #[doc(hidden)]
+        #[rust_name = "qsqldatabase_contains"]
+        fn qsqldatabaseContains(connection_name: &QString) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qsqldatabase_database"]
+        fn qsqldatabaseDatabase(connection_name: &QString, open: bool) -> UniquePtr<QSqlDatabase>;
+        #[doc(hidden)]
+        #[rust_name = "qsqldatabase_drivers"]
+        fn qsqldatabaseDrivers() -> QStringList;
+        #[doc(hidden)]
+        #[rust_name = "qsqldatabase_is_driver_available"]
+        fn qsqldatabaseIsDriverAvailable(name: &QString) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qsqldatabase_last_error_text"]
+        fn qsqldatabaseLastErrorText(database: &QSqlDatabase) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qsqldatabase_remove_database"]
+        fn qsqldatabaseRemoveDatabase(connection_name: &QString);
+        #[doc(hidden)]
+        #[rust_name = "qsqldatabase_set_connect_options"]
+        fn qsqldatabaseSetConnectOptions(database: Pin<&mut QSqlDatabase>, options: &QString);
+        #[doc(hidden)]
+        #[rust_name = "qsqldatabase_tables"]
+        fn qsqldatabaseTables(database: &QSqlDatabase) -> QStringList;
+    }
+}
+
+pub use ffi::QSqlDatabase;
+
+impl QSqlDatabase {
+    /// The name of the connection which is used when no connection name is given to Qt.
+    pub const DEFAULT_CONNECTION: &'static str = "qt_sql_default_connection";
+
+    /// Adds a database to the list of database connections using the driver type and the connection name,
+    /// if there already exists a database connection with the name then that connection is removed.
+    ///
+    /// The driver is the name of a Qt SQL driver plugin, such as QSQLITE, QPSQL, or QODBC.
+    pub fn add_database(driver: &ffi::QString, connection_name: &ffi::QString) -> UniquePtr<Self> {
+        ffi::qsqldatabase_add_database(driver, connection_name)
+    }
+
+    /// Returns true if the list of database connections contains the connection name; otherwise returns false.
+    pub fn contains(connection_name: &ffi::QString) -> bool {
+        ffi::qsqldatabase_contains(connection_name)
+    }
+
+    /// Returns the database connection called connection name, which must have been added with [QSqlDatabase::add_database].
+    ///
+    /// If open is true and the database connection is not already open it is opened now.
+    pub fn database(connection_name: &ffi::QString, open: bool) -> UniquePtr<Self> {
+        ffi::qsqldatabase_database(connection_name, open)
+    }
+
+    /// Returns a list of all the available database drivers.
+    pub fn drivers() -> ffi::QStringList {
+        ffi::qsqldatabase_drivers()
+    }
+
+    /// Returns true if a driver called name is available; otherwise returns false.
+    pub fn is_driver_available(name: &ffi::QString) -> bool {
+        ffi::qsqldatabase_is_driver_available(name)
+    }
+
+    /// Returns a text description of the last error that occurred on the database,
+    /// or an empty string if there was no error.
+    pub fn last_error_text(&self) -> ffi::QString {
+        ffi::qsqldatabase_last_error_text(self)
+    }
+
+    /// Removes the database connection with the connection name from the list of database connections.
+    ///
+    /// There should be no open queries on the database connection, and every [QSqlDatabase] for the
+    /// connection should have been dropped, otherwise a resource leak will occur.
+    pub fn remove_database(connection_name: &ffi::QString) {
+        ffi::qsqldatabase_remove_database(connection_name)
+    }
+
+    /// Sets database-specific options, which must be set before the connection is opened.
+    ///
+    /// The format of the options is a semicolon separated list of option names or option=value pairs,
+    /// such as `SQL_ATTR_CONNECTION_TIMEOUT=5` for QODBC.
+    pub fn set_connect_options(self: Pin<&mut Self>, options: &ffi::QString) {
+        ffi::qsqldatabase_set_connect_options(self, options)
+    }
+
+    /// Returns a list of the database's tables.
+    pub fn tables(&self) -> ffi::QStringList {
+        ffi::qsqldatabase_tables(self)
+    }
+}
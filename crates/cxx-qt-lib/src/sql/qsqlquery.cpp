@@ -0,0 +1,88 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_SQL_FEATURE
+#include "cxx-qt-lib/qsqlquery.h"
+
+#include <QtSql/QSqlError>
+#include <QtSql/QSqlRecord>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QSqlQuery>
+qsqlqueryNew(const QSqlDatabase& database)
+{
+  return ::std::make_unique<QSqlQuery>(database);
+}
+
+void
+qsqlqueryAddBindValue(QSqlQuery& query, const QVariant& value)
+{
+  query.addBindValue(value);
+}
+
+void
+qsqlqueryBindValue(QSqlQuery& query,
+                   const QString& placeholder,
+                   const QVariant& value)
+{
+  query.bindValue(placeholder, value);
+}
+
+::std::int32_t
+qsqlqueryColumnCount(const QSqlQuery& query)
+{
+  return static_cast<::std::int32_t>(query.record().count());
+}
+
+bool
+qsqlqueryExecQuery(QSqlQuery& query, const QString& text)
+{
+  return query.exec(text);
+}
+
+QString
+qsqlqueryLastErrorText(const QSqlQuery& query)
+{
+  return query.lastError().text();
+}
+
+QList<QVariant>
+qsqlqueryRowValues(const QSqlQuery& query)
+{
+  const auto record = query.record();
+  QList<QVariant> values;
+  values.reserve(record.count());
+  for (int i = 0; i < record.count(); ++i) {
+    values.append(query.value(i));
+  }
+  return values;
+}
+
+bool
+qsqlquerySeek(QSqlQuery& query, ::std::int32_t index)
+{
+  return query.seek(static_cast<int>(index));
+}
+
+QVariant
+qsqlqueryValue(const QSqlQuery& query, ::std::int32_t index)
+{
+  return query.value(static_cast<int>(index));
+}
+
+QVariant
+qsqlqueryValueByName(const QSqlQuery& query, const QString& name)
+{
+  return query.value(name);
+}
+
+}
+}
+
+#endif
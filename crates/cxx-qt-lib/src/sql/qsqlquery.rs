@@ -0,0 +1,237 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QList, QVariant};
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qlist.h");
+        type QList_QVariant = crate::QList<QVariant>;
+        include!("cxx-qt-lib/qsqldatabase.h");
+        type QSqlDatabase = crate::QSqlDatabase;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+
+        include!("cxx-qt-lib/qsqlquery.h");
+        /// The QSqlQuery class provides a means of executing and manipulating SQL statements.
+        ///
+        /// A query is positioned before the first record after it has been executed,
+        /// so [QSqlQuery::next] must be called before values of the first record can be read.
+        ///
+        /// ```ignore
+        /// let mut query = QSqlQuery::new(&database);
+        /// query.pin_mut().prepare(&QString::from("SELECT id, name FROM users WHERE active = :active"));
+        /// query.pin_mut().bind_value(&QString::from(":active"), &QVariant::from(&true));
+        /// if query.pin_mut().exec() {
+        ///     while query.pin_mut().next() {
+        ///         let id: i32 = query.value(0).value().unwrap_or_default();
+        ///         let name: QString = query.value(1).value().unwrap_or_default();
+        ///     }
+        /// }
+        /// ```
+        type QSqlQuery;
+
+        /// Returns the current internal position of the query, the first record is at position zero.
+        ///
+        /// If the position is invalid, the function returns a negative value.
+        fn at(self: &QSqlQuery) -> i32;
+
+        /// Clears the result set and releases any resources held by the query, setting the query state to inactive.
+        fn clear(self: Pin<&mut QSqlQuery>);
+
+        /// Executes a previously prepared SQL query, returns true if the query executed successfully; otherwise returns false.
+        ///
+        /// Values which have been bound with [QSqlQuery::bind_value] or [QSqlQuery::add_bind_value] are used.
+        fn exec(self: Pin<&mut QSqlQuery>) -> bool;
+
+        /// Instruct the database driver that no more data will be fetched from this query until it is re-executed.
+        ///
+        /// This frees resources such as locks or cursors, which is useful for a select statement that has not been fully read.
+        fn finish(self: Pin<&mut QSqlQuery>);
+
+        /// Retrieves the first record in the result, if available, and positions the query on the retrieved record.
+        fn first(self: Pin<&mut QSqlQuery>) -> bool;
+
+        /// Returns true if the query is active, an active query has been executed successfully but is not yet finished.
+        #[rust_name = "is_active"]
+        fn isActive(self: &QSqlQuery) -> bool;
+
+        /// Returns true if the current query is a SQL SELECT statement; otherwise returns false.
+        #[rust_name = "is_select"]
+        fn isSelect(self: &QSqlQuery) -> bool;
+
+        /// Returns true if the query is currently positioned on a valid record; otherwise returns false.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QSqlQuery) -> bool;
+
+        /// Retrieves the last record in the result, if available, and positions the query on the retrieved record.
+        fn last(self: Pin<&mut QSqlQuery>) -> bool;
+
+        /// Returns the object ID of the most recent inserted row if the database supports it,
+        /// otherwise an invalid QVariant is returned.
+        #[rust_name = "last_insert_id"]
+        fn lastInsertId(self: &QSqlQuery) -> QVariant;
+
+        /// Returns the text of the current query being used, or an empty string if there is no current query text.
+        #[rust_name = "last_query"]
+        fn lastQuery(self: &QSqlQuery) -> QString;
+
+        /// Retrieves the next record in the result, if available, and positions the query on the retrieved record.
+        ///
+        /// Returns false when the query is positioned after the last record.
+        fn next(self: Pin<&mut QSqlQuery>) -> bool;
+
+        /// Returns the number of rows affected by the result's SQL statement, or -1 if it cannot be determined.
+        #[rust_name = "num_rows_affected"]
+        fn numRowsAffected(self: &QSqlQuery) -> i32;
+
+        /// Prepares the SQL query for execution, returns true if the query is prepared successfully; otherwise returns false.
+        ///
+        /// The query may contain placeholders for binding values, both ODBC style (?) and Oracle style (:name) placeholders are supported.
+        fn prepare(self: Pin<&mut QSqlQuery>, query: &QString) -> bool;
+
+        /// Retrieves the previous record in the result, if available, and positions the query on the retrieved record.
+        fn previous(self: Pin<&mut QSqlQuery>) -> bool;
+
+        /// Returns the size of the result (number of rows returned), or -1 if the size cannot be determined
+        /// or if the database does not support reporting information about query sizes.
+        fn size(self: &QSqlQuery) -> i32;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qsqlquery_new"]
+        fn qsqlqueryNew(database: &QSqlDatabase) -> UniquePtr<QSqlQuery>;
+        #[doc(hidden)]
+        #[rust_name = "qsqlquery_add_bind_value"]
+        fn qsqlqueryAddBindValue(query: Pin<&mut QSqlQuery>, value: &QVariant);
+        #[doc(hidden)]
+        #[rust_name = "qsqlquery_bind_value"]
+        fn qsqlqueryBindValue(query: Pin<&mut QSqlQuery>, placeholder: &QString, value: &QVariant);
+        #[doc(hidden)]
+        #[rust_name = "qsqlquery_column_count"]
+        fn qsqlqueryColumnCount(query: &QSqlQuery) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qsqlquery_exec_query"]
+        fn qsqlqueryExecQuery(query: Pin<&mut QSqlQuery>, text: &QString) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qsqlquery_last_error_text"]
+        fn qsqlqueryLastErrorText(query: &QSqlQuery) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qsqlquery_row_values"]
+        fn qsqlqueryRowValues(query: &QSqlQuery) -> QList_QVariant;
+        #[doc(hidden)]
+        #[rust_name = "qsqlquery_seek"]
+        fn qsqlquerySeek(query: Pin<&mut QSqlQuery>, index: i32) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qsqlquery_value"]
+        fn qsqlqueryValue(query: &QSqlQuery, index: i32) -> QVariant;
+        #[doc(hidden)]
+        #[rust_name = "qsqlquery_value_by_name"]
+        fn qsqlqueryValueByName(query: &QSqlQuery, name: &QString) -> QVariant;
+    }
+}
+
+pub use ffi::QSqlQuery;
+
+impl QSqlQuery {
+    /// Adds the value to the list of values when using positional value binding,
+    /// the order of the calls determines which placeholder a value will be bound to in the prepared query.
+    pub fn add_bind_value(self: Pin<&mut Self>, value: &ffi::QVariant) {
+        ffi::qsqlquery_add_bind_value(self, value)
+    }
+
+    /// Set the placeholder, such as `:name`, to be bound to value in the prepared statement.
+    pub fn bind_value(self: Pin<&mut Self>, placeholder: &ffi::QString, value: &ffi::QVariant) {
+        ffi::qsqlquery_bind_value(self, placeholder, value)
+    }
+
+    /// Returns the number of columns in the result of the query, or zero if the query is not a select.
+    pub fn column_count(&self) -> i32 {
+        ffi::qsqlquery_column_count(self)
+    }
+
+    /// Executes the SQL in query, returns true and sets the query state to active if the query was successful;
+    /// otherwise returns false.
+    ///
+    /// Use [QSqlQuery::prepare] and bound values instead when the query contains values from the user.
+    pub fn exec_query(self: Pin<&mut Self>, query: &ffi::QString) -> bool {
+        ffi::qsqlquery_exec_query(self, query)
+    }
+
+    /// Returns a text description of the last error that occurred with this query,
+    /// or an empty string if there was no error.
+    pub fn last_error_text(&self) -> ffi::QString {
+        ffi::qsqlquery_last_error_text(self)
+    }
+
+    /// Constructs a QSqlQuery object using the database connection.
+    pub fn new(database: &ffi::QSqlDatabase) -> UniquePtr<Self> {
+        ffi::qsqlquery_new(database)
+    }
+
+    /// Returns the values of every column in the current record, which can be used to feed a model a whole row at a time.
+    ///
+    /// Use [QSqlQuery::rows] to read the values of each of the remaining records.
+    pub fn row_values(&self) -> ffi::QList_QVariant {
+        ffi::qsqlquery_row_values(self)
+    }
+
+    /// Returns an iterator over the remaining records of the query, which moves the query to each record
+    /// with [QSqlQuery::next] and yields its [QSqlQuery::row_values].
+    ///
+    /// ```ignore
+    /// if query.pin_mut().exec_query(&QString::from("SELECT id, name FROM users")) {
+    ///     for row in query.pin_mut().rows() {
+    ///         let id: i32 = row.get(0).and_then(|id| id.value()).unwrap_or_default();
+    ///     }
+    /// }
+    /// ```
+    pub fn rows(self: Pin<&mut Self>) -> QSqlQueryRows<'_> {
+        QSqlQueryRows { query: self }
+    }
+
+    /// Retrieves the record at position index, if available, and positions the query on the retrieved record.
+    ///
+    /// The first record is at position 0, returns false if the record could not be retrieved.
+    pub fn seek(self: Pin<&mut Self>, index: i32) -> bool {
+        ffi::qsqlquery_seek(self, index)
+    }
+
+    /// Returns the value of the field at index in the current record, or an invalid QVariant if the index is out of range.
+    ///
+    /// The fields are numbered from left to right using the text of the SELECT statement.
+    pub fn value(&self, index: i32) -> ffi::QVariant {
+        ffi::qsqlquery_value(self, index)
+    }
+
+    /// Returns the value of the field called name in the current record, or an invalid QVariant if it does not exist.
+    pub fn value_by_name(&self, name: &ffi::QString) -> ffi::QVariant {
+        ffi::qsqlquery_value_by_name(self, name)
+    }
+}
+
+/// An iterator over the remaining records of a [QSqlQuery], which is created by [QSqlQuery::rows]
+pub struct QSqlQueryRows<'a> {
+    query: Pin<&'a mut QSqlQuery>,
+}
+
+impl Iterator for QSqlQueryRows<'_> {
+    type Item = QList<QVariant>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.query.as_mut().next() {
+            Some(self.query.row_values())
+        } else {
+            None
+        }
+    }
+}
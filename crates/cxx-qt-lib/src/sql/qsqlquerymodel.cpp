@@ -0,0 +1,51 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_SQL_FEATURE
+#include "cxx-qt-lib/qsqlquerymodel.h"
+
+#include <QtSql/QSqlError>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QSqlQueryModel>
+qsqlquerymodelNew()
+{
+  return ::std::make_unique<QSqlQueryModel>();
+}
+
+QString
+qsqlquerymodelLastErrorText(const QSqlQueryModel& model)
+{
+  return model.lastError().text();
+}
+
+void
+qsqlquerymodelSetExecutedQuery(QSqlQueryModel& model,
+                               ::std::unique_ptr<QSqlQuery> query)
+{
+  // Qt 6.2 takes ownership of the query, and deprecates copying it
+#if QT_VERSION >= QT_VERSION_CHECK(6, 2, 0)
+  model.setQuery(::std::move(*query));
+#else
+  model.setQuery(*query);
+#endif
+}
+
+void
+qsqlquerymodelSetQuery(QSqlQueryModel& model,
+                       const QString& query,
+                       const QSqlDatabase& database)
+{
+  model.setQuery(query, database);
+}
+
+}
+}
+
+#endif
@@ -0,0 +1,122 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qsqlquerymodel.h");
+
+        include!("cxx-qt-lib/qabstractitemmodel.h");
+        type QAbstractItemModel = crate::QAbstractItemModel;
+        include!("cxx-qt-lib/qmodelindex.h");
+        type QModelIndex = crate::QModelIndex;
+        include!("cxx-qt-lib/qsqldatabase.h");
+        type QSqlDatabase = crate::QSqlDatabase;
+        include!("cxx-qt-lib/qsqlquery.h");
+        type QSqlQuery = crate::QSqlQuery;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+
+        /// The QSqlQueryModel class provides a read-only data model for SQL result sets.
+        ///
+        /// The model can be used from a bridge as `type QSqlQueryModel = cxx_qt_lib::QSqlQueryModel;`
+        /// and exposed to QML as a `*mut QSqlQueryModel` property, where each column of the result
+        /// is available to the delegates of a TableView as the display role.
+        ///
+        /// ```ignore
+        /// let mut model = QSqlQueryModel::new();
+        /// model.pin_mut().set_query(&QString::from("SELECT name, total FROM orders"), &database);
+        /// ```
+        type QSqlQueryModel;
+
+        /// Returns true if it is possible to read more rows from the database.
+        #[rust_name = "can_fetch_more"]
+        fn canFetchMore(self: &QSqlQueryModel, parent: &QModelIndex) -> bool;
+
+        /// Clears the model and releases any acquired resource.
+        fn clear(self: Pin<&mut QSqlQueryModel>);
+
+        /// Returns the number of columns in the result of the query.
+        #[rust_name = "column_count"]
+        fn columnCount(self: &QSqlQueryModel, index: &QModelIndex) -> i32;
+
+        /// Returns the value for the specified item and role.
+        fn data(self: &QSqlQueryModel, item: &QModelIndex, role: i32) -> QVariant;
+
+        /// Fetches more rows from a database, this only affects databases that don't report back the size of a query.
+        #[rust_name = "fetch_more"]
+        fn fetchMore(self: Pin<&mut QSqlQueryModel>, parent: &QModelIndex);
+
+        /// Returns the number of rows which have been fetched from the result of the query.
+        ///
+        /// If the database does not report the size of a query, more rows are fetched as a view scrolls.
+        #[rust_name = "row_count"]
+        fn rowCount(self: &QSqlQueryModel, parent: &QModelIndex) -> i32;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qsqlquerymodel_new"]
+        fn qsqlquerymodelNew() -> UniquePtr<QSqlQueryModel>;
+        #[doc(hidden)]
+        #[rust_name = "qsqlquerymodel_last_error_text"]
+        fn qsqlquerymodelLastErrorText(model: &QSqlQueryModel) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qsqlquerymodel_set_executed_query"]
+        fn qsqlquerymodelSetExecutedQuery(
+            model: Pin<&mut QSqlQueryModel>,
+            query: UniquePtr<QSqlQuery>,
+        );
+        #[doc(hidden)]
+        #[rust_name = "qsqlquerymodel_set_query"]
+        fn qsqlquerymodelSetQuery(
+            model: Pin<&mut QSqlQueryModel>,
+            query: &QString,
+            database: &QSqlDatabase,
+        );
+        #[doc(hidden)]
+        #[cxx_name = "qabstractitemmodelUpcast"]
+        #[rust_name = "qsqlquerymodel_upcast"]
+        unsafe fn upcast(model: *mut QSqlQueryModel) -> *mut QAbstractItemModel;
+    }
+}
+
+pub use ffi::QSqlQueryModel;
+
+impl QSqlQueryModel {
+    /// Returns a pointer to the model as a QAbstractItemModel, so that it can be used as the source of a proxy model.
+    pub fn as_abstract_item_model_ptr(self: Pin<&mut Self>) -> *mut ffi::QAbstractItemModel {
+        unsafe { ffi::qsqlquerymodel_upcast(self.get_unchecked_mut()) }
+    }
+
+    /// Returns a text description of the last error that occurred with the query of the model,
+    /// or an empty string if there was no error.
+    pub fn last_error_text(&self) -> ffi::QString {
+        ffi::qsqlquerymodel_last_error_text(self)
+    }
+
+    /// Creates an empty QSqlQueryModel.
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qsqlquerymodel_new()
+    }
+
+    /// Resets the model and sets the data provider to be the given query, which must already be active.
+    ///
+    /// This is used to show the result of a [QSqlQuery] with bound values, the model takes ownership of the query.
+    pub fn set_executed_query(self: Pin<&mut Self>, query: UniquePtr<ffi::QSqlQuery>) {
+        ffi::qsqlquerymodel_set_executed_query(self, query)
+    }
+
+    /// Executes the query for the given database connection, and resets the model to show its result.
+    pub fn set_query(self: Pin<&mut Self>, query: &ffi::QString, database: &ffi::QSqlDatabase) {
+        ffi::qsqlquerymodel_set_query(self, query, database)
+    }
+}
@@ -0,0 +1,37 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_SQL_FEATURE
+#include "cxx-qt-lib/qsqltablemodel.h"
+
+#include <QtSql/QSqlError>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QSqlTableModel>
+qsqltablemodelNew(const QSqlDatabase& database)
+{
+  return ::std::make_unique<QSqlTableModel>(nullptr, database);
+}
+
+bool
+qsqltablemodelIsDirty(const QSqlTableModel& model)
+{
+  return model.isDirty();
+}
+
+QString
+qsqltablemodelLastErrorText(const QSqlTableModel& model)
+{
+  return model.lastError().text();
+}
+
+}
+}
+
+#endif
@@ -0,0 +1,181 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+#[cxx::bridge]
+mod ffi {
+    /// When the changes which are made to a [QSqlTableModel] are written to the database.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QSqlTableModelEditStrategy {
+        /// All changes to the model will be applied immediately to the database.
+        OnFieldChange = 0,
+        /// Changes to a row will be applied when the user selects a different row.
+        OnRowChange = 1,
+        /// All changes will be cached in the model until either submit_all() or revert_all() is called.
+        OnManualSubmit = 2,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qsqltablemodel.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QSqlTableModelEditStrategy;
+
+        include!("cxx-qt-lib/qabstractitemmodel.h");
+        type QAbstractItemModel = crate::QAbstractItemModel;
+        include!("cxx-qt-lib/qmodelindex.h");
+        type QModelIndex = crate::QModelIndex;
+        include!("cxx-qt-lib/qsqldatabase.h");
+        type QSqlDatabase = crate::QSqlDatabase;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+
+        /// The QSqlTableModel class provides an editable data model for a single database table.
+        ///
+        /// The model can be used from a bridge as `type QSqlTableModel = cxx_qt_lib::QSqlTableModel;`
+        /// and exposed to QML as a `*mut QSqlTableModel` property, edits from the delegates of a view
+        /// are written to the database according to the [QSqlTableModelEditStrategy].
+        ///
+        /// ```ignore
+        /// let mut model = QSqlTableModel::new(&database);
+        /// model.pin_mut().set_table(&QString::from("customers"));
+        /// model.pin_mut().set_edit_strategy(QSqlTableModelEditStrategy::OnManualSubmit);
+        /// model.pin_mut().set_filter(&QString::from("country = 'Sweden'"));
+        /// model.pin_mut().select();
+        /// ```
+        type QSqlTableModel;
+
+        /// Returns the number of columns of the table.
+        #[rust_name = "column_count"]
+        fn columnCount(self: &QSqlTableModel, index: &QModelIndex) -> i32;
+
+        /// Returns the data for the item at index for the role, including any changes which have not been submitted.
+        fn data(self: &QSqlTableModel, index: &QModelIndex, role: i32) -> QVariant;
+
+        /// Returns the current edit strategy.
+        #[rust_name = "edit_strategy"]
+        fn editStrategy(self: &QSqlTableModel) -> QSqlTableModelEditStrategy;
+
+        /// Returns the index of the field name, or -1 if no corresponding field exists in the model.
+        #[rust_name = "field_index"]
+        fn fieldIndex(self: &QSqlTableModel, field_name: &QString) -> i32;
+
+        /// Returns the currently set filter.
+        fn filter(self: &QSqlTableModel) -> QString;
+
+        /// Inserts count empty rows at position row, which are written to the database
+        /// according to the edit strategy once they have been filled in.
+        #[rust_name = "insert_rows"]
+        fn insertRows(
+            self: Pin<&mut QSqlTableModel>,
+            row: i32,
+            count: i32,
+            parent: &QModelIndex,
+        ) -> bool;
+
+        /// Removes count rows starting at row, which are deleted from the database
+        /// according to the edit strategy.
+        #[rust_name = "remove_rows"]
+        fn removeRows(
+            self: Pin<&mut QSqlTableModel>,
+            row: i32,
+            count: i32,
+            parent: &QModelIndex,
+        ) -> bool;
+
+        /// Reverts all pending changes.
+        #[rust_name = "revert_all"]
+        fn revertAll(self: Pin<&mut QSqlTableModel>);
+
+        /// Returns the number of rows of the table, including rows which have been inserted but not submitted.
+        #[rust_name = "row_count"]
+        fn rowCount(self: &QSqlTableModel, parent: &QModelIndex) -> i32;
+
+        /// Populates the model with data from the table that was set via set_table(), using the specified filter.
+        ///
+        /// Returns true if successful; otherwise returns false.
+        fn select(self: Pin<&mut QSqlTableModel>) -> bool;
+
+        /// Sets the data value for the item index for the role to value,
+        /// which is written to the database according to the edit strategy.
+        ///
+        /// Returns true if the value could be set or false on error, for example if index is out of bounds.
+        #[rust_name = "set_data"]
+        fn setData(
+            self: Pin<&mut QSqlTableModel>,
+            index: &QModelIndex,
+            value: &QVariant,
+            role: i32,
+        ) -> bool;
+
+        /// Sets the strategy for editing values in the database to strategy, which discards any pending changes.
+        #[rust_name = "set_edit_strategy"]
+        fn setEditStrategy(self: Pin<&mut QSqlTableModel>, strategy: QSqlTableModelEditStrategy);
+
+        /// Sets the current filter to filter, which is an SQL WHERE clause without the keyword WHERE.
+        ///
+        /// The filter will be applied the next time select() is called.
+        #[rust_name = "set_filter"]
+        fn setFilter(self: Pin<&mut QSqlTableModel>, filter: &QString);
+
+        /// Sets the database table on which the model operates to table_name, this does not select data from the table.
+        #[rust_name = "set_table"]
+        fn setTable(self: Pin<&mut QSqlTableModel>, table_name: &QString);
+
+        /// Submits all pending changes and returns true on success, or false on error.
+        #[rust_name = "submit_all"]
+        fn submitAll(self: Pin<&mut QSqlTableModel>) -> bool;
+
+        /// Returns the name of the currently selected table.
+        #[rust_name = "table_name"]
+        fn tableName(self: &QSqlTableModel) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qsqltablemodel_new"]
+        fn qsqltablemodelNew(database: &QSqlDatabase) -> UniquePtr<QSqlTableModel>;
+        #[doc(hidden)]
+        #[rust_name = "qsqltablemodel_is_dirty"]
+        fn qsqltablemodelIsDirty(model: &QSqlTableModel) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qsqltablemodel_last_error_text"]
+        fn qsqltablemodelLastErrorText(model: &QSqlTableModel) -> QString;
+        #[doc(hidden)]
+        #[cxx_name = "qabstractitemmodelUpcast"]
+        #[rust_name = "qsqltablemodel_upcast"]
+        unsafe fn upcast(model: *mut QSqlTableModel) -> *mut QAbstractItemModel;
+    }
+}
+
+pub use ffi::{QSqlTableModel, QSqlTableModelEditStrategy};
+
+impl QSqlTableModel {
+    /// Returns a pointer to the model as a QAbstractItemModel, so that it can be used as the source of a proxy model.
+    pub fn as_abstract_item_model_ptr(self: Pin<&mut Self>) -> *mut ffi::QAbstractItemModel {
+        unsafe { ffi::qsqltablemodel_upcast(self.get_unchecked_mut()) }
+    }
+
+    /// Returns true if the model contains modified values that have not been committed to the database.
+    pub fn is_dirty(&self) -> bool {
+        ffi::qsqltablemodel_is_dirty(self)
+    }
+
+    /// Returns a text description of the last error that occurred on the table,
+    /// such as a failed submit, or an empty string if there was no error.
+    pub fn last_error_text(&self) -> ffi::QString {
+        ffi::qsqltablemodel_last_error_text(self)
+    }
+
+    /// Creates an empty QSqlTableModel which operates on the given database connection.
+    pub fn new(database: &ffi::QSqlDatabase) -> UniquePtr<Self> {
+        ffi::qsqltablemodel_new(database)
+    }
+}
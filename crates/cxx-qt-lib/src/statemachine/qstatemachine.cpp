@@ -0,0 +1,78 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_STATEMACHINE_FEATURE
+#include "cxx-qt-lib/qstatemachine.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QStateMachine>
+qstatemachineNew()
+{
+  return ::std::make_unique<QStateMachine>();
+}
+
+bool
+qstatemachineAddState(QStateMachine& machine, const QString& name)
+{
+  if (qstatemachineFindState(machine, name) != nullptr) {
+    return false;
+  }
+
+  // The state is a child of the machine so it is destroyed with it
+  auto* state = new QState(&machine);
+  state->setObjectName(name);
+  return true;
+}
+
+bool
+qstatemachineAddFinalState(QStateMachine& machine, const QString& name)
+{
+  if (qstatemachineFindState(machine, name) != nullptr) {
+    return false;
+  }
+
+  auto* state = new QFinalState(&machine);
+  state->setObjectName(name);
+  return true;
+}
+
+QStringList
+qstatemachineActiveStates(const QStateMachine& machine)
+{
+  QStringList names;
+  for (const auto* state : machine.configuration()) {
+    if (state->parent() == &machine) {
+      names.append(state->objectName());
+    }
+  }
+  names.sort();
+  return names;
+}
+
+QAbstractState*
+qstatemachineFindState(const QStateMachine& machine, const QString& name)
+{
+  return machine.findChild<QAbstractState*>(name, Qt::FindDirectChildrenOnly);
+}
+
+bool
+qstatemachineSetInitialState(QStateMachine& machine, const QString& name)
+{
+  auto* state = qstatemachineFindState(machine, name);
+  if (state == nullptr) {
+    return false;
+  }
+
+  machine.setInitialState(state);
+  return true;
+}
+
+}
+}
+#endif
@@ -0,0 +1,155 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstatemachine.h");
+
+        include!("cxx-qt-lib/qmetaobjectconnection.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QMetaObjectConnection = crate::QMetaObjectConnection;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = crate::QStringList;
+
+        /// The QStateMachine class provides a hierarchical finite state machine.
+        ///
+        /// States are identified by their name, and transitions between them are driven by
+        /// the signals of QObjects, including the signals of QObjects defined by CXX-Qt.
+        /// The closures connected to a state are called by the Qt event loop when it is entered,
+        /// which can be used to update a property that QML visual states are bound to.
+        ///
+        /// ```ignore
+        /// let mut machine = QStateMachine::new();
+        /// machine.pin_mut().add_state(&QString::from("idle"));
+        /// machine.pin_mut().add_state(&QString::from("running"));
+        /// machine.pin_mut().set_initial_state(&QString::from("idle"));
+        /// machine
+        ///     .pin_mut()
+        ///     .on_state_entered(&QString::from("running"), |name| println!("Entered {name}"))
+        ///     .release();
+        /// machine.pin_mut().start();
+        /// ```
+        type QStateMachine;
+
+        /// Returns whether this state machine is running.
+        #[rust_name = "is_running"]
+        fn isRunning(self: &QStateMachine) -> bool;
+
+        /// Starts this state machine.
+        /// The machine will reset its configuration and transition to the initial state.
+        ///
+        /// When a final top-level state is entered, the machine stops running.
+        fn start(self: Pin<&mut QStateMachine>);
+
+        /// Stops this state machine.
+        fn stop(self: Pin<&mut QStateMachine>);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qstatemachine_new"]
+        fn qstatemachineNew() -> UniquePtr<QStateMachine>;
+        #[doc(hidden)]
+        #[rust_name = "qstatemachine_active_states"]
+        fn qstatemachineActiveStates(machine: &QStateMachine) -> QStringList;
+        #[doc(hidden)]
+        #[rust_name = "qstatemachine_add_final_state"]
+        fn qstatemachineAddFinalState(machine: Pin<&mut QStateMachine>, name: &QString) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qstatemachine_add_state"]
+        fn qstatemachineAddState(machine: Pin<&mut QStateMachine>, name: &QString) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qstatemachine_on_state_entered"]
+        fn qstatemachineOnStateEntered(
+            machine: Pin<&mut QStateMachine>,
+            name: &QString,
+            callback: Box<QStateMachineCallback>,
+        ) -> Result<QMetaObjectConnection>;
+        #[doc(hidden)]
+        #[rust_name = "qstatemachine_set_initial_state"]
+        fn qstatemachineSetInitialState(machine: Pin<&mut QStateMachine>, name: &QString) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        type QStateMachineCallback;
+
+        #[doc(hidden)]
+        fn call(self: &mut QStateMachineCallback, name: &QString);
+    }
+}
+
+pub use ffi::QStateMachine;
+
+/// A Rust closure which is called by a connection of a [QStateMachine]
+#[doc(hidden)]
+pub struct QStateMachineCallback {
+    callback: Box<dyn FnMut(&ffi::QString)>,
+}
+
+impl QStateMachineCallback {
+    fn new(callback: impl FnMut(&ffi::QString) + 'static) -> Box<Self> {
+        Box::new(Self {
+            callback: Box::new(callback),
+        })
+    }
+
+    fn call(&mut self, name: &ffi::QString) {
+        (self.callback)(name);
+    }
+}
+
+impl QStateMachine {
+    /// Constructs a new state machine without any states.
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qstatemachine_new()
+    }
+
+    /// Returns the names of the states that are currently active, sorted by name.
+    pub fn active_states(&self) -> ffi::QStringList {
+        ffi::qstatemachine_active_states(self)
+    }
+
+    /// Adds a final state with the given name, the machine stops running when it is entered.
+    ///
+    /// Returns false if a state with the name already exists.
+    pub fn add_final_state(self: Pin<&mut Self>, name: &ffi::QString) -> bool {
+        ffi::qstatemachine_add_final_state(self, name)
+    }
+
+    /// Adds a state with the given name, the state is owned by the machine.
+    ///
+    /// Returns false if a state with the name already exists.
+    pub fn add_state(self: Pin<&mut Self>, name: &ffi::QString) -> bool {
+        ffi::qstatemachine_add_state(self, name)
+    }
+
+    /// Connect a closure which is called with the name of the state each time it is entered.
+    ///
+    /// The closure is called on the thread of the machine, and is dropped when the connection is disconnected.
+    /// Returns an error if there is no state with the name.
+    pub fn on_state_entered(
+        self: Pin<&mut Self>,
+        name: &ffi::QString,
+        callback: impl FnMut(&ffi::QString) + 'static,
+    ) -> Result<ffi::QMetaObjectConnection, cxx::Exception> {
+        ffi::qstatemachine_on_state_entered(self, name, QStateMachineCallback::new(callback))
+    }
+
+    /// Sets the state that the machine enters when it is started.
+    ///
+    /// Returns false if there is no state with the name.
+    pub fn set_initial_state(self: Pin<&mut Self>, name: &ffi::QString) -> bool {
+        ffi::qstatemachine_set_initial_state(self, name)
+    }
+}
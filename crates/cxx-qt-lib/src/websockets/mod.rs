@@ -0,0 +1,10 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+mod qwebsocket;
+pub use qwebsocket::{QAbstractSocketSocketState, QWebSocket};
+
+mod qwebsocketserver;
+pub use qwebsocketserver::{QWebSocketServer, QWebSocketServerSslMode};
@@ -0,0 +1,41 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_WEBSOCKETS_FEATURE
+#include "cxx-qt-lib/qwebsocket.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QWebSocket>
+qwebsocketNew()
+{
+  return ::std::make_unique<QWebSocket>();
+}
+
+void
+qwebsocketClose(QWebSocket& socket)
+{
+  socket.close();
+}
+
+::std::int64_t
+qwebsocketSendBinaryMessage(QWebSocket& socket, const QByteArray& data)
+{
+  return static_cast<::std::int64_t>(socket.sendBinaryMessage(data));
+}
+
+::std::int64_t
+qwebsocketSendTextMessage(QWebSocket& socket, const QString& message)
+{
+  return static_cast<::std::int64_t>(socket.sendTextMessage(message));
+}
+
+}
+}
+
+#endif
@@ -0,0 +1,273 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+#[cxx::bridge]
+mod ffi {
+    /// The states of a socket.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QAbstractSocketSocketState {
+        /// The socket is not connected.
+        UnconnectedState = 0,
+        /// The socket is performing a host name lookup.
+        HostLookupState = 1,
+        /// The socket has started establishing a connection.
+        ConnectingState = 2,
+        /// A connection is established.
+        ConnectedState = 3,
+        /// The socket is bound to an address and port.
+        BoundState = 4,
+        /// For internal use only.
+        ListeningState = 5,
+        /// The socket is about to close.
+        ClosingState = 6,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qwebsocket.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QAbstractSocketSocketState;
+
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qmetaobjectconnection.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QMetaObjectConnection = crate::QMetaObjectConnection;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = crate::QUrl;
+
+        /// The QWebSocket class implements a TCP socket that talks the WebSocket protocol.
+        ///
+        /// The socket uses the proxy and TLS configuration of the Qt application,
+        /// with messages delivered to Rust closures by the Qt event loop of the thread of the socket.
+        ///
+        /// ```ignore
+        /// let mut socket = QWebSocket::new();
+        /// socket
+        ///     .pin_mut()
+        ///     .on_text_message_received(|message| println!("Received: {message}"))
+        ///     .release();
+        /// socket.pin_mut().open(&QUrl::from("wss://example.com/socket"));
+        /// ```
+        type QWebSocket;
+
+        /// Aborts the current socket and resets the socket, unlike close() this closes the socket immediately.
+        fn abort(self: Pin<&mut QWebSocket>);
+
+        /// Returns a human-readable description of the last error that occurred.
+        #[rust_name = "error_string"]
+        fn errorString(self: &QWebSocket) -> QString;
+
+        /// Writes as much as possible from the internal write buffer to the underlying network socket, without blocking.
+        ///
+        /// Returns true if any data was written.
+        fn flush(self: Pin<&mut QWebSocket>) -> bool;
+
+        /// Returns true if the socket is ready for reading and writing, otherwise returns false.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QWebSocket) -> bool;
+
+        /// Opens a WebSocket connection using the given url.
+        ///
+        /// If the url contains newline characters (\r\n), then the error signal will be emitted.
+        fn open(self: Pin<&mut QWebSocket>, url: &QUrl);
+
+        /// Returns the current origin.
+        fn origin(self: &QWebSocket) -> QString;
+
+        /// Pings the server to indicate that the connection is still alive, the payload can be at most 125 bytes.
+        fn ping(self: Pin<&mut QWebSocket>, payload: &QByteArray);
+
+        /// Returns the url the socket is connected to or will connect to.
+        #[rust_name = "request_url"]
+        fn requestUrl(self: &QWebSocket) -> QUrl;
+
+        /// Returns the current state of the socket.
+        fn state(self: &QWebSocket) -> QAbstractSocketSocketState;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qwebsocket_new"]
+        fn qwebsocketNew() -> UniquePtr<QWebSocket>;
+        #[doc(hidden)]
+        #[rust_name = "qwebsocket_close"]
+        fn qwebsocketClose(socket: Pin<&mut QWebSocket>);
+        #[doc(hidden)]
+        #[rust_name = "qwebsocket_on_binary_message_received"]
+        fn qwebsocketOnBinaryMessageReceived(
+            socket: Pin<&mut QWebSocket>,
+            callback: Box<QWebSocketBinaryMessageCallback>,
+        ) -> QMetaObjectConnection;
+        #[doc(hidden)]
+        #[rust_name = "qwebsocket_on_connected"]
+        fn qwebsocketOnConnected(
+            socket: Pin<&mut QWebSocket>,
+            callback: Box<QWebSocketCallback>,
+        ) -> QMetaObjectConnection;
+        #[doc(hidden)]
+        #[rust_name = "qwebsocket_on_disconnected"]
+        fn qwebsocketOnDisconnected(
+            socket: Pin<&mut QWebSocket>,
+            callback: Box<QWebSocketCallback>,
+        ) -> QMetaObjectConnection;
+        #[doc(hidden)]
+        #[rust_name = "qwebsocket_on_text_message_received"]
+        fn qwebsocketOnTextMessageReceived(
+            socket: Pin<&mut QWebSocket>,
+            callback: Box<QWebSocketTextMessageCallback>,
+        ) -> QMetaObjectConnection;
+        #[doc(hidden)]
+        #[rust_name = "qwebsocket_send_binary_message"]
+        fn qwebsocketSendBinaryMessage(socket: Pin<&mut QWebSocket>, data: &QByteArray) -> i64;
+        #[doc(hidden)]
+        #[rust_name = "qwebsocket_send_text_message"]
+        fn qwebsocketSendTextMessage(socket: Pin<&mut QWebSocket>, message: &QString) -> i64;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        type QWebSocketCallback;
+        #[doc(hidden)]
+        type QWebSocketBinaryMessageCallback;
+        #[doc(hidden)]
+        type QWebSocketTextMessageCallback;
+
+        #[doc(hidden)]
+        fn call(self: &mut QWebSocketCallback);
+        #[doc(hidden)]
+        fn call(self: &mut QWebSocketBinaryMessageCallback, data: &QByteArray);
+        #[doc(hidden)]
+        fn call(self: &mut QWebSocketTextMessageCallback, message: &QString);
+    }
+}
+
+pub use ffi::{QAbstractSocketSocketState, QWebSocket};
+
+/// A Rust closure which is called by a connection of a [QWebSocket]
+#[doc(hidden)]
+pub struct QWebSocketCallback {
+    callback: Box<dyn FnMut()>,
+}
+
+impl QWebSocketCallback {
+    fn new(callback: impl FnMut() + 'static) -> Box<Self> {
+        Box::new(Self {
+            callback: Box::new(callback),
+        })
+    }
+
+    fn call(&mut self) {
+        (self.callback)();
+    }
+}
+
+/// A Rust closure which is called when a binary message is received by a [QWebSocket]
+#[doc(hidden)]
+pub struct QWebSocketBinaryMessageCallback {
+    callback: Box<dyn FnMut(&ffi::QByteArray)>,
+}
+
+impl QWebSocketBinaryMessageCallback {
+    fn new(callback: impl FnMut(&ffi::QByteArray) + 'static) -> Box<Self> {
+        Box::new(Self {
+            callback: Box::new(callback),
+        })
+    }
+
+    fn call(&mut self, data: &ffi::QByteArray) {
+        (self.callback)(data);
+    }
+}
+
+/// A Rust closure which is called when a text message is received by a [QWebSocket]
+#[doc(hidden)]
+pub struct QWebSocketTextMessageCallback {
+    callback: Box<dyn FnMut(&ffi::QString)>,
+}
+
+impl QWebSocketTextMessageCallback {
+    fn new(callback: impl FnMut(&ffi::QString) + 'static) -> Box<Self> {
+        Box::new(Self {
+            callback: Box::new(callback),
+        })
+    }
+
+    fn call(&mut self, message: &ffi::QString) {
+        (self.callback)(message);
+    }
+}
+
+impl QWebSocket {
+    /// Gracefully closes the socket with a normal close code.
+    pub fn close(self: Pin<&mut Self>) {
+        ffi::qwebsocket_close(self)
+    }
+
+    /// Constructs a new client socket, which is connected by calling [QWebSocket::open].
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qwebsocket_new()
+    }
+
+    /// Connect a closure which is called with the data of each binary message that is received.
+    ///
+    /// The closure is called on the thread of the socket, and is dropped when the connection is disconnected.
+    pub fn on_binary_message_received(
+        self: Pin<&mut Self>,
+        callback: impl FnMut(&ffi::QByteArray) + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qwebsocket_on_binary_message_received(
+            self,
+            QWebSocketBinaryMessageCallback::new(callback),
+        )
+    }
+
+    /// Connect a closure which is called when the connection has been established, after the handshake.
+    ///
+    /// The closure is called on the thread of the socket, and is dropped when the connection is disconnected.
+    pub fn on_connected(
+        self: Pin<&mut Self>,
+        callback: impl FnMut() + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qwebsocket_on_connected(self, QWebSocketCallback::new(callback))
+    }
+
+    /// Connect a closure which is called when the socket has been disconnected.
+    ///
+    /// The closure is called on the thread of the socket, and is dropped when the connection is disconnected.
+    pub fn on_disconnected(
+        self: Pin<&mut Self>,
+        callback: impl FnMut() + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qwebsocket_on_disconnected(self, QWebSocketCallback::new(callback))
+    }
+
+    /// Connect a closure which is called with each text message that is received.
+    ///
+    /// The closure is called on the thread of the socket, and is dropped when the connection is disconnected.
+    pub fn on_text_message_received(
+        self: Pin<&mut Self>,
+        callback: impl FnMut(&ffi::QString) + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qwebsocket_on_text_message_received(self, QWebSocketTextMessageCallback::new(callback))
+    }
+
+    /// Sends the data as a binary message, returns the number of bytes sent.
+    pub fn send_binary_message(self: Pin<&mut Self>, data: &ffi::QByteArray) -> i64 {
+        ffi::qwebsocket_send_binary_message(self, data)
+    }
+
+    /// Sends the message as a text message, returns the number of bytes sent.
+    pub fn send_text_message(self: Pin<&mut Self>, message: &ffi::QString) -> i64 {
+        ffi::qwebsocket_send_text_message(self, message)
+    }
+}
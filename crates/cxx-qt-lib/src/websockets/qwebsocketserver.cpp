@@ -0,0 +1,42 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#ifdef CXX_QT_WEBSOCKETS_FEATURE
+#include "cxx-qt-lib/qwebsocketserver.h"
+
+#include <QtNetwork/QHostAddress>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QWebSocketServer>
+qwebsocketserverNew(const QString& serverName, QWebSocketServerSslMode mode)
+{
+  return ::std::make_unique<QWebSocketServer>(serverName, mode);
+}
+
+bool
+qwebsocketserverListen(QWebSocketServer& server, ::std::uint16_t port)
+{
+  return server.listen(QHostAddress::Any, static_cast<quint16>(port));
+}
+
+::std::unique_ptr<QWebSocket>
+qwebsocketserverNextPendingConnection(QWebSocketServer& server)
+{
+  // The socket is a child of the server, instead give ownership to Rust
+  auto* socket = server.nextPendingConnection();
+  if (socket != nullptr) {
+    socket->setParent(nullptr);
+  }
+  return ::std::unique_ptr<QWebSocket>(socket);
+}
+
+}
+}
+
+#endif
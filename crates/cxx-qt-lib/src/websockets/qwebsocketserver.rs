@@ -0,0 +1,175 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+#[cxx::bridge]
+mod ffi {
+    /// Whether a QWebSocketServer runs over wss:// or ws://.
+    #[namespace = "rust::cxxqtlib1"]
+    #[repr(i32)]
+    enum QWebSocketServerSslMode {
+        /// The server operates in secure mode, over wss://.
+        SecureMode = 0,
+        /// The server operates in non-secure mode, over ws://.
+        NonSecureMode = 1,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qwebsocketserver.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QWebSocketServerSslMode;
+
+        include!("cxx-qt-lib/qmetaobjectconnection.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QMetaObjectConnection = crate::QMetaObjectConnection;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = crate::QUrl;
+        include!("cxx-qt-lib/qwebsocket.h");
+        type QWebSocket = crate::QWebSocket;
+
+        /// The QWebSocketServer class implements a WebSocket-based server.
+        ///
+        /// Clients which connect are given to Rust as a [QWebSocket](crate::QWebSocket).
+        ///
+        /// ```ignore
+        /// let mut server = QWebSocketServer::new(&QString::from("my_app"), QWebSocketServerSslMode::NonSecureMode);
+        /// let server_ptr = server.pin_mut().get_unchecked_mut() as *mut QWebSocketServer;
+        /// server
+        ///     .pin_mut()
+        ///     .on_new_connection(move || {
+        ///         let server = unsafe { Pin::new_unchecked(&mut *server_ptr) };
+        ///         let socket = server.next_pending_connection();
+        ///     })
+        ///     .release();
+        /// server.pin_mut().listen(8080);
+        /// ```
+        type QWebSocketServer;
+
+        /// Closes the server, the server will no longer listen for incoming connections.
+        fn close(self: Pin<&mut QWebSocketServer>);
+
+        /// Returns a human readable description of the last error that occurred.
+        #[rust_name = "error_string"]
+        fn errorString(self: &QWebSocketServer) -> QString;
+
+        /// Returns true if the server has pending connections, otherwise returns false.
+        #[rust_name = "has_pending_connections"]
+        fn hasPendingConnections(self: &QWebSocketServer) -> bool;
+
+        /// Returns true if the server is currently listening for incoming connections, otherwise returns false.
+        #[rust_name = "is_listening"]
+        fn isListening(self: &QWebSocketServer) -> bool;
+
+        /// Returns the secure mode the server is running in.
+        #[rust_name = "secure_mode"]
+        fn secureMode(self: &QWebSocketServer) -> QWebSocketServerSslMode;
+
+        /// Returns the server name that is used during the http handshake phase.
+        #[rust_name = "server_name"]
+        fn serverName(self: &QWebSocketServer) -> QString;
+
+        /// Returns the server's port if the server is listening for connections, otherwise returns 0.
+        #[rust_name = "server_port"]
+        fn serverPort(self: &QWebSocketServer) -> u16;
+
+        /// Returns a URL clients can use to connect to this server if the server is listening for connections,
+        /// otherwise an invalid URL is returned.
+        #[rust_name = "server_url"]
+        fn serverUrl(self: &QWebSocketServer) -> QUrl;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qwebsocketserver_new"]
+        fn qwebsocketserverNew(
+            server_name: &QString,
+            mode: QWebSocketServerSslMode,
+        ) -> UniquePtr<QWebSocketServer>;
+        #[doc(hidden)]
+        #[rust_name = "qwebsocketserver_listen"]
+        fn qwebsocketserverListen(server: Pin<&mut QWebSocketServer>, port: u16) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qwebsocketserver_next_pending_connection"]
+        fn qwebsocketserverNextPendingConnection(
+            server: Pin<&mut QWebSocketServer>,
+        ) -> UniquePtr<QWebSocket>;
+        #[doc(hidden)]
+        #[rust_name = "qwebsocketserver_on_new_connection"]
+        fn qwebsocketserverOnNewConnection(
+            server: Pin<&mut QWebSocketServer>,
+            callback: Box<QWebSocketServerCallback>,
+        ) -> QMetaObjectConnection;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        type QWebSocketServerCallback;
+
+        #[doc(hidden)]
+        fn call(self: &mut QWebSocketServerCallback);
+    }
+}
+
+pub use ffi::{QWebSocketServer, QWebSocketServerSslMode};
+
+/// A Rust closure which is called when a [QWebSocketServer] has a new connection
+#[doc(hidden)]
+pub struct QWebSocketServerCallback {
+    callback: Box<dyn FnMut()>,
+}
+
+impl QWebSocketServerCallback {
+    fn new(callback: impl FnMut() + 'static) -> Box<Self> {
+        Box::new(Self {
+            callback: Box::new(callback),
+        })
+    }
+
+    fn call(&mut self) {
+        (self.callback)();
+    }
+}
+
+impl QWebSocketServer {
+    /// Tells the server to listen for incoming connections on any address and the given port,
+    /// if the port is 0 then a port is chosen automatically.
+    ///
+    /// Returns true on success, otherwise returns false.
+    pub fn listen(self: Pin<&mut Self>, port: u16) -> bool {
+        ffi::qwebsocketserver_listen(self, port)
+    }
+
+    /// Constructs a new server with the given server name, which is used in the http handshake phase.
+    ///
+    /// In [QWebSocketServerSslMode::SecureMode] the server uses the TLS configuration of the Qt application.
+    pub fn new(server_name: &ffi::QString, mode: QWebSocketServerSslMode) -> UniquePtr<Self> {
+        ffi::qwebsocketserver_new(server_name, mode)
+    }
+
+    /// Returns the next pending connection as a connected [QWebSocket](crate::QWebSocket),
+    /// or a null pointer if there are no pending connections.
+    ///
+    /// The socket is owned by the returned pointer rather than the server.
+    pub fn next_pending_connection(self: Pin<&mut Self>) -> UniquePtr<ffi::QWebSocket> {
+        ffi::qwebsocketserver_next_pending_connection(self)
+    }
+
+    /// Connect a closure which is called each time a new connection is available,
+    /// the connection can then be taken with [QWebSocketServer::next_pending_connection].
+    ///
+    /// The closure is called on the thread of the server, and is dropped when the connection is disconnected.
+    pub fn on_new_connection(
+        self: Pin<&mut Self>,
+        callback: impl FnMut() + 'static,
+    ) -> ffi::QMetaObjectConnection {
+        ffi::qwebsocketserver_on_new_connection(self, QWebSocketServerCallback::new(callback))
+    }
+}
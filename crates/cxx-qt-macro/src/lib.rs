@@ -8,9 +8,13 @@
 //! The cxx-qt-macro crate provides the procedural attribute macros which are used with cxx-qt.
 
 use proc_macro::TokenStream;
-use syn::{parse_macro_input, ItemMod};
+use syn::{parse_macro_input, parse_quote, ItemMod};
 
-use cxx_qt_gen::{write_rust, GeneratedRustBlocks, Parser};
+#[cfg(not(feature = "ra-stub"))]
+use cxx_qt_gen::write_rust;
+#[cfg(feature = "ra-stub")]
+use cxx_qt_gen::write_rust_stub;
+use cxx_qt_gen::{GeneratedRustBlocks, Parser};
 
 /// A procedural macro which generates a QObject for a struct inside a module.
 ///
@@ -55,9 +59,13 @@ pub fn bridge(args: TokenStream, input: TokenStream) -> TokenStream {
     //
     // To keep the inputs to the parser consistent for all code paths,
     // add the attribute to the module before giving it to the parser.
-    let args_input = format!("#[cxx_qt::bridge({args})] mod dummy;");
-    let attrs = syn::parse_str::<ItemMod>(&args_input).unwrap().attrs;
-    module.attrs = attrs.into_iter().chain(module.attrs).collect();
+    //
+    // The attribute is built from the tokens directly, so that the arguments are not
+    // stringified and parsed again.
+    let args = proc_macro2::TokenStream::from(args);
+    module
+        .attrs
+        .insert(0, parse_quote! { #[cxx_qt::bridge(#args)] });
 
     // Extract and generate the rust code
     extract_and_generate(module)
@@ -119,8 +127,24 @@ pub fn qobject(_args: TokenStream, _input: TokenStream) -> TokenStream {
 // Take the module and C++ namespace and generate the rust code
 fn extract_and_generate(module: ItemMod) -> TokenStream {
     Parser::from(module)
+        .map(|mut parser| {
+            // Generate tracing spans around the Rust entry points if the feature is enabled
+            parser.trace = cfg!(feature = "tracing");
+            parser
+        })
         .and_then(|parser| GeneratedRustBlocks::from(&parser))
-        .map(|generated_rust| write_rust(&generated_rust))
+        .and_then(|generated_rust| generate_rust(&generated_rust))
         .unwrap_or_else(|err| err.to_compile_error())
         .into()
 }
+
+#[cfg(not(feature = "ra-stub"))]
+fn generate_rust(generated_rust: &GeneratedRustBlocks) -> syn::Result<proc_macro2::TokenStream> {
+    Ok(write_rust(generated_rust))
+}
+
+// Simplified stubs which are quick to expand for IDE tooling, these cannot be linked
+#[cfg(feature = "ra-stub")]
+fn generate_rust(generated_rust: &GeneratedRustBlocks) -> syn::Result<proc_macro2::TokenStream> {
+    write_rust_stub(generated_rust)
+}
@@ -0,0 +1,125 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+use crate::{CxxQtThread, Threading};
+
+/// A closure which is waiting for the QObject to be constructed
+type PendingFn<T> = Box<dyn FnOnce(Pin<&mut T>) + Send>;
+
+enum ContextState<T>
+where
+    T: Threading,
+{
+    /// The QObject is still being constructed, so closures are stored until it is attached
+    Pending(Vec<PendingFn<T>>),
+    /// The QObject has been constructed and closures are queued onto its Qt thread
+    Attached(CxxQtThread<T>),
+}
+
+/// A lightweight handle to a QObject which implements [Context](crate::Context), which is stored in the Rust struct.
+///
+/// Unlike a [CxxQtThread], which is created from a QObject, a [CxxQtContext] can be created in the `Default`
+/// implementation or the `new` function of a [Constructor](crate::Constructor) of the Rust struct.
+/// This allows for long-lived background tasks to be spawned while the QObject is being constructed,
+/// these can communicate back to the QObject without first needing a `Pin<&mut T>`.
+///
+/// A [CxxQtContext] is cheap to clone and implements [Send] and [Sync], all the clones refer to the same QObject.
+/// Closures which are queued before the QObject has been constructed are called once it is on the Qt event loop.
+pub struct CxxQtContext<T>
+where
+    T: Threading,
+{
+    state: Arc<Mutex<ContextState<T>>>,
+}
+
+impl<T> Clone for CxxQtContext<T>
+where
+    T: Threading,
+{
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<T> Default for CxxQtContext<T>
+where
+    T: Threading + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> CxxQtContext<T>
+where
+    T: Threading + 'static,
+{
+    /// Create a new [CxxQtContext] which is attached to the QObject once it has been constructed
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(ContextState::Pending(vec![]))),
+        }
+    }
+
+    /// Attach the context to the Qt thread of the constructed QObject and queue any pending closures
+    #[doc(hidden)]
+    pub fn attach(&self, qt_thread: CxxQtThread<T>) {
+        let mut state = self.state.lock().unwrap_or_else(|err| err.into_inner());
+        if let ContextState::Pending(pending) =
+            core::mem::replace(&mut *state, ContextState::Attached(qt_thread.clone()))
+        {
+            for f in pending {
+                // The QObject is being constructed, so it cannot have been destroyed yet
+                let _ = qt_thread.queue(f);
+            }
+        }
+    }
+
+    /// Returns the [CxxQtThread] of the QObject, or [None] if the QObject has not been constructed yet
+    pub fn qt_thread(&self) -> Option<CxxQtThread<T>> {
+        match &*self.state.lock().unwrap_or_else(|err| err.into_inner()) {
+            ContextState::Pending(_) => None,
+            ContextState::Attached(qt_thread) => Some(qt_thread.clone()),
+        }
+    }
+
+    /// Whether the QObject for this context has been destroyed
+    ///
+    /// This is `false` while the QObject is still being constructed.
+    pub fn is_destroyed(&self) -> bool {
+        self.qt_thread()
+            .map(|qt_thread| qt_thread.is_destroyed())
+            .unwrap_or(false)
+    }
+
+    /// Queue the given closure onto the Qt event loop for this QObject, such as for emitting a signal
+    ///
+    /// If the QObject has not been constructed yet, the closure is queued once it has been.
+    /// Once the QObject has been destroyed, an error is returned.
+    pub fn queue<F>(&self, f: F) -> Result<(), cxx::Exception>
+    where
+        F: FnOnce(Pin<&mut T>),
+        F: Send + 'static,
+    {
+        let qt_thread = {
+            let mut state = self.state.lock().unwrap_or_else(|err| err.into_inner());
+            match &mut *state {
+                ContextState::Pending(pending) => {
+                    pending.push(Box::new(f));
+                    return Ok(());
+                }
+                ContextState::Attached(qt_thread) => qt_thread.clone(),
+            }
+        };
+
+        // The lock is not held while queueing, so that the closure can use the context
+        qt_thread.queue(f)
+    }
+}
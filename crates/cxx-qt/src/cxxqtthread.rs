@@ -65,4 +65,12 @@ where
     {
         T::queue(self, f)
     }
+
+    /// Whether the QObject for this thread has been destroyed
+    ///
+    /// Once the QObject has been destroyed, for example by QML, any closures which are queued are no longer called
+    /// and [queue](Self::queue) returns an error.
+    pub fn is_destroyed(&self) -> bool {
+        T::is_destroyed(self)
+    }
 }
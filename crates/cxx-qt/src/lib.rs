@@ -9,12 +9,92 @@
 //!
 //! See the [book](https://kdab.github.io/cxx-qt/book/) for more information.
 
+mod cxxqtcontext;
 mod cxxqtthread;
+mod qobjectweakref;
 
 pub use cxx_qt_macro::bridge;
 pub use cxx_qt_macro::qobject;
 
+pub use cxxqtcontext::CxxQtContext;
 pub use cxxqtthread::CxxQtThread;
+pub use qobjectweakref::QObjectWeakRef;
+
+// Used by the generated code when the tracing feature is enabled
+#[cfg(feature = "tracing")]
+#[doc(hidden)]
+pub use tracing;
+
+/// Run the closure, returning the message of any panic as an error
+///
+/// This is used by the generated code for QObjects with a `#[qpanic(signal)]` or `#[qpanic(exception)]` policy,
+/// CXX throws the error as a `rust::Error` which is then handled by the generated C++ code.
+#[doc(hidden)]
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)).map_err(|payload| {
+        if let Some(message) = payload.downcast_ref::<&str>() {
+            (*message).to_owned()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "Box<dyn Any>".to_owned()
+        }
+    })
+}
+
+/// Emit a signal of a QObject, converting each of the arguments into the type of the signal parameter with [Into].
+///
+/// With a `Pin<&mut T>` the signal is emitted immediately, the pinned reference is reborrowed
+/// so that it can still be used after the signal has been emitted.
+///
+/// ```ignore
+/// cxx_qt::emit!(self.status_changed(format!("{count} items")));
+/// ```
+///
+/// With `queue` and a [CxxQtThread] or [CxxQtContext] the signal is emitted from a closure which is queued
+/// onto the Qt event loop of the QObject, this returns the result of queueing the closure.
+/// Note that the arguments are evaluated in the closure, so any variables which they use are moved into it.
+///
+/// ```ignore
+/// cxx_qt::emit!(queue qt_thread.status_changed(format!("{count} items")))?;
+/// ```
+///
+/// Signal parameters which are references, such as `&QString`, must still be given references.
+#[macro_export]
+macro_rules! emit {
+    (queue $thread:ident . $signal:ident ( $($arg:expr),* $(,)? )) => {
+        $thread.queue(move |qobject| {
+            qobject.$signal($(::core::convert::Into::into($arg)),*);
+        })
+    };
+    ($qobject:ident . $signal:ident ( $($arg:expr),* $(,)? )) => {
+        $qobject.as_mut().$signal($(::core::convert::Into::into($arg)),*)
+    };
+}
+
+/// Explicitly register the QML types and resources of a QML module which is built with `cxx_qt_build::QmlModule`.
+///
+/// The module is given as its URI with the dots replaced by underscores, this calls the generated
+/// `{uri}_register_all_types` function of the module. Call this before loading any QML when the linker
+/// or LTO could discard the static initializers which otherwise register the module.
+/// The module is only registered once, even if this is called multiple times or from multiple threads.
+///
+/// ```
+/// # #[no_mangle]
+/// # extern "C" fn com_kdab_cxx_qt_demo_register_all_types() {}
+/// cxx_qt::register_qml_module!(com_kdab_cxx_qt_demo);
+/// ```
+#[macro_export]
+macro_rules! register_qml_module {
+    ($uri:ident) => {{
+        extern "C" {
+            #[link_name = concat!(stringify!($uri), "_register_all_types")]
+            fn register_all_types();
+        }
+        // Safety: the function is generated by cxx-qt-build, takes no arguments, and guards against repeated calls
+        unsafe { register_all_types() }
+    }};
+}
 
 /// This trait is automatically implemented for all types which are marked as `#[qobject]`.
 /// It provides information about the type that is wrapped by the QObject, as well as the methods
@@ -75,6 +155,129 @@ pub trait Threading: Locking + Sized {
 
     #[doc(hidden)]
     fn threading_drop(cxx_qt_thread: &mut CxxQtThread<Self>);
+
+    #[doc(hidden)]
+    fn is_destroyed(cxx_qt_thread: &CxxQtThread<Self>) -> bool;
+}
+
+/// Indicates that the Rust struct of the object stores a [CxxQtContext], which is attached to the object once it has been constructed.
+///
+/// To enable this for a `T`, add `impl cxx_qt::Context for T {}` to your [`#[cxx_qt::bridge]`](bridge)
+/// and implement the trait outside of the bridge to return the context from the Rust struct.
+/// The object must also implement [Threading].
+///
+/// ```ignore
+/// impl cxx_qt::Context for qobject::MyObject {
+///     fn context(&self) -> &cxx_qt::CxxQtContext<Self> {
+///         &self.context
+///     }
+/// }
+/// ```
+pub trait Context: Threading {
+    /// Returns the [CxxQtContext] which is stored in the Rust struct
+    fn context(&self) -> &CxxQtContext<Self>;
+}
+
+/// An error which can be reported to QML as the code of a QEnum and a message.
+///
+/// This trait is implemented by CxxQt automatically for a `#[qenum(T)]` with the `#[qml_error]` attribute,
+/// which uses the doc comment of each variant as its message.
+/// It can also be implemented for Rust error types, so that they can be given to the generated `emit_error` method of `T`.
+///
+/// ```ignore
+/// impl cxx_qt::QmlError for NetworkError {
+///     type Code = qobject::NetworkErrorCode;
+///
+///     fn code(&self) -> Self::Code {
+///         match self {
+///             Self::Timeout(_) => qobject::NetworkErrorCode::Timeout,
+///             Self::Http(_) => qobject::NetworkErrorCode::Http,
+///         }
+///     }
+///
+///     fn message(&self) -> String {
+///         self.to_string()
+///     }
+/// }
+/// ```
+pub trait QmlError {
+    /// The QEnum which is the code of the error
+    type Code;
+
+    /// The code of the error
+    fn code(&self) -> Self::Code;
+
+    /// The message of the error, which can be shown to the user
+    fn message(&self) -> String;
+}
+
+/// Indicates that the object can be referred to by a [QObjectWeakRef], which tracks its destruction.
+///
+/// This trait is implemented by CxxQt automatically.
+/// To enable this for a `T`, add `impl cxx_qt::WeakRef for T {}` to your [`#[cxx_qt::bridge]`](bridge).
+pub trait WeakRef: Sized {
+    #[doc(hidden)]
+    type WeakRefTypeId;
+
+    /// Create a [QObjectWeakRef] to this QObject
+    fn weak_ref(&self) -> QObjectWeakRef<Self>;
+
+    #[doc(hidden)]
+    fn weak_ref_clone(weak_ref: &QObjectWeakRef<Self>) -> QObjectWeakRef<Self>;
+
+    #[doc(hidden)]
+    fn weak_ref_drop(weak_ref: &mut QObjectWeakRef<Self>);
+
+    #[doc(hidden)]
+    fn weak_ref_get(weak_ref: &QObjectWeakRef<Self>) -> *mut Self;
+}
+
+/// The ownership of a QObject which is created by a [QmlFactory], this is a `QQmlEngine::ObjectOwnership` in C++.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(i32)]
+pub enum QmlOwnership {
+    /// The QObject is owned by C++ or Rust code, so it is never destroyed by the QML engine
+    Cpp = 0,
+    /// The QObject is owned by the QML engine, so it is destroyed by the garbage collector once it is no longer referenced
+    JavaScript = 1,
+}
+
+/// Indicates that the object can be created with an explicit [QmlOwnership].
+///
+/// This is useful for QObjects which are returned from invokables to QML,
+/// so that they are not garbage-collected or leaked unexpectedly.
+///
+/// This trait is implemented by CxxQt automatically.
+/// To enable this for a `T`, add `impl cxx_qt::QmlFactory for T {}` to your [`#[cxx_qt::bridge]`](bridge).
+/// The QObject must have a default constructor and the crate must link to the QtQml module.
+pub trait QmlFactory: Sized {
+    /// Create a new instance of the QObject without a parent and with the given [QmlOwnership]
+    ///
+    /// With [QmlOwnership::Cpp] the caller is responsible for destroying the QObject.
+    fn new_with_ownership(ownership: QmlOwnership) -> *mut Self;
+}
+
+/// Indicates that the QObject implements the `QQmlParserStatus` interface,
+/// so that it is notified when the QML engine begins and completes the creation of the object.
+///
+/// This is useful for deferring initialization until all the properties which are set in QML are available.
+///
+/// To enable this for a `T`, add `impl cxx_qt::QmlParserStatus for T {}` to your [`#[cxx_qt::bridge]`](bridge)
+/// and implement the trait outside of the bridge. The crate must link to the QtQml module.
+///
+/// ```ignore
+/// impl cxx_qt::QmlParserStatus for qobject::MyObject {
+///     fn component_complete(self: core::pin::Pin<&mut Self>) {
+///         // All the properties which are set in QML are available here
+///     }
+/// }
+/// ```
+pub trait QmlParserStatus {
+    /// Called when the QML engine begins creating the object, before any properties have been set
+    fn class_begin(self: core::pin::Pin<&mut Self>) {}
+
+    /// Called when the QML engine has finished creating the object and all its properties and bindings have been set
+    fn component_complete(self: core::pin::Pin<&mut Self>) {}
 }
 
 /// This trait can be implemented on any [CxxQtType] to define a
@@ -208,6 +411,22 @@ pub trait Initialize: CxxQtType {
     fn initialize(self: core::pin::Pin<&mut Self>);
 }
 
+/// This trait can be implemented on any [CxxQtType] to run code once the QObject has been constructed.
+///
+/// The `initialized` function is called at the end of every constructor of the QObject, after the `initialize` function
+/// of the [Constructor] or [Initialize] trait, so it is also called for the default constructor.
+/// This is useful for starting timers, connecting signals to the QObject itself, or reading the state of the base class.
+///
+/// To enable this for a `T`, add `impl cxx_qt::Initialized for T {}` to your [`#[cxx_qt::bridge]`](bridge)
+/// and implement the trait outside of the bridge.
+///
+/// Note that for QML elements the properties which are set in QML are not available yet,
+/// use [QmlParserStatus] to defer initialization until they are.
+pub trait Initialized: CxxQtType {
+    /// This function is called once the QObject has been constructed.
+    fn initialized(self: core::pin::Pin<&mut Self>);
+}
+
 impl<T> Constructor<()> for T
 where
     T: Initialize,
@@ -0,0 +1,83 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::{marker::PhantomData, mem::MaybeUninit, pin::Pin};
+use cxx::ExternType;
+
+use crate::WeakRef;
+
+/// A weak reference to a QObject which implements [WeakRef], this is a QPointer in C++.
+///
+/// This tracks the destruction of the QObject, so that Rust code can hold a long-lived
+/// reference to a QObject which it does not own, such as a QObject which is owned by QML.
+///
+/// Note that [QObjectWeakRef] is neither [Send] nor [Sync], it must only be used on the Qt thread
+/// of the QObject. To access the QObject from other threads use a [CxxQtThread](crate::CxxQtThread) instead.
+#[repr(C)]
+pub struct QObjectWeakRef<T>
+where
+    T: WeakRef,
+{
+    // The layout is a QPointer<T>, which is two pointers
+    _space: MaybeUninit<[usize; 2]>,
+    _value: PhantomData<*const T>,
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl<T> ExternType for QObjectWeakRef<T>
+where
+    T: ExternType + WeakRef,
+{
+    type Id = T::WeakRefTypeId;
+    type Kind = cxx::kind::Trivial;
+}
+
+impl<T> Clone for QObjectWeakRef<T>
+where
+    T: WeakRef,
+{
+    fn clone(&self) -> Self {
+        T::weak_ref_clone(self)
+    }
+}
+
+impl<T> Drop for QObjectWeakRef<T>
+where
+    T: WeakRef,
+{
+    fn drop(&mut self) {
+        T::weak_ref_drop(self);
+    }
+}
+
+impl<T> QObjectWeakRef<T>
+where
+    T: WeakRef,
+{
+    /// Whether the QObject has been destroyed
+    pub fn is_destroyed(&self) -> bool {
+        T::weak_ref_get(self).is_null()
+    }
+
+    /// Retrieve a reference to the QObject, or [None] if it has been destroyed
+    pub fn upgrade(&self) -> Option<&T> {
+        // Safety: the pointer is either null or points to a QObject which is still alive
+        unsafe { T::weak_ref_get(self).as_ref() }
+    }
+
+    /// Retrieve a pinned mutable reference to the QObject, or [None] if it has been destroyed
+    ///
+    /// # Safety
+    ///
+    /// There must not be any other references to the QObject while the returned reference is alive,
+    /// including references from other [QObjectWeakRef]s to the same QObject.
+    pub unsafe fn upgrade_mut(&mut self) -> Option<Pin<&mut T>> {
+        T::weak_ref_get(self)
+            .as_mut()
+            .map(|obj| Pin::new_unchecked(obj))
+    }
+}
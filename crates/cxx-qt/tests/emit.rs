@@ -0,0 +1,75 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Check both arms of the `emit!` macro against a mock QObject and thread.
+
+use core::pin::Pin;
+use std::cell::RefCell;
+
+#[derive(Default)]
+struct MyObject {
+    emitted: Vec<(String, i32)>,
+}
+
+impl MyObject {
+    fn status_changed(self: Pin<&mut Self>, status: String, count: i32) {
+        self.get_mut().emitted.push((status, count));
+    }
+}
+
+type QueuedFn = Box<dyn FnOnce(Pin<&mut MyObject>)>;
+
+/// Queues closures like a `CxxQtThread` and runs them when the event loop is processed
+#[derive(Default)]
+struct MyObjectThread {
+    queued: RefCell<Vec<QueuedFn>>,
+}
+
+impl MyObjectThread {
+    fn queue<F>(&self, f: F) -> Result<(), &'static str>
+    where
+        F: FnOnce(Pin<&mut MyObject>) + 'static,
+    {
+        self.queued.borrow_mut().push(Box::new(f));
+        Ok(())
+    }
+
+    fn process_events(&self, mut qobject: Pin<&mut MyObject>) {
+        for f in self.queued.take() {
+            f(qobject.as_mut());
+        }
+    }
+}
+
+#[test]
+fn test_emit_direct() {
+    let mut object = MyObject::default();
+    let mut qobject = Pin::new(&mut object);
+
+    cxx_qt::emit!(qobject.status_changed("ready", 1i16));
+    // The pinned reference is reborrowed so it can be used again
+    cxx_qt::emit!(qobject.status_changed(format!("{} items", 2), 2,));
+
+    assert_eq!(
+        object.emitted,
+        vec![("ready".to_owned(), 1), ("2 items".to_owned(), 2)]
+    );
+}
+
+#[test]
+fn test_emit_queue() {
+    let thread = MyObjectThread::default();
+    let count = 3;
+
+    let result = cxx_qt::emit!(queue thread.status_changed(format!("{count} items"), count));
+    assert_eq!(result, Ok(()));
+
+    // Nothing is emitted until the queued closure is run
+    let mut object = MyObject::default();
+    assert!(object.emitted.is_empty());
+
+    thread.process_events(Pin::new(&mut object));
+    assert_eq!(object.emitted, vec![("3 items".to_owned(), 3)]);
+}
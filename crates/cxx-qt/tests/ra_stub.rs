@@ -0,0 +1,85 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Check that the stubs emitted with the `ra-stub` feature have the same Rust API as the bridge.
+//!
+//! The stubs cannot be linked against the C++ side, so the API is only type checked by
+//! coercing the generated methods to function pointers, none of them are called.
+
+#![cfg(feature = "ra-stub")]
+
+use core::pin::Pin;
+use cxx_qt::CxxQtType;
+use cxx_qt_lib::{ConnectionType, QMetaObjectConnection, QString};
+
+#[cxx_qt::bridge(namespace = "cxx_qt::ra_stub")]
+mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    unsafe extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(i32, number)]
+        #[qproperty(QString, name)]
+        type MyObject = super::MyObjectRust;
+
+        #[qsignal]
+        fn ready(self: Pin<&mut MyObject>, value: i32);
+
+        #[qinvokable]
+        fn increment(self: Pin<&mut MyObject>);
+
+        #[qinvokable]
+        fn doubled(self: &MyObject, value: i32) -> i32;
+    }
+}
+
+#[derive(Default)]
+pub struct MyObjectRust {
+    number: i32,
+    name: QString,
+}
+
+impl qobject::MyObject {
+    fn increment(self: Pin<&mut Self>) {
+        let number = *self.number();
+        self.set_number(number + 1);
+    }
+
+    fn doubled(&self, value: i32) -> i32 {
+        value * 2
+    }
+}
+
+/// The function pointer which is connected to the ready signal
+type ReadyHandler = fn(Pin<&mut qobject::MyObject>, i32);
+
+#[test]
+fn test_ra_stub_api() {
+    // Properties
+    let _: fn(&qobject::MyObject) -> &i32 = qobject::MyObject::number;
+    let _: fn(Pin<&mut qobject::MyObject>, i32) = qobject::MyObject::set_number;
+    let _: fn(&qobject::MyObject) -> &QString = qobject::MyObject::name;
+    let _: fn(Pin<&mut qobject::MyObject>, QString) = qobject::MyObject::set_name;
+    let _: fn(Pin<&mut qobject::MyObject>) = qobject::MyObject::number_changed;
+
+    // Signals
+    let _: fn(Pin<&mut qobject::MyObject>, i32) = qobject::MyObject::ready;
+    let _: fn(Pin<&mut qobject::MyObject>, ReadyHandler, ConnectionType) -> QMetaObjectConnection =
+        qobject::MyObject::connect_ready;
+    let _: fn(Pin<&mut qobject::MyObject>, ReadyHandler) -> QMetaObjectConnection =
+        qobject::MyObject::on_ready;
+
+    // Invokables
+    let _: fn(Pin<&mut qobject::MyObject>) = qobject::MyObject::increment;
+    let _: fn(&qobject::MyObject, i32) -> i32 = qobject::MyObject::doubled;
+
+    // Access to the Rust struct
+    let _: fn(&qobject::MyObject) -> &MyObjectRust = qobject::MyObject::rust;
+    let _: fn(Pin<&mut qobject::MyObject>) -> Pin<&mut MyObjectRust> = qobject::MyObject::rust_mut;
+}
@@ -16,6 +16,7 @@
 mod parse_cflags;
 
 use std::{
+    collections::HashMap,
     env,
     fs::File,
     io::Write,
@@ -27,32 +28,97 @@ pub use versions::SemVer;
 
 use thiserror::Error;
 
+/// The minimum version of Qt which is supported
+const QT_VERSION_MINIMUM: &str = "5.15.0";
+
 #[derive(Error, Debug)]
 pub enum QtBuildError {
     /// `QMAKE` environment variable was set but Qt was not detected
-    #[error("QMAKE environment variable specified as {qmake_env_var} but could not detect Qt: {error:?}")]
+    #[error(
+        "QMAKE environment variable specified as {qmake_env_var} but could not detect Qt: {error}"
+    )]
     QMakeSetQtMissing {
         qmake_env_var: String,
         error: Box<QtBuildError>,
     },
     /// Qt was not found
-    #[error("Could not find Qt")]
-    QtMissing,
+    #[error("Could not find Qt, ensure that qmake is in the PATH or set the QMAKE environment variable to the path of qmake")]
+    QtNotFound,
     /// Executing `qmake -query` failed
-    #[error("Executing `qmake -query` failed: {0:?}")]
+    #[error("Executing `qmake -query` failed: {0}")]
     QmakeFailed(#[from] std::io::Error),
     /// `QT_VERSION_MAJOR` environment variable was specified but could not be parsed as an integer
-    #[error("QT_VERSION_MAJOR environment variable specified as {qt_version_major_env_var} but could not parse as integer: {source:?}")]
+    #[error("QT_VERSION_MAJOR environment variable specified as {qt_version_major_env_var} but could not parse as integer: {source}")]
     QtVersionMajorInvalid {
         qt_version_major_env_var: String,
         source: std::num::ParseIntError,
     },
     /// `QT_VERSION_MAJOR` environment variable was specified but the Qt version specified by `qmake -query QT_VERSION` did not match
-    #[error("qmake version ({qmake_version}) does not match version specified by QT_VERISON_MAJOR ({qt_version_major})")]
+    #[error("qmake version ({qmake_version}) does not match version specified by QT_VERISON_MAJOR ({qt_version_major}), set the QMAKE environment variable to the path of qmake for Qt{qt_version_major}")]
     QtVersionMajorDoesNotMatch {
         qmake_version: u32,
         qt_version_major: u32,
     },
+    /// The version of Qt found is older than the minimum supported version
+    #[error("Found Qt {found} but Qt {required} or newer is required, install a newer version of Qt and set the QMAKE environment variable to the path of its qmake")]
+    VersionTooOld { found: String, required: String },
+    /// A Qt module which was requested is not part of the Qt installation
+    #[error("Could not find the Qt{name} module in the Qt installation, install the package which provides it or remove it from the required Qt modules")]
+    MissingModule { name: String },
+    /// The file of pre-queried Qt metadata could not be read
+    #[error("Could not read Qt metadata from {path:?}: {error}, it should be a JSON object of the variables from `qmake -query`")]
+    QueryFileInvalid { path: PathBuf, error: String },
+    /// A Qt tool, such as moc or rcc, could not be found in the Qt installation
+    #[error("Could not find the {tool} executable in the Qt installation, install the Qt development tools package which provides it")]
+    ToolMissing { tool: String },
+}
+
+/// Check that the candidate qmake executable can be run and matches any `QT_VERSION_MAJOR`
+fn verify_candidate(candidate: &str) -> Result<(&str, versions::SemVer), QtBuildError> {
+    match Command::new(candidate)
+        .args(["-query", "QT_VERSION"])
+        .output()
+    {
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Err(QtBuildError::QtNotFound),
+        Err(e) => Err(QtBuildError::QmakeFailed(e)),
+        Ok(output) => {
+            if output.status.success() {
+                let version_string = std::str::from_utf8(&output.stdout)
+                    .unwrap()
+                    .trim()
+                    .to_string();
+                let qmake_version = versions::SemVer::new(&version_string).unwrap();
+                if let Ok(env_version) = env::var("QT_VERSION_MAJOR") {
+                    let env_version = match env_version.trim().parse::<u32>() {
+                        Err(e) if *e.kind() == std::num::IntErrorKind::Empty => {
+                            println!(
+                                    "cargo:warning=QT_VERSION_MAJOR environment variable defined but empty"
+                                );
+                            return Ok((candidate, qmake_version));
+                        }
+                        Err(e) => {
+                            return Err(QtBuildError::QtVersionMajorInvalid {
+                                qt_version_major_env_var: env_version,
+                                source: e,
+                            })
+                        }
+                        Ok(int) => int,
+                    };
+                    if env_version == qmake_version.major {
+                        return Ok((candidate, qmake_version));
+                    } else {
+                        return Err(QtBuildError::QtVersionMajorDoesNotMatch {
+                            qmake_version: qmake_version.major,
+                            qt_version_major: env_version,
+                        });
+                    }
+                }
+                Ok((candidate, qmake_version))
+            } else {
+                Err(QtBuildError::QtNotFound)
+            }
+        }
+    }
 }
 
 fn command_help_output(command: &str) -> std::io::Result<std::process::Output> {
@@ -108,6 +174,15 @@ pub fn setup_linker() {
     }
 }
 
+/// The name of the initialization function that rcc generates for a given resource name,
+/// this is the argument that should be passed to `Q_INIT_RESOURCE`.
+fn rcc_init_name(resource_name: &str) -> String {
+    resource_name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
 /// Paths to files generated by [QtBuild::moc]
 pub struct MocProducts {
     /// Generated C++ file
@@ -130,6 +205,13 @@ pub struct QmlModuleRegistrationFiles {
     pub plugin: PathBuf,
     /// File that automatically registers the QQmlExtensionPlugin at startup. Must be linked with `+whole-archive`.
     pub plugin_init: PathBuf,
+    /// Name of the `extern "C"` function defined in [plugin](Self::plugin) which registers the QML types
+    /// and initializes the resources of the module when called.
+    ///
+    /// This is an alternative to relying on the static initializers in [plugin_init](Self::plugin_init), [rcc](Self::rcc)
+    /// and [qmlcachegen](Self::qmlcachegen), which can be discarded by some linkers or when using LTO.
+    /// Calling the function more than once, including from multiple threads, has no further effect.
+    pub register_all_types_function: String,
 }
 
 /// Helper for build.rs scripts using Qt
@@ -148,6 +230,8 @@ pub struct QtBuild {
     qmlcachegen_executable: Option<String>,
     rcc_executable: Option<String>,
     qt_modules: Vec<String>,
+    /// Pre-queried `qmake -query` variables which are used instead of running qmake
+    qmake_query_values: Option<HashMap<String, String>>,
 }
 
 impl QtBuild {
@@ -186,71 +270,18 @@ impl QtBuild {
     ///     WORKING_DIRECTORY ${CMAKE_CURRENT_SOURCE_DIR}
     /// )
     /// ```
-    pub fn new(mut qt_modules: Vec<String>) -> Result<Self, QtBuildError> {
-        if qt_modules.is_empty() {
-            qt_modules.push("Core".to_string());
-        }
+    pub fn new(qt_modules: Vec<String>) -> Result<Self, QtBuildError> {
         println!("cargo:rerun-if-env-changed=QMAKE");
         println!("cargo:rerun-if-env-changed=QT_VERSION_MAJOR");
-        fn verify_candidate(candidate: &str) -> Result<(&str, versions::SemVer), QtBuildError> {
-            match Command::new(candidate)
-                .args(["-query", "QT_VERSION"])
-                .output()
-            {
-                Err(e) if e.kind() == std::io::ErrorKind::NotFound => Err(QtBuildError::QtMissing),
-                Err(e) => Err(QtBuildError::QmakeFailed(e)),
-                Ok(output) => {
-                    if output.status.success() {
-                        let version_string = std::str::from_utf8(&output.stdout)
-                            .unwrap()
-                            .trim()
-                            .to_string();
-                        let qmake_version = versions::SemVer::new(&version_string).unwrap();
-                        if let Ok(env_version) = env::var("QT_VERSION_MAJOR") {
-                            let env_version = match env_version.trim().parse::<u32>() {
-                                Err(e) if *e.kind() == std::num::IntErrorKind::Empty => {
-                                    println!(
-                                        "cargo:warning=QT_VERSION_MAJOR environment variable defined but empty"
-                                    );
-                                    return Ok((candidate, qmake_version));
-                                }
-                                Err(e) => {
-                                    return Err(QtBuildError::QtVersionMajorInvalid {
-                                        qt_version_major_env_var: env_version,
-                                        source: e,
-                                    })
-                                }
-                                Ok(int) => int,
-                            };
-                            if env_version == qmake_version.major {
-                                return Ok((candidate, qmake_version));
-                            } else {
-                                return Err(QtBuildError::QtVersionMajorDoesNotMatch {
-                                    qmake_version: qmake_version.major,
-                                    qt_version_major: env_version,
-                                });
-                            }
-                        }
-                        Ok((candidate, qmake_version))
-                    } else {
-                        Err(QtBuildError::QtMissing)
-                    }
-                }
-            }
-        }
-
         if let Ok(qmake_env_var) = env::var("QMAKE") {
             match verify_candidate(qmake_env_var.trim()) {
                 Ok((executable_name, version)) => {
-                    return Ok(Self {
-                        qmake_executable: executable_name.to_string(),
-                        moc_executable: None,
-                        qmltyperegistrar_executable: None,
-                        qmlcachegen_executable: None,
-                        rcc_executable: None,
+                    return Self::from_qmake(
+                        executable_name.to_string(),
                         version,
                         qt_modules,
-                    });
+                        None,
+                    );
                 }
                 Err(e) => {
                     return Err(QtBuildError::QMakeSetQtMissing {
@@ -266,15 +297,12 @@ impl QtBuild {
         for (index, executable_name) in candidate_executable_names.iter().enumerate() {
             match verify_candidate(executable_name) {
                 Ok((executable_name, version)) => {
-                    return Ok(Self {
-                        qmake_executable: executable_name.to_string(),
-                        moc_executable: None,
-                        qmltyperegistrar_executable: None,
-                        qmlcachegen_executable: None,
-                        rcc_executable: None,
+                    return Self::from_qmake(
+                        executable_name.to_string(),
                         version,
                         qt_modules,
-                    });
+                        None,
+                    );
                 }
                 // If QT_VERSION_MAJOR is specified, it is expected that one of the versioned
                 // executable names will not match, so the unversioned `qmake` needs to be
@@ -293,16 +321,130 @@ impl QtBuild {
                     eprintln!("Candidate qmake executable `{executable_name}` is for Qt{qmake_version} but QT_VERISON_MAJOR environment variable specified as {qt_version_major}. Trying next candidate executable name `{}`...", candidate_executable_names[index + 1]);
                     continue;
                 }
-                Err(QtBuildError::QtMissing) => continue,
+                Err(QtBuildError::QtNotFound) => continue,
                 Err(e) => return Err(e),
             }
         }
 
-        Err(QtBuildError::QtMissing)
+        Err(QtBuildError::QtNotFound)
+    }
+
+    /// Construct from a verified qmake executable, ensuring that the Qt installation is usable
+    fn from_qmake(
+        qmake_executable: String,
+        version: SemVer,
+        mut qt_modules: Vec<String>,
+        qmake_query_values: Option<HashMap<String, String>>,
+    ) -> Result<Self, QtBuildError> {
+        if qt_modules.is_empty() {
+            qt_modules.push("Core".to_string());
+        }
+
+        if version < SemVer::new(QT_VERSION_MINIMUM).unwrap() {
+            return Err(QtBuildError::VersionTooOld {
+                found: version.to_string(),
+                required: QT_VERSION_MINIMUM.to_string(),
+            });
+        }
+
+        let qtbuild = Self {
+            qmake_executable,
+            moc_executable: None,
+            qmltyperegistrar_executable: None,
+            qmlcachegen_executable: None,
+            rcc_executable: None,
+            version,
+            qt_modules,
+            qmake_query_values,
+        };
+
+        // Each module has a header directory, or a framework on macOS
+        let headers_path = qtbuild.qmake_query("QT_INSTALL_HEADERS");
+        let lib_path = qtbuild.qmake_query("QT_INSTALL_LIBS");
+        if let Some(name) = qtbuild.qt_modules.iter().find(|qt_module| {
+            !Path::new(&format!("{headers_path}/Qt{qt_module}")).exists()
+                && !Path::new(&format!("{lib_path}/Qt{qt_module}.framework")).exists()
+        }) {
+            return Err(QtBuildError::MissingModule { name: name.clone() });
+        }
+
+        Ok(qtbuild)
+    }
+
+    /// Use the qmake executable at the given path, rather than searching for qmake.
+    ///
+    /// This is useful for hermetic build systems where qmake is not in the `PATH`.
+    pub fn with_qmake(
+        qmake_executable: impl AsRef<Path>,
+        qt_modules: Vec<String>,
+    ) -> Result<Self, QtBuildError> {
+        let qmake_executable = qmake_executable.as_ref().to_string_lossy().to_string();
+        let (_, version) = verify_candidate(&qmake_executable)?;
+        Self::from_qmake(qmake_executable, version, qt_modules, None)
+    }
+
+    /// Use the Qt installation at the given prefix, the directory which contains the `bin`
+    /// directory with qmake, rather than searching for qmake.
+    pub fn with_install_prefix(
+        install_prefix: impl AsRef<Path>,
+        qt_modules: Vec<String>,
+    ) -> Result<Self, QtBuildError> {
+        let bin_path = install_prefix.as_ref().join("bin");
+        ["qmake6", "qmake-qt5", "qmake"]
+            .iter()
+            .map(|name| bin_path.join(format!("{name}{}", env::consts::EXE_SUFFIX)))
+            .find(|path| path.exists())
+            .ok_or(QtBuildError::QtNotFound)
+            .and_then(|path| Self::with_qmake(path, qt_modules))
+    }
+
+    /// Use pre-queried metadata about the Qt installation, rather than running qmake.
+    ///
+    /// The file is a JSON object of the variables returned by `qmake -query`, which must
+    /// contain at least `QT_VERSION`, `QT_INSTALL_HEADERS`, and `QT_INSTALL_LIBS`. For example:
+    /// ```json
+    /// {
+    ///     "QT_VERSION": "6.5.0",
+    ///     "QT_INSTALL_PREFIX": "/opt/qt",
+    ///     "QT_INSTALL_HEADERS": "/opt/qt/include",
+    ///     "QT_INSTALL_LIBS": "/opt/qt/lib",
+    ///     "QT_INSTALL_LIBEXECS": "/opt/qt/libexec"
+    /// }
+    /// ```
+    pub fn with_query_file(
+        query_file: impl AsRef<Path>,
+        qt_modules: Vec<String>,
+    ) -> Result<Self, QtBuildError> {
+        let query_file = query_file.as_ref();
+        println!("cargo:rerun-if-changed={}", query_file.display());
+
+        let invalid = |error: String| QtBuildError::QueryFileInvalid {
+            path: query_file.to_path_buf(),
+            error,
+        };
+        let contents = std::fs::read_to_string(query_file).map_err(|e| invalid(e.to_string()))?;
+        let values: HashMap<String, String> =
+            serde_json::from_str(&contents).map_err(|e| invalid(e.to_string()))?;
+        let version = values
+            .get("QT_VERSION")
+            .ok_or_else(|| invalid("QT_VERSION is missing".to_string()))
+            .and_then(|version| {
+                SemVer::new(version)
+                    .ok_or_else(|| invalid(format!("QT_VERSION {version} is invalid")))
+            })?;
+
+        Self::from_qmake(String::new(), version, qt_modules, Some(values))
     }
 
     /// Get the output of running `qmake -query var_name`
+    ///
+    /// When constructed with [QtBuild::with_query_file] the value is read from the file instead,
+    /// a variable which is not in the file is empty.
     pub fn qmake_query(&self, var_name: &str) -> String {
+        if let Some(values) = &self.qmake_query_values {
+            return values.get(var_name).cloned().unwrap_or_default();
+        }
+
         std::str::from_utf8(
             &Command::new(&self.qmake_executable)
                 .args(["-query", var_name])
@@ -473,7 +615,7 @@ impl QtBuild {
 
     /// Lazy load the path of a Qt executable tool
     /// Skip doing this in the constructor because not every user of this crate will use each tool
-    fn get_qt_tool(&self, tool_name: &str) -> Result<String, ()> {
+    fn get_qt_tool(&self, tool_name: &str) -> Result<String, QtBuildError> {
         // "qmake -query" exposes a list of paths that describe where Qt executables and libraries
         // are located, as well as where new executables & libraries should be installed to.
         // We can use these variables to find any Qt tool.
@@ -523,7 +665,9 @@ impl QtBuild {
                 Err(_) => continue,
             }
         }
-        Err(())
+        Err(QtBuildError::ToolMissing {
+            tool: tool_name.to_string(),
+        })
     }
 
     /// Run moc on a C++ header file and save the output into [cargo's OUT_DIR](https://doc.rust-lang.org/cargo/reference/environment-variables.html).
@@ -533,7 +677,10 @@ impl QtBuild {
     /// * uri - Should be passed if the input_file is part of a QML module
     pub fn moc(&mut self, input_file: impl AsRef<Path>, uri: Option<&str>) -> MocProducts {
         if self.moc_executable.is_none() {
-            self.moc_executable = Some(self.get_qt_tool("moc").expect("Could not find moc"));
+            self.moc_executable = Some(
+                self.get_qt_tool("moc")
+                    .unwrap_or_else(|err| panic!("{err}")),
+            );
         }
 
         let input_path = input_file.as_ref();
@@ -599,7 +746,7 @@ impl QtBuild {
         if self.qmltyperegistrar_executable.is_none() {
             self.qmltyperegistrar_executable = Some(
                 self.get_qt_tool("qmltyperegistrar")
-                    .expect("Could not find qmltyperegistrar"),
+                    .unwrap_or_else(|err| panic!("{err}")),
             );
         }
         // qmlcachegen has a different CLI in Qt 5, so only support Qt >= 6
@@ -616,6 +763,7 @@ impl QtBuild {
         std::fs::create_dir_all(&qml_module_dir).expect("Could not create QML module directory");
 
         let qml_uri_underscores = uri.replace('.', "_");
+        let register_all_types_function = format!("{qml_uri_underscores}_register_all_types");
         let qmltypes_path = format!("{qml_module_dir}/plugin.qmltypes");
         let plugin_class_name = format!("{qml_uri_underscores}_plugin");
 
@@ -636,7 +784,11 @@ prefer :/qt/qml/{qml_uri_dirs}/
         }
 
         // Generate .qrc file and run rcc on it
-        let qrc_path = format!("{qml_module_dir}/qml_module_resources.qrc");
+        //
+        // Note that the file name includes the URI so that the rcc output and the
+        // resource initialization function are unique per QML module
+        let qrc_file_name = format!("qml_module_resources_{qml_uri_underscores}.qrc");
+        let qrc_path = format!("{qml_module_dir}/{qrc_file_name}");
         {
             fn qrc_file_line(file_path: &impl AsRef<Path>) -> String {
                 let path_display = file_path.as_ref().display();
@@ -791,6 +943,18 @@ prefer :/qt/qml/{qml_uri_dirs}/
             }
         }
 
+        // Collect the resource initialization functions which need to be called when
+        // registering explicitly, these match the names generated by rcc and qmlcachegen
+        let mut init_resources = vec![rcc_init_name(&qrc_file_name)];
+        if self.qmlcachegen_executable.is_some() {
+            init_resources.push(format!("qmlcache_{qml_uri_underscores}"));
+        }
+        let init_resources = init_resources
+            .iter()
+            .map(|name| format!("        Q_INIT_RESOURCE({name});"))
+            .collect::<Vec<String>>()
+            .join("\n");
+
         // Generate QQmlEngineExtensionPlugin
         let qml_plugin_cpp_path = PathBuf::from(format!("{out_dir}/{plugin_class_name}.cpp"));
         let qml_plugin_init_path = PathBuf::from(format!("{out_dir}/{plugin_class_name}_init.cpp"));
@@ -802,6 +966,8 @@ prefer :/qt/qml/{qml_uri_dirs}/
             write!(
                 qml_plugin_cpp,
                 r#"
+#include <mutex>
+
 #include <QtQml/qqmlextensionplugin.h>
 
 extern void {register_types_function}();
@@ -819,6 +985,18 @@ public:
     }}
 }};
 
+// Explicitly register the QML types and resources of the module.
+// This allows for the registration to occur without relying on static initializers,
+// which some linkers or LTO can discard.
+extern "C" void {register_all_types_function}()
+{{
+    static ::std::once_flag registered;
+    ::std::call_once(registered, [] {{
+        {register_types_function}();
+{init_resources}
+    }});
+}}
+
 // The moc-generated cpp file doesn't compile on its own; it needs to be #included here.
 #include "moc_{plugin_class_name}.cpp.cpp"
 "#
@@ -844,6 +1022,7 @@ Q_IMPORT_PLUGIN({plugin_class_name});
             qmltyperegistrar: qmltyperegistrar_output_path,
             plugin: qml_plugin_cpp_path,
             plugin_init: qml_plugin_init_path,
+            register_all_types_function,
         }
     }
 
@@ -853,7 +1032,10 @@ Q_IMPORT_PLUGIN({plugin_class_name});
     /// or the linker will discard the generated static variables because they are not referenced from `main`.
     pub fn qrc(&mut self, input_file: &impl AsRef<Path>) -> PathBuf {
         if self.rcc_executable.is_none() {
-            self.rcc_executable = Some(self.get_qt_tool("rcc").expect("Could not find rcc"));
+            self.rcc_executable = Some(
+                self.get_qt_tool("rcc")
+                    .unwrap_or_else(|err| panic!("{err}")),
+            );
         }
 
         let input_path = input_file.as_ref();
@@ -888,7 +1070,10 @@ Q_IMPORT_PLUGIN({plugin_class_name});
     /// Run [rcc](https://doc.qt.io/qt-6/resources.html) on a .qrc file and return the paths of the sources
     pub fn qrc_list(&mut self, input_file: &impl AsRef<Path>) -> Vec<PathBuf> {
         if self.rcc_executable.is_none() {
-            self.rcc_executable = Some(self.get_qt_tool("rcc").expect("Could not find rcc"));
+            self.rcc_executable = Some(
+                self.get_qt_tool("rcc")
+                    .unwrap_or_else(|err| panic!("{err}")),
+            );
         }
 
         // Add the qrc file contents to the cargo rerun list
@@ -912,3 +1097,204 @@ Q_IMPORT_PLUGIN({plugin_class_name});
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Create a query file with the given Qt version, whose headers contain the given modules
+    fn create_query_file(name: &str, version: &str, qt_modules: &[&str]) -> PathBuf {
+        let prefix =
+            env::temp_dir().join(format!("qt-build-utils-test-{name}-{}", std::process::id()));
+        for qt_module in qt_modules {
+            std::fs::create_dir_all(prefix.join(format!("include/Qt{qt_module}"))).unwrap();
+        }
+        std::fs::create_dir_all(prefix.join("lib")).unwrap();
+
+        let query_file = prefix.join("query.json");
+        let values = HashMap::from([
+            ("QT_VERSION", version.to_string()),
+            ("QT_INSTALL_PREFIX", prefix.display().to_string()),
+            (
+                "QT_INSTALL_HEADERS",
+                prefix.join("include").display().to_string(),
+            ),
+            ("QT_INSTALL_LIBS", prefix.join("lib").display().to_string()),
+        ]);
+        std::fs::write(&query_file, serde_json::to_string(&values).unwrap()).unwrap();
+        query_file
+    }
+
+    #[test]
+    fn test_query_file() {
+        let query_file = create_query_file("query-file", "6.5.0", &["Core"]);
+        let prefix = query_file.parent().unwrap().to_path_buf();
+        let result = QtBuild::with_query_file(&query_file, vec![]);
+        std::fs::remove_dir_all(&prefix).unwrap();
+
+        let qtbuild = result.unwrap();
+        assert_eq!(qtbuild.version().to_string(), "6.5.0");
+        assert_eq!(
+            qtbuild.qmake_query("QT_INSTALL_HEADERS"),
+            prefix.join("include").display().to_string()
+        );
+        // Variables which are not in the file are empty
+        assert_eq!(qtbuild.qmake_query("QT_INSTALL_QML"), "");
+    }
+
+    #[test]
+    fn test_query_file_invalid() {
+        let prefix = env::temp_dir().join(format!(
+            "qt-build-utils-test-query-file-invalid-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&prefix).unwrap();
+
+        let cases = [
+            (
+                "missing-version.json",
+                r#"{"QT_INSTALL_HEADERS": "/opt/qt/include"}"#,
+                "QT_VERSION is missing",
+            ),
+            (
+                "invalid-version.json",
+                r#"{"QT_VERSION": "six"}"#,
+                "QT_VERSION six is invalid",
+            ),
+            (
+                "malformed.json",
+                r#"{"QT_VERSION": "6.5.0""#,
+                "EOF while parsing",
+            ),
+        ];
+        let results: Vec<_> = cases
+            .iter()
+            .map(|(name, contents, _)| {
+                let query_file = prefix.join(name);
+                std::fs::write(&query_file, contents).unwrap();
+                (
+                    query_file.clone(),
+                    QtBuild::with_query_file(&query_file, vec![]),
+                )
+            })
+            .collect();
+        let missing_file = prefix.join("missing.json");
+        let missing = QtBuild::with_query_file(&missing_file, vec![]);
+        std::fs::remove_dir_all(&prefix).unwrap();
+
+        for ((query_file, result), (_, _, expected)) in results.into_iter().zip(cases) {
+            match result {
+                Err(QtBuildError::QueryFileInvalid { path, error }) => {
+                    assert_eq!(path, query_file);
+                    assert!(
+                        error.contains(expected),
+                        "{error} should contain {expected}"
+                    );
+                }
+                _others => panic!("Expected QueryFileInvalid for {query_file:?}"),
+            }
+        }
+        match missing {
+            Err(QtBuildError::QueryFileInvalid { path, .. }) => assert_eq!(path, missing_file),
+            _others => panic!("Expected QueryFileInvalid for a missing file"),
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_install_prefix() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let prefix = env::temp_dir().join(format!(
+            "qt-build-utils-test-install-prefix-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(prefix.join("bin")).unwrap();
+        std::fs::create_dir_all(prefix.join("include/QtCore")).unwrap();
+        std::fs::create_dir_all(prefix.join("lib")).unwrap();
+
+        // A qmake which only answers the queries that are needed to find the Qt modules
+        let qmake = prefix.join("bin/qmake");
+        std::fs::write(
+            &qmake,
+            format!(
+                "#!/bin/sh\n\
+                 case \"$2\" in\n\
+                 QT_VERSION) echo 6.5.0 ;;\n\
+                 QT_INSTALL_HEADERS) echo {prefix}/include ;;\n\
+                 QT_INSTALL_LIBS) echo {prefix}/lib ;;\n\
+                 esac\n",
+                prefix = prefix.display()
+            ),
+        )
+        .unwrap();
+        std::fs::set_permissions(&qmake, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let result = QtBuild::with_install_prefix(&prefix, vec![]);
+        std::fs::remove_dir_all(&prefix).unwrap();
+
+        let qtbuild = result.unwrap();
+        assert_eq!(qtbuild.version().to_string(), "6.5.0");
+        assert_eq!(qtbuild.qmake_executable, qmake.to_string_lossy());
+    }
+
+    #[test]
+    fn test_version_too_old() {
+        let query_file = create_query_file("version-too-old", "5.12.2", &["Core"]);
+        let result = QtBuild::with_query_file(&query_file, vec![]);
+        std::fs::remove_dir_all(query_file.parent().unwrap()).unwrap();
+
+        match result {
+            Err(QtBuildError::VersionTooOld { found, required }) => {
+                assert_eq!(found, "5.12.2");
+                assert_eq!(required, QT_VERSION_MINIMUM);
+            }
+            _others => panic!("Expected VersionTooOld"),
+        }
+    }
+
+    #[test]
+    fn test_missing_module() {
+        let query_file = create_query_file("missing-module", "6.5.0", &["Core", "Gui"]);
+        let found =
+            QtBuild::with_query_file(&query_file, vec!["Core".to_owned(), "Gui".to_owned()]);
+        let missing =
+            QtBuild::with_query_file(&query_file, vec!["Core".to_owned(), "Qml".to_owned()]);
+        std::fs::remove_dir_all(query_file.parent().unwrap()).unwrap();
+
+        assert_eq!(found.unwrap().version().major, 6);
+        match missing {
+            Err(QtBuildError::MissingModule { name }) => assert_eq!(name, "Qml"),
+            _others => panic!("Expected MissingModule"),
+        }
+    }
+
+    #[test]
+    fn test_tool_missing() {
+        let query_file = create_query_file("tool-missing", "6.5.0", &["Core"]);
+        let qtbuild = QtBuild::with_query_file(&query_file, vec![]).unwrap();
+        let result = qtbuild.get_qt_tool("moc");
+        std::fs::remove_dir_all(query_file.parent().unwrap()).unwrap();
+
+        match result {
+            Err(QtBuildError::ToolMissing { tool }) => assert_eq!(tool, "moc"),
+            _others => panic!("Expected ToolMissing"),
+        }
+    }
+
+    #[test]
+    fn test_qt_not_found() {
+        let prefix = env::temp_dir().join(format!(
+            "qt-build-utils-test-qt-not-found-{}",
+            std::process::id()
+        ));
+        assert!(matches!(
+            QtBuild::with_install_prefix(&prefix, vec![]),
+            Err(QtBuildError::QtNotFound)
+        ));
+        assert!(matches!(
+            QtBuild::with_qmake(prefix.join("bin/qmake"), vec![]),
+            Err(QtBuildError::QtNotFound)
+        ));
+    }
+}
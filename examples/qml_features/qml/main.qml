@@ -116,6 +116,10 @@ ApplicationWindow {
                     name: "Custom Parent Class"
                     source: "pages/CustomParentClassPage.qml"
                 }
+                ListElement {
+                    name: "Fetch More"
+                    source: "pages/FetchMorePage.qml"
+                }
             }
         }
     }
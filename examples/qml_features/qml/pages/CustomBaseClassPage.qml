@@ -32,6 +32,16 @@ Page {
                 onClicked: customBaseClass.multiply(listView.currentIndex, 2.0)
             }
 
+            ToolButton {
+                enabled: listView.currentIndex > 0 && listView.count > 0
+                text: qsTr("Move Up")
+
+                onClicked: {
+                    customBaseClass.moveRow(listView.currentIndex, listView.currentIndex - 1);
+                    listView.currentIndex -= 1;
+                }
+            }
+
             ToolButton {
                 enabled: listView.currentIndex > -1 && listView.count > 0
                 text: qsTr("Remove Selected")
@@ -39,6 +49,13 @@ Page {
                 onClicked: customBaseClass.remove(listView.currentIndex)
             }
 
+            ToolButton {
+                enabled: listView.count > 1
+                text: qsTr("Sort")
+
+                onClicked: customBaseClass.sortByValue()
+            }
+
             ToolButton {
                 enabled: listView.count > 0
                 text: qsTr("Clear")
@@ -68,12 +85,15 @@ Page {
             model: CustomBaseClass {
                 id: customBaseClass
             }
-            delegate: ItemDelegate {
+            delegate: CheckDelegate {
+                checked: model.checked
                 highlighted: ListView.isCurrentItem
                 text: model.id + ": " + model.value
                 width: ListView.view.width
 
                 onClicked: ListView.view.currentIndex = index
+                // Writing to the role calls setData on the model
+                onToggled: model.checked = checked
             }
         }
     }
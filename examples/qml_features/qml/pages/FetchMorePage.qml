@@ -0,0 +1,44 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+import QtQuick 2.12
+import QtQuick.Controls 2.12
+import QtQuick.Layouts 1.12
+
+import com.kdab.cxx_qt.demo 1.0
+
+Page {
+    header: ToolBar {
+        RowLayout {
+            anchors.fill: parent
+
+            Label {
+                Layout.fillWidth: true
+                text: qsTr("Scroll to the end of the list to fetch more rows")
+            }
+
+            Label {
+                text: qsTr("Count: %1 / %2").arg(listView.count).arg(fetchMoreModel.totalRows)
+            }
+        }
+    }
+
+    ScrollView {
+        anchors.fill: parent
+        clip: true
+        ScrollBar.vertical.policy: ScrollBar.vertical.size === 1.0 ? ScrollBar.AlwaysOff : ScrollBar.AlwaysOn
+
+        ListView {
+            id: listView
+            // The view calls fetchMore on the model when it reaches the end of the rows
+            model: FetchMoreModel {
+                id: fetchMoreModel
+            }
+            delegate: ItemDelegate {
+                text: model.display
+                width: ListView.view.width
+            }
+        }
+    }
+}
@@ -15,6 +15,7 @@ fn main() {
                 "src/containers.rs",
                 "src/custom_base_class.rs",
                 "src/custom_parent_class.rs",
+                "src/fetch_more.rs",
                 "src/invokables.rs",
                 "src/multiple_qobjects.rs",
                 "src/nested_qobjects.rs",
@@ -31,6 +32,7 @@ fn main() {
                 "../qml/pages/ContainersPage.qml",
                 "../qml/pages/CustomBaseClassPage.qml",
                 "../qml/pages/CustomParentClassPage.qml",
+                "../qml/pages/FetchMorePage.qml",
                 "../qml/pages/InvokablesPage.qml",
                 "../qml/pages/MultipleQObjectsPage.qml",
                 "../qml/pages/NestedQObjectsPage.qml",
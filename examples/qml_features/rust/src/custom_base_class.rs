@@ -14,6 +14,10 @@ pub mod qobject {
         include!(< QAbstractListModel >);
         // ANCHOR_END: book_base_include
 
+        include!("cxx-qt-lib/qlist.h");
+        /// QList<QModelIndex> from cxx_qt_lib
+        type QList_QModelIndex = cxx_qt_lib::QList<QModelIndex>;
+
         include!("cxx-qt-lib/qhash.h");
         /// QHash<i32, QByteArray> from cxx_qt_lib
         type QHash_i32_QByteArray = cxx_qt_lib::QHash<cxx_qt_lib::QHashPair_i32_QByteArray>;
@@ -26,6 +30,11 @@ pub mod qobject {
         /// QModelIndex from cxx_qt_lib
         type QModelIndex = cxx_qt_lib::QModelIndex;
 
+        include!("cxx-qt-lib/qt.h");
+        /// Qt::ItemFlags from cxx_qt_lib
+        #[namespace = "Qt"]
+        type ItemFlags = cxx_qt_lib::ItemFlags;
+
         include!("cxx-qt-lib/qvector.h");
         /// QVector<i32> from cxx_qt_lib
         type QVector_i32 = cxx_qt_lib::QVector<i32>;
@@ -38,8 +47,11 @@ pub mod qobject {
         Id,
         /// The value of the row
         Value,
+        /// Whether the row is checked
+        Checked,
     }
 
+    // ANCHOR: book_qenum_in_qobject
     #[qenum(CustomBaseClass)]
     /// State of the CustomBaseClass list model
     enum State {
@@ -60,6 +72,7 @@ pub mod qobject {
     }
     // ANCHOR_END: book_qobject_base
     // ANCHOR_END: book_inherit_qalm
+    // ANCHOR_END: book_qenum_in_qobject
 
     // Enabling threading on the qobject
     impl cxx_qt::Threading for CustomBaseClass {}
@@ -107,9 +120,17 @@ pub mod qobject {
         #[qinvokable]
         pub fn multiply(self: Pin<&mut CustomBaseClass>, index: i32, factor: f64);
 
+        /// Move the row with the given index to a new position, as used when drag-reordering a list
+        #[qinvokable]
+        pub fn move_row(self: Pin<&mut CustomBaseClass>, from: i32, to: i32);
+
         /// Remove the row with the given index
         #[qinvokable]
         pub fn remove(self: Pin<&mut CustomBaseClass>, index: i32);
+
+        /// Sort the rows by their value, preserving any persistent indexes held by views
+        #[qinvokable]
+        pub fn sort_by_value(self: Pin<&mut CustomBaseClass>);
     }
 
     // ANCHOR: book_inherit_qalm_impl_unsafe
@@ -139,6 +160,35 @@ pub mod qobject {
         #[inherit]
         unsafe fn end_remove_rows(self: Pin<&mut CustomBaseClass>);
 
+        /// Inherited beginMoveRows from the base class
+        #[inherit]
+        unsafe fn begin_move_rows(
+            self: Pin<&mut CustomBaseClass>,
+            source_parent: &QModelIndex,
+            source_first: i32,
+            source_last: i32,
+            destination_parent: &QModelIndex,
+            destination_child: i32,
+        ) -> bool;
+        /// Inherited endMoveRows from the base class
+        #[inherit]
+        unsafe fn end_move_rows(self: Pin<&mut CustomBaseClass>);
+
+        /// Inherited layoutAboutToBeChanged from the base class, emitted with the default arguments
+        #[inherit]
+        unsafe fn layout_about_to_be_changed(self: Pin<&mut CustomBaseClass>);
+        /// Inherited layoutChanged from the base class, emitted with the default arguments
+        #[inherit]
+        unsafe fn layout_changed(self: Pin<&mut CustomBaseClass>);
+
+        /// Inherited changePersistentIndexList from the base class
+        #[inherit]
+        unsafe fn change_persistent_index_list(
+            self: Pin<&mut CustomBaseClass>,
+            from: &QList_QModelIndex,
+            to: &QList_QModelIndex,
+        );
+
         /// Inherited beginResetModel from the base class
         #[inherit]
         unsafe fn begin_reset_model(self: Pin<&mut CustomBaseClass>);
@@ -155,6 +205,10 @@ pub mod qobject {
         #[inherit]
         fn base_can_fetch_more(self: &CustomBaseClass, parent: &QModelIndex) -> bool;
 
+        /// Inherited persistentIndexList from the base class
+        #[inherit]
+        fn persistent_index_list(self: &CustomBaseClass) -> QList_QModelIndex;
+
         /// Inherited index from the base class
         #[inherit]
         fn index(
@@ -175,6 +229,24 @@ pub mod qobject {
     }
     // ANCHOR_END: book_inherit_data_signature
 
+    // ANCHOR: book_inherit_set_data_signature
+    unsafe extern "RustQt" {
+        /// Set the data for a given index and role, this allows views to edit the value and checked roles
+        #[qinvokable]
+        #[cxx_override]
+        fn set_data(
+            self: Pin<&mut CustomBaseClass>,
+            index: &QModelIndex,
+            value: &QVariant,
+            role: i32,
+        ) -> bool;
+
+        /// Return the item flags for a given index, marking the rows as editable and checkable
+        #[cxx_override]
+        fn flags(self: &CustomBaseClass, index: &QModelIndex) -> ItemFlags;
+    }
+    // ANCHOR_END: book_inherit_set_data_signature
+
     // ANCHOR: book_inherit_can_fetch_more_signature
     unsafe extern "RustQt" {
         /// Return whether the base class can fetch more
@@ -200,7 +272,10 @@ pub mod qobject {
 
 use core::pin::Pin;
 use cxx_qt::{CxxQtType, Threading};
-use cxx_qt_lib::{QByteArray, QHash, QHashPair_i32_QByteArray, QModelIndex, QVariant, QVector};
+use cxx_qt_lib::{
+    ItemFlag, ItemFlags, QByteArray, QHash, QHashPair_i32_QByteArray, QList, QModelIndex, QVariant,
+    QVector,
+};
 
 impl Default for qobject::State {
     fn default() -> Self {
@@ -215,7 +290,7 @@ pub struct CustomBaseClassRust {
     pending_adds: i32,
 
     pub(crate) id: u32,
-    pub(crate) vector: Vec<(u32, f64)>,
+    pub(crate) vector: Vec<(u32, f64, bool)>,
 }
 
 impl qobject::CustomBaseClass {
@@ -270,7 +345,7 @@ impl qobject::CustomBaseClass {
             self.as_mut()
                 .rust_mut()
                 .vector
-                .push((id, (id as f64) / 3.0));
+                .push((id, (id as f64) / 3.0, false));
             self.as_mut().end_insert_rows();
         }
     }
@@ -293,15 +368,85 @@ impl qobject::CustomBaseClass {
 impl qobject::CustomBaseClass {
     /// Multiply the number in the row with the given index by the given factor
     pub fn multiply(mut self: Pin<&mut Self>, index: i32, factor: f64) {
-        if let Some((_, value)) = self.as_mut().rust_mut().vector.get_mut(index as usize) {
+        if let Some((_, value, _)) = self.as_mut().rust_mut().vector.get_mut(index as usize) {
             *value *= factor;
 
             // Emit dataChanged for the index and value role
             let model_index = self.index(index, 0, &QModelIndex::default());
-            let mut vector_roles = QVector::<i32>::default();
-            vector_roles.append(1);
-            self.as_mut()
-                .data_changed(&model_index, &model_index, &vector_roles);
+            self.emit_data_changed(&model_index, qobject::Roles::Value);
+        }
+    }
+
+    /// Emit dataChanged for a single index and role
+    fn emit_data_changed(self: Pin<&mut Self>, model_index: &QModelIndex, role: qobject::Roles) {
+        let mut vector_roles = QVector::<i32>::default();
+        vector_roles.append(role.repr);
+        self.data_changed(model_index, model_index, &vector_roles);
+    }
+
+    /// Move the row with the given index to a new position, as used when drag-reordering a list
+    pub fn move_row(mut self: Pin<&mut Self>, from: i32, to: i32) {
+        let len = self.vector.len() as i32;
+        if from == to || !(0..len).contains(&from) || !(0..len).contains(&to) {
+            return;
+        }
+
+        // beginMoveRows expects the row before which the moved row is inserted,
+        // so when moving down this is one after the final position of the row
+        let destination_child = if to > from { to + 1 } else { to };
+        let parent = QModelIndex::default();
+        unsafe {
+            if self
+                .as_mut()
+                .begin_move_rows(&parent, from, from, &parent, destination_child)
+            {
+                let row = self.as_mut().rust_mut().vector.remove(from as usize);
+                self.as_mut().rust_mut().vector.insert(to as usize, row);
+                self.as_mut().end_move_rows();
+            }
+        }
+    }
+
+    /// Sort the rows by their value, preserving any persistent indexes held by views
+    pub fn sort_by_value(mut self: Pin<&mut Self>) {
+        unsafe {
+            self.as_mut().layout_about_to_be_changed();
+        }
+
+        // Sort the rows while remembering the previous position of each row
+        let mut previous_rows: Vec<usize> = (0..self.vector.len()).collect();
+        previous_rows.sort_by(|a, b| self.vector[*a].1.total_cmp(&self.vector[*b].1));
+        let sorted: Vec<_> = previous_rows.iter().map(|row| self.vector[*row]).collect();
+        self.as_mut().rust_mut().vector = sorted;
+        self.as_mut().update_persistent_indexes(&previous_rows);
+
+        unsafe {
+            self.as_mut().layout_changed();
+        }
+    }
+
+    /// Update the persistent indexes after the rows have been reordered during a layout change
+    ///
+    /// previous_rows contains the previous position of each row in its new order
+    fn update_persistent_indexes(self: Pin<&mut Self>, previous_rows: &[usize]) {
+        let mut new_rows = vec![0; previous_rows.len()];
+        for (new_row, previous_row) in previous_rows.iter().enumerate() {
+            new_rows[*previous_row] = new_row;
+        }
+
+        // All of the persistent indexes must be changed at once, as changing them
+        // individually could cause an index to be moved twice
+        let from = self.persistent_index_list();
+        let to = QList::from(
+            from.iter()
+                .map(|index| match new_rows.get(index.row() as usize) {
+                    Some(row) => self.index(*row as i32, index.column(), &QModelIndex::default()),
+                    None => QModelIndex::default(),
+                })
+                .collect::<Vec<QModelIndex>>(),
+        );
+        unsafe {
+            self.change_persistent_index_list(&from, &to);
         }
     }
 
@@ -327,10 +472,11 @@ impl qobject::CustomBaseClass {
     /// Retrieve the data for a given index and role
     pub fn data(&self, index: &QModelIndex, role: i32) -> QVariant {
         let role = qobject::Roles { repr: role };
-        if let Some((id, value)) = self.vector.get(index.row() as usize) {
+        if let Some((id, value, checked)) = self.vector.get(index.row() as usize) {
             return match role {
                 qobject::Roles::Id => QVariant::from(id),
                 qobject::Roles::Value => QVariant::from(value),
+                qobject::Roles::Checked => QVariant::from(checked),
                 _ => QVariant::default(),
             };
         }
@@ -340,6 +486,49 @@ impl qobject::CustomBaseClass {
 }
 // ANCHOR_END: book_inherit_data
 
+// ANCHOR: book_inherit_set_data
+impl qobject::CustomBaseClass {
+    /// Set the data for a given index and role, this allows views to edit the value and checked roles
+    pub fn set_data(
+        mut self: Pin<&mut Self>,
+        index: &QModelIndex,
+        value: &QVariant,
+        role: i32,
+    ) -> bool {
+        let role = qobject::Roles { repr: role };
+        let row = index.row() as usize;
+        if let Some((_, row_value, row_checked)) = self.as_mut().rust_mut().vector.get_mut(row) {
+            let changed = match role {
+                qobject::Roles::Value => value.value::<f64>().map(|value| *row_value = value),
+                qobject::Roles::Checked => value.value::<bool>().map(|value| *row_checked = value),
+                _ => None,
+            };
+
+            if changed.is_some() {
+                // Views are only told about a change once the QVariant was valid for the role
+                self.emit_data_changed(index, role);
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Return the item flags for a given index, marking the rows as editable and checkable
+    pub fn flags(&self, index: &QModelIndex) -> ItemFlags {
+        if !index.is_valid() {
+            return ItemFlags::from(ItemFlag::NoItemFlags);
+        }
+
+        ItemFlag::ItemIsEnabled
+            | ItemFlag::ItemIsSelectable
+            | ItemFlag::ItemIsEditable
+            | ItemFlag::ItemIsUserCheckable
+            | ItemFlag::ItemNeverHasChildren
+    }
+}
+// ANCHOR_END: book_inherit_set_data
+
 // ANCHOR: book_inherit_can_fetch_more
 impl qobject::CustomBaseClass {
     /// Return whether the base class can fetch more
@@ -354,8 +543,10 @@ impl qobject::CustomBaseClass {
     /// Return the role names for the QAbstractListModel
     pub fn role_names(&self) -> QHash<QHashPair_i32_QByteArray> {
         let mut roles = QHash::<QHashPair_i32_QByteArray>::default();
-        roles.insert(qobject::Roles::Id.repr, QByteArray::from("id"));
-        roles.insert(qobject::Roles::Value.repr, QByteArray::from("value"));
+        // Build the role names from the Roles QEnum, so that they cannot drift from the values used in QML
+        for (role, name) in qobject::Roles::VARIANTS.iter().zip(qobject::Roles::NAMES) {
+            roles.insert(role.repr, QByteArray::from(name.to_lowercase().as_str()));
+        }
         roles
     }
 
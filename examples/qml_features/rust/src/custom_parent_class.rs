@@ -45,6 +45,7 @@ pub mod qobject {
     unsafe extern "RustQt" {
         #[qobject]
         #[base = "QQuickPaintedItem"]
+        #[qml_element]
         #[qproperty(QColor, color)]
         type CustomParentClass = super::CustomParentClassRust;
 
@@ -62,7 +63,9 @@ pub mod qobject {
         fn update(self: Pin<&mut CustomParentClass>);
     }
 
-    impl cxx_qt::Constructor<()> for CustomParentClass {}
+    // The default constructor is used, the constructor of QQuickPaintedItem takes a QQuickItem
+    // parent so the QObject parent is set once it has been constructed
+    impl cxx_qt::Initialized for CustomParentClass {}
 }
 
 use core::pin::Pin;
@@ -103,8 +106,8 @@ impl qobject::CustomParentClass {
     }
 }
 
-impl cxx_qt::Initialize for qobject::CustomParentClass {
-    fn initialize(self: core::pin::Pin<&mut Self>) {
+impl cxx_qt::Initialized for qobject::CustomParentClass {
+    fn initialized(self: core::pin::Pin<&mut Self>) {
         self.on_color_changed(|qobject| qobject.update()).release();
     }
 }
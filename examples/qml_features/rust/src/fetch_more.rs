@@ -0,0 +1,152 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! This example shows how a list model can be populated incrementally using canFetchMore and fetchMore
+
+/// A CXX-Qt bridge which shows how a list model can lazily page in rows as a view scrolls
+// ANCHOR: book_macro_code
+#[cxx_qt::bridge(cxx_file_stem = "fetch_more")]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!(< QAbstractListModel >);
+
+        include!("cxx-qt-lib/qvariant.h");
+        /// QVariant from cxx_qt_lib
+        type QVariant = cxx_qt_lib::QVariant;
+
+        include!("cxx-qt-lib/qmodelindex.h");
+        /// QModelIndex from cxx_qt_lib
+        type QModelIndex = cxx_qt_lib::QModelIndex;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[base = "QAbstractListModel"]
+        #[qml_element]
+        #[qproperty(i32, page_size)]
+        #[qproperty(i32, total_rows)]
+        type FetchMoreModel = super::FetchMoreModelRust;
+    }
+
+    extern "RustQt" {
+        /// Inherited beginInsertRows from the base class
+        #[inherit]
+        unsafe fn begin_insert_rows(
+            self: Pin<&mut FetchMoreModel>,
+            parent: &QModelIndex,
+            first: i32,
+            last: i32,
+        );
+        /// Inherited endInsertRows from the base class
+        #[inherit]
+        unsafe fn end_insert_rows(self: Pin<&mut FetchMoreModel>);
+    }
+
+    // ANCHOR: book_fetch_more_signature
+    unsafe extern "RustQt" {
+        /// Return whether there are more rows which have not been fetched yet
+        #[qinvokable]
+        #[cxx_override]
+        fn can_fetch_more(self: &FetchMoreModel, parent: &QModelIndex) -> bool;
+
+        /// Fetch the next page of rows into the model
+        #[qinvokable]
+        #[cxx_override]
+        fn fetch_more(self: Pin<&mut FetchMoreModel>, parent: &QModelIndex);
+    }
+    // ANCHOR_END: book_fetch_more_signature
+
+    unsafe extern "RustQt" {
+        /// Retrieve the data for a given index and role
+        #[qinvokable]
+        #[cxx_override]
+        fn data(self: &FetchMoreModel, index: &QModelIndex, role: i32) -> QVariant;
+
+        /// Return the row count for the QAbstractListModel
+        #[qinvokable]
+        #[cxx_override]
+        fn row_count(self: &FetchMoreModel, parent: &QModelIndex) -> i32;
+    }
+}
+
+use core::pin::Pin;
+use cxx_qt::CxxQtType;
+use cxx_qt_lib::{ItemDataRole, QModelIndex, QString, QVariant};
+
+/// A struct which inherits from QAbstractListModel and only fetches rows when the view needs them
+pub struct FetchMoreModelRust {
+    page_size: i32,
+    total_rows: i32,
+
+    rows: Vec<QString>,
+}
+
+impl Default for FetchMoreModelRust {
+    fn default() -> Self {
+        Self {
+            page_size: 20,
+            total_rows: 200,
+            rows: vec![],
+        }
+    }
+}
+
+impl FetchMoreModelRust {
+    /// Load a page of rows, this could instead be a query to a database or a network request
+    fn load_page(first: i32, count: i32) -> impl Iterator<Item = QString> {
+        (first..first + count).map(|row| QString::from(&format!("Row {row}")))
+    }
+}
+
+// ANCHOR: book_fetch_more
+impl qobject::FetchMoreModel {
+    /// Return whether there are more rows which have not been fetched yet
+    pub fn can_fetch_more(&self, parent: &QModelIndex) -> bool {
+        // A list model only has rows under the invalid root index
+        !parent.is_valid() && (self.rows.len() as i32) < self.total_rows
+    }
+
+    /// Fetch the next page of rows into the model
+    pub fn fetch_more(mut self: Pin<&mut Self>, parent: &QModelIndex) {
+        if !self.can_fetch_more(parent) {
+            return;
+        }
+
+        let first = self.rows.len() as i32;
+        let count = (self.total_rows - first).min(self.page_size.max(1));
+        let page = FetchMoreModelRust::load_page(first, count);
+
+        unsafe {
+            self.as_mut()
+                .begin_insert_rows(parent, first, first + count - 1);
+            self.as_mut().rust_mut().rows.extend(page);
+            self.as_mut().end_insert_rows();
+        }
+    }
+}
+// ANCHOR_END: book_fetch_more
+
+impl qobject::FetchMoreModel {
+    /// Retrieve the data for a given index and role
+    pub fn data(&self, index: &QModelIndex, role: i32) -> QVariant {
+        if role == ItemDataRole::DisplayRole.repr {
+            if let Some(row) = self.rows.get(index.row() as usize) {
+                return QVariant::from(row);
+            }
+        }
+
+        QVariant::default()
+    }
+
+    /// Return the row count for the QAbstractListModel
+    pub fn row_count(&self, parent: &QModelIndex) -> i32 {
+        if parent.is_valid() {
+            0
+        } else {
+            self.rows.len() as i32
+        }
+    }
+}
+// ANCHOR_END: book_macro_code
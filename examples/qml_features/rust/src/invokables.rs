@@ -21,6 +21,7 @@ pub mod qobject {
         type RustInvokables = super::RustInvokablesRust;
     }
 
+    // ANCHOR: book_qnamespace
     #[qml_element]
     qnamespace!("Colors");
 
@@ -35,6 +36,7 @@ pub mod qobject {
         /// Blue
         Blue,
     }
+    // ANCHOR_END: book_qnamespace
 
     // ANCHOR: book_invokable_signature
     unsafe extern "RustQt" {
@@ -21,6 +21,7 @@ pub mod qobject {
         fn increment(self: Pin<&mut RustSingleton>);
     }
 }
+// ANCHOR_END: book_macro_code
 
 use core::pin::Pin;
 
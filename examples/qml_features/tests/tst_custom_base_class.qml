@@ -83,9 +83,65 @@ TestCase {
         compare(dataChangedSpy.count, 1);
     }
 
+    function test_move_sort() {
+        const model = createTemporaryObject(componentCustomBaseClass, null, {});
+        const rowsMovedSpy = createTemporaryObject(componentSpy, null, {
+            signalName: "rowsMoved",
+            target: model,
+        });
+        const layoutChangedSpy = createTemporaryObject(componentSpy, null, {
+            signalName: "layoutChanged",
+            target: model,
+        });
+        model.add();
+        model.add();
+        model.add();
+
+        model.moveRow(0, 2);
+        compare(rowsMovedSpy.count, 1);
+        compare(model.data(model.index(0, 0), CustomBaseClass.Id), 1);
+        compare(model.data(model.index(1, 0), CustomBaseClass.Id), 2);
+        compare(model.data(model.index(2, 0), CustomBaseClass.Id), 0);
+
+        // Moving a row onto itself or out of range is ignored
+        model.moveRow(1, 1);
+        model.moveRow(0, 3);
+        compare(rowsMovedSpy.count, 1);
+
+        model.sortByValue();
+        compare(layoutChangedSpy.count, 1);
+        compare(model.data(model.index(0, 0), CustomBaseClass.Id), 0);
+        compare(model.data(model.index(1, 0), CustomBaseClass.Id), 1);
+        compare(model.data(model.index(2, 0), CustomBaseClass.Id), 2);
+    }
+
+    function test_set_data() {
+        const model = createTemporaryObject(componentCustomBaseClass, null, {});
+        const dataChangedSpy = createTemporaryObject(componentSpy, null, {
+            signalName: "dataChanged",
+            target: model,
+        });
+        model.add();
+        compare(model.data(model.index(0, 0), CustomBaseClass.Checked), false);
+
+        compare(model.setData(model.index(0, 0), true, CustomBaseClass.Checked), true);
+        compare(model.data(model.index(0, 0), CustomBaseClass.Checked), true);
+        compare(dataChangedSpy.count, 1);
+
+        compare(model.setData(model.index(0, 0), 4.0, CustomBaseClass.Value), true);
+        compare(model.data(model.index(0, 0), CustomBaseClass.Value), 4.0);
+        compare(dataChangedSpy.count, 2);
+
+        // The id role is read only and rows out of range are ignored
+        compare(model.setData(model.index(0, 0), 10, CustomBaseClass.Id), false);
+        compare(model.setData(model.index(1, 0), true, CustomBaseClass.Checked), false);
+        compare(dataChangedSpy.count, 2);
+    }
+
     function test_roles_qenum() {
         compare(CustomBaseClass.Id, 0);
         compare(CustomBaseClass.Value, 1);
+        compare(CustomBaseClass.Checked, 2);
 
         const model = createTemporaryObject(componentCustomBaseClass, null, {});
 
@@ -0,0 +1,45 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+import QtQuick 2.12
+import QtTest 1.12
+
+import com.kdab.cxx_qt.demo 1.0
+
+TestCase {
+    name: "CustomParentClassTests"
+
+    Component {
+        id: componentCustomParentClass
+
+        CustomParentClass {
+            width: 100
+            height: 50
+        }
+    }
+
+    Component {
+        id: componentItem
+
+        Item {
+
+        }
+    }
+
+    function test_construct() {
+        const obj = createTemporaryObject(componentCustomParentClass, null, {});
+        verify(obj !== null);
+        compare(obj.parent, null);
+    }
+
+    function test_construct_with_parent() {
+        const parentItem = createTemporaryObject(componentItem, null, {});
+        const obj = createTemporaryObject(componentCustomParentClass, parentItem, {
+            color: "red",
+        });
+        compare(obj.parent, parentItem);
+        compare(parentItem.children.length, 1);
+        compare(obj.color, "#ff0000");
+    }
+}
@@ -0,0 +1,59 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+import QtQuick 2.12
+import QtTest 1.12
+
+import com.kdab.cxx_qt.demo 1.0
+
+TestCase {
+    name: "FetchMoreTests"
+
+    Component {
+        id: componentFetchMoreModel
+
+        FetchMoreModel {
+
+        }
+    }
+
+    Component {
+        id: componentSpy
+
+        SignalSpy {
+
+        }
+    }
+
+    function test_fetch_more() {
+        const model = createTemporaryObject(componentFetchMoreModel, null, {
+            pageSize: 20,
+            totalRows: 50,
+        });
+        const rowsInsertedSpy = createTemporaryObject(componentSpy, null, {
+            signalName: "rowsInserted",
+            target: model,
+        });
+        compare(model.rowCount(model.index(-1, -1)), 0);
+        compare(model.canFetchMore(model.index(-1, -1)), true);
+
+        model.fetchMore(model.index(-1, -1));
+        compare(model.rowCount(model.index(-1, -1)), 20);
+        compare(model.data(model.index(0, 0), Qt.DisplayRole), "Row 0");
+        compare(model.data(model.index(19, 0), Qt.DisplayRole), "Row 19");
+
+        model.fetchMore(model.index(-1, -1));
+        model.fetchMore(model.index(-1, -1));
+        // The final page only contains the remaining rows
+        compare(model.rowCount(model.index(-1, -1)), 50);
+        compare(model.data(model.index(49, 0), Qt.DisplayRole), "Row 49");
+        compare(model.canFetchMore(model.index(-1, -1)), false);
+        compare(rowsInsertedSpy.count, 3);
+
+        // Once all the rows are fetched further requests are ignored
+        model.fetchMore(model.index(-1, -1));
+        compare(model.rowCount(model.index(-1, -1)), 50);
+        compare(rowsInsertedSpy.count, 3);
+    }
+}
@@ -10,6 +10,7 @@
 #include <QtTest/QSignalSpy>
 #include <QtTest/QTest>
 
+#include "cxx-qt-gen/connect.cxxqt.h"
 #include "cxx-qt-gen/empty.cxxqt.h"
 #include "cxx-qt-gen/locking.cxxqt.h"
 #include "cxx-qt-gen/my_data.cxxqt.h"
@@ -109,6 +110,62 @@ private Q_SLOTS:
              QStringLiteral("{\"number\":16,\"string\":\"Hello\"}"));
   }
 
+  // Ensure that a signal can be connected to an invokable and to a signal
+  void test_connect_signal_to()
+  {
+    cxx_qt::connect::Model model;
+    cxx_qt::connect::View view;
+    QSignalSpy forwardedSpy(&view, &cxx_qt::connect::View::forwarded);
+
+    view.connectTo(model);
+    QCOMPARE(view.getRefreshed(), 0);
+    QCOMPARE(forwardedSpy.count(), 0);
+
+    Q_EMIT model.dataChanged(5);
+    QCOMPARE(view.getRefreshed(), 5);
+    QCOMPARE(forwardedSpy.count(), 1);
+    QCOMPARE(forwardedSpy.takeFirst().at(0).toInt(), 5);
+  }
+
+  // Ensure that a property binding writes the value until it is disconnected
+  void test_bind_property()
+  {
+    cxx_qt::connect::Model model;
+    cxx_qt::connect::View view;
+    model.setNumber(1);
+
+    // The target is written with the current value when it is bound
+    auto conn = view.bindNumber(model);
+    QCOMPARE(view.getNumber(), 1);
+
+    model.setNumber(2);
+    QCOMPARE(view.getNumber(), 2);
+
+    // Once disconnected the target is no longer written
+    QVERIFY(QObject::disconnect(conn));
+    model.setNumber(3);
+    QCOMPARE(view.getNumber(), 2);
+
+    // A read only property cannot be the target of a binding
+    QVERIFY(view.bindReadOnlyFails(model));
+    QCOMPARE(view.getRefreshed(), 0);
+  }
+
+  // Ensure that a property binding is removed when the target is destroyed
+  void test_bind_property_target_destroyed()
+  {
+    cxx_qt::connect::Model model;
+    {
+      cxx_qt::connect::View view;
+      view.bindNumber(model);
+      QCOMPARE(view.getNumber(), 0);
+    }
+
+    // Writing to the destroyed target would crash
+    model.setNumber(1);
+    QCOMPARE(model.getNumber(), 1);
+  }
+
   // Ensure that locking can be disabled
   void test_locking_disabled()
   {
@@ -188,6 +245,18 @@ private Q_SLOTS:
     QTRY_COMPARE(obj.fetchUpdateCallCount(), 100);
   }
 
+  // CXX-Qt allows Rust code to check if the QObject has been destroyed
+  void test_queue_destroyed()
+  {
+    cxx_qt::my_object::MyObject checker;
+    {
+      cxx_qt::my_object::MyObject obj;
+      obj.storeQtThread();
+      QCOMPARE(checker.storedQtThreadIsDestroyed(), false);
+    }
+    QCOMPARE(checker.storedQtThreadIsDestroyed(), true);
+  }
+
   // CXX-Qt types are exposed to C++ correctly
   void test_primitive_types()
   {
@@ -0,0 +1,110 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+/// Two QObjects that allow for testing the typed connections and bindings
+#[cxx_qt::bridge(cxx_file_stem = "connect", namespace = "cxx_qt::connect")]
+pub mod qobject {
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmetaobjectconnection.h");
+        type QMetaObjectConnection = cxx_qt_lib::QMetaObjectConnection;
+    }
+
+    unsafe extern "RustQt" {
+        /// A QObject which is the sender of the connections and the source of the bindings
+        #[qobject]
+        #[qproperty(i32, number)]
+        type Model = super::ModelRust;
+
+        #[qsignal]
+        fn data_changed(self: Pin<&mut Model>, value: i32);
+    }
+
+    unsafe extern "RustQt" {
+        /// A QObject which is the receiver of the connections and the target of the bindings
+        #[qobject]
+        #[qproperty(i32, number)]
+        #[qproperty(i32, refreshed, read_only)]
+        type View = super::ViewRust;
+
+        #[qsignal]
+        fn forwarded(self: Pin<&mut View>, value: i32);
+
+        #[qinvokable]
+        fn refresh(self: Pin<&mut View>, value: i32);
+
+        fn connect_to(self: Pin<&mut View>, model: &Model);
+
+        fn bind_number(self: Pin<&mut View>, model: &Model) -> QMetaObjectConnection;
+
+        fn bind_read_only_fails(self: Pin<&mut View>, model: &Model) -> bool;
+    }
+
+    impl cxx_qt_lib::Connect for Model {}
+    impl cxx_qt_lib::Connect for View {}
+}
+
+use core::pin::Pin;
+use cxx_qt::CxxQtType;
+use cxx_qt_lib::{bind_property, connect_signal_to, ConnectionType, QMetaObjectConnection};
+use qobject::{Model, View};
+
+#[derive(Default)]
+pub struct ModelRust {
+    number: i32,
+}
+
+#[derive(Default)]
+pub struct ViewRust {
+    number: i32,
+    refreshed: i32,
+}
+
+impl qobject::View {
+    fn refresh(mut self: Pin<&mut Self>, value: i32) {
+        self.as_mut().rust_mut().refreshed = value;
+        self.refreshed_changed();
+    }
+
+    fn connect_to(self: Pin<&mut Self>, model: &Model) {
+        // Connect the signal to an invokable and forward it to another signal
+        connect_signal_to(
+            model,
+            Model::data_changed_signal(),
+            &*self,
+            View::refresh_slot(),
+            ConnectionType::AutoConnection,
+        )
+        .release();
+        connect_signal_to(
+            model,
+            Model::data_changed_signal(),
+            &*self,
+            View::forwarded_signal(),
+            ConnectionType::AutoConnection,
+        )
+        .release();
+    }
+
+    fn bind_number(self: Pin<&mut Self>, model: &Model) -> QMetaObjectConnection {
+        bind_property(
+            model,
+            Model::number_property(),
+            self,
+            View::number_property(),
+        )
+        .expect("number is writable")
+    }
+
+    fn bind_read_only_fails(self: Pin<&mut Self>, model: &Model) -> bool {
+        bind_property(
+            model,
+            Model::number_property(),
+            self,
+            View::refreshed_property(),
+        )
+        .is_err()
+    }
+}
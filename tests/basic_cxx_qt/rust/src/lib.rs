@@ -4,6 +4,7 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+mod connect;
 mod data;
 mod empty;
 mod locking;
@@ -41,13 +42,21 @@ mod qobject {
 
         fn fetch_update_call_count(self: &MyObject) -> i32;
 
+        fn store_qt_thread(self: &MyObject);
+
+        fn stored_qt_thread_is_destroyed(self: &MyObject) -> bool;
+
         fn throw_exception(self: &MyObject) -> Result<i32>;
     }
 }
 
 use core::pin::Pin;
-use cxx_qt::{CxxQtType, Threading};
+use cxx_qt::{CxxQtThread, CxxQtType, Threading};
 use cxx_qt_lib::QString;
+use std::sync::Mutex;
+
+// A CxxQtThread which outlives the MyObject it was created from
+static STORED_QT_THREAD: Mutex<Option<CxxQtThread<qobject::MyObject>>> = Mutex::new(None);
 
 pub struct MyObjectRust {
     number: i32,
@@ -122,6 +131,18 @@ impl qobject::MyObject {
         self.update_call_count
     }
 
+    fn store_qt_thread(&self) {
+        *STORED_QT_THREAD.lock().unwrap() = Some(self.qt_thread());
+    }
+
+    fn stored_qt_thread_is_destroyed(&self) -> bool {
+        STORED_QT_THREAD
+            .lock()
+            .unwrap()
+            .as_ref()
+            .is_some_and(CxxQtThread::is_destroyed)
+    }
+
     fn throw_exception(&self) -> Result<i32, String> {
         Err("RustException".to_string())
     }
@@ -7,39 +7,95 @@
 #include <QtCore/QScopedPointer>
 #include <QtTest/QTest>
 
+#ifdef CXX_QT_SENSORS_FEATURE
+#include "qaccelerometer.h"
+#endif
+#ifdef CXX_QT_BLUETOOTH_FEATURE
+#include "qbluetoothdevicediscoveryagent.h"
+#include "qbluetoothdeviceinfo.h"
+#endif
 #include "qbytearray.h"
+#include "qcborvalue.h"
+#include "qconcatenatetablesproxymodel.h"
 #include "qcolor.h"
+#include "qcontainer_custom.h"
 #include "qcoreapplication.h"
+#include "qcryptographichash.h"
+#include "qdatastream.h"
 #include "qdate.h"
 #include "qdatetime.h"
+#include "qevent.h"
+#include "qfilesystemwatcher.h"
 #include "qguiapplication.h"
+#ifdef CXX_QT_SENSORS_FEATURE
+#include "qgyroscope.h"
+#endif
 #include "qhash.h"
+#ifdef CXX_QT_KEYCHAIN_FEATURE
+#include "qkeychain.h"
+#endif
 #include "qlist.h"
+#ifdef CXX_QT_NETWORK_FEATURE
+#include "qlocalserver.h"
+#include "qlocalsocket.h"
+#endif
+#ifdef CXX_QT_BLUETOOTH_FEATURE
+#include "qlowenergycontroller.h"
+#endif
 #include "qmap.h"
 #include "qmargins.h"
 #include "qmarginsf.h"
+#include "qmatrix4x4.h"
 #include "qmetaobjectconnection.h"
 #include "qmodelindex.h"
+#ifdef CXX_QT_NETWORKINFORMATION_FEATURE
+#include "qnetworkinformation.h"
+#endif
 #include "qpersistentmodelindex.h"
 #include "qpoint.h"
 #include "qpointf.h"
 #include "qqmlapplicationengine.h"
 #include "qqmlengine.h"
+#include "qquaternion.h"
+#ifdef CXX_QT_QUICK3D_FEATURE
+#include "qquick3dgeometry.h"
+#endif
 #include "qrect.h"
 #include "qrectf.h"
+#ifdef CXX_QT_SERIALPORT_FEATURE
+#include "qserialport.h"
+#endif
 #include "qset.h"
+#include "qsharedmemory.h"
 #include "qsize.h"
 #include "qsizef.h"
+#ifdef CXX_QT_SQL_FEATURE
+#include "qsqldatabase.h"
+#include "qsqlquery.h"
+#include "qsqlquerymodel.h"
+#include "qsqltablemodel.h"
+#endif
+#ifdef CXX_QT_STATEMACHINE_FEATURE
+#include "qstatemachine.h"
+#endif
 #include "qstring.h"
 #include "qstringlist.h"
+#include "qstringlistmodel.h"
+#include "qsystemsemaphore.h"
+#include "qtemporaryfile.h"
 #include "qtime.h"
 #include "qtimezone.h"
+#include "qtlogging.h"
 #include "qurl.h"
 #include "qvariant.h"
 #include "qvector.h"
 #include "qvector2d.h"
 #include "qvector3d.h"
 #include "qvector4d.h"
+#ifdef CXX_QT_WEBSOCKETS_FEATURE
+#include "qwebsocket.h"
+#include "qwebsocketserver.h"
+#endif
 
 int
 main(int argc, char* argv[])
@@ -53,39 +109,95 @@ main(int argc, char* argv[])
     }
   };
 
+#ifdef CXX_QT_SENSORS_FEATURE
+  runTest(QScopedPointer<QObject>(new QAccelerometerTest));
+#endif
+#ifdef CXX_QT_BLUETOOTH_FEATURE
+  runTest(QScopedPointer<QObject>(new QBluetoothDeviceDiscoveryAgentTest));
+  runTest(QScopedPointer<QObject>(new QBluetoothDeviceInfoTest));
+#endif
   runTest(QScopedPointer<QObject>(new QByteArrayTest));
+  runTest(QScopedPointer<QObject>(new QCborValueTest));
+  runTest(QScopedPointer<QObject>(new QConcatenateTablesProxyModelTest));
   runTest(QScopedPointer<QObject>(new QColorTest));
+  runTest(QScopedPointer<QObject>(new QContainerCustomTest));
   runTest(QScopedPointer<QObject>(new QCoreApplicationTest));
+  runTest(QScopedPointer<QObject>(new QCryptographicHashTest));
+  runTest(QScopedPointer<QObject>(new QDataStreamTest));
   runTest(QScopedPointer<QObject>(new QDateTest));
   runTest(QScopedPointer<QObject>(new QDateTimeTest));
+  runTest(QScopedPointer<QObject>(new QEventTest));
+  runTest(QScopedPointer<QObject>(new QFileSystemWatcherTest));
   runTest(QScopedPointer<QObject>(new QGuiApplicationTest));
+#ifdef CXX_QT_SENSORS_FEATURE
+  runTest(QScopedPointer<QObject>(new QGyroscopeTest));
+#endif
   runTest(QScopedPointer<QObject>(new QHashTest));
+#ifdef CXX_QT_KEYCHAIN_FEATURE
+  runTest(QScopedPointer<QObject>(new QKeychainTest));
+#endif
   runTest(QScopedPointer<QObject>(new QListTest));
+#ifdef CXX_QT_NETWORK_FEATURE
+  runTest(QScopedPointer<QObject>(new QLocalServerTest));
+  runTest(QScopedPointer<QObject>(new QLocalSocketTest));
+#endif
+#ifdef CXX_QT_BLUETOOTH_FEATURE
+  runTest(QScopedPointer<QObject>(new QLowEnergyControllerTest));
+#endif
   runTest(QScopedPointer<QObject>(new QMapTest));
   runTest(QScopedPointer<QObject>(new QMarginsTest));
   runTest(QScopedPointer<QObject>(new QMarginsFTest));
+  runTest(QScopedPointer<QObject>(new QMatrix4x4Test));
   runTest(QScopedPointer<QObject>(new QMetaObjectConnectionTest));
   runTest(QScopedPointer<QObject>(new QModelIndexTest));
+#ifdef CXX_QT_NETWORKINFORMATION_FEATURE
+  runTest(QScopedPointer<QObject>(new QNetworkInformationTest));
+#endif
   runTest(QScopedPointer<QObject>(new QPersistentModelIndexTest));
   runTest(QScopedPointer<QObject>(new QPointTest));
   runTest(QScopedPointer<QObject>(new QPointFTest));
   runTest(QScopedPointer<QObject>(new QQmlApplicationEngineTest));
   runTest(QScopedPointer<QObject>(new QQmlEngineTest));
+  runTest(QScopedPointer<QObject>(new QQuaternionTest));
+#ifdef CXX_QT_QUICK3D_FEATURE
+  runTest(QScopedPointer<QObject>(new QQuick3DGeometryTest));
+#endif
   runTest(QScopedPointer<QObject>(new QRectTest));
   runTest(QScopedPointer<QObject>(new QRectFTest));
+#ifdef CXX_QT_SERIALPORT_FEATURE
+  runTest(QScopedPointer<QObject>(new QSerialPortTest));
+#endif
   runTest(QScopedPointer<QObject>(new QSetTest));
+  runTest(QScopedPointer<QObject>(new QSharedMemoryTest));
   runTest(QScopedPointer<QObject>(new QSizeTest));
   runTest(QScopedPointer<QObject>(new QSizeFTest));
+#ifdef CXX_QT_SQL_FEATURE
+  runTest(QScopedPointer<QObject>(new QSqlDatabaseTest));
+  runTest(QScopedPointer<QObject>(new QSqlQueryTest));
+  runTest(QScopedPointer<QObject>(new QSqlQueryModelTest));
+  runTest(QScopedPointer<QObject>(new QSqlTableModelTest));
+#endif
+#ifdef CXX_QT_STATEMACHINE_FEATURE
+  runTest(QScopedPointer<QObject>(new QStateMachineTest));
+#endif
   runTest(QScopedPointer<QObject>(new QStringTest));
   runTest(QScopedPointer<QObject>(new QStringListTest));
+  runTest(QScopedPointer<QObject>(new QStringListModelTest));
+  runTest(QScopedPointer<QObject>(new QSystemSemaphoreTest));
+  runTest(QScopedPointer<QObject>(new QTemporaryFileTest));
   runTest(QScopedPointer<QObject>(new QTimeTest));
   runTest(QScopedPointer<QObject>(new QTimeZoneTest));
+  runTest(QScopedPointer<QObject>(new QtLoggingTest));
   runTest(QScopedPointer<QObject>(new QUrlTest));
   runTest(QScopedPointer<QObject>(new QVariantTest));
   runTest(QScopedPointer<QObject>(new QVectorTest));
   runTest(QScopedPointer<QObject>(new QVector2DTest));
   runTest(QScopedPointer<QObject>(new QVector3DTest));
   runTest(QScopedPointer<QObject>(new QVector4DTest));
+#ifdef CXX_QT_WEBSOCKETS_FEATURE
+  runTest(QScopedPointer<QObject>(new QWebSocketTest));
+  runTest(QScopedPointer<QObject>(new QWebSocketServerTest));
+#endif
 
   return status;
 }
@@ -0,0 +1,108 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCoreApplication>
+#include <QtCore/QTimer>
+#include <QtSensors/QAccelerometer>
+#include <QtSensors/QSensorBackend>
+#include <QtSensors/QSensorManager>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qaccelerometer_cxx.cxx.h"
+
+// A backend which reports a single reading once the sensor has been started
+class TestQAccelerometerBackend : public QSensorBackend
+{
+public:
+  explicit TestQAccelerometerBackend(QSensor* sensor)
+    : QSensorBackend(sensor)
+  {
+    setReading<QAccelerometerReading>(&m_reading);
+    addDataRate(1, 100);
+  }
+
+  void start() override
+  {
+    QTimer::singleShot(0, this, [this]() {
+      m_reading.setX(1.0);
+      m_reading.setY(2.5);
+      m_reading.setZ(-3.0);
+      m_reading.setTimestamp(42);
+      newReadingAvailable();
+    });
+  }
+
+  void stop() override {}
+
+private:
+  QAccelerometerReading m_reading;
+};
+
+class TestQAccelerometerBackendFactory : public QSensorBackendFactory
+{
+public:
+  QSensorBackend* createBackend(QSensor* sensor) override
+  {
+    return new TestQAccelerometerBackend(sensor);
+  }
+};
+
+class QAccelerometerTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void initTestCase()
+  {
+    QSensorManager::registerBackend(
+      QAccelerometer::sensorType, m_identifier, &m_factory);
+    QSensorManager::setDefaultBackend(QAccelerometer::sensorType, m_identifier);
+  }
+
+  void cleanupTestCase()
+  {
+    QSensorManager::unregisterBackend(QAccelerometer::sensorType, m_identifier);
+  }
+
+  void construct()
+  {
+    const auto sensor = construct_qaccelerometer();
+    QVERIFY(sensor != nullptr);
+    QVERIFY(!sensor->isActive());
+    QCOMPARE(sensor->dataRate(), 10);
+    QCOMPARE(sensor->accelerationMode(), QAccelerometer::Gravity);
+  }
+
+  void readings()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    auto sensor = construct_qaccelerometer();
+    QVERIFY(qaccelerometer_start(*sensor));
+    QVERIFY(sensor->isActive());
+    QVERIFY(sensor->isConnectedToBackend());
+
+    QString readings;
+    QVERIFY(QTest::qWaitFor([&readings]() {
+      readings = qaccelerometer_take_readings();
+      return !readings.isEmpty();
+    }));
+    QCOMPARE(readings, QStringLiteral("1,2.5,-3,42"));
+
+    sensor->stop();
+    QVERIFY(!sensor->isActive());
+  }
+
+private:
+  const QByteArray m_identifier = QByteArrayLiteral("cxx-qt-test");
+  TestQAccelerometerBackendFactory m_factory;
+};
@@ -0,0 +1,33 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtBluetooth/QBluetoothDeviceDiscoveryAgent>
+#include <QtCore/QCoreApplication>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qbluetoothdevicediscoveryagent_cxx.cxx.h"
+
+class QBluetoothDeviceDiscoveryAgentTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    const auto agent = construct_qbluetoothdevicediscoveryagent();
+    QVERIFY(agent != nullptr);
+    QCOMPARE(agent->lowEnergyDiscoveryTimeout(), 5000);
+    QVERIFY(!agent->isActive());
+  }
+};
@@ -0,0 +1,53 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtBluetooth/QBluetoothAddress>
+#include <QtBluetooth/QBluetoothDeviceInfo>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qbluetoothdeviceinfo_cxx.cxx.h"
+
+class QBluetoothDeviceInfoTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void read()
+  {
+    QBluetoothDeviceInfo info(
+      QBluetoothAddress(QStringLiteral("00:11:22:33:44:55")),
+      QStringLiteral("Device"),
+      0);
+    info.setCoreConfigurations(
+      QBluetoothDeviceInfo::LowEnergyCoreConfiguration);
+    info.setRssi(-42);
+
+    QCOMPARE(read_qbluetoothdeviceinfo(info),
+             QStringLiteral("00:11:22:33:44:55,Device,true,-42,true"));
+  }
+
+  void readInvalid()
+  {
+    const QBluetoothDeviceInfo info;
+    QCOMPARE(read_qbluetoothdeviceinfo(info),
+             QStringLiteral("00:00:00:00:00:00,,false,%1,false")
+               .arg(info.rssi()));
+  }
+
+  void clone()
+  {
+    QBluetoothDeviceInfo info(
+      QBluetoothAddress(QStringLiteral("00:11:22:33:44:55")),
+      QStringLiteral("Device"),
+      0);
+    const auto c = clone_qbluetoothdeviceinfo(info);
+    QVERIFY(c != nullptr);
+    QCOMPARE(*c, info);
+    QCOMPARE(c->name(), QStringLiteral("Device"));
+  }
+};
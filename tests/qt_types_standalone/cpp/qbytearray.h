@@ -54,4 +54,17 @@ private Q_SLOTS:
   }
 
   void can_use_as_slice_cpp() { QVERIFY(can_use_as_slice()); }
+
+  void can_borrow_as_str_cpp() { QVERIFY(can_borrow_as_str()); }
+
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  void borrow_view_as_slice()
+  {
+    const auto s = QByteArrayLiteral("Bytes borrowed from C++");
+    const auto view = QByteArrayView(s);
+    const auto slice = ::rust::cxxqtlib1::qbytearrayViewAsSlice(view);
+    QVERIFY(read_byte_slice(slice));
+    QCOMPARE(::rust::cxxqtlib1::qbytearrayViewFromSlice(slice), view);
+  }
+#endif
 };
@@ -0,0 +1,48 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCborArray>
+#include <QtCore/QCborMap>
+#include <QtCore/QCborValue>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qcborvalue_cxx.cxx.h"
+
+class QCborValueTest : public QObject
+{
+  Q_OBJECT
+
+private:
+  static QCborValue createValue()
+  {
+    QCborMap map;
+    map.insert(QStringLiteral("name"), QStringLiteral("KDAB"));
+    map.insert(1, QCborArray{ true, 1.5, nullptr });
+    return map;
+  }
+
+private Q_SLOTS:
+  void construct() { QCOMPARE(construct_qcborvalue(), createValue()); }
+
+  void read() { QVERIFY(read_qcborvalue(createValue())); }
+
+  void clone()
+  {
+    const auto v = createValue();
+    const auto c = clone_qcborvalue(v);
+    QCOMPARE(c, v);
+  }
+
+  void fromCbor()
+  {
+    const auto v = createValue();
+    QCOMPARE(qcborvalue_from_cbor(v.toCbor()), v);
+    // A map header with a missing entry
+    QVERIFY(qcborvalue_from_invalid_cbor(QByteArray::fromHex("a1")));
+  }
+};
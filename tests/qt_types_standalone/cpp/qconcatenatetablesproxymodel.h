@@ -0,0 +1,44 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QConcatenateTablesProxyModel>
+#include <QtCore/QIdentityProxyModel>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qconcatenatetablesproxymodel_cxx.cxx.h"
+
+class QConcatenateTablesProxyModelTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void concatenate()
+  {
+    auto test = new_proxy_model_test();
+    QConcatenateTablesProxyModel& model = test->concatenate();
+    QCOMPARE(model.rowCount(), 3);
+    QCOMPARE(model.data(model.index(0, 0)).toString(), QStringLiteral("Red"));
+    QCOMPARE(model.data(model.index(2, 0)).toString(), QStringLiteral("Blue"));
+
+    test->remove_second_model();
+    QCOMPARE(model.rowCount(), 2);
+  }
+
+  void identity()
+  {
+    auto test = new_proxy_model_test();
+    QIdentityProxyModel& model = test->identity();
+    QCOMPARE(model.sourceModel(),
+             static_cast<QAbstractItemModel*>(&test->concatenate()));
+    QCOMPARE(model.rowCount(), 3);
+    QCOMPARE(model.data(model.index(1, 0)).toString(), QStringLiteral("Green"));
+
+    test->remove_second_model();
+    QCOMPARE(model.rowCount(), 2);
+  }
+};
@@ -0,0 +1,76 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QHash>
+#include <QtCore/QList>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qcontainer_custom_cxx.cxxqt.h"
+
+using ::cxx_qt::containers::CustomPoint;
+
+class QContainerCustomTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void constructList()
+  {
+    const auto list = ::cxx_qt::containers::construct_qlist_custom();
+    QCOMPARE(list.size(), 3);
+    QVERIFY(list.at(0) == (CustomPoint{ 0, 0 }));
+    QVERIFY(list.at(2) == (CustomPoint{ 3, 4 }));
+  }
+
+  void readList()
+  {
+    auto list = ::cxx_qt::containers::QList_CustomPoint();
+    list.append(CustomPoint{ 1, 2 });
+    list.append(CustomPoint{ 3, 4 });
+    QVERIFY(::cxx_qt::containers::read_qlist_custom(list));
+  }
+
+  void cloneList()
+  {
+    auto list = ::cxx_qt::containers::QList_CustomPoint();
+    list.append(CustomPoint{ 0, 0 });
+    list.append(CustomPoint{ 1, 2 });
+    const auto c = ::cxx_qt::containers::clone_qlist_custom(list);
+    QCOMPARE(c.size(), 1);
+    QVERIFY(c.at(0) == (CustomPoint{ 1, 2 }));
+    // The original list is not modified
+    QCOMPARE(list.size(), 2);
+  }
+
+  void constructHash()
+  {
+    const auto hash = ::cxx_qt::containers::construct_qhash_custom();
+    QCOMPARE(hash.size(), 2);
+    QVERIFY(hash.value(QStringLiteral("corner")) == (CustomPoint{ 10, 20 }));
+    QVERIFY(hash.contains(QStringLiteral("origin")));
+  }
+
+  void readHash()
+  {
+    auto hash = ::cxx_qt::containers::QHash_QString_CustomPoint();
+    hash.insert(QStringLiteral("origin"), CustomPoint{ 0, 0 });
+    hash.insert(QStringLiteral("corner"), CustomPoint{ 10, 20 });
+    QVERIFY(::cxx_qt::containers::read_qhash_custom(hash));
+  }
+
+  void cloneHash()
+  {
+    auto hash = ::cxx_qt::containers::QHash_QString_CustomPoint();
+    hash.insert(QStringLiteral("origin"), CustomPoint{ 0, 0 });
+    hash.insert(QStringLiteral("corner"), CustomPoint{ 10, 20 });
+    const auto c = ::cxx_qt::containers::clone_qhash_custom(hash);
+    QCOMPARE(c.size(), 1);
+    QVERIFY(!c.contains(QStringLiteral("origin")));
+    QCOMPARE(hash.size(), 2);
+  }
+};
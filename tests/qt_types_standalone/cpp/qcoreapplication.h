@@ -7,6 +7,7 @@
 #pragma once
 
 #include <QtCore/QCoreApplication>
+#include <QtCore/QTimer>
 #include <QtTest/QTest>
 
 #include "cxx-qt-gen/qcoreapplication_cxx.cxx.h"
@@ -34,4 +35,20 @@ private Q_SLOTS:
     app.setApplicationName(QStringLiteral("kdab"));
     QVERIFY(read_qcoreapplication(app));
   }
+
+  void aboutToQuit()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+
+    QCoreApplication app(argc, args.data());
+    connect_qcoreapplication_about_to_quit(app);
+    QCOMPARE(qcoreapplication_about_to_quit_count(), 0);
+
+    QTimer::singleShot(0, &app, &QCoreApplication::quit);
+    QCOMPARE(app.exec(), 0);
+    QCOMPARE(qcoreapplication_about_to_quit_count(), 1);
+  }
 };
@@ -0,0 +1,40 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCryptographicHash>
+#include <QtCore/QMessageAuthenticationCode>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qcryptographichash_cxx.cxx.h"
+
+class QCryptographicHashTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void hash()
+  {
+    const auto data = QByteArrayLiteral("The quick brown fox");
+    const auto expected =
+      QCryptographicHash::hash(data, QCryptographicHash::Sha256);
+    QCOMPARE(qcryptographichash_sha256(data), expected);
+    QCOMPARE(qcryptographichash_sha256_incremental(data), expected);
+    QCOMPARE(qcryptographichash_sha256_length(), 32);
+  }
+
+  void messageAuthenticationCode()
+  {
+    const auto message = QByteArrayLiteral("The quick brown fox");
+    const auto key = QByteArrayLiteral("secret");
+    const auto expected = QMessageAuthenticationCode::hash(
+      message, key, QCryptographicHash::Sha256);
+    QCOMPARE(qmessageauthenticationcode_sha256(message, key), expected);
+    QCOMPARE(qmessageauthenticationcode_sha256_incremental(message, key),
+             expected);
+  }
+};
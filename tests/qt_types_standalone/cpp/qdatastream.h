@@ -0,0 +1,48 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QDataStream>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qdatastream_cxx.cxx.h"
+
+class QDataStreamTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void read()
+  {
+    QByteArray bytes;
+    {
+      QDataStream stream(&bytes, QIODevice::WriteOnly);
+      stream.setVersion(QDataStream::Qt_5_15);
+      stream << QStringLiteral("KDAB") << qint32(42) << qint64(-1) << 1.5;
+    }
+    QVERIFY(read_qdatastream(bytes));
+  }
+
+  void write()
+  {
+    const auto bytes = write_qdatastream();
+    QDataStream stream(bytes);
+    stream.setVersion(QDataStream::Qt_5_15);
+
+    QString string;
+    qint32 number = 0;
+    qint64 large = 0;
+    double value = 0.0;
+    stream >> string >> number >> large >> value;
+    QCOMPARE(stream.status(), QDataStream::Ok);
+    QCOMPARE(string, QStringLiteral("KDAB"));
+    QCOMPARE(number, 42);
+    QCOMPARE(large, qint64(-1));
+    QCOMPARE(value, 1.5);
+    QVERIFY(stream.atEnd());
+  }
+};
@@ -0,0 +1,146 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCoreApplication>
+#include <QtCore/QEvent>
+#include <QtCore/QObject>
+#include <QtGui/QKeyEvent>
+#include <QtGui/QKeySequence>
+#include <QtGui/QMouseEvent>
+#include <QtGui/QShortcutEvent>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qevent_cxx.cxx.h"
+
+// A QObject which counts the key events that it handles
+class KeyCounter : public QObject
+{
+public:
+  bool event(QEvent* event) override
+  {
+    if (event->type() == QEvent::KeyPress) {
+      m_keyPresses++;
+      return true;
+    }
+
+    return QObject::event(event);
+  }
+
+  int m_keyPresses = 0;
+};
+
+class QEventTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void asTimerEvent()
+  {
+    QTimerEvent timerEvent(7);
+    QCOMPARE(qevent_timer_id(timerEvent), 7);
+
+    QEvent other(QEvent::Show);
+    QCOMPARE(qevent_timer_id(other), -1);
+  }
+
+  void asKeyEvent()
+  {
+    QKeyEvent keyEvent(QEvent::KeyPress, Qt::Key_A, Qt::NoModifier);
+    QCOMPARE(qevent_key(keyEvent), static_cast<int>(Qt::Key_A));
+
+    QTimerEvent other(7);
+    QCOMPARE(qevent_key(other), -1);
+  }
+
+  void asMouseEvent()
+  {
+    QMouseEvent mouseEvent(QEvent::MouseButtonPress,
+                           QPointF(1.0, 2.0),
+                           QPointF(1.0, 2.0),
+                           Qt::RightButton,
+                           Qt::RightButton,
+                           Qt::NoModifier);
+    QCOMPARE(qevent_mouse_button(mouseEvent),
+             static_cast<int>(Qt::RightButton));
+
+    QKeyEvent other(QEvent::KeyPress, Qt::Key_A, Qt::NoModifier);
+    QCOMPARE(qevent_mouse_button(other), -1);
+  }
+
+  void asShortcutEvent()
+  {
+#if QT_VERSION >= QT_VERSION_CHECK(6, 5, 0)
+    QShortcutEvent shortcutEvent(QKeySequence(QStringLiteral("Ctrl+S")),
+                                 nullptr);
+#else
+    QShortcutEvent shortcutEvent(QKeySequence(QStringLiteral("Ctrl+S")), 0);
+#endif
+    QCOMPARE(qevent_shortcut_key(shortcutEvent), QStringLiteral("Ctrl+S"));
+
+    QEvent other(QEvent::Show);
+    QCOMPARE(qevent_shortcut_key(other), QString());
+  }
+
+  void eventFilter()
+  {
+    auto* counter = new KeyCounter();
+    qevent_install_filter(*counter);
+    QVERIFY(!qevent_filter_dropped());
+
+    // Every second key event is stopped by the filter
+    for (int i = 0; i < 4; i++) {
+      QKeyEvent keyEvent(QEvent::KeyPress, Qt::Key_A, Qt::NoModifier);
+      QCoreApplication::sendEvent(counter, &keyEvent);
+    }
+    QCOMPARE(counter->m_keyPresses, 2);
+
+    // Other events are not stopped
+    QEvent other(QEvent::Show);
+    QCoreApplication::sendEvent(counter, &other);
+
+    // The closure is dropped with the object
+    delete counter;
+    QVERIFY(qevent_filter_dropped());
+  }
+
+  void customEvent()
+  {
+    auto event = qevent_new_custom(QStringLiteral("payload"));
+    QVERIFY(event != nullptr);
+    QVERIFY(event->type() >= QEvent::User);
+    QVERIFY(event->type() <= QEvent::MaxUser);
+    QCOMPARE(qevent_custom_text(*event), QStringLiteral("payload"));
+    QVERIFY(!qevent_custom_is_number(*event));
+
+    // The payload is dropped with the event
+    QVERIFY(!qevent_custom_dropped());
+    event.reset();
+    QVERIFY(qevent_custom_dropped());
+  }
+
+  void customEventPosted()
+  {
+    auto* counter = new KeyCounter();
+    auto event = qevent_new_custom(QStringLiteral("posted"));
+    const auto type = event->type();
+    QCoreApplication::postEvent(counter, event.release());
+    QVERIFY(!qevent_custom_dropped());
+
+    // The event is destroyed once it has been delivered
+    QCoreApplication::sendPostedEvents(counter, type);
+    QVERIFY(qevent_custom_dropped());
+    delete counter;
+  }
+
+  void customPayloadOtherEvent()
+  {
+    QEvent other(QEvent::Show);
+    QCOMPARE(qevent_custom_text(other), QString());
+    QVERIFY(!qevent_custom_is_number(other));
+  }
+};
@@ -0,0 +1,60 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCoreApplication>
+#include <QtCore/QFile>
+#include <QtCore/QTemporaryDir>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qfilesystemwatcher_cxx.cxx.h"
+
+class QFileSystemWatcherTest : public QObject
+{
+  Q_OBJECT
+
+private:
+  static void appendToFile(const QString& fileName)
+  {
+    QFile file(fileName);
+    QVERIFY(file.open(QIODevice::Append));
+    QVERIFY(file.write(QByteArrayLiteral("KDAB")) > 0);
+  }
+
+private Q_SLOTS:
+  void fileChanged()
+  {
+    // QFileSystemWatcher requires a QCoreApplication for the event loop
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    QTemporaryDir dir;
+    QVERIFY(dir.isValid());
+    const auto fileName = dir.filePath(QStringLiteral("watched.txt"));
+    appendToFile(fileName);
+
+    auto test = new_file_system_watcher_test(fileName);
+    QVERIFY(test->changed_paths().empty());
+
+    // The closure is called from the event loop
+    appendToFile(fileName);
+    QTRY_VERIFY(!test->changed_paths().empty());
+    QCOMPARE(QString::fromUtf8(test->changed_paths().front().data(),
+                               test->changed_paths().front().size()),
+             fileName);
+
+    // Once disconnected the closure is no longer called
+    test->disconnect();
+    const auto count = test->changed_paths().size();
+    appendToFile(fileName);
+    QTest::qWait(100);
+    QCOMPARE(test->changed_paths().size(), count);
+  }
+};
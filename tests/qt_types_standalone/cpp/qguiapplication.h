@@ -7,6 +7,7 @@
 #pragma once
 
 #include <QtGui/QGuiApplication>
+#include <QtCore/QTimer>
 #include <QtTest/QTest>
 
 #include "cxx-qt-gen/qguiapplication_cxx.cxx.h"
@@ -34,4 +35,20 @@ private Q_SLOTS:
     app.setApplicationName(QStringLiteral("kdab"));
     QVERIFY(read_qguiapplication(app));
   }
+
+  void aboutToQuit()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+
+    QGuiApplication app(argc, args.data());
+    connect_qguiapplication_about_to_quit(app);
+    QCOMPARE(qguiapplication_about_to_quit_count(), 0);
+
+    QTimer::singleShot(0, &app, &QCoreApplication::quit);
+    QCOMPARE(app.exec(), 0);
+    QCOMPARE(qguiapplication_about_to_quit_count(), 1);
+  }
 };
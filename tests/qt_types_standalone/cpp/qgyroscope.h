@@ -0,0 +1,107 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCoreApplication>
+#include <QtCore/QTimer>
+#include <QtSensors/QGyroscope>
+#include <QtSensors/QSensorBackend>
+#include <QtSensors/QSensorManager>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qgyroscope_cxx.cxx.h"
+
+// A backend which reports a single reading once the sensor has been started
+class TestQGyroscopeBackend : public QSensorBackend
+{
+public:
+  explicit TestQGyroscopeBackend(QSensor* sensor)
+    : QSensorBackend(sensor)
+  {
+    setReading<QGyroscopeReading>(&m_reading);
+    addDataRate(1, 100);
+  }
+
+  void start() override
+  {
+    QTimer::singleShot(0, this, [this]() {
+      m_reading.setX(1.0);
+      m_reading.setY(2.5);
+      m_reading.setZ(-3.0);
+      m_reading.setTimestamp(42);
+      newReadingAvailable();
+    });
+  }
+
+  void stop() override {}
+
+private:
+  QGyroscopeReading m_reading;
+};
+
+class TestQGyroscopeBackendFactory : public QSensorBackendFactory
+{
+public:
+  QSensorBackend* createBackend(QSensor* sensor) override
+  {
+    return new TestQGyroscopeBackend(sensor);
+  }
+};
+
+class QGyroscopeTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void initTestCase()
+  {
+    QSensorManager::registerBackend(
+      QGyroscope::sensorType, m_identifier, &m_factory);
+    QSensorManager::setDefaultBackend(QGyroscope::sensorType, m_identifier);
+  }
+
+  void cleanupTestCase()
+  {
+    QSensorManager::unregisterBackend(QGyroscope::sensorType, m_identifier);
+  }
+
+  void construct()
+  {
+    const auto sensor = construct_qgyroscope();
+    QVERIFY(sensor != nullptr);
+    QVERIFY(!sensor->isActive());
+    QCOMPARE(sensor->dataRate(), 10);
+  }
+
+  void readings()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    auto sensor = construct_qgyroscope();
+    QVERIFY(qgyroscope_start(*sensor));
+    QVERIFY(sensor->isActive());
+    QVERIFY(sensor->isConnectedToBackend());
+
+    QString readings;
+    QVERIFY(QTest::qWaitFor([&readings]() {
+      readings = qgyroscope_take_readings();
+      return !readings.isEmpty();
+    }));
+    QCOMPARE(readings, QStringLiteral("1,2.5,-3,42"));
+
+    sensor->stop();
+    QVERIFY(!sensor->isActive());
+  }
+
+private:
+  const QByteArray m_identifier = QByteArrayLiteral("cxx-qt-test");
+  TestQGyroscopeBackendFactory m_factory;
+};
@@ -0,0 +1,70 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCoreApplication>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qkeychain_cxx.cxx.h"
+
+namespace {
+
+// Run the event loop until the job has finished and return its result
+QString
+waitForKeychain()
+{
+  QString result;
+  QTest::qWaitFor(
+    [&result]() {
+      result = keychain_take_result();
+      return !result.isEmpty();
+    },
+    10000);
+  return result;
+}
+
+}
+
+class QKeychainTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    QCOMPARE(keychain_service(QStringLiteral("service")),
+             QStringLiteral("service"));
+  }
+
+  void roundTrip()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    const auto key = QStringLiteral("password");
+    keychain_write(key, QStringLiteral("secret"));
+    const auto written = waitForKeychain();
+    if (written != QStringLiteral("ok:")) {
+      // CI machines usually do not have a secure storage, such as a secret
+      // service
+      QSKIP(qPrintable(QStringLiteral("Could not write to the keychain: ") +
+                       written));
+    }
+
+    keychain_read(key);
+    QCOMPARE(waitForKeychain(), QStringLiteral("ok:secret"));
+
+    keychain_delete(key);
+    QCOMPARE(waitForKeychain(), QStringLiteral("ok:"));
+
+    keychain_read(key);
+    QCOMPARE(waitForKeychain(), QStringLiteral("error:EntryNotFound"));
+  }
+};
@@ -0,0 +1,42 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCoreApplication>
+#include <QtCore/QUuid>
+#include <QtNetwork/QLocalServer>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qlocalserver_cxx.cxx.h"
+
+class QLocalServerTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    const auto name = QUuid::createUuid().toString(QUuid::WithoutBraces);
+    auto server = construct_qlocalserver(name);
+    QVERIFY(server != nullptr);
+    QVERIFY(server->isListening());
+    QCOMPARE(server->serverName(), name);
+    QVERIFY(!server->fullServerName().isEmpty());
+    QVERIFY(!server->hasPendingConnections());
+    QVERIFY(qlocalserver_accept(*server) == nullptr);
+    QCOMPARE(qlocalserver_take_connections(), 0);
+
+    server->close();
+    QVERIFY(!server->isListening());
+  }
+};
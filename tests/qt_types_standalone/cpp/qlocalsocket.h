@@ -0,0 +1,85 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCoreApplication>
+#include <QtCore/QUuid>
+#include <QtNetwork/QLocalServer>
+#include <QtNetwork/QLocalSocket>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qlocalserver_cxx.cxx.h"
+#include "cxx-qt-gen/qlocalsocket_cxx.cxx.h"
+
+class QLocalSocketTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto socket = construct_qlocalsocket();
+    QVERIFY(socket != nullptr);
+    QCOMPARE(socket->state(), QLocalSocket::UnconnectedState);
+    QVERIFY(!socket->isValid());
+    QCOMPARE(qlocalsocket_take_events(), QString());
+  }
+
+  void loopback()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    const auto name = QUuid::createUuid().toString(QUuid::WithoutBraces);
+    auto server = construct_qlocalserver(name);
+    QVERIFY(server->isListening());
+
+    auto client = construct_qlocalsocket();
+    qlocalsocket_connect(*client, name);
+    QTRY_VERIFY(server->hasPendingConnections());
+    QCOMPARE(qlocalserver_take_connections(), 1);
+    auto accepted = qlocalserver_accept(*server);
+    QVERIFY(accepted != nullptr);
+    QTRY_COMPARE(client->state(), QLocalSocket::ConnectedState);
+    QCOMPARE(client->serverName(), name);
+
+    QCOMPARE(qlocalsocket_write(*client, QByteArrayLiteral("ping")),
+             std::int64_t(4));
+    QTRY_COMPARE(accepted->bytesAvailable(), qint64(4));
+    QCOMPARE(qlocalsocket_read_all(*accepted), QByteArrayLiteral("ping"));
+
+    QCOMPARE(qlocalsocket_write(*accepted, QByteArrayLiteral("pong")),
+             std::int64_t(4));
+    QTRY_COMPARE(client->bytesAvailable(), qint64(4));
+    QCOMPARE(qlocalsocket_read_all(*client), QByteArrayLiteral("pong"));
+
+    client->disconnectFromServer();
+    QTRY_COMPARE(client->state(), QLocalSocket::UnconnectedState);
+    QCOMPARE(qlocalsocket_take_events(),
+             QStringLiteral("connected;ready_read;disconnected"));
+  }
+
+  void connectMissingServer()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    auto socket = construct_qlocalsocket();
+    qlocalsocket_connect(*socket,
+                         QUuid::createUuid().toString(QUuid::WithoutBraces));
+    QTRY_COMPARE(socket->error(), QLocalSocket::ServerNotFoundError);
+    QTRY_COMPARE(
+      qlocalsocket_take_events(),
+      QStringLiteral("error:%1").arg(QLocalSocket::ServerNotFoundError));
+  }
+};
@@ -0,0 +1,44 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtBluetooth/QBluetoothAddress>
+#include <QtBluetooth/QBluetoothDeviceInfo>
+#include <QtBluetooth/QLowEnergyController>
+#include <QtCore/QCoreApplication>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qlowenergycontroller_cxx.cxx.h"
+
+class QLowEnergyControllerTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    QBluetoothDeviceInfo device(
+      QBluetoothAddress(QStringLiteral("00:11:22:33:44:55")),
+      QStringLiteral("Device"),
+      0);
+    device.setCoreConfigurations(
+      QBluetoothDeviceInfo::LowEnergyCoreConfiguration);
+
+    auto controller = construct_qlowenergycontroller(device);
+    QVERIFY(controller != nullptr);
+    QCOMPARE(controller->role(), QLowEnergyController::CentralRole);
+    QCOMPARE(read_qlowenergycontroller(*controller),
+             QStringLiteral("Device,%1,0,true")
+               .arg(QLowEnergyController::UnconnectedState));
+  }
+};
@@ -0,0 +1,65 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QMatrix4x4>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qmatrix_4_x_4_cxx.cxx.h"
+
+class QMatrix4x4Test : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto m = construct_qmatrix4x4();
+    QCOMPARE(m(0, 0), 1.0f);
+    QCOMPARE(m(0, 3), 4.0f);
+    QCOMPARE(m(3, 0), 13.0f);
+    QCOMPARE(m(3, 3), 16.0f);
+  }
+
+  void constructTranslated()
+  {
+    const auto m = construct_qmatrix4x4_translated();
+    QCOMPARE(m.map(QVector3D(0.0f, 0.0f, 0.0f)), QVector3D(1.0f, 2.0f, 3.0f));
+  }
+
+  void read()
+  {
+    auto m = QMatrix4x4();
+    m(0, 0) = 1.0f;
+    m(0, 3) = 4.0f;
+    m(3, 0) = 13.0f;
+    m(3, 3) = 16.0f;
+    QVERIFY(read_qmatrix4x4(m));
+  }
+
+  void clone()
+  {
+    const auto m = QMatrix4x4(1.0f,
+                              2.0f,
+                              3.0f,
+                              4.0f,
+                              5.0f,
+                              6.0f,
+                              7.0f,
+                              8.0f,
+                              9.0f,
+                              10.0f,
+                              11.0f,
+                              12.0f,
+                              13.0f,
+                              14.0f,
+                              15.0f,
+                              16.0f);
+    const auto c = clone_qmatrix4x4(m);
+    QCOMPARE(c, m);
+  }
+};
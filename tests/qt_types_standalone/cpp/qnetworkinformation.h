@@ -0,0 +1,44 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCoreApplication>
+#include <QtNetwork/QNetworkInformation>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qnetworkinformation_cxx.cxx.h"
+
+class QNetworkInformationTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void properties()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    if (!qnetworkinformation_load()) {
+      // CI machines do not always have a network backend, such as
+      // NetworkManager
+      QSKIP("Could not load a QNetworkInformation backend");
+    }
+
+    const auto* information = QNetworkInformation::instance();
+    QVERIFY(information != nullptr);
+    QCOMPARE(qnetworkinformation_backend_name(), information->backendName());
+    QCOMPARE(qnetworkinformation_reachability(),
+             static_cast<int>(information->reachability()));
+    QCOMPARE(qnetworkinformation_transport_medium(),
+             static_cast<int>(information->transportMedium()));
+    QCOMPARE(qnetworkinformation_is_metered(), information->isMetered());
+    QVERIFY(qnetworkinformation_connect());
+  }
+};
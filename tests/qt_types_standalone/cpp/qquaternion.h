@@ -0,0 +1,43 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QQuaternion>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qquaternion_cxx.cxx.h"
+
+class QQuaternionTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto q = construct_qquaternion();
+    QCOMPARE(q.scalar(), 1.23f);
+    QCOMPARE(q.x(), 4.56f);
+    QCOMPARE(q.y(), 7.89f);
+    QCOMPARE(q.z(), 1.47f);
+  }
+
+  void read()
+  {
+    const auto q = QQuaternion(1.23f, 4.56f, 7.89f, 1.47f);
+    QVERIFY(read_qquaternion(q));
+  }
+
+  void clone()
+  {
+    const auto q = QQuaternion(1.23f, 4.56f, 7.89f, 1.47f);
+    const auto c = clone_qquaternion(q);
+    QCOMPARE(c.scalar(), 1.23f);
+    QCOMPARE(c.x(), 4.56f);
+    QCOMPARE(c.y(), 7.89f);
+    QCOMPARE(c.z(), 1.47f);
+  }
+};
@@ -0,0 +1,65 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+#include <cstring>
+
+#include <QtQuick3D/QQuick3DGeometry>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qquick3dgeometry_cxx.cxx.h"
+
+class QQuick3DGeometryTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void vertexBuffer()
+  {
+    const auto buffer = construct_vertex_buffer();
+    QCOMPARE(buffer.size(), static_cast<int>(9 * sizeof(float)));
+
+    float vertices[9];
+    ::std::memcpy(vertices, buffer.constData(), sizeof(vertices));
+    QCOMPARE(vertices[0], -1.0f);
+    QCOMPARE(vertices[3], 1.0f);
+    QCOMPARE(vertices[7], 1.0f);
+    QCOMPARE(vertices[8], 0.0f);
+  }
+
+  void indexBuffer()
+  {
+    const auto buffer = construct_index_buffer();
+    QCOMPARE(buffer.size(), static_cast<int>(3 * sizeof(::std::uint16_t)));
+
+    ::std::uint16_t indices[3];
+    ::std::memcpy(indices, buffer.constData(), sizeof(indices));
+    QCOMPARE(indices[0], ::std::uint16_t(0));
+    QCOMPARE(indices[2], ::std::uint16_t(2));
+  }
+
+  void geometry()
+  {
+    QQuick3DGeometry geometry;
+    geometry.setVertexData(construct_vertex_buffer());
+    geometry.setIndexData(construct_index_buffer());
+    geometry.setStride(3 * sizeof(float));
+    geometry.setPrimitiveType(primitive_type());
+    geometry.addAttribute(position_semantic(), 0, position_component_type());
+
+    QCOMPARE(geometry.vertexData().size(),
+             static_cast<int>(9 * sizeof(float)));
+    QCOMPARE(geometry.primitiveType(),
+             QQuick3DGeometry::PrimitiveType::Triangles);
+    QCOMPARE(geometry.attributeCount(), 1);
+    QCOMPARE(geometry.attribute(0).semantic,
+             QQuick3DGeometry::Attribute::PositionSemantic);
+    QCOMPARE(geometry.attribute(0).componentType,
+             QQuick3DGeometry::Attribute::F32Type);
+  }
+};
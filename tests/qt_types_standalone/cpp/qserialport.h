@@ -0,0 +1,45 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtSerialPort/QSerialPort>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qserialport_cxx.cxx.h"
+
+class QSerialPortTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto port = construct_qserialport();
+    QVERIFY(port != nullptr);
+    QCOMPARE(port->portName(), QStringLiteral("ttyCxxQt"));
+    QCOMPARE(port->baudRate(), 9600);
+    QCOMPARE(port->dataBits(), QSerialPort::Data7);
+    QCOMPARE(port->parity(), QSerialPort::EvenParity);
+    QCOMPARE(port->stopBits(), QSerialPort::TwoStop);
+    QCOMPARE(port->flowControl(), QSerialPort::SoftwareControl);
+    QVERIFY(!port->isOpen());
+  }
+
+  void openMissingPort()
+  {
+    QSerialPort port;
+    QVERIFY(!qserialport_open(
+      port, QStringLiteral("/dev/cxx-qt-port-which-does-not-exist")));
+    QVERIFY(!port.isOpen());
+    QCOMPARE(port.error(), QSerialPort::DeviceNotFoundError);
+    QCOMPARE(qserialport_take_errors(),
+             QString::number(QSerialPort::DeviceNotFoundError));
+
+    port.clearError();
+    QCOMPARE(port.error(), QSerialPort::NoError);
+  }
+};
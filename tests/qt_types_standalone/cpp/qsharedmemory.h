@@ -0,0 +1,58 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QSharedMemory>
+#include <QtCore/QUuid>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qsharedmemory_cxx.cxx.h"
+
+class QSharedMemoryTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto sharedMemory =
+      construct_qsharedmemory(QStringLiteral("cxx-qt-test"));
+    QVERIFY(sharedMemory != nullptr);
+    QCOMPARE(sharedMemory->key(), QStringLiteral("cxx-qt-test"));
+    QVERIFY(!sharedMemory->isAttached());
+    QCOMPARE(sharedMemory->size(), 0);
+  }
+
+  void roundTrip()
+  {
+    // A unique key so that a segment left behind by a previous run is not used
+    const auto key = QUuid::createUuid().toString();
+    auto owner = construct_qsharedmemory(key);
+    QVERIFY(qsharedmemory_create(*owner, 16));
+    QVERIFY(owner->isAttached());
+    QVERIFY(owner->size() >= 16);
+    QVERIFY(qsharedmemory_write(*owner, QByteArrayLiteral("hello")));
+
+    QSharedMemory other(key);
+    QVERIFY(other.attach());
+    QVERIFY(other.lock());
+    QCOMPARE(QByteArray(static_cast<const char*>(other.constData()), 5),
+             QByteArrayLiteral("hello"));
+    memcpy(other.data(), "world", 5);
+    QVERIFY(other.unlock());
+
+    QCOMPARE(qsharedmemory_read(*owner, 5), QByteArrayLiteral("world"));
+  }
+
+  void attachMissing()
+  {
+    QSharedMemory sharedMemory(QUuid::createUuid().toString());
+    QVERIFY(!qsharedmemory_attach(sharedMemory));
+    QCOMPARE(sharedMemory.error(), QSharedMemory::NotFound);
+    QCOMPARE(qsharedmemory_read(sharedMemory, 0), QByteArray());
+  }
+};
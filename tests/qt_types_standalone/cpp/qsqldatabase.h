@@ -0,0 +1,76 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtSql/QSqlDatabase>
+#include <QtSql/QSqlQuery>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qsqldatabase_cxx.cxx.h"
+
+class QSqlDatabaseTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void initTestCase()
+  {
+    if (!QSqlDatabase::isDriverAvailable(QStringLiteral("QSQLITE"))) {
+      QSKIP("The QSQLITE driver is not available");
+    }
+  }
+
+  void construct()
+  {
+    const auto name = QStringLiteral("cxx-qt-test-construct");
+    {
+      auto database = construct_qsqldatabase(name);
+      QVERIFY(database != nullptr);
+      QVERIFY(database->isValid());
+      QVERIFY(!database->isOpen());
+      QCOMPARE(database->connectionName(), name);
+      QCOMPARE(database->driverName(), QStringLiteral("QSQLITE"));
+      QCOMPARE(database->databaseName(), QStringLiteral(":memory:"));
+      QVERIFY(qsqldatabase_contains(name));
+
+      QVERIFY(qsqldatabase_open(*database));
+      QVERIFY(database->isOpen());
+      database->close();
+    }
+
+    qsqldatabase_remove(name);
+    QVERIFY(!qsqldatabase_contains(name));
+  }
+
+  void transaction()
+  {
+    const auto name = QStringLiteral("cxx-qt-test-transaction");
+    {
+      auto database = construct_qsqldatabase(name);
+      QVERIFY(qsqldatabase_open(*database));
+      QSqlQuery query(*database);
+      QVERIFY(query.exec(QStringLiteral("CREATE TABLE items (id INTEGER)")));
+
+      const auto count = [&query]() {
+        query.exec(QStringLiteral("SELECT COUNT(*) FROM items"));
+        query.next();
+        return query.value(0).toInt();
+      };
+
+      QVERIFY(qsqldatabase_transaction(*database));
+      QVERIFY(query.exec(QStringLiteral("INSERT INTO items VALUES (1)")));
+      QVERIFY(qsqldatabase_finish_transaction(*database, false));
+      QCOMPARE(count(), 0);
+
+      QVERIFY(qsqldatabase_transaction(*database));
+      QVERIFY(query.exec(QStringLiteral("INSERT INTO items VALUES (1)")));
+      QVERIFY(qsqldatabase_finish_transaction(*database, true));
+      QCOMPARE(count(), 1);
+    }
+    qsqldatabase_remove(name);
+  }
+};
@@ -0,0 +1,80 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtSql/QSqlDatabase>
+#include <QtSql/QSqlQuery>
+#include <QtSql/QSqlRecord>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qsqlquery_cxx.cxx.h"
+
+class QSqlQueryTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void initTestCase()
+  {
+    if (!QSqlDatabase::isDriverAvailable(QStringLiteral("QSQLITE"))) {
+      QSKIP("The QSQLITE driver is not available");
+    }
+
+    m_database = QSqlDatabase::addDatabase(QStringLiteral("QSQLITE"),
+                                           QStringLiteral("cxx-qt-test-query"));
+    m_database.setDatabaseName(QStringLiteral(":memory:"));
+    QVERIFY(m_database.open());
+    QVERIFY(QSqlQuery(m_database).exec(
+      QStringLiteral("CREATE TABLE items "
+                     "(id INTEGER PRIMARY KEY, name TEXT)")));
+  }
+
+  void cleanupTestCase()
+  {
+    m_database.close();
+    m_database = QSqlDatabase();
+    QSqlDatabase::removeDatabase(QStringLiteral("cxx-qt-test-query"));
+  }
+
+  void construct()
+  {
+    const auto query = construct_qsqlquery(m_database);
+    QVERIFY(query != nullptr);
+    QVERIFY(!query->isActive());
+    QVERIFY(!query->isValid());
+    QCOMPARE(query->at(), int(QSql::BeforeFirstRow));
+  }
+
+  void roundTrip()
+  {
+    auto query = construct_qsqlquery(m_database);
+    QVERIFY(qsqlquery_insert_named(*query, 1, QStringLiteral("one")));
+    QCOMPARE(query->numRowsAffected(), 1);
+    QVERIFY(qsqlquery_insert_positional(*query, 2, QStringLiteral("two")));
+    QCOMPARE(query->lastInsertId().toLongLong(), qlonglong(2));
+
+    QCOMPARE(qsqlquery_select_rows(*query), QStringLiteral("1:one;2:two"));
+    QVERIFY(query->isSelect());
+    QCOMPARE(query->record().count(), 2);
+    // The iterator reads every record, so the query is after the last record
+    QCOMPARE(query->at(), int(QSql::AfterLastRow));
+    QCOMPARE(qsqlquery_first_name(*query), QStringLiteral("one"));
+    QCOMPARE(query->at(), 0);
+  }
+
+  void execError()
+  {
+    auto query = construct_qsqlquery(m_database);
+    const auto error =
+      qsqlquery_exec_error(*query, QStringLiteral("SELECT * FROM missing"));
+    QVERIFY(!error.isEmpty());
+    QVERIFY(!query->isActive());
+  }
+
+private:
+  QSqlDatabase m_database;
+};
@@ -0,0 +1,98 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtSql/QSqlDatabase>
+#include <QtSql/QSqlQuery>
+#include <QtSql/QSqlQueryModel>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qsqlquerymodel_cxx.cxx.h"
+
+class QSqlQueryModelTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void initTestCase()
+  {
+    if (!QSqlDatabase::isDriverAvailable(QStringLiteral("QSQLITE"))) {
+      QSKIP("The QSQLITE driver is not available");
+    }
+
+    m_database = QSqlDatabase::addDatabase(
+      QStringLiteral("QSQLITE"), QStringLiteral("cxx-qt-test-query-model"));
+    m_database.setDatabaseName(QStringLiteral(":memory:"));
+    QVERIFY(m_database.open());
+    QSqlQuery query(m_database);
+    QVERIFY(query.exec(
+      QStringLiteral("CREATE TABLE items (id INTEGER PRIMARY KEY, name TEXT)")));
+    QVERIFY(query.exec(
+      QStringLiteral("INSERT INTO items VALUES (1, 'one'), (2, 'two')")));
+  }
+
+  void cleanupTestCase()
+  {
+    m_database.close();
+    m_database = QSqlDatabase();
+    QSqlDatabase::removeDatabase(QStringLiteral("cxx-qt-test-query-model"));
+  }
+
+  void construct()
+  {
+    const auto model = construct_qsqlquerymodel();
+    QVERIFY(model != nullptr);
+    QCOMPARE(model->rowCount(), 0);
+    QCOMPARE(model->columnCount(), 0);
+  }
+
+  void setQuery()
+  {
+    auto model = construct_qsqlquerymodel();
+    QCOMPARE(qsqlquerymodel_set_query(
+               *model,
+               m_database,
+               QStringLiteral("SELECT id, name FROM items ORDER BY id")),
+             QString());
+    QCOMPARE(model->rowCount(), 2);
+    QCOMPARE(model->columnCount(), 2);
+    QCOMPARE(model->headerData(1, Qt::Horizontal).toString(),
+             QStringLiteral("name"));
+    QCOMPARE(qsqlquerymodel_data(*model, model->index(0, 0)),
+             QStringLiteral("1"));
+    QCOMPARE(qsqlquerymodel_data(*model, model->index(1, 1)),
+             QStringLiteral("two"));
+    QCOMPARE(qsqlquerymodel_data(*model, model->index(2, 1)), QString());
+
+    model->clear();
+    QCOMPARE(model->rowCount(), 0);
+  }
+
+  void setExecutedQuery()
+  {
+    auto model = construct_qsqlquerymodel();
+    QVERIFY(qsqlquerymodel_set_executed_query(
+      *model,
+      m_database,
+      QStringLiteral("SELECT name FROM items WHERE id = 2")));
+    QCOMPARE(model->rowCount(), 1);
+    QCOMPARE(qsqlquerymodel_data(*model, model->index(0, 0)),
+             QStringLiteral("two"));
+  }
+
+  void setQueryError()
+  {
+    auto model = construct_qsqlquerymodel();
+    QVERIFY(!qsqlquerymodel_set_query(
+               *model, m_database, QStringLiteral("SELECT * FROM missing"))
+               .isEmpty());
+    QCOMPARE(model->rowCount(), 0);
+  }
+
+private:
+  QSqlDatabase m_database;
+};
@@ -0,0 +1,89 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtSql/QSqlDatabase>
+#include <QtSql/QSqlQuery>
+#include <QtSql/QSqlTableModel>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qsqltablemodel_cxx.cxx.h"
+
+class QSqlTableModelTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void initTestCase()
+  {
+    if (!QSqlDatabase::isDriverAvailable(QStringLiteral("QSQLITE"))) {
+      QSKIP("The QSQLITE driver is not available");
+    }
+
+    m_database = QSqlDatabase::addDatabase(
+      QStringLiteral("QSQLITE"), QStringLiteral("cxx-qt-test-table-model"));
+    m_database.setDatabaseName(QStringLiteral(":memory:"));
+    QVERIFY(m_database.open());
+    QSqlQuery query(m_database);
+    QVERIFY(query.exec(
+      QStringLiteral("CREATE TABLE items (id INTEGER PRIMARY KEY, name TEXT)")));
+    QVERIFY(query.exec(
+      QStringLiteral("INSERT INTO items VALUES (1, 'one'), (2, 'two')")));
+  }
+
+  void cleanupTestCase()
+  {
+    m_database.close();
+    m_database = QSqlDatabase();
+    QSqlDatabase::removeDatabase(QStringLiteral("cxx-qt-test-table-model"));
+  }
+
+  void construct()
+  {
+    auto model = construct_qsqltablemodel(m_database);
+    QVERIFY(model != nullptr);
+    QCOMPARE(model->tableName(), QStringLiteral("items"));
+    QCOMPARE(model->editStrategy(), QSqlTableModel::OnManualSubmit);
+    QVERIFY(qsqltablemodel_select(*model, QString()));
+    QCOMPARE(model->rowCount(), 2);
+    QCOMPARE(model->columnCount(), 2);
+    QVERIFY(!qsqltablemodel_is_dirty(*model));
+  }
+
+  void roundTrip()
+  {
+    auto model = construct_qsqltablemodel(m_database);
+    QVERIFY(qsqltablemodel_select(*model, QString()));
+    QVERIFY(qsqltablemodel_append(*model, 3, QStringLiteral("three")));
+    QCOMPARE(model->rowCount(), 3);
+    QVERIFY(qsqltablemodel_is_dirty(*model));
+
+    QVERIFY(qsqltablemodel_submit(*model));
+    QVERIFY(!qsqltablemodel_is_dirty(*model));
+    QSqlQuery query(m_database);
+    QVERIFY(query.exec(QStringLiteral("SELECT name FROM items WHERE id = 3")));
+    QVERIFY(query.next());
+    QCOMPARE(query.value(0).toString(), QStringLiteral("three"));
+
+    QVERIFY(qsqltablemodel_select(*model, QStringLiteral("name = 'three'")));
+    QCOMPARE(model->filter(), QStringLiteral("name = 'three'"));
+    QCOMPARE(model->rowCount(), 1);
+    QCOMPARE(
+      model->data(model->index(0, model->fieldIndex(QStringLiteral("name")))),
+      QVariant(QStringLiteral("three")));
+
+    // Removing a row is only pending until it is submitted
+    QVERIFY(qsqltablemodel_remove_first(*model));
+    QVERIFY(qsqltablemodel_is_dirty(*model));
+    model->revertAll();
+    QVERIFY(!qsqltablemodel_is_dirty(*model));
+    QCOMPARE(model->rowCount(), 1);
+  }
+
+private:
+  QSqlDatabase m_database;
+};
@@ -0,0 +1,58 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCoreApplication>
+#include <QtCore/QObject>
+#include <QtCore/QStringList>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qstatemachine_cxx.cxx.h"
+
+class QStateMachineTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void transitions()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    QObject sender;
+    QObject target;
+    auto machine = construct_qstatemachine(sender, target);
+    QVERIFY(machine != nullptr);
+    QVERIFY(!machine->isRunning());
+
+    machine->start();
+    QTRY_VERIFY(machine->isRunning());
+    QCOMPARE(qstatemachine_active_states(*machine),
+             QStringList{ QStringLiteral("idle") });
+
+    // The signal of the sender drives the transition, which assigns the
+    // property of the target
+    sender.setObjectName(QStringLiteral("first"));
+    QTRY_COMPARE(qstatemachine_active_states(*machine),
+                 QStringList{ QStringLiteral("running") });
+    QCOMPARE(target.objectName(), QStringLiteral("assigned"));
+    QCOMPARE(qstatemachine_take_entered(), QStringLiteral("idle,running"));
+
+    // Entering the final state stops the machine
+    sender.setObjectName(QStringLiteral("second"));
+    QTRY_VERIFY(!machine->isRunning());
+  }
+
+  void invalidNames()
+  {
+    QObject sender;
+    QVERIFY(qstatemachine_rejects_invalid_names(sender));
+  }
+};
@@ -52,4 +52,6 @@ private Q_SLOTS:
     modify_qstring(s);
     QCOMPARE(s, QStringLiteral("Updated string value"));
   }
+
+  void can_use_as_utf16_slice_cpp() { QVERIFY(can_use_as_utf16_slice()); }
 };
@@ -0,0 +1,38 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QStringListModel>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qstringlistmodel_cxx.cxx.h"
+
+class QStringListModelTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void setStringList()
+  {
+    auto test = new_string_list_model_test();
+    QStringListModel& model = test->model();
+    QCOMPARE(model.stringList(),
+             QStringList({ QStringLiteral("Red"), QStringLiteral("Green") }));
+    QCOMPARE(test->changed_count(), 0);
+  }
+
+  void dataChanged()
+  {
+    auto test = new_string_list_model_test();
+    QStringListModel& model = test->model();
+    QVERIFY(model.setData(model.index(1), QStringLiteral("Blue")));
+
+    QCOMPARE(test->changed_count(), 1);
+    QCOMPARE(test->changed_strings(),
+             QStringList({ QStringLiteral("Red"), QStringLiteral("Blue") }));
+  }
+};
@@ -0,0 +1,51 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QSystemSemaphore>
+#include <QtCore/QUuid>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qsystemsemaphore_cxx.cxx.h"
+
+class QSystemSemaphoreTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto key = QUuid::createUuid().toString();
+    const auto semaphore = construct_qsystemsemaphore(key, 1);
+    QVERIFY(semaphore != nullptr);
+    QCOMPARE(semaphore->key(), key);
+    QCOMPARE(semaphore->error(), QSystemSemaphore::NoError);
+  }
+
+  void acquireRelease()
+  {
+    const auto key = QUuid::createUuid().toString();
+    auto semaphore = construct_qsystemsemaphore(key, 1);
+
+    // Acquiring the only resource and releasing two leaves two resources
+    QVERIFY(qsystemsemaphore_acquire_release(*semaphore, 2));
+    QSystemSemaphore other(key);
+    QVERIFY(other.acquire());
+    QVERIFY(other.acquire());
+    QVERIFY(other.release(2));
+  }
+
+  void setKey()
+  {
+    auto semaphore =
+      construct_qsystemsemaphore(QUuid::createUuid().toString(), 1);
+    const auto key = QUuid::createUuid().toString();
+    qsystemsemaphore_set_key(*semaphore, key, 1);
+    QCOMPARE(semaphore->key(), key);
+    QVERIFY(qsystemsemaphore_acquire_release(*semaphore, 1));
+  }
+};
@@ -0,0 +1,45 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QFile>
+#include <QtCore/QLockFile>
+#include <QtCore/QTemporaryDir>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qtemporaryfile_cxx.cxx.h"
+
+class QTemporaryFileTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void lockFile()
+  {
+    QTemporaryDir dir;
+    QVERIFY(dir.isValid());
+
+    const auto fileName = dir.filePath(QStringLiteral("test.lock"));
+    QLockFile lockFile(fileName);
+    QVERIFY(lockFile.tryLock());
+    QVERIFY(qlockfile_is_locked_elsewhere(fileName));
+  }
+
+  void temporaryDir() { QVERIFY(qtemporarydir_is_removed()); }
+
+  void temporaryFile()
+  {
+    const auto data = QByteArrayLiteral("KDAB");
+    const auto fileName = qtemporaryfile_write_data(data);
+    QVERIFY(!fileName.isEmpty());
+
+    QFile file(fileName);
+    QVERIFY(file.open(QIODevice::ReadOnly));
+    QCOMPARE(file.readAll(), data);
+    QVERIFY(file.remove());
+  }
+};
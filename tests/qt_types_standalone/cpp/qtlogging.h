@@ -0,0 +1,45 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QString>
+#include <QtCore/QtGlobal>
+#include <QtTest/QTest>
+
+#include "cxx-qt-gen/qtlogging_cxx.cxx.h"
+
+class QtLoggingTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void formatLogMessage()
+  {
+    if (qEnvironmentVariableIsSet("QT_MESSAGE_PATTERN")) {
+      QSKIP("QT_MESSAGE_PATTERN overrides the message pattern");
+    }
+
+    const QMessageLogContext context("file.cpp", 12, "function", "category");
+    QCOMPARE(format_log_message(context, QStringLiteral("message")),
+             QStringLiteral("warning category: message"));
+  }
+
+  void installMessageHandler()
+  {
+    install_message_handler();
+    QMessageLogger("file.cpp", 12, "function").debug("first");
+    QMessageLogger("file.cpp", 34, "function", "category").warning("second");
+    reset_message_handler();
+
+    // Messages after the handler has been reset go to the default handler
+    qDebug("not handled");
+
+    QCOMPARE(take_logged_messages(),
+             QStringLiteral("0 default file.cpp:12: first\n"
+                            "1 category file.cpp:34: second"));
+  }
+};
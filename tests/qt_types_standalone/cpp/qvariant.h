@@ -202,4 +202,24 @@ private Q_SLOTS:
     QCOMPARE(c.toPoint().x(), 8);
     QCOMPARE(c.toPoint().y(), 9);
   }
+
+  void rustHandle()
+  {
+    auto v = new QVariant(construct_qvariant_rust_handle());
+    QCOMPARE(read_qvariant_rust_handle(*v), 42);
+
+    // The copy shares the value, which is dropped with the last copy
+    const auto c = new QVariant(*v);
+    delete v;
+    QVERIFY(!qvariant_rust_handle_dropped());
+    QCOMPARE(read_qvariant_rust_handle(*c), 42);
+    delete c;
+    QVERIFY(qvariant_rust_handle_dropped());
+  }
+
+  void rustHandleNotAHandle()
+  {
+    QCOMPARE(read_qvariant_rust_handle(QVariant::fromValue(42)), -1);
+    QCOMPARE(read_qvariant_rust_handle(QVariant()), -1);
+  }
 };
@@ -0,0 +1,74 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCoreApplication>
+#include <QtTest/QSignalSpy>
+#include <QtTest/QTest>
+#include <QtWebSockets/QWebSocket>
+
+#include "cxx-qt-gen/qwebsocket_cxx.cxx.h"
+#include "cxx-qt-gen/qwebsocketserver_cxx.cxx.h"
+
+class QWebSocketTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto socket = construct_qwebsocket();
+    QVERIFY(socket != nullptr);
+    QCOMPARE(socket->state(), QAbstractSocket::UnconnectedState);
+    QVERIFY(!socket->isValid());
+    QCOMPARE(qwebsocket_take_events(), QString());
+  }
+
+  void loopback()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    auto server = construct_qwebsocketserver(QStringLiteral("cxx-qt-test"));
+    QVERIFY(server->isListening());
+
+    auto client = construct_qwebsocket();
+    const QUrl url(
+      QStringLiteral("ws://127.0.0.1:%1").arg(server->serverPort()));
+    qwebsocket_open(*client, url);
+    QTRY_VERIFY(server->hasPendingConnections());
+    QCOMPARE(qwebsocketserver_take_connections(), 1);
+    auto accepted = qwebsocketserver_accept(*server);
+    QVERIFY(accepted != nullptr);
+    QTRY_COMPARE(client->state(), QAbstractSocket::ConnectedState);
+    QCOMPARE(client->requestUrl(), url);
+    QCOMPARE(qwebsocket_take_events(), QStringLiteral("connected"));
+
+    QSignalSpy textSpy(accepted.get(), &QWebSocket::textMessageReceived);
+    QSignalSpy binarySpy(accepted.get(), &QWebSocket::binaryMessageReceived);
+    QCOMPARE(qwebsocket_send_text_message(*client, QStringLiteral("ping")),
+             std::int64_t(4));
+    QCOMPARE(qwebsocket_send_binary_message(*client, QByteArrayLiteral("data")),
+             std::int64_t(4));
+    QTRY_COMPARE(binarySpy.count(), 1);
+    QCOMPARE(textSpy.count(), 1);
+    QCOMPARE(textSpy.takeFirst().at(0).toString(), QStringLiteral("ping"));
+    QCOMPARE(binarySpy.takeFirst().at(0).toByteArray(),
+             QByteArrayLiteral("data"));
+
+    QCOMPARE(qwebsocket_send_text_message(*accepted, QStringLiteral("pong")),
+             std::int64_t(4));
+    QTRY_COMPARE(qwebsocket_take_events(), QStringLiteral("text:pong"));
+
+    qwebsocket_close(*client);
+    QTRY_COMPARE(client->state(), QAbstractSocket::UnconnectedState);
+    QTRY_COMPARE(qwebsocket_take_events(), QStringLiteral("disconnected"));
+  }
+};
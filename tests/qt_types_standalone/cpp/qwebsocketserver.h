@@ -0,0 +1,42 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCoreApplication>
+#include <QtTest/QTest>
+#include <QtWebSockets/QWebSocketServer>
+
+#include "cxx-qt-gen/qwebsocketserver_cxx.cxx.h"
+
+class QWebSocketServerTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    auto server = construct_qwebsocketserver(QStringLiteral("cxx-qt-test"));
+    QVERIFY(server != nullptr);
+    QVERIFY(server->isListening());
+    QCOMPARE(server->serverName(), QStringLiteral("cxx-qt-test"));
+    QCOMPARE(server->secureMode(), QWebSocketServer::NonSecureMode);
+    QVERIFY(server->serverPort() != 0);
+    QCOMPARE(server->serverUrl().port(), int(server->serverPort()));
+    QVERIFY(!server->hasPendingConnections());
+    QVERIFY(qwebsocketserver_accept(*server) == nullptr);
+    QCOMPARE(qwebsocketserver_take_connections(), 0);
+
+    server->close();
+    QVERIFY(!server->isListening());
+  }
+};
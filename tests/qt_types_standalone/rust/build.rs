@@ -6,18 +6,26 @@
 use cxx_qt_build::CxxQtBuilder;
 
 fn main() {
-    CxxQtBuilder::new()
+    let mut builder = CxxQtBuilder::new()
         .file("src/qbytearray.rs")
+        .file("src/qcborvalue.rs")
+        .file("src/qconcatenatetablesproxymodel.rs")
         .file("src/qcolor.rs")
+        .file("src/qcontainer_custom.rs")
         .file("src/qcoreapplication.rs")
+        .file("src/qcryptographichash.rs")
+        .file("src/qdatastream.rs")
         .file("src/qdate.rs")
         .file("src/qdatetime.rs")
+        .file("src/qevent.rs")
+        .file("src/qfilesystemwatcher.rs")
         .file("src/qguiapplication.rs")
         .file("src/qhash.rs")
         .file("src/qlist.rs")
         .file("src/qmap.rs")
         .file("src/qmargins.rs")
         .file("src/qmarginsf.rs")
+        .file("src/qmatrix4x4.rs")
         .file("src/qmetaobjectconnection.rs")
         .file("src/qmodelindex.rs")
         .file("src/qpersistentmodelindex.rs")
@@ -25,20 +33,72 @@ fn main() {
         .file("src/qpointf.rs")
         .file("src/qqmlapplicationengine.rs")
         .file("src/qqmlengine.rs")
+        .file("src/qquaternion.rs")
         .file("src/qrect.rs")
         .file("src/qrectf.rs")
         .file("src/qset.rs")
+        .file("src/qsharedmemory.rs")
         .file("src/qsize.rs")
         .file("src/qsizef.rs")
         .file("src/qstring.rs")
         .file("src/qstringlist.rs")
+        .file("src/qstringlistmodel.rs")
+        .file("src/qsystemsemaphore.rs")
+        .file("src/qtemporaryfile.rs")
         .file("src/qtime.rs")
         .file("src/qtimezone.rs")
+        .file("src/qtlogging.rs")
         .file("src/qurl.rs")
         .file("src/qvariant.rs")
         .file("src/qvector.rs")
         .file("src/qvector2d.rs")
         .file("src/qvector3d.rs")
-        .file("src/qvector4d.rs")
-        .build();
+        .file("src/qvector4d.rs");
+
+    // The optional Qt modules are only tested when their feature is enabled
+    if std::env::var("CARGO_FEATURE_QT_BLUETOOTH").is_ok() {
+        builder = builder
+            .file("src/qbluetoothdevicediscoveryagent.rs")
+            .file("src/qbluetoothdeviceinfo.rs")
+            .file("src/qlowenergycontroller.rs");
+    }
+    if std::env::var("CARGO_FEATURE_QT_KEYCHAIN").is_ok() {
+        builder = builder.file("src/qkeychain.rs");
+    }
+    if std::env::var("CARGO_FEATURE_QT_NETWORK").is_ok() {
+        builder = builder
+            .file("src/qlocalserver.rs")
+            .file("src/qlocalsocket.rs");
+    }
+    if std::env::var("CARGO_FEATURE_QT_NETWORKINFORMATION").is_ok() {
+        builder = builder.file("src/qnetworkinformation.rs");
+    }
+    if std::env::var("CARGO_FEATURE_QT_QUICK3D").is_ok() {
+        builder = builder.file("src/qquick3dgeometry.rs");
+    }
+    if std::env::var("CARGO_FEATURE_QT_SENSORS").is_ok() {
+        builder = builder
+            .file("src/qaccelerometer.rs")
+            .file("src/qgyroscope.rs");
+    }
+    if std::env::var("CARGO_FEATURE_QT_SERIALPORT").is_ok() {
+        builder = builder.file("src/qserialport.rs");
+    }
+    if std::env::var("CARGO_FEATURE_QT_SQL").is_ok() {
+        builder = builder
+            .file("src/qsqldatabase.rs")
+            .file("src/qsqlquery.rs")
+            .file("src/qsqlquerymodel.rs")
+            .file("src/qsqltablemodel.rs");
+    }
+    if std::env::var("CARGO_FEATURE_QT_STATEMACHINE").is_ok() {
+        builder = builder.file("src/qstatemachine.rs");
+    }
+    if std::env::var("CARGO_FEATURE_QT_WEBSOCKETS").is_ok() {
+        builder = builder
+            .file("src/qwebsocket.rs")
+            .file("src/qwebsocketserver.rs");
+    }
+
+    builder.build();
 }
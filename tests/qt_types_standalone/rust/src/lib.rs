@@ -4,36 +4,86 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+#[cfg(feature = "qt_sensors")]
+mod qaccelerometer;
+#[cfg(feature = "qt_bluetooth")]
+mod qbluetoothdevicediscoveryagent;
+#[cfg(feature = "qt_bluetooth")]
+mod qbluetoothdeviceinfo;
 mod qbytearray;
+mod qcborvalue;
 mod qcolor;
+mod qconcatenatetablesproxymodel;
+mod qcontainer_custom;
 mod qcoreapplication;
+mod qcryptographichash;
+mod qdatastream;
 mod qdate;
 mod qdatetime;
+mod qevent;
+mod qfilesystemwatcher;
 mod qguiapplication;
+#[cfg(feature = "qt_sensors")]
+mod qgyroscope;
 mod qhash;
+#[cfg(feature = "qt_keychain")]
+mod qkeychain;
 mod qlist;
+#[cfg(feature = "qt_network")]
+mod qlocalserver;
+#[cfg(feature = "qt_network")]
+mod qlocalsocket;
+#[cfg(feature = "qt_bluetooth")]
+mod qlowenergycontroller;
 mod qmap;
 mod qmargins;
 mod qmarginsf;
+mod qmatrix4x4;
 mod qmetaobjectconnection;
 mod qmodelindex;
+#[cfg(feature = "qt_networkinformation")]
+mod qnetworkinformation;
 mod qpersistentmodelindex;
 mod qpoint;
 mod qpointf;
 mod qqmlapplicationengine;
 mod qqmlengine;
+mod qquaternion;
+#[cfg(feature = "qt_quick3d")]
+mod qquick3dgeometry;
 mod qrect;
 mod qrectf;
+#[cfg(feature = "qt_serialport")]
+mod qserialport;
 mod qset;
+mod qsharedmemory;
 mod qsize;
 mod qsizef;
+#[cfg(feature = "qt_sql")]
+mod qsqldatabase;
+#[cfg(feature = "qt_sql")]
+mod qsqlquery;
+#[cfg(feature = "qt_sql")]
+mod qsqlquerymodel;
+#[cfg(feature = "qt_sql")]
+mod qsqltablemodel;
+#[cfg(feature = "qt_statemachine")]
+mod qstatemachine;
 mod qstring;
 mod qstringlist;
+mod qstringlistmodel;
+mod qsystemsemaphore;
+mod qtemporaryfile;
 mod qtime;
 mod qtimezone;
+mod qtlogging;
 mod qurl;
 mod qvariant;
 mod qvector;
 mod qvector2d;
 mod qvector3d;
 mod qvector4d;
+#[cfg(feature = "qt_websockets")]
+mod qwebsocket;
+#[cfg(feature = "qt_websockets")]
+mod qwebsocketserver;
@@ -0,0 +1,60 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{QAccelerometer, QAccelerometerAccelerationMode, QString};
+use std::sync::Mutex;
+
+#[cxx::bridge]
+mod qaccelerometer_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qaccelerometer.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QAccelerometerAccelerationMode = cxx_qt_lib::QAccelerometerAccelerationMode;
+        include!("cxx-qt-lib/qaccelerometer.h");
+        type QAccelerometer = cxx_qt_lib::QAccelerometer;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "Rust" {
+        fn construct_qaccelerometer() -> UniquePtr<QAccelerometer>;
+        fn qaccelerometer_start(sensor: Pin<&mut QAccelerometer>) -> bool;
+        fn qaccelerometer_take_readings() -> QString;
+    }
+}
+
+/// The readings which have been received, formatted as "x,y,z,timestamp"
+static READINGS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+fn construct_qaccelerometer() -> cxx::UniquePtr<QAccelerometer> {
+    let mut sensor = QAccelerometer::new();
+    if let Some(mut sensor) = sensor.as_mut() {
+        sensor
+            .as_mut()
+            .set_acceleration_mode(QAccelerometerAccelerationMode::Gravity);
+        sensor.set_data_rate(10);
+    }
+    sensor
+}
+
+fn qaccelerometer_start(mut sensor: Pin<&mut QAccelerometer>) -> bool {
+    READINGS.lock().unwrap().clear();
+    sensor
+        .as_mut()
+        .on_reading_changed(|reading| {
+            READINGS.lock().unwrap().push(format!(
+                "{},{},{},{}",
+                reading.x, reading.y, reading.z, reading.timestamp
+            ));
+        })
+        .release();
+    sensor.start()
+}
+
+// Returns the readings separated by semicolons, and clears them
+fn qaccelerometer_take_readings() -> QString {
+    QString::from(&std::mem::take(&mut *READINGS.lock().unwrap()).join(";"))
+}
@@ -0,0 +1,26 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::QBluetoothDeviceDiscoveryAgent;
+
+#[cxx::bridge]
+mod qbluetoothdevicediscoveryagent_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbluetoothdevicediscoveryagent.h");
+        type QBluetoothDeviceDiscoveryAgent = cxx_qt_lib::QBluetoothDeviceDiscoveryAgent;
+    }
+
+    extern "Rust" {
+        fn construct_qbluetoothdevicediscoveryagent() -> UniquePtr<QBluetoothDeviceDiscoveryAgent>;
+    }
+}
+
+fn construct_qbluetoothdevicediscoveryagent() -> cxx::UniquePtr<QBluetoothDeviceDiscoveryAgent> {
+    let mut agent = QBluetoothDeviceDiscoveryAgent::new();
+    if let Some(agent) = agent.as_mut() {
+        agent.set_low_energy_discovery_timeout(5000);
+    }
+    agent
+}
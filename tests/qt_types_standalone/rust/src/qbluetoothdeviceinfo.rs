@@ -0,0 +1,38 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QBluetoothDeviceInfo, QString};
+
+#[cxx::bridge]
+mod qbluetoothdeviceinfo_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbluetoothdeviceinfo.h");
+        type QBluetoothDeviceInfo = cxx_qt_lib::QBluetoothDeviceInfo;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "Rust" {
+        fn clone_qbluetoothdeviceinfo(
+            info: &QBluetoothDeviceInfo,
+        ) -> UniquePtr<QBluetoothDeviceInfo>;
+        fn read_qbluetoothdeviceinfo(info: &QBluetoothDeviceInfo) -> QString;
+    }
+}
+
+fn clone_qbluetoothdeviceinfo(info: &QBluetoothDeviceInfo) -> cxx::UniquePtr<QBluetoothDeviceInfo> {
+    info.clone_unique()
+}
+
+fn read_qbluetoothdeviceinfo(info: &QBluetoothDeviceInfo) -> QString {
+    QString::from(&format!(
+        "{},{},{},{},{}",
+        info.address(),
+        info.name(),
+        info.is_low_energy(),
+        info.rssi(),
+        info.is_valid()
+    ))
+}
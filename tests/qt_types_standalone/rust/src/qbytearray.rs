@@ -21,6 +21,8 @@ mod qbytearray_cxx {
         fn can_handle_qbytearray_change() -> bool;
         fn clone_qbytearray(s: &QByteArray) -> QByteArray;
         fn can_use_as_slice() -> bool;
+        fn can_borrow_as_str() -> bool;
+        fn read_byte_slice(slice: &[u8]) -> bool;
     }
 }
 
@@ -65,3 +67,20 @@ fn can_use_as_slice() -> bool {
 
     slice.as_slice() == string.as_bytes()
 }
+
+fn can_borrow_as_str() -> bool {
+    let valid = QByteArray::from("String slice");
+    let invalid = QByteArray::from(&[0x61, 0xff, 0x62][..]);
+
+    valid.to_str() == Ok("String slice")
+        && matches!(
+            valid.to_string_lossy(),
+            std::borrow::Cow::Borrowed("String slice")
+        )
+        && invalid.to_str().is_err()
+        && invalid.to_string_lossy() == "a\u{fffd}b"
+}
+
+fn read_byte_slice(slice: &[u8]) -> bool {
+    slice == b"Bytes borrowed from C++"
+}
@@ -0,0 +1,80 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QByteArray, QCborArray, QCborMap, QCborValue};
+
+#[cxx::bridge]
+mod qcborvalue_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        include!("cxx-qt-lib/qcborvalue.h");
+
+        type QByteArray = cxx_qt_lib::QByteArray;
+        type QCborValue = cxx_qt_lib::QCborValue;
+    }
+
+    extern "Rust" {
+        fn construct_qcborvalue() -> QCborValue;
+        fn read_qcborvalue(v: &QCborValue) -> bool;
+        fn clone_qcborvalue(v: &QCborValue) -> QCborValue;
+        fn qcborvalue_from_cbor(bytes: &QByteArray) -> QCborValue;
+        fn qcborvalue_from_invalid_cbor(bytes: &QByteArray) -> bool;
+    }
+}
+
+fn construct_qcborvalue() -> QCborValue {
+    let array = [
+        QCborValue::from(true),
+        QCborValue::from(1.5),
+        QCborValue::null(),
+    ]
+    .iter()
+    .collect::<QCborArray>();
+
+    let mut map = QCborMap::default();
+    map.insert(&QCborValue::from("name"), &QCborValue::from("KDAB"));
+    map.insert(&QCborValue::from(1), &QCborValue::from(&array));
+    QCborValue::from(&map)
+}
+
+fn read_qcborvalue(v: &QCborValue) -> bool {
+    let map = match v.to_map() {
+        Some(map) => map,
+        None => return false,
+    };
+    if map.len() != 2 {
+        return false;
+    }
+
+    let name = map
+        .get(&QCborValue::from("name"))
+        .and_then(|value| value.to_qstring());
+    let array = map
+        .get(&QCborValue::from(1))
+        .and_then(|value| value.to_array());
+    match (name, array) {
+        (Some(name), Some(array)) => {
+            name.to_string() == "KDAB"
+                && array.len() == 3
+                && array.get(0).and_then(|value| value.to_bool()) == Some(true)
+                && array.get(1).and_then(|value| value.to_double()) == Some(1.5)
+                && array.get(2).map(|value| value.is_null()) == Some(true)
+                && array.get(3).is_none()
+        }
+        _others => false,
+    }
+}
+
+fn clone_qcborvalue(v: &QCborValue) -> QCborValue {
+    v.clone()
+}
+
+fn qcborvalue_from_cbor(bytes: &QByteArray) -> QCborValue {
+    QCborValue::from_cbor(bytes).unwrap()
+}
+
+fn qcborvalue_from_invalid_cbor(bytes: &QByteArray) -> bool {
+    QCborValue::from_cbor(bytes).is_err()
+}
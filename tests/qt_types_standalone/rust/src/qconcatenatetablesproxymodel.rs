@@ -0,0 +1,85 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::UniquePtr;
+use cxx_qt_lib::{QConcatenateTablesProxyModel, QIdentityProxyModel, QString, QStringListModel};
+
+#[cxx::bridge]
+mod qconcatenatetablesproxymodel_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qconcatenatetablesproxymodel.h");
+        include!("cxx-qt-lib/qidentityproxymodel.h");
+
+        type QConcatenateTablesProxyModel = cxx_qt_lib::QConcatenateTablesProxyModel;
+        type QIdentityProxyModel = cxx_qt_lib::QIdentityProxyModel;
+    }
+
+    extern "Rust" {
+        type ProxyModelTest;
+
+        fn new_proxy_model_test() -> Box<ProxyModelTest>;
+        fn concatenate(self: &mut ProxyModelTest) -> Pin<&mut QConcatenateTablesProxyModel>;
+        fn identity(self: &mut ProxyModelTest) -> Pin<&mut QIdentityProxyModel>;
+        fn remove_second_model(self: &mut ProxyModelTest);
+    }
+}
+
+struct ProxyModelTest {
+    // The proxies are declared first so that they are dropped before their source models
+    identity: UniquePtr<QIdentityProxyModel>,
+    concatenate: UniquePtr<QConcatenateTablesProxyModel>,
+    first: UniquePtr<QStringListModel>,
+    second: UniquePtr<QStringListModel>,
+}
+
+fn new_proxy_model_test() -> Box<ProxyModelTest> {
+    let mut first = QStringListModel::new();
+    first
+        .pin_mut()
+        .set_string_list(vec![QString::from("Red"), QString::from("Green")]);
+    let mut second = QStringListModel::new();
+    second
+        .pin_mut()
+        .set_string_list(vec![QString::from("Blue")]);
+
+    let mut concatenate = QConcatenateTablesProxyModel::new();
+    let mut identity = QIdentityProxyModel::new();
+    unsafe {
+        concatenate
+            .pin_mut()
+            .add_source_model(first.pin_mut().as_abstract_item_model_ptr());
+        concatenate
+            .pin_mut()
+            .add_source_model(second.pin_mut().as_abstract_item_model_ptr());
+        identity
+            .pin_mut()
+            .set_source_model(concatenate.pin_mut().as_abstract_item_model_ptr());
+    }
+
+    Box::new(ProxyModelTest {
+        identity,
+        concatenate,
+        first,
+        second,
+    })
+}
+
+impl ProxyModelTest {
+    fn concatenate(&mut self) -> core::pin::Pin<&mut QConcatenateTablesProxyModel> {
+        self.concatenate.pin_mut()
+    }
+
+    fn identity(&mut self) -> core::pin::Pin<&mut QIdentityProxyModel> {
+        self.identity.pin_mut()
+    }
+
+    fn remove_second_model(&mut self) {
+        unsafe {
+            self.concatenate
+                .pin_mut()
+                .remove_source_model(self.second.pin_mut().as_abstract_item_model_ptr());
+        }
+    }
+}
@@ -0,0 +1,82 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QHash, QList, QString};
+
+#[cxx_qt::bridge(namespace = "cxx_qt::containers")]
+mod qcontainer_custom_cxx {
+    #[derive(Clone, Debug, PartialEq)]
+    struct CustomPoint {
+        x: i32,
+        y: i32,
+    }
+
+    #[namespace = ""]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    impl_qlist_element!(CustomPoint);
+    impl_qhash_pair!(QString, CustomPoint);
+
+    extern "Rust" {
+        fn construct_qlist_custom() -> QList_CustomPoint;
+        fn read_qlist_custom(list: &QList_CustomPoint) -> bool;
+        fn clone_qlist_custom(list: &QList_CustomPoint) -> QList_CustomPoint;
+        fn construct_qhash_custom() -> QHash_QString_CustomPoint;
+        fn read_qhash_custom(hash: &QHash_QString_CustomPoint) -> bool;
+        fn clone_qhash_custom(hash: &QHash_QString_CustomPoint) -> QHash_QString_CustomPoint;
+    }
+}
+
+use qcontainer_custom_cxx::CustomPoint;
+
+fn construct_qlist_custom() -> QList<CustomPoint> {
+    let mut list = QList::<CustomPoint>::default();
+    list.append(CustomPoint { x: 1, y: 2 });
+    list.append(CustomPoint { x: 3, y: 4 });
+    list.insert(0, CustomPoint { x: 0, y: 0 });
+    list
+}
+
+fn read_qlist_custom(list: &QList<CustomPoint>) -> bool {
+    // Ensure that the iterator works by building a vector from it
+    let vec = list.iter().cloned().collect::<Vec<CustomPoint>>();
+
+    vec == vec![CustomPoint { x: 1, y: 2 }, CustomPoint { x: 3, y: 4 }]
+        && list.contains(&CustomPoint { x: 3, y: 4 })
+        && !list.contains(&CustomPoint { x: 5, y: 6 })
+        && list.index_of(&CustomPoint { x: 3, y: 4 }) == 1
+}
+
+fn clone_qlist_custom(list: &QList<CustomPoint>) -> QList<CustomPoint> {
+    let mut list = list.clone();
+    list.remove(0);
+    list
+}
+
+fn construct_qhash_custom() -> QHash<QHashPair_QString_CustomPoint> {
+    let mut hash = QHash::<QHashPair_QString_CustomPoint>::default();
+    hash.insert(QString::from("origin"), CustomPoint { x: 0, y: 0 });
+    hash.insert(QString::from("corner"), CustomPoint { x: 10, y: 20 });
+    hash
+}
+
+fn read_qhash_custom(hash: &QHash<QHashPair_QString_CustomPoint>) -> bool {
+    hash.len() == 2
+        && hash.iter().count() == 2
+        && hash.get_or_default(&QString::from("corner")) == CustomPoint { x: 10, y: 20 }
+        && hash.contains(&QString::from("origin"))
+        && !hash.contains(&QString::from("missing"))
+}
+
+fn clone_qhash_custom(
+    hash: &QHash<QHashPair_QString_CustomPoint>,
+) -> QHash<QHashPair_QString_CustomPoint> {
+    let mut hash = hash.clone();
+    hash.remove(&QString::from("origin"));
+    hash
+}
@@ -3,7 +3,9 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+use core::pin::Pin;
 use cxx_qt_lib::{QCoreApplication, QString};
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 #[cxx::bridge]
 mod qcoreapplication_cxx {
@@ -15,6 +17,8 @@ mod qcoreapplication_cxx {
     extern "Rust" {
         fn construct_qcoreapplication() -> UniquePtr<QCoreApplication>;
         fn read_qcoreapplication(c: &QCoreApplication) -> bool;
+        fn connect_qcoreapplication_about_to_quit(app: Pin<&mut QCoreApplication>);
+        fn qcoreapplication_about_to_quit_count() -> usize;
     }
 }
 
@@ -29,3 +33,17 @@ fn construct_qcoreapplication() -> cxx::UniquePtr<QCoreApplication> {
 fn read_qcoreapplication(app: &QCoreApplication) -> bool {
     app.application_name().to_string() == "kdab"
 }
+
+static ABOUT_TO_QUIT_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+fn connect_qcoreapplication_about_to_quit(app: Pin<&mut QCoreApplication>) {
+    ABOUT_TO_QUIT_COUNT.store(0, Ordering::SeqCst);
+    app.on_about_to_quit(|| {
+        ABOUT_TO_QUIT_COUNT.fetch_add(1, Ordering::SeqCst);
+    })
+    .release();
+}
+
+fn qcoreapplication_about_to_quit_count() -> usize {
+    ABOUT_TO_QUIT_COUNT.load(Ordering::SeqCst)
+}
@@ -0,0 +1,66 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{
+    QByteArray, QCryptographicHash, QCryptographicHashAlgorithm, QMessageAuthenticationCode,
+};
+
+#[cxx::bridge]
+mod qcryptographichash_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+
+        type QByteArray = cxx_qt_lib::QByteArray;
+    }
+
+    extern "Rust" {
+        fn qcryptographichash_sha256(data: &QByteArray) -> QByteArray;
+        fn qcryptographichash_sha256_incremental(data: &QByteArray) -> QByteArray;
+        fn qcryptographichash_sha256_length() -> i32;
+        fn qmessageauthenticationcode_sha256(message: &QByteArray, key: &QByteArray) -> QByteArray;
+        fn qmessageauthenticationcode_sha256_incremental(
+            message: &QByteArray,
+            key: &QByteArray,
+        ) -> QByteArray;
+    }
+}
+
+fn qcryptographichash_sha256(data: &QByteArray) -> QByteArray {
+    QCryptographicHash::hash(data.as_slice(), QCryptographicHashAlgorithm::Sha256)
+}
+
+fn qcryptographichash_sha256_incremental(data: &QByteArray) -> QByteArray {
+    let mut hash = QCryptographicHash::new(QCryptographicHashAlgorithm::Sha256);
+    hash.pin_mut().add_data(b"discarded");
+    hash.pin_mut().reset();
+    for chunk in data.as_slice().chunks(3) {
+        hash.pin_mut().add_data(chunk);
+    }
+    hash.result()
+}
+
+fn qcryptographichash_sha256_length() -> i32 {
+    QCryptographicHash::hash_length(QCryptographicHashAlgorithm::Sha256)
+}
+
+fn qmessageauthenticationcode_sha256(message: &QByteArray, key: &QByteArray) -> QByteArray {
+    QMessageAuthenticationCode::hash(
+        message.as_slice(),
+        key.as_slice(),
+        QCryptographicHashAlgorithm::Sha256,
+    )
+}
+
+fn qmessageauthenticationcode_sha256_incremental(
+    message: &QByteArray,
+    key: &QByteArray,
+) -> QByteArray {
+    let mut code = QMessageAuthenticationCode::new(QCryptographicHashAlgorithm::Sha256, b"wrong");
+    code.pin_mut().set_key(key.as_slice());
+    for chunk in message.as_slice().chunks(3) {
+        code.pin_mut().add_data(chunk);
+    }
+    code.result()
+}
@@ -0,0 +1,49 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QByteArray, QDataStream, QDataStreamStatus, QString};
+
+/// The version of the format for Qt 5.15, so that the data can be read by Qt 5 and Qt 6
+const QDATASTREAM_VERSION: i32 = 19;
+
+#[cxx::bridge]
+mod qdatastream_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+    }
+
+    extern "Rust" {
+        fn write_qdatastream() -> QByteArray;
+        fn read_qdatastream(bytes: &QByteArray) -> bool;
+    }
+}
+
+fn write_qdatastream() -> QByteArray {
+    QDataStream::write_to_bytearray(|mut stream| {
+        stream.as_mut().set_version(QDATASTREAM_VERSION);
+        stream.as_mut().write(&QString::from("KDAB"));
+        stream.as_mut().write(&42_i32);
+        stream.as_mut().write(&-1_i64);
+        stream.as_mut().write(&1.5_f64);
+    })
+}
+
+fn read_qdatastream(bytes: &QByteArray) -> bool {
+    let mut stream = QDataStream::from_bytearray(bytes);
+    stream.pin_mut().set_version(QDATASTREAM_VERSION);
+
+    let string: QString = stream.pin_mut().read();
+    let number: i32 = stream.pin_mut().read();
+    let large: i64 = stream.pin_mut().read();
+    let float: f64 = stream.pin_mut().read();
+
+    string == QString::from("KDAB")
+        && number == 42
+        && large == -1
+        && float == 1.5
+        && stream.at_end()
+        && stream.status() == QDataStreamStatus::Ok
+}
@@ -0,0 +1,129 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{QEvent, QEventType, QObject, QString};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[cxx::bridge]
+mod qevent_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qevent.h");
+        type QEvent = cxx_qt_lib::QEvent;
+        include!("cxx-qt-lib/qobjectconnect.h");
+        type QObject = cxx_qt_lib::QObject;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "Rust" {
+        fn qevent_timer_id(event: &QEvent) -> i32;
+        fn qevent_key(event: &QEvent) -> i32;
+        fn qevent_mouse_button(event: &QEvent) -> i32;
+        fn qevent_shortcut_key(event: &QEvent) -> QString;
+        fn qevent_install_filter(object: Pin<&mut QObject>);
+        fn qevent_filter_dropped() -> bool;
+        fn qevent_new_custom(text: &QString) -> UniquePtr<QEvent>;
+        fn qevent_custom_text(event: &QEvent) -> QString;
+        fn qevent_custom_is_number(event: &QEvent) -> bool;
+        fn qevent_custom_dropped() -> bool;
+    }
+}
+
+// Returns -1 if the event is not of the requested type
+fn qevent_timer_id(event: &QEvent) -> i32 {
+    event
+        .as_timer_event()
+        .map(|event| event.timer_id())
+        .unwrap_or(-1)
+}
+
+fn qevent_key(event: &QEvent) -> i32 {
+    event.as_key_event().map(|event| event.key()).unwrap_or(-1)
+}
+
+fn qevent_mouse_button(event: &QEvent) -> i32 {
+    event
+        .as_mouse_event()
+        .map(|event| event.button())
+        .unwrap_or(-1)
+}
+
+fn qevent_shortcut_key(event: &QEvent) -> QString {
+    event
+        .as_shortcut_event()
+        .map(|event| event.key())
+        .unwrap_or_default()
+}
+
+static FILTER_DROPPED: AtomicBool = AtomicBool::new(false);
+
+// Sets FILTER_DROPPED when the closure which owns it is dropped
+struct DropFlag;
+
+impl Drop for DropFlag {
+    fn drop(&mut self) {
+        FILTER_DROPPED.store(true, Ordering::Release);
+    }
+}
+
+fn qevent_install_filter(object: Pin<&mut QObject>) {
+    FILTER_DROPPED.store(false, Ordering::Release);
+    let flag = DropFlag;
+    let mut key_events = 0;
+    // Stop every second key event being handled, so that the closure must keep state
+    object.install_event_filter(move |event| {
+        let _drop_flag = &flag;
+        if event.as_key_event().is_some() {
+            key_events += 1;
+            return key_events % 2 == 0;
+        }
+
+        false
+    });
+}
+
+fn qevent_filter_dropped() -> bool {
+    FILTER_DROPPED.load(Ordering::Acquire)
+}
+
+static CUSTOM_DROPPED: AtomicBool = AtomicBool::new(false);
+
+// The payload of a custom event, which sets CUSTOM_DROPPED when it is dropped
+struct CustomPayload {
+    text: String,
+}
+
+impl Drop for CustomPayload {
+    fn drop(&mut self) {
+        CUSTOM_DROPPED.store(true, Ordering::Release);
+    }
+}
+
+fn qevent_new_custom(text: &QString) -> cxx::UniquePtr<QEvent> {
+    CUSTOM_DROPPED.store(false, Ordering::Release);
+    QEvent::new_custom(
+        QEventType::register(),
+        CustomPayload {
+            text: text.to_string(),
+        },
+    )
+}
+
+fn qevent_custom_text(event: &QEvent) -> QString {
+    event
+        .custom_payload::<CustomPayload>()
+        .map(|payload| QString::from(&payload.text))
+        .unwrap_or_default()
+}
+
+// A payload of another type is not returned
+fn qevent_custom_is_number(event: &QEvent) -> bool {
+    event.custom_payload::<i32>().is_some()
+}
+
+fn qevent_custom_dropped() -> bool {
+    CUSTOM_DROPPED.load(Ordering::Acquire)
+}
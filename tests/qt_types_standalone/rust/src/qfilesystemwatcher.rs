@@ -0,0 +1,59 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::UniquePtr;
+use cxx_qt_lib::{QFileSystemWatcher, QMetaObjectConnection, QString};
+use std::{cell::RefCell, rc::Rc};
+
+#[cxx::bridge]
+mod qfilesystemwatcher_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "Rust" {
+        type FileSystemWatcherTest;
+
+        fn new_file_system_watcher_test(path: &QString) -> Box<FileSystemWatcherTest>;
+        fn changed_paths(self: &FileSystemWatcherTest) -> Vec<String>;
+        fn disconnect(self: &mut FileSystemWatcherTest);
+    }
+}
+
+struct FileSystemWatcherTest {
+    changed_paths: Rc<RefCell<Vec<String>>>,
+    connection: Option<QMetaObjectConnection>,
+    // Drop the connection before the watcher
+    _watcher: UniquePtr<QFileSystemWatcher>,
+}
+
+fn new_file_system_watcher_test(path: &QString) -> Box<FileSystemWatcherTest> {
+    let mut watcher = QFileSystemWatcher::new();
+    watcher.pin_mut().add_path(path);
+
+    let changed_paths = Rc::new(RefCell::new(vec![]));
+    let connection = watcher.pin_mut().on_file_changed({
+        let changed_paths = changed_paths.clone();
+        move |path| changed_paths.borrow_mut().push(path.to_string())
+    });
+
+    Box::new(FileSystemWatcherTest {
+        changed_paths,
+        connection: Some(connection),
+        _watcher: watcher,
+    })
+}
+
+impl FileSystemWatcherTest {
+    fn changed_paths(&self) -> Vec<String> {
+        self.changed_paths.borrow().clone()
+    }
+
+    fn disconnect(&mut self) {
+        self.connection = None;
+    }
+}
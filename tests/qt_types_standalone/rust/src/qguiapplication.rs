@@ -3,7 +3,9 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+use core::pin::Pin;
 use cxx_qt_lib::{QGuiApplication, QString};
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 #[cxx::bridge]
 mod qguiapplication_cxx {
@@ -15,6 +17,8 @@ mod qguiapplication_cxx {
     extern "Rust" {
         fn construct_qguiapplication() -> UniquePtr<QGuiApplication>;
         fn read_qguiapplication(c: &QGuiApplication) -> bool;
+        fn connect_qguiapplication_about_to_quit(app: Pin<&mut QGuiApplication>);
+        fn qguiapplication_about_to_quit_count() -> usize;
     }
 }
 
@@ -29,3 +33,17 @@ fn construct_qguiapplication() -> cxx::UniquePtr<QGuiApplication> {
 fn read_qguiapplication(app: &QGuiApplication) -> bool {
     app.application_name().to_string() == "kdab"
 }
+
+static ABOUT_TO_QUIT_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+fn connect_qguiapplication_about_to_quit(app: Pin<&mut QGuiApplication>) {
+    ABOUT_TO_QUIT_COUNT.store(0, Ordering::SeqCst);
+    app.on_about_to_quit(|| {
+        ABOUT_TO_QUIT_COUNT.fetch_add(1, Ordering::SeqCst);
+    })
+    .release();
+}
+
+fn qguiapplication_about_to_quit_count() -> usize {
+    ABOUT_TO_QUIT_COUNT.load(Ordering::SeqCst)
+}
@@ -0,0 +1,54 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{QGyroscope, QString};
+use std::sync::Mutex;
+
+#[cxx::bridge]
+mod qgyroscope_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qgyroscope.h");
+        type QGyroscope = cxx_qt_lib::QGyroscope;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "Rust" {
+        fn construct_qgyroscope() -> UniquePtr<QGyroscope>;
+        fn qgyroscope_start(sensor: Pin<&mut QGyroscope>) -> bool;
+        fn qgyroscope_take_readings() -> QString;
+    }
+}
+
+/// The readings which have been received, formatted as "x,y,z,timestamp"
+static READINGS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+fn construct_qgyroscope() -> cxx::UniquePtr<QGyroscope> {
+    let mut sensor = QGyroscope::new();
+    if let Some(sensor) = sensor.as_mut() {
+        sensor.set_data_rate(10);
+    }
+    sensor
+}
+
+fn qgyroscope_start(mut sensor: Pin<&mut QGyroscope>) -> bool {
+    READINGS.lock().unwrap().clear();
+    sensor
+        .as_mut()
+        .on_reading_changed(|reading| {
+            READINGS.lock().unwrap().push(format!(
+                "{},{},{},{}",
+                reading.x, reading.y, reading.z, reading.timestamp
+            ));
+        })
+        .release();
+    sensor.start()
+}
+
+// Returns the readings separated by semicolons, and clears them
+fn qgyroscope_take_readings() -> QString {
+    QString::from(&std::mem::take(&mut *READINGS.lock().unwrap()).join(";"))
+}
@@ -0,0 +1,61 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QKeychain, QKeychainError, QString};
+use std::sync::Mutex;
+
+#[cxx::bridge]
+mod qkeychain_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "Rust" {
+        fn keychain_service(service: &QString) -> QString;
+        fn keychain_write(key: &QString, password: &QString);
+        fn keychain_read(key: &QString);
+        fn keychain_delete(key: &QString);
+        fn keychain_take_result() -> QString;
+    }
+}
+
+/// The result of the last job, which is empty while the job is running
+static RESULT: Mutex<String> = Mutex::new(String::new());
+
+fn keychain() -> QKeychain {
+    QKeychain::new(&QString::from("org.kdab.cxx_qt.qt_types_standalone"))
+}
+
+fn store_result(result: Result<String, QKeychainError>) {
+    *RESULT.lock().unwrap() = match result {
+        Ok(data) => format!("ok:{data}"),
+        Err(err) => format!("error:{:?}", err.kind),
+    };
+}
+
+fn keychain_service(service: &QString) -> QString {
+    QKeychain::new(service).service().clone()
+}
+
+fn keychain_write(key: &QString, password: &QString) {
+    keychain().write_password(key, password, |result| {
+        store_result(result.map(|_| String::new()))
+    });
+}
+
+fn keychain_read(key: &QString) {
+    keychain().read_password(key, |result| {
+        store_result(result.map(|password| password.to_string()))
+    });
+}
+
+fn keychain_delete(key: &QString) {
+    keychain().delete_password(key, |result| store_result(result.map(|_| String::new())));
+}
+
+fn keychain_take_result() -> QString {
+    QString::from(&std::mem::take(&mut *RESULT.lock().unwrap()))
+}
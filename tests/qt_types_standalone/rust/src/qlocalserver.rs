@@ -0,0 +1,54 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{QLocalServer, QLocalSocket, QString};
+use std::sync::atomic::{AtomicI32, Ordering};
+
+#[cxx::bridge]
+mod qlocalserver_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qlocalserver.h");
+        type QLocalServer = cxx_qt_lib::QLocalServer;
+        include!("cxx-qt-lib/qlocalsocket.h");
+        type QLocalSocket = cxx_qt_lib::QLocalSocket;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "Rust" {
+        fn construct_qlocalserver(name: &QString) -> UniquePtr<QLocalServer>;
+        fn qlocalserver_accept(server: Pin<&mut QLocalServer>) -> UniquePtr<QLocalSocket>;
+        fn qlocalserver_take_connections() -> i32;
+    }
+}
+
+/// The number of new connections which the servers have been notified of
+static CONNECTIONS: AtomicI32 = AtomicI32::new(0);
+
+fn construct_qlocalserver(name: &QString) -> cxx::UniquePtr<QLocalServer> {
+    // Remove the socket file of a previous run which did not close its server
+    QLocalServer::remove_server(name);
+
+    let mut server = QLocalServer::new();
+    if let Some(mut server) = server.as_mut() {
+        server
+            .as_mut()
+            .on_new_connection(|| {
+                CONNECTIONS.fetch_add(1, Ordering::SeqCst);
+            })
+            .release();
+        server.listen(name);
+    }
+    server
+}
+
+fn qlocalserver_accept(server: Pin<&mut QLocalServer>) -> cxx::UniquePtr<QLocalSocket> {
+    server.next_pending_connection()
+}
+
+fn qlocalserver_take_connections() -> i32 {
+    CONNECTIONS.swap(0, Ordering::SeqCst)
+}
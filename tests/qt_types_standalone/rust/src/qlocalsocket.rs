@@ -0,0 +1,73 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{QByteArray, QLocalSocket, QString};
+use std::sync::Mutex;
+
+#[cxx::bridge]
+mod qlocalsocket_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        include!("cxx-qt-lib/qlocalsocket.h");
+        type QLocalSocket = cxx_qt_lib::QLocalSocket;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "Rust" {
+        fn construct_qlocalsocket() -> UniquePtr<QLocalSocket>;
+        fn qlocalsocket_connect(socket: Pin<&mut QLocalSocket>, name: &QString);
+        fn qlocalsocket_read_all(socket: Pin<&mut QLocalSocket>) -> QByteArray;
+        fn qlocalsocket_write(socket: Pin<&mut QLocalSocket>, data: &QByteArray) -> i64;
+        fn qlocalsocket_take_events() -> QString;
+    }
+}
+
+/// The signals which the sockets have emitted, the errors are recorded with their value
+static EVENTS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+fn push_event(event: String) {
+    EVENTS.lock().unwrap().push(event);
+}
+
+fn construct_qlocalsocket() -> cxx::UniquePtr<QLocalSocket> {
+    let mut socket = QLocalSocket::new();
+    if let Some(mut socket) = socket.as_mut() {
+        socket
+            .as_mut()
+            .on_connected(|| push_event("connected".to_owned()))
+            .release();
+        socket
+            .as_mut()
+            .on_disconnected(|| push_event("disconnected".to_owned()))
+            .release();
+        socket
+            .as_mut()
+            .on_error_occurred(|error| push_event(format!("error:{}", error.repr)))
+            .release();
+        socket
+            .on_ready_read(|| push_event("ready_read".to_owned()))
+            .release();
+    }
+    socket
+}
+
+fn qlocalsocket_connect(socket: Pin<&mut QLocalSocket>, name: &QString) {
+    socket.connect_to_server(name);
+}
+
+fn qlocalsocket_read_all(socket: Pin<&mut QLocalSocket>) -> QByteArray {
+    socket.read_all()
+}
+
+fn qlocalsocket_write(socket: Pin<&mut QLocalSocket>, data: &QByteArray) -> i64 {
+    socket.write(data)
+}
+
+fn qlocalsocket_take_events() -> QString {
+    QString::from(&std::mem::take(&mut *EVENTS.lock().unwrap()).join(";"))
+}
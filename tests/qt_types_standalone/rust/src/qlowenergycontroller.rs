@@ -0,0 +1,47 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{QBluetoothDeviceInfo, QList, QLowEnergyController, QString};
+
+#[cxx::bridge]
+mod qlowenergycontroller_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbluetoothdeviceinfo.h");
+        type QBluetoothDeviceInfo = cxx_qt_lib::QBluetoothDeviceInfo;
+        include!("cxx-qt-lib/qlowenergycontroller.h");
+        type QLowEnergyController = cxx_qt_lib::QLowEnergyController;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "Rust" {
+        fn construct_qlowenergycontroller(
+            device: &QBluetoothDeviceInfo,
+        ) -> UniquePtr<QLowEnergyController>;
+        fn read_qlowenergycontroller(controller: Pin<&mut QLowEnergyController>) -> QString;
+    }
+}
+
+fn construct_qlowenergycontroller(
+    device: &QBluetoothDeviceInfo,
+) -> cxx::UniquePtr<QLowEnergyController> {
+    QLowEnergyController::new_central(device)
+}
+
+fn read_qlowenergycontroller(mut controller: Pin<&mut QLowEnergyController>) -> QString {
+    let services = QList::<QString>::from(&controller.services()).len();
+    // The services of a controller which has not connected have not been discovered
+    let service = controller
+        .as_mut()
+        .create_service(&QString::from("0000180d-0000-1000-8000-00805f9b34fb"));
+    QString::from(&format!(
+        "{},{},{},{}",
+        controller.remote_name(),
+        controller.state().repr,
+        services,
+        service.is_null()
+    ))
+}
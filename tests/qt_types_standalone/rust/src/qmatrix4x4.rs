@@ -0,0 +1,41 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QMatrix4x4, QVector3D};
+
+#[cxx::bridge]
+mod qmatrix4x4_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmatrix4x4.h");
+        type QMatrix4x4 = cxx_qt_lib::QMatrix4x4;
+    }
+
+    extern "Rust" {
+        fn construct_qmatrix4x4() -> QMatrix4x4;
+        fn construct_qmatrix4x4_translated() -> QMatrix4x4;
+        fn read_qmatrix4x4(m: &QMatrix4x4) -> bool;
+        fn clone_qmatrix4x4(m: &QMatrix4x4) -> QMatrix4x4;
+    }
+}
+
+fn construct_qmatrix4x4() -> QMatrix4x4 {
+    QMatrix4x4::from_row_major(&[
+        1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+    ])
+}
+
+fn construct_qmatrix4x4_translated() -> QMatrix4x4 {
+    let mut m = QMatrix4x4::default();
+    m.translate(&QVector3D::new(1.0, 2.0, 3.0));
+    m
+}
+
+fn read_qmatrix4x4(m: &QMatrix4x4) -> bool {
+    m.get(0, 0) == 1.0 && m.get(0, 3) == 4.0 && m.get(3, 0) == 13.0 && m.get(3, 3) == 16.0
+}
+
+fn clone_qmatrix4x4(m: &QMatrix4x4) -> QMatrix4x4 {
+    m.clone()
+}
@@ -0,0 +1,63 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QNetworkInformation, QString};
+
+#[cxx::bridge]
+mod qnetworkinformation_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "Rust" {
+        fn qnetworkinformation_load() -> bool;
+        fn qnetworkinformation_backend_name() -> QString;
+        fn qnetworkinformation_reachability() -> i32;
+        fn qnetworkinformation_transport_medium() -> i32;
+        fn qnetworkinformation_is_metered() -> bool;
+        fn qnetworkinformation_connect() -> bool;
+    }
+}
+
+fn qnetworkinformation_load() -> bool {
+    QNetworkInformation::load_default_backend()
+}
+
+fn qnetworkinformation_backend_name() -> QString {
+    QNetworkInformation::instance()
+        .map(|information| information.backend_name())
+        .unwrap_or_default()
+}
+
+fn qnetworkinformation_reachability() -> i32 {
+    QNetworkInformation::instance()
+        .map(|information| information.reachability().repr)
+        .unwrap_or(-1)
+}
+
+fn qnetworkinformation_transport_medium() -> i32 {
+    QNetworkInformation::instance()
+        .map(|information| information.transport_medium().repr)
+        .unwrap_or(-1)
+}
+
+fn qnetworkinformation_is_metered() -> bool {
+    QNetworkInformation::instance().is_some_and(|information| information.is_metered())
+}
+
+fn qnetworkinformation_connect() -> bool {
+    if let Some(information) = QNetworkInformation::instance() {
+        information.on_reachability_changed(|_| {}).release();
+        information.on_transport_medium_changed(|_| {}).release();
+        information
+            .on_is_behind_captive_portal_changed(|_| {})
+            .release();
+        information.on_is_metered_changed(|_| {}).release();
+        true
+    } else {
+        false
+    }
+}
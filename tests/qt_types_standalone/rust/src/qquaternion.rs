@@ -0,0 +1,35 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::QQuaternion;
+
+#[cxx::bridge]
+mod qquaternion_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qquaternion.h");
+        type QQuaternion = cxx_qt_lib::QQuaternion;
+    }
+
+    extern "Rust" {
+        fn construct_qquaternion() -> QQuaternion;
+        fn read_qquaternion(q: &QQuaternion) -> bool;
+        fn clone_qquaternion(q: &QQuaternion) -> QQuaternion;
+    }
+}
+
+fn construct_qquaternion() -> QQuaternion {
+    QQuaternion::new(1.23, 4.56, 7.89, 1.47)
+}
+
+fn read_qquaternion(q: &QQuaternion) -> bool {
+    ((q.scalar() - 1.23).abs() < f32::EPSILON)
+        && ((q.x() - 4.56).abs() < f32::EPSILON)
+        && ((q.y() - 7.89).abs() < f32::EPSILON)
+        && ((q.z() - 1.47).abs() < f32::EPSILON)
+}
+
+fn clone_qquaternion(q: &QQuaternion) -> QQuaternion {
+    q.clone()
+}
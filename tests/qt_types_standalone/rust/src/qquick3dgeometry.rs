@@ -0,0 +1,55 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{
+    qquick3dgeometry_buffer, QByteArray, QQuick3DGeometryAttributeComponentType,
+    QQuick3DGeometryAttributeSemantic, QQuick3DGeometryPrimitiveType,
+};
+
+#[cxx::bridge]
+mod qquick3dgeometry_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+
+        include!("cxx-qt-lib/qquick3dgeometry.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QQuick3DGeometryAttributeComponentType =
+            cxx_qt_lib::QQuick3DGeometryAttributeComponentType;
+        #[namespace = "rust::cxxqtlib1"]
+        type QQuick3DGeometryAttributeSemantic = cxx_qt_lib::QQuick3DGeometryAttributeSemantic;
+        #[namespace = "rust::cxxqtlib1"]
+        type QQuick3DGeometryPrimitiveType = cxx_qt_lib::QQuick3DGeometryPrimitiveType;
+    }
+
+    extern "Rust" {
+        fn construct_vertex_buffer() -> QByteArray;
+        fn construct_index_buffer() -> QByteArray;
+        fn position_component_type() -> QQuick3DGeometryAttributeComponentType;
+        fn position_semantic() -> QQuick3DGeometryAttributeSemantic;
+        fn primitive_type() -> QQuick3DGeometryPrimitiveType;
+    }
+}
+
+fn construct_vertex_buffer() -> QByteArray {
+    let vertices: [[f32; 3]; 3] = [[-1.0, -1.0, 0.0], [1.0, -1.0, 0.0], [0.0, 1.0, 0.0]];
+    qquick3dgeometry_buffer(&vertices)
+}
+
+fn construct_index_buffer() -> QByteArray {
+    qquick3dgeometry_buffer(&[0_u16, 1, 2])
+}
+
+fn position_component_type() -> QQuick3DGeometryAttributeComponentType {
+    QQuick3DGeometryAttributeComponentType::F32Type
+}
+
+fn position_semantic() -> QQuick3DGeometryAttributeSemantic {
+    QQuick3DGeometryAttributeSemantic::PositionSemantic
+}
+
+fn primitive_type() -> QQuick3DGeometryPrimitiveType {
+    QQuick3DGeometryPrimitiveType::Triangles
+}
@@ -0,0 +1,55 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{
+    QIODeviceOpenModeFlag, QSerialPort, QSerialPortDataBits, QSerialPortFlowControl,
+    QSerialPortParity, QSerialPortStopBits, QString,
+};
+use std::sync::Mutex;
+
+#[cxx::bridge]
+mod qserialport_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qserialport.h");
+        type QSerialPort = cxx_qt_lib::QSerialPort;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "Rust" {
+        fn construct_qserialport() -> UniquePtr<QSerialPort>;
+        fn qserialport_open(port: Pin<&mut QSerialPort>, name: &QString) -> bool;
+        fn qserialport_take_errors() -> QString;
+    }
+}
+
+/// The errors which have occurred, as the value of the error
+static ERRORS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+fn construct_qserialport() -> cxx::UniquePtr<QSerialPort> {
+    let mut port = QSerialPort::new();
+    if let Some(mut port) = port.as_mut() {
+        port.as_mut().set_port_name(&QString::from("ttyCxxQt"));
+        port.as_mut().set_baud_rate(9600);
+        port.as_mut().set_data_bits(QSerialPortDataBits::Data7);
+        port.as_mut().set_parity(QSerialPortParity::EvenParity);
+        port.as_mut().set_stop_bits(QSerialPortStopBits::TwoStop);
+        port.set_flow_control(QSerialPortFlowControl::SoftwareControl);
+    }
+    port
+}
+
+fn qserialport_open(mut port: Pin<&mut QSerialPort>, name: &QString) -> bool {
+    port.as_mut()
+        .on_error_occurred(|error| ERRORS.lock().unwrap().push(error.repr.to_string()))
+        .release();
+    port.as_mut().set_port_name(name);
+    port.open(QIODeviceOpenModeFlag::ReadWrite)
+}
+
+fn qserialport_take_errors() -> QString {
+    QString::from(&std::mem::take(&mut *ERRORS.lock().unwrap()).join(";"))
+}
@@ -0,0 +1,51 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{QByteArray, QSharedMemory, QSharedMemoryAccessMode, QString};
+
+#[cxx::bridge]
+mod qsharedmemory_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        include!("cxx-qt-lib/qsharedmemory.h");
+        type QSharedMemory = cxx_qt_lib::QSharedMemory;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "Rust" {
+        fn construct_qsharedmemory(key: &QString) -> UniquePtr<QSharedMemory>;
+        fn qsharedmemory_attach(shared_memory: Pin<&mut QSharedMemory>) -> bool;
+        fn qsharedmemory_create(shared_memory: Pin<&mut QSharedMemory>, size: isize) -> bool;
+        fn qsharedmemory_read(shared_memory: Pin<&mut QSharedMemory>, len: usize) -> QByteArray;
+        fn qsharedmemory_write(shared_memory: Pin<&mut QSharedMemory>, data: &QByteArray) -> bool;
+    }
+}
+
+fn construct_qsharedmemory(key: &QString) -> cxx::UniquePtr<QSharedMemory> {
+    QSharedMemory::new(key)
+}
+
+fn qsharedmemory_attach(shared_memory: Pin<&mut QSharedMemory>) -> bool {
+    shared_memory.attach(QSharedMemoryAccessMode::ReadWrite)
+}
+
+fn qsharedmemory_create(shared_memory: Pin<&mut QSharedMemory>, size: isize) -> bool {
+    shared_memory.create(size, QSharedMemoryAccessMode::ReadWrite)
+}
+
+fn qsharedmemory_read(shared_memory: Pin<&mut QSharedMemory>, len: usize) -> QByteArray {
+    shared_memory
+        .with_lock(|memory| QByteArray::from(&memory[..len]))
+        .unwrap_or_default()
+}
+
+fn qsharedmemory_write(shared_memory: Pin<&mut QSharedMemory>, data: &QByteArray) -> bool {
+    shared_memory
+        .with_lock(|memory| memory[..data.len() as usize].copy_from_slice(data.as_slice()))
+        .is_some()
+}
@@ -0,0 +1,58 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{QSqlDatabase, QString};
+
+#[cxx::bridge]
+mod qsqldatabase_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qsqldatabase.h");
+        type QSqlDatabase = cxx_qt_lib::QSqlDatabase;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "Rust" {
+        fn construct_qsqldatabase(connection_name: &QString) -> UniquePtr<QSqlDatabase>;
+        fn qsqldatabase_contains(connection_name: &QString) -> bool;
+        fn qsqldatabase_open(database: Pin<&mut QSqlDatabase>) -> bool;
+        fn qsqldatabase_remove(connection_name: &QString);
+        fn qsqldatabase_transaction(database: Pin<&mut QSqlDatabase>) -> bool;
+        fn qsqldatabase_finish_transaction(database: Pin<&mut QSqlDatabase>, commit: bool) -> bool;
+    }
+}
+
+fn construct_qsqldatabase(connection_name: &QString) -> cxx::UniquePtr<QSqlDatabase> {
+    let mut database = QSqlDatabase::add_database(&QString::from("QSQLITE"), connection_name);
+    if let Some(database) = database.as_mut() {
+        database.set_database_name(&QString::from(":memory:"));
+    }
+    database
+}
+
+fn qsqldatabase_contains(connection_name: &QString) -> bool {
+    QSqlDatabase::contains(connection_name)
+}
+
+fn qsqldatabase_open(database: Pin<&mut QSqlDatabase>) -> bool {
+    database.open()
+}
+
+fn qsqldatabase_remove(connection_name: &QString) {
+    QSqlDatabase::remove_database(connection_name);
+}
+
+fn qsqldatabase_transaction(database: Pin<&mut QSqlDatabase>) -> bool {
+    database.transaction()
+}
+
+fn qsqldatabase_finish_transaction(database: Pin<&mut QSqlDatabase>, commit: bool) -> bool {
+    if commit {
+        database.commit()
+    } else {
+        database.rollback()
+    }
+}
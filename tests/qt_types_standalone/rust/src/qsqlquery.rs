@@ -0,0 +1,93 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{QSqlDatabase, QSqlQuery, QString, QVariant};
+
+#[cxx::bridge]
+mod qsqlquery_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qsqldatabase.h");
+        type QSqlDatabase = cxx_qt_lib::QSqlDatabase;
+        include!("cxx-qt-lib/qsqlquery.h");
+        type QSqlQuery = cxx_qt_lib::QSqlQuery;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "Rust" {
+        fn construct_qsqlquery(database: &QSqlDatabase) -> UniquePtr<QSqlQuery>;
+        fn qsqlquery_insert_named(query: Pin<&mut QSqlQuery>, id: i64, name: &QString) -> bool;
+        fn qsqlquery_insert_positional(query: Pin<&mut QSqlQuery>, id: i64, name: &QString)
+            -> bool;
+        fn qsqlquery_select_rows(query: Pin<&mut QSqlQuery>) -> QString;
+        fn qsqlquery_first_name(query: Pin<&mut QSqlQuery>) -> QString;
+        fn qsqlquery_exec_error(query: Pin<&mut QSqlQuery>, text: &QString) -> QString;
+    }
+}
+
+fn construct_qsqlquery(database: &QSqlDatabase) -> cxx::UniquePtr<QSqlQuery> {
+    QSqlQuery::new(database)
+}
+
+fn qsqlquery_insert_named(mut query: Pin<&mut QSqlQuery>, id: i64, name: &QString) -> bool {
+    query.as_mut().prepare(&QString::from(
+        "INSERT INTO items (id, name) VALUES (:id, :name)",
+    ));
+    query
+        .as_mut()
+        .bind_value(&QString::from(":id"), &QVariant::from(&id));
+    query
+        .as_mut()
+        .bind_value(&QString::from(":name"), &QVariant::from(name));
+    query.exec()
+}
+
+fn qsqlquery_insert_positional(mut query: Pin<&mut QSqlQuery>, id: i64, name: &QString) -> bool {
+    query
+        .as_mut()
+        .prepare(&QString::from("INSERT INTO items (id, name) VALUES (?, ?)"));
+    query.as_mut().add_bind_value(&QVariant::from(&id));
+    query.as_mut().add_bind_value(&QVariant::from(name));
+    query.exec()
+}
+
+fn qsqlquery_select_rows(mut query: Pin<&mut QSqlQuery>) -> QString {
+    if !query
+        .as_mut()
+        .exec_query(&QString::from("SELECT id, name FROM items ORDER BY id"))
+    {
+        return QString::default();
+    }
+
+    let rows: Vec<String> = query
+        .rows()
+        .map(|row| {
+            let id: i64 = row.get(0).and_then(|id| id.value()).unwrap_or_default();
+            let name: QString = row.get(1).and_then(|name| name.value()).unwrap_or_default();
+            format!("{id}:{name}")
+        })
+        .collect();
+    QString::from(&rows.join(";"))
+}
+
+fn qsqlquery_first_name(mut query: Pin<&mut QSqlQuery>) -> QString {
+    if query.as_mut().first() {
+        query
+            .value_by_name(&QString::from("name"))
+            .value()
+            .unwrap_or_default()
+    } else {
+        QString::default()
+    }
+}
+
+fn qsqlquery_exec_error(mut query: Pin<&mut QSqlQuery>, text: &QString) -> QString {
+    if query.as_mut().exec_query(text) {
+        QString::default()
+    } else {
+        query.last_error_text()
+    }
+}
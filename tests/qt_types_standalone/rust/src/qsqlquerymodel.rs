@@ -0,0 +1,71 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{ItemDataRole, QModelIndex, QSqlDatabase, QSqlQuery, QSqlQueryModel, QString};
+
+#[cxx::bridge]
+mod qsqlquerymodel_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmodelindex.h");
+        type QModelIndex = cxx_qt_lib::QModelIndex;
+        include!("cxx-qt-lib/qsqldatabase.h");
+        type QSqlDatabase = cxx_qt_lib::QSqlDatabase;
+        include!("cxx-qt-lib/qsqlquerymodel.h");
+        type QSqlQueryModel = cxx_qt_lib::QSqlQueryModel;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "Rust" {
+        fn construct_qsqlquerymodel() -> UniquePtr<QSqlQueryModel>;
+        fn qsqlquerymodel_data(model: &QSqlQueryModel, index: &QModelIndex) -> QString;
+        fn qsqlquerymodel_set_executed_query(
+            model: Pin<&mut QSqlQueryModel>,
+            database: &QSqlDatabase,
+            text: &QString,
+        ) -> bool;
+        fn qsqlquerymodel_set_query(
+            model: Pin<&mut QSqlQueryModel>,
+            database: &QSqlDatabase,
+            text: &QString,
+        ) -> QString;
+    }
+}
+
+fn construct_qsqlquerymodel() -> cxx::UniquePtr<QSqlQueryModel> {
+    QSqlQueryModel::new()
+}
+
+fn qsqlquerymodel_data(model: &QSqlQueryModel, index: &QModelIndex) -> QString {
+    model
+        .data(index, ItemDataRole::DisplayRole.repr)
+        .value()
+        .unwrap_or_default()
+}
+
+fn qsqlquerymodel_set_executed_query(
+    model: Pin<&mut QSqlQueryModel>,
+    database: &QSqlDatabase,
+    text: &QString,
+) -> bool {
+    let mut query = QSqlQuery::new(database);
+    let executed = query
+        .as_mut()
+        .map(|query| query.exec_query(text))
+        .unwrap_or(false);
+    model.set_executed_query(query);
+    executed
+}
+
+/// Returns the text of the error of the query, which is empty if the query was successful
+fn qsqlquerymodel_set_query(
+    mut model: Pin<&mut QSqlQueryModel>,
+    database: &QSqlDatabase,
+    text: &QString,
+) -> QString {
+    model.as_mut().set_query(text, database);
+    model.last_error_text()
+}
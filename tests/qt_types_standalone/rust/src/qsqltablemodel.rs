@@ -0,0 +1,84 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{
+    ItemDataRole, QModelIndex, QSqlDatabase, QSqlTableModel, QSqlTableModelEditStrategy, QString,
+    QVariant,
+};
+
+#[cxx::bridge]
+mod qsqltablemodel_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qsqldatabase.h");
+        type QSqlDatabase = cxx_qt_lib::QSqlDatabase;
+        include!("cxx-qt-lib/qsqltablemodel.h");
+        type QSqlTableModel = cxx_qt_lib::QSqlTableModel;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "Rust" {
+        fn construct_qsqltablemodel(database: &QSqlDatabase) -> UniquePtr<QSqlTableModel>;
+        fn qsqltablemodel_append(model: Pin<&mut QSqlTableModel>, id: i64, name: &QString) -> bool;
+        fn qsqltablemodel_is_dirty(model: &QSqlTableModel) -> bool;
+        fn qsqltablemodel_remove_first(model: Pin<&mut QSqlTableModel>) -> bool;
+        fn qsqltablemodel_select(model: Pin<&mut QSqlTableModel>, filter: &QString) -> bool;
+        fn qsqltablemodel_submit(model: Pin<&mut QSqlTableModel>) -> bool;
+    }
+}
+
+fn construct_qsqltablemodel(database: &QSqlDatabase) -> cxx::UniquePtr<QSqlTableModel> {
+    let mut model = QSqlTableModel::new(database);
+    if let Some(mut model) = model.as_mut() {
+        model.as_mut().set_table(&QString::from("items"));
+        model.set_edit_strategy(QSqlTableModelEditStrategy::OnManualSubmit);
+    }
+    model
+}
+
+fn qsqltablemodel_append(mut model: Pin<&mut QSqlTableModel>, id: i64, name: &QString) -> bool {
+    let parent = QModelIndex::default();
+    let row = model.row_count(&parent);
+    if !model.as_mut().insert_rows(row, 1, &parent) {
+        return false;
+    }
+
+    let id_column = model.field_index(&QString::from("id"));
+    let name_column = model.field_index(&QString::from("name"));
+    // SAFETY: the pointer is to the model, which is valid for the duration of this function
+    let (id_index, name_index) = unsafe {
+        let items = &*model.as_mut().as_abstract_item_model_ptr();
+        (
+            items.index(row, id_column, &parent),
+            items.index(row, name_column, &parent),
+        )
+    };
+    model
+        .as_mut()
+        .set_data(&id_index, &QVariant::from(&id), ItemDataRole::EditRole.repr)
+        && model.as_mut().set_data(
+            &name_index,
+            &QVariant::from(name),
+            ItemDataRole::EditRole.repr,
+        )
+}
+
+fn qsqltablemodel_is_dirty(model: &QSqlTableModel) -> bool {
+    model.is_dirty()
+}
+
+fn qsqltablemodel_remove_first(model: Pin<&mut QSqlTableModel>) -> bool {
+    model.remove_rows(0, 1, &QModelIndex::default())
+}
+
+fn qsqltablemodel_select(mut model: Pin<&mut QSqlTableModel>, filter: &QString) -> bool {
+    model.as_mut().set_filter(filter);
+    model.select()
+}
+
+fn qsqltablemodel_submit(model: Pin<&mut QSqlTableModel>) -> bool {
+    model.submit_all()
+}
@@ -0,0 +1,125 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{QObject, QStateMachine, QString, QStringList, QVariant};
+use std::sync::Mutex;
+
+#[cxx::bridge]
+mod qstatemachine_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qobjectconnect.h");
+        type QObject = cxx_qt_lib::QObject;
+        include!("cxx-qt-lib/qstatemachine.h");
+        type QStateMachine = cxx_qt_lib::QStateMachine;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = cxx_qt_lib::QStringList;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = cxx_qt_lib::QVariant;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[rust_name = "qstatemachine_add_transition"]
+        fn qstatemachineAddTransition(
+            machine: Pin<&mut QStateMachine>,
+            from: &QString,
+            sender: &QObject,
+            signal: &str,
+            to: &QString,
+        ) -> bool;
+        #[rust_name = "qstatemachine_assign_property"]
+        fn qstatemachineAssignProperty(
+            machine: Pin<&mut QStateMachine>,
+            name: &QString,
+            object: Pin<&mut QObject>,
+            property: &str,
+            value: &QVariant,
+        ) -> bool;
+    }
+
+    extern "Rust" {
+        fn construct_qstatemachine(
+            sender: &QObject,
+            target: Pin<&mut QObject>,
+        ) -> UniquePtr<QStateMachine>;
+        fn qstatemachine_rejects_invalid_names(sender: &QObject) -> bool;
+        fn qstatemachine_active_states(machine: &QStateMachine) -> QStringList;
+        fn qstatemachine_take_entered() -> QString;
+    }
+}
+
+use qstatemachine_cxx::{qstatemachine_add_transition, qstatemachine_assign_property};
+
+/// The names of the states which have been entered, in order
+static ENTERED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+// Each change of the name of the sender moves the machine from idle to running and then to done
+fn construct_qstatemachine(
+    sender: &QObject,
+    target: Pin<&mut QObject>,
+) -> cxx::UniquePtr<QStateMachine> {
+    let idle = QString::from("idle");
+    let running = QString::from("running");
+    let done = QString::from("done");
+
+    let mut machine = QStateMachine::new();
+    machine.pin_mut().add_state(&idle);
+    machine.pin_mut().add_state(&running);
+    machine.pin_mut().add_final_state(&done);
+    machine.pin_mut().set_initial_state(&idle);
+
+    let signal = "objectNameChanged(QString)";
+    qstatemachine_add_transition(machine.pin_mut(), &idle, sender, signal, &running);
+    qstatemachine_add_transition(machine.pin_mut(), &running, sender, signal, &done);
+    qstatemachine_assign_property(
+        machine.pin_mut(),
+        &running,
+        target,
+        "objectName",
+        &QVariant::from(&QString::from("assigned")),
+    );
+
+    ENTERED.lock().unwrap().clear();
+    for name in [&idle, &running] {
+        machine
+            .pin_mut()
+            .on_state_entered(name, |name| {
+                ENTERED.lock().unwrap().push(name.to_string());
+            })
+            .expect("the state exists")
+            .release();
+    }
+
+    machine
+}
+
+fn qstatemachine_rejects_invalid_names(sender: &QObject) -> bool {
+    let idle = QString::from("idle");
+    let missing = QString::from("missing");
+
+    let mut machine = QStateMachine::new();
+    machine.pin_mut().add_state(&idle)
+        && !machine.pin_mut().add_state(&idle)
+        && !machine.pin_mut().add_final_state(&idle)
+        && !machine.pin_mut().set_initial_state(&missing)
+        && machine
+            .pin_mut()
+            .on_state_entered(&missing, |_| {})
+            .is_err()
+        && !qstatemachine_add_transition(machine.pin_mut(), &idle, sender, "missing()", &idle)
+        && !qstatemachine_add_transition(machine.pin_mut(), &missing, sender, "destroyed()", &idle)
+}
+
+fn qstatemachine_active_states(machine: &QStateMachine) -> QStringList {
+    machine.active_states()
+}
+
+// Returns the names of the entered states separated by commas, and clears them
+fn qstatemachine_take_entered() -> QString {
+    QString::from(&std::mem::take(&mut *ENTERED.lock().unwrap()).join(","))
+}
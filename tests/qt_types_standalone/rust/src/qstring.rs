@@ -20,6 +20,7 @@ mod qstring_cxx {
         fn modify_qstring(s: Pin<&mut QString>);
         fn can_handle_qstring_change() -> bool;
         fn clone_qstring(s: &QString) -> QString;
+        fn can_use_as_utf16_slice() -> bool;
     }
 }
 
@@ -57,3 +58,11 @@ fn can_handle_qstring_change() -> bool {
 fn clone_qstring(s: &QString) -> QString {
     s.clone()
 }
+
+fn can_use_as_utf16_slice() -> bool {
+    let string = "String slice 😀";
+    let qstring = QString::from(string);
+
+    qstring.as_slice() == string.encode_utf16().collect::<Vec<u16>>()
+        && qstring.to_string() == string
+}
@@ -0,0 +1,71 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::UniquePtr;
+use cxx_qt_lib::{QMetaObjectConnection, QString, QStringList, QStringListModel};
+use std::{cell::RefCell, rc::Rc};
+
+#[cxx::bridge]
+mod qstringlistmodel_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstringlist.h");
+        include!("cxx-qt-lib/qstringlistmodel.h");
+
+        type QStringList = cxx_qt_lib::QStringList;
+        type QStringListModel = cxx_qt_lib::QStringListModel;
+    }
+
+    extern "Rust" {
+        type StringListModelTest;
+
+        fn new_string_list_model_test() -> Box<StringListModelTest>;
+        fn changed_count(self: &StringListModelTest) -> i32;
+        fn changed_strings(self: &StringListModelTest) -> QStringList;
+        fn model(self: &mut StringListModelTest) -> Pin<&mut QStringListModel>;
+    }
+}
+
+struct StringListModelTest {
+    changed: Rc<RefCell<(i32, QStringList)>>,
+    _connection: QMetaObjectConnection,
+    model: UniquePtr<QStringListModel>,
+}
+
+fn new_string_list_model_test() -> Box<StringListModelTest> {
+    let mut model = QStringListModel::new();
+    model
+        .pin_mut()
+        .set_string_list(vec![QString::from("Red"), QString::from("Green")]);
+
+    let changed = Rc::new(RefCell::new((0, QStringList::default())));
+    let connection = model.pin_mut().on_data_changed({
+        let changed = changed.clone();
+        move |strings| {
+            let mut changed = changed.borrow_mut();
+            changed.0 += 1;
+            changed.1 = strings.clone();
+        }
+    });
+
+    Box::new(StringListModelTest {
+        changed,
+        _connection: connection,
+        model,
+    })
+}
+
+impl StringListModelTest {
+    fn changed_count(&self) -> i32 {
+        self.changed.borrow().0
+    }
+
+    fn changed_strings(&self) -> QStringList {
+        self.changed.borrow().1.clone()
+    }
+
+    fn model(&mut self) -> core::pin::Pin<&mut QStringListModel> {
+        self.model.pin_mut()
+    }
+}
@@ -0,0 +1,49 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{QString, QSystemSemaphore, QSystemSemaphoreAccessMode};
+
+#[cxx::bridge]
+mod qsystemsemaphore_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qsystemsemaphore.h");
+        type QSystemSemaphore = cxx_qt_lib::QSystemSemaphore;
+    }
+
+    extern "Rust" {
+        fn construct_qsystemsemaphore(
+            key: &QString,
+            initial_value: i32,
+        ) -> UniquePtr<QSystemSemaphore>;
+        fn qsystemsemaphore_acquire_release(semaphore: Pin<&mut QSystemSemaphore>, n: i32) -> bool;
+        fn qsystemsemaphore_set_key(
+            semaphore: Pin<&mut QSystemSemaphore>,
+            key: &QString,
+            initial_value: i32,
+        );
+    }
+}
+
+fn construct_qsystemsemaphore(
+    key: &QString,
+    initial_value: i32,
+) -> cxx::UniquePtr<QSystemSemaphore> {
+    QSystemSemaphore::new(key, initial_value, QSystemSemaphoreAccessMode::Create)
+}
+
+fn qsystemsemaphore_acquire_release(mut semaphore: Pin<&mut QSystemSemaphore>, n: i32) -> bool {
+    semaphore.as_mut().acquire() && semaphore.release(n)
+}
+
+fn qsystemsemaphore_set_key(
+    semaphore: Pin<&mut QSystemSemaphore>,
+    key: &QString,
+    initial_value: i32,
+) {
+    semaphore.set_key(key, initial_value, QSystemSemaphoreAccessMode::Create);
+}
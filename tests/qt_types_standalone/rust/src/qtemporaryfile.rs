@@ -0,0 +1,56 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{
+    QByteArray, QLockFile, QLockFileLockError, QString, QTemporaryDir, QTemporaryFile,
+};
+
+#[cxx::bridge]
+mod qtemporaryfile_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        include!("cxx-qt-lib/qstring.h");
+
+        type QByteArray = cxx_qt_lib::QByteArray;
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "Rust" {
+        fn qlockfile_is_locked_elsewhere(file_name: &QString) -> bool;
+        fn qtemporarydir_is_removed() -> bool;
+        fn qtemporaryfile_write_data(data: &QByteArray) -> QString;
+    }
+}
+
+fn qlockfile_is_locked_elsewhere(file_name: &QString) -> bool {
+    let mut lock_file = QLockFile::new(file_name);
+    !lock_file.pin_mut().try_lock(0)
+        && !lock_file.is_locked()
+        && lock_file.error() == QLockFileLockError::LockFailedError
+}
+
+fn qtemporarydir_is_removed() -> bool {
+    let dir = QTemporaryDir::new();
+    if !dir.is_valid() || !dir.auto_remove() {
+        return false;
+    }
+
+    let path = std::path::PathBuf::from(dir.path().to_string());
+    let existed = path.is_dir();
+    drop(dir);
+    existed && !path.exists()
+}
+
+fn qtemporaryfile_write_data(data: &QByteArray) -> QString {
+    let mut file = QTemporaryFile::new();
+    file.pin_mut().set_auto_remove(false);
+    if !file.pin_mut().open() {
+        return QString::default();
+    }
+
+    file.pin_mut().write(data.as_slice());
+    file.pin_mut().close();
+    file.file_name()
+}
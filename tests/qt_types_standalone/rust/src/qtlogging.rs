@@ -0,0 +1,65 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QMessageLogContext, QString, QtMsgType};
+use std::sync::Mutex;
+
+#[cxx::bridge]
+mod qtlogging_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qtlogging.h");
+        type QMessageLogContext = cxx_qt_lib::QMessageLogContext;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "Rust" {
+        fn format_log_message(context: &QMessageLogContext, message: &QString) -> QString;
+        fn install_message_handler();
+        fn reset_message_handler();
+        fn take_logged_messages() -> QString;
+    }
+}
+
+/// The messages which have been handled by the installed message handler
+static LOGGED_MESSAGES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+fn format_log_message(context: &QMessageLogContext, message: &QString) -> QString {
+    // The pattern of the default message handler is also used when formatting
+    cxx_qt_lib::q_set_message_pattern(&QString::from("%{type} %{category}: %{message}"));
+    let formatted = cxx_qt_lib::q_format_log_message(QtMsgType::QtWarningMsg, context, message);
+    // Restore the default pattern of Qt
+    cxx_qt_lib::q_set_message_pattern(&QString::from(
+        "%{if-category}%{category}: %{endif}%{message}",
+    ));
+    formatted
+}
+
+fn install_message_handler() {
+    cxx_qt_lib::q_install_message_handler(|msg_type, context, message| {
+        let category = context
+            .category()
+            .map(|category| category.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let file = context
+            .file()
+            .map(|file| file.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        LOGGED_MESSAGES.lock().unwrap().push(format!(
+            "{} {category} {file}:{}: {message}",
+            msg_type.repr,
+            context.line()
+        ));
+    });
+}
+
+fn reset_message_handler() {
+    cxx_qt_lib::q_reset_message_handler();
+}
+
+// Returns the logged messages separated by new lines, and clears them
+fn take_logged_messages() -> QString {
+    QString::from(&std::mem::take(&mut *LOGGED_MESSAGES.lock().unwrap()).join("\n"))
+}
@@ -7,6 +7,7 @@ use cxx_qt_lib::{
     QByteArray, QColor, QDate, QDateTime, QPoint, QPointF, QRect, QRectF, QSize, QSizeF, QString,
     QTime, QTimeZone, QUrl, QVariant,
 };
+use std::sync::atomic::{AtomicBool, Ordering};
 
 #[cxx::bridge]
 mod qvariant_cxx {
@@ -45,6 +46,9 @@ mod qvariant_cxx {
         fn construct_qvariant(test: VariantTest) -> QVariant;
         fn read_qvariant(v: &QVariant, test: VariantTest) -> bool;
         fn clone_qvariant(v: &QVariant) -> QVariant;
+        fn construct_qvariant_rust_handle() -> QVariant;
+        fn read_qvariant_rust_handle(v: &QVariant) -> i32;
+        fn qvariant_rust_handle_dropped() -> bool;
     }
 }
 
@@ -202,3 +206,36 @@ fn read_qvariant(v: &cxx_qt_lib::QVariant, test: VariantTest) -> bool {
 fn clone_qvariant(v: &QVariant) -> QVariant {
     v.clone()
 }
+
+static RUST_HANDLE_DROPPED: AtomicBool = AtomicBool::new(false);
+
+struct RustHandle {
+    number: i32,
+}
+
+impl Drop for RustHandle {
+    fn drop(&mut self) {
+        RUST_HANDLE_DROPPED.store(true, Ordering::SeqCst);
+    }
+}
+
+fn construct_qvariant_rust_handle() -> QVariant {
+    RUST_HANDLE_DROPPED.store(false, Ordering::SeqCst);
+    QVariant::from_rust_handle(RustHandle { number: 42 })
+}
+
+fn read_qvariant_rust_handle(v: &QVariant) -> i32 {
+    // A handle of another type is not returned
+    if v.rust_handle::<i32>().is_some() {
+        return -2;
+    }
+
+    match v.rust_handle::<RustHandle>() {
+        Some(handle) => handle.number,
+        None => -1,
+    }
+}
+
+fn qvariant_rust_handle_dropped() -> bool {
+    RUST_HANDLE_DROPPED.load(Ordering::SeqCst)
+}
@@ -0,0 +1,85 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{QByteArray, QString, QUrl, QWebSocket};
+use std::sync::Mutex;
+
+#[cxx::bridge]
+mod qwebsocket_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = cxx_qt_lib::QUrl;
+        include!("cxx-qt-lib/qwebsocket.h");
+        type QWebSocket = cxx_qt_lib::QWebSocket;
+    }
+
+    extern "Rust" {
+        fn construct_qwebsocket() -> UniquePtr<QWebSocket>;
+        fn qwebsocket_open(socket: Pin<&mut QWebSocket>, url: &QUrl);
+        fn qwebsocket_close(socket: Pin<&mut QWebSocket>);
+        fn qwebsocket_send_binary_message(socket: Pin<&mut QWebSocket>, data: &QByteArray) -> i64;
+        fn qwebsocket_send_text_message(socket: Pin<&mut QWebSocket>, message: &QString) -> i64;
+        fn qwebsocket_take_events() -> QString;
+    }
+}
+
+/// The signals which the sockets have emitted, with the messages which have been received
+static EVENTS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+fn push_event(event: String) {
+    EVENTS.lock().unwrap().push(event);
+}
+
+fn construct_qwebsocket() -> cxx::UniquePtr<QWebSocket> {
+    let mut socket = QWebSocket::new();
+    if let Some(mut socket) = socket.as_mut() {
+        socket
+            .as_mut()
+            .on_connected(|| push_event("connected".to_owned()))
+            .release();
+        socket
+            .as_mut()
+            .on_disconnected(|| push_event("disconnected".to_owned()))
+            .release();
+        socket
+            .as_mut()
+            .on_binary_message_received(|data| {
+                push_event(format!(
+                    "binary:{}",
+                    String::from_utf8_lossy(data.as_slice())
+                ))
+            })
+            .release();
+        socket
+            .on_text_message_received(|message| push_event(format!("text:{message}")))
+            .release();
+    }
+    socket
+}
+
+fn qwebsocket_open(socket: Pin<&mut QWebSocket>, url: &QUrl) {
+    socket.open(url);
+}
+
+fn qwebsocket_close(socket: Pin<&mut QWebSocket>) {
+    socket.close();
+}
+
+fn qwebsocket_send_binary_message(socket: Pin<&mut QWebSocket>, data: &QByteArray) -> i64 {
+    socket.send_binary_message(data)
+}
+
+fn qwebsocket_send_text_message(socket: Pin<&mut QWebSocket>, message: &QString) -> i64 {
+    socket.send_text_message(message)
+}
+
+fn qwebsocket_take_events() -> QString {
+    QString::from(&std::mem::take(&mut *EVENTS.lock().unwrap()).join(";"))
+}
@@ -0,0 +1,52 @@
+// SPDX-FileCopyrightText: 2026 agent <agent@local>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{QString, QWebSocket, QWebSocketServer, QWebSocketServerSslMode};
+use std::sync::atomic::{AtomicI32, Ordering};
+
+#[cxx::bridge]
+mod qwebsocketserver_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qwebsocket.h");
+        type QWebSocket = cxx_qt_lib::QWebSocket;
+        include!("cxx-qt-lib/qwebsocketserver.h");
+        type QWebSocketServer = cxx_qt_lib::QWebSocketServer;
+    }
+
+    extern "Rust" {
+        fn construct_qwebsocketserver(name: &QString) -> UniquePtr<QWebSocketServer>;
+        fn qwebsocketserver_accept(server: Pin<&mut QWebSocketServer>) -> UniquePtr<QWebSocket>;
+        fn qwebsocketserver_take_connections() -> i32;
+    }
+}
+
+/// The number of new connections which the servers have been notified of
+static CONNECTIONS: AtomicI32 = AtomicI32::new(0);
+
+fn construct_qwebsocketserver(name: &QString) -> cxx::UniquePtr<QWebSocketServer> {
+    let mut server = QWebSocketServer::new(name, QWebSocketServerSslMode::NonSecureMode);
+    if let Some(mut server) = server.as_mut() {
+        server
+            .as_mut()
+            .on_new_connection(|| {
+                CONNECTIONS.fetch_add(1, Ordering::SeqCst);
+            })
+            .release();
+        // Let the system choose a free port
+        server.listen(0);
+    }
+    server
+}
+
+fn qwebsocketserver_accept(server: Pin<&mut QWebSocketServer>) -> cxx::UniquePtr<QWebSocket> {
+    server.next_pending_connection()
+}
+
+fn qwebsocketserver_take_connections() -> i32 {
+    CONNECTIONS.swap(0, Ordering::SeqCst)
+}